@@ -0,0 +1,85 @@
+use serde::{Deserialize, Serialize};
+use spl_domain::entities::auth::{Impersonation, ImpersonationAction};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuthTokensDto {
+    pub access_token: String,
+    pub refresh_token: String,
+    /// Access token lifetime in seconds
+    pub expires_in: i64,
+}
+
+/// Client a login comes from, recorded on the session
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ClientInfoDto {
+    pub user_agent: Option<String>,
+    pub ip_address: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ForgotPasswordDto {
+    pub username: Option<String>,
+    pub email: Option<String>,
+    pub company_id: Option<Uuid>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResetPasswordDto {
+    pub token: String,
+    pub new_password: String,
+}
+
+/// Outcome of a valid password: either the session is open, or a second factor is needed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum LoginResultDto {
+    Authenticated(AuthTokensDto),
+    TwoFactorRequired(TwoFactorChallengeDto),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TwoFactorChallengeDto {
+    pub challenge_token: String,
+    /// Challenge lifetime in seconds
+    pub expires_in: i64,
+    /// The role requires two-factor authentication but the user has not enrolled yet
+    pub setup_required: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VerifyTwoFactorDto {
+    pub challenge_token: String,
+    /// TOTP code or recovery code
+    pub code: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TwoFactorSetupDto {
+    pub secret: String,
+    pub provisioning_uri: String,
+    pub recovery_codes: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TwoFactorStatusDto {
+    pub enabled: bool,
+    pub required: bool,
+    pub recovery_codes_remaining: u64,
+}
+
+/// Access token acting as the subject of an impersonation. It cannot be refreshed,
+/// the impersonation has to be started again once it expires.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImpersonationTokenDto {
+    pub access_token: String,
+    /// Access token lifetime in seconds
+    pub expires_in: i64,
+    pub impersonation: Impersonation,
+}
+
+/// An impersonation with every request made during it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImpersonationAuditDto {
+    pub impersonation: Impersonation,
+    pub actions: Vec<ImpersonationAction>,
+}
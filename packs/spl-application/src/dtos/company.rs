@@ -1,13 +1,33 @@
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateCompanyDto {
     pub name: String,
     pub description: Option<String>,
+    /// Company the new one belongs to, e.g. the cooperative of a member farm
+    pub parent_id: Option<Uuid>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UpdateCompanyDto {
     pub name: Option<String>,
     pub description: Option<String>,
+    /// 0 makes two-factor authentication optional again
+    pub two_factor_required_level: Option<i16>,
+}
+
+/// Replaces the settings of a company, unset fields follow the server defaults
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UpdateCompanySettingsDto {
+    pub timezone: Option<String>,
+    pub default_language: Option<String>,
+    pub data_retention_days: Option<u32>,
+    /// Unset or 0 makes two-factor authentication optional
+    pub two_factor_required_level: Option<i16>,
+    pub default_page_size: Option<u64>,
+    /// Names of image formats, e.g. `jpeg` or `png`
+    pub allowed_upload_formats: Option<Vec<String>>,
+    pub alert_severity_threshold: Option<f32>,
+    pub alert_confidence_threshold: Option<f32>,
 }
@@ -15,6 +15,7 @@ pub struct DashboardSummaryDto {
     pub max_date: Option<DateTime<Utc>>,
     pub plot_ids: Option<Vec<Option<Uuid>>>,
     pub labels: Option<Vec<String>>,
+    pub model_versions: Option<Vec<String>>,
 }
 
 /// DTO for requesting dashboard counts (summary + last predictions)
@@ -25,6 +26,7 @@ pub struct DashboardCountsDto {
     pub max_date: Option<DateTime<Utc>>,
     pub plot_ids: Option<Vec<Option<Uuid>>>,
     pub labels: Option<Vec<String>>,
+    pub model_versions: Option<Vec<String>>,
     pub last_n: u64,
 }
 
@@ -36,4 +38,5 @@ pub struct DashboardSummaryPlotDto {
     pub min_date: Option<DateTime<Utc>>,
     pub max_date: Option<DateTime<Utc>>,
     pub labels: Option<Vec<String>>,
+    pub model_versions: Option<Vec<String>>,
 }
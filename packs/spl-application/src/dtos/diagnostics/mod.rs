@@ -1,7 +1,11 @@
 pub mod label;
 pub mod mark_type;
 pub mod prediction;
+pub mod prediction_batch;
 
 pub use label::{CreateLabelDto, UpdateLabelDto};
 pub use mark_type::{CreateMarkTypeDto, UpdateMarkTypeDto};
-pub use prediction::{CreatePredictionDto, FilterPredictionDto, UpdatePredictionDto};
+pub use prediction::{
+    CreatePredictionDto, FilterPredictionDto, PaginatedPredictions, UpdatePredictionDto,
+};
+pub use prediction_batch::{PredictionBatchDetailDto, UploadedFileDto};
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use spl_domain::entities::diagnostics::Prediction;
 use uuid::Uuid;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -24,9 +25,18 @@ pub struct FilterPredictionDto {
     pub company_id: Option<Uuid>,
     pub target_user_ids: Option<Vec<Uuid>>,
     pub labels: Option<Vec<String>>,
+    pub model_versions: Option<Vec<String>>,
     pub plot_ids: Option<Vec<Option<Uuid>>>,
     pub min_date: Option<chrono::DateTime<chrono::Utc>>,
     pub max_date: Option<chrono::DateTime<chrono::Utc>>,
     pub limit: Option<u64>,
     pub page: Option<u64>,
 }
+
+/// Page of filtered predictions
+pub struct PaginatedPredictions {
+    pub total: u64,
+    pub page: u64,
+    pub limit: u64,
+    pub items: Vec<Prediction>,
+}
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+use spl_domain::entities::diagnostics::{PredictionBatch, PredictionBatchItem};
+
+/// File of a batch upload, either an image or an archive of images
+#[derive(Debug, Clone)]
+pub struct UploadedFileDto {
+    pub filename: Option<String>,
+    pub content: Vec<u8>,
+}
+
+/// A batch with the outcome of each of its images
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PredictionBatchDetailDto {
+    pub batch: PredictionBatch,
+    pub items: Vec<PredictionBatchItem>,
+}
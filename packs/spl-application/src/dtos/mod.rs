@@ -1,8 +1,14 @@
+pub mod auth;
 pub mod company;
 pub mod dashboard;
 pub mod diagnostics;
 pub mod feedback;
 pub mod image;
+pub mod offboarding;
 pub mod plot;
 pub mod recommendation;
+pub mod service_account;
+pub mod sso;
+pub mod team;
+pub mod usage;
 pub mod user;
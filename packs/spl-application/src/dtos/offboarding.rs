@@ -0,0 +1,99 @@
+use bytes::Bytes;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use spl_domain::entities::company::Company;
+use spl_domain::entities::diagnostics::{JobStatus, PredictionModel};
+use spl_domain::entities::feedback::Feedback;
+use spl_domain::entities::offboarding::{CompanyOffboarding, OffboardingEvent};
+use spl_domain::entities::user::User;
+use uuid::Uuid;
+
+/// An offboarding with its audit trail
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OffboardingAuditDto {
+    pub offboarding: CompanyOffboarding,
+    pub events: Vec<OffboardingEvent>,
+}
+
+/// Archive with the data of an offboarded company
+#[derive(Debug, Clone)]
+pub struct OffboardingExportDto {
+    pub filename: String,
+    pub content_type: String,
+    pub content: Bytes,
+}
+
+/// Member of the company in an export, without credentials
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportedUser {
+    pub id: Uuid,
+    pub username: String,
+    pub email: Option<String>,
+    pub name: Option<String>,
+    pub surname: Option<String>,
+    pub role: String,
+    pub deactivated_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<&User> for ExportedUser {
+    fn from(user: &User) -> Self {
+        Self {
+            id: user.id,
+            username: user.username.clone(),
+            email: user.email.clone(),
+            name: user.name.clone(),
+            surname: user.surname.clone(),
+            role: user.role.name.clone(),
+            deactivated_at: user.deactivated_at,
+            created_at: user.created_at,
+        }
+    }
+}
+
+/// Prediction in an export. Files are referenced by their path inside the archive.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportedPrediction {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub plot_id: Option<Uuid>,
+    pub label: String,
+    pub presence_confidence: f32,
+    pub absence_confidence: f32,
+    pub severity: f32,
+    pub model: Option<PredictionModel>,
+    pub image: String,
+    pub masks: Vec<String>,
+    pub feedback: Option<Feedback>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Inference job in an export, with its upload while it is still kept
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportedJob {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub batch_item_id: Option<Uuid>,
+    pub filename: String,
+    pub upload: Option<String>,
+    pub status: JobStatus,
+    pub prediction_id: Option<Uuid>,
+    pub error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+/// `manifest.json` of an export
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportManifest {
+    pub offboarding_id: Uuid,
+    pub company: Company,
+    pub exported_at: DateTime<Utc>,
+    pub users: usize,
+    pub plots: usize,
+    pub predictions: usize,
+    pub jobs: usize,
+    pub files: usize,
+    /// Files referenced by predictions or jobs that were no longer in storage
+    pub missing_files: Vec<String>,
+}
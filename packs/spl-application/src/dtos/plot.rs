@@ -23,7 +23,8 @@ pub struct AssignPlotDto {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DetailedPlotDto {
     pub page: u64,
-    pub limit: u64,
+    /// Items per page, the default page size of the company when unset
+    pub limit: Option<u64>,
     pub labels: Option<Vec<String>>,
 }
 
@@ -0,0 +1,27 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use spl_domain::entities::auth::ApiKey;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateServiceAccountDto {
+    pub name: String,
+    pub description: Option<String>,
+    /// Required for admins, supervisors always use their own company
+    pub company_id: Option<Uuid>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateApiKeyDto {
+    pub name: String,
+    pub scopes: Vec<String>,
+    /// Keys without expiration stay valid until revoked
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+/// A new key: the plain value is only available here
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreatedApiKeyDto {
+    pub key: String,
+    pub api_key: ApiKey,
+}
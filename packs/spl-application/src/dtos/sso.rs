@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfigureIdentityProviderDto {
+    pub issuer: String,
+    pub client_id: String,
+    /// Keeps the stored secret when omitted
+    pub client_secret: Option<String>,
+    pub scopes: Option<String>,
+    pub role_claim: Option<String>,
+    pub role_mapping: BTreeMap<String, String>,
+    pub default_role: Option<String>,
+    pub auto_provision: bool,
+    pub enabled: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SsoCallbackDto {
+    pub code: String,
+    pub state: String,
+}
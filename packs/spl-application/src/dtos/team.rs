@@ -0,0 +1,30 @@
+use serde::{Deserialize, Serialize};
+use spl_domain::entities::team::{Team, TeamMember, TeamRole};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateTeamDto {
+    pub company_id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateTeamDto {
+    pub name: Option<String>,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetTeamMemberDto {
+    pub user_id: Uuid,
+    pub role: TeamRole,
+}
+
+/// A team with its members and assigned plots
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TeamDetailsDto {
+    pub team: Team,
+    pub members: Vec<TeamMember>,
+    pub plot_ids: Vec<Uuid>,
+}
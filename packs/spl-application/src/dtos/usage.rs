@@ -0,0 +1,36 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use spl_domain::entities::usage::{Usage, UsageQuota, UsageStatus};
+use uuid::Uuid;
+
+/// Replaces the quota of a company. Unset limits are unlimited, an unset warning ratio or
+/// enforcement follows the server quota.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UpdateCompanyQuotaDto {
+    pub predictions_per_month: Option<u64>,
+    pub predictions_per_user_per_month: Option<u64>,
+    pub stored_bytes_per_month: Option<u64>,
+    pub warning_ratio: Option<f32>,
+    /// `soft` or `hard`
+    pub enforcement: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserUsageReport {
+    pub user_id: Uuid,
+    pub usage: Usage,
+    pub status: UsageStatus,
+}
+
+/// Consumption of a company during a month against its quota
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UsageReport {
+    pub company_id: Uuid,
+    /// First day of the month
+    pub period: NaiveDate,
+    pub resets_at: DateTime<Utc>,
+    pub usage: Usage,
+    pub quota: UsageQuota,
+    pub status: UsageStatus,
+    pub users: Vec<UserUsageReport>,
+}
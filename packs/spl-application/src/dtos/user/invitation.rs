@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateInvitationDto {
+    pub email: String,
+    /// Role name, defaults to "user"
+    pub role: Option<String>,
+    /// Required for admins, supervisors always use their own company
+    pub company_id: Option<Uuid>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AcceptInvitationDto {
+    pub token: String,
+    pub username: String,
+    pub password: String,
+    pub name: Option<String>,
+    pub surname: Option<String>,
+}
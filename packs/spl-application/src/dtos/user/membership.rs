@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+use spl_domain::entities::user::CompanyMembership;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetMembershipDto {
+    /// Role name the user has in the company
+    pub role: String,
+}
+
+/// A company the user works for. `home` marks the company of their account, the
+/// others come from memberships.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserMembershipDto {
+    pub membership: CompanyMembership,
+    pub home: bool,
+}
@@ -1,3 +1,9 @@
+pub mod invitation;
+pub mod membership;
+pub mod role;
 pub mod user;
 
+pub use invitation::*;
+pub use membership::*;
+pub use role::*;
 pub use user::*;
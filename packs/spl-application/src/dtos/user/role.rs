@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+use spl_domain::entities::user::PermissionGrant;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateRoleDto {
+    pub name: String,
+    pub level: i16,
+    pub permissions: Vec<PermissionGrant>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateRoleDto {
+    pub name: Option<String>,
+    pub level: Option<i16>,
+    /// Replaces every permission of the role when given
+    pub permissions: Option<Vec<PermissionGrant>>,
+}
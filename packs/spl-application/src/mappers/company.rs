@@ -1,4 +1,5 @@
 use crate::dtos::company::{CreateCompanyDto, UpdateCompanyDto};
+use crate::services::two_factor::MIN_TWO_FACTOR_LEVEL;
 use chrono::Utc;
 use spl_domain::entities::company::Company;
 use spl_shared::error::{AppError, Result};
@@ -11,6 +12,8 @@ impl From<CreateCompanyDto> for Company {
             id: Uuid::new_v4(),
             name: dto.name,
             description: dto.description,
+            parent_id: dto.parent_id,
+            two_factor_required_level: None,
             created_at: Utc::now(),
             updated_at: Utc::now(),
         }
@@ -21,11 +24,29 @@ impl IntoWithContext<Company, Company> for UpdateCompanyDto {
     type Error = AppError;
 
     fn into_with_context(self, context: Company) -> Result<Company> {
+        let two_factor_required_level = match self.two_factor_required_level {
+            None => context.two_factor_required_level,
+            Some(level) => two_factor_required_level(level)?,
+        };
+
         Ok(Company {
             name: self.name.unwrap_or(context.name),
             description: self.description.or(context.description),
+            two_factor_required_level,
             updated_at: Utc::now(),
             ..context
         })
     }
 }
+
+/// Level from which two-factor authentication is mandatory, 0 making it optional
+pub(crate) fn two_factor_required_level(level: i16) -> Result<Option<i16>> {
+    match level {
+        0 => Ok(None),
+        level if level >= MIN_TWO_FACTOR_LEVEL => Ok(Some(level)),
+        _ => Err(AppError::ValidationError(format!(
+            "Two-factor authentication can only be required from level {}",
+            MIN_TWO_FACTOR_LEVEL
+        ))),
+    }
+}
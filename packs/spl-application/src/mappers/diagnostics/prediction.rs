@@ -20,6 +20,7 @@ impl IntoWithContext<Prediction, CreatePredictionContext> for CreatePredictionDt
     fn into_with_context(self, context: CreatePredictionContext) -> Result<Prediction> {
         Ok(Prediction {
             id: Uuid::new_v4(),
+            company_id: context.user.company.as_ref().map(|c| c.id),
             user: context.user,
             image: context.image,
             label: context.label,
@@ -27,6 +28,7 @@ impl IntoWithContext<Prediction, CreatePredictionContext> for CreatePredictionDt
             presence_confidence: self.presence_confidence,
             absence_confidence: self.absence_confidence,
             severity: self.severity,
+            model: None,
             feedback: None,
             created_at: Utc::now(),
             marks: vec![],
@@ -3,4 +3,5 @@ pub mod diagnostics;
 pub mod feedback;
 pub mod plot;
 pub mod recommendation;
+pub mod team;
 pub mod user;
@@ -0,0 +1,32 @@
+use crate::dtos::team::{CreateTeamDto, UpdateTeamDto};
+use chrono::Utc;
+use spl_domain::entities::team::Team;
+use spl_shared::error::{AppError, Result};
+use spl_shared::traits::IntoWithContext;
+use uuid::Uuid;
+
+impl From<CreateTeamDto> for Team {
+    fn from(dto: CreateTeamDto) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            company_id: dto.company_id,
+            name: dto.name,
+            description: dto.description,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+}
+
+impl IntoWithContext<Team, Team> for UpdateTeamDto {
+    type Error = AppError;
+
+    fn into_with_context(self, context: Team) -> Result<Team> {
+        Ok(Team {
+            name: self.name.unwrap_or(context.name),
+            description: self.description.or(context.description),
+            updated_at: Utc::now(),
+            ..context
+        })
+    }
+}
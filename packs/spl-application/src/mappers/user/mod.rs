@@ -1,3 +1,4 @@
+pub mod role;
 pub mod user;
 
 pub use user::*;
@@ -0,0 +1,30 @@
+use crate::dtos::user::{CreateRoleDto, UpdateRoleDto};
+use chrono::Utc;
+use spl_domain::entities::user::Role;
+use spl_shared::error::{AppError, Result};
+use spl_shared::traits::IntoWithContext;
+
+impl From<CreateRoleDto> for Role {
+    fn from(dto: CreateRoleDto) -> Self {
+        Self {
+            id: 0,
+            name: dto.name,
+            level: dto.level,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+}
+
+impl IntoWithContext<Role, Role> for UpdateRoleDto {
+    type Error = AppError;
+
+    fn into_with_context(self, current: Role) -> Result<Role> {
+        Ok(Role {
+            name: self.name.unwrap_or(current.name),
+            level: self.level.unwrap_or(current.level),
+            updated_at: Utc::now(),
+            ..current
+        })
+    }
+}
@@ -1,5 +1,5 @@
 use crate::dtos::user::{CreateUserDto, UpdateProfileDto, UpdateUserDto};
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use spl_domain::entities::company::Company;
 use spl_domain::entities::user::{Role, User};
 use spl_shared::error::{AppError, Result};
@@ -20,11 +20,13 @@ impl IntoWithContext<User, UserCreationContext> for CreateUserDto {
             id: Uuid::new_v4(),
             username: self.username,
             email: self.email,
+            email_verified_at: None,
             password_hash: context.password_hash,
             name: self.name,
             surname: self.surname,
             role: context.role,
             company: context.company,
+            deactivated_at: None,
             created_at: Utc::now(),
             updated_at: Utc::now(),
         })
@@ -35,15 +37,20 @@ impl IntoWithContext<User, User> for UpdateProfileDto {
     type Error = AppError;
 
     fn into_with_context(self, current: User) -> Result<User> {
+        let email = self.email.or(current.email.clone());
+        let email_verified_at = verified_at(&current, &email);
+
         Ok(User {
             id: current.id,
             username: current.username,
-            email: self.email.or(current.email),
+            email,
+            email_verified_at,
             password_hash: current.password_hash,
             name: self.name.or(current.name),
             surname: self.surname.or(current.surname),
             role: current.role,
             company: current.company,
+            deactivated_at: current.deactivated_at,
             created_at: current.created_at,
             updated_at: Utc::now(),
         })
@@ -61,11 +68,14 @@ impl IntoWithContext<User, UserUpdateContext> for UpdateUserDto {
 
     fn into_with_context(self, context: UserUpdateContext) -> Result<User> {
         let current = context.current_user;
+        let email = self.email.or(current.email.clone());
+        let email_verified_at = verified_at(&current, &email);
 
         Ok(User {
             id: current.id,
             username: self.username.unwrap_or(current.username),
-            email: self.email.or(current.email),
+            email,
+            email_verified_at,
             password_hash: context.password_hash.unwrap_or(current.password_hash),
             name: self.name.or(current.name),
             surname: self.surname.or(current.surname),
@@ -75,8 +85,18 @@ impl IntoWithContext<User, UserUpdateContext> for UpdateUserDto {
             } else {
                 current.company
             },
+            deactivated_at: current.deactivated_at,
             created_at: current.created_at,
             updated_at: Utc::now(),
         })
     }
 }
+
+/// Verification only holds for the address that was verified
+fn verified_at(current: &User, email: &Option<String>) -> Option<DateTime<Utc>> {
+    if &current.email == email {
+        current.email_verified_at
+    } else {
+        None
+    }
+}
@@ -1,136 +1,231 @@
-use spl_domain::entities::user::User;
+use crate::services::policy::{PolicyService, Resource};
+use spl_domain::entities::user::{PermissionScope, User};
 use spl_domain::ports::repositories::company::CompanyRepository;
-use spl_domain::ports::repositories::user::UserRepository;
+use spl_domain::ports::repositories::team::TeamRepository;
 use spl_shared::error::{AppError, Result};
 use std::sync::Arc;
 use uuid::Uuid;
 
+/// Resolves which company, users and plots a request may reach, from the scope of the
+/// permission it needs.
 pub struct AccessControlService {
     company_repo: Arc<dyn CompanyRepository>,
-    user_repo: Arc<dyn UserRepository>,
+    team_repo: Arc<dyn TeamRepository>,
+    policy: Arc<PolicyService>,
 }
 
 impl AccessControlService {
     pub fn new(
         company_repo: Arc<dyn CompanyRepository>,
-        user_repo: Arc<dyn UserRepository>,
+        team_repo: Arc<dyn TeamRepository>,
+        policy: Arc<PolicyService>,
     ) -> Self {
         Self {
             company_repo,
-            user_repo,
+            team_repo,
+            policy,
         }
     }
 
+    pub fn policy(&self) -> &PolicyService {
+        &self.policy
+    }
+
     /// Validates if a user has access to a specific company context.
     /// Returns the resolved Company ID to be used in queries.
+    /// - `any` scope: Must pass the company, which must exist.
+    /// - Narrower scopes: Their own company.
     pub async fn validate_company_access(
         &self,
         requester: &User,
+        permission: &str,
         requested_company_id: Option<Uuid>,
     ) -> Result<Uuid> {
-        if requester.role.level >= 100 {
-            // Admin: Must provide company_id to target a specific company
-            let cid = requested_company_id.ok_or_else(|| {
-                AppError::ValidationError(
-                    "Company ID is required for Admin operations on company resources".to_string(),
-                )
-            })?;
-
-            // Verify Company Exists
-            if self.company_repo.get_by_id(cid).await?.is_none() {
-                return Err(AppError::ValidationError(
-                    "Target company not found".to_string(),
-                ));
+        match self.policy.scope(requester, permission).await? {
+            None => Err(AppError::Forbidden),
+            Some(PermissionScope::Any) => {
+                // Must provide company_id to target a specific company
+                let cid = requested_company_id.ok_or_else(|| {
+                    AppError::ValidationError(
+                        "Company ID is required for Admin operations on company resources"
+                            .to_string(),
+                    )
+                })?;
+
+                // Verify Company Exists
+                if self.company_repo.get_by_id(cid).await?.is_none() {
+                    return Err(AppError::ValidationError(
+                        "Target company not found".to_string(),
+                    ));
+                }
+                Ok(cid)
             }
-            Ok(cid)
-        } else {
-            // Non-Admin: Must belong to a company
-            let user_company_id = requester.company.as_ref().map(|c| c.id).ok_or_else(|| {
-                AppError::ValidationError("User must belong to a company".to_string())
-            })?;
-
-            // If they requested a specific company, it MUST match their own
-            if let Some(requested_cid) = requested_company_id {
-                if requested_cid != user_company_id {
-                    return Err(AppError::Forbidden);
+            Some(_) => {
+                // Must belong to a company
+                let user_company_id =
+                    requester.company.as_ref().map(|c| c.id).ok_or_else(|| {
+                        AppError::ValidationError("User must belong to a company".to_string())
+                    })?;
+
+                // If they requested a specific company, it MUST match their own
+                if let Some(requested_cid) = requested_company_id {
+                    if requested_cid != user_company_id {
+                        return Err(AppError::Forbidden);
+                    }
                 }
+
+                Ok(user_company_id)
             }
+        }
+    }
+
+    /// Like `validate_company_access`, but users reading their own company may also
+    /// target the companies below it, e.g. the member farms of a cooperative.
+    pub async fn validate_company_read_access(
+        &self,
+        requester: &User,
+        permission: &str,
+        requested_company_id: Option<Uuid>,
+    ) -> Result<Uuid> {
+        let scope = self.policy.scope(requester, permission).await?;
+        let own_company_id = requester.company.as_ref().map(|c| c.id);
 
-            Ok(user_company_id)
+        if let (Some(PermissionScope::Company), Some(own), Some(requested)) =
+            (scope, own_company_id, requested_company_id)
+        {
+            if requested != own {
+                if !self.get_company_subtree_ids(own).await?.contains(&requested) {
+                    return Err(AppError::Forbidden);
+                }
+                return Ok(requested);
+            }
         }
+
+        self.validate_company_access(requester, permission, requested_company_id)
+            .await
     }
 
-    /// Returns a list of User IDs that the requester is allowed to access/filter by.
-    /// - Admin: Can access anyone in the target company.
-    /// - Supervisor: Can access anyone in their own company.
-    /// - User: Can only access themselves.
-    pub async fn get_accessible_user_ids(
+    /// Companies whose data is read together with the company: the company and those
+    /// below it when the permission spans companies, only the company otherwise.
+    pub async fn get_reachable_company_ids(
         &self,
         requester: &User,
-        target_company_id: Option<Uuid>,
+        permission: &str,
+        company_id: Uuid,
     ) -> Result<Vec<Uuid>> {
-        let mut target_user_ids = Vec::new();
-
-        if requester.role.level >= 100 {
-            // Admin
-            if let Some(company_id) = target_company_id {
-                // Fetch all users of that company
-                let users: Vec<Uuid> = self
-                    .user_repo
-                    .get_by_company_id(company_id)
-                    .await?
-                    .iter()
-                    .map(|u| u.id)
-                    .collect();
-                target_user_ids.extend(users);
-            } else {
-                // For simplified compatibility, default to self
-                target_user_ids.push(requester.id);
+        match self.policy.scope(requester, permission).await? {
+            Some(PermissionScope::Any) | Some(PermissionScope::Company) => {
+                self.get_company_subtree_ids(company_id).await
             }
-        } else if requester.role.level >= 50 {
-            // Supervisor
-            if let Some(company) = &requester.company {
-                let users = self.user_repo.get_by_company_id(company.id).await?;
-                for user in users {
-                    target_user_ids.push(user.id);
-                }
-            } else {
-                // Supervisor without company (shouldn't happen but fallback)
-                target_user_ids.push(requester.id);
-            }
-        } else {
-            // Regular user
-            target_user_ids.push(requester.id);
+            _ => Ok(vec![company_id]),
+        }
+    }
+
+    /// The company followed by every company below it
+    pub async fn get_company_subtree_ids(&self, company_id: Uuid) -> Result<Vec<Uuid>> {
+        let mut ids = vec![company_id];
+        let mut level = vec![company_id];
+
+        while !level.is_empty() {
+            level = self
+                .company_repo
+                .get_by_parent_ids(level)
+                .await?
+                .into_iter()
+                .map(|c| c.id)
+                .filter(|id| !ids.contains(id))
+                .collect();
+            ids.extend(level.iter().copied());
         }
 
-        Ok(target_user_ids)
+        Ok(ids)
+    }
+
+    /// Users reached through the teams the requester supervises, themselves included.
+    /// `None` when the permission is not narrowed to teams.
+    pub async fn get_team_user_ids(
+        &self,
+        requester: &User,
+        permission: &str,
+    ) -> Result<Option<Vec<Uuid>>> {
+        let Some(team_ids) = self.get_supervised_team_ids(requester, permission).await? else {
+            return Ok(None);
+        };
+
+        let mut ids = vec![requester.id];
+        if !team_ids.is_empty() {
+            ids.extend(
+                self.team_repo
+                    .get_members(team_ids)
+                    .await?
+                    .into_iter()
+                    .map(|m| m.user_id),
+            );
+        }
+        ids.sort();
+        ids.dedup();
+
+        Ok(Some(ids))
+    }
+
+    /// Plots assigned to the teams the requester supervises.
+    /// `None` when the permission is not narrowed to teams.
+    pub async fn get_team_plot_ids(
+        &self,
+        requester: &User,
+        permission: &str,
+    ) -> Result<Option<Vec<Uuid>>> {
+        let Some(team_ids) = self.get_supervised_team_ids(requester, permission).await? else {
+            return Ok(None);
+        };
+
+        if team_ids.is_empty() {
+            return Ok(Some(vec![]));
+        }
+
+        Ok(Some(self.team_repo.get_plot_ids(team_ids).await?))
+    }
+
+    /// Teams supervised by the requester when the permission is granted with the `team`
+    /// scope. Companies without teams are not narrowed.
+    pub async fn get_supervised_team_ids(
+        &self,
+        requester: &User,
+        permission: &str,
+    ) -> Result<Option<Vec<Uuid>>> {
+        if self.policy.scope(requester, permission).await? != Some(PermissionScope::Team) {
+            return Ok(None);
+        }
+
+        let Some(company_id) = requester.company.as_ref().map(|c| c.id) else {
+            return Ok(Some(vec![]));
+        };
+
+        if self.team_repo.get_by_company_id(company_id).await?.is_empty() {
+            return Ok(None);
+        }
+
+        // Teams supervised in other companies under a membership do not count here
+        Ok(Some(
+            self.team_repo
+                .get_supervised_by(requester.id)
+                .await?
+                .into_iter()
+                .filter(|t| t.company_id == company_id)
+                .map(|t| t.id)
+                .collect(),
+        ))
     }
 
     /// Validates if a user has permission to manage resources for a specific company.
-    /// - Admin (>= 100): Allowed for any company.
-    /// - Supervisor (>= 50): Allowed ONLY for their own company.
-    /// - User (< 50): Forbidden.
-    pub fn validate_company_management_access(
+    pub async fn validate_company_management_access(
         &self,
         requester: &User,
+        permission: &str,
         target_company_id: Uuid,
     ) -> Result<()> {
-        let role_level = requester.role.level;
-
-        if role_level >= 100 {
-            // Admin allowed
-            Ok(())
-        } else if role_level >= 50 {
-            // Supervisor check
-            let user_company_id = requester.company.as_ref().map(|c| c.id);
-            if user_company_id == Some(target_company_id) {
-                Ok(())
-            } else {
-                Err(AppError::Forbidden)
-            }
-        } else {
-            // User forbidden
-            Err(AppError::Forbidden)
-        }
+        self.policy
+            .ensure(requester, permission, Resource::Company(target_company_id))
+            .await
     }
 }
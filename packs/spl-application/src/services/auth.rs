@@ -1,29 +1,74 @@
+use crate::dtos::auth::{
+    AuthTokensDto, ClientInfoDto, LoginResultDto, TwoFactorSetupDto, VerifyTwoFactorDto,
+};
 use crate::dtos::user::LoginDto;
-use spl_domain::ports::auth::{PasswordEncoder, TokenGenerator};
-use spl_domain::ports::repositories::user::UserRepository;
+use crate::services::login_lockout::LoginLockoutService;
+use crate::services::two_factor::TwoFactorService;
+use chrono::{Duration, Utc};
+use spl_domain::entities::auth::{RefreshToken, Session};
+use spl_domain::entities::user::{CompanyMembership, User};
+use spl_domain::ports::auth::{OpaqueTokenGenerator, PasswordEncoder, TokenGenerator};
+use spl_domain::ports::cache::UserCache;
+use spl_domain::ports::repositories::auth::{RefreshTokenRepository, SessionRepository};
+use spl_domain::ports::repositories::user::{MembershipRepository, UserRepository};
 use spl_shared::error::{AppError, Result};
 use std::sync::Arc;
+use tracing::warn;
+use uuid::Uuid;
+
+/// `last_seen_at` is written at most once per interval to avoid a write per request
+const LAST_SEEN_RESOLUTION_SECONDS: i64 = 60;
 
 pub struct AuthService {
     user_repo: Arc<dyn UserRepository>,
+    membership_repo: Arc<dyn MembershipRepository>,
+    session_repo: Arc<dyn SessionRepository>,
+    refresh_token_repo: Arc<dyn RefreshTokenRepository>,
     password_encoder: Arc<dyn PasswordEncoder>,
     token_generator: Arc<dyn TokenGenerator>,
+    opaque_token_generator: Arc<dyn OpaqueTokenGenerator>,
+    login_lockout_service: Arc<LoginLockoutService>,
+    two_factor_service: Arc<TwoFactorService>,
+    user_cache: Arc<dyn UserCache>,
+    access_token_ttl_seconds: i64,
+    refresh_token_ttl_days: i64,
 }
 
 impl AuthService {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         user_repo: Arc<dyn UserRepository>,
+        membership_repo: Arc<dyn MembershipRepository>,
+        session_repo: Arc<dyn SessionRepository>,
+        refresh_token_repo: Arc<dyn RefreshTokenRepository>,
         password_encoder: Arc<dyn PasswordEncoder>,
         token_generator: Arc<dyn TokenGenerator>,
+        opaque_token_generator: Arc<dyn OpaqueTokenGenerator>,
+        login_lockout_service: Arc<LoginLockoutService>,
+        two_factor_service: Arc<TwoFactorService>,
+        user_cache: Arc<dyn UserCache>,
+        access_token_ttl_seconds: i64,
+        refresh_token_ttl_days: i64,
     ) -> Self {
         Self {
             user_repo,
+            membership_repo,
+            session_repo,
+            refresh_token_repo,
             password_encoder,
             token_generator,
+            opaque_token_generator,
+            login_lockout_service,
+            two_factor_service,
+            user_cache,
+            access_token_ttl_seconds,
+            refresh_token_ttl_days,
         }
     }
 
-    pub async fn login(&self, dto: LoginDto) -> Result<String> {
+    /// Checks the password. Users with a second factor get a challenge to complete
+    /// with `verify_two_factor` instead of tokens.
+    pub async fn login(&self, dto: LoginDto, client: ClientInfoDto) -> Result<LoginResultDto> {
         // Validate that at least one of username or email is provided
         if dto.username.is_none() && dto.email.is_none() {
             return Err(AppError::ValidationError(
@@ -31,6 +76,7 @@ impl AuthService {
             ));
         }
 
+        let username = dto.username.clone();
         let user = self
             .user_repo
             .get_by_username_or_email_and_company(
@@ -41,22 +87,369 @@ impl AuthService {
             .await?
             .ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
 
+        self.login_lockout_service
+            .ensure_not_locked(user.id)
+            .await?;
+
         if !self
             .password_encoder
             .verify(&dto.password, &user.password_hash)?
         {
-            return Err(AppError::InvalidCredentials);
+            return match self.login_lockout_service.register_failure(user.id).await? {
+                Some(until) => Err(AppError::AccountLocked { until }),
+                None => Err(AppError::InvalidCredentials),
+            };
         }
 
-        // Payload with role
-        let claims = serde_json::json!({
-            "role": &user.role.name
-        });
+        Self::ensure_active(&user)?;
+
+        // Only verified addresses identify a user
+        let by_email = username.as_deref() != Some(user.username.as_str());
+        if by_email && !user.is_email_verified() {
+            return Err(AppError::AuthError(
+                "Email is not verified, log in with your username".to_string(),
+            ));
+        }
+
+        let user = self.upgrade_hash(user, &dto.password).await;
+
+        // Failed attempts are only forgotten once every factor has been verified
+        if let Some(challenge) = self.two_factor_service.create_challenge(&user).await? {
+            return Ok(LoginResultDto::TwoFactorRequired(challenge));
+        }
+
+        self.login_lockout_service.register_success(user.id).await?;
+
+        self.start_session(&user, client)
+            .await
+            .map(LoginResultDto::Authenticated)
+    }
+
+    /// Re-hashes the password when the hashing parameters were strengthened since it was set.
+    /// Failures are only logged, the login goes on with the old hash.
+    async fn upgrade_hash(&self, mut user: User, password: &str) -> User {
+        if !self.password_encoder.needs_rehash(&user.password_hash) {
+            return user;
+        }
 
-        self.token_generator.generate(&user.id.to_string(), claims)
+        let previous = user.clone();
+        let upgraded = match self.password_encoder.hash(password) {
+            Ok(hash) => {
+                user.password_hash = hash;
+                user.updated_at = Utc::now();
+                self.user_repo.update(user).await
+            }
+            Err(e) => Err(e),
+        };
+
+        match upgraded {
+            Ok(user) => {
+                self.user_cache.invalidate(user.id).await;
+                user
+            }
+            Err(e) => {
+                warn!(user_id = %previous.id, error = %e, "Failed to upgrade password hash");
+                previous
+            }
+        }
+    }
+
+    /// Completes a login with the TOTP or recovery code of the challenged user.
+    /// Wrong codes count as failed logins.
+    pub async fn verify_two_factor(
+        &self,
+        dto: VerifyTwoFactorDto,
+        client: ClientInfoDto,
+    ) -> Result<AuthTokensDto> {
+        let challenge = self
+            .two_factor_service
+            .get_challenge(&dto.challenge_token)
+            .await?;
+
+        self.login_lockout_service
+            .ensure_not_locked(challenge.user_id)
+            .await?;
+
+        let user = self
+            .user_repo
+            .get_by_id(challenge.user_id)
+            .await?
+            .ok_or_else(|| AppError::AuthError("Invalid two-factor challenge".to_string()))?;
+
+        if !self
+            .two_factor_service
+            .verify_login_code(&user, &dto.code)
+            .await?
+        {
+            return match self.login_lockout_service.register_failure(user.id).await? {
+                Some(until) => Err(AppError::AccountLocked { until }),
+                None => Err(AppError::AuthError("Invalid two-factor code".to_string())),
+            };
+        }
+
+        self.two_factor_service.consume_challenge(&challenge).await?;
+        self.login_lockout_service.register_success(user.id).await?;
+
+        self.start_session(&user, client).await
+    }
+
+    /// Enrollment for users whose role requires a second factor they do not have yet.
+    /// The challenge stays valid so the first code can be sent to `verify_two_factor`.
+    pub async fn setup_two_factor(&self, challenge_token: &str) -> Result<TwoFactorSetupDto> {
+        let challenge = self
+            .two_factor_service
+            .get_challenge(challenge_token)
+            .await?;
+
+        let user = self
+            .user_repo
+            .get_by_id(challenge.user_id)
+            .await?
+            .ok_or_else(|| AppError::AuthError("Invalid two-factor challenge".to_string()))?;
+
+        if !TwoFactorService::is_required(&user) {
+            return Err(AppError::Forbidden);
+        }
+
+        self.two_factor_service.start_enrollment(&user).await
+    }
+
+    /// Exchanges a refresh token for a new token pair. The presented token is consumed;
+    /// presenting it again revokes the whole session.
+    pub async fn refresh(
+        &self,
+        refresh_token: &str,
+        client: ClientInfoDto,
+    ) -> Result<AuthTokensDto> {
+        let token_hash = self.opaque_token_generator.hash(refresh_token);
+
+        let token = self
+            .refresh_token_repo
+            .get_by_token_hash(&token_hash)
+            .await?
+            .ok_or_else(|| AppError::AuthError("Invalid refresh token".to_string()))?;
+
+        let session = self
+            .session_repo
+            .get_by_id(token.session_id)
+            .await?
+            .ok_or_else(|| AppError::AuthError("Invalid refresh token".to_string()))?;
+
+        if !session.is_active() {
+            return Err(AppError::AuthError("Session is no longer active".to_string()));
+        }
+
+        if token.used_at.is_some() || !self.refresh_token_repo.mark_used(token.id).await? {
+            warn!(
+                session_id = %session.id,
+                user_id = %session.user_id,
+                "Refresh token reuse detected, revoking session"
+            );
+            self.session_repo.revoke(session.id).await?;
+            return Err(AppError::AuthError("Refresh token reuse detected".to_string()));
+        }
+
+        if token.expires_at <= Utc::now() {
+            return Err(AppError::AuthError("Refresh token expired".to_string()));
+        }
+
+        let user = self
+            .user_repo
+            .get_by_id(session.user_id)
+            .await?
+            .ok_or_else(|| AppError::AuthError("Invalid refresh token".to_string()))?;
+
+        self.session_repo
+            .touch(session.id, Utc::now(), client.ip_address)
+            .await?;
+
+        self.issue_tokens(&user, &session).await
+    }
+
+    /// Revokes the session (token family) the refresh token belongs to.
+    pub async fn logout(&self, refresh_token: &str) -> Result<()> {
+        let token_hash = self.opaque_token_generator.hash(refresh_token);
+
+        let token = self
+            .refresh_token_repo
+            .get_by_token_hash(&token_hash)
+            .await?
+            .ok_or_else(|| AppError::AuthError("Invalid refresh token".to_string()))?;
+
+        self.session_repo.revoke(token.session_id).await?;
+
+        Ok(())
+    }
+
+    /// Opens a session for a user authenticated by an external identity provider
+    pub async fn login_external(
+        &self,
+        user: &User,
+        client: ClientInfoDto,
+    ) -> Result<AuthTokensDto> {
+        self.login_lockout_service
+            .ensure_not_locked(user.id)
+            .await?;
+
+        self.start_session(user, client).await
+    }
+
+    /// Returns false for revoked or expired sessions. Use of an active session is
+    /// recorded as its `last_seen_at` and address.
+    pub async fn check_session(
+        &self,
+        session_id: Uuid,
+        ip_address: Option<String>,
+    ) -> Result<bool> {
+        let Some(session) = self.session_repo.get_by_id(session_id).await? else {
+            return Ok(false);
+        };
+
+        if !session.is_active() {
+            return Ok(false);
+        }
+
+        let now = Utc::now();
+        let stale = now - session.last_seen_at >= Duration::seconds(LAST_SEEN_RESOLUTION_SECONDS);
+
+        if stale {
+            self.session_repo.touch(session.id, now, ip_address).await?;
+        }
+
+        Ok(true)
+    }
+
+    /// Makes the company active on the session and issues tokens acting in it. Users act
+    /// in their own company or in the company of one of their memberships.
+    pub async fn switch_company(
+        &self,
+        user_id: Uuid,
+        session_id: Uuid,
+        company_id: Uuid,
+    ) -> Result<AuthTokensDto> {
+        let mut session = self
+            .session_repo
+            .get_by_id(session_id)
+            .await?
+            .filter(|s| s.user_id == user_id && s.is_active())
+            .ok_or_else(|| AppError::AuthError("Session is no longer active".to_string()))?;
+
+        let user = self
+            .user_repo
+            .get_by_id(user_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
+
+        session.active_company_id = if user.company.as_ref().map(|c| c.id) == Some(company_id) {
+            None
+        } else {
+            self.membership_repo
+                .get(user_id, company_id)
+                .await?
+                .ok_or_else(|| AppError::NotFound("Company membership not found".to_string()))?;
+            Some(company_id)
+        };
+
+        let session = self.session_repo.update(session).await?;
+
+        self.issue_tokens(&user, &session).await
     }
 
     pub fn validate_token(&self, token: &str) -> Result<serde_json::Value> {
         self.token_generator.validate(token)
     }
+
+    /// Public keys other services can use to verify our access tokens
+    pub fn jwks(&self) -> serde_json::Value {
+        self.token_generator.jwks()
+    }
+
+    async fn start_session(&self, user: &User, client: ClientInfoDto) -> Result<AuthTokensDto> {
+        let now = Utc::now();
+        let session = self
+            .session_repo
+            .create(Session {
+                id: Uuid::new_v4(),
+                user_id: user.id,
+                expires_at: now + Duration::days(self.refresh_token_ttl_days),
+                revoked_at: None,
+                user_agent: client.user_agent,
+                ip_address: client.ip_address,
+                active_company_id: None,
+                last_seen_at: now,
+                created_at: now,
+            })
+            .await?;
+
+        self.issue_tokens(user, &session).await
+    }
+
+    /// Deactivated users keep their data but cannot obtain tokens
+    fn ensure_active(user: &User) -> Result<()> {
+        if !user.is_active() {
+            return Err(AppError::AuthError("Account is deactivated".to_string()));
+        }
+
+        Ok(())
+    }
+
+    /// Membership of the company active on the session. Sessions whose membership was
+    /// removed go back to the company of the user.
+    async fn get_active_membership(
+        &self,
+        user: &User,
+        session: &Session,
+    ) -> Result<Option<CompanyMembership>> {
+        let Some(company_id) = session.active_company_id else {
+            return Ok(None);
+        };
+
+        let membership = self.membership_repo.get(user.id, company_id).await?;
+        if membership.is_none() {
+            let mut session = session.clone();
+            session.active_company_id = None;
+            self.session_repo.update(session).await?;
+        }
+
+        Ok(membership)
+    }
+
+    async fn issue_tokens(&self, user: &User, session: &Session) -> Result<AuthTokensDto> {
+        Self::ensure_active(user)?;
+
+        let refresh_token = self.opaque_token_generator.generate();
+        let now = Utc::now();
+
+        self.refresh_token_repo
+            .create(RefreshToken {
+                id: Uuid::new_v4(),
+                session_id: session.id,
+                token_hash: self.opaque_token_generator.hash(&refresh_token),
+                expires_at: session.expires_at,
+                used_at: None,
+                created_at: now,
+            })
+            .await?;
+
+        let active = self.get_active_membership(user, session).await?;
+        let role = active.as_ref().map_or(&user.role, |m| &m.role);
+
+        // Payload with role and the session the token belongs to
+        let mut claims = serde_json::json!({
+            "role": &role.name,
+            "sid": session.id.to_string(),
+            "jti": Uuid::new_v4().to_string(),
+        });
+        if let Some(membership) = &active {
+            claims["cid"] = serde_json::json!(membership.company.id.to_string());
+        }
+
+        let access_token = self.token_generator.generate(&user.id.to_string(), claims)?;
+
+        Ok(AuthTokensDto {
+            access_token,
+            refresh_token,
+            expires_in: self.access_token_ttl_seconds,
+        })
+    }
 }
@@ -1,8 +1,10 @@
 use crate::dtos::company::{CreateCompanyDto, UpdateCompanyDto};
 use crate::services::access_control::AccessControlService;
+use crate::services::policy::Resource;
 
+use chrono::Utc;
 use spl_domain::entities::company::Company;
-use spl_domain::entities::user::User;
+use spl_domain::entities::user::{permissions, User};
 use spl_domain::ports::repositories::company::CompanyRepository;
 use spl_shared::error::{AppError, Result};
 use spl_shared::traits::IntoWithContext;
@@ -26,9 +28,13 @@ impl CompanyService {
     }
 
     pub async fn create(&self, creator: &User, dto: CreateCompanyDto) -> Result<Company> {
-        let role_level = creator.role.level;
-        if role_level < 100 {
-            return Err(AppError::Forbidden);
+        self.access_control
+            .policy()
+            .ensure(creator, permissions::COMPANIES_MANAGE, Resource::Global)
+            .await?;
+
+        if let Some(parent_id) = dto.parent_id {
+            self.get_company(parent_id).await?;
         }
 
         self.company_repo.create(dto.into()).await
@@ -39,23 +45,24 @@ impl CompanyService {
     }
 
     pub async fn get_all(&self, requester: &User) -> Result<Vec<Company>> {
-        if requester.role.level < 100 {
-            return Err(AppError::Forbidden);
-        }
+        self.access_control
+            .policy()
+            .ensure(requester, permissions::COMPANIES_MANAGE, Resource::Global)
+            .await?;
         self.company_repo.get_all().await
     }
 
     pub async fn get_by_id(&self, requester: &User, id: Uuid) -> Result<Option<Company>> {
-        let role_level = requester.role.level;
-
-        if role_level < 100 {
-            let user_company_id = requester.company.as_ref().map(|c| c.id);
-            match user_company_id {
-                Some(cid) if cid == id => {
-                    // Allowed
-                }
-                _ => return Err(AppError::Forbidden),
-            }
+        // Users see their own company and those below it, managers the companies they manage
+        if !self.is_within_own_company(requester, id).await? {
+            self.access_control
+                .policy()
+                .ensure(
+                    requester,
+                    permissions::COMPANIES_MANAGE,
+                    Resource::Company(id),
+                )
+                .await?;
         }
 
         self.company_repo.get_by_id(id).await
@@ -68,7 +75,8 @@ impl CompanyService {
         dto: UpdateCompanyDto,
     ) -> Result<Company> {
         self.access_control
-            .validate_company_management_access(requester, id)?;
+            .validate_company_management_access(requester, permissions::COMPANIES_MANAGE, id)
+            .await?;
 
         let current = self
             .company_repo
@@ -81,10 +89,82 @@ impl CompanyService {
         self.company_repo.update(updated).await
     }
 
-    pub async fn delete(&self, requester: &User, id: Uuid) -> Result<Company> {
-        if requester.role.level < 100 {
-            return Err(AppError::Forbidden);
+    /// Companies right below the company, e.g. the member farms of a cooperative
+    pub async fn get_children(&self, requester: &User, id: Uuid) -> Result<Vec<Company>> {
+        if self.get_by_id(requester, id).await?.is_none() {
+            return Err(AppError::NotFound("Company not found".to_string()));
         }
+
+        self.company_repo.get_by_parent_ids(vec![id]).await
+    }
+
+    /// Moves the company below another one, or makes it a top company with `None`.
+    /// Companies cannot be moved below themselves or a company below them.
+    pub async fn set_parent(
+        &self,
+        requester: &User,
+        id: Uuid,
+        parent_id: Option<Uuid>,
+    ) -> Result<Company> {
+        self.access_control
+            .policy()
+            .ensure(requester, permissions::COMPANIES_MANAGE, Resource::Global)
+            .await?;
+
+        let current = self.get_company(id).await?;
+
+        if let Some(parent_id) = parent_id {
+            self.get_company(parent_id).await?;
+
+            let subtree = self.access_control.get_company_subtree_ids(id).await?;
+            if subtree.contains(&parent_id) {
+                return Err(AppError::ValidationError(
+                    "A company cannot be placed below itself or a company below it".to_string(),
+                ));
+            }
+        }
+
+        self.company_repo
+            .update(Company {
+                parent_id,
+                updated_at: Utc::now(),
+                ..current
+            })
+            .await
+    }
+
+    pub async fn delete(&self, requester: &User, id: Uuid) -> Result<Company> {
+        self.access_control
+            .policy()
+            .ensure(
+                requester,
+                permissions::COMPANIES_MANAGE,
+                Resource::Company(id),
+            )
+            .await?;
         self.company_repo.delete(id).await
     }
+
+    async fn get_company(&self, id: Uuid) -> Result<Company> {
+        self.company_repo
+            .get_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Company not found".to_string()))
+    }
+
+    /// Whether the company is the requester's own or one below it
+    async fn is_within_own_company(&self, requester: &User, id: Uuid) -> Result<bool> {
+        let Some(own_id) = requester.company.as_ref().map(|c| c.id) else {
+            return Ok(false);
+        };
+        if own_id == id {
+            return Ok(true);
+        }
+
+        Ok(self
+            .access_control
+            .get_company_subtree_ids(own_id)
+            .await?
+            .contains(&id))
+    }
 }
@@ -0,0 +1,188 @@
+use crate::dtos::company::UpdateCompanySettingsDto;
+use crate::mappers::company::two_factor_required_level;
+use crate::services::access_control::AccessControlService;
+use crate::services::policy::Resource;
+use chrono::Utc;
+use spl_domain::entities::company::{Company, CompanySettings, CompanySettingsOverride};
+use spl_domain::entities::image::ImageFormat;
+use spl_domain::entities::user::{permissions, User};
+use spl_domain::ports::cache::UserCache;
+use spl_domain::ports::repositories::company::{CompanyRepository, CompanySettingsRepository};
+use spl_shared::error::{AppError, Result};
+use std::sync::Arc;
+use tracing::info;
+use uuid::Uuid;
+
+/// Settings of each company over the server defaults. Other services ask it how the
+/// company of a user behaves.
+pub struct CompanySettingsService {
+    settings_repo: Arc<dyn CompanySettingsRepository>,
+    company_repo: Arc<dyn CompanyRepository>,
+    access_control: Arc<AccessControlService>,
+    user_cache: Arc<dyn UserCache>,
+    defaults: CompanySettings,
+}
+
+impl CompanySettingsService {
+    pub fn new(
+        settings_repo: Arc<dyn CompanySettingsRepository>,
+        company_repo: Arc<dyn CompanyRepository>,
+        access_control: Arc<AccessControlService>,
+        user_cache: Arc<dyn UserCache>,
+        defaults: CompanySettings,
+    ) -> Self {
+        Self {
+            settings_repo,
+            company_repo,
+            access_control,
+            user_cache,
+            defaults,
+        }
+    }
+
+    /// Settings the user follows, the server defaults for users without company
+    pub async fn for_user(&self, user: &User) -> Result<CompanySettings> {
+        let Some(company) = &user.company else {
+            return Ok(self.defaults.clone());
+        };
+
+        let overrides = self.settings_repo.get_by_id(company.id).await?;
+
+        Ok(self.defaults.for_company(company, overrides.as_ref()))
+    }
+
+    /// Members read the settings of their company, managers those of the companies they manage
+    pub async fn get(&self, requester: &User, company_id: Uuid) -> Result<CompanySettings> {
+        if requester.company.as_ref().map(|c| c.id) != Some(company_id) {
+            self.access_control
+                .policy()
+                .ensure(
+                    requester,
+                    permissions::SETTINGS_MANAGE,
+                    Resource::Company(company_id),
+                )
+                .await?;
+        }
+
+        let company = self.get_company(company_id).await?;
+        let overrides = self.settings_repo.get_by_id(company_id).await?;
+
+        Ok(self.defaults.for_company(&company, overrides.as_ref()))
+    }
+
+    /// Replaces the settings of a company
+    pub async fn update(
+        &self,
+        requester: &User,
+        company_id: Uuid,
+        dto: UpdateCompanySettingsDto,
+    ) -> Result<CompanySettings> {
+        self.ensure_can_manage(requester, company_id).await?;
+
+        let company = self.get_company(company_id).await?;
+
+        let allowed_upload_formats = match dto.allowed_upload_formats {
+            Some(names) => Some(parse_formats(&names)?),
+            None => None,
+        };
+
+        let required_level = two_factor_required_level(dto.two_factor_required_level.unwrap_or(0))?;
+
+        let existing = self.settings_repo.get_by_id(company_id).await?;
+        let now = Utc::now();
+
+        let overrides = CompanySettingsOverride {
+            company_id,
+            timezone: dto.timezone,
+            default_language: dto.default_language,
+            data_retention_days: dto.data_retention_days,
+            default_page_size: dto.default_page_size,
+            allowed_upload_formats,
+            alert_severity_threshold: dto.alert_severity_threshold,
+            alert_confidence_threshold: dto.alert_confidence_threshold,
+            created_at: existing.as_ref().map(|s| s.created_at).unwrap_or(now),
+            updated_at: now,
+        };
+
+        let overrides = match existing {
+            Some(_) => self.settings_repo.update(overrides).await?,
+            None => self.settings_repo.create(overrides).await?,
+        };
+
+        let company = self.set_two_factor_level(company, required_level).await?;
+
+        info!(company_id = %company_id, updated_by = %requester.id, "Company settings updated");
+
+        Ok(self.defaults.for_company(&company, Some(&overrides)))
+    }
+
+    /// Puts every setting of the company back to the server defaults
+    pub async fn reset(&self, requester: &User, company_id: Uuid) -> Result<CompanySettings> {
+        self.ensure_can_manage(requester, company_id).await?;
+
+        let company = self.get_company(company_id).await?;
+
+        if self.settings_repo.get_by_id(company_id).await?.is_some() {
+            self.settings_repo.delete(company_id).await?;
+        }
+
+        let company = self.set_two_factor_level(company, None).await?;
+
+        info!(company_id = %company_id, reset_by = %requester.id, "Company settings reset");
+
+        Ok(self.defaults.for_company(&company, None))
+    }
+
+    /// The requirement is kept on the company, which users carry around
+    async fn set_two_factor_level(&self, company: Company, level: Option<i16>) -> Result<Company> {
+        if company.two_factor_required_level == level {
+            return Ok(company);
+        }
+
+        let company = self
+            .company_repo
+            .update(Company {
+                two_factor_required_level: level,
+                updated_at: Utc::now(),
+                ..company
+            })
+            .await?;
+
+        // Cached users still carry the previous requirement
+        self.user_cache.clear().await;
+
+        Ok(company)
+    }
+
+    async fn get_company(&self, company_id: Uuid) -> Result<Company> {
+        self.company_repo
+            .get_by_id(company_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Company not found".to_string()))
+    }
+
+    async fn ensure_can_manage(&self, requester: &User, company_id: Uuid) -> Result<()> {
+        self.access_control
+            .validate_company_management_access(requester, permissions::SETTINGS_MANAGE, company_id)
+            .await
+    }
+}
+
+fn parse_formats(names: &[String]) -> Result<Vec<ImageFormat>> {
+    if names.is_empty() {
+        return Err(AppError::ValidationError(
+            "At least one upload format must be allowed".to_string(),
+        ));
+    }
+
+    let mut formats = Vec::new();
+    for name in names {
+        let format = ImageFormat::parse(&name.to_lowercase())
+            .ok_or_else(|| AppError::ValidationError(format!("Unknown upload format: {}", name)))?;
+        if !formats.contains(&format) {
+            formats.push(format);
+        }
+    }
+
+    Ok(formats)
+}
@@ -1,16 +1,17 @@
 use crate::dtos::dashboard::{
     DashboardCountsDto, DashboardFiltersDto, DashboardSummaryDto, DashboardSummaryPlotDto,
 };
+use crate::services::access_control::AccessControlService;
 use spl_domain::entities::dashboard::{
     DashboardCounts, DashboardDetailedPlot, DashboardSummary, DashboardSummaryFilters,
 };
-use spl_domain::entities::user::User;
+use spl_domain::entities::user::{permissions, PermissionScope, User};
 use spl_domain::ports::repositories::dashboard::DashboardSummaryRepository;
 use spl_domain::ports::repositories::diagnostics::LabelRepository;
 use spl_domain::ports::repositories::plot::PlotRepository;
-use spl_domain::ports::repositories::user::UserRepository;
+use spl_domain::ports::repositories::user::{MembershipRepository, UserRepository};
 use spl_shared::error::{AppError, Result};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use uuid::Uuid;
 
@@ -19,6 +20,8 @@ pub struct DashboardService {
     label_repository: Arc<dyn LabelRepository>,
     plot_repository: Arc<dyn PlotRepository>,
     user_repository: Arc<dyn UserRepository>,
+    membership_repository: Arc<dyn MembershipRepository>,
+    access_control: Arc<AccessControlService>,
 }
 
 impl DashboardService {
@@ -27,104 +30,198 @@ impl DashboardService {
         label_repository: Arc<dyn LabelRepository>,
         plot_repository: Arc<dyn PlotRepository>,
         user_repository: Arc<dyn UserRepository>,
+        membership_repository: Arc<dyn MembershipRepository>,
+        access_control: Arc<AccessControlService>,
     ) -> Self {
         Self {
             dashboard_repository,
             label_repository,
             plot_repository,
             user_repository,
+            membership_repository,
+            access_control,
         }
     }
 
-    /// Get available filters for dashboard (labels and plots)
+    /// Whether the requester reads predictions of every company
+    async fn reads_any_company(&self, requester: &User) -> Result<bool> {
+        let scope = self
+            .access_control
+            .policy()
+            .scope(requester, permissions::PREDICTIONS_READ)
+            .await?;
+
+        Ok(scope == Some(PermissionScope::Any))
+    }
+
+    /// Users and plots of the teams the requester supervises, `None` when not narrowed to teams
+    async fn get_team_reach(&self, requester: &User) -> Result<Option<(Vec<Uuid>, Vec<Uuid>)>> {
+        let (user_ids, plot_ids) = tokio::try_join!(
+            self.access_control
+                .get_team_user_ids(requester, permissions::PREDICTIONS_READ),
+            self.access_control
+                .get_team_plot_ids(requester, permissions::PREDICTIONS_READ),
+        )?;
+
+        Ok(user_ids.zip(plot_ids))
+    }
+
+    /// Companies covered by the dashboard: the requested company, the requester's own by
+    /// default, and the companies below it
+    async fn get_company_ids(
+        &self,
+        requester: &User,
+        company_id: Option<Uuid>,
+    ) -> Result<Vec<Uuid>> {
+        let company_id = if self.reads_any_company(requester).await? {
+            company_id.ok_or_else(|| {
+                AppError::ValidationError("Company ID is required for admin users".into())
+            })?
+        } else {
+            if requester.company.is_none() {
+                return Err(AppError::Forbidden);
+            }
+            self.access_control
+                .validate_company_read_access(requester, permissions::PREDICTIONS_READ, company_id)
+                .await?
+        };
+
+        self.access_control
+            .get_reachable_company_ids(requester, permissions::PREDICTIONS_READ, company_id)
+            .await
+    }
+
+    /// Get available filters for dashboard (labels, plots, users and model versions)
     pub async fn get_filters(
         &self,
         requester: User,
         dto: DashboardFiltersDto,
     ) -> Result<DashboardSummaryFilters> {
-        let company_id;
-        // Admin users must provides company
-        if requester.role.level >= 100 {
-            company_id = dto.company_id.ok_or_else(|| {
-                AppError::ValidationError("Company ID is required for admin users".into())
-            })?;
-        } else if let Some(company) = requester.company {
-            if let Some(provided_id) = dto.company_id {
-                if company.id != provided_id {
-                    return Err(AppError::Forbidden);
-                }
-            }
-            company_id = company.id
-        } else {
-            return Err(AppError::Forbidden);
+        // Users reading every company must provide one
+        let company_ids = self.get_company_ids(&requester, dto.company_id).await?;
+
+        let labels = self.label_repository.get_all().await?;
+        let mut plots = vec![];
+        for company_id in &company_ids {
+            plots.extend(
+                self.plot_repository
+                    .get_all_by_company_id(*company_id)
+                    .await?,
+            );
         }
+        let mut users = self.get_company_users(&company_ids).await?;
 
-        let (labels, plots, users) = tokio::try_join!(
-            self.label_repository.get_all(),
-            self.plot_repository.get_all_by_company_id(company_id),
-            self.user_repository.get_by_company_id(company_id),
-        )?;
+        // Every company lists the default plot, it is kept once
+        let mut has_default = false;
+        plots.retain(|p| !p.id.is_nil() || !std::mem::replace(&mut has_default, true));
+
+        let team_reach = self.get_team_reach(&requester).await?;
+        if let Some((user_ids, plot_ids)) = &team_reach {
+            users.retain(|u| user_ids.contains(&u.id));
+            // The default plot only holds predictions of users within reach
+            plots.retain(|p| p.id.is_nil() || plot_ids.contains(&p.id));
+        }
+
+        let model_versions = self
+            .dashboard_repository
+            .get_model_versions(company_ids, team_reach.map(|(user_ids, _)| user_ids))
+            .await?;
 
         Ok(DashboardSummaryFilters {
             labels,
             plots,
             users,
+            model_versions,
         })
     }
 
+    /// Users acting in the companies: their own and the members from other companies
+    async fn get_company_users(&self, company_ids: &[Uuid]) -> Result<Vec<User>> {
+        let mut users = vec![];
+        let mut member_ids = HashSet::new();
+        for company_id in company_ids {
+            let (company_users, memberships) = tokio::try_join!(
+                self.user_repository.get_by_company_id(*company_id),
+                self.membership_repository.get_by_company_id(*company_id),
+            )?;
+            users.extend(company_users);
+            member_ids.extend(memberships.into_iter().map(|m| m.user_id));
+        }
+
+        // Members of a company below another one may be users of the one above
+        for user in &users {
+            member_ids.remove(&user.id);
+        }
+        if !member_ids.is_empty() {
+            users.extend(
+                self.user_repository
+                    .get_by_ids(member_ids.into_iter().collect())
+                    .await?,
+            );
+        }
+
+        Ok(users)
+    }
+
+    /// Users whose predictions are summarized, `None` for every user of the companies
     async fn get_allowed_user_ids(
         &self,
         requester: &User,
+        company_ids: &[Uuid],
         ids: &Option<Vec<Uuid>>,
-    ) -> Result<Vec<Uuid>> {
-        let company = requester
-            .company
-            .clone()
-            .ok_or_else(|| AppError::Forbidden)?;
-
-        let allowed_ids = self
-            .user_repository
-            .get_by_company_id(company.id)
-            .await?
-            .into_iter()
-            .map(|u| (u.id, u.id))
-            .collect::<HashMap<_, _>>();
+    ) -> Result<Option<Vec<Uuid>>> {
+        let team_user_ids = self
+            .access_control
+            .get_team_user_ids(requester, permissions::PREDICTIONS_READ)
+            .await?;
 
         let ids = ids.clone().unwrap_or_default();
-
-        if ids.len() > allowed_ids.len() {
-            return Err(AppError::Forbidden);
+        if ids.is_empty() {
+            return Ok(team_user_ids);
         }
 
-        if !ids.iter().all(|id| allowed_ids.contains_key(id)) {
-            return Err(AppError::Forbidden);
-        }
+        let allowed_ids: HashSet<Uuid> = self
+            .get_company_users(company_ids)
+            .await?
+            .into_iter()
+            .filter(|u| team_user_ids.as_ref().is_none_or(|ids| ids.contains(&u.id)))
+            .map(|u| u.id)
+            .collect();
 
-        if ids.is_empty() {
-            return Ok(allowed_ids.into_iter().map(|e| e.0).collect());
+        if !ids.iter().all(|id| allowed_ids.contains(id)) {
+            return Err(AppError::Forbidden);
         }
 
-        Ok(ids)
+        Ok(Some(ids))
     }
 
     async fn get_allowed_plot_ids(
         &self,
         requester: &User,
+        company_ids: &[Uuid],
         ids: &Option<Vec<Option<Uuid>>>,
     ) -> Result<Vec<Option<Uuid>>> {
         // For non-admin users, we also need to check if they have access to the requested plot IDs (if any)
-        let company = requester
-            .company
-            .clone()
-            .ok_or_else(|| AppError::Forbidden)?;
-
-        let allowed_ids = self
-            .plot_repository
-            .get_all_by_company_id(company.id)
-            .await?
-            .into_iter()
-            .map(|p| (p.id, p.id))
-            .collect::<HashMap<_, _>>();
+        let team_plot_ids = self
+            .access_control
+            .get_team_plot_ids(requester, permissions::PREDICTIONS_READ)
+            .await?;
+
+        let mut allowed_ids = HashMap::new();
+        for company_id in company_ids {
+            allowed_ids.extend(
+                self.plot_repository
+                    .get_all_by_company_id(*company_id)
+                    .await?
+                    .into_iter()
+                    .filter(|p| {
+                        team_plot_ids
+                            .as_ref()
+                            .is_none_or(|ids| p.id.is_nil() || ids.contains(&p.id))
+                    })
+                    .map(|p| (p.id, p.id)),
+            );
+        }
 
         if let Some(ids) = ids.clone() {
             if ids.len() > allowed_ids.len() {
@@ -152,42 +249,53 @@ impl DashboardService {
         Ok(ids)
     }
 
+    /// Companies, users and plots the requested summary covers. Predictions belong to the
+    /// company they were made in, whatever the company of their user.
     async fn validate_ids(
         &self,
         requester: &User,
+        company_id: Option<Uuid>,
         user_ids: &Option<Vec<Uuid>>,
         plot_ids: &Option<Vec<Option<Uuid>>>,
-    ) -> Result<(Vec<Uuid>, Vec<Option<Uuid>>)> {
+    ) -> Result<(Vec<Uuid>, Option<Vec<Uuid>>, Vec<Option<Uuid>>)> {
+        let mut company_ids: Vec<Uuid> = vec![];
         let mut plots_ids: Vec<Option<Uuid>> = vec![];
-        let mut users_ids: Vec<Uuid> = vec![];
+        let mut users_ids: Option<Vec<Uuid>> = None;
 
-        if requester.role.level < 100 {
-            // For non-admin users, we need to check if they have access to the requested user IDs (if any)
+        if !self.reads_any_company(requester).await? {
+            // For users of a single company, we need to check if they have access to the requested user IDs (if any)
+            company_ids = self.get_company_ids(requester, company_id).await?;
             (users_ids, plots_ids) = tokio::try_join!(
-                self.get_allowed_user_ids(&requester, user_ids),
-                self.get_allowed_plot_ids(&requester, plot_ids)
+                self.get_allowed_user_ids(requester, &company_ids, user_ids),
+                self.get_allowed_plot_ids(requester, &company_ids, plot_ids)
             )?;
         }
 
-        Ok((users_ids, plots_ids))
+        Ok((company_ids, users_ids, plots_ids))
     }
 
-    /// Resolve company_id from requester and optional dto company_id (admin only)
+    /// Resolve company_id from requester and optional dto company_id
     async fn resolve_company_id(
         &self,
         requester: &User,
         dto: &DashboardSummaryPlotDto,
     ) -> Result<Uuid> {
-        if requester.role.level >= 100 {
+        if self.reads_any_company(requester).await? {
             dto.company_id.ok_or_else(|| {
                 AppError::ValidationError("Company ID is required for admin users".into())
             })
         } else {
-            requester
-                .company
-                .clone()
-                .ok_or_else(|| AppError::Forbidden)
-                .map(|c| c.id)
+            if requester.company.is_none() {
+                return Err(AppError::Forbidden);
+            }
+            // Users of a company may look into the companies below it
+            self.access_control
+                .validate_company_read_access(
+                    requester,
+                    permissions::PREDICTIONS_READ,
+                    dto.company_id,
+                )
+                .await
         }
     }
 
@@ -197,11 +305,19 @@ impl DashboardService {
         requester: User,
         dto: DashboardSummaryDto,
     ) -> Result<DashboardSummary> {
-        let (users_ids, plots_ids) = self
-            .validate_ids(&requester, &dto.users_ids, &dto.plot_ids)
+        let (company_ids, users_ids, plots_ids) = self
+            .validate_ids(&requester, None, &dto.users_ids, &dto.plot_ids)
             .await?;
         self.dashboard_repository
-            .get_summary(users_ids, dto.min_date, dto.max_date, plots_ids, dto.labels)
+            .get_summary(
+                company_ids,
+                users_ids,
+                dto.min_date,
+                dto.max_date,
+                plots_ids,
+                dto.labels,
+                dto.model_versions,
+            )
             .await
     }
 
@@ -211,17 +327,19 @@ impl DashboardService {
         requester: User,
         dto: DashboardCountsDto,
     ) -> Result<DashboardCounts> {
-        let (users_ids, plots_ids) = self
-            .validate_ids(&requester, &dto.users_ids, &dto.plot_ids)
+        let (company_ids, users_ids, plots_ids) = self
+            .validate_ids(&requester, None, &dto.users_ids, &dto.plot_ids)
             .await?;
 
         self.dashboard_repository
             .get_counts(
+                company_ids,
                 users_ids,
                 dto.min_date,
                 dto.max_date,
                 plots_ids,
                 dto.labels,
+                dto.model_versions,
                 dto.last_n,
             )
             .await
@@ -235,18 +353,25 @@ impl DashboardService {
         dto: DashboardSummaryPlotDto,
     ) -> Result<Option<DashboardDetailedPlot>> {
         let company_id = self.resolve_company_id(&requester, &dto).await?;
-        let (users_ids, _) = self
-            .validate_ids(&requester, &dto.users_ids, &Some(vec![Some(plot_id)]))
+        let (company_ids, users_ids, _) = self
+            .validate_ids(
+                &requester,
+                Some(company_id),
+                &dto.users_ids,
+                &Some(vec![Some(plot_id)]),
+            )
             .await?;
 
         self.dashboard_repository
             .get_summary_detailed_plot_by_id(
                 company_id,
                 plot_id,
+                company_ids,
                 users_ids,
                 dto.min_date,
                 dto.max_date,
                 dto.labels,
+                dto.model_versions,
             )
             .await
     }
@@ -258,16 +383,20 @@ impl DashboardService {
         dto: DashboardSummaryPlotDto,
     ) -> Result<Option<DashboardDetailedPlot>> {
         let company_id = self.resolve_company_id(&requester, &dto).await?;
-        let (users_ids, plots_ids) = self.validate_ids(&requester, &dto.users_ids, &None).await?;
+        let (company_ids, users_ids, plots_ids) = self
+            .validate_ids(&requester, Some(company_id), &dto.users_ids, &None)
+            .await?;
 
         self.dashboard_repository
             .get_default_summary_detailed_plot(
                 company_id,
+                company_ids,
                 users_ids,
                 dto.min_date,
                 dto.max_date,
                 plots_ids,
                 dto.labels,
+                dto.model_versions,
             )
             .await
     }
@@ -282,12 +411,20 @@ impl DashboardService {
                 "No content available for empty plot_ids".to_string(),
             ));
         }
-        let (users_ids, plots_ids) = self
-            .validate_ids(&requester, &dto.users_ids, &dto.plot_ids)
+        let (company_ids, users_ids, plots_ids) = self
+            .validate_ids(&requester, None, &dto.users_ids, &dto.plot_ids)
             .await?;
 
         self.dashboard_repository
-            .get_compare(users_ids, dto.min_date, dto.max_date, plots_ids, dto.labels)
+            .get_compare(
+                company_ids,
+                users_ids,
+                dto.min_date,
+                dto.max_date,
+                plots_ids,
+                dto.labels,
+                dto.model_versions,
+            )
             .await
     }
 }
@@ -0,0 +1,419 @@
+use crate::dtos::diagnostics::UploadedFileDto;
+use crate::services::diagnostics::PredictionService;
+use crate::services::user::MembershipService;
+use bytes::Bytes;
+use chrono::Utc;
+use spl_domain::entities::diagnostics::{
+    BatchItemStatus, InferenceJob, JobPolicy, JobStatus, Prediction, PredictionBatchItem,
+};
+use spl_domain::entities::user::User;
+use spl_domain::ports::integrations::BlobStorageClient;
+use spl_domain::ports::repositories::diagnostics::{
+    InferenceJobRepository, PredictionBatchItemRepository, PredictionBatchRepository,
+};
+use spl_domain::ports::repositories::user::UserRepository;
+use spl_shared::error::{AppError, Result};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{broadcast, Notify};
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+/// Predicts uploads outside of the request that sent them. Uploads are stored and
+/// queued in the database, then picked up by a pool of workers in each server.
+/// Failures of the model or the storage are retried with a growing delay; jobs
+/// that run out of attempts are dead-lettered and keep their upload to be retried
+/// by hand. Images that cannot be predicted at all fail on the first attempt.
+/// Jobs are predicted in the company the user was acting in when they were queued.
+pub struct InferenceJobService {
+    job_repo: Arc<dyn InferenceJobRepository>,
+    batch_repo: Arc<dyn PredictionBatchRepository>,
+    item_repo: Arc<dyn PredictionBatchItemRepository>,
+    user_repo: Arc<dyn UserRepository>,
+    prediction_service: Arc<PredictionService>,
+    membership_service: Arc<MembershipService>,
+    storage_client: Arc<dyn BlobStorageClient>,
+    policy: JobPolicy,
+    /// Wakes idle workers when jobs are queued by this server
+    wake: Notify,
+    /// Jobs as they change, for clients waiting on them
+    events: broadcast::Sender<InferenceJob>,
+}
+
+impl InferenceJobService {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        job_repo: Arc<dyn InferenceJobRepository>,
+        batch_repo: Arc<dyn PredictionBatchRepository>,
+        item_repo: Arc<dyn PredictionBatchItemRepository>,
+        user_repo: Arc<dyn UserRepository>,
+        prediction_service: Arc<PredictionService>,
+        membership_service: Arc<MembershipService>,
+        storage_client: Arc<dyn BlobStorageClient>,
+        policy: JobPolicy,
+    ) -> Self {
+        let (events, _) = broadcast::channel(256);
+
+        Self {
+            job_repo,
+            batch_repo,
+            item_repo,
+            user_repo,
+            prediction_service,
+            membership_service,
+            storage_client,
+            policy,
+            wake: Notify::new(),
+            events,
+        }
+    }
+
+    /// Stores the image and queues its prediction
+    pub async fn enqueue(&self, requester: &User, file: UploadedFileDto) -> Result<InferenceJob> {
+        if file.content.is_empty() {
+            return Err(AppError::ValidationError("No file provided".to_string()));
+        }
+
+        let path = format!("{}/jobs/{}", requester.id, Uuid::new_v4());
+        self.storage_client
+            .upload(Bytes::from(file.content), &path)
+            .await?;
+
+        let filename = file.filename.unwrap_or_else(|| Uuid::new_v4().to_string());
+        let job = InferenceJob::queued(
+            requester.id,
+            company_id(requester),
+            None,
+            filename,
+            path.clone(),
+        );
+
+        let job = match self.job_repo.create(job).await {
+            Ok(job) => job,
+            Err(e) => {
+                self.delete_upload(&path).await;
+                return Err(e);
+            }
+        };
+
+        info!(job_id = %job.id, user_id = %requester.id, "Inference job queued");
+
+        self.wake.notify_waiters();
+        self.publish(&job);
+
+        Ok(job)
+    }
+
+    /// Queues the prediction of the stored images of a batch
+    pub async fn enqueue_batch(
+        &self,
+        requester: &User,
+        items: &[PredictionBatchItem],
+    ) -> Result<()> {
+        let jobs = items
+            .iter()
+            .filter_map(|item| {
+                let path = item.upload_path.clone()?;
+                Some(InferenceJob::queued(
+                    requester.id,
+                    company_id(requester),
+                    Some(item.id),
+                    item.filename.clone(),
+                    path,
+                ))
+            })
+            .collect();
+
+        self.job_repo.create_many(jobs).await?;
+        self.wake.notify_waiters();
+
+        Ok(())
+    }
+
+    pub async fn get(&self, requester: &User, id: Uuid) -> Result<InferenceJob> {
+        self.job_repo
+            .get_by_id(id)
+            .await?
+            .filter(|job| job.user_id == requester.id && job.company_id == company_id(requester))
+            .ok_or_else(|| AppError::NotFound("Job not found".to_string()))
+    }
+
+    /// Jobs of the requester in the company they are acting in, most recent first
+    pub async fn get_by_user(
+        &self,
+        requester: &User,
+        status: Option<JobStatus>,
+    ) -> Result<Vec<InferenceJob>> {
+        let mut jobs = self.job_repo.get_by_user_id(requester.id, status).await?;
+        jobs.retain(|job| job.company_id == company_id(requester));
+
+        Ok(jobs)
+    }
+
+    /// Queues a dead-lettered job again with a fresh set of attempts
+    pub async fn retry(&self, requester: &User, id: Uuid) -> Result<InferenceJob> {
+        let mut job = self.get(requester, id).await?;
+
+        if job.status != JobStatus::Dead {
+            return Err(AppError::Conflict(format!(
+                "Job cannot be retried while {}",
+                job.status.as_str()
+            )));
+        }
+
+        let now = Utc::now();
+        job.status = JobStatus::Queued;
+        job.attempts = 0;
+        job.error = None;
+        job.run_after = now;
+        job.locked_until = None;
+        job.updated_at = now;
+        job.completed_at = None;
+        let job = self.job_repo.update(job).await?;
+
+        self.sync_batch_item(&job).await?;
+
+        info!(job_id = %job.id, user_id = %requester.id, "Inference job retried");
+
+        self.wake.notify_waiters();
+        self.publish(&job);
+
+        Ok(job)
+    }
+
+    /// Changes of every job handled by this server, from now on
+    pub fn subscribe(&self) -> broadcast::Receiver<InferenceJob> {
+        self.events.subscribe()
+    }
+
+    /// Starts the workers of this server. Idle workers are woken by new jobs and
+    /// otherwise look for jobs queued by other servers every `poll_interval`.
+    pub fn start_workers(self: &Arc<Self>, count: usize, poll_interval: Duration) {
+        for worker in 0..count {
+            let service = self.clone();
+            tokio::spawn(async move { service.work(worker, poll_interval).await });
+        }
+
+        info!(workers = count, "Inference workers started");
+    }
+
+    /// Runs the next job due, returns false when there is none
+    pub async fn run_next(&self) -> Result<bool> {
+        let now = Utc::now();
+        let Some(mut job) = self
+            .job_repo
+            .claim_next(now, now + self.policy.lease())
+            .await?
+        else {
+            return Ok(false);
+        };
+
+        self.publish(&job);
+
+        // The prediction is stored under an id saved on the job beforehand, a run whose
+        // outcome was not saved left it behind and is not predicted again
+        let prediction_id = match job.prediction_id {
+            Some(prediction_id) => {
+                if self
+                    .prediction_service
+                    .get_by_id(prediction_id)
+                    .await?
+                    .is_some()
+                {
+                    job.error = None;
+                    self.finish(job, JobStatus::Completed).await?;
+                    return Ok(true);
+                }
+                prediction_id
+            }
+            None => {
+                let prediction_id = Uuid::new_v4();
+                job.prediction_id = Some(prediction_id);
+                job = self.job_repo.update(job).await?;
+                prediction_id
+            }
+        };
+
+        // Only the lease of a worker that stopped mid-job gets a job this far
+        if job.attempts > self.policy.max_attempts {
+            job.error = Some("The worker stopped before finishing the prediction".to_string());
+            self.finish(job, JobStatus::Dead).await?;
+            return Ok(true);
+        }
+
+        match self.predict(&job, prediction_id).await {
+            Ok(_) => {
+                job.error = None;
+                self.finish(job, JobStatus::Completed).await?;
+            }
+            Err(e) if is_transient(&e) && job.attempts < self.policy.max_attempts => {
+                warn!(job_id = %job.id, attempt = job.attempts, "Inference job will be retried: {}", e);
+
+                let now = Utc::now();
+                job.status = JobStatus::Queued;
+                job.error = Some(e.to_string());
+                job.run_after = now + self.policy.retry_delay(job.attempts);
+                job.locked_until = None;
+                job.updated_at = now;
+                let job = self.job_repo.update(job).await?;
+                self.publish(&job);
+            }
+            Err(e) => {
+                let status = if is_transient(&e) {
+                    JobStatus::Dead
+                } else {
+                    JobStatus::Failed
+                };
+                warn!(job_id = %job.id, status = status.as_str(), "Inference job failed: {}", e);
+
+                job.error = Some(e.to_string());
+                self.finish(job, status).await?;
+            }
+        }
+
+        Ok(true)
+    }
+
+    async fn work(&self, worker: usize, poll_interval: Duration) {
+        loop {
+            match self.run_next().await {
+                Ok(true) => continue,
+                Ok(false) => {}
+                Err(e) => error!(worker, "Inference worker failed: {}", e),
+            }
+
+            let _ = tokio::time::timeout(poll_interval, self.wake.notified()).await;
+        }
+    }
+
+    async fn predict(&self, job: &InferenceJob, prediction_id: Uuid) -> Result<Prediction> {
+        let path = job
+            .upload_path
+            .as_deref()
+            .ok_or_else(|| AppError::NotFound("Uploaded image is missing".to_string()))?;
+
+        let user = self.get_uploader(job).await?;
+        let content = self.storage_client.download(path).await?;
+
+        self.prediction_service
+            .predict_and_create_with_id(prediction_id, user, content.to_vec(), job.filename.clone())
+            .await
+    }
+
+    /// User who queued the job, acting in the company they queued it in. Fails once
+    /// they no longer work for it.
+    async fn get_uploader(&self, job: &InferenceJob) -> Result<User> {
+        let user = self
+            .user_repo
+            .get_by_id(job.user_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("User {} not found", job.user_id)))?;
+
+        match job.company_id {
+            Some(company_id) => self.membership_service.resolve(user, company_id).await,
+            None if user.company.is_none() => Ok(user),
+            None => Err(AppError::Forbidden),
+        }
+    }
+
+    /// Saves the outcome of the job. Dead jobs keep their upload to be retried.
+    async fn finish(&self, mut job: InferenceJob, status: JobStatus) -> Result<()> {
+        let now = Utc::now();
+        let upload = match status {
+            JobStatus::Dead => None,
+            _ => job.upload_path.take(),
+        };
+        // Only completed jobs have the prediction stored under their id
+        if status != JobStatus::Completed {
+            job.prediction_id = None;
+        }
+
+        job.status = status;
+        job.locked_until = None;
+        job.updated_at = now;
+        job.completed_at = Some(now);
+        let job = self.job_repo.update(job).await?;
+
+        // Deleted once the job no longer points to it, a retry never finds it missing
+        if let Some(path) = upload {
+            self.delete_upload(&path).await;
+        }
+
+        self.sync_batch_item(&job).await?;
+
+        info!(
+            job_id = %job.id,
+            status = job.status.as_str(),
+            attempts = job.attempts,
+            "Inference job finished"
+        );
+
+        self.publish(&job);
+
+        Ok(())
+    }
+
+    /// Copies the state of the job to the batch image it predicts
+    async fn sync_batch_item(&self, job: &InferenceJob) -> Result<()> {
+        let Some(item_id) = job.batch_item_id else {
+            return Ok(());
+        };
+
+        let Some(mut item) = self.item_repo.get_by_id(item_id).await? else {
+            return Ok(());
+        };
+
+        item.status = match job.status {
+            JobStatus::Queued | JobStatus::Running => BatchItemStatus::Pending,
+            JobStatus::Completed => BatchItemStatus::Completed,
+            JobStatus::Failed | JobStatus::Dead => BatchItemStatus::Failed,
+        };
+        item.prediction_id = job.prediction_id;
+        item.error = job.error.clone();
+        item.upload_path = job.upload_path.clone();
+        item.updated_at = Utc::now();
+        let item = self.item_repo.update(item).await?;
+
+        let batch = self.batch_repo.refresh_progress(item.batch_id).await?;
+        if batch.items_pending() == 0 {
+            info!(
+                batch_id = %batch.id,
+                completed = batch.items_completed,
+                failed = batch.items_failed,
+                "Prediction batch completed"
+            );
+        }
+
+        Ok(())
+    }
+
+    async fn delete_upload(&self, path: &str) {
+        match self.storage_client.delete(path).await {
+            Ok(()) | Err(AppError::NotFound(_)) => {}
+            Err(e) => warn!("Failed to delete upload {}: {}", path, e),
+        }
+    }
+
+    fn publish(&self, job: &InferenceJob) {
+        // Nobody may be listening
+        let _ = self.events.send(job.clone());
+    }
+}
+
+/// Company the user is acting in
+fn company_id(user: &User) -> Option<Uuid> {
+    user.company.as_ref().map(|c| c.id)
+}
+
+/// Returns true for failures of the database, the storage or the model, which may
+/// be gone by the next attempt
+fn is_transient(error: &AppError) -> bool {
+    matches!(
+        error,
+        AppError::DatabaseError(_)
+            | AppError::Unknown(_)
+            | AppError::IntegrationError { .. }
+            | AppError::IntegrationTimeout(_)
+            | AppError::IntegrationUnavailable(_)
+    )
+}
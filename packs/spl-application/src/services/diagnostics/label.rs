@@ -1,7 +1,8 @@
 use crate::dtos::diagnostics::{CreateLabelDto, UpdateLabelDto};
+use crate::services::policy::{PolicyService, Resource};
 
 use spl_domain::entities::diagnostics::Label;
-use spl_domain::entities::user::User;
+use spl_domain::entities::user::{permissions, User};
 use spl_domain::ports::repositories::diagnostics::LabelRepository;
 use spl_shared::error::{AppError, Result};
 use spl_shared::traits::IntoWithContext;
@@ -9,17 +10,18 @@ use std::sync::Arc;
 
 pub struct LabelService {
     label_repo: Arc<dyn LabelRepository>,
+    policy: Arc<PolicyService>,
 }
 
 impl LabelService {
-    pub fn new(label_repo: Arc<dyn LabelRepository>) -> Self {
-        Self { label_repo }
+    pub fn new(label_repo: Arc<dyn LabelRepository>, policy: Arc<PolicyService>) -> Self {
+        Self { label_repo, policy }
     }
 
     pub async fn create(&self, requester: &User, dto: CreateLabelDto) -> Result<Label> {
-        if requester.role.level < 100 {
-            return Err(AppError::Forbidden);
-        }
+        self.policy
+            .ensure(requester, permissions::CATALOG_MANAGE, Resource::Global)
+            .await?;
         self.label_repo.create(dto.into()).await
     }
 
@@ -36,9 +38,9 @@ impl LabelService {
     }
 
     pub async fn update(&self, requester: &User, id: i32, dto: UpdateLabelDto) -> Result<Label> {
-        if requester.role.level < 100 {
-            return Err(AppError::Forbidden);
-        }
+        self.policy
+            .ensure(requester, permissions::CATALOG_MANAGE, Resource::Global)
+            .await?;
 
         let current = self
             .label_repo
@@ -51,9 +53,9 @@ impl LabelService {
     }
 
     pub async fn delete(&self, requester: &User, id: i32) -> Result<Label> {
-        if requester.role.level < 100 {
-            return Err(AppError::Forbidden);
-        }
+        self.policy
+            .ensure(requester, permissions::CATALOG_MANAGE, Resource::Global)
+            .await?;
         self.label_repo.delete(id).await
     }
 }
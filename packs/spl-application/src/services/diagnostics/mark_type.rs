@@ -1,7 +1,8 @@
 use crate::dtos::diagnostics::{CreateMarkTypeDto, UpdateMarkTypeDto};
+use crate::services::policy::{PolicyService, Resource};
 
 use spl_domain::entities::diagnostics::MarkType;
-use spl_domain::entities::user::User;
+use spl_domain::entities::user::{permissions, User};
 use spl_domain::ports::repositories::diagnostics::MarkTypeRepository;
 use spl_shared::error::{AppError, Result};
 use spl_shared::traits::IntoWithContext;
@@ -9,31 +10,35 @@ use std::sync::Arc;
 
 pub struct MarkTypeService {
     mark_type_repo: Arc<dyn MarkTypeRepository>,
+    policy: Arc<PolicyService>,
 }
 
 impl MarkTypeService {
-    pub fn new(mark_type_repo: Arc<dyn MarkTypeRepository>) -> Self {
-        Self { mark_type_repo }
+    pub fn new(mark_type_repo: Arc<dyn MarkTypeRepository>, policy: Arc<PolicyService>) -> Self {
+        Self {
+            mark_type_repo,
+            policy,
+        }
     }
 
     pub async fn create(&self, requester: &User, dto: CreateMarkTypeDto) -> Result<MarkType> {
-        if requester.role.level < 100 {
-            return Err(AppError::Forbidden);
-        }
+        self.policy
+            .ensure(requester, permissions::CATALOG_MANAGE, Resource::Global)
+            .await?;
         self.mark_type_repo.create(dto.into()).await
     }
 
     pub async fn get_by_id(&self, requester: &User, id: i32) -> Result<Option<MarkType>> {
-        if requester.role.level < 100 {
-            return Err(AppError::Forbidden);
-        }
+        self.policy
+            .ensure(requester, permissions::CATALOG_MANAGE, Resource::Global)
+            .await?;
         self.mark_type_repo.get_by_id(id).await
     }
 
     pub async fn get_all(&self, requester: &User) -> Result<Vec<MarkType>> {
-        if requester.role.level < 100 {
-            return Err(AppError::Forbidden);
-        }
+        self.policy
+            .ensure(requester, permissions::CATALOG_MANAGE, Resource::Global)
+            .await?;
         self.mark_type_repo.get_all().await
     }
 
@@ -43,9 +48,9 @@ impl MarkTypeService {
         id: i32,
         dto: UpdateMarkTypeDto,
     ) -> Result<MarkType> {
-        if requester.role.level < 100 {
-            return Err(AppError::Forbidden);
-        }
+        self.policy
+            .ensure(requester, permissions::CATALOG_MANAGE, Resource::Global)
+            .await?;
 
         let current = self
             .mark_type_repo
@@ -58,9 +63,9 @@ impl MarkTypeService {
     }
 
     pub async fn delete(&self, requester: &User, id: i32) -> Result<MarkType> {
-        if requester.role.level < 100 {
-            return Err(AppError::Forbidden);
-        }
+        self.policy
+            .ensure(requester, permissions::CATALOG_MANAGE, Resource::Global)
+            .await?;
         self.mark_type_repo.delete(id).await
     }
 }
@@ -1,7 +1,11 @@
+pub mod inference_job;
 pub mod label;
 pub mod mark_type;
 pub mod prediction;
+pub mod prediction_batch;
 
+pub use inference_job::InferenceJobService;
 pub use label::LabelService;
 pub use mark_type::MarkTypeService;
 pub use prediction::PredictionService;
+pub use prediction_batch::PredictionBatchService;
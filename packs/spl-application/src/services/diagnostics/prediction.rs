@@ -1,13 +1,16 @@
 use crate::dtos::diagnostics::CreatePredictionDto;
 use crate::mappers::diagnostics::prediction::CreatePredictionContext;
 use crate::services::access_control::AccessControlService;
+use crate::services::company_settings::CompanySettingsService;
+use crate::services::policy::Resource;
+use crate::services::usage::UsageService;
 
-use crate::dtos::diagnostics::FilterPredictionDto;
+use crate::dtos::diagnostics::{FilterPredictionDto, PaginatedPredictions};
 use spl_domain::entities::diagnostics::prediction::{PredictionDetailed, RawPrediction};
 use spl_domain::entities::diagnostics::{Prediction, PredictionMark, RawPredictionMark};
-use spl_domain::entities::image::{Image, RawImage};
+use spl_domain::entities::image::{Image, ImageFormat, RawImage};
 use spl_domain::entities::recommendation::Recommendation;
-use spl_domain::entities::user::User;
+use spl_domain::entities::user::{permissions, PermissionScope, User};
 use spl_domain::ports::integrations::{BlobStorageClient, ModelPredictionClient};
 use spl_domain::ports::repositories::diagnostics::{
     LabelRepository, MarkTypeRepository, PredictionMarkRepository, PredictionRepository,
@@ -32,6 +35,8 @@ pub struct PredictionService {
     model_client: Arc<dyn ModelPredictionClient>,
     access_control: Arc<AccessControlService>,
     recommendation_repo: Arc<dyn RecommendationRepository>,
+    company_settings: Arc<CompanySettingsService>,
+    usage: Arc<UsageService>,
 }
 
 impl PredictionService {
@@ -47,6 +52,8 @@ impl PredictionService {
         storage_client: Arc<dyn BlobStorageClient>,
         model_client: Arc<dyn ModelPredictionClient>,
         access_control: Arc<AccessControlService>,
+        company_settings: Arc<CompanySettingsService>,
+        usage: Arc<UsageService>,
     ) -> Self {
         Self {
             prediction_repo,
@@ -59,6 +66,8 @@ impl PredictionService {
             storage_client,
             model_client,
             access_control,
+            company_settings,
+            usage,
         }
     }
 
@@ -99,6 +108,7 @@ impl PredictionService {
             absence_confidence: 1.0 - result.lesion_confidence,
             severity,
             label,
+            model: result.model,
             image: RawImage {
                 data: result.image,
                 filename: Some(filename),
@@ -117,25 +127,91 @@ impl PredictionService {
         })
     }
 
+    /// Predicts the image and stores the prediction in the company the user is acting in,
+    /// which is not their own under a membership
     pub async fn predict_and_create(
         &self,
-        user_id: Uuid,
+        user: User,
         image_bytes: Vec<u8>,
         filename: String,
     ) -> Result<Prediction> {
-        // 1. Validate user
-        let user = self
-            .user_repo
-            .get_by_id(user_id)
-            .await?
-            .ok_or_else(|| AppError::NotFound(format!("User {} not found", user_id)))?;
+        self.predict_and_create_with_id(Uuid::new_v4(), user, image_bytes, filename)
+            .await
+    }
+
+    /// Same as `predict_and_create`, the prediction is stored under `id` so that callers
+    /// running more than once can find it
+    pub async fn predict_and_create_with_id(
+        &self,
+        id: Uuid,
+        user: User,
+        image_bytes: Vec<u8>,
+        filename: String,
+    ) -> Result<Prediction> {
+        let settings = self.company_settings.for_user(&user).await?;
+        match ImageFormat::detect(&image_bytes) {
+            Some(format) if settings.allowed_upload_formats.contains(&format) => {}
+            Some(format) => {
+                return Err(AppError::ValidationError(format!(
+                    "Images in {} format cannot be uploaded",
+                    format.as_str()
+                )))
+            }
+            None => {
+                return Err(AppError::ValidationError(
+                    "File is not a supported image".to_string(),
+                ))
+            }
+        }
+
+        // Counted in the company acted in, like `company_id`; the saved prediction reloads
+        // its user with the company of their account
+        let reservation = self
+            .usage
+            .reserve_prediction(&user, image_bytes.len() as u64)
+            .await?;
 
+        match self.store_prediction(id, user, image_bytes, filename).await {
+            Ok((prediction, stored_bytes)) => {
+                self.usage
+                    .record_prediction(reservation, stored_bytes)
+                    .await?;
+                Ok(prediction)
+            }
+            Err(e) => {
+                if let Err(release_error) = self.usage.release(reservation).await {
+                    error!(
+                        "Failed to release usage of a failed prediction: {}",
+                        release_error
+                    );
+                }
+                Err(e)
+            }
+        }
+    }
+
+    /// Predicts the image and stores the prediction, returning the bytes it stored
+    async fn store_prediction(
+        &self,
+        id: Uuid,
+        user: User,
+        image_bytes: Vec<u8>,
+        filename: String,
+    ) -> Result<(Prediction, u64)> {
         // Helper to determine file paths
         let now = chrono::Utc::now();
-        let filesdir = format!("{}/images/{}", user.id, now.format("%Y-%m-%d_%H-%M-%S"));
+        // The suffix keeps apart images predicted at the same time by different workers
+        let filesdir = format!(
+            "{}/images/{}_{}",
+            user.id,
+            now.format("%Y-%m-%d_%H-%M-%S"),
+            &Uuid::new_v4().simple().to_string()[..8]
+        );
         let image_path = format!("{}/image.jpg", filesdir);
 
         let prediction = self.predict(image_bytes, filename.clone()).await?;
+        let stored_bytes = prediction.image.data.len()
+            + prediction.marks.iter().map(|m| m.data.len()).sum::<usize>();
 
         self.storage_client
             .upload(prediction.image.data, &image_path)
@@ -188,7 +264,7 @@ impl PredictionService {
         // 7. Save Image Entity
         let image = Image {
             id: Uuid::new_v4(),
-            user_id,
+            user_id: user.id,
             filename,
             filepath: image_path,
             created_at: chrono::Utc::now(),
@@ -198,8 +274,9 @@ impl PredictionService {
 
         // 8. Save Prediction Entity
         let mut prediction = Prediction {
-            id: Uuid::new_v4(),
-            user,
+            id,
+            company_id: user.company.as_ref().map(|c| c.id),
+            user: user.clone(),
             image: image.clone(),
             label: prediction.label,
             plot_id: None,
@@ -208,6 +285,7 @@ impl PredictionService {
             // Legacy: lesion_confidence.absence
             absence_confidence: prediction.absence_confidence,
             severity: prediction.severity,
+            model: Some(prediction.model),
             feedback: None,
             created_at: chrono::Utc::now(),
             marks: vec![],
@@ -235,95 +313,146 @@ impl PredictionService {
 
         prediction.image = image; // Update prediction with image that now has prediction_id
         prediction.marks = marks; // Add marks to prediction
-        Ok(prediction)
+
+        Ok((prediction, stored_bytes as u64))
     }
 
     pub async fn get_by_id(&self, id: Uuid) -> Result<Option<Prediction>> {
         self.prediction_repo.get_by_id(id).await
     }
 
-    pub async fn get_by_user_id(&self, user_id: Uuid) -> Result<Vec<Prediction>> {
-        self.prediction_repo.get_by_user_id(user_id).await
+    /// Predictions of the user made in the company they are acting in
+    pub async fn get_by_user(&self, user: &User) -> Result<Vec<Prediction>> {
+        let company_id = user.company.as_ref().map(|c| c.id);
+
+        let mut predictions = self.prediction_repo.get_by_user_id(user.id).await?;
+        predictions.retain(|p| p.company_id == company_id);
+
+        Ok(predictions)
     }
 
-    pub async fn get_by_user_id_and_id(
-        &self,
-        user_id: Uuid,
-        id: Uuid,
-    ) -> Result<Option<Prediction>> {
-        self.prediction_repo
-            .get_by_user_id_and_id(user_id, id)
-            .await
+    pub async fn get_by_user_and_id(&self, user: &User, id: Uuid) -> Result<Option<Prediction>> {
+        let company_id = user.company.as_ref().map(|c| c.id);
+
+        Ok(self
+            .prediction_repo
+            .get_by_user_id_and_id(user.id, id)
+            .await?
+            .filter(|p| p.company_id == company_id))
     }
 
-    pub async fn get_detailed_by_user_id_and_id(
+    pub async fn get_detailed_by_user_and_id(
         &self,
-        user_id: Uuid,
+        user: &User,
         id: Uuid,
     ) -> Result<Option<PredictionDetailed>> {
-        self.prediction_repo
-            .get_detailed_by_user_id_and_id(user_id, id)
-            .await
+        let company_id = user.company.as_ref().map(|c| c.id);
+
+        Ok(self
+            .prediction_repo
+            .get_detailed_by_user_id_and_id(user.id, id)
+            .await?
+            .filter(|p| p.company_id == company_id))
     }
 
     pub async fn filter(
         &self,
         dto: FilterPredictionDto,
         requester: &User,
-    ) -> Result<(u64, Vec<Prediction>)> {
-        // Determine target users using AccessControlService
-        let mut target_user_ids = self
+    ) -> Result<PaginatedPredictions> {
+        let scope = self
             .access_control
-            .get_accessible_user_ids(requester, dto.company_id)
+            .policy()
+            .scope(requester, permissions::PREDICTIONS_READ)
             .await?;
 
+        // Predictions are read in the company they were made in, whoever made them.
+        // `None` for the users stands for every user of the company.
+        let company_id = requester.company.as_ref().map(|c| c.id);
+        let (company_id, mut target_user_ids) = match scope {
+            None => return Err(AppError::Forbidden),
+            Some(PermissionScope::Any) if dto.company_id.is_some() => (dto.company_id, None),
+            Some(PermissionScope::Company) => (company_id, None),
+            Some(PermissionScope::Team) => (
+                company_id,
+                self.access_control
+                    .get_team_user_ids(requester, permissions::PREDICTIONS_READ)
+                    .await?,
+            ),
+            Some(_) => (company_id, Some(vec![requester.id])),
+        };
+
         // If specific target users requested
         if let Some(requested_users) = dto.target_user_ids {
-            if requester.role.level >= 50 {
-                // Supervisor/Admin can filter within their scope
-                let allowed_set: std::collections::HashSet<_> =
-                    target_user_ids.into_iter().collect();
-
-                target_user_ids = requested_users
-                    .into_iter()
-                    .filter(|uid| allowed_set.contains(uid))
-                    .collect();
+            if scope > Some(PermissionScope::Own) {
+                // Users reading others' predictions can filter within their scope
+                target_user_ids = Some(match target_user_ids {
+                    Some(allowed) => requested_users
+                        .into_iter()
+                        .filter(|uid| allowed.contains(uid))
+                        .collect(),
+                    None => requested_users,
+                });
             }
         }
 
-        if target_user_ids.is_empty() {
-            return Ok((0, Vec::new()));
+        let limit = match dto.limit {
+            Some(limit) => limit,
+            None => {
+                self.company_settings
+                    .for_user(requester)
+                    .await?
+                    .default_page_size
+            }
+        };
+        let page = dto.page.unwrap_or(1);
+
+        if target_user_ids.as_ref().is_some_and(|ids| ids.is_empty()) {
+            return Ok(PaginatedPredictions {
+                total: 0,
+                page,
+                limit,
+                items: Vec::new(),
+            });
         }
 
-        let limit = dto.limit.unwrap_or(16);
-        let page = dto.page.unwrap_or(1);
         let offset = (page - 1) * limit;
 
-        self.prediction_repo
+        let (total, items) = self
+            .prediction_repo
             .filter(
+                vec![company_id],
                 target_user_ids,
                 dto.labels,
+                dto.model_versions,
                 dto.plot_ids,
                 dto.min_date,
                 dto.max_date,
                 offset,
                 limit,
             )
-            .await
+            .await?;
+
+        Ok(PaginatedPredictions {
+            total,
+            page,
+            limit,
+            items,
+        })
     }
 
     pub async fn get_all(&self, requester: &User) -> Result<Vec<Prediction>> {
-        // Only admins can get all predictions
-        if requester.role.level < 100 {
-            return Err(AppError::Forbidden);
-        }
+        // Only users reading every company can get all predictions
+        self.access_control
+            .policy()
+            .ensure(requester, permissions::PREDICTIONS_READ, Resource::Global)
+            .await?;
         self.prediction_repo.get_all().await
     }
 
-    pub async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<Prediction> {
+    pub async fn delete(&self, user: &User, id: Uuid) -> Result<Prediction> {
         let prediction = self
-            .prediction_repo
-            .get_by_user_id_and_id(user_id, id)
+            .get_by_user_and_id(user, id)
             .await?
             .ok_or_else(|| AppError::NotFound("Prediction not found".to_string()))?;
 
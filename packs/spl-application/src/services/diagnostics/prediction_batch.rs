@@ -0,0 +1,212 @@
+use crate::dtos::diagnostics::{PredictionBatchDetailDto, UploadedFileDto};
+use crate::services::diagnostics::InferenceJobService;
+use bytes::Bytes;
+use chrono::Utc;
+use spl_domain::entities::diagnostics::{
+    BatchItemStatus, BatchLimits, BatchStatus, PredictionBatch, PredictionBatchItem,
+};
+use spl_domain::entities::user::User;
+use spl_domain::ports::archive::ArchiveReader;
+use spl_domain::ports::integrations::BlobStorageClient;
+use spl_domain::ports::repositories::diagnostics::{
+    PredictionBatchItemRepository, PredictionBatchRepository,
+};
+use spl_shared::error::{AppError, Result};
+use std::sync::Arc;
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+/// Predicts many images in the background. Uploads are stored before the batch is
+/// returned, then each image is queued as an inference job of its own. The jobs
+/// keep the items and the progress of the batch up to date.
+pub struct PredictionBatchService {
+    batch_repo: Arc<dyn PredictionBatchRepository>,
+    item_repo: Arc<dyn PredictionBatchItemRepository>,
+    job_service: Arc<InferenceJobService>,
+    storage_client: Arc<dyn BlobStorageClient>,
+    archive_reader: Arc<dyn ArchiveReader>,
+    limits: BatchLimits,
+}
+
+impl PredictionBatchService {
+    pub fn new(
+        batch_repo: Arc<dyn PredictionBatchRepository>,
+        item_repo: Arc<dyn PredictionBatchItemRepository>,
+        job_service: Arc<InferenceJobService>,
+        storage_client: Arc<dyn BlobStorageClient>,
+        archive_reader: Arc<dyn ArchiveReader>,
+        limits: BatchLimits,
+    ) -> Self {
+        Self {
+            batch_repo,
+            item_repo,
+            job_service,
+            storage_client,
+            archive_reader,
+            limits,
+        }
+    }
+
+    /// Stores the images, archives are extracted first, and queues their predictions
+    pub async fn create(
+        &self,
+        requester: &User,
+        files: Vec<UploadedFileDto>,
+    ) -> Result<PredictionBatch> {
+        let images = self.expand(files)?;
+
+        if images.is_empty() {
+            return Err(AppError::ValidationError("No files provided".to_string()));
+        }
+
+        let now = Utc::now();
+        let batch = self
+            .batch_repo
+            .create(PredictionBatch {
+                id: Uuid::new_v4(),
+                user_id: requester.id,
+                company_id: company_id(requester),
+                status: BatchStatus::Processing,
+                items_total: images.len() as u64,
+                items_completed: 0,
+                items_failed: 0,
+                created_at: now,
+                updated_at: now,
+                completed_at: None,
+            })
+            .await?;
+
+        let queued = match self.store(&batch, images).await {
+            Ok(items) => self.job_service.enqueue_batch(requester, &items).await,
+            Err(e) => Err(e),
+        };
+
+        if let Err(e) = queued {
+            self.discard(&batch).await;
+            return Err(e);
+        }
+
+        info!(
+            batch_id = %batch.id,
+            user_id = %requester.id,
+            items = batch.items_total,
+            "Prediction batch created"
+        );
+
+        Ok(batch)
+    }
+
+    /// Batches of the requester in the company they are acting in, most recent first
+    pub async fn get_by_user(&self, requester: &User) -> Result<Vec<PredictionBatch>> {
+        let mut batches = self.batch_repo.get_by_user_id(requester.id).await?;
+        batches.retain(|batch| batch.company_id == company_id(requester));
+
+        Ok(batches)
+    }
+
+    pub async fn get_detailed(
+        &self,
+        requester: &User,
+        id: Uuid,
+    ) -> Result<PredictionBatchDetailDto> {
+        let batch = self
+            .batch_repo
+            .get_by_id(id)
+            .await?
+            .filter(|batch| {
+                batch.user_id == requester.id && batch.company_id == company_id(requester)
+            })
+            .ok_or_else(|| AppError::NotFound("Batch not found".to_string()))?;
+
+        let items = self.item_repo.get_by_batch_id(id).await?;
+
+        Ok(PredictionBatchDetailDto { batch, items })
+    }
+
+    /// Images of the upload in order, with the content of archives in place of them
+    fn expand(&self, files: Vec<UploadedFileDto>) -> Result<Vec<(String, Vec<u8>)>> {
+        let mut images = Vec::new();
+        let mut extracted = 0;
+
+        for file in files {
+            if self.archive_reader.is_archive(&file.content) {
+                let remaining = self.limits.max_extracted_bytes.saturating_sub(extracted);
+                for entry in self.archive_reader.extract(&file.content, remaining)? {
+                    extracted += entry.content.len() as u64;
+                    images.push((entry.path, entry.content));
+                }
+            } else {
+                let filename = file
+                    .filename
+                    .unwrap_or_else(|| format!("image-{}", images.len() + 1));
+                images.push((filename, file.content));
+            }
+
+            if images.len() > self.limits.max_files {
+                return Err(AppError::ValidationError(format!(
+                    "A batch cannot have more than {} images",
+                    self.limits.max_files
+                )));
+            }
+        }
+
+        Ok(images)
+    }
+
+    async fn store(
+        &self,
+        batch: &PredictionBatch,
+        images: Vec<(String, Vec<u8>)>,
+    ) -> Result<Vec<PredictionBatchItem>> {
+        let mut items = Vec::with_capacity(images.len());
+
+        for (position, (filename, content)) in images.into_iter().enumerate() {
+            let path = format!("{}{}", staging_dir(batch), position);
+            self.storage_client
+                .upload(Bytes::from(content), &path)
+                .await?;
+
+            items.push(PredictionBatchItem {
+                id: Uuid::new_v4(),
+                batch_id: batch.id,
+                position: position as u32,
+                filename,
+                upload_path: Some(path),
+                status: BatchItemStatus::Pending,
+                prediction_id: None,
+                error: None,
+                created_at: batch.created_at,
+                updated_at: batch.created_at,
+            });
+        }
+
+        self.item_repo.create_many(items.clone()).await?;
+
+        Ok(items)
+    }
+
+    /// Removes a batch that could not be stored or queued, its items go with it
+    async fn discard(&self, batch: &PredictionBatch) {
+        if let Err(e) = self
+            .storage_client
+            .delete_directory(&staging_dir(batch))
+            .await
+        {
+            warn!(batch_id = %batch.id, "Failed to delete uploads of discarded batch: {}", e);
+        }
+
+        if let Err(e) = self.batch_repo.delete(batch.id).await {
+            error!(batch_id = %batch.id, "Failed to delete discarded batch: {}", e);
+        }
+    }
+}
+
+/// Storage folder of the images of the batch until they are predicted
+fn staging_dir(batch: &PredictionBatch) -> String {
+    format!("{}/batches/{}/", batch.user_id, batch.id)
+}
+
+/// Company the user is acting in
+fn company_id(user: &User) -> Option<Uuid> {
+    user.company.as_ref().map(|c| c.id)
+}
@@ -0,0 +1,158 @@
+use chrono::{Duration, Utc};
+use spl_domain::entities::auth::EmailVerificationToken;
+use spl_domain::entities::user::User;
+use spl_domain::ports::auth::OpaqueTokenGenerator;
+use spl_domain::ports::cache::UserCache;
+use spl_domain::ports::mailer::{EmailMessage, Mailer};
+use spl_domain::ports::repositories::auth::EmailVerificationTokenRepository;
+use spl_domain::ports::repositories::user::UserRepository;
+use spl_shared::error::{AppError, Result};
+use std::sync::Arc;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+pub struct EmailVerificationService {
+    user_repo: Arc<dyn UserRepository>,
+    verification_token_repo: Arc<dyn EmailVerificationTokenRepository>,
+    opaque_token_generator: Arc<dyn OpaqueTokenGenerator>,
+    mailer: Arc<dyn Mailer>,
+    user_cache: Arc<dyn UserCache>,
+    frontend_url: Option<String>,
+    token_ttl_hours: i64,
+}
+
+impl EmailVerificationService {
+    pub fn new(
+        user_repo: Arc<dyn UserRepository>,
+        verification_token_repo: Arc<dyn EmailVerificationTokenRepository>,
+        opaque_token_generator: Arc<dyn OpaqueTokenGenerator>,
+        mailer: Arc<dyn Mailer>,
+        user_cache: Arc<dyn UserCache>,
+        frontend_url: Option<String>,
+        token_ttl_hours: i64,
+    ) -> Self {
+        Self {
+            user_repo,
+            verification_token_repo,
+            opaque_token_generator,
+            mailer,
+            user_cache,
+            frontend_url,
+            token_ttl_hours,
+        }
+    }
+
+    /// Emails a verification link to the current address of the user.
+    /// Links sent before stop working.
+    pub async fn send_verification(&self, user: &User) -> Result<()> {
+        let Some(email) = user.email.clone() else {
+            return Err(AppError::ValidationError(
+                "User has no email to verify".to_string(),
+            ));
+        };
+
+        if user.is_email_verified() {
+            return Err(AppError::Conflict("Email is already verified".to_string()));
+        }
+
+        self.verification_token_repo
+            .invalidate_by_user_id(user.id)
+            .await?;
+
+        let token = self.opaque_token_generator.generate();
+        let now = Utc::now();
+
+        self.verification_token_repo
+            .create(EmailVerificationToken {
+                id: Uuid::new_v4(),
+                user_id: user.id,
+                email: email.clone(),
+                token_hash: self.opaque_token_generator.hash(&token),
+                expires_at: now + Duration::hours(self.token_ttl_hours),
+                used_at: None,
+                created_at: now,
+            })
+            .await?;
+
+        self.mailer
+            .send(self.verification_email(user, email, &token))
+            .await?;
+
+        info!(user_id = %user.id, "Email verification sent");
+
+        Ok(())
+    }
+
+    /// Sends the link after the email of a user was set or changed. The change is already
+    /// saved, so failures are only logged and the user can ask for a new link.
+    pub async fn email_changed(&self, user: &User) {
+        if user.email.is_none() || user.is_email_verified() {
+            return;
+        }
+
+        if let Err(e) = self.send_verification(user).await {
+            warn!(user_id = %user.id, error = %e, "Failed to send email verification");
+        }
+    }
+
+    /// Marks the email of the user as verified using a token sent by `send_verification`
+    pub async fn verify(&self, token: &str) -> Result<User> {
+        let token_hash = self.opaque_token_generator.hash(token);
+        let invalid =
+            || AppError::ValidationError("Invalid or expired verification token".to_string());
+
+        let token = self
+            .verification_token_repo
+            .get_by_token_hash(&token_hash)
+            .await?
+            .filter(|token| token.is_usable())
+            .ok_or_else(invalid)?;
+
+        let mut user = self
+            .user_repo
+            .get_by_id(token.user_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
+
+        // The email changed since the link was sent
+        if user.email.as_deref() != Some(token.email.as_str()) {
+            return Err(invalid());
+        }
+
+        if !self.verification_token_repo.mark_used(token.id).await? {
+            return Err(invalid());
+        }
+
+        let now = Utc::now();
+        user.email_verified_at = Some(now);
+        user.updated_at = now;
+
+        let user = self.user_repo.update(user).await?;
+        self.user_cache.invalidate(user.id).await;
+
+        info!(user_id = %user.id, "Email verified");
+
+        Ok(user)
+    }
+
+    fn verification_email(&self, user: &User, to: String, token: &str) -> EmailMessage {
+        let link = match &self.frontend_url {
+            Some(url) => format!("{}/verify-email?token={token}", url.trim_end_matches('/')),
+            None => token.to_string(),
+        };
+
+        EmailMessage {
+            to,
+            subject: "Verify your email".to_string(),
+            body: format!(
+                "Hello {},\n\n\
+                 Please confirm this is the email of your SmartPotatoLeaf account\n\
+                 by opening the following link:\n\n{link}\n\n\
+                 The link expires in {} hours and can only be used once.\n\
+                 If you did not expect this email, you can ignore it.\n",
+                user.name.as_deref().unwrap_or(&user.username),
+                self.token_ttl_hours
+            ),
+        }
+    }
+}
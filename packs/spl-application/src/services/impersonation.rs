@@ -0,0 +1,230 @@
+use crate::dtos::auth::{ClientInfoDto, ImpersonationAuditDto, ImpersonationTokenDto};
+use crate::services::policy::{PolicyService, Resource};
+use chrono::{Duration, Utc};
+use spl_domain::entities::auth::{Impersonation, ImpersonationAction};
+use spl_domain::entities::user::{permissions, User};
+use spl_domain::ports::auth::TokenGenerator;
+use spl_domain::ports::repositories::auth::{
+    ImpersonationActionRepository, ImpersonationRepository,
+};
+use spl_domain::ports::repositories::user::UserRepository;
+use spl_shared::error::{AppError, Result};
+use std::sync::Arc;
+use tracing::info;
+use uuid::Uuid;
+
+/// Lets administrators act as users with lower privileges. The issued access tokens carry
+/// the subject as `sub` and the administrator as `act`, and every request made with them
+/// is recorded.
+pub struct ImpersonationService {
+    impersonation_repo: Arc<dyn ImpersonationRepository>,
+    action_repo: Arc<dyn ImpersonationActionRepository>,
+    user_repo: Arc<dyn UserRepository>,
+    token_generator: Arc<dyn TokenGenerator>,
+    policy: Arc<PolicyService>,
+    access_token_ttl_seconds: i64,
+}
+
+impl ImpersonationService {
+    pub fn new(
+        impersonation_repo: Arc<dyn ImpersonationRepository>,
+        action_repo: Arc<dyn ImpersonationActionRepository>,
+        user_repo: Arc<dyn UserRepository>,
+        token_generator: Arc<dyn TokenGenerator>,
+        policy: Arc<PolicyService>,
+        access_token_ttl_seconds: i64,
+    ) -> Self {
+        Self {
+            impersonation_repo,
+            action_repo,
+            user_repo,
+            token_generator,
+            policy,
+            access_token_ttl_seconds,
+        }
+    }
+
+    /// Starts acting as the subject. Only users with a lower role level that cannot
+    /// impersonate themselves may be impersonated, so the actor never gains privileges.
+    pub async fn start(
+        &self,
+        actor: &User,
+        subject_id: Uuid,
+        reason: String,
+        client: ClientInfoDto,
+    ) -> Result<ImpersonationTokenDto> {
+        let reason = reason.trim().to_string();
+        if reason.is_empty() {
+            return Err(AppError::ValidationError(
+                "A reason is required to impersonate a user".to_string(),
+            ));
+        }
+
+        if actor.id == subject_id {
+            return Err(AppError::ValidationError(
+                "Cannot impersonate yourself".to_string(),
+            ));
+        }
+
+        let subject = self
+            .user_repo
+            .get_by_id(subject_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
+
+        self.policy
+            .ensure(
+                actor,
+                permissions::USERS_IMPERSONATE,
+                Resource::User(&subject),
+            )
+            .await?;
+
+        if subject.role.level >= actor.role.level
+            || self
+                .policy
+                .scope(&subject, permissions::USERS_IMPERSONATE)
+                .await?
+                .is_some()
+        {
+            return Err(AppError::Forbidden);
+        }
+
+        if !subject.is_active() {
+            return Err(AppError::ValidationError(
+                "Cannot impersonate a deactivated user".to_string(),
+            ));
+        }
+
+        let now = Utc::now();
+        let impersonation = self
+            .impersonation_repo
+            .create(Impersonation {
+                id: Uuid::new_v4(),
+                actor_id: Some(actor.id),
+                subject_id: Some(subject_id),
+                reason,
+                ip_address: client.ip_address,
+                started_at: now,
+                expires_at: now + Duration::seconds(self.access_token_ttl_seconds),
+                ended_at: None,
+            })
+            .await?;
+
+        // No session: the token is checked against the impersonation instead
+        let claims = serde_json::json!({
+            "role": &subject.role.name,
+            "imp": impersonation.id.to_string(),
+            "act": { "sub": actor.id.to_string() },
+            "jti": Uuid::new_v4().to_string(),
+        });
+
+        let access_token = self
+            .token_generator
+            .generate(&subject.id.to_string(), claims)?;
+
+        info!(
+            impersonation_id = %impersonation.id,
+            actor_id = %actor.id,
+            subject_id = %subject.id,
+            "Impersonation started"
+        );
+
+        Ok(ImpersonationTokenDto {
+            access_token,
+            expires_in: self.access_token_ttl_seconds,
+            impersonation,
+        })
+    }
+
+    /// Returns true if tokens of the impersonation are still accepted: it was started by
+    /// `actor_id`, has not ended nor expired, and the actor is still active
+    pub async fn check_active(&self, impersonation_id: Uuid, actor_id: Uuid) -> Result<bool> {
+        let Some(impersonation) = self.impersonation_repo.get_by_id(impersonation_id).await? else {
+            return Ok(false);
+        };
+
+        if impersonation.actor_id != Some(actor_id) || !impersonation.is_active() {
+            return Ok(false);
+        }
+
+        Ok(self
+            .user_repo
+            .get_by_id(actor_id)
+            .await?
+            .is_some_and(|actor| actor.is_active()))
+    }
+
+    /// Ends the impersonation, its tokens stop working right away
+    pub async fn end(&self, impersonation_id: Uuid) -> Result<()> {
+        if !self.impersonation_repo.end(impersonation_id).await? {
+            return Err(AppError::Conflict(
+                "Impersonation has already ended".to_string(),
+            ));
+        }
+
+        info!(impersonation_id = %impersonation_id, "Impersonation ended");
+
+        Ok(())
+    }
+
+    /// Records a request made with a token of the impersonation
+    pub async fn record_action(
+        &self,
+        impersonation_id: Uuid,
+        method: String,
+        path: String,
+        status_code: u16,
+    ) -> Result<()> {
+        self.action_repo
+            .create(ImpersonationAction {
+                id: Uuid::new_v4(),
+                impersonation_id,
+                method,
+                path,
+                status_code,
+                created_at: Utc::now(),
+            })
+            .await?;
+
+        Ok(())
+    }
+
+    /// Impersonations the user started or was the subject of, most recent first
+    pub async fn get_by_user(&self, requester: &User, user_id: Uuid) -> Result<Vec<Impersonation>> {
+        self.ensure_can_audit(requester).await?;
+
+        self.impersonation_repo.get_by_user_id(user_id).await
+    }
+
+    pub async fn get_audit(
+        &self,
+        requester: &User,
+        impersonation_id: Uuid,
+    ) -> Result<ImpersonationAuditDto> {
+        self.ensure_can_audit(requester).await?;
+
+        let impersonation = self
+            .impersonation_repo
+            .get_by_id(impersonation_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Impersonation not found".to_string()))?;
+
+        let actions = self
+            .action_repo
+            .get_by_impersonation_id(impersonation_id)
+            .await?;
+
+        Ok(ImpersonationAuditDto {
+            impersonation,
+            actions,
+        })
+    }
+
+    /// Impersonations across companies are only audited by whoever may start them anywhere
+    async fn ensure_can_audit(&self, requester: &User) -> Result<()> {
+        self.policy
+            .ensure(requester, permissions::USERS_IMPERSONATE, Resource::Global)
+            .await
+    }
+}
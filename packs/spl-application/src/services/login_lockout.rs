@@ -0,0 +1,134 @@
+use chrono::{DateTime, Duration, Utc};
+use spl_domain::entities::auth::LoginAttempts;
+use spl_domain::ports::auth::LoginAttemptStore;
+use spl_domain::ports::repositories::user::UserRepository;
+use spl_shared::error::{AppError, Result};
+use std::sync::Arc;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+/// When and for how long accounts are locked after failed logins
+#[derive(Debug, Clone, Copy)]
+pub struct LockoutPolicy {
+    pub enabled: bool,
+    pub max_attempts: u32,
+    pub lockout_seconds: u64,
+    pub max_lockout_seconds: u64,
+}
+
+impl LockoutPolicy {
+    /// Lockout duration after `previous_lockouts` lockouts: doubles every time, up to the maximum
+    pub fn lockout_duration(&self, previous_lockouts: u32) -> Duration {
+        let factor = 2u64.saturating_pow(previous_lockouts);
+        let seconds = self
+            .lockout_seconds
+            .saturating_mul(factor)
+            .min(self.max_lockout_seconds);
+
+        Duration::seconds(seconds as i64)
+    }
+}
+
+pub struct LoginLockoutService {
+    user_repo: Arc<dyn UserRepository>,
+    store: Arc<dyn LoginAttemptStore>,
+    policy: LockoutPolicy,
+}
+
+impl LoginLockoutService {
+    pub fn new(
+        user_repo: Arc<dyn UserRepository>,
+        store: Arc<dyn LoginAttemptStore>,
+        policy: LockoutPolicy,
+    ) -> Self {
+        Self {
+            user_repo,
+            store,
+            policy,
+        }
+    }
+
+    /// Fails with `AccountLocked` while the account is locked
+    pub async fn ensure_not_locked(&self, user_id: Uuid) -> Result<()> {
+        if !self.policy.enabled {
+            return Ok(());
+        }
+
+        match self.store.get(user_id).await? {
+            Some(LoginAttempts {
+                locked_until: Some(until),
+                ..
+            }) if until > Utc::now() => Err(AppError::AccountLocked { until }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Records a failed login. Returns the end of the lockout when this failure locked the account.
+    pub async fn register_failure(&self, user_id: Uuid) -> Result<Option<DateTime<Utc>>> {
+        if !self.policy.enabled {
+            return Ok(None);
+        }
+
+        let attempts = self.store.record_failure(user_id).await?;
+
+        if attempts.failed_attempts < self.policy.max_attempts {
+            return Ok(None);
+        }
+
+        let until = Utc::now() + self.policy.lockout_duration(attempts.lockout_count);
+        self.store.lock(user_id, until).await?;
+
+        warn!(
+            user_id = %user_id,
+            locked_until = %until,
+            lockout = attempts.lockout_count + 1,
+            "Account locked after repeated failed logins"
+        );
+
+        Ok(Some(until))
+    }
+
+    /// Forgets failed attempts after a successful login
+    pub async fn register_success(&self, user_id: Uuid) -> Result<()> {
+        if !self.policy.enabled {
+            return Ok(());
+        }
+
+        self.store.clear(user_id).await
+    }
+
+    /// Current failed attempts and lockout of a user
+    pub async fn get_status(&self, user_id: Uuid) -> Result<LoginAttempts> {
+        self.ensure_user_exists(user_id).await?;
+
+        Ok(self
+            .store
+            .get(user_id)
+            .await?
+            .unwrap_or(LoginAttempts {
+                user_id,
+                failed_attempts: 0,
+                lockout_count: 0,
+                locked_until: None,
+            }))
+    }
+
+    /// Removes the lockout and the failed attempts of a user
+    pub async fn unlock(&self, user_id: Uuid) -> Result<()> {
+        self.ensure_user_exists(user_id).await?;
+        self.store.clear(user_id).await?;
+
+        info!(user_id = %user_id, "Account unlocked");
+
+        Ok(())
+    }
+
+    async fn ensure_user_exists(&self, user_id: Uuid) -> Result<()> {
+        self.user_repo
+            .get_by_id(user_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
+
+        Ok(())
+    }
+}
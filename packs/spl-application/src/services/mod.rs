@@ -1,10 +1,24 @@
 pub mod access_control;
 pub mod auth;
 pub mod company;
+pub mod company_settings;
 pub mod dashboard;
 pub mod diagnostics;
+pub mod email_verification;
 pub mod feedback;
 pub mod image;
+pub mod impersonation;
+pub mod login_lockout;
+pub mod offboarding;
+pub mod password_policy;
+pub mod password_reset;
 pub mod plot;
+pub mod policy;
 pub mod recommendation;
+pub mod service_account;
+pub mod session;
+pub mod sso;
+pub mod team;
+pub mod two_factor;
+pub mod usage;
 pub mod user;
@@ -0,0 +1,570 @@
+use crate::dtos::offboarding::{
+    ExportManifest, ExportedJob, ExportedPrediction, ExportedUser, OffboardingAuditDto,
+    OffboardingExportDto,
+};
+use crate::services::policy::{PolicyService, Resource};
+use chrono::Utc;
+use serde::Serialize;
+use spl_domain::entities::offboarding::{CompanyOffboarding, OffboardingEvent, OffboardingStatus};
+use spl_domain::entities::user::{permissions, User};
+use spl_domain::ports::archive::{Archive, ArchiveWriter};
+use spl_domain::ports::cache::UserCache;
+use spl_domain::ports::integrations::BlobStorageClient;
+use spl_domain::ports::repositories::company::CompanyRepository;
+use spl_domain::ports::repositories::diagnostics::{InferenceJobRepository, PredictionRepository};
+use spl_domain::ports::repositories::offboarding::{
+    CompanyOffboardingRepository, OffboardingEventRepository,
+};
+use spl_domain::ports::repositories::plot::PlotRepository;
+use spl_domain::ports::repositories::user::UserRepository;
+use spl_shared::error::{AppError, Result};
+use std::sync::Arc;
+use tracing::{error, info};
+use uuid::Uuid;
+
+/// Removes companies for good. The data of the company is first written to an archive
+/// that administrators download; deletion only starts once they confirm it. Both steps
+/// run in the background, save their progress as they go and leave an audit trail.
+pub struct OffboardingService {
+    offboarding_repo: Arc<dyn CompanyOffboardingRepository>,
+    event_repo: Arc<dyn OffboardingEventRepository>,
+    company_repo: Arc<dyn CompanyRepository>,
+    user_repo: Arc<dyn UserRepository>,
+    plot_repo: Arc<dyn PlotRepository>,
+    prediction_repo: Arc<dyn PredictionRepository>,
+    job_repo: Arc<dyn InferenceJobRepository>,
+    storage_client: Arc<dyn BlobStorageClient>,
+    archive_writer: Arc<dyn ArchiveWriter>,
+    user_cache: Arc<dyn UserCache>,
+    policy: Arc<PolicyService>,
+}
+
+impl OffboardingService {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        offboarding_repo: Arc<dyn CompanyOffboardingRepository>,
+        event_repo: Arc<dyn OffboardingEventRepository>,
+        company_repo: Arc<dyn CompanyRepository>,
+        user_repo: Arc<dyn UserRepository>,
+        plot_repo: Arc<dyn PlotRepository>,
+        prediction_repo: Arc<dyn PredictionRepository>,
+        job_repo: Arc<dyn InferenceJobRepository>,
+        storage_client: Arc<dyn BlobStorageClient>,
+        archive_writer: Arc<dyn ArchiveWriter>,
+        user_cache: Arc<dyn UserCache>,
+        policy: Arc<PolicyService>,
+    ) -> Self {
+        Self {
+            offboarding_repo,
+            event_repo,
+            company_repo,
+            user_repo,
+            plot_repo,
+            prediction_repo,
+            job_repo,
+            storage_client,
+            archive_writer,
+            user_cache,
+            policy,
+        }
+    }
+
+    /// Starts exporting the company. Its data is kept until deletion is confirmed.
+    pub async fn start(
+        self: &Arc<Self>,
+        requester: &User,
+        company_id: Uuid,
+    ) -> Result<CompanyOffboarding> {
+        self.ensure_can_offboard(requester).await?;
+
+        let company = self
+            .company_repo
+            .get_by_id(company_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Company not found".to_string()))?;
+
+        if requester.company.as_ref().map(|c| c.id) == Some(company_id) {
+            return Err(AppError::ValidationError(
+                "Cannot offboard your own company".to_string(),
+            ));
+        }
+
+        let pending = self
+            .offboarding_repo
+            .get_by_company_id(company_id)
+            .await?
+            .into_iter()
+            .any(|o| o.is_running() || o.status == OffboardingStatus::Exported);
+        if pending {
+            return Err(AppError::Conflict(
+                "Company already has an offboarding in progress".to_string(),
+            ));
+        }
+
+        let now = Utc::now();
+        let offboarding = self
+            .offboarding_repo
+            .create(CompanyOffboarding {
+                id: Uuid::new_v4(),
+                company_id,
+                company_name: company.name,
+                requested_by: requester.id,
+                status: OffboardingStatus::Exporting,
+                export_path: None,
+                export_size: None,
+                users_total: 0,
+                users_deleted: 0,
+                error: None,
+                created_at: now,
+                updated_at: now,
+                completed_at: None,
+            })
+            .await?;
+
+        self.record(&offboarding, Some(requester.id), "export_started", None)
+            .await?;
+
+        info!(
+            offboarding_id = %offboarding.id,
+            company_id = %company_id,
+            requested_by = %requester.id,
+            "Company offboarding started"
+        );
+
+        self.spawn(offboarding.id);
+
+        Ok(offboarding)
+    }
+
+    /// Confirms the deletion of an exported company, or resumes one that failed
+    pub async fn confirm_deletion(
+        self: &Arc<Self>,
+        requester: &User,
+        id: Uuid,
+    ) -> Result<CompanyOffboarding> {
+        self.ensure_can_offboard(requester).await?;
+
+        let mut offboarding = self.get_offboarding(id).await?;
+
+        if !offboarding.can_delete() {
+            return Err(AppError::Conflict(format!(
+                "Offboarding cannot be deleted while {}",
+                offboarding.status.as_str()
+            )));
+        }
+
+        let action = if offboarding.status == OffboardingStatus::Failed {
+            "deletion_resumed"
+        } else {
+            "deletion_confirmed"
+        };
+
+        offboarding.status = OffboardingStatus::Deleting;
+        offboarding.error = None;
+        offboarding.updated_at = Utc::now();
+        let offboarding = self.offboarding_repo.update(offboarding).await?;
+
+        self.record(&offboarding, Some(requester.id), action, None)
+            .await?;
+
+        info!(
+            offboarding_id = %offboarding.id,
+            company_id = %offboarding.company_id,
+            requested_by = %requester.id,
+            "Company deletion confirmed"
+        );
+
+        self.spawn(offboarding.id);
+
+        Ok(offboarding)
+    }
+
+    pub async fn get_audit(&self, requester: &User, id: Uuid) -> Result<OffboardingAuditDto> {
+        self.ensure_can_offboard(requester).await?;
+
+        let offboarding = self.get_offboarding(id).await?;
+        let events = self.event_repo.get_by_offboarding_id(id).await?;
+
+        Ok(OffboardingAuditDto {
+            offboarding,
+            events,
+        })
+    }
+
+    /// Offboardings of the company, most recent first. Still available once it is deleted.
+    pub async fn get_by_company(
+        &self,
+        requester: &User,
+        company_id: Uuid,
+    ) -> Result<Vec<CompanyOffboarding>> {
+        self.ensure_can_offboard(requester).await?;
+
+        self.offboarding_repo.get_by_company_id(company_id).await
+    }
+
+    /// Archive of the company, every download is recorded
+    pub async fn download_export(
+        &self,
+        requester: &User,
+        id: Uuid,
+    ) -> Result<OffboardingExportDto> {
+        self.ensure_can_offboard(requester).await?;
+
+        let offboarding = self.get_offboarding(id).await?;
+
+        let path = offboarding
+            .export_path
+            .as_deref()
+            .ok_or_else(|| AppError::Conflict("Export is not ready yet".to_string()))?;
+
+        let content = self.storage_client.download(path).await?;
+
+        self.record(&offboarding, Some(requester.id), "export_downloaded", None)
+            .await?;
+
+        Ok(OffboardingExportDto {
+            filename: format!(
+                "company-{}-export.{}",
+                offboarding.company_id,
+                self.archive_writer.extension()
+            ),
+            content_type: self.archive_writer.content_type().to_string(),
+            content,
+        })
+    }
+
+    /// Continues the offboardings interrupted by a restart of the server
+    pub async fn resume_running(self: Arc<Self>) {
+        let running = match self.offboarding_repo.get_running().await {
+            Ok(running) => running,
+            Err(e) => {
+                error!("Failed to load running offboardings: {}", e);
+                return;
+            }
+        };
+
+        for offboarding in running {
+            info!(offboarding_id = %offboarding.id, "Resuming company offboarding");
+            self.run(offboarding.id).await;
+        }
+    }
+
+    /// Runs the current step of the offboarding, recording why it failed if it does
+    pub async fn run(&self, id: Uuid) {
+        let result = match self.get_offboarding(id).await {
+            Ok(offboarding) => match offboarding.status {
+                OffboardingStatus::Exporting => self.export(offboarding).await,
+                OffboardingStatus::Deleting => self.delete(offboarding).await,
+                _ => Ok(()),
+            },
+            Err(e) => Err(e),
+        };
+
+        if let Err(e) = result {
+            error!(offboarding_id = %id, "Company offboarding failed: {}", e);
+
+            if let Err(e) = self.fail(id, e.to_string()).await {
+                error!(offboarding_id = %id, "Failed to record offboarding failure: {}", e);
+            }
+        }
+    }
+
+    fn spawn(self: &Arc<Self>, id: Uuid) {
+        let service = self.clone();
+        tokio::spawn(async move { service.run(id).await });
+    }
+
+    /// Writes the company, its users and plots to an archive, with the predictions and jobs
+    /// made in it and their files. Predictions its users made in other companies stay there.
+    async fn export(&self, mut offboarding: CompanyOffboarding) -> Result<()> {
+        let company = self
+            .company_repo
+            .get_by_id(offboarding.company_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Company not found".to_string()))?;
+
+        let users = self.user_repo.get_by_company_id(company.id).await?;
+        let plots = self.plot_repo.get_by_company_id(company.id).await?;
+
+        let predictions = self.prediction_repo.get_by_company_id(company.id).await?;
+        let jobs = self.job_repo.get_by_company_id(company.id).await?;
+
+        let mut archive = self.archive_writer.create();
+        let mut exported = Vec::with_capacity(predictions.len());
+        let mut files = 0;
+        let mut missing_files = Vec::new();
+
+        for prediction in &predictions {
+            let masks: Vec<String> = prediction
+                .marks
+                .iter()
+                .filter_map(|mark| mark.data.get("filepath")?.as_str())
+                .map(str::to_string)
+                .collect();
+
+            for path in std::iter::once(&prediction.image.filepath).chain(masks.iter()) {
+                self.add_file(archive.as_mut(), path, &mut files, &mut missing_files)
+                    .await?;
+            }
+
+            exported.push(ExportedPrediction {
+                id: prediction.id,
+                user_id: prediction.user.id,
+                plot_id: prediction.plot_id,
+                label: prediction.label.name.clone(),
+                presence_confidence: prediction.presence_confidence,
+                absence_confidence: prediction.absence_confidence,
+                severity: prediction.severity,
+                model: prediction.model.clone(),
+                image: format!("files/{}", prediction.image.filepath),
+                masks: masks.iter().map(|path| format!("files/{}", path)).collect(),
+                feedback: prediction.feedback.clone(),
+                created_at: prediction.created_at,
+            });
+        }
+
+        let mut exported_jobs = Vec::with_capacity(jobs.len());
+        for job in &jobs {
+            if let Some(path) = &job.upload_path {
+                self.add_file(archive.as_mut(), path, &mut files, &mut missing_files)
+                    .await?;
+            }
+
+            exported_jobs.push(ExportedJob {
+                id: job.id,
+                user_id: job.user_id,
+                batch_item_id: job.batch_item_id,
+                filename: job.filename.clone(),
+                upload: job
+                    .upload_path
+                    .as_ref()
+                    .map(|path| format!("files/{}", path)),
+                status: job.status,
+                prediction_id: job.prediction_id,
+                error: job.error.clone(),
+                created_at: job.created_at,
+                completed_at: job.completed_at,
+            });
+        }
+
+        let users: Vec<ExportedUser> = users.iter().map(Into::into).collect();
+        let manifest = ExportManifest {
+            offboarding_id: offboarding.id,
+            company,
+            exported_at: Utc::now(),
+            users: users.len(),
+            plots: plots.len(),
+            predictions: exported.len(),
+            jobs: exported_jobs.len(),
+            files,
+            missing_files,
+        };
+
+        add_json(archive.as_mut(), "manifest.json", &manifest)?;
+        add_json(archive.as_mut(), "users.json", &users)?;
+        add_json(archive.as_mut(), "plots.json", &plots)?;
+        add_json(archive.as_mut(), "predictions.json", &exported)?;
+        add_json(archive.as_mut(), "jobs.json", &exported_jobs)?;
+
+        let content = archive.finish()?;
+        let size = content.len() as u64;
+        let path = format!(
+            "offboarding/{}/{}.{}",
+            offboarding.company_id,
+            offboarding.id,
+            self.archive_writer.extension()
+        );
+        self.storage_client.upload(content, &path).await?;
+
+        offboarding.status = OffboardingStatus::Exported;
+        offboarding.export_path = Some(path);
+        offboarding.export_size = Some(size);
+        offboarding.updated_at = Utc::now();
+        let offboarding = self.offboarding_repo.update(offboarding).await?;
+
+        let detail = format!(
+            "{} users, {} plots, {} predictions, {} jobs, {} files, {} missing",
+            manifest.users,
+            manifest.plots,
+            manifest.predictions,
+            manifest.jobs,
+            manifest.files,
+            manifest.missing_files.len()
+        );
+        self.record(&offboarding, None, "export_completed", Some(detail))
+            .await?;
+
+        info!(offboarding_id = %offboarding.id, size, "Company export completed");
+
+        Ok(())
+    }
+
+    /// Deletes the jobs and predictions made in the company with their files, then its users
+    /// one at a time and the company. What is already deleted is gone from the company, so a
+    /// rerun skips it.
+    async fn delete(&self, mut offboarding: CompanyOffboarding) -> Result<()> {
+        let users = self
+            .user_repo
+            .get_by_company_id(offboarding.company_id)
+            .await?;
+
+        offboarding.users_total = offboarding.users_deleted + users.len() as u64;
+        offboarding.updated_at = Utc::now();
+        offboarding = self.offboarding_repo.update(offboarding).await?;
+
+        for job in self
+            .job_repo
+            .get_by_company_id(offboarding.company_id)
+            .await?
+        {
+            if let Some(path) = &job.upload_path {
+                self.delete_file(path).await?;
+            }
+            self.job_repo.delete(job.id).await?;
+        }
+
+        // Files are removed by path, the folders of users also hold what they predicted
+        // in other companies. Feedback and marks go with their prediction.
+        for prediction in self
+            .prediction_repo
+            .get_by_company_id(offboarding.company_id)
+            .await?
+        {
+            let masks = prediction
+                .marks
+                .iter()
+                .filter_map(|mark| mark.data.get("filepath")?.as_str());
+            for path in std::iter::once(prediction.image.filepath.as_str()).chain(masks) {
+                self.delete_file(path).await?;
+            }
+            self.prediction_repo.delete(prediction.id).await?;
+        }
+
+        for user in users {
+            self.user_repo.delete(user.id).await?;
+            self.user_cache.invalidate(user.id).await;
+
+            offboarding.users_deleted += 1;
+            offboarding.updated_at = Utc::now();
+            offboarding = self.offboarding_repo.update(offboarding).await?;
+
+            self.record(
+                &offboarding,
+                None,
+                "user_deleted",
+                Some(format!("{} ({})", user.username, user.id)),
+            )
+            .await?;
+        }
+
+        // Plots, settings and the rest of the company go with it
+        if self
+            .company_repo
+            .get_by_id(offboarding.company_id)
+            .await?
+            .is_some()
+        {
+            self.company_repo.delete(offboarding.company_id).await?;
+            self.record(&offboarding, None, "company_deleted", None)
+                .await?;
+        }
+
+        let now = Utc::now();
+        offboarding.status = OffboardingStatus::Completed;
+        offboarding.updated_at = now;
+        offboarding.completed_at = Some(now);
+        let offboarding = self.offboarding_repo.update(offboarding).await?;
+
+        info!(
+            offboarding_id = %offboarding.id,
+            company_id = %offboarding.company_id,
+            users = offboarding.users_deleted,
+            "Company offboarding completed"
+        );
+
+        Ok(())
+    }
+
+    async fn fail(&self, id: Uuid, message: String) -> Result<()> {
+        let mut offboarding = self.get_offboarding(id).await?;
+
+        offboarding.status = OffboardingStatus::Failed;
+        offboarding.error = Some(message.clone());
+        offboarding.updated_at = Utc::now();
+        let offboarding = self.offboarding_repo.update(offboarding).await?;
+
+        self.record(&offboarding, None, "failed", Some(message))
+            .await
+    }
+
+    /// Adds a stored file under `files/` of the archive, or notes it as missing
+    async fn add_file(
+        &self,
+        archive: &mut dyn Archive,
+        path: &str,
+        files: &mut usize,
+        missing_files: &mut Vec<String>,
+    ) -> Result<()> {
+        match self.storage_client.download(path).await {
+            Ok(content) => {
+                archive.add(&format!("files/{}", path), &content)?;
+                *files += 1;
+                Ok(())
+            }
+            Err(AppError::NotFound(_)) => {
+                missing_files.push(path.to_string());
+                Ok(())
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn delete_file(&self, path: &str) -> Result<()> {
+        match self.storage_client.delete(path).await {
+            Ok(()) | Err(AppError::NotFound(_)) => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn get_offboarding(&self, id: Uuid) -> Result<CompanyOffboarding> {
+        self.offboarding_repo
+            .get_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Offboarding not found".to_string()))
+    }
+
+    async fn record(
+        &self,
+        offboarding: &CompanyOffboarding,
+        actor_id: Option<Uuid>,
+        action: &str,
+        detail: Option<String>,
+    ) -> Result<()> {
+        self.event_repo
+            .create(OffboardingEvent {
+                id: Uuid::new_v4(),
+                offboarding_id: offboarding.id,
+                actor_id,
+                action: action.to_string(),
+                detail,
+                created_at: Utc::now(),
+            })
+            .await?;
+
+        Ok(())
+    }
+
+    /// Only whoever manages every company can remove one
+    async fn ensure_can_offboard(&self, requester: &User) -> Result<()> {
+        self.policy
+            .ensure(requester, permissions::COMPANIES_MANAGE, Resource::Global)
+            .await
+    }
+}
+
+fn add_json<T: Serialize>(archive: &mut dyn Archive, path: &str, value: &T) -> Result<()> {
+    let content = serde_json::to_vec_pretty(value)
+        .map_err(|e| AppError::Unknown(format!("Failed to serialize {}: {}", path, e)))?;
+
+    archive.add(path, &content)
+}
@@ -0,0 +1,200 @@
+use crate::services::access_control::AccessControlService;
+use chrono::Utc;
+use spl_domain::entities::auth::{CompanyPasswordPolicy, PasswordPolicy};
+use spl_domain::entities::user::{permissions, User};
+use spl_domain::ports::auth::{BreachedPasswordList, PasswordEncoder};
+use spl_domain::ports::repositories::auth::{
+    CompanyPasswordPolicyRepository, PasswordHistoryRepository,
+};
+use spl_domain::ports::repositories::company::CompanyRepository;
+use spl_shared::error::{AppError, Result};
+use std::sync::Arc;
+use tracing::info;
+use uuid::Uuid;
+
+/// Password rules of the server, made stricter by the policy of each company,
+/// and the history of hashes used to refuse recent passwords
+pub struct PasswordPolicyService {
+    policy_repo: Arc<dyn CompanyPasswordPolicyRepository>,
+    history_repo: Arc<dyn PasswordHistoryRepository>,
+    company_repo: Arc<dyn CompanyRepository>,
+    breached_passwords: Arc<dyn BreachedPasswordList>,
+    password_encoder: Arc<dyn PasswordEncoder>,
+    access_control: Arc<AccessControlService>,
+    server_policy: PasswordPolicy,
+}
+
+impl PasswordPolicyService {
+    pub fn new(
+        policy_repo: Arc<dyn CompanyPasswordPolicyRepository>,
+        history_repo: Arc<dyn PasswordHistoryRepository>,
+        company_repo: Arc<dyn CompanyRepository>,
+        breached_passwords: Arc<dyn BreachedPasswordList>,
+        password_encoder: Arc<dyn PasswordEncoder>,
+        access_control: Arc<AccessControlService>,
+        server_policy: PasswordPolicy,
+    ) -> Self {
+        Self {
+            policy_repo,
+            history_repo,
+            company_repo,
+            breached_passwords,
+            password_encoder,
+            access_control,
+            server_policy,
+        }
+    }
+
+    /// Policy enforced for users of the company, or the server one for users without company
+    pub async fn effective_policy(&self, company_id: Option<Uuid>) -> Result<PasswordPolicy> {
+        let Some(company_id) = company_id else {
+            return Ok(self.server_policy.clone());
+        };
+
+        Ok(match self.policy_repo.get_by_id(company_id).await? {
+            Some(company) => self.server_policy.strictest(&company.policy),
+            None => self.server_policy.clone(),
+        })
+    }
+
+    /// Checks the password of a new user
+    pub async fn validate(&self, company_id: Option<Uuid>, password: &str) -> Result<()> {
+        let policy = self.effective_policy(company_id).await?;
+        self.check_rules(&policy, password)
+    }
+
+    /// Checks a new password of an existing user, which cannot be its current one
+    /// nor one of the last `history_size` it had
+    pub async fn validate_change(&self, user: &User, password: &str) -> Result<()> {
+        let policy = self
+            .effective_policy(user.company.as_ref().map(|c| c.id))
+            .await?;
+        self.check_rules(&policy, password)?;
+
+        if policy.history_size == 0 {
+            return Ok(());
+        }
+
+        let mut hashes = vec![user.password_hash.clone()];
+        hashes.extend(
+            self.history_repo
+                .get_recent(user.id, policy.history_size as u64)
+                .await?,
+        );
+
+        for hash in &hashes {
+            if self
+                .password_encoder
+                .verify(password, hash)
+                .unwrap_or(false)
+            {
+                return Err(AppError::ValidationError(format!(
+                    "Password cannot be one of the last {} passwords",
+                    policy.history_size
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Records the current password hash of the user, keeping only as many as the policy needs
+    pub async fn remember(&self, user: &User) -> Result<()> {
+        let policy = self
+            .effective_policy(user.company.as_ref().map(|c| c.id))
+            .await?;
+        let keep = policy.history_size as u64;
+
+        if keep > 0 {
+            self.history_repo.add(user.id, &user.password_hash).await?;
+        }
+        self.history_repo.prune(user.id, keep).await?;
+
+        Ok(())
+    }
+
+    pub async fn get_company_policy(
+        &self,
+        requester: &User,
+        company_id: Uuid,
+    ) -> Result<CompanyPasswordPolicy> {
+        self.ensure_can_manage(requester, company_id).await?;
+
+        self.policy_repo
+            .get_by_id(company_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Password policy not found".to_string()))
+    }
+
+    /// Creates or replaces the password policy of a company
+    pub async fn set_company_policy(
+        &self,
+        requester: &User,
+        company_id: Uuid,
+        policy: PasswordPolicy,
+    ) -> Result<CompanyPasswordPolicy> {
+        self.ensure_can_manage(requester, company_id).await?;
+
+        if self.company_repo.get_by_id(company_id).await?.is_none() {
+            return Err(AppError::NotFound("Company not found".to_string()));
+        }
+
+        let existing = self.policy_repo.get_by_id(company_id).await?;
+        let now = Utc::now();
+
+        let company_policy = CompanyPasswordPolicy {
+            company_id,
+            policy,
+            created_at: existing.as_ref().map(|p| p.created_at).unwrap_or(now),
+            updated_at: now,
+        };
+
+        let company_policy = match existing {
+            Some(_) => self.policy_repo.update(company_policy).await?,
+            None => self.policy_repo.create(company_policy).await?,
+        };
+
+        info!(company_id = %company_id, updated_by = %requester.id, "Password policy configured");
+
+        Ok(company_policy)
+    }
+
+    /// Removes the policy of a company, whose users follow the server one again
+    pub async fn delete_company_policy(
+        &self,
+        requester: &User,
+        company_id: Uuid,
+    ) -> Result<CompanyPasswordPolicy> {
+        let policy = self.get_company_policy(requester, company_id).await?;
+
+        self.policy_repo.delete(company_id).await?;
+
+        info!(company_id = %company_id, deleted_by = %requester.id, "Password policy removed");
+
+        Ok(policy)
+    }
+
+    async fn ensure_can_manage(&self, requester: &User, company_id: Uuid) -> Result<()> {
+        self.access_control
+            .validate_company_management_access(requester, permissions::USERS_MANAGE, company_id)
+            .await
+    }
+
+    fn check_rules(&self, policy: &PasswordPolicy, password: &str) -> Result<()> {
+        let violations = policy.violations(password);
+        if !violations.is_empty() {
+            return Err(AppError::ValidationError(format!(
+                "Password {}",
+                violations.join(", ")
+            )));
+        }
+
+        if self.breached_passwords.contains(password) {
+            return Err(AppError::ValidationError(
+                "Password appears in a list of breached passwords".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
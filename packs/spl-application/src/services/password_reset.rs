@@ -0,0 +1,178 @@
+use crate::dtos::auth::{ForgotPasswordDto, ResetPasswordDto};
+use crate::services::password_policy::PasswordPolicyService;
+use chrono::{Duration, Utc};
+use spl_domain::entities::auth::PasswordResetToken;
+use spl_domain::entities::user::User;
+use spl_domain::ports::auth::{OpaqueTokenGenerator, PasswordEncoder};
+use spl_domain::ports::cache::UserCache;
+use spl_domain::ports::mailer::{EmailMessage, Mailer};
+use spl_domain::ports::repositories::auth::{PasswordResetTokenRepository, SessionRepository};
+use spl_domain::ports::repositories::user::UserRepository;
+use spl_shared::error::{AppError, Result};
+use std::sync::Arc;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+pub struct PasswordResetService {
+    user_repo: Arc<dyn UserRepository>,
+    reset_token_repo: Arc<dyn PasswordResetTokenRepository>,
+    session_repo: Arc<dyn SessionRepository>,
+    password_encoder: Arc<dyn PasswordEncoder>,
+    opaque_token_generator: Arc<dyn OpaqueTokenGenerator>,
+    mailer: Arc<dyn Mailer>,
+    password_policy: Arc<PasswordPolicyService>,
+    user_cache: Arc<dyn UserCache>,
+    frontend_url: Option<String>,
+    token_ttl_minutes: i64,
+}
+
+impl PasswordResetService {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        user_repo: Arc<dyn UserRepository>,
+        reset_token_repo: Arc<dyn PasswordResetTokenRepository>,
+        session_repo: Arc<dyn SessionRepository>,
+        password_encoder: Arc<dyn PasswordEncoder>,
+        opaque_token_generator: Arc<dyn OpaqueTokenGenerator>,
+        mailer: Arc<dyn Mailer>,
+        password_policy: Arc<PasswordPolicyService>,
+        user_cache: Arc<dyn UserCache>,
+        frontend_url: Option<String>,
+        token_ttl_minutes: i64,
+    ) -> Self {
+        Self {
+            user_repo,
+            reset_token_repo,
+            session_repo,
+            password_encoder,
+            opaque_token_generator,
+            mailer,
+            password_policy,
+            user_cache,
+            frontend_url,
+            token_ttl_minutes,
+        }
+    }
+
+    /// Issues a reset token and emails it to the user. Unknown accounts and accounts
+    /// without a verified email are ignored so the endpoint cannot be used to enumerate
+    /// users. Unverified addresses may belong to anyone, so no link is sent to them.
+    pub async fn request_reset(&self, dto: ForgotPasswordDto) -> Result<()> {
+        if dto.username.is_none() && dto.email.is_none() {
+            return Err(AppError::ValidationError(
+                "Either username or email must be provided".to_string(),
+            ));
+        }
+
+        let user = match self
+            .user_repo
+            .get_by_username_or_email_and_company(dto.username, dto.email, dto.company_id)
+            .await?
+        {
+            // Deactivated users are answered like unknown ones
+            Some(user) if user.is_active() => user,
+            _ => return Ok(()),
+        };
+
+        let Some(email) = user.email.clone() else {
+            warn!(user_id = %user.id, "Password reset requested for a user without email");
+            return Ok(());
+        };
+
+        if !user.is_email_verified() {
+            warn!(user_id = %user.id, "Password reset requested for an unverified email");
+            return Ok(());
+        }
+
+        // Only the latest link is valid
+        self.reset_token_repo.invalidate_by_user_id(user.id).await?;
+
+        let token = self.opaque_token_generator.generate();
+        let now = Utc::now();
+
+        self.reset_token_repo
+            .create(PasswordResetToken {
+                id: Uuid::new_v4(),
+                user_id: user.id,
+                token_hash: self.opaque_token_generator.hash(&token),
+                expires_at: now + Duration::minutes(self.token_ttl_minutes),
+                used_at: None,
+                created_at: now,
+            })
+            .await?;
+
+        self.mailer
+            .send(self.reset_email(&user, email, &token))
+            .await?;
+
+        info!(user_id = %user.id, "Password reset email sent");
+
+        Ok(())
+    }
+
+    /// Sets a new password using a reset token. The token is consumed and every
+    /// active session of the user is revoked.
+    pub async fn reset_password(&self, dto: ResetPasswordDto) -> Result<()> {
+        let token_hash = self.opaque_token_generator.hash(&dto.token);
+
+        let token = self
+            .reset_token_repo
+            .get_by_token_hash(&token_hash)
+            .await?
+            .filter(|token| token.is_usable())
+            .ok_or_else(|| {
+                AppError::ValidationError("Invalid or expired reset token".to_string())
+            })?;
+
+        let mut user = self
+            .user_repo
+            .get_by_id(token.user_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
+
+        // Checked before consuming the token so another password can be tried
+        self.password_policy
+            .validate_change(&user, &dto.new_password)
+            .await?;
+
+        if !self.reset_token_repo.mark_used(token.id).await? {
+            return Err(AppError::ValidationError(
+                "Invalid or expired reset token".to_string(),
+            ));
+        }
+
+        user.password_hash = self.password_encoder.hash(&dto.new_password)?;
+        user.updated_at = Utc::now();
+
+        let user = self.user_repo.update(user).await?;
+        self.user_cache.invalidate(user.id).await;
+        self.password_policy.remember(&user).await?;
+
+        self.session_repo.revoke_by_user_id(user.id).await?;
+
+        info!(user_id = %user.id, "Password reset completed");
+
+        Ok(())
+    }
+
+    fn reset_email(&self, user: &User, to: String, token: &str) -> EmailMessage {
+        let link = match &self.frontend_url {
+            Some(url) => format!("{}/reset-password?token={token}", url.trim_end_matches('/')),
+            None => token.to_string(),
+        };
+
+        EmailMessage {
+            to,
+            subject: "Password reset".to_string(),
+            body: format!(
+                "Hello {},\n\n\
+                 We received a request to reset your SmartPotatoLeaf password.\n\
+                 Use the following link to choose a new one:\n\n{link}\n\n\
+                 The link expires in {} minutes and can only be used once.\n\
+                 If you did not request a reset, you can ignore this email.\n",
+                user.name.as_deref().unwrap_or(&user.username),
+                self.token_ttl_minutes
+            ),
+        }
+    }
+}
@@ -3,9 +3,10 @@ use crate::dtos::plot::{
     UpdatePlotDto,
 };
 use crate::services::access_control::AccessControlService;
+use crate::services::company_settings::CompanySettingsService;
 
 use spl_domain::entities::plot::{DetailedPlot, Plot};
-use spl_domain::entities::user::User;
+use spl_domain::entities::user::{permissions, User};
 use spl_domain::ports::repositories::diagnostics::PredictionRepository;
 use spl_domain::ports::repositories::plot::PlotRepository;
 use spl_shared::error::{AppError, Result};
@@ -17,6 +18,7 @@ pub struct PlotService {
     plot_repo: Arc<dyn PlotRepository>,
     prediction_repo: Arc<dyn PredictionRepository>,
     access_control: Arc<AccessControlService>,
+    company_settings: Arc<CompanySettingsService>,
 }
 
 impl PlotService {
@@ -24,44 +26,47 @@ impl PlotService {
         plot_repo: Arc<dyn PlotRepository>,
         prediction_repo: Arc<dyn PredictionRepository>,
         access_control: Arc<AccessControlService>,
+        company_settings: Arc<CompanySettingsService>,
     ) -> Self {
         Self {
             plot_repo,
             prediction_repo,
             access_control,
+            company_settings,
         }
     }
 
     /// Create a new plot for the user
     pub async fn create(&self, creator: &User, mut dto: CreatePlotDto) -> Result<Plot> {
-        // 1. Authorization: Only Supervisor (>= 50) or higher can create
-        if creator.role.level < 50 {
-            return Err(AppError::Forbidden);
-        }
-
-        // 2. Resolve Company ID
+        // 1. Authorization and Company ID resolution
         let target_company_id = self
             .access_control
-            .validate_company_access(creator, dto.company_id)
+            .validate_company_access(creator, permissions::PLOTS_MANAGE, dto.company_id)
             .await?;
 
-        // 3. Update DTO
+        // 2. Update DTO
         dto.company_id = Some(target_company_id);
 
         self.plot_repo.create(dto.into()).await
     }
 
-    /// Get all plots for the user's company
+    /// Get all plots for the user's company and the companies below it
     pub async fn get_all_by_user(
         &self,
         user: &User,
         company_id: Option<Uuid>,
     ) -> Result<Vec<Plot>> {
-        let target_company_id = self
-            .access_control
-            .validate_company_access(user, company_id)
-            .await?;
-        self.plot_repo.get_by_company_id(target_company_id).await
+        let company_ids = self.get_reachable_company_ids(user, company_id).await?;
+
+        let mut plots = vec![];
+        for company_id in company_ids {
+            plots.extend(self.plot_repo.get_by_company_id(company_id).await?);
+        }
+
+        match self.get_team_plot_ids(user).await? {
+            Some(ids) => Ok(plots.into_iter().filter(|p| ids.contains(&p.id)).collect()),
+            None => Ok(plots),
+        }
     }
 
     /// Get a single plot by ID (scoped to user's company)
@@ -73,8 +78,13 @@ impl PlotService {
     ) -> Result<Option<Plot>> {
         let target_company_id = self
             .access_control
-            .validate_company_access(user, company_id)
+            .validate_company_read_access(user, permissions::PLOTS_READ, company_id)
             .await?;
+
+        if !self.reaches_plot(user, id).await? {
+            return Ok(None);
+        }
+
         self.plot_repo
             .get_by_company_id_and_id(target_company_id, id)
             .await
@@ -90,7 +100,7 @@ impl PlotService {
     ) -> Result<Plot> {
         let target_company_id = self
             .access_control
-            .validate_company_access(user, company_id)
+            .validate_company_access(user, permissions::PLOTS_MANAGE, company_id)
             .await?;
 
         let current = self
@@ -107,7 +117,7 @@ impl PlotService {
     pub async fn delete(&self, user: &User, id: Uuid, company_id: Option<Uuid>) -> Result<Plot> {
         let target_company_id = self
             .access_control
-            .validate_company_access(user, company_id)
+            .validate_company_access(user, permissions::PLOTS_MANAGE, company_id)
             .await?;
 
         // Verify plot belongs to company before deleting
@@ -130,9 +140,13 @@ impl PlotService {
     ) -> Result<AssignedPlot> {
         let target_company_id = self
             .access_control
-            .validate_company_access(user, company_id)
+            .validate_company_access(user, permissions::PLOTS_READ, company_id)
             .await?;
 
+        if !self.reaches_plot(user, plot_id).await? {
+            return Err(AppError::NotFound("Plot not found".to_string()));
+        }
+
         // Verify plot belongs to company
         let _ = self
             .plot_repo
@@ -140,9 +154,15 @@ impl PlotService {
             .await?
             .ok_or_else(|| AppError::NotFound("Plot not found".to_string()))?;
 
+        // Only predictions made in the company of the plot
         let predictions = self
             .prediction_repo
-            .assign_plot_by_ids_and_user_id(dto.prediction_ids.clone(), user.id, Some(plot_id))
+            .assign_plot_by_ids_and_user_id(
+                dto.prediction_ids.clone(),
+                user.id,
+                Some(target_company_id),
+                Some(plot_id),
+            )
             .await?;
 
         if predictions.is_empty() {
@@ -164,7 +184,12 @@ impl PlotService {
     ) -> Result<AssignedPlot> {
         let predictions = self
             .prediction_repo
-            .assign_plot_by_ids_and_user_id(dto.prediction_ids.clone(), user.id, None)
+            .assign_plot_by_ids_and_user_id(
+                dto.prediction_ids.clone(),
+                user.id,
+                user.company.as_ref().map(|c| c.id),
+                None,
+            )
             .await?;
 
         if predictions.is_empty() {
@@ -185,20 +210,23 @@ impl PlotService {
         dto: DetailedPlotDto,
         company_id: Option<Uuid>,
     ) -> Result<PaginatedDetailedPlot> {
-        let target_company_id = self
-            .access_control
-            .validate_company_access(user, company_id)
-            .await?;
+        let company_ids = self.get_reachable_company_ids(user, company_id).await?;
 
         let page = dto.page.max(1);
-        let limit = dto.limit.min(100).max(1);
+        let limit = match dto.limit {
+            Some(limit) => limit,
+            None => self.company_settings.for_user(user).await?.default_page_size,
+        }
+        .clamp(1, 100);
         let offset = (page - 1) * limit;
 
         let labels = dto.labels.unwrap_or_default();
 
+        let plot_ids = self.get_team_plot_ids(user).await?;
+
         let (total, items) = self
             .plot_repo
-            .get_detailed(target_company_id, offset, limit, labels.clone())
+            .get_detailed(company_ids, plot_ids, offset, limit, labels.clone())
             .await?;
 
         Ok(PaginatedDetailedPlot {
@@ -219,9 +247,13 @@ impl PlotService {
     ) -> Result<Option<DetailedPlot>> {
         let target_company_id = self
             .access_control
-            .validate_company_access(user, company_id)
+            .validate_company_read_access(user, permissions::PLOTS_READ, company_id)
             .await?;
 
+        if !self.reaches_plot(user, id).await? {
+            return Ok(None);
+        }
+
         let detailed = self
             .plot_repo
             .get_detailed_by_id(target_company_id, id, labels)
@@ -239,9 +271,14 @@ impl PlotService {
     ) -> Result<Option<DetailedPlot>> {
         let target_company_id = self
             .access_control
-            .validate_company_access(user, company_id)
+            .validate_company_read_access(user, permissions::PLOTS_READ, company_id)
             .await?;
 
+        // Unassigned predictions of the whole company are not shown to supervisors of teams
+        if self.get_team_plot_ids(user).await?.is_some() {
+            return Ok(None);
+        }
+
         let detailed = self
             .plot_repo
             .get_default_detailed(target_company_id, labels)
@@ -249,4 +286,34 @@ impl PlotService {
 
         Ok(detailed.map(Into::into))
     }
+
+    /// The requested company, the user's own by default, and the companies below it
+    async fn get_reachable_company_ids(
+        &self,
+        user: &User,
+        company_id: Option<Uuid>,
+    ) -> Result<Vec<Uuid>> {
+        let target_company_id = self
+            .access_control
+            .validate_company_read_access(user, permissions::PLOTS_READ, company_id)
+            .await?;
+
+        self.access_control
+            .get_reachable_company_ids(user, permissions::PLOTS_READ, target_company_id)
+            .await
+    }
+
+    /// Plots of the teams the user supervises, `None` when not narrowed to teams
+    async fn get_team_plot_ids(&self, user: &User) -> Result<Option<Vec<Uuid>>> {
+        self.access_control
+            .get_team_plot_ids(user, permissions::PLOTS_READ)
+            .await
+    }
+
+    async fn reaches_plot(&self, user: &User, plot_id: Uuid) -> Result<bool> {
+        Ok(self
+            .get_team_plot_ids(user)
+            .await?
+            .is_none_or(|ids| ids.contains(&plot_id)))
+    }
 }
@@ -0,0 +1,137 @@
+use spl_domain::entities::user::{PermissionScope, Role, User};
+use spl_domain::ports::repositories::user::PermissionRepository;
+use spl_shared::error::{AppError, Result};
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+use uuid::Uuid;
+
+/// Granted scope per permission, per role name
+type Grants = HashMap<String, HashMap<String, PermissionScope>>;
+
+/// What a permission is checked against
+#[derive(Debug, Clone, Copy)]
+pub enum Resource<'a> {
+    /// Not owned by a company, e.g. the label catalog or the list of companies
+    Global,
+    /// Resources of a company
+    Company(Uuid),
+    /// A user and what it owns
+    User(&'a User),
+}
+
+/// Decides what users may do from the permissions granted to their role.
+/// Grants are loaded once and kept until they expire or `invalidate` is called.
+/// Every server keeps its own, so changes made through another server are only
+/// seen once they expire.
+pub struct PolicyService {
+    permission_repo: Arc<dyn PermissionRepository>,
+    grants: RwLock<Option<(Arc<Grants>, Instant)>>,
+    ttl: Duration,
+}
+
+impl PolicyService {
+    pub fn new(permission_repo: Arc<dyn PermissionRepository>, ttl_seconds: u64) -> Self {
+        Self {
+            permission_repo,
+            grants: RwLock::new(None),
+            ttl: Duration::from_secs(ttl_seconds),
+        }
+    }
+
+    /// Widest scope the role is granted the permission with
+    pub async fn scope_of(&self, role: &Role, permission: &str) -> Result<Option<PermissionScope>> {
+        let grants = self.grants().await?;
+
+        Ok(grants
+            .get(&role.name)
+            .and_then(|permissions| permissions.get(permission))
+            .copied())
+    }
+
+    pub async fn scope(&self, user: &User, permission: &str) -> Result<Option<PermissionScope>> {
+        self.scope_of(&user.role, permission).await
+    }
+
+    pub async fn can(&self, user: &User, permission: &str, resource: Resource<'_>) -> Result<bool> {
+        let Some(scope) = self.scope(user, permission).await? else {
+            return Ok(false);
+        };
+
+        let company_id = user.company.as_ref().map(|c| c.id);
+
+        Ok(match (scope, resource) {
+            (PermissionScope::Any, _) => true,
+            (PermissionScope::Company | PermissionScope::Team, Resource::Company(id)) => {
+                company_id == Some(id)
+            }
+            (PermissionScope::Company, Resource::User(target)) => {
+                company_id.is_some() && company_id == target.company.as_ref().map(|c| c.id)
+            }
+            // Teams are resolved by `AccessControlService`, here the scope only reaches the user
+            (PermissionScope::Own | PermissionScope::Team, Resource::User(target)) => {
+                target.id == user.id
+            }
+            _ => false,
+        })
+    }
+
+    /// Like `can`, failing with `Forbidden`
+    pub async fn ensure(
+        &self,
+        user: &User,
+        permission: &str,
+        resource: Resource<'_>,
+    ) -> Result<()> {
+        if self.can(user, permission, resource).await? {
+            Ok(())
+        } else {
+            Err(AppError::Forbidden)
+        }
+    }
+
+    /// Roles granted any permission across companies, users with them belong to no company
+    pub async fn is_global(&self, role: &Role) -> Result<bool> {
+        let grants = self.grants().await?;
+
+        Ok(grants.get(&role.name).is_some_and(|permissions| {
+            permissions
+                .values()
+                .any(|scope| *scope == PermissionScope::Any)
+        }))
+    }
+
+    /// Drops the cached grants, the next check reloads them
+    pub fn invalidate(&self) {
+        *self.grants.write().unwrap_or_else(|e| e.into_inner()) = None;
+    }
+
+    async fn grants(&self) -> Result<Arc<Grants>> {
+        if let Some((grants, loaded_at)) = self
+            .grants
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .as_ref()
+        {
+            if loaded_at.elapsed() < self.ttl {
+                return Ok(grants.clone());
+            }
+        }
+
+        let mut grants = Grants::new();
+        for grant in self.permission_repo.get_grants().await? {
+            let scope = grants
+                .entry(grant.role)
+                .or_default()
+                .entry(grant.permission)
+                .or_insert(grant.scope);
+            *scope = (*scope).max(grant.scope);
+        }
+
+        let grants = Arc::new(grants);
+        *self.grants.write().unwrap_or_else(|e| e.into_inner()) =
+            Some((grants.clone(), Instant::now()));
+
+        Ok(grants)
+    }
+}
@@ -0,0 +1,315 @@
+use crate::dtos::service_account::{CreateApiKeyDto, CreateServiceAccountDto, CreatedApiKeyDto};
+use crate::services::access_control::AccessControlService;
+use chrono::{Duration, Utc};
+use spl_domain::entities::auth::{api_key::scopes, ApiKey, ServiceAccount};
+use spl_domain::entities::user::{permissions, User};
+use spl_domain::ports::auth::{OpaqueTokenGenerator, PasswordEncoder};
+use spl_domain::ports::repositories::auth::{ApiKeyRepository, ServiceAccountRepository};
+use spl_domain::ports::repositories::company::CompanyRepository;
+use spl_domain::ports::repositories::user::{RoleRepository, UserRepository};
+use spl_shared::error::{AppError, Result};
+use std::sync::Arc;
+use tracing::info;
+use uuid::Uuid;
+
+/// Every API key starts with this prefix, which tells them apart from JWTs
+pub const API_KEY_PREFIX: &str = "spl_";
+
+/// Characters of the key kept in clear to identify it
+const KEY_PREFIX_LENGTH: usize = 12;
+
+/// `last_used_at` is written at most once per interval to avoid a write per request
+const LAST_USED_RESOLUTION_SECONDS: i64 = 60;
+
+pub struct ServiceAccountService {
+    service_account_repo: Arc<dyn ServiceAccountRepository>,
+    api_key_repo: Arc<dyn ApiKeyRepository>,
+    user_repo: Arc<dyn UserRepository>,
+    role_repo: Arc<dyn RoleRepository>,
+    company_repo: Arc<dyn CompanyRepository>,
+    password_encoder: Arc<dyn PasswordEncoder>,
+    opaque_token_generator: Arc<dyn OpaqueTokenGenerator>,
+    access_control: Arc<AccessControlService>,
+}
+
+impl ServiceAccountService {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        service_account_repo: Arc<dyn ServiceAccountRepository>,
+        api_key_repo: Arc<dyn ApiKeyRepository>,
+        user_repo: Arc<dyn UserRepository>,
+        role_repo: Arc<dyn RoleRepository>,
+        company_repo: Arc<dyn CompanyRepository>,
+        password_encoder: Arc<dyn PasswordEncoder>,
+        opaque_token_generator: Arc<dyn OpaqueTokenGenerator>,
+        access_control: Arc<AccessControlService>,
+    ) -> Self {
+        Self {
+            service_account_repo,
+            api_key_repo,
+            user_repo,
+            role_repo,
+            company_repo,
+            password_encoder,
+            opaque_token_generator,
+            access_control,
+        }
+    }
+
+    /// Creates the service account and its backing user. The user gets a random
+    /// password nobody knows, so it can only authenticate with API keys.
+    pub async fn create(
+        &self,
+        requester: &User,
+        dto: CreateServiceAccountDto,
+    ) -> Result<ServiceAccount> {
+        let company_id = self
+            .access_control
+            .validate_company_access(
+                requester,
+                permissions::SERVICE_ACCOUNTS_MANAGE,
+                dto.company_id,
+            )
+            .await?;
+        self.access_control
+            .validate_company_management_access(
+                requester,
+                permissions::SERVICE_ACCOUNTS_MANAGE,
+                company_id,
+            )
+            .await?;
+
+        let company = self
+            .company_repo
+            .get_by_id(company_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Company not found".to_string()))?;
+
+        let role = self.role_repo.get_by_name("user").await?.ok_or_else(|| {
+            AppError::ValidationError("Default 'User' role not found".to_string())
+        })?;
+
+        let id = Uuid::new_v4();
+        let now = Utc::now();
+        let password_hash = self
+            .password_encoder
+            .hash(&self.opaque_token_generator.generate())?;
+
+        self.user_repo
+            .create(User {
+                id,
+                username: format!("svc-{}", id.simple()),
+                email: None,
+                email_verified_at: None,
+                password_hash,
+                name: Some(dto.name.clone()),
+                surname: None,
+                role,
+                company: Some(company),
+                deactivated_at: None,
+                created_at: now,
+                updated_at: now,
+            })
+            .await?;
+
+        let account = self
+            .service_account_repo
+            .create(ServiceAccount {
+                id,
+                company_id,
+                name: dto.name,
+                description: dto.description,
+                created_by: Some(requester.id),
+                created_at: now,
+            })
+            .await?;
+
+        info!(service_account_id = %id, company_id = %company_id, "Service account created");
+
+        Ok(account)
+    }
+
+    pub async fn get_by_company(
+        &self,
+        requester: &User,
+        company_id: Option<Uuid>,
+    ) -> Result<Vec<ServiceAccount>> {
+        let company_id = self
+            .access_control
+            .validate_company_access(requester, permissions::SERVICE_ACCOUNTS_MANAGE, company_id)
+            .await?;
+        self.access_control
+            .validate_company_management_access(
+                requester,
+                permissions::SERVICE_ACCOUNTS_MANAGE,
+                company_id,
+            )
+            .await?;
+
+        self.service_account_repo
+            .get_by_company_id(company_id)
+            .await
+    }
+
+    /// Deletes the service account together with its backing user and keys
+    pub async fn delete(&self, requester: &User, id: Uuid) -> Result<ServiceAccount> {
+        let account = self.get_managed(requester, id).await?;
+
+        self.user_repo.delete(account.id).await?;
+
+        info!(service_account_id = %id, "Service account deleted");
+
+        Ok(account)
+    }
+
+    pub async fn create_key(
+        &self,
+        requester: &User,
+        service_account_id: Uuid,
+        dto: CreateApiKeyDto,
+    ) -> Result<CreatedApiKeyDto> {
+        let account = self.get_managed(requester, service_account_id).await?;
+
+        if dto.scopes.is_empty() {
+            return Err(AppError::ValidationError(
+                "At least one scope is required".to_string(),
+            ));
+        }
+
+        if let Some(scope) = dto.scopes.iter().find(|scope| !scopes::is_valid(scope)) {
+            return Err(AppError::ValidationError(format!(
+                "Unknown scope '{}'",
+                scope
+            )));
+        }
+
+        if dto.expires_at.is_some_and(|at| at <= Utc::now()) {
+            return Err(AppError::ValidationError(
+                "Expiration must be in the future".to_string(),
+            ));
+        }
+
+        let mut key_scopes = dto.scopes;
+        key_scopes.sort();
+        key_scopes.dedup();
+
+        let key = format!(
+            "{}{}",
+            API_KEY_PREFIX,
+            self.opaque_token_generator.generate()
+        );
+
+        let api_key = self
+            .api_key_repo
+            .create(ApiKey {
+                id: Uuid::new_v4(),
+                service_account_id: account.id,
+                name: dto.name,
+                key_prefix: key.chars().take(KEY_PREFIX_LENGTH).collect(),
+                key_hash: self.opaque_token_generator.hash(&key),
+                scopes: key_scopes,
+                expires_at: dto.expires_at,
+                last_used_at: None,
+                revoked_at: None,
+                created_at: Utc::now(),
+            })
+            .await?;
+
+        info!(service_account_id = %account.id, api_key_id = %api_key.id, "API key created");
+
+        Ok(CreatedApiKeyDto { key, api_key })
+    }
+
+    pub async fn get_keys(
+        &self,
+        requester: &User,
+        service_account_id: Uuid,
+    ) -> Result<Vec<ApiKey>> {
+        let account = self.get_managed(requester, service_account_id).await?;
+
+        self.api_key_repo
+            .get_by_service_account_id(account.id)
+            .await
+    }
+
+    pub async fn revoke_key(
+        &self,
+        requester: &User,
+        service_account_id: Uuid,
+        key_id: Uuid,
+    ) -> Result<ApiKey> {
+        let account = self.get_managed(requester, service_account_id).await?;
+
+        let mut api_key = self
+            .api_key_repo
+            .get_by_id(key_id)
+            .await?
+            .filter(|key| key.service_account_id == account.id)
+            .ok_or_else(|| AppError::NotFound("API key not found".to_string()))?;
+
+        if self.api_key_repo.revoke(api_key.id).await? {
+            api_key.revoked_at = Some(Utc::now());
+            info!(service_account_id = %account.id, api_key_id = %api_key.id, "API key revoked");
+        }
+
+        Ok(api_key)
+    }
+
+    /// Resolves an API key to the user of its service account
+    pub async fn authenticate(&self, key: &str) -> Result<(User, ApiKey)> {
+        if !key.starts_with(API_KEY_PREFIX) {
+            return Err(AppError::AuthError("Invalid API key".to_string()));
+        }
+
+        let api_key = self
+            .api_key_repo
+            .get_by_key_hash(&self.opaque_token_generator.hash(key))
+            .await?
+            .ok_or_else(|| AppError::AuthError("Invalid API key".to_string()))?;
+
+        if !api_key.is_active() {
+            return Err(AppError::AuthError(
+                "API key expired or revoked".to_string(),
+            ));
+        }
+
+        let user = self
+            .user_repo
+            .get_by_id(api_key.service_account_id)
+            .await?
+            .ok_or_else(|| AppError::AuthError("Invalid API key".to_string()))?;
+
+        if !user.is_active() {
+            return Err(AppError::AuthError("Account is deactivated".to_string()));
+        }
+
+        let now = Utc::now();
+        let stale = api_key
+            .last_used_at
+            .is_none_or(|at| now - at >= Duration::seconds(LAST_USED_RESOLUTION_SECONDS));
+
+        if stale {
+            self.api_key_repo.touch(api_key.id, now).await?;
+        }
+
+        Ok((user, api_key))
+    }
+
+    async fn get_managed(&self, requester: &User, id: Uuid) -> Result<ServiceAccount> {
+        let account = self
+            .service_account_repo
+            .get_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Service account not found".to_string()))?;
+
+        self.access_control
+            .validate_company_management_access(
+                requester,
+                permissions::SERVICE_ACCOUNTS_MANAGE,
+                account.company_id,
+            )
+            .await?;
+
+        Ok(account)
+    }
+}
@@ -0,0 +1,102 @@
+use crate::services::access_control::AccessControlService;
+use crate::services::policy::Resource;
+use spl_domain::entities::auth::Session;
+use spl_domain::entities::user::{permissions, User};
+use spl_domain::ports::repositories::auth::SessionRepository;
+use spl_domain::ports::repositories::user::UserRepository;
+use spl_shared::error::{AppError, Result};
+use std::sync::Arc;
+use tracing::info;
+use uuid::Uuid;
+
+/// Active sessions of a user. Users manage their own sessions, supervisors those of
+/// lower roles in their company and admins those of anyone below them.
+pub struct SessionService {
+    session_repo: Arc<dyn SessionRepository>,
+    user_repo: Arc<dyn UserRepository>,
+    access_control: Arc<AccessControlService>,
+}
+
+impl SessionService {
+    pub fn new(
+        session_repo: Arc<dyn SessionRepository>,
+        user_repo: Arc<dyn UserRepository>,
+        access_control: Arc<AccessControlService>,
+    ) -> Self {
+        Self {
+            session_repo,
+            user_repo,
+            access_control,
+        }
+    }
+
+    pub async fn get_active(&self, requester: &User, user_id: Uuid) -> Result<Vec<Session>> {
+        self.ensure_can_manage(requester, user_id).await?;
+
+        self.session_repo.get_active_by_user_id(user_id).await
+    }
+
+    /// Revokes one session. Access tokens of the session stop working immediately.
+    pub async fn revoke(&self, requester: &User, user_id: Uuid, session_id: Uuid) -> Result<()> {
+        self.ensure_can_manage(requester, user_id).await?;
+
+        let session = self
+            .session_repo
+            .get_by_id(session_id)
+            .await?
+            .filter(|session| session.user_id == user_id && session.is_active())
+            .ok_or_else(|| AppError::NotFound("Session not found".to_string()))?;
+
+        self.session_repo.revoke(session.id).await?;
+
+        info!(
+            session_id = %session.id,
+            user_id = %user_id,
+            revoked_by = %requester.id,
+            "Session revoked"
+        );
+
+        Ok(())
+    }
+
+    /// Revokes every session of the user. Returns how many were still active.
+    pub async fn revoke_all(&self, requester: &User, user_id: Uuid) -> Result<u64> {
+        self.ensure_can_manage(requester, user_id).await?;
+
+        let revoked = self.session_repo.revoke_by_user_id(user_id).await?;
+
+        info!(
+            user_id = %user_id,
+            revoked_by = %requester.id,
+            revoked,
+            "All sessions revoked"
+        );
+
+        Ok(revoked)
+    }
+
+    async fn ensure_can_manage(&self, requester: &User, user_id: Uuid) -> Result<()> {
+        if requester.id == user_id {
+            return Ok(());
+        }
+
+        let target = self
+            .user_repo
+            .get_by_id(user_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
+
+        if target.role.level >= requester.role.level {
+            return Err(AppError::Forbidden);
+        }
+
+        self.access_control
+            .policy()
+            .ensure(
+                requester,
+                permissions::USERS_MANAGE,
+                Resource::User(&target),
+            )
+            .await
+    }
+}
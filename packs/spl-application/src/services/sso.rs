@@ -0,0 +1,468 @@
+use crate::dtos::auth::{AuthTokensDto, ClientInfoDto};
+use crate::dtos::sso::{ConfigureIdentityProviderDto, SsoCallbackDto};
+use crate::services::access_control::AccessControlService;
+use crate::services::auth::AuthService;
+use chrono::{Duration, Utc};
+use spl_domain::entities::auth::{IdentityProvider, OidcLoginState, UserIdentity};
+use spl_domain::entities::user::{permissions, Role, User};
+use spl_domain::ports::auth::{OpaqueTokenGenerator, PasswordEncoder};
+use spl_domain::ports::cache::UserCache;
+use spl_domain::ports::oidc::{OidcClaims, OidcClient};
+use spl_domain::ports::repositories::auth::{
+    IdentityProviderRepository, OidcLoginStateRepository, UserIdentityRepository,
+};
+use spl_domain::ports::repositories::company::CompanyRepository;
+use spl_domain::ports::repositories::user::{RoleRepository, UserRepository};
+use spl_shared::error::{AppError, Result};
+use std::sync::Arc;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+const DEFAULT_SCOPES: &str = "openid email profile";
+const DEFAULT_ROLE_CLAIM: &str = "roles";
+
+/// Single sign-on through the OpenID Connect provider of a company
+pub struct SsoService {
+    identity_provider_repo: Arc<dyn IdentityProviderRepository>,
+    user_identity_repo: Arc<dyn UserIdentityRepository>,
+    login_state_repo: Arc<dyn OidcLoginStateRepository>,
+    user_repo: Arc<dyn UserRepository>,
+    role_repo: Arc<dyn RoleRepository>,
+    company_repo: Arc<dyn CompanyRepository>,
+    oidc_client: Arc<dyn OidcClient>,
+    password_encoder: Arc<dyn PasswordEncoder>,
+    opaque_token_generator: Arc<dyn OpaqueTokenGenerator>,
+    auth_service: Arc<AuthService>,
+    access_control: Arc<AccessControlService>,
+    user_cache: Arc<dyn UserCache>,
+    redirect_url: Option<String>,
+    state_ttl_seconds: i64,
+}
+
+impl SsoService {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        identity_provider_repo: Arc<dyn IdentityProviderRepository>,
+        user_identity_repo: Arc<dyn UserIdentityRepository>,
+        login_state_repo: Arc<dyn OidcLoginStateRepository>,
+        user_repo: Arc<dyn UserRepository>,
+        role_repo: Arc<dyn RoleRepository>,
+        company_repo: Arc<dyn CompanyRepository>,
+        oidc_client: Arc<dyn OidcClient>,
+        password_encoder: Arc<dyn PasswordEncoder>,
+        opaque_token_generator: Arc<dyn OpaqueTokenGenerator>,
+        auth_service: Arc<AuthService>,
+        access_control: Arc<AccessControlService>,
+        user_cache: Arc<dyn UserCache>,
+        redirect_url: Option<String>,
+        state_ttl_seconds: i64,
+    ) -> Self {
+        Self {
+            identity_provider_repo,
+            user_identity_repo,
+            login_state_repo,
+            user_repo,
+            role_repo,
+            company_repo,
+            oidc_client,
+            password_encoder,
+            opaque_token_generator,
+            auth_service,
+            access_control,
+            user_cache,
+            redirect_url,
+            state_ttl_seconds,
+        }
+    }
+
+    pub async fn get_provider(
+        &self,
+        requester: &User,
+        company_id: Uuid,
+    ) -> Result<IdentityProvider> {
+        self.access_control
+            .validate_company_management_access(requester, permissions::SSO_MANAGE, company_id)
+            .await?;
+
+        self.identity_provider_repo
+            .get_by_id(company_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Identity provider not found".to_string()))
+    }
+
+    /// Creates or replaces the identity provider of a company. Claims can only be
+    /// mapped to company roles not above the requester's own role.
+    pub async fn configure_provider(
+        &self,
+        requester: &User,
+        company_id: Uuid,
+        dto: ConfigureIdentityProviderDto,
+    ) -> Result<IdentityProvider> {
+        self.access_control
+            .validate_company_management_access(requester, permissions::SSO_MANAGE, company_id)
+            .await?;
+
+        if self.company_repo.get_by_id(company_id).await?.is_none() {
+            return Err(AppError::NotFound("Company not found".to_string()));
+        }
+
+        let scopes = dto.scopes.unwrap_or_else(|| DEFAULT_SCOPES.to_string());
+        if !scopes.split_whitespace().any(|scope| scope == "openid") {
+            return Err(AppError::ValidationError(
+                "Scopes must include 'openid'".to_string(),
+            ));
+        }
+
+        for role_name in dto.role_mapping.values().chain(dto.default_role.iter()) {
+            let role = self
+                .role_repo
+                .get_by_name(role_name)
+                .await?
+                .ok_or_else(|| {
+                    AppError::ValidationError(format!("Unknown role '{}'", role_name))
+                })?;
+
+            if role.level > requester.role.level
+                || self.access_control.policy().is_global(&role).await?
+            {
+                return Err(AppError::ValidationError(format!(
+                    "Role '{}' cannot be granted through single sign-on",
+                    role_name
+                )));
+            }
+        }
+
+        let existing = self.identity_provider_repo.get_by_id(company_id).await?;
+        let now = Utc::now();
+
+        let provider = IdentityProvider {
+            company_id,
+            issuer: dto.issuer.trim_end_matches('/').to_string(),
+            client_id: dto.client_id,
+            client_secret: dto
+                .client_secret
+                .or_else(|| existing.as_ref().and_then(|p| p.client_secret.clone())),
+            scopes,
+            role_claim: dto
+                .role_claim
+                .unwrap_or_else(|| DEFAULT_ROLE_CLAIM.to_string()),
+            role_mapping: dto.role_mapping,
+            default_role: dto.default_role,
+            auto_provision: dto.auto_provision,
+            enabled: dto.enabled,
+            created_at: existing.as_ref().map(|p| p.created_at).unwrap_or(now),
+            updated_at: now,
+        };
+
+        let provider = match existing {
+            Some(_) => self.identity_provider_repo.update(provider).await?,
+            None => self.identity_provider_repo.create(provider).await?,
+        };
+
+        info!(company_id = %company_id, issuer = %provider.issuer, "Identity provider configured");
+
+        Ok(provider)
+    }
+
+    pub async fn delete_provider(
+        &self,
+        requester: &User,
+        company_id: Uuid,
+    ) -> Result<IdentityProvider> {
+        let provider = self.get_provider(requester, company_id).await?;
+
+        self.identity_provider_repo.delete(company_id).await?;
+
+        info!(company_id = %company_id, "Identity provider removed");
+
+        Ok(provider)
+    }
+
+    /// Starts a login: stores the state, nonce and PKCE verifier, and returns the
+    /// URL of the identity provider the user has to be redirected to
+    pub async fn authorize(&self, company_id: Uuid) -> Result<String> {
+        let provider = self.get_enabled_provider(company_id).await?;
+        let redirect_url = self.redirect_url()?;
+
+        let state = self.opaque_token_generator.generate();
+        let nonce = self.opaque_token_generator.generate();
+        let code_verifier = self.opaque_token_generator.generate();
+        let now = Utc::now();
+
+        let url = self
+            .oidc_client
+            .authorization_url(&provider, redirect_url, &state, &nonce, &code_verifier)
+            .await?;
+
+        self.login_state_repo
+            .create(OidcLoginState {
+                id: Uuid::new_v4(),
+                state_hash: self.opaque_token_generator.hash(&state),
+                company_id,
+                nonce,
+                code_verifier,
+                expires_at: now + Duration::seconds(self.state_ttl_seconds),
+                used_at: None,
+                created_at: now,
+            })
+            .await?;
+
+        Ok(url)
+    }
+
+    /// Completes a login with the code returned by the identity provider.
+    /// The provider is trusted for every factor, so no TOTP challenge follows.
+    pub async fn callback(
+        &self,
+        dto: SsoCallbackDto,
+        client: ClientInfoDto,
+    ) -> Result<AuthTokensDto> {
+        let login_state = self
+            .login_state_repo
+            .get_by_state_hash(&self.opaque_token_generator.hash(&dto.state))
+            .await?
+            .filter(|state| state.is_usable())
+            .ok_or_else(|| AppError::AuthError("Invalid or expired login state".to_string()))?;
+
+        if !self.login_state_repo.mark_used(login_state.id).await? {
+            return Err(AppError::AuthError(
+                "Invalid or expired login state".to_string(),
+            ));
+        }
+
+        let provider = self.get_enabled_provider(login_state.company_id).await?;
+
+        let claims = self
+            .oidc_client
+            .exchange_code(
+                &provider,
+                &dto.code,
+                self.redirect_url()?,
+                &login_state.code_verifier,
+                &login_state.nonce,
+            )
+            .await?;
+
+        if claims.subject.is_empty() {
+            return Err(AppError::AuthError("ID token without subject".to_string()));
+        }
+
+        let role = self.resolve_role(&provider, &claims).await?;
+        let user = self.resolve_user(&provider, &claims, role).await?;
+
+        info!(user_id = %user.id, company_id = %provider.company_id, "Single sign-on login");
+
+        self.auth_service.login_external(&user, client).await
+    }
+
+    /// Highest SPL role mapped from the claim values, otherwise the default role
+    async fn resolve_role(&self, provider: &IdentityProvider, claims: &OidcClaims) -> Result<Role> {
+        let mut role: Option<Role> = None;
+
+        let role_names = claims
+            .roles
+            .iter()
+            .filter_map(|value| provider.role_mapping.get(value));
+
+        for name in role_names {
+            if let Some(candidate) = self.role_repo.get_by_name(name).await? {
+                if role.as_ref().is_none_or(|current| candidate > *current) {
+                    role = Some(candidate);
+                }
+            }
+        }
+
+        if let Some(role) = role {
+            return Ok(role);
+        }
+
+        match &provider.default_role {
+            Some(name) => self
+                .role_repo
+                .get_by_name(name)
+                .await?
+                .ok_or_else(|| AppError::Unknown(format!("Role '{}' not found", name))),
+            None => {
+                warn!(company_id = %provider.company_id, subject = %claims.subject, "Single sign-on user without mapped role");
+                Err(AppError::Forbidden)
+            }
+        }
+    }
+
+    /// Finds the user linked to the external identity, links an existing user with
+    /// the same verified email, or provisions a new one. The role follows the claims.
+    async fn resolve_user(
+        &self,
+        provider: &IdentityProvider,
+        claims: &OidcClaims,
+        role: Role,
+    ) -> Result<User> {
+        let linked = match self
+            .user_identity_repo
+            .get_by_subject(&provider.issuer, &claims.subject)
+            .await?
+        {
+            Some(identity) => self.user_repo.get_by_id(identity.user_id).await?,
+            None => None,
+        };
+
+        let user = match linked {
+            Some(user) => user,
+            None => {
+                let user = match self.find_by_verified_email(provider, claims).await? {
+                    Some(user) => user,
+                    None if provider.auto_provision => {
+                        self.provision_user(provider, claims, role.clone()).await?
+                    }
+                    None => {
+                        return Err(AppError::AuthError(
+                            "No account linked to this identity".to_string(),
+                        ))
+                    }
+                };
+
+                self.user_identity_repo
+                    .create(UserIdentity {
+                        id: Uuid::new_v4(),
+                        user_id: user.id,
+                        issuer: provider.issuer.clone(),
+                        subject: claims.subject.clone(),
+                        email: claims.email.clone(),
+                        created_at: Utc::now(),
+                    })
+                    .await?;
+
+                info!(user_id = %user.id, issuer = %provider.issuer, "External identity linked");
+
+                user
+            }
+        };
+
+        if user.company.as_ref().map(|c| c.id) != Some(provider.company_id) {
+            return Err(AppError::Forbidden);
+        }
+
+        if user.role.id == role.id {
+            return Ok(user);
+        }
+
+        let user = self
+            .user_repo
+            .update(User {
+                role,
+                updated_at: Utc::now(),
+                ..user
+            })
+            .await?;
+
+        self.user_cache.invalidate(user.id).await;
+
+        Ok(user)
+    }
+
+    async fn find_by_verified_email(
+        &self,
+        provider: &IdentityProvider,
+        claims: &OidcClaims,
+    ) -> Result<Option<User>> {
+        match &claims.email {
+            Some(email) if claims.email_verified => {
+                self.user_repo
+                    .get_by_username_or_email_and_company(
+                        None,
+                        Some(email.clone()),
+                        Some(provider.company_id),
+                    )
+                    .await
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// New user of the company. It gets a random password nobody knows, so it can
+    /// only log in through the identity provider until a password is reset.
+    async fn provision_user(
+        &self,
+        provider: &IdentityProvider,
+        claims: &OidcClaims,
+        role: Role,
+    ) -> Result<User> {
+        let company = self
+            .company_repo
+            .get_by_id(provider.company_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Company not found".to_string()))?;
+
+        let base = claims
+            .preferred_username
+            .clone()
+            .or_else(|| {
+                claims
+                    .email
+                    .as_ref()
+                    .and_then(|email| email.split('@').next().map(str::to_string))
+            })
+            .filter(|name| !name.is_empty())
+            .unwrap_or_else(|| format!("sso-{}", claims.subject));
+
+        let mut username = base.clone();
+        let mut suffix = 1;
+        while self
+            .user_repo
+            .get_by_username_and_company(&username, Some(company.id))
+            .await?
+            .is_some()
+        {
+            suffix += 1;
+            username = format!("{}{}", base, suffix);
+        }
+
+        let now = Utc::now();
+        let password_hash = self
+            .password_encoder
+            .hash(&self.opaque_token_generator.generate())?;
+
+        let user = self
+            .user_repo
+            .create(User {
+                id: Uuid::new_v4(),
+                username,
+                email: claims.email.clone().filter(|_| claims.email_verified),
+                // Only addresses verified by the identity provider are kept
+                email_verified_at: claims
+                    .email
+                    .as_ref()
+                    .filter(|_| claims.email_verified)
+                    .map(|_| now),
+                password_hash,
+                name: claims.given_name.clone(),
+                surname: claims.family_name.clone(),
+                role,
+                company: Some(company),
+                deactivated_at: None,
+                created_at: now,
+                updated_at: now,
+            })
+            .await?;
+
+        info!(user_id = %user.id, company_id = %provider.company_id, "User provisioned through single sign-on");
+
+        Ok(user)
+    }
+
+    async fn get_enabled_provider(&self, company_id: Uuid) -> Result<IdentityProvider> {
+        self.identity_provider_repo
+            .get_by_id(company_id)
+            .await?
+            .filter(|provider| provider.enabled)
+            .ok_or_else(|| {
+                AppError::NotFound("Single sign-on is not enabled for this company".to_string())
+            })
+    }
+
+    fn redirect_url(&self) -> Result<&str> {
+        self.redirect_url.as_deref().ok_or_else(|| {
+            AppError::ValidationError("Single sign-on redirect URL is not configured".to_string())
+        })
+    }
+}
@@ -0,0 +1,218 @@
+use crate::dtos::team::{CreateTeamDto, SetTeamMemberDto, TeamDetailsDto, UpdateTeamDto};
+use crate::services::access_control::AccessControlService;
+use crate::services::policy::Resource;
+use chrono::Utc;
+use spl_domain::entities::team::{Team, TeamMember};
+use spl_domain::entities::user::{permissions, User};
+use spl_domain::ports::repositories::plot::PlotRepository;
+use spl_domain::ports::repositories::team::TeamRepository;
+use spl_domain::ports::repositories::user::UserRepository;
+use spl_shared::error::{AppError, Result};
+use spl_shared::traits::IntoWithContext;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Teams group users and plots of a company. Supervisors of a team only see its members
+/// and plots with permissions granted with the `team` scope.
+pub struct TeamService {
+    team_repo: Arc<dyn TeamRepository>,
+    user_repo: Arc<dyn UserRepository>,
+    plot_repo: Arc<dyn PlotRepository>,
+    access_control: Arc<AccessControlService>,
+}
+
+impl TeamService {
+    pub fn new(
+        team_repo: Arc<dyn TeamRepository>,
+        user_repo: Arc<dyn UserRepository>,
+        plot_repo: Arc<dyn PlotRepository>,
+        access_control: Arc<AccessControlService>,
+    ) -> Self {
+        Self {
+            team_repo,
+            user_repo,
+            plot_repo,
+            access_control,
+        }
+    }
+
+    pub async fn create(&self, requester: &User, dto: CreateTeamDto) -> Result<Team> {
+        self.ensure_can_manage(requester, dto.company_id).await?;
+        self.ensure_name_available(dto.company_id, &dto.name, None)
+            .await?;
+
+        self.team_repo.create(dto.into()).await
+    }
+
+    pub async fn update(&self, requester: &User, id: Uuid, dto: UpdateTeamDto) -> Result<Team> {
+        let team = self.get_team(id).await?;
+        self.ensure_can_manage(requester, team.company_id).await?;
+
+        if let Some(name) = &dto.name {
+            self.ensure_name_available(team.company_id, name, Some(id))
+                .await?;
+        }
+
+        let updated = dto.into_with_context(team)?;
+        self.team_repo.update(updated).await
+    }
+
+    pub async fn delete(&self, requester: &User, id: Uuid) -> Result<Team> {
+        let team = self.get_team(id).await?;
+        self.ensure_can_manage(requester, team.company_id).await?;
+
+        self.team_repo.delete(id).await
+    }
+
+    /// Teams of the company, only those they supervise for supervisors of teams
+    pub async fn get_by_company(&self, requester: &User, company_id: Uuid) -> Result<Vec<Team>> {
+        self.ensure_can_read(requester, company_id).await?;
+
+        let teams = self.team_repo.get_by_company_id(company_id).await?;
+
+        match self.get_supervised_team_ids(requester).await? {
+            Some(ids) => Ok(teams.into_iter().filter(|t| ids.contains(&t.id)).collect()),
+            None => Ok(teams),
+        }
+    }
+
+    pub async fn get_details(&self, requester: &User, id: Uuid) -> Result<TeamDetailsDto> {
+        let team = self.get_team(id).await?;
+        self.ensure_can_read(requester, team.company_id).await?;
+
+        if let Some(ids) = self.get_supervised_team_ids(requester).await? {
+            if !ids.contains(&id) {
+                return Err(AppError::NotFound("Team not found".to_string()));
+            }
+        }
+
+        let (members, plot_ids) = tokio::try_join!(
+            self.team_repo.get_members(vec![id]),
+            self.team_repo.get_plot_ids(vec![id]),
+        )?;
+
+        Ok(TeamDetailsDto {
+            team,
+            members,
+            plot_ids,
+        })
+    }
+
+    /// Adds a user of the company to the team, or changes their role in it
+    pub async fn set_member(
+        &self,
+        requester: &User,
+        team_id: Uuid,
+        dto: SetTeamMemberDto,
+    ) -> Result<TeamMember> {
+        let team = self.get_team(team_id).await?;
+        self.ensure_can_manage(requester, team.company_id).await?;
+
+        let user = self
+            .user_repo
+            .get_by_id(dto.user_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
+        if user.company.as_ref().map(|c| c.id) != Some(team.company_id) {
+            return Err(AppError::ValidationError(
+                "User does not belong to the company of the team".to_string(),
+            ));
+        }
+
+        self.team_repo
+            .set_member(TeamMember {
+                team_id,
+                user_id: user.id,
+                role: dto.role,
+                created_at: Utc::now(),
+            })
+            .await
+    }
+
+    pub async fn remove_member(
+        &self,
+        requester: &User,
+        team_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<()> {
+        let team = self.get_team(team_id).await?;
+        self.ensure_can_manage(requester, team.company_id).await?;
+
+        self.team_repo.remove_member(team_id, user_id).await
+    }
+
+    /// Assigns a plot of the company to the team
+    pub async fn add_plot(&self, requester: &User, team_id: Uuid, plot_id: Uuid) -> Result<()> {
+        let team = self.get_team(team_id).await?;
+        self.ensure_can_manage(requester, team.company_id).await?;
+
+        self.plot_repo
+            .get_by_company_id_and_id(team.company_id, plot_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Plot not found".to_string()))?;
+
+        self.team_repo.add_plot(team_id, plot_id).await
+    }
+
+    pub async fn remove_plot(&self, requester: &User, team_id: Uuid, plot_id: Uuid) -> Result<()> {
+        let team = self.get_team(team_id).await?;
+        self.ensure_can_manage(requester, team.company_id).await?;
+
+        self.team_repo.remove_plot(team_id, plot_id).await
+    }
+
+    async fn get_team(&self, id: Uuid) -> Result<Team> {
+        self.team_repo
+            .get_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Team not found".to_string()))
+    }
+
+    async fn ensure_name_available(
+        &self,
+        company_id: Uuid,
+        name: &str,
+        except: Option<Uuid>,
+    ) -> Result<()> {
+        let taken = self
+            .team_repo
+            .get_by_company_id(company_id)
+            .await?
+            .iter()
+            .any(|t| t.name.eq_ignore_ascii_case(name) && Some(t.id) != except);
+
+        if taken {
+            return Err(AppError::Conflict("Team name already in use".to_string()));
+        }
+
+        Ok(())
+    }
+
+    async fn get_supervised_team_ids(&self, requester: &User) -> Result<Option<Vec<Uuid>>> {
+        self.access_control
+            .get_supervised_team_ids(requester, permissions::USERS_READ)
+            .await
+    }
+
+    async fn ensure_can_read(&self, requester: &User, company_id: Uuid) -> Result<()> {
+        self.access_control
+            .policy()
+            .ensure(
+                requester,
+                permissions::USERS_READ,
+                Resource::Company(company_id),
+            )
+            .await
+    }
+
+    async fn ensure_can_manage(&self, requester: &User, company_id: Uuid) -> Result<()> {
+        self.access_control
+            .policy()
+            .ensure(
+                requester,
+                permissions::TEAMS_MANAGE,
+                Resource::Company(company_id),
+            )
+            .await
+    }
+}
@@ -0,0 +1,295 @@
+use crate::dtos::auth::{TwoFactorChallengeDto, TwoFactorSetupDto, TwoFactorStatusDto};
+use chrono::{Duration, Utc};
+use spl_domain::entities::auth::{RecoveryCode, TwoFactor, TwoFactorChallenge};
+use spl_domain::entities::user::User;
+use spl_domain::ports::auth::{OpaqueTokenGenerator, TwoFactorProvider};
+use spl_domain::ports::repositories::auth::{
+    RecoveryCodeRepository, TwoFactorChallengeRepository, TwoFactorRepository,
+};
+use spl_shared::error::{AppError, Result};
+use std::sync::Arc;
+use tracing::info;
+use uuid::Uuid;
+
+/// Lowest role level (supervisor) allowed to enroll a second factor
+pub const MIN_TWO_FACTOR_LEVEL: i16 = 50;
+
+const RECOVERY_CODE_COUNT: usize = 10;
+
+pub struct TwoFactorService {
+    two_factor_repo: Arc<dyn TwoFactorRepository>,
+    recovery_code_repo: Arc<dyn RecoveryCodeRepository>,
+    challenge_repo: Arc<dyn TwoFactorChallengeRepository>,
+    provider: Arc<dyn TwoFactorProvider>,
+    opaque_token_generator: Arc<dyn OpaqueTokenGenerator>,
+    challenge_ttl_seconds: i64,
+}
+
+impl TwoFactorService {
+    pub fn new(
+        two_factor_repo: Arc<dyn TwoFactorRepository>,
+        recovery_code_repo: Arc<dyn RecoveryCodeRepository>,
+        challenge_repo: Arc<dyn TwoFactorChallengeRepository>,
+        provider: Arc<dyn TwoFactorProvider>,
+        opaque_token_generator: Arc<dyn OpaqueTokenGenerator>,
+        challenge_ttl_seconds: i64,
+    ) -> Self {
+        Self {
+            two_factor_repo,
+            recovery_code_repo,
+            challenge_repo,
+            provider,
+            opaque_token_generator,
+            challenge_ttl_seconds,
+        }
+    }
+
+    /// Whether the company of the user makes a second factor mandatory for its role
+    pub fn is_required(user: &User) -> bool {
+        user.company
+            .as_ref()
+            .is_some_and(|company| company.requires_two_factor(user.role.level))
+    }
+
+    pub async fn get_status(&self, user: &User) -> Result<TwoFactorStatusDto> {
+        let enabled = self
+            .two_factor_repo
+            .get_by_id(user.id)
+            .await?
+            .is_some_and(|two_factor| two_factor.is_enabled());
+
+        let recovery_codes_remaining = if enabled {
+            self.recovery_code_repo.count_unused(user.id).await?
+        } else {
+            0
+        };
+
+        Ok(TwoFactorStatusDto {
+            enabled,
+            required: Self::is_required(user),
+            recovery_codes_remaining,
+        })
+    }
+
+    /// Creates a new secret and recovery codes. The second factor is not enforced
+    /// until the user confirms it with a valid code.
+    pub async fn start_enrollment(&self, user: &User) -> Result<TwoFactorSetupDto> {
+        if user.role.level < MIN_TWO_FACTOR_LEVEL {
+            return Err(AppError::Forbidden);
+        }
+
+        if let Some(existing) = self.two_factor_repo.get_by_id(user.id).await? {
+            if existing.is_enabled() {
+                return Err(AppError::Conflict(
+                    "Two-factor authentication is already enabled".to_string(),
+                ));
+            }
+
+            // Restarting an unfinished enrollment replaces its secret
+            self.two_factor_repo.delete(user.id).await?;
+        }
+
+        let secret = self.provider.generate_secret();
+        let provisioning_uri = self.provider.provisioning_uri(&secret, &user.username)?;
+
+        self.two_factor_repo
+            .create(TwoFactor {
+                user_id: user.id,
+                secret: secret.clone(),
+                enabled_at: None,
+                created_at: Utc::now(),
+            })
+            .await?;
+
+        let recovery_codes = self.replace_recovery_codes(user.id).await?;
+
+        Ok(TwoFactorSetupDto {
+            secret,
+            provisioning_uri,
+            recovery_codes,
+        })
+    }
+
+    pub async fn confirm_enrollment(&self, user: &User, code: &str) -> Result<()> {
+        let two_factor = self
+            .two_factor_repo
+            .get_by_id(user.id)
+            .await?
+            .ok_or_else(|| {
+                AppError::ValidationError("Two-factor enrollment has not been started".to_string())
+            })?;
+
+        if two_factor.is_enabled() {
+            return Err(AppError::Conflict(
+                "Two-factor authentication is already enabled".to_string(),
+            ));
+        }
+
+        if !self.provider.verify(&two_factor.secret, code)? {
+            return Err(AppError::ValidationError(
+                "Invalid two-factor code".to_string(),
+            ));
+        }
+
+        self.two_factor_repo.enable(user.id).await?;
+        info!(user_id = %user.id, "Two-factor authentication enabled");
+
+        Ok(())
+    }
+
+    /// Removes the second factor. Requires a valid code and is refused when mandatory.
+    pub async fn disable(&self, user: &User, code: &str) -> Result<()> {
+        if Self::is_required(user) {
+            return Err(AppError::ValidationError(
+                "Two-factor authentication is mandatory for your role".to_string(),
+            ));
+        }
+
+        let two_factor = self
+            .two_factor_repo
+            .get_by_id(user.id)
+            .await?
+            .filter(TwoFactor::is_enabled)
+            .ok_or_else(|| {
+                AppError::NotFound("Two-factor authentication is not enabled".to_string())
+            })?;
+
+        if !self.verify_enabled(&two_factor, code).await? {
+            return Err(AppError::AuthError("Invalid two-factor code".to_string()));
+        }
+
+        self.recovery_code_repo.delete_by_user_id(user.id).await?;
+        self.two_factor_repo.delete(user.id).await?;
+        info!(user_id = %user.id, "Two-factor authentication disabled");
+
+        Ok(())
+    }
+
+    /// Issues a login challenge when the user has, or must have, a second factor
+    pub async fn create_challenge(&self, user: &User) -> Result<Option<TwoFactorChallengeDto>> {
+        let enabled = self
+            .two_factor_repo
+            .get_by_id(user.id)
+            .await?
+            .is_some_and(|two_factor| two_factor.is_enabled());
+
+        if !enabled && !Self::is_required(user) {
+            return Ok(None);
+        }
+
+        let challenge_token = self.opaque_token_generator.generate();
+        let now = Utc::now();
+
+        self.challenge_repo
+            .create(TwoFactorChallenge {
+                id: Uuid::new_v4(),
+                user_id: user.id,
+                token_hash: self.opaque_token_generator.hash(&challenge_token),
+                expires_at: now + Duration::seconds(self.challenge_ttl_seconds),
+                used_at: None,
+                created_at: now,
+            })
+            .await?;
+
+        Ok(Some(TwoFactorChallengeDto {
+            challenge_token,
+            expires_in: self.challenge_ttl_seconds,
+            setup_required: !enabled,
+        }))
+    }
+
+    pub async fn get_challenge(&self, challenge_token: &str) -> Result<TwoFactorChallenge> {
+        let token_hash = self.opaque_token_generator.hash(challenge_token);
+
+        self.challenge_repo
+            .get_by_token_hash(&token_hash)
+            .await?
+            .filter(TwoFactorChallenge::is_usable)
+            .ok_or_else(|| {
+                AppError::AuthError("Invalid or expired two-factor challenge".to_string())
+            })
+    }
+
+    pub async fn consume_challenge(&self, challenge: &TwoFactorChallenge) -> Result<()> {
+        if !self.challenge_repo.mark_used(challenge.id).await? {
+            return Err(AppError::AuthError(
+                "Invalid or expired two-factor challenge".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Checks the second factor presented at login. Users that must enroll
+    /// complete their pending enrollment with their first valid code.
+    pub async fn verify_login_code(&self, user: &User, code: &str) -> Result<bool> {
+        let Some(two_factor) = self.two_factor_repo.get_by_id(user.id).await? else {
+            return Ok(false);
+        };
+
+        if two_factor.is_enabled() {
+            return self.verify_enabled(&two_factor, code).await;
+        }
+
+        if !Self::is_required(user) || !self.provider.verify(&two_factor.secret, code)? {
+            return Ok(false);
+        }
+
+        self.two_factor_repo.enable(user.id).await?;
+        info!(user_id = %user.id, "Mandatory two-factor authentication enabled");
+
+        Ok(true)
+    }
+
+    /// Accepts a TOTP code or an unused recovery code
+    async fn verify_enabled(&self, two_factor: &TwoFactor, code: &str) -> Result<bool> {
+        if self.provider.verify(&two_factor.secret, code)? {
+            return Ok(true);
+        }
+
+        let code_hash = self
+            .opaque_token_generator
+            .hash(&code.trim().to_ascii_uppercase());
+
+        let Some(recovery_code) = self
+            .recovery_code_repo
+            .get_by_code_hash(two_factor.user_id, &code_hash)
+            .await?
+        else {
+            return Ok(false);
+        };
+
+        if recovery_code.used_at.is_some()
+            || !self.recovery_code_repo.mark_used(recovery_code.id).await?
+        {
+            return Ok(false);
+        }
+
+        info!(user_id = %two_factor.user_id, "Two-factor recovery code used");
+
+        Ok(true)
+    }
+
+    async fn replace_recovery_codes(&self, user_id: Uuid) -> Result<Vec<String>> {
+        self.recovery_code_repo.delete_by_user_id(user_id).await?;
+
+        let mut codes = Vec::with_capacity(RECOVERY_CODE_COUNT);
+        for _ in 0..RECOVERY_CODE_COUNT {
+            let code = self.provider.generate_recovery_code();
+
+            self.recovery_code_repo
+                .create(RecoveryCode {
+                    id: Uuid::new_v4(),
+                    user_id,
+                    code_hash: self.opaque_token_generator.hash(&code),
+                    used_at: None,
+                    created_at: Utc::now(),
+                })
+                .await?;
+
+            codes.push(code);
+        }
+
+        Ok(codes)
+    }
+}
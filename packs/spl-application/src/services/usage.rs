@@ -0,0 +1,310 @@
+use crate::dtos::usage::{UpdateCompanyQuotaDto, UsageReport, UserUsageReport};
+use crate::services::access_control::AccessControlService;
+use chrono::{NaiveDate, Utc};
+use spl_domain::entities::usage::{
+    period_end, period_of, CompanyQuota, QuotaEnforcement, Usage, UsageQuota, UsageStatus,
+};
+use spl_domain::entities::user::{permissions, User};
+use spl_domain::ports::repositories::company::CompanyRepository;
+use spl_domain::ports::repositories::usage::{CompanyQuotaRepository, UsageRepository};
+use spl_shared::error::{AppError, Result};
+use std::sync::Arc;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+/// Prediction counted before it is made, in the company the user is acting in
+#[derive(Debug, Clone, PartialEq)]
+pub struct UsageReservation {
+    pub user_id: Uuid,
+    pub company_id: Option<Uuid>,
+    pub period: NaiveDate,
+    /// Bytes counted so far, the size of the upload until the prediction is recorded
+    pub stored_bytes: u64,
+}
+
+/// Monthly predictions and stored bytes of each user and company, checked against the quota
+/// of the server or the one an admin set for the company
+pub struct UsageService {
+    usage_repo: Arc<dyn UsageRepository>,
+    quota_repo: Arc<dyn CompanyQuotaRepository>,
+    company_repo: Arc<dyn CompanyRepository>,
+    access_control: Arc<AccessControlService>,
+    server_quota: UsageQuota,
+}
+
+impl UsageService {
+    pub fn new(
+        usage_repo: Arc<dyn UsageRepository>,
+        quota_repo: Arc<dyn CompanyQuotaRepository>,
+        company_repo: Arc<dyn CompanyRepository>,
+        access_control: Arc<AccessControlService>,
+        server_quota: UsageQuota,
+    ) -> Self {
+        Self {
+            usage_repo,
+            quota_repo,
+            company_repo,
+            access_control,
+            server_quota,
+        }
+    }
+
+    /// Quota of the company, or the server one for users without company
+    pub async fn quota_for(&self, company_id: Option<Uuid>) -> Result<UsageQuota> {
+        let Some(company_id) = company_id else {
+            return Ok(self.server_quota.clone());
+        };
+
+        Ok(match self.quota_repo.get_by_id(company_id).await? {
+            Some(company) => company.quota,
+            None => self.server_quota.clone(),
+        })
+    }
+
+    /// Counts one more prediction of `upload_bytes` for the user if it fits in the quota, so
+    /// concurrent predictions cannot go over it together. With soft enforcement exhausted
+    /// quotas are only logged. The reservation is released when the prediction fails.
+    pub async fn reserve_prediction(
+        &self,
+        user: &User,
+        upload_bytes: u64,
+    ) -> Result<UsageReservation> {
+        let reservation = UsageReservation {
+            user_id: user.id,
+            company_id: user.company.as_ref().map(|c| c.id),
+            period: period_of(Utc::now()),
+            stored_bytes: upload_bytes,
+        };
+        let quota = self.quota_for(reservation.company_id).await?;
+
+        let exhausted = self
+            .usage_repo
+            .reserve(
+                reservation.user_id,
+                reservation.company_id,
+                reservation.period,
+                upload_bytes,
+                &quota,
+            )
+            .await?;
+
+        let Some(limit) = exhausted else {
+            return Ok(reservation);
+        };
+
+        match quota.enforcement {
+            QuotaEnforcement::Hard => Err(AppError::QuotaExceeded {
+                message: limit.message().to_string(),
+                resets_at: period_end(reservation.period),
+            }),
+            QuotaEnforcement::Soft => {
+                warn!(
+                    user_id = %user.id,
+                    company_id = ?reservation.company_id,
+                    "{}",
+                    limit.message()
+                );
+                self.usage_repo
+                    .increment(
+                        reservation.user_id,
+                        reservation.company_id,
+                        reservation.period,
+                        1,
+                        upload_bytes,
+                    )
+                    .await?;
+                Ok(reservation)
+            }
+        }
+    }
+
+    /// Settles a reserved prediction with the bytes it actually stored
+    pub async fn record_prediction(
+        &self,
+        reservation: UsageReservation,
+        stored_bytes: u64,
+    ) -> Result<()> {
+        if stored_bytes > reservation.stored_bytes {
+            self.usage_repo
+                .increment(
+                    reservation.user_id,
+                    reservation.company_id,
+                    reservation.period,
+                    0,
+                    stored_bytes - reservation.stored_bytes,
+                )
+                .await
+        } else if stored_bytes < reservation.stored_bytes {
+            self.usage_repo
+                .release(
+                    reservation.user_id,
+                    reservation.period,
+                    0,
+                    reservation.stored_bytes - stored_bytes,
+                )
+                .await
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Takes back a reservation of a prediction that failed
+    pub async fn release(&self, reservation: UsageReservation) -> Result<()> {
+        self.usage_repo
+            .release(
+                reservation.user_id,
+                reservation.period,
+                1,
+                reservation.stored_bytes,
+            )
+            .await
+    }
+
+    /// Worst status of the user and its company this month
+    pub async fn status_for(&self, user: &User) -> Result<UsageStatus> {
+        let period = period_of(Utc::now());
+        let company_id = user.company.as_ref().map(|c| c.id);
+        let quota = self.quota_for(company_id).await?;
+
+        let mut status = quota.user_status(&self.user_usage(user.id, period).await?);
+        if let Some(company_id) = company_id {
+            let usage = self.company_usage(company_id, period).await?;
+            status = status.max(quota.company_status(&usage));
+        }
+
+        Ok(status)
+    }
+
+    /// Usage of a company in the month starting at `period`, the current one when missing
+    pub async fn report(
+        &self,
+        requester: &User,
+        company_id: Uuid,
+        period: Option<NaiveDate>,
+    ) -> Result<UsageReport> {
+        self.access_control
+            .validate_company_management_access(requester, permissions::USAGE_READ, company_id)
+            .await?;
+
+        self.ensure_company_exists(company_id).await?;
+
+        let period = period.unwrap_or_else(|| period_of(Utc::now()));
+        let quota = self.quota_for(Some(company_id)).await?;
+        let records = self.usage_repo.get_by_company(company_id, period).await?;
+        let usage = Usage::of(&records);
+
+        let users = records
+            .iter()
+            .map(|record| UserUsageReport {
+                user_id: record.user_id,
+                usage: record.usage(),
+                status: quota.user_status(&record.usage()),
+            })
+            .collect();
+
+        Ok(UsageReport {
+            company_id,
+            period,
+            resets_at: period_end(period),
+            status: quota.company_status(&usage),
+            usage,
+            quota,
+            users,
+        })
+    }
+
+    /// Creates or replaces the quota of a company
+    pub async fn set_company_quota(
+        &self,
+        requester: &User,
+        company_id: Uuid,
+        dto: UpdateCompanyQuotaDto,
+    ) -> Result<CompanyQuota> {
+        self.ensure_can_manage(requester, company_id).await?;
+        self.ensure_company_exists(company_id).await?;
+
+        let enforcement = match dto.enforcement {
+            Some(name) => QuotaEnforcement::parse(&name.to_lowercase()).ok_or_else(|| {
+                AppError::ValidationError(format!("Unknown quota enforcement: {}", name))
+            })?,
+            None => self.server_quota.enforcement,
+        };
+
+        let existing = self.quota_repo.get_by_id(company_id).await?;
+        let now = Utc::now();
+
+        let company_quota = CompanyQuota {
+            company_id,
+            quota: UsageQuota {
+                predictions_per_month: dto.predictions_per_month,
+                predictions_per_user_per_month: dto.predictions_per_user_per_month,
+                stored_bytes_per_month: dto.stored_bytes_per_month,
+                warning_ratio: dto.warning_ratio.unwrap_or(self.server_quota.warning_ratio),
+                enforcement,
+            },
+            created_at: existing.as_ref().map(|q| q.created_at).unwrap_or(now),
+            updated_at: now,
+        };
+
+        let company_quota = match existing {
+            Some(_) => self.quota_repo.update(company_quota).await?,
+            None => self.quota_repo.create(company_quota).await?,
+        };
+
+        info!(company_id = %company_id, updated_by = %requester.id, "Company quota configured");
+
+        Ok(company_quota)
+    }
+
+    /// Removes the quota of a company, which follows the server one again
+    pub async fn delete_company_quota(
+        &self,
+        requester: &User,
+        company_id: Uuid,
+    ) -> Result<CompanyQuota> {
+        self.ensure_can_manage(requester, company_id).await?;
+
+        let quota = self
+            .quota_repo
+            .get_by_id(company_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Company quota not found".to_string()))?;
+
+        self.quota_repo.delete(company_id).await?;
+
+        info!(company_id = %company_id, deleted_by = %requester.id, "Company quota removed");
+
+        Ok(quota)
+    }
+
+    async fn user_usage(&self, user_id: Uuid, period: NaiveDate) -> Result<Usage> {
+        Ok(self
+            .usage_repo
+            .get_by_user(user_id, period)
+            .await?
+            .map(|record| record.usage())
+            .unwrap_or_default())
+    }
+
+    async fn company_usage(&self, company_id: Uuid, period: NaiveDate) -> Result<Usage> {
+        let records = self.usage_repo.get_by_company(company_id, period).await?;
+        Ok(Usage::of(&records))
+    }
+
+    async fn ensure_company_exists(&self, company_id: Uuid) -> Result<()> {
+        if self.company_repo.get_by_id(company_id).await?.is_none() {
+            return Err(AppError::NotFound("Company not found".to_string()));
+        }
+        Ok(())
+    }
+
+    async fn ensure_can_manage(&self, requester: &User, company_id: Uuid) -> Result<()> {
+        self.access_control
+            .validate_company_management_access(
+                requester,
+                permissions::COMPANIES_MANAGE,
+                company_id,
+            )
+            .await
+    }
+}
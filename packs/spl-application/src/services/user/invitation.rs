@@ -0,0 +1,357 @@
+use crate::dtos::user::{AcceptInvitationDto, CreateInvitationDto};
+use crate::services::access_control::AccessControlService;
+use crate::services::password_policy::PasswordPolicyService;
+use chrono::{Duration, Utc};
+use spl_domain::entities::company::Company;
+use spl_domain::entities::user::{permissions, Invitation, PermissionScope, Role, User};
+use spl_domain::ports::auth::{OpaqueTokenGenerator, PasswordEncoder};
+use spl_domain::ports::mailer::{EmailMessage, Mailer};
+use spl_domain::ports::repositories::company::CompanyRepository;
+use spl_domain::ports::repositories::user::{InvitationRepository, RoleRepository, UserRepository};
+use spl_shared::error::{AppError, Result};
+use std::sync::Arc;
+use tracing::info;
+use uuid::Uuid;
+
+/// Invitations of new users into a company. Users with `users:manage` invite roles below
+/// their own into their company, or any company-bound role into any company with `any` scope.
+pub struct InvitationService {
+    invitation_repo: Arc<dyn InvitationRepository>,
+    user_repo: Arc<dyn UserRepository>,
+    role_repo: Arc<dyn RoleRepository>,
+    company_repo: Arc<dyn CompanyRepository>,
+    password_encoder: Arc<dyn PasswordEncoder>,
+    opaque_token_generator: Arc<dyn OpaqueTokenGenerator>,
+    mailer: Arc<dyn Mailer>,
+    access_control: Arc<AccessControlService>,
+    password_policy: Arc<PasswordPolicyService>,
+    frontend_url: Option<String>,
+    invitation_ttl_hours: i64,
+}
+
+impl InvitationService {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        invitation_repo: Arc<dyn InvitationRepository>,
+        user_repo: Arc<dyn UserRepository>,
+        role_repo: Arc<dyn RoleRepository>,
+        company_repo: Arc<dyn CompanyRepository>,
+        password_encoder: Arc<dyn PasswordEncoder>,
+        opaque_token_generator: Arc<dyn OpaqueTokenGenerator>,
+        mailer: Arc<dyn Mailer>,
+        access_control: Arc<AccessControlService>,
+        password_policy: Arc<PasswordPolicyService>,
+        frontend_url: Option<String>,
+        invitation_ttl_hours: i64,
+    ) -> Self {
+        Self {
+            invitation_repo,
+            user_repo,
+            role_repo,
+            company_repo,
+            password_encoder,
+            opaque_token_generator,
+            mailer,
+            access_control,
+            password_policy,
+            frontend_url,
+            invitation_ttl_hours,
+        }
+    }
+
+    /// Creates the invitation and emails the signup link to the invitee
+    pub async fn create(&self, requester: &User, dto: CreateInvitationDto) -> Result<Invitation> {
+        let company_id = self
+            .access_control
+            .validate_company_access(requester, permissions::USERS_MANAGE, dto.company_id)
+            .await?;
+        self.access_control
+            .validate_company_management_access(requester, permissions::USERS_MANAGE, company_id)
+            .await?;
+
+        let company = self
+            .company_repo
+            .get_by_id(company_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Company not found".to_string()))?;
+
+        let role_name = dto.role.as_deref().unwrap_or("user");
+        let role = self
+            .role_repo
+            .get_by_name(role_name)
+            .await?
+            .ok_or_else(|| AppError::ValidationError("Invalid target role name".to_string()))?;
+
+        if self.access_control.policy().is_global(&role).await? {
+            return Err(AppError::ValidationError(
+                "Roles without a company cannot be invited into a company".to_string(),
+            ));
+        }
+        self.ensure_can_assign(requester, &role).await?;
+
+        let email = dto.email.trim().to_lowercase();
+
+        if self
+            .user_repo
+            .get_by_username_or_email_and_company(None, Some(email.clone()), Some(company_id))
+            .await?
+            .is_some()
+        {
+            return Err(AppError::UserAlreadyExists);
+        }
+
+        if self
+            .invitation_repo
+            .get_pending_by_email(company_id, &email)
+            .await?
+            .is_some()
+        {
+            return Err(AppError::Conflict(
+                "A pending invitation already exists for this email".to_string(),
+            ));
+        }
+
+        let token = self.opaque_token_generator.generate();
+        let now = Utc::now();
+
+        let invitation = self
+            .invitation_repo
+            .create(Invitation {
+                id: Uuid::new_v4(),
+                company_id,
+                email,
+                role_id: role.id,
+                token_hash: self.opaque_token_generator.hash(&token),
+                invited_by: Some(requester.id),
+                expires_at: now + Duration::hours(self.invitation_ttl_hours),
+                accepted_at: None,
+                cancelled_at: None,
+                created_at: now,
+                updated_at: now,
+            })
+            .await?;
+
+        self.mailer
+            .send(self.invitation_email(&invitation, &company, &token))
+            .await?;
+
+        info!(
+            invitation_id = %invitation.id,
+            company_id = %company_id,
+            invited_by = %requester.id,
+            "Invitation sent"
+        );
+
+        Ok(invitation)
+    }
+
+    pub async fn get_by_company(
+        &self,
+        requester: &User,
+        company_id: Option<Uuid>,
+    ) -> Result<Vec<Invitation>> {
+        let company_id = self
+            .access_control
+            .validate_company_access(requester, permissions::USERS_MANAGE, company_id)
+            .await?;
+        self.access_control
+            .validate_company_management_access(requester, permissions::USERS_MANAGE, company_id)
+            .await?;
+
+        self.invitation_repo.get_by_company_id(company_id).await
+    }
+
+    /// Sends a new link and restarts the expiration. The previous link stops working.
+    /// Expired invitations can be resent, accepted and cancelled ones cannot.
+    pub async fn resend(&self, requester: &User, id: Uuid) -> Result<Invitation> {
+        let mut invitation = self.get_managed(requester, id).await?;
+
+        if invitation.accepted_at.is_some() || invitation.cancelled_at.is_some() {
+            return Err(AppError::Conflict(format!(
+                "Invitation is already {}",
+                invitation.status().as_str()
+            )));
+        }
+
+        let company = self
+            .company_repo
+            .get_by_id(invitation.company_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Company not found".to_string()))?;
+
+        let token = self.opaque_token_generator.generate();
+        let now = Utc::now();
+
+        invitation.token_hash = self.opaque_token_generator.hash(&token);
+        invitation.expires_at = now + Duration::hours(self.invitation_ttl_hours);
+        invitation.updated_at = now;
+
+        let invitation = self.invitation_repo.update(invitation).await?;
+
+        self.mailer
+            .send(self.invitation_email(&invitation, &company, &token))
+            .await?;
+
+        info!(invitation_id = %invitation.id, resent_by = %requester.id, "Invitation resent");
+
+        Ok(invitation)
+    }
+
+    /// Cancels a pending or expired invitation, its link stops working
+    pub async fn cancel(&self, requester: &User, id: Uuid) -> Result<()> {
+        let invitation = self.get_managed(requester, id).await?;
+
+        if !self.invitation_repo.cancel(invitation.id).await? {
+            return Err(AppError::Conflict(format!(
+                "Invitation is already {}",
+                invitation.status().as_str()
+            )));
+        }
+
+        info!(invitation_id = %invitation.id, cancelled_by = %requester.id, "Invitation cancelled");
+
+        Ok(())
+    }
+
+    /// Creates the invited user with the credentials chosen by the invitee.
+    /// The token is consumed, so the link only works once.
+    pub async fn accept(&self, dto: AcceptInvitationDto) -> Result<User> {
+        let token_hash = self.opaque_token_generator.hash(&dto.token);
+
+        let invitation = self
+            .invitation_repo
+            .get_by_token_hash(&token_hash)
+            .await?
+            .filter(|invitation| invitation.is_pending())
+            .ok_or_else(|| {
+                AppError::ValidationError("Invalid or expired invitation".to_string())
+            })?;
+
+        // Checked before consuming the token so the invitee can pick another username
+        if self
+            .user_repo
+            .get_by_username_and_company(&dto.username, Some(invitation.company_id))
+            .await?
+            .is_some()
+        {
+            return Err(AppError::UserAlreadyExists);
+        }
+
+        let role = self
+            .role_repo
+            .get_by_id(invitation.role_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Role not found".to_string()))?;
+
+        let company = self
+            .company_repo
+            .get_by_id(invitation.company_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Company not found".to_string()))?;
+
+        self.password_policy
+            .validate(Some(company.id), &dto.password)
+            .await?;
+        let password_hash = self.password_encoder.hash(&dto.password)?;
+
+        if !self.invitation_repo.mark_accepted(invitation.id).await? {
+            return Err(AppError::ValidationError(
+                "Invalid or expired invitation".to_string(),
+            ));
+        }
+
+        let now = Utc::now();
+        let user = self
+            .user_repo
+            .create(User {
+                id: Uuid::new_v4(),
+                username: dto.username,
+                email: Some(invitation.email),
+                // The invitation link was received at this address
+                email_verified_at: Some(now),
+                password_hash,
+                name: dto.name,
+                surname: dto.surname,
+                role,
+                company: Some(company),
+                deactivated_at: None,
+                created_at: now,
+                updated_at: now,
+            })
+            .await?;
+        self.password_policy.remember(&user).await?;
+
+        info!(invitation_id = %invitation.id, user_id = %user.id, "Invitation accepted");
+
+        Ok(user)
+    }
+
+    /// Loads an invitation the requester may manage
+    async fn get_managed(&self, requester: &User, id: Uuid) -> Result<Invitation> {
+        let invitation = self
+            .invitation_repo
+            .get_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Invitation not found".to_string()))?;
+
+        self.access_control
+            .validate_company_management_access(
+                requester,
+                permissions::USERS_MANAGE,
+                invitation.company_id,
+            )
+            .await?;
+
+        let role = self
+            .role_repo
+            .get_by_id(invitation.role_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Role not found".to_string()))?;
+        self.ensure_can_assign(requester, &role).await?;
+
+        Ok(invitation)
+    }
+
+    /// Users managing every company assign any role, the others only roles below their own
+    async fn ensure_can_assign(&self, requester: &User, role: &Role) -> Result<()> {
+        let scope = self
+            .access_control
+            .policy()
+            .scope(requester, permissions::USERS_MANAGE)
+            .await?;
+
+        if scope != Some(PermissionScope::Any) && role.level >= requester.role.level {
+            return Err(AppError::Forbidden);
+        }
+
+        Ok(())
+    }
+
+    fn invitation_email(
+        &self,
+        invitation: &Invitation,
+        company: &Company,
+        token: &str,
+    ) -> EmailMessage {
+        let link = match &self.frontend_url {
+            Some(url) => format!(
+                "{}/accept-invitation?token={token}",
+                url.trim_end_matches('/')
+            ),
+            None => token.to_string(),
+        };
+
+        EmailMessage {
+            to: invitation.email.clone(),
+            subject: format!("Invitation to join {} on SmartPotatoLeaf", company.name),
+            body: format!(
+                "Hello,\n\n\
+                 You have been invited to join {} on SmartPotatoLeaf.\n\
+                 Use the following link to choose your username and password:\n\n{link}\n\n\
+                 The link expires in {} hours and can only be used once.\n\
+                 If you were not expecting this invitation, you can ignore this email.\n",
+                company.name, self.invitation_ttl_hours
+            ),
+        }
+    }
+}
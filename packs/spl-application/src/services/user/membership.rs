@@ -0,0 +1,172 @@
+use crate::dtos::user::{SetMembershipDto, UserMembershipDto};
+use crate::services::access_control::AccessControlService;
+use spl_domain::entities::user::{permissions, CompanyMembership, PermissionScope, Role, User};
+use spl_domain::ports::repositories::user::{
+    MembershipRepository, RoleRepository, UserRepository,
+};
+use spl_shared::error::{AppError, Result};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Memberships give users access to companies other than their own, with a role per
+/// company. Requests act in one company at a time, the one active on their session.
+pub struct MembershipService {
+    membership_repo: Arc<dyn MembershipRepository>,
+    user_repo: Arc<dyn UserRepository>,
+    role_repo: Arc<dyn RoleRepository>,
+    access_control: Arc<AccessControlService>,
+}
+
+impl MembershipService {
+    pub fn new(
+        membership_repo: Arc<dyn MembershipRepository>,
+        user_repo: Arc<dyn UserRepository>,
+        role_repo: Arc<dyn RoleRepository>,
+        access_control: Arc<AccessControlService>,
+    ) -> Self {
+        Self {
+            membership_repo,
+            user_repo,
+            role_repo,
+            access_control,
+        }
+    }
+
+    /// Companies the user works for, the company of their account first
+    pub async fn get_by_user(&self, user_id: Uuid) -> Result<Vec<UserMembershipDto>> {
+        let user = self.get_user(user_id).await?;
+
+        let home = user.company.clone().map(|company| UserMembershipDto {
+            membership: CompanyMembership {
+                user_id: user.id,
+                company,
+                role: user.role.clone(),
+                created_at: user.created_at,
+            },
+            home: true,
+        });
+
+        let memberships = self.membership_repo.get_by_user_id(user_id).await?;
+
+        Ok(home
+            .into_iter()
+            .chain(memberships.into_iter().map(|membership| UserMembershipDto {
+                membership,
+                home: false,
+            }))
+            .collect())
+    }
+
+    /// Users of other companies with access to the company
+    pub async fn get_by_company(
+        &self,
+        requester: &User,
+        company_id: Uuid,
+    ) -> Result<Vec<CompanyMembership>> {
+        let company_id = self
+            .access_control
+            .validate_company_access(requester, permissions::USERS_READ, Some(company_id))
+            .await?;
+
+        self.membership_repo.get_by_company_id(company_id).await
+    }
+
+    /// Gives the user access to the company, or changes their role in it
+    pub async fn set(
+        &self,
+        requester: &User,
+        company_id: Uuid,
+        user_id: Uuid,
+        dto: SetMembershipDto,
+    ) -> Result<CompanyMembership> {
+        let company_id = self.ensure_can_manage(requester, company_id).await?;
+
+        let user = self.get_user(user_id).await?;
+        if !user.is_active() {
+            return Err(AppError::ValidationError(
+                "Deactivated users cannot join a company".to_string(),
+            ));
+        }
+        if user.company.as_ref().map(|c| c.id) == Some(company_id) {
+            return Err(AppError::ValidationError(
+                "User already belongs to the company".to_string(),
+            ));
+        }
+
+        let role = self
+            .role_repo
+            .get_by_name(&dto.role)
+            .await?
+            .ok_or_else(|| AppError::ValidationError("Invalid target role name".to_string()))?;
+
+        if self.access_control.policy().is_global(&role).await? {
+            return Err(AppError::ValidationError(
+                "Roles without a company cannot be given in a company".to_string(),
+            ));
+        }
+        self.ensure_can_assign(requester, &role).await?;
+
+        self.membership_repo
+            .set(user.id, company_id, role.id)
+            .await
+    }
+
+    /// Removes the access of the user to the company. Users may leave on their own.
+    pub async fn remove(&self, requester: &User, company_id: Uuid, user_id: Uuid) -> Result<()> {
+        if requester.id != user_id {
+            self.ensure_can_manage(requester, company_id).await?;
+        }
+
+        self.membership_repo.delete(user_id, company_id).await
+    }
+
+    /// The user acting in the company, with the role of their membership in it.
+    /// Fails once the membership is removed.
+    pub async fn resolve(&self, user: User, company_id: Uuid) -> Result<User> {
+        if user.company.as_ref().map(|c| c.id) == Some(company_id) {
+            return Ok(user);
+        }
+
+        let membership = self
+            .membership_repo
+            .get(user.id, company_id)
+            .await?
+            .ok_or_else(|| AppError::AuthError("Company membership has ended".to_string()))?;
+
+        Ok(membership.apply_to(user))
+    }
+
+    async fn get_user(&self, id: Uuid) -> Result<User> {
+        self.user_repo
+            .get_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("User not found".to_string()))
+    }
+
+    async fn ensure_can_manage(&self, requester: &User, company_id: Uuid) -> Result<Uuid> {
+        let company_id = self
+            .access_control
+            .validate_company_access(requester, permissions::USERS_MANAGE, Some(company_id))
+            .await?;
+        self.access_control
+            .validate_company_management_access(requester, permissions::USERS_MANAGE, company_id)
+            .await?;
+
+        Ok(company_id)
+    }
+
+    /// Users managing every company give any role, the others only roles below their own
+    async fn ensure_can_assign(&self, requester: &User, role: &Role) -> Result<()> {
+        let scope = self
+            .access_control
+            .policy()
+            .scope(requester, permissions::USERS_MANAGE)
+            .await?;
+
+        if scope != Some(PermissionScope::Any) && role.level >= requester.role.level {
+            return Err(AppError::Forbidden);
+        }
+
+        Ok(())
+    }
+}
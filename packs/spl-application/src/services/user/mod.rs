@@ -1,5 +1,9 @@
+pub mod invitation;
+pub mod membership;
 pub mod role;
 pub mod user;
 
+pub use invitation::InvitationService;
+pub use membership::MembershipService;
 pub use role::RoleService;
 pub use user::UserService;
@@ -1,15 +1,47 @@
-use spl_domain::entities::user::Role;
-use spl_domain::ports::repositories::user::RoleRepository;
-use spl_shared::error::Result;
+use crate::dtos::user::{CreateRoleDto, UpdateRoleDto};
+use crate::services::policy::{PolicyService, Resource};
+use spl_domain::entities::user::{
+    permissions, DetailedRole, PermissionGrant, PermissionScope, Role, User,
+};
+use spl_domain::ports::cache::UserCache;
+use spl_domain::ports::repositories::user::{
+    InvitationRepository, MembershipRepository, PermissionRepository, RoleRepository,
+    UserRepository,
+};
+use spl_shared::error::{AppError, Result};
+use spl_shared::traits::IntoWithContext;
+use std::collections::HashSet;
 use std::sync::Arc;
 
 pub struct RoleService {
     repo: Arc<dyn RoleRepository>,
+    permission_repo: Arc<dyn PermissionRepository>,
+    user_repo: Arc<dyn UserRepository>,
+    invitation_repo: Arc<dyn InvitationRepository>,
+    membership_repo: Arc<dyn MembershipRepository>,
+    policy: Arc<PolicyService>,
+    user_cache: Arc<dyn UserCache>,
 }
 
 impl RoleService {
-    pub fn new(repo: Arc<dyn RoleRepository>) -> Self {
-        Self { repo }
+    pub fn new(
+        repo: Arc<dyn RoleRepository>,
+        permission_repo: Arc<dyn PermissionRepository>,
+        user_repo: Arc<dyn UserRepository>,
+        invitation_repo: Arc<dyn InvitationRepository>,
+        membership_repo: Arc<dyn MembershipRepository>,
+        policy: Arc<PolicyService>,
+        user_cache: Arc<dyn UserCache>,
+    ) -> Self {
+        Self {
+            repo,
+            permission_repo,
+            user_repo,
+            invitation_repo,
+            membership_repo,
+            policy,
+            user_cache,
+        }
     }
 
     pub async fn get_by_id(&self, id: i32) -> Result<Option<Role>> {
@@ -23,4 +55,246 @@ impl RoleService {
     pub async fn get_all(&self) -> Result<Vec<Role>> {
         self.repo.get_all().await
     }
+
+    /// Roles with their permissions, from the lowest level to the highest
+    pub async fn get_all_detailed(&self, requester: &User) -> Result<Vec<DetailedRole>> {
+        self.ensure_can_manage(requester).await?;
+
+        let mut roles = self.repo.get_all().await?;
+        roles.sort();
+
+        let grants = self.permission_repo.get_grants().await?;
+
+        Ok(roles
+            .into_iter()
+            .map(|role| {
+                let permissions = grants
+                    .iter()
+                    .filter(|grant| grant.role_id == role.id)
+                    .map(|grant| PermissionGrant {
+                        permission: grant.permission.clone(),
+                        scope: grant.scope,
+                    })
+                    .collect();
+
+                DetailedRole { role, permissions }
+            })
+            .collect())
+    }
+
+    pub async fn get_detailed(&self, requester: &User, id: i32) -> Result<DetailedRole> {
+        self.ensure_can_manage(requester).await?;
+
+        let role = self.get_existing(id).await?;
+        self.detailed(role).await
+    }
+
+    pub async fn create(&self, requester: &User, dto: CreateRoleDto) -> Result<DetailedRole> {
+        self.ensure_can_manage(requester).await?;
+        ensure_level_allowed(requester, dto.level)?;
+        validate_grants(&dto.permissions)?;
+
+        if self.repo.get_by_name(&dto.name).await?.is_some() {
+            return Err(AppError::Conflict(format!(
+                "Role '{}' already exists",
+                dto.name
+            )));
+        }
+
+        let permissions = dto.permissions.clone();
+        let role = self.repo.create(dto.into()).await?;
+
+        self.permission_repo
+            .set_role_grants(role.id, permissions.clone())
+            .await?;
+        self.policy.invalidate();
+
+        Ok(DetailedRole { role, permissions })
+    }
+
+    pub async fn update(
+        &self,
+        requester: &User,
+        id: i32,
+        dto: UpdateRoleDto,
+    ) -> Result<DetailedRole> {
+        self.ensure_can_manage(requester).await?;
+
+        let current = self.get_existing(id).await?;
+        ensure_level_allowed(requester, current.level)?;
+        if let Some(level) = dto.level {
+            ensure_level_allowed(requester, level)?;
+        }
+
+        if let Some(name) = dto.name.as_deref().filter(|name| *name != current.name) {
+            if self.repo.get_by_name(name).await?.is_some() {
+                return Err(AppError::Conflict(format!(
+                    "Role '{}' already exists",
+                    name
+                )));
+            }
+        }
+
+        if let Some(grants) = &dto.permissions {
+            validate_grants(grants)?;
+            self.ensure_roles_stay_managed(id, grants).await?;
+        }
+
+        let permissions = dto.permissions.clone();
+        let role = self.repo.update(dto.into_with_context(current)?).await?;
+
+        if let Some(permissions) = permissions {
+            self.permission_repo
+                .set_role_grants(role.id, permissions)
+                .await?;
+        }
+        // Grants are looked up by role name, renames need a reload as well
+        self.policy.invalidate();
+        // Cached users carry their role
+        self.user_cache.clear().await;
+
+        self.detailed(role).await
+    }
+
+    /// Deletes a role no user or invitation refers to
+    pub async fn delete(&self, requester: &User, id: i32) -> Result<Role> {
+        self.ensure_can_manage(requester).await?;
+
+        let role = self.get_existing(id).await?;
+        ensure_level_allowed(requester, role.level)?;
+
+        let users = self.user_repo.count_by_role_id(id).await?;
+        if users > 0 {
+            return Err(AppError::Conflict(format!(
+                "Role is still assigned to {} users",
+                users
+            )));
+        }
+
+        let invitations = self.invitation_repo.count_by_role_id(id).await?;
+        if invitations > 0 {
+            return Err(AppError::Conflict(format!(
+                "Role is still used by {} invitations",
+                invitations
+            )));
+        }
+
+        let memberships = self.membership_repo.count_by_role_id(id).await?;
+        if memberships > 0 {
+            return Err(AppError::Conflict(format!(
+                "Role is still used by {} memberships",
+                memberships
+            )));
+        }
+
+        let role = self.repo.delete(id).await?;
+        self.policy.invalidate();
+        self.user_cache.clear().await;
+
+        Ok(role)
+    }
+
+    async fn ensure_can_manage(&self, requester: &User) -> Result<()> {
+        self.policy
+            .ensure(requester, permissions::ROLES_MANAGE, Resource::Global)
+            .await
+    }
+
+    async fn get_existing(&self, id: i32) -> Result<Role> {
+        self.repo
+            .get_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Role not found".to_string()))
+    }
+
+    async fn detailed(&self, role: Role) -> Result<DetailedRole> {
+        let permissions = self
+            .permission_repo
+            .get_by_role_id(role.id)
+            .await?
+            .into_iter()
+            .map(|grant| PermissionGrant {
+                permission: grant.permission,
+                scope: grant.scope,
+            })
+            .collect();
+
+        Ok(DetailedRole { role, permissions })
+    }
+
+    /// Fails when taking the role's right to manage roles leaves no active user with it,
+    /// as nobody could grant it back
+    async fn ensure_roles_stay_managed(
+        &self,
+        role_id: i32,
+        grants: &[PermissionGrant],
+    ) -> Result<()> {
+        let keeps = grants.iter().any(|grant| {
+            grant.permission == permissions::ROLES_MANAGE && grant.scope == PermissionScope::Any
+        });
+        if keeps {
+            return Ok(());
+        }
+
+        let managers: Vec<i32> = self
+            .permission_repo
+            .get_grants()
+            .await?
+            .into_iter()
+            .filter(|grant| {
+                grant.permission == permissions::ROLES_MANAGE && grant.scope == PermissionScope::Any
+            })
+            .map(|grant| grant.role_id)
+            .collect();
+
+        if !managers.contains(&role_id) {
+            return Ok(());
+        }
+
+        let others: Vec<i32> = managers.into_iter().filter(|id| *id != role_id).collect();
+        if others.is_empty() || self.user_repo.count_active_by_role_ids(others).await? == 0 {
+            return Err(AppError::Conflict(
+                "No other active user could manage roles afterwards".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// Roles above the requester would let their users manage the requester
+fn ensure_level_allowed(requester: &User, level: i16) -> Result<()> {
+    if level > requester.role.level {
+        return Err(AppError::Forbidden);
+    }
+    Ok(())
+}
+
+fn validate_grants(grants: &[PermissionGrant]) -> Result<()> {
+    let mut seen = HashSet::new();
+
+    for grant in grants {
+        if !permissions::is_valid(&grant.permission) {
+            return Err(AppError::ValidationError(format!(
+                "Unknown permission: {}",
+                grant.permission
+            )));
+        }
+        if grant.scope == PermissionScope::Team
+            && !permissions::TEAM_SCOPED.contains(&grant.permission.as_str())
+        {
+            return Err(AppError::ValidationError(format!(
+                "Permission cannot be granted to teams: {}",
+                grant.permission
+            )));
+        }
+        if !seen.insert(grant.permission.as_str()) {
+            return Err(AppError::ValidationError(format!(
+                "Permission granted more than once: {}",
+                grant.permission
+            )));
+        }
+    }
+
+    Ok(())
 }
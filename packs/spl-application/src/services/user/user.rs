@@ -1,86 +1,146 @@
 use crate::dtos::user::{ChangePasswordDto, CreateUserDto, UpdateProfileDto, UpdateUserDto};
 use crate::mappers::user::{UserCreationContext, UserUpdateContext};
-use spl_domain::entities::user::User;
+use chrono::Utc;
+use spl_domain::entities::user::{permissions, PermissionScope, Role, User};
 use spl_domain::ports::auth::PasswordEncoder;
+use spl_domain::ports::cache::UserCache;
+use spl_domain::ports::repositories::auth::SessionRepository;
 use spl_domain::ports::repositories::company::CompanyRepository;
 use spl_domain::ports::repositories::user::{RoleRepository, UserRepository};
 use spl_shared::error::{AppError, Result};
 use spl_shared::traits::IntoWithContext;
 use std::sync::Arc;
+use tracing::info;
 use uuid::Uuid;
 
 use crate::services::access_control::AccessControlService;
+use crate::services::email_verification::EmailVerificationService;
+use crate::services::password_policy::PasswordPolicyService;
+use crate::services::policy::Resource;
 
 pub struct UserService {
     user_repo: Arc<dyn UserRepository>,
     role_repo: Arc<dyn RoleRepository>,
     company_repo: Arc<dyn CompanyRepository>,
+    session_repo: Arc<dyn SessionRepository>,
     password_encoder: Arc<dyn PasswordEncoder>,
     access_control: Arc<AccessControlService>,
+    password_policy: Arc<PasswordPolicyService>,
+    email_verification: Arc<EmailVerificationService>,
+    user_cache: Arc<dyn UserCache>,
 }
 
 impl UserService {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         user_repo: Arc<dyn UserRepository>,
         role_repo: Arc<dyn RoleRepository>,
         company_repo: Arc<dyn CompanyRepository>,
+        session_repo: Arc<dyn SessionRepository>,
         password_encoder: Arc<dyn PasswordEncoder>,
         access_control: Arc<AccessControlService>,
+        password_policy: Arc<PasswordPolicyService>,
+        email_verification: Arc<EmailVerificationService>,
+        user_cache: Arc<dyn UserCache>,
     ) -> Self {
         Self {
             user_repo,
             role_repo,
             company_repo,
+            session_repo,
             password_encoder,
             access_control,
+            password_policy,
+            email_verification,
+            user_cache,
         }
     }
 
+    /// Reads through the user cache, as every authenticated request loads its user
     pub async fn get_by_id(&self, id: Uuid) -> Result<Option<User>> {
-        self.user_repo.get_by_id(id).await
+        if let Some(user) = self.user_cache.get(id).await {
+            return Ok(Some(user));
+        }
+
+        let user = self.user_repo.get_by_id(id).await?;
+        if let Some(user) = &user {
+            self.user_cache.set(user).await;
+        }
+
+        Ok(user)
+    }
+
+    /// The user as stored, with their own company and role. Requests acting in the company
+    /// of a membership carry those of the membership instead, which must not be saved.
+    async fn get_stored(&self, id: Uuid) -> Result<User> {
+        self.user_repo
+            .get_by_id(id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("User not found".to_string()))
+    }
+
+    /// Persists a changed user and drops its cached copy
+    async fn save(&self, user: User) -> Result<User> {
+        let user = self.user_repo.update(user).await?;
+        self.user_cache.invalidate(user.id).await;
+
+        Ok(user)
     }
 
     /// Validates if the requester can perform actions on the target user
     /// Returns true if allowed, false otherwise
     async fn can_manage_user(&self, requester: &User, target_user: &User) -> Result<bool> {
-        let requester_level = requester.role.level;
-
-        // Admin can manage anyone
-        if requester_level >= 100 {
-            return Ok(true);
+        let policy = self.access_control.policy();
+
+        match policy.scope(requester, permissions::USERS_MANAGE).await? {
+            // Manages anyone
+            Some(PermissionScope::Any) => Ok(true),
+            // Only users of lower roles within reach
+            Some(_) => Ok(target_user.role.level < requester.role.level
+                && policy
+                    .can(
+                        requester,
+                        permissions::USERS_MANAGE,
+                        Resource::User(target_user),
+                    )
+                    .await?),
+            None => Ok(false),
         }
+    }
 
-        // Supervisor can only manage users in their own company
-        if requester_level >= 50 {
-            let requester_company_id = requester.company.as_ref().map(|c| c.id);
-            let target_company_id = target_user.company.as_ref().map(|c| c.id);
-
-            // Both must have a company and they must match
-            if requester_company_id.is_some()
-                && requester_company_id == target_company_id
-                && target_user.role.level < requester_level
-            {
-                return Ok(true);
-            }
-        }
+    /// Roles managing users need an email to be reachable
+    async fn manages_users(&self, role: &Role) -> Result<bool> {
+        let scope = self
+            .access_control
+            .policy()
+            .scope_of(role, permissions::USERS_MANAGE)
+            .await?;
+
+        Ok(scope.is_some())
+    }
+
+    /// Whether the requester manages users of every company
+    async fn manages_any_user(&self, requester: &User) -> Result<bool> {
+        let scope = self
+            .access_control
+            .policy()
+            .scope(requester, permissions::USERS_MANAGE)
+            .await?;
 
-        // Users cannot manage other users
-        Ok(false)
+        Ok(scope == Some(PermissionScope::Any))
     }
 
     pub async fn create_user(&self, creator: &User, dto: CreateUserDto) -> Result<User> {
-        // Fetch creator's role to check permissions
         let creator_role = &creator.role;
+        let policy = self.access_control.policy();
 
         let target_role_id;
         let target_company_id;
 
-        // Level 100 = Admin
-        // Level 50 = Supervisor
-        // Level 10 = User
+        let scope = policy.scope(creator, permissions::USERS_MANAGE).await?;
 
-        if creator_role.level >= 100 {
-            // Admin Logic
+        if scope == Some(PermissionScope::Any) {
+            // Manages every company
             let target_role_name = dto.role.as_ref().ok_or_else(|| {
                 AppError::ValidationError("Role name required for Admin creation".to_string())
             })?;
@@ -93,18 +153,16 @@ impl UserService {
 
             target_role_id = target_role.id;
 
-            if target_role.level >= 100 {
-                // Creating another Admin
+            if policy.is_global(&target_role).await? {
+                // Roles spanning every company belong to none
                 target_company_id = None;
             } else {
-                // Creating Supervisor or User
                 target_company_id = Some(dto.company_id.ok_or_else(|| {
                     AppError::ValidationError("Company ID required for this role".to_string())
                 })?);
             }
-        } else if creator_role.level >= 50 {
-            // Supervisor Logic
-            // Can only create users (level < 50)
+        } else if scope == Some(PermissionScope::Company) {
+            // Can only create lower roles in their own company
 
             let target_role = if let Some(r_name) = &dto.role {
                 self.role_repo.get_by_name(r_name).await?.ok_or_else(|| {
@@ -123,7 +181,7 @@ impl UserService {
             target_role_id = target_role.id;
             target_company_id = creator.company.as_ref().map(|c| c.id); // Inherit company_id from creator
         } else {
-            // Users cannot create users
+            // Cannot create users
             return Err(AppError::Forbidden);
         }
 
@@ -144,12 +202,15 @@ impl UserService {
             .await?
             .ok_or_else(|| AppError::ValidationError("Role lost".to_string()))?;
 
-        if target_role.level >= 50 && dto.email.is_none() {
+        if dto.email.is_none() && self.manages_users(&target_role).await? {
             return Err(AppError::ValidationError(
-                "Email is required for Supervisor and Admin roles".to_string(),
+                "Email is required for roles that manage users".to_string(),
             ));
         }
 
+        self.password_policy
+            .validate(target_company_id, &dto.password)
+            .await?;
         let password_hash = self.password_encoder.hash(&dto.password)?;
 
         // get target company if needed
@@ -173,7 +234,11 @@ impl UserService {
 
         let new_user = dto.into_with_context(context)?;
 
-        self.user_repo.create(new_user).await
+        let user = self.user_repo.create(new_user).await?;
+        self.password_policy.remember(&user).await?;
+        self.email_verification.email_changed(&user).await;
+
+        Ok(user)
     }
 
     pub async fn update_user(
@@ -194,7 +259,7 @@ impl UserService {
             return Err(AppError::Forbidden);
         }
 
-        let requester_level = requester.role.level;
+        let manages_any = self.manages_any_user(requester).await?;
 
         // Determine new role if role_name is provided
         let new_role = if let Some(ref role_name) = dto.role {
@@ -204,9 +269,9 @@ impl UserService {
                 .await?
                 .ok_or_else(|| AppError::ValidationError("Invalid role name".to_string()))?;
 
-            // Admins can assign any role
-            // Supervisors can only assign roles lower than their own
-            if requester_level < 100 && role.level >= requester_level {
+            // Managers of every company can assign any role,
+            // the others only roles lower than their own
+            if !manages_any && role.level >= requester.role.level {
                 return Err(AppError::Forbidden);
             }
 
@@ -217,16 +282,16 @@ impl UserService {
 
         let role = new_role.clone().unwrap_or(target_user.role.clone());
 
-        if role.level >= 50 && dto.email.is_none() && target_user.email.is_none() {
+        if dto.email.is_none() && target_user.email.is_none() && self.manages_users(&role).await? {
             return Err(AppError::ValidationError(
-                "Email is required for Supervisor and Admin roles".to_string(),
+                "Email is required for roles that manage users".to_string(),
             ));
         }
 
         // Determine new company if company_id is provided
         let new_company = if let Some(cid) = dto.company_id {
-            if requester_level < 100 {
-                // Only admins can change company
+            if !manages_any {
+                // Only managers of every company can change company
                 return Err(AppError::Forbidden);
             }
             self.company_repo.get_by_id(cid).await?
@@ -236,6 +301,9 @@ impl UserService {
 
         // Hash new password if provided
         let password_hash = if let Some(ref password) = dto.password {
+            self.password_policy
+                .validate_change(&target_user, password)
+                .await?;
             Some(self.password_encoder.hash(password)?)
         } else {
             None
@@ -260,6 +328,8 @@ impl UserService {
             }
         }
 
+        let password_changed = password_hash.is_some();
+        let previous_email = target_user.email.clone();
         let context = UserUpdateContext {
             current_user: target_user,
             password_hash,
@@ -269,46 +339,112 @@ impl UserService {
 
         let updated_user = dto.into_with_context(context)?;
 
-        self.user_repo.update(updated_user).await
+        let user = self.save(updated_user).await?;
+        if password_changed {
+            self.password_policy.remember(&user).await?;
+        }
+        if user.email != previous_email {
+            self.email_verification.email_changed(&user).await;
+        }
+
+        Ok(user)
     }
 
-    pub async fn delete_user(&self, requester: &User, target_id: Uuid) -> Result<User> {
-        // Get the target user
+    /// Deactivates the user instead of deleting it, so predictions, images and feedback
+    /// keep their author. Every session of the user is revoked.
+    pub async fn deactivate_user(&self, requester: &User, target_id: Uuid) -> Result<User> {
+        let mut target_user = self.get_managed(requester, target_id).await?;
+
+        if requester.id == target_id {
+            return Err(AppError::ValidationError(
+                "Cannot deactivate your own account".to_string(),
+            ));
+        }
+
+        if !target_user.is_active() {
+            return Err(AppError::Conflict(
+                "User is already deactivated".to_string(),
+            ));
+        }
+
+        let now = Utc::now();
+        target_user.deactivated_at = Some(now);
+        target_user.updated_at = now;
+
+        let user = self.save(target_user).await?;
+        self.session_repo.revoke_by_user_id(user.id).await?;
+
+        info!(user_id = %user.id, deactivated_by = %requester.id, "User deactivated");
+
+        Ok(user)
+    }
+
+    pub async fn reactivate_user(&self, requester: &User, target_id: Uuid) -> Result<User> {
+        let mut target_user = self.get_managed(requester, target_id).await?;
+
+        if target_user.is_active() {
+            return Err(AppError::Conflict("User is already active".to_string()));
+        }
+
+        target_user.deactivated_at = None;
+        target_user.updated_at = Utc::now();
+
+        let user = self.save(target_user).await?;
+
+        info!(user_id = %user.id, reactivated_by = %requester.id, "User reactivated");
+
+        Ok(user)
+    }
+
+    /// Loads a user the requester may manage
+    async fn get_managed(&self, requester: &User, target_id: Uuid) -> Result<User> {
         let target_user = self
             .user_repo
             .get_by_id(target_id)
             .await?
             .ok_or_else(|| AppError::NotFound("User not found".to_string()))?;
 
-        // Check permissions
         if !self.can_manage_user(requester, &target_user).await? {
             return Err(AppError::Forbidden);
         }
 
-        // Prevent self-deletion
-        if requester.id == target_id {
-            return Err(AppError::ValidationError(
-                "Cannot delete your own account".to_string(),
-            ));
-        }
-
-        self.user_repo.delete(target_id).await
+        Ok(target_user)
     }
 
     pub async fn get_by_company(&self, requester: &User, company_id: Uuid) -> Result<Vec<User>> {
         self.access_control
-            .validate_company_management_access(requester, company_id)?;
+            .validate_company_management_access(requester, permissions::USERS_READ, company_id)
+            .await?;
 
-        self.user_repo.get_by_company_id(company_id).await
+        let users = self.user_repo.get_by_company_id(company_id).await?;
+
+        // Supervisors of teams only list their members
+        match self
+            .access_control
+            .get_team_user_ids(requester, permissions::USERS_READ)
+            .await?
+        {
+            Some(ids) => Ok(users.into_iter().filter(|u| ids.contains(&u.id)).collect()),
+            None => Ok(users),
+        }
     }
 
     pub async fn update_profile(&self, user: &User, dto: UpdateProfileDto) -> Result<User> {
+        let user = &self.get_stored(user.id).await?;
         let updated = dto.into_with_context(user.clone())?;
 
-        self.user_repo.update(updated).await
+        let updated = self.save(updated).await?;
+        // A new address has to be verified again
+        if updated.email != user.email {
+            self.email_verification.email_changed(&updated).await;
+        }
+
+        Ok(updated)
     }
 
     pub async fn change_password(&self, user: &User, dto: ChangePasswordDto) -> Result<User> {
+        let user = &self.get_stored(user.id).await?;
+
         // Verify current password
         if !self
             .password_encoder
@@ -317,12 +453,18 @@ impl UserService {
             return Err(AppError::InvalidCredentials);
         }
 
+        self.password_policy
+            .validate_change(user, &dto.new_password)
+            .await?;
         let new_hash = self.password_encoder.hash(&dto.new_password)?;
 
         let mut updated = user.clone();
         updated.password_hash = new_hash;
         updated.updated_at = chrono::Utc::now();
 
-        self.user_repo.update(updated).await
+        let user = self.save(updated).await?;
+        self.password_policy.remember(&user).await?;
+
+        Ok(user)
     }
 }
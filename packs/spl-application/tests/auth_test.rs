@@ -1,19 +1,47 @@
 use async_trait::async_trait;
 use mockall::mock;
 use mockall::predicate::*;
+use spl_application::dtos::auth::{ClientInfoDto, LoginResultDto, VerifyTwoFactorDto};
 use spl_application::dtos::user::LoginDto;
 use spl_application::services::auth::AuthService;
-use spl_domain::entities::user::{Role, User};
-use spl_domain::ports::auth::{PasswordEncoder, TokenGenerator};
+use spl_application::services::login_lockout::{LockoutPolicy, LoginLockoutService};
+use spl_application::services::two_factor::TwoFactorService;
+use chrono::{DateTime, Duration, Utc};
+use spl_domain::entities::auth::{
+    LoginAttempts, RecoveryCode, RefreshToken, Session, TwoFactor, TwoFactorChallenge,
+};
+use spl_domain::entities::company::Company;
+use spl_domain::entities::user::{CompanyMembership, Role, User};
+use spl_domain::ports::auth::{
+    LoginAttemptStore, OpaqueTokenGenerator, PasswordEncoder, TokenGenerator, TwoFactorProvider,
+};
+use spl_domain::ports::cache::UserCache;
+use spl_domain::ports::repositories::auth::{
+    RecoveryCodeRepository, RefreshTokenRepository, SessionRepository,
+    TwoFactorChallengeRepository, TwoFactorRepository,
+};
 use spl_domain::ports::repositories::crud::CrudRepository;
-use spl_domain::ports::repositories::user::{RoleRepository, UserRepository};
-use spl_shared::error::Result;
+use spl_domain::ports::repositories::user::{
+    MembershipRepository, RoleRepository, UserRepository,
+};
+use spl_shared::error::{AppError, Result};
 use std::sync::Arc;
 use uuid::Uuid;
 
 const ROLE_USER_ID: i32 = 3;
 
 // Mock definitions
+mock! {
+    pub UserCache {}
+    #[async_trait]
+    impl UserCache for UserCache {
+        async fn get(&self, id: Uuid) -> Option<User>;
+        async fn set(&self, user: &User);
+        async fn invalidate(&self, id: Uuid);
+        async fn clear(&self);
+    }
+}
+
 mock! {
     pub UserRepository {}
     #[async_trait]
@@ -29,6 +57,21 @@ mock! {
         async fn get_by_username_and_company(&self, username: &str, company_id: Option<Uuid>) -> Result<Option<User>>;
         async fn get_by_username_or_email_and_company(&self, username: Option<String>, email: Option<String>, company_id: Option<Uuid>) -> Result<Option<User>>;
         async fn get_by_company_id(&self, company_id: Uuid) -> Result<Vec<User>> ;
+        async fn count_by_role_id(&self, role_id: i32) -> Result<u64>;
+        async fn count_active_by_role_ids(&self, role_ids: Vec<i32>) -> Result<u64>;
+    }
+}
+
+mock! {
+    pub MembershipRepository {}
+    #[async_trait]
+    impl MembershipRepository for MembershipRepository {
+        async fn get_by_user_id(&self, user_id: Uuid) -> Result<Vec<CompanyMembership>>;
+        async fn get_by_company_id(&self, company_id: Uuid) -> Result<Vec<CompanyMembership>>;
+        async fn get(&self, user_id: Uuid, company_id: Uuid) -> Result<Option<CompanyMembership>>;
+        async fn set(&self, user_id: Uuid, company_id: Uuid, role_id: i32) -> Result<CompanyMembership>;
+        async fn delete(&self, user_id: Uuid, company_id: Uuid) -> Result<()>;
+        async fn count_by_role_id(&self, role_id: i32) -> Result<u64>;
     }
 }
 
@@ -40,6 +83,15 @@ mock! {
     }
 }
 
+mock! {
+    pub RehashingPasswordEncoder {}
+    impl PasswordEncoder for RehashingPasswordEncoder {
+        fn hash(&self, password: &str) -> Result<String>;
+        fn verify(&self, password: &str, hash: &str) -> Result<bool>;
+        fn needs_rehash(&self, hash: &str) -> bool;
+    }
+}
+
 mock! {
     pub TokenGenerator {}
     impl TokenGenerator for TokenGenerator {
@@ -48,6 +100,118 @@ mock! {
     }
 }
 
+mock! {
+    pub OpaqueTokenGenerator {}
+    impl OpaqueTokenGenerator for OpaqueTokenGenerator {
+        fn generate(&self) -> String;
+        fn hash(&self, token: &str) -> String;
+    }
+}
+
+mock! {
+    pub SessionRepository {}
+    #[async_trait]
+    impl CrudRepository<Session, Uuid> for SessionRepository {
+        async fn get_by_id(&self, id: Uuid) -> Result<Option<Session>>;
+        async fn create(&self, entity: Session) -> Result<Session>;
+        async fn update(&self, entity: Session) -> Result<Session>;
+        async fn delete(&self, id: Uuid) -> Result<Session>;
+    }
+    #[async_trait]
+    impl SessionRepository for SessionRepository {
+        async fn revoke(&self, id: Uuid) -> Result<bool>;
+        async fn revoke_by_user_id(&self, user_id: Uuid) -> Result<u64>;
+        async fn get_active_by_user_id(&self, user_id: Uuid) -> Result<Vec<Session>>;
+        async fn touch(&self, id: Uuid, seen_at: DateTime<Utc>, ip_address: Option<String>) -> Result<()>;
+    }
+}
+
+mock! {
+    pub RefreshTokenRepository {}
+    #[async_trait]
+    impl CrudRepository<RefreshToken, Uuid> for RefreshTokenRepository {
+        async fn get_by_id(&self, id: Uuid) -> Result<Option<RefreshToken>>;
+        async fn create(&self, entity: RefreshToken) -> Result<RefreshToken>;
+        async fn update(&self, entity: RefreshToken) -> Result<RefreshToken>;
+        async fn delete(&self, id: Uuid) -> Result<RefreshToken>;
+    }
+    #[async_trait]
+    impl RefreshTokenRepository for RefreshTokenRepository {
+        async fn get_by_token_hash(&self, token_hash: &str) -> Result<Option<RefreshToken>>;
+        async fn mark_used(&self, id: Uuid) -> Result<bool>;
+    }
+}
+
+mock! {
+    pub LoginAttemptStore {}
+    #[async_trait]
+    impl LoginAttemptStore for LoginAttemptStore {
+        async fn get(&self, user_id: Uuid) -> Result<Option<LoginAttempts>>;
+        async fn record_failure(&self, user_id: Uuid) -> Result<LoginAttempts>;
+        async fn lock(&self, user_id: Uuid, until: DateTime<Utc>) -> Result<LoginAttempts>;
+        async fn clear(&self, user_id: Uuid) -> Result<()>;
+    }
+}
+
+mock! {
+    pub TwoFactorRepository {}
+    #[async_trait]
+    impl CrudRepository<TwoFactor, Uuid> for TwoFactorRepository {
+        async fn get_by_id(&self, id: Uuid) -> Result<Option<TwoFactor>>;
+        async fn create(&self, entity: TwoFactor) -> Result<TwoFactor>;
+        async fn update(&self, entity: TwoFactor) -> Result<TwoFactor>;
+        async fn delete(&self, id: Uuid) -> Result<TwoFactor>;
+    }
+    #[async_trait]
+    impl TwoFactorRepository for TwoFactorRepository {
+        async fn enable(&self, user_id: Uuid) -> Result<bool>;
+    }
+}
+
+mock! {
+    pub RecoveryCodeRepository {}
+    #[async_trait]
+    impl CrudRepository<RecoveryCode, Uuid> for RecoveryCodeRepository {
+        async fn get_by_id(&self, id: Uuid) -> Result<Option<RecoveryCode>>;
+        async fn create(&self, entity: RecoveryCode) -> Result<RecoveryCode>;
+        async fn update(&self, entity: RecoveryCode) -> Result<RecoveryCode>;
+        async fn delete(&self, id: Uuid) -> Result<RecoveryCode>;
+    }
+    #[async_trait]
+    impl RecoveryCodeRepository for RecoveryCodeRepository {
+        async fn get_by_code_hash(&self, user_id: Uuid, code_hash: &str) -> Result<Option<RecoveryCode>>;
+        async fn mark_used(&self, id: Uuid) -> Result<bool>;
+        async fn count_unused(&self, user_id: Uuid) -> Result<u64>;
+        async fn delete_by_user_id(&self, user_id: Uuid) -> Result<u64>;
+    }
+}
+
+mock! {
+    pub TwoFactorChallengeRepository {}
+    #[async_trait]
+    impl CrudRepository<TwoFactorChallenge, Uuid> for TwoFactorChallengeRepository {
+        async fn get_by_id(&self, id: Uuid) -> Result<Option<TwoFactorChallenge>>;
+        async fn create(&self, entity: TwoFactorChallenge) -> Result<TwoFactorChallenge>;
+        async fn update(&self, entity: TwoFactorChallenge) -> Result<TwoFactorChallenge>;
+        async fn delete(&self, id: Uuid) -> Result<TwoFactorChallenge>;
+    }
+    #[async_trait]
+    impl TwoFactorChallengeRepository for TwoFactorChallengeRepository {
+        async fn get_by_token_hash(&self, token_hash: &str) -> Result<Option<TwoFactorChallenge>>;
+        async fn mark_used(&self, id: Uuid) -> Result<bool>;
+    }
+}
+
+mock! {
+    pub TwoFactorProvider {}
+    impl TwoFactorProvider for TwoFactorProvider {
+        fn generate_secret(&self) -> String;
+        fn provisioning_uri(&self, secret: &str, account_name: &str) -> Result<String>;
+        fn verify(&self, secret: &str, code: &str) -> Result<bool>;
+        fn generate_recovery_code(&self) -> String;
+    }
+}
+
 mock! {
     pub RoleRepository {}
     #[async_trait]
@@ -76,6 +240,7 @@ async fn test_login_success() {
         id: user_id,
         username: "testuser".to_string(),
         email: Some("test@example.com".to_string()),
+        email_verified_at: None,
         password_hash: "hashed_secret".to_string(),
         name: None,
         surname: None,
@@ -87,6 +252,7 @@ async fn test_login_success() {
             updated_at: chrono::Utc::now(),
         },
         company: None,
+        deactivated_at: None,
         created_at: chrono::Utc::now(),
         updated_at: chrono::Utc::now(),
     };
@@ -115,15 +281,45 @@ async fn test_login_success() {
         .expect_generate()
         .with(
             always(), // subject
-            function(|claims: &serde_json::Value| claims["role"] == "User"),
+            function(|claims: &serde_json::Value| {
+                claims["role"] == "User" && claims["sid"].is_string() && claims["jti"].is_string()
+            }),
         )
         .times(1)
         .returning(|_, _| Ok("jwt_token".to_string()));
 
+    let mut mock_session_repo = MockSessionRepository::new();
+    mock_session_repo
+        .expect_create()
+        .withf(move |session| {
+            session.user_id == user_id
+                && session.revoked_at.is_none()
+                && session.user_agent.as_deref() == Some("Mozilla/5.0")
+                && session.ip_address.as_deref() == Some("203.0.113.7")
+        })
+        .times(1)
+        .returning(Ok);
+
+    let mut mock_refresh_repo = MockRefreshTokenRepository::new();
+    mock_refresh_repo
+        .expect_create()
+        .withf(|token| token.token_hash == "hashed_refresh" && token.used_at.is_none())
+        .times(1)
+        .returning(Ok);
+
     let service = AuthService::new(
         Arc::new(mock_repo),
+        Arc::new(MockMembershipRepository::new()),
+        Arc::new(mock_session_repo),
+        Arc::new(mock_refresh_repo),
         Arc::new(mock_encoder),
         Arc::new(mock_token),
+        Arc::new(opaque_generator()),
+        lockout_service(unlocked_store()),
+        without_two_factor(),
+        Arc::new(MockUserCache::new()),
+        900,
+        30,
     );
 
     let login_dto = LoginDto {
@@ -133,7 +329,1136 @@ async fn test_login_success() {
         company_id: None,
     };
 
-    let result = service.login(login_dto).await;
+    let result = service.login(login_dto, client_info()).await;
     assert!(result.is_ok());
-    assert_eq!(result.unwrap(), "jwt_token");
+    let LoginResultDto::Authenticated(tokens) = result.unwrap() else {
+        panic!("expected tokens");
+    };
+    assert_eq!(tokens.access_token, "jwt_token");
+    assert_eq!(tokens.refresh_token, "refresh");
+    assert_eq!(tokens.expires_in, 900);
+}
+
+fn opaque_generator() -> MockOpaqueTokenGenerator {
+    let mut generator = MockOpaqueTokenGenerator::new();
+    generator
+        .expect_generate()
+        .returning(|| "refresh".to_string());
+    generator
+        .expect_hash()
+        .returning(|token| format!("hashed_{token}"));
+    generator
+}
+
+struct TwoFactorMocks {
+    two_factor_repo: MockTwoFactorRepository,
+    recovery_code_repo: MockRecoveryCodeRepository,
+    challenge_repo: MockTwoFactorChallengeRepository,
+    provider: MockTwoFactorProvider,
+}
+
+impl TwoFactorMocks {
+    fn new() -> Self {
+        Self {
+            two_factor_repo: MockTwoFactorRepository::new(),
+            recovery_code_repo: MockRecoveryCodeRepository::new(),
+            challenge_repo: MockTwoFactorChallengeRepository::new(),
+            provider: MockTwoFactorProvider::new(),
+        }
+    }
+
+    fn into_service(self) -> Arc<TwoFactorService> {
+        Arc::new(TwoFactorService::new(
+            Arc::new(self.two_factor_repo),
+            Arc::new(self.recovery_code_repo),
+            Arc::new(self.challenge_repo),
+            Arc::new(self.provider),
+            Arc::new(opaque_generator()),
+            300,
+        ))
+    }
+}
+
+fn without_two_factor() -> Arc<TwoFactorService> {
+    let mut mocks = TwoFactorMocks::new();
+    mocks.two_factor_repo.expect_get_by_id().returning(|_| Ok(None));
+    mocks.into_service()
+}
+
+const LOCKOUT_POLICY: LockoutPolicy = LockoutPolicy {
+    enabled: true,
+    max_attempts: 3,
+    lockout_seconds: 60,
+    max_lockout_seconds: 3600,
+};
+
+fn lockout_service(store: MockLoginAttemptStore) -> Arc<LoginLockoutService> {
+    Arc::new(LoginLockoutService::new(
+        Arc::new(MockUserRepository::new()),
+        Arc::new(store),
+        LOCKOUT_POLICY,
+    ))
+}
+
+fn unlocked_store() -> MockLoginAttemptStore {
+    let mut store = MockLoginAttemptStore::new();
+    store.expect_get().returning(|_| Ok(None));
+    store.expect_clear().returning(|_| Ok(()));
+    store
+}
+
+fn login_attempts(user_id: Uuid, failed_attempts: u32, lockout_count: u32) -> LoginAttempts {
+    LoginAttempts {
+        user_id,
+        failed_attempts,
+        lockout_count,
+        locked_until: None,
+    }
+}
+
+fn create_user(user_id: Uuid) -> User {
+    User {
+        id: user_id,
+        username: "testuser".to_string(),
+        email: None,
+        email_verified_at: None,
+        password_hash: "hashed_secret".to_string(),
+        name: None,
+        surname: None,
+        role: Role {
+            id: ROLE_USER_ID,
+            name: "User".to_string(),
+            level: 1,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        },
+        company: None,
+        deactivated_at: None,
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+    }
+}
+
+fn client_info() -> ClientInfoDto {
+    ClientInfoDto {
+        user_agent: Some("Mozilla/5.0".to_string()),
+        ip_address: Some("203.0.113.7".to_string()),
+    }
+}
+
+fn create_session(user_id: Uuid) -> Session {
+    Session {
+        id: Uuid::new_v4(),
+        user_id,
+        expires_at: Utc::now() + Duration::days(30),
+        revoked_at: None,
+        user_agent: None,
+        ip_address: None,
+        active_company_id: None,
+        last_seen_at: Utc::now(),
+        created_at: Utc::now(),
+    }
+}
+
+fn create_refresh_token(session_id: Uuid, used: bool) -> RefreshToken {
+    RefreshToken {
+        id: Uuid::new_v4(),
+        session_id,
+        token_hash: "hashed_old".to_string(),
+        expires_at: Utc::now() + Duration::days(30),
+        used_at: if used { Some(Utc::now()) } else { None },
+        created_at: Utc::now(),
+    }
+}
+
+#[tokio::test]
+async fn test_refresh_rotates_token() {
+    let user_id = Uuid::new_v4();
+    let user = create_user(user_id);
+    let session = create_session(user_id);
+    let session_id = session.id;
+    let token = create_refresh_token(session_id, false);
+    let token_id = token.id;
+
+    let mut mock_repo = MockUserRepository::new();
+    mock_repo
+        .expect_get_by_id()
+        .with(eq(user_id))
+        .times(1)
+        .returning(move |_| Ok(Some(user.clone())));
+
+    let mut mock_session_repo = MockSessionRepository::new();
+    mock_session_repo
+        .expect_get_by_id()
+        .with(eq(session_id))
+        .times(1)
+        .returning(move |_| Ok(Some(session.clone())));
+    mock_session_repo.expect_revoke().never();
+    mock_session_repo
+        .expect_touch()
+        .withf(move |id, _, ip| *id == session_id && ip.as_deref() == Some("203.0.113.7"))
+        .times(1)
+        .returning(|_, _, _| Ok(()));
+
+    let mut mock_refresh_repo = MockRefreshTokenRepository::new();
+    mock_refresh_repo
+        .expect_get_by_token_hash()
+        .with(eq("hashed_old"))
+        .times(1)
+        .returning(move |_| Ok(Some(token.clone())));
+    mock_refresh_repo
+        .expect_mark_used()
+        .with(eq(token_id))
+        .times(1)
+        .returning(|_| Ok(true));
+    mock_refresh_repo
+        .expect_create()
+        .withf(move |token| token.session_id == session_id && token.token_hash == "hashed_refresh")
+        .times(1)
+        .returning(Ok);
+
+    let mut mock_token = MockTokenGenerator::new();
+    mock_token
+        .expect_generate()
+        .with(
+            eq(user_id.to_string()),
+            function(move |claims: &serde_json::Value| claims["sid"] == session_id.to_string()),
+        )
+        .times(1)
+        .returning(|_, _| Ok("new_jwt".to_string()));
+
+    let service = AuthService::new(
+        Arc::new(mock_repo),
+        Arc::new(MockMembershipRepository::new()),
+        Arc::new(mock_session_repo),
+        Arc::new(mock_refresh_repo),
+        Arc::new(MockPasswordEncoder::new()),
+        Arc::new(mock_token),
+        Arc::new(opaque_generator()),
+        lockout_service(unlocked_store()),
+        without_two_factor(),
+        Arc::new(MockUserCache::new()),
+        900,
+        30,
+    );
+
+    let tokens = service.refresh("old", client_info()).await.unwrap();
+    assert_eq!(tokens.access_token, "new_jwt");
+    assert_eq!(tokens.refresh_token, "refresh");
+}
+
+#[tokio::test]
+async fn test_refresh_reuse_revokes_session() {
+    let user_id = Uuid::new_v4();
+    let session = create_session(user_id);
+    let session_id = session.id;
+    let token = create_refresh_token(session_id, true);
+
+    let mut mock_session_repo = MockSessionRepository::new();
+    mock_session_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(session.clone())));
+    mock_session_repo
+        .expect_revoke()
+        .with(eq(session_id))
+        .times(1)
+        .returning(|_| Ok(true));
+
+    let mut mock_refresh_repo = MockRefreshTokenRepository::new();
+    mock_refresh_repo
+        .expect_get_by_token_hash()
+        .returning(move |_| Ok(Some(token.clone())));
+    mock_refresh_repo.expect_mark_used().never();
+    mock_refresh_repo.expect_create().never();
+
+    let service = AuthService::new(
+        Arc::new(MockUserRepository::new()),
+        Arc::new(MockMembershipRepository::new()),
+        Arc::new(mock_session_repo),
+        Arc::new(mock_refresh_repo),
+        Arc::new(MockPasswordEncoder::new()),
+        Arc::new(MockTokenGenerator::new()),
+        Arc::new(opaque_generator()),
+        lockout_service(unlocked_store()),
+        without_two_factor(),
+        Arc::new(MockUserCache::new()),
+        900,
+        30,
+    );
+
+    let result = service.refresh("old", ClientInfoDto::default()).await;
+    assert!(matches!(result, Err(AppError::AuthError(_))));
+}
+
+#[tokio::test]
+async fn test_refresh_concurrent_use_revokes_session() {
+    let user_id = Uuid::new_v4();
+    let session = create_session(user_id);
+    let session_id = session.id;
+    let token = create_refresh_token(session_id, false);
+
+    let mut mock_session_repo = MockSessionRepository::new();
+    mock_session_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(session.clone())));
+    mock_session_repo
+        .expect_revoke()
+        .with(eq(session_id))
+        .times(1)
+        .returning(|_| Ok(true));
+
+    // Token looked unused but another request consumed it first
+    let mut mock_refresh_repo = MockRefreshTokenRepository::new();
+    mock_refresh_repo
+        .expect_get_by_token_hash()
+        .returning(move |_| Ok(Some(token.clone())));
+    mock_refresh_repo
+        .expect_mark_used()
+        .times(1)
+        .returning(|_| Ok(false));
+    mock_refresh_repo.expect_create().never();
+
+    let service = AuthService::new(
+        Arc::new(MockUserRepository::new()),
+        Arc::new(MockMembershipRepository::new()),
+        Arc::new(mock_session_repo),
+        Arc::new(mock_refresh_repo),
+        Arc::new(MockPasswordEncoder::new()),
+        Arc::new(MockTokenGenerator::new()),
+        Arc::new(opaque_generator()),
+        lockout_service(unlocked_store()),
+        without_two_factor(),
+        Arc::new(MockUserCache::new()),
+        900,
+        30,
+    );
+
+    let result = service.refresh("old", ClientInfoDto::default()).await;
+    assert!(matches!(result, Err(AppError::AuthError(_))));
+}
+
+#[tokio::test]
+async fn test_refresh_revoked_session_is_rejected() {
+    let user_id = Uuid::new_v4();
+    let mut session = create_session(user_id);
+    session.revoked_at = Some(Utc::now());
+    let token = create_refresh_token(session.id, false);
+
+    let mut mock_session_repo = MockSessionRepository::new();
+    mock_session_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(session.clone())));
+
+    let mut mock_refresh_repo = MockRefreshTokenRepository::new();
+    mock_refresh_repo
+        .expect_get_by_token_hash()
+        .returning(move |_| Ok(Some(token.clone())));
+    mock_refresh_repo.expect_mark_used().never();
+
+    let service = AuthService::new(
+        Arc::new(MockUserRepository::new()),
+        Arc::new(MockMembershipRepository::new()),
+        Arc::new(mock_session_repo),
+        Arc::new(mock_refresh_repo),
+        Arc::new(MockPasswordEncoder::new()),
+        Arc::new(MockTokenGenerator::new()),
+        Arc::new(opaque_generator()),
+        lockout_service(unlocked_store()),
+        without_two_factor(),
+        Arc::new(MockUserCache::new()),
+        900,
+        30,
+    );
+
+    let result = service.refresh("old", ClientInfoDto::default()).await;
+    assert!(matches!(result, Err(AppError::AuthError(_))));
+}
+
+fn login_service(
+    user: User,
+    encoder: MockPasswordEncoder,
+    store: MockLoginAttemptStore,
+) -> AuthService {
+    let mut mock_repo = MockUserRepository::new();
+    mock_repo
+        .expect_get_by_username_or_email_and_company()
+        .returning(move |_, _, _| Ok(Some(user.clone())));
+
+    AuthService::new(
+        Arc::new(mock_repo),
+        Arc::new(MockMembershipRepository::new()),
+        Arc::new(MockSessionRepository::new()),
+        Arc::new(MockRefreshTokenRepository::new()),
+        Arc::new(encoder),
+        Arc::new(MockTokenGenerator::new()),
+        Arc::new(opaque_generator()),
+        lockout_service(store),
+        without_two_factor(),
+        Arc::new(MockUserCache::new()),
+        900,
+        30,
+    )
+}
+
+fn wrong_password_login() -> LoginDto {
+    LoginDto {
+        username: Some("testuser".to_string()),
+        email: None,
+        password: "wrong".to_string(),
+        company_id: None,
+    }
+}
+
+#[tokio::test]
+async fn test_login_locked_account_is_rejected_before_password_check() {
+    let user_id = Uuid::new_v4();
+    let locked_until = Utc::now() + Duration::minutes(5);
+
+    let mut store = MockLoginAttemptStore::new();
+    store.expect_get().returning(move |user_id| {
+        Ok(Some(LoginAttempts {
+            locked_until: Some(locked_until),
+            ..login_attempts(user_id, 0, 1)
+        }))
+    });
+    store.expect_record_failure().never();
+
+    let mut mock_encoder = MockPasswordEncoder::new();
+    mock_encoder.expect_verify().never();
+
+    let service = login_service(create_user(user_id), mock_encoder, store);
+
+    let result = service
+        .login(wrong_password_login(), ClientInfoDto::default())
+        .await;
+    assert!(matches!(result, Err(AppError::AccountLocked { until }) if until == locked_until));
+}
+
+#[tokio::test]
+async fn test_login_failure_below_threshold_returns_invalid_credentials() {
+    let user_id = Uuid::new_v4();
+
+    let mut store = MockLoginAttemptStore::new();
+    store.expect_get().returning(|_| Ok(None));
+    store
+        .expect_record_failure()
+        .with(eq(user_id))
+        .times(1)
+        .returning(|user_id| Ok(login_attempts(user_id, 2, 0)));
+    store.expect_lock().never();
+
+    let mut mock_encoder = MockPasswordEncoder::new();
+    mock_encoder.expect_verify().returning(|_, _| Ok(false));
+
+    let service = login_service(create_user(user_id), mock_encoder, store);
+
+    let result = service
+        .login(wrong_password_login(), ClientInfoDto::default())
+        .await;
+    assert!(matches!(result, Err(AppError::InvalidCredentials)));
+}
+
+#[tokio::test]
+async fn test_login_failure_at_threshold_locks_with_exponential_duration() {
+    let user_id = Uuid::new_v4();
+
+    let mut store = MockLoginAttemptStore::new();
+    store.expect_get().returning(|_| Ok(None));
+    // Third failure after one previous lockout: 60s * 2
+    store
+        .expect_record_failure()
+        .times(1)
+        .returning(|user_id| Ok(login_attempts(user_id, 3, 1)));
+    store
+        .expect_lock()
+        .withf(move |id, until| {
+            let seconds = (*until - Utc::now()).num_seconds();
+            *id == user_id && (115..=120).contains(&seconds)
+        })
+        .times(1)
+        .returning(|user_id, until| {
+            Ok(LoginAttempts {
+                locked_until: Some(until),
+                ..login_attempts(user_id, 0, 2)
+            })
+        });
+
+    let mut mock_encoder = MockPasswordEncoder::new();
+    mock_encoder.expect_verify().returning(|_, _| Ok(false));
+
+    let service = login_service(create_user(user_id), mock_encoder, store);
+
+    let result = service
+        .login(wrong_password_login(), ClientInfoDto::default())
+        .await;
+    assert!(matches!(result, Err(AppError::AccountLocked { .. })));
+}
+
+#[tokio::test]
+async fn test_login_deactivated_user_is_rejected() {
+    let user_id = Uuid::new_v4();
+    let mut user = create_user(user_id);
+    user.deactivated_at = Some(Utc::now());
+
+    let mut store = MockLoginAttemptStore::new();
+    store.expect_get().returning(|_| Ok(None));
+    store.expect_clear().never();
+
+    let mut mock_encoder = MockPasswordEncoder::new();
+    mock_encoder.expect_verify().returning(|_, _| Ok(true));
+
+    // No session repository expectations: a session must not be created
+    let service = login_service(user, mock_encoder, store);
+
+    let dto = LoginDto {
+        password: "correct".to_string(),
+        ..wrong_password_login()
+    };
+
+    let result = service.login(dto, ClientInfoDto::default()).await;
+    assert!(
+        matches!(result, Err(AppError::AuthError(message)) if message == "Account is deactivated")
+    );
+}
+
+#[test]
+fn test_lockout_duration_doubles_up_to_maximum() {
+    assert_eq!(LOCKOUT_POLICY.lockout_duration(0), Duration::seconds(60));
+    assert_eq!(LOCKOUT_POLICY.lockout_duration(1), Duration::seconds(120));
+    assert_eq!(LOCKOUT_POLICY.lockout_duration(3), Duration::seconds(480));
+    assert_eq!(LOCKOUT_POLICY.lockout_duration(6), Duration::seconds(3600));
+    assert_eq!(LOCKOUT_POLICY.lockout_duration(100), Duration::seconds(3600));
+}
+
+fn enabled_two_factor(user_id: Uuid) -> TwoFactor {
+    TwoFactor {
+        user_id,
+        secret: "SECRET".to_string(),
+        enabled_at: Some(Utc::now()),
+        created_at: Utc::now(),
+    }
+}
+
+fn create_challenge(user_id: Uuid) -> TwoFactorChallenge {
+    TwoFactorChallenge {
+        id: Uuid::new_v4(),
+        user_id,
+        token_hash: "hashed_challenge".to_string(),
+        expires_at: Utc::now() + Duration::minutes(5),
+        used_at: None,
+        created_at: Utc::now(),
+    }
+}
+
+fn verify_dto(code: &str) -> VerifyTwoFactorDto {
+    VerifyTwoFactorDto {
+        challenge_token: "challenge".to_string(),
+        code: code.to_string(),
+    }
+}
+
+#[tokio::test]
+async fn test_login_with_two_factor_returns_challenge_without_session() {
+    let user_id = Uuid::new_v4();
+
+    let mut mock_repo = MockUserRepository::new();
+    let user = create_user(user_id);
+    mock_repo
+        .expect_get_by_username_or_email_and_company()
+        .returning(move |_, _, _| Ok(Some(user.clone())));
+
+    let mut mock_encoder = MockPasswordEncoder::new();
+    mock_encoder.expect_verify().returning(|_, _| Ok(true));
+
+    // Failed attempts must survive until the second factor is verified
+    let mut store = MockLoginAttemptStore::new();
+    store.expect_get().returning(|_| Ok(None));
+    store.expect_clear().never();
+
+    let mut two_factor = TwoFactorMocks::new();
+    two_factor
+        .two_factor_repo
+        .expect_get_by_id()
+        .returning(move |id| Ok(Some(enabled_two_factor(id))));
+    two_factor
+        .challenge_repo
+        .expect_create()
+        .withf(move |challenge| challenge.user_id == user_id && challenge.used_at.is_none())
+        .times(1)
+        .returning(Ok);
+
+    let mut mock_session_repo = MockSessionRepository::new();
+    mock_session_repo.expect_create().never();
+
+    let service = AuthService::new(
+        Arc::new(mock_repo),
+        Arc::new(MockMembershipRepository::new()),
+        Arc::new(mock_session_repo),
+        Arc::new(MockRefreshTokenRepository::new()),
+        Arc::new(mock_encoder),
+        Arc::new(MockTokenGenerator::new()),
+        Arc::new(opaque_generator()),
+        lockout_service(store),
+        two_factor.into_service(),
+        Arc::new(MockUserCache::new()),
+        900,
+        30,
+    );
+
+    let result = service
+        .login(
+            LoginDto {
+                password: "secret".to_string(),
+                ..wrong_password_login()
+            },
+            ClientInfoDto::default(),
+        )
+        .await
+        .unwrap();
+
+    let LoginResultDto::TwoFactorRequired(challenge) = result else {
+        panic!("expected a two-factor challenge");
+    };
+    assert_eq!(challenge.expires_in, 300);
+    assert!(!challenge.setup_required);
+}
+
+#[tokio::test]
+async fn test_verify_two_factor_issues_tokens_and_consumes_challenge() {
+    let user_id = Uuid::new_v4();
+    let challenge = create_challenge(user_id);
+    let challenge_id = challenge.id;
+
+    let mut mock_repo = MockUserRepository::new();
+    let user = create_user(user_id);
+    mock_repo
+        .expect_get_by_id()
+        .with(eq(user_id))
+        .returning(move |_| Ok(Some(user.clone())));
+
+    let mut store = MockLoginAttemptStore::new();
+    store.expect_get().returning(|_| Ok(None));
+    store.expect_clear().times(1).returning(|_| Ok(()));
+
+    let mut two_factor = TwoFactorMocks::new();
+    two_factor
+        .challenge_repo
+        .expect_get_by_token_hash()
+        .with(eq("hashed_challenge"))
+        .returning(move |_| Ok(Some(challenge.clone())));
+    two_factor
+        .challenge_repo
+        .expect_mark_used()
+        .with(eq(challenge_id))
+        .times(1)
+        .returning(|_| Ok(true));
+    two_factor
+        .two_factor_repo
+        .expect_get_by_id()
+        .returning(move |id| Ok(Some(enabled_two_factor(id))));
+    two_factor
+        .provider
+        .expect_verify()
+        .with(eq("SECRET"), eq("123456"))
+        .returning(|_, _| Ok(true));
+
+    let mut mock_session_repo = MockSessionRepository::new();
+    mock_session_repo.expect_create().times(1).returning(Ok);
+
+    let mut mock_refresh_repo = MockRefreshTokenRepository::new();
+    mock_refresh_repo.expect_create().times(1).returning(Ok);
+
+    let mut mock_token = MockTokenGenerator::new();
+    mock_token
+        .expect_generate()
+        .returning(|_, _| Ok("jwt_token".to_string()));
+
+    let service = AuthService::new(
+        Arc::new(mock_repo),
+        Arc::new(MockMembershipRepository::new()),
+        Arc::new(mock_session_repo),
+        Arc::new(mock_refresh_repo),
+        Arc::new(MockPasswordEncoder::new()),
+        Arc::new(mock_token),
+        Arc::new(opaque_generator()),
+        lockout_service(store),
+        two_factor.into_service(),
+        Arc::new(MockUserCache::new()),
+        900,
+        30,
+    );
+
+    let tokens = service
+        .verify_two_factor(verify_dto("123456"), ClientInfoDto::default())
+        .await
+        .unwrap();
+    assert_eq!(tokens.access_token, "jwt_token");
+}
+
+#[tokio::test]
+async fn test_verify_two_factor_wrong_code_counts_as_failed_login() {
+    let user_id = Uuid::new_v4();
+    let challenge = create_challenge(user_id);
+
+    let mut mock_repo = MockUserRepository::new();
+    let user = create_user(user_id);
+    mock_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(user.clone())));
+
+    let mut store = MockLoginAttemptStore::new();
+    store.expect_get().returning(|_| Ok(None));
+    store
+        .expect_record_failure()
+        .times(1)
+        .returning(|user_id| Ok(login_attempts(user_id, 1, 0)));
+    store.expect_clear().never();
+
+    let mut two_factor = TwoFactorMocks::new();
+    two_factor
+        .challenge_repo
+        .expect_get_by_token_hash()
+        .returning(move |_| Ok(Some(challenge.clone())));
+    two_factor.challenge_repo.expect_mark_used().never();
+    two_factor
+        .two_factor_repo
+        .expect_get_by_id()
+        .returning(move |id| Ok(Some(enabled_two_factor(id))));
+    two_factor
+        .provider
+        .expect_verify()
+        .returning(|_, _| Ok(false));
+    two_factor
+        .recovery_code_repo
+        .expect_get_by_code_hash()
+        .returning(|_, _| Ok(None));
+
+    let mut mock_session_repo = MockSessionRepository::new();
+    mock_session_repo.expect_create().never();
+
+    let service = AuthService::new(
+        Arc::new(mock_repo),
+        Arc::new(MockMembershipRepository::new()),
+        Arc::new(mock_session_repo),
+        Arc::new(MockRefreshTokenRepository::new()),
+        Arc::new(MockPasswordEncoder::new()),
+        Arc::new(MockTokenGenerator::new()),
+        Arc::new(opaque_generator()),
+        lockout_service(store),
+        two_factor.into_service(),
+        Arc::new(MockUserCache::new()),
+        900,
+        30,
+    );
+
+    let result = service
+        .verify_two_factor(verify_dto("000000"), ClientInfoDto::default())
+        .await;
+    assert!(matches!(result, Err(AppError::AuthError(_))));
+}
+
+fn session_service(mock_session_repo: MockSessionRepository) -> AuthService {
+    AuthService::new(
+        Arc::new(MockUserRepository::new()),
+        Arc::new(MockMembershipRepository::new()),
+        Arc::new(mock_session_repo),
+        Arc::new(MockRefreshTokenRepository::new()),
+        Arc::new(MockPasswordEncoder::new()),
+        Arc::new(MockTokenGenerator::new()),
+        Arc::new(opaque_generator()),
+        lockout_service(unlocked_store()),
+        without_two_factor(),
+        Arc::new(MockUserCache::new()),
+        900,
+        30,
+    )
+}
+
+#[tokio::test]
+async fn test_check_session_records_activity_of_stale_session() {
+    let mut session = create_session(Uuid::new_v4());
+    session.last_seen_at = Utc::now() - Duration::minutes(5);
+    let session_id = session.id;
+
+    let mut mock_session_repo = MockSessionRepository::new();
+    mock_session_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(session.clone())));
+    mock_session_repo
+        .expect_touch()
+        .withf(move |id, _, ip| *id == session_id && ip.as_deref() == Some("203.0.113.7"))
+        .times(1)
+        .returning(|_, _, _| Ok(()));
+
+    let service = session_service(mock_session_repo);
+
+    let active = service
+        .check_session(session_id, Some("203.0.113.7".to_string()))
+        .await
+        .unwrap();
+    assert!(active);
+}
+
+#[tokio::test]
+async fn test_check_session_skips_write_for_recent_activity() {
+    let session = create_session(Uuid::new_v4());
+    let session_id = session.id;
+
+    let mut mock_session_repo = MockSessionRepository::new();
+    mock_session_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(session.clone())));
+    mock_session_repo.expect_touch().never();
+
+    let service = session_service(mock_session_repo);
+
+    assert!(service.check_session(session_id, None).await.unwrap());
+}
+
+#[tokio::test]
+async fn test_check_session_rejects_revoked_session() {
+    let mut session = create_session(Uuid::new_v4());
+    session.revoked_at = Some(Utc::now());
+    let session_id = session.id;
+
+    let mut mock_session_repo = MockSessionRepository::new();
+    mock_session_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(session.clone())));
+    mock_session_repo.expect_touch().never();
+
+    let service = session_service(mock_session_repo);
+
+    assert!(!service.check_session(session_id, None).await.unwrap());
+}
+
+/// Login of a user with a second factor, which stops at the challenge
+fn rehash_login_service(
+    user_repo: MockUserRepository,
+    encoder: MockRehashingPasswordEncoder,
+    user_cache: MockUserCache,
+) -> AuthService {
+    let mut two_factor = TwoFactorMocks::new();
+    two_factor
+        .two_factor_repo
+        .expect_get_by_id()
+        .returning(move |id| Ok(Some(enabled_two_factor(id))));
+    two_factor.challenge_repo.expect_create().returning(Ok);
+
+    let mut store = MockLoginAttemptStore::new();
+    store.expect_get().returning(|_| Ok(None));
+
+    AuthService::new(
+        Arc::new(user_repo),
+        Arc::new(MockMembershipRepository::new()),
+        Arc::new(MockSessionRepository::new()),
+        Arc::new(MockRefreshTokenRepository::new()),
+        Arc::new(encoder),
+        Arc::new(MockTokenGenerator::new()),
+        Arc::new(opaque_generator()),
+        lockout_service(store),
+        two_factor.into_service(),
+        Arc::new(user_cache),
+        900,
+        30,
+    )
+}
+
+fn secret_login() -> LoginDto {
+    LoginDto {
+        password: "secret".to_string(),
+        ..wrong_password_login()
+    }
+}
+
+#[tokio::test]
+async fn test_login_rehashes_password_with_outdated_parameters() {
+    let user = create_user(Uuid::new_v4());
+    let user_id = user.id;
+    let old_hash = user.password_hash.clone();
+
+    let mut user_repo = MockUserRepository::new();
+    user_repo
+        .expect_get_by_username_or_email_and_company()
+        .returning(move |_, _, _| Ok(Some(user.clone())));
+    user_repo
+        .expect_update()
+        .withf(|user| user.password_hash == "stronger_hash")
+        .times(1)
+        .returning(Ok);
+
+    let mut encoder = MockRehashingPasswordEncoder::new();
+    encoder.expect_verify().returning(|_, _| Ok(true));
+    encoder
+        .expect_needs_rehash()
+        .with(eq(old_hash))
+        .returning(|_| true);
+    encoder
+        .expect_hash()
+        .with(eq("secret"))
+        .times(1)
+        .returning(|_| Ok("stronger_hash".to_string()));
+
+    // The cached copy still carries the old hash
+    let mut user_cache = MockUserCache::new();
+    user_cache
+        .expect_invalidate()
+        .with(eq(user_id))
+        .times(1)
+        .returning(|_| ());
+
+    let result = rehash_login_service(user_repo, encoder, user_cache)
+        .login(secret_login(), ClientInfoDto::default())
+        .await;
+
+    assert!(matches!(result, Ok(LoginResultDto::TwoFactorRequired(_))));
+}
+
+#[tokio::test]
+async fn test_login_succeeds_when_rehash_fails() {
+    let user = create_user(Uuid::new_v4());
+
+    let mut user_repo = MockUserRepository::new();
+    user_repo
+        .expect_get_by_username_or_email_and_company()
+        .returning(move |_, _, _| Ok(Some(user.clone())));
+    user_repo
+        .expect_update()
+        .times(1)
+        .returning(|_| Err(AppError::DatabaseError("database down".to_string())));
+
+    let mut encoder = MockRehashingPasswordEncoder::new();
+    encoder.expect_verify().returning(|_, _| Ok(true));
+    encoder.expect_needs_rehash().returning(|_| true);
+    encoder
+        .expect_hash()
+        .returning(|_| Ok("stronger_hash".to_string()));
+
+    let mut user_cache = MockUserCache::new();
+    user_cache.expect_invalidate().never();
+
+    let result = rehash_login_service(user_repo, encoder, user_cache)
+        .login(secret_login(), ClientInfoDto::default())
+        .await;
+
+    assert!(matches!(result, Ok(LoginResultDto::TwoFactorRequired(_))));
+}
+
+#[tokio::test]
+async fn test_login_keeps_current_hash() {
+    let user = create_user(Uuid::new_v4());
+
+    let mut user_repo = MockUserRepository::new();
+    user_repo
+        .expect_get_by_username_or_email_and_company()
+        .returning(move |_, _, _| Ok(Some(user.clone())));
+    user_repo.expect_update().never();
+
+    let mut encoder = MockRehashingPasswordEncoder::new();
+    encoder.expect_verify().returning(|_, _| Ok(true));
+    encoder.expect_needs_rehash().returning(|_| false);
+    encoder.expect_hash().never();
+
+    let result = rehash_login_service(user_repo, encoder, MockUserCache::new())
+        .login(secret_login(), ClientInfoDto::default())
+        .await;
+
+    assert!(matches!(result, Ok(LoginResultDto::TwoFactorRequired(_))));
+}
+
+fn create_membership(user_id: Uuid, company_id: Uuid) -> CompanyMembership {
+    CompanyMembership {
+        user_id,
+        company: Company {
+            id: company_id,
+            name: "Client".to_string(),
+            description: None,
+            parent_id: None,
+            two_factor_required_level: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        },
+        role: Role {
+            id: 4,
+            name: "supervisor".to_string(),
+            level: 50,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        },
+        created_at: Utc::now(),
+    }
+}
+
+#[tokio::test]
+async fn test_switch_company_issues_tokens_acting_in_membership() {
+    let user_id = Uuid::new_v4();
+    let company_id = Uuid::new_v4();
+    let user = create_user(user_id);
+    let session = create_session(user_id);
+    let session_id = session.id;
+
+    let mut mock_repo = MockUserRepository::new();
+    mock_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(user.clone())));
+
+    let mut mock_membership_repo = MockMembershipRepository::new();
+    mock_membership_repo
+        .expect_get()
+        .with(eq(user_id), eq(company_id))
+        .returning(|user_id, company_id| Ok(Some(create_membership(user_id, company_id))));
+
+    let mut mock_session_repo = MockSessionRepository::new();
+    mock_session_repo
+        .expect_get_by_id()
+        .with(eq(session_id))
+        .returning(move |_| Ok(Some(session.clone())));
+    mock_session_repo
+        .expect_update()
+        .withf(move |session| session.active_company_id == Some(company_id))
+        .times(1)
+        .returning(Ok);
+
+    let mut mock_refresh_repo = MockRefreshTokenRepository::new();
+    mock_refresh_repo.expect_create().times(1).returning(Ok);
+
+    let mut mock_token = MockTokenGenerator::new();
+    mock_token
+        .expect_generate()
+        .with(
+            eq(user_id.to_string()),
+            function(move |claims: &serde_json::Value| {
+                claims["cid"] == company_id.to_string() && claims["role"] == "supervisor"
+            }),
+        )
+        .times(1)
+        .returning(|_, _| Ok("jwt".to_string()));
+
+    let service = AuthService::new(
+        Arc::new(mock_repo),
+        Arc::new(mock_membership_repo),
+        Arc::new(mock_session_repo),
+        Arc::new(mock_refresh_repo),
+        Arc::new(MockPasswordEncoder::new()),
+        Arc::new(mock_token),
+        Arc::new(opaque_generator()),
+        lockout_service(unlocked_store()),
+        without_two_factor(),
+        Arc::new(MockUserCache::new()),
+        900,
+        30,
+    );
+
+    let tokens = service
+        .switch_company(user_id, session_id, company_id)
+        .await
+        .unwrap();
+    assert_eq!(tokens.access_token, "jwt");
+}
+
+#[tokio::test]
+async fn test_switch_company_without_membership_is_rejected() {
+    let user_id = Uuid::new_v4();
+    let user = create_user(user_id);
+    let session = create_session(user_id);
+    let session_id = session.id;
+
+    let mut mock_repo = MockUserRepository::new();
+    mock_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(user.clone())));
+
+    let mut mock_membership_repo = MockMembershipRepository::new();
+    mock_membership_repo.expect_get().returning(|_, _| Ok(None));
+
+    let mut mock_session_repo = MockSessionRepository::new();
+    mock_session_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(session.clone())));
+    mock_session_repo.expect_update().never();
+
+    let service = AuthService::new(
+        Arc::new(mock_repo),
+        Arc::new(mock_membership_repo),
+        Arc::new(mock_session_repo),
+        Arc::new(MockRefreshTokenRepository::new()),
+        Arc::new(MockPasswordEncoder::new()),
+        Arc::new(MockTokenGenerator::new()),
+        Arc::new(opaque_generator()),
+        lockout_service(unlocked_store()),
+        without_two_factor(),
+        Arc::new(MockUserCache::new()),
+        900,
+        30,
+    );
+
+    let result = service
+        .switch_company(user_id, session_id, Uuid::new_v4())
+        .await;
+    assert!(matches!(result, Err(AppError::NotFound(_))));
+}
+
+#[tokio::test]
+async fn test_refresh_after_membership_removed_acts_in_own_company() {
+    let user_id = Uuid::new_v4();
+    let user = create_user(user_id);
+    let mut session = create_session(user_id);
+    session.active_company_id = Some(Uuid::new_v4());
+    let session_id = session.id;
+    let token = create_refresh_token(session_id, false);
+
+    let mut mock_repo = MockUserRepository::new();
+    mock_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(user.clone())));
+
+    let mut mock_membership_repo = MockMembershipRepository::new();
+    mock_membership_repo.expect_get().returning(|_, _| Ok(None));
+
+    let mut mock_session_repo = MockSessionRepository::new();
+    mock_session_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(session.clone())));
+    mock_session_repo.expect_touch().returning(|_, _, _| Ok(()));
+    mock_session_repo
+        .expect_update()
+        .withf(|session| session.active_company_id.is_none())
+        .times(1)
+        .returning(Ok);
+
+    let mut mock_refresh_repo = MockRefreshTokenRepository::new();
+    mock_refresh_repo
+        .expect_get_by_token_hash()
+        .returning(move |_| Ok(Some(token.clone())));
+    mock_refresh_repo.expect_mark_used().returning(|_| Ok(true));
+    mock_refresh_repo.expect_create().returning(Ok);
+
+    let mut mock_token = MockTokenGenerator::new();
+    mock_token
+        .expect_generate()
+        .with(
+            eq(user_id.to_string()),
+            function(|claims: &serde_json::Value| {
+                claims.get("cid").is_none() && claims["role"] == "User"
+            }),
+        )
+        .times(1)
+        .returning(|_, _| Ok("jwt".to_string()));
+
+    let service = AuthService::new(
+        Arc::new(mock_repo),
+        Arc::new(mock_membership_repo),
+        Arc::new(mock_session_repo),
+        Arc::new(mock_refresh_repo),
+        Arc::new(MockPasswordEncoder::new()),
+        Arc::new(mock_token),
+        Arc::new(opaque_generator()),
+        lockout_service(unlocked_store()),
+        without_two_factor(),
+        Arc::new(MockUserCache::new()),
+        900,
+        30,
+    );
+
+    service.refresh("old", client_info()).await.unwrap();
 }
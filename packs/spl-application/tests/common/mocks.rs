@@ -0,0 +1,813 @@
+use async_trait::async_trait;
+use bytes::Bytes;
+use chrono::{DateTime, NaiveDate, Utc};
+use mockall::mock;
+use spl_domain::entities::auth::{
+    ApiKey, CompanyPasswordPolicy, EmailVerificationToken, IdentityProvider, Impersonation,
+    ImpersonationAction, LoginAttempts, OidcLoginState, PasswordResetToken, RecoveryCode,
+    RefreshToken, ServiceAccount, Session, TwoFactor, TwoFactorChallenge, UserIdentity,
+};
+use spl_domain::entities::company::{Company, CompanySettingsOverride};
+use spl_domain::entities::dashboard::{DashboardCounts, DashboardDetailedPlot, DashboardSummary};
+use spl_domain::entities::diagnostics::prediction::PredictionDetailed;
+use spl_domain::entities::diagnostics::{
+    InferenceJob, JobStatus, Label, MarkType, Prediction, PredictionBatch, PredictionBatchItem,
+    PredictionMark,
+};
+use spl_domain::entities::image::Image;
+use spl_domain::entities::offboarding::{CompanyOffboarding, OffboardingEvent};
+use spl_domain::entities::plot::{DetailedPlot, Plot};
+use spl_domain::entities::recommendation::Recommendation;
+use spl_domain::entities::team::{Team, TeamMember};
+use spl_domain::entities::usage::{CompanyQuota, QuotaLimit, UsageQuota, UsageRecord};
+use spl_domain::entities::user::{
+    CompanyMembership, Invitation, PermissionGrant, Role, RolePermission, User,
+};
+use spl_domain::ports::auth::{
+    BreachedPasswordList, LoginAttemptStore, OpaqueTokenGenerator, PasswordEncoder, TokenGenerator,
+    TwoFactorProvider,
+};
+use spl_domain::ports::cache::UserCache;
+use spl_domain::ports::integrations::{
+    BlobStorageClient, IntegrationClient, ModelPredictionClient, PredictionResult,
+};
+use spl_domain::ports::mailer::{EmailMessage, Mailer};
+use spl_domain::ports::oidc::{OidcClaims, OidcClient};
+use spl_domain::ports::repositories::auth::{
+    ApiKeyRepository, CompanyPasswordPolicyRepository, EmailVerificationTokenRepository,
+    IdentityProviderRepository, ImpersonationActionRepository, ImpersonationRepository,
+    OidcLoginStateRepository, PasswordHistoryRepository, PasswordResetTokenRepository,
+    RecoveryCodeRepository, RefreshTokenRepository, ServiceAccountRepository, SessionRepository,
+    TwoFactorChallengeRepository, TwoFactorRepository, UserIdentityRepository,
+};
+use spl_domain::ports::repositories::company::{CompanyRepository, CompanySettingsRepository};
+use spl_domain::ports::repositories::crud::CrudRepository;
+use spl_domain::ports::repositories::dashboard::DashboardSummaryRepository;
+use spl_domain::ports::repositories::diagnostics::{
+    InferenceJobRepository, LabelRepository, MarkTypeRepository, PredictionBatchItemRepository,
+    PredictionBatchRepository, PredictionMarkRepository, PredictionRepository,
+};
+use spl_domain::ports::repositories::image::ImageRepository;
+use spl_domain::ports::repositories::offboarding::{
+    CompanyOffboardingRepository, OffboardingEventRepository,
+};
+use spl_domain::ports::repositories::plot::PlotRepository;
+use spl_domain::ports::repositories::recommendation::RecommendationRepository;
+use spl_domain::ports::repositories::team::TeamRepository;
+use spl_domain::ports::repositories::usage::{CompanyQuotaRepository, UsageRepository};
+use spl_domain::ports::repositories::user::{
+    InvitationRepository, MembershipRepository, PermissionRepository, RoleRepository,
+    UserRepository,
+};
+use spl_shared::error::Result;
+use uuid::Uuid;
+
+mock! {
+    pub InvitationRepository {}
+    #[async_trait]
+    impl CrudRepository<Invitation, Uuid> for InvitationRepository {
+        async fn get_by_id(&self, id: Uuid) -> Result<Option<Invitation>>;
+        async fn create(&self, entity: Invitation) -> Result<Invitation>;
+        async fn update(&self, entity: Invitation) -> Result<Invitation>;
+        async fn delete(&self, id: Uuid) -> Result<Invitation>;
+    }
+    #[async_trait]
+    impl InvitationRepository for InvitationRepository {
+        async fn get_by_token_hash(&self, token_hash: &str) -> Result<Option<Invitation>>;
+        async fn get_by_company_id(&self, company_id: Uuid) -> Result<Vec<Invitation>>;
+        async fn get_pending_by_email(&self, company_id: Uuid, email: &str) -> Result<Option<Invitation>>;
+        async fn mark_accepted(&self, id: Uuid) -> Result<bool>;
+        async fn cancel(&self, id: Uuid) -> Result<bool>;
+        async fn count_by_role_id(&self, role_id: i32) -> Result<u64>;
+    }
+}
+
+mock! {
+    pub UserRepository {}
+    #[async_trait]
+    impl CrudRepository<User, Uuid> for UserRepository {
+        async fn get_by_id(&self, id: Uuid) -> Result<Option<User>>;
+        async fn create(&self, entity: User) -> Result<User>;
+        async fn update(&self, entity: User) -> Result<User>;
+        async fn delete(&self, id: Uuid) -> Result<User>;
+    }
+    #[async_trait]
+    impl UserRepository for UserRepository {
+        async fn get_by_ids(&self, ids: Vec<Uuid>) -> Result<Vec<User>>;
+        async fn get_by_username_and_company(&self, username: &str, company_id: Option<Uuid>) -> Result<Option<User>>;
+        async fn get_by_username_or_email_and_company(&self, username: Option<String>, email: Option<String>, company_id: Option<Uuid>) -> Result<Option<User>>;
+        async fn get_by_company_id(&self, company_id: Uuid) -> Result<Vec<User>> ;
+        async fn count_by_role_id(&self, role_id: i32) -> Result<u64>;
+        async fn count_active_by_role_ids(&self, role_ids: Vec<i32>) -> Result<u64>;
+    }
+}
+
+mock! {
+    pub RoleRepository {}
+    #[async_trait]
+    impl CrudRepository<Role, i32> for RoleRepository {
+        async fn get_by_id(&self, id: i32) -> Result<Option<Role>>;
+        async fn create(&self, entity: Role) -> Result<Role>;
+        async fn update(&self, entity: Role) -> Result<Role>;
+        async fn delete(&self, id: i32) -> Result<Role>;
+    }
+    #[async_trait]
+    impl RoleRepository for RoleRepository {
+        async fn get_by_name(&self, name: &str) -> Result<Option<Role>>;
+        async fn get_all(&self) -> Result<Vec<Role>>;
+    }
+}
+
+mock! {
+    pub MembershipRepository {}
+    #[async_trait]
+    impl MembershipRepository for MembershipRepository {
+        async fn get_by_user_id(&self, user_id: Uuid) -> Result<Vec<CompanyMembership>>;
+        async fn get_by_company_id(&self, company_id: Uuid) -> Result<Vec<CompanyMembership>>;
+        async fn get(&self, user_id: Uuid, company_id: Uuid) -> Result<Option<CompanyMembership>>;
+        async fn set(&self, user_id: Uuid, company_id: Uuid, role_id: i32) -> Result<CompanyMembership>;
+        async fn delete(&self, user_id: Uuid, company_id: Uuid) -> Result<()>;
+        async fn count_by_role_id(&self, role_id: i32) -> Result<u64>;
+    }
+}
+
+mock! {
+    pub CompanyRepository {}
+    #[async_trait]
+    impl CrudRepository<Company, Uuid> for CompanyRepository {
+        async fn get_by_id(&self, id: Uuid) -> Result<Option<Company>>;
+        async fn create(&self, entity: Company) -> Result<Company>;
+        async fn update(&self, entity: Company) -> Result<Company>;
+        async fn delete(&self, id: Uuid) -> Result<Company>;
+    }
+    #[async_trait]
+    impl CompanyRepository for CompanyRepository {
+        async fn get_all(&self) -> Result<Vec<Company>>;
+        async fn get_by_parent_ids(&self, parent_ids: Vec<Uuid>) -> Result<Vec<Company>>;
+    }
+}
+
+mock! {
+    pub CompanySettingsRepository {}
+    #[async_trait]
+    impl CrudRepository<CompanySettingsOverride, Uuid> for CompanySettingsRepository {
+        async fn get_by_id(&self, company_id: Uuid) -> Result<Option<CompanySettingsOverride>>;
+        async fn create(&self, entity: CompanySettingsOverride) -> Result<CompanySettingsOverride>;
+        async fn update(&self, entity: CompanySettingsOverride) -> Result<CompanySettingsOverride>;
+        async fn delete(&self, company_id: Uuid) -> Result<CompanySettingsOverride>;
+    }
+    #[async_trait]
+    impl CompanySettingsRepository for CompanySettingsRepository {}
+}
+
+mock! {
+    pub TeamRepository {}
+    #[async_trait]
+    impl CrudRepository<Team, Uuid> for TeamRepository {
+        async fn get_by_id(&self, id: Uuid) -> Result<Option<Team>>;
+        async fn create(&self, entity: Team) -> Result<Team>;
+        async fn update(&self, entity: Team) -> Result<Team>;
+        async fn delete(&self, id: Uuid) -> Result<Team>;
+    }
+    #[async_trait]
+    impl TeamRepository for TeamRepository {
+        async fn get_by_company_id(&self, company_id: Uuid) -> Result<Vec<Team>>;
+        async fn get_supervised_by(&self, user_id: Uuid) -> Result<Vec<Team>>;
+        async fn get_members(&self, team_ids: Vec<Uuid>) -> Result<Vec<TeamMember>>;
+        async fn set_member(&self, member: TeamMember) -> Result<TeamMember>;
+        async fn remove_member(&self, team_id: Uuid, user_id: Uuid) -> Result<()>;
+        async fn get_plot_ids(&self, team_ids: Vec<Uuid>) -> Result<Vec<Uuid>>;
+        async fn add_plot(&self, team_id: Uuid, plot_id: Uuid) -> Result<()>;
+        async fn remove_plot(&self, team_id: Uuid, plot_id: Uuid) -> Result<()>;
+    }
+}
+
+mock! {
+    pub PasswordEncoder {}
+    impl PasswordEncoder for PasswordEncoder {
+        fn hash(&self, password: &str) -> Result<String>;
+        fn verify(&self, password: &str, hash: &str) -> Result<bool>;
+    }
+}
+
+mock! {
+    pub OpaqueTokenGenerator {}
+    impl OpaqueTokenGenerator for OpaqueTokenGenerator {
+        fn generate(&self) -> String;
+        fn hash(&self, token: &str) -> String;
+    }
+}
+
+mock! {
+    pub Mailer {}
+    #[async_trait]
+    impl IntegrationClient for Mailer {
+        fn name(&self) -> &'static str;
+        async fn health_check(&self) -> Result<()>;
+    }
+    #[async_trait]
+    impl Mailer for Mailer {
+        async fn send(&self, message: EmailMessage) -> Result<()>;
+    }
+}
+
+mock! {
+    pub PermissionRepository {}
+    #[async_trait]
+    impl PermissionRepository for PermissionRepository {
+        async fn get_grants(&self) -> Result<Vec<RolePermission>>;
+        async fn get_by_role_id(&self, role_id: i32) -> Result<Vec<RolePermission>>;
+        async fn set_role_grants(&self, role_id: i32, grants: Vec<PermissionGrant>) -> Result<()>;
+    }
+}
+
+mock! {
+    pub CompanyPasswordPolicyRepository {}
+    #[async_trait]
+    impl CrudRepository<CompanyPasswordPolicy, Uuid> for CompanyPasswordPolicyRepository {
+        async fn get_by_id(&self, company_id: Uuid) -> Result<Option<CompanyPasswordPolicy>>;
+        async fn create(&self, entity: CompanyPasswordPolicy) -> Result<CompanyPasswordPolicy>;
+        async fn update(&self, entity: CompanyPasswordPolicy) -> Result<CompanyPasswordPolicy>;
+        async fn delete(&self, company_id: Uuid) -> Result<CompanyPasswordPolicy>;
+    }
+    #[async_trait]
+    impl CompanyPasswordPolicyRepository for CompanyPasswordPolicyRepository {}
+}
+
+mock! {
+    pub PasswordHistoryRepository {}
+    #[async_trait]
+    impl PasswordHistoryRepository for PasswordHistoryRepository {
+        async fn get_recent(&self, user_id: Uuid, limit: u64) -> Result<Vec<String>>;
+        async fn add(&self, user_id: Uuid, password_hash: &str) -> Result<()>;
+        async fn prune(&self, user_id: Uuid, keep: u64) -> Result<u64>;
+    }
+}
+
+mock! {
+    pub BreachedPasswordList {}
+    impl BreachedPasswordList for BreachedPasswordList {
+        fn contains(&self, password: &str) -> bool;
+    }
+}
+
+mock! {
+    pub UserCache {}
+    #[async_trait]
+    impl UserCache for UserCache {
+        async fn get(&self, id: Uuid) -> Option<User>;
+        async fn set(&self, user: &User);
+        async fn invalidate(&self, id: Uuid);
+        async fn clear(&self);
+    }
+}
+
+mock! {
+    pub CompanyOffboardingRepository {}
+    #[async_trait]
+    impl CrudRepository<CompanyOffboarding, Uuid> for CompanyOffboardingRepository {
+        async fn get_by_id(&self, id: Uuid) -> Result<Option<CompanyOffboarding>>;
+        async fn create(&self, entity: CompanyOffboarding) -> Result<CompanyOffboarding>;
+        async fn update(&self, entity: CompanyOffboarding) -> Result<CompanyOffboarding>;
+        async fn delete(&self, id: Uuid) -> Result<CompanyOffboarding>;
+    }
+    #[async_trait]
+    impl CompanyOffboardingRepository for CompanyOffboardingRepository {
+        async fn get_by_company_id(&self, company_id: Uuid) -> Result<Vec<CompanyOffboarding>>;
+        async fn get_running(&self) -> Result<Vec<CompanyOffboarding>>;
+    }
+}
+
+mock! {
+    pub OffboardingEventRepository {}
+    #[async_trait]
+    impl CrudRepository<OffboardingEvent, Uuid> for OffboardingEventRepository {
+        async fn get_by_id(&self, id: Uuid) -> Result<Option<OffboardingEvent>>;
+        async fn create(&self, entity: OffboardingEvent) -> Result<OffboardingEvent>;
+        async fn update(&self, entity: OffboardingEvent) -> Result<OffboardingEvent>;
+        async fn delete(&self, id: Uuid) -> Result<OffboardingEvent>;
+    }
+    #[async_trait]
+    impl OffboardingEventRepository for OffboardingEventRepository {
+        async fn get_by_offboarding_id(&self, offboarding_id: Uuid) -> Result<Vec<OffboardingEvent>>;
+    }
+}
+
+mock! {
+    pub PlotRepository {}
+    #[async_trait]
+    impl CrudRepository<Plot, Uuid> for PlotRepository {
+        async fn get_by_id(&self, id: Uuid) -> Result<Option<Plot>>;
+        async fn create(&self, entity: Plot) -> Result<Plot>;
+        async fn update(&self, entity: Plot) -> Result<Plot>;
+        async fn delete(&self, id: Uuid) -> Result<Plot>;
+    }
+    #[async_trait]
+    impl PlotRepository for PlotRepository {
+        async fn get_by_company_id(&self, company_id: Uuid) -> Result<Vec<Plot>>;
+        async fn get_all_by_company_id(&self, company_id: Uuid) -> Result<Vec<Plot>>;
+        async fn get_by_company_id_and_id(&self, company_id: Uuid, id: Uuid) -> Result<Option<Plot>>;
+        async fn get_detailed(&self, company_ids: Vec<Uuid>, plot_ids: Option<Vec<Uuid>>, offset: u64, limit: u64, labels: Vec<String>) -> Result<(i64, Vec<DetailedPlot>)>;
+        async fn get_detailed_by_id(&self, company_id: Uuid, plot_id: Uuid, labels: Vec<String>) -> Result<Option<DetailedPlot>>;
+        async fn get_default_detailed(&self, company_id: Uuid, labels: Vec<String>) -> Result<Option<DetailedPlot>>;
+    }
+}
+
+mock! {
+    pub PredictionRepository {}
+    #[async_trait]
+    impl CrudRepository<Prediction, Uuid> for PredictionRepository {
+        async fn get_by_id(&self, id: Uuid) -> Result<Option<Prediction>>;
+        async fn create(&self, entity: Prediction) -> Result<Prediction>;
+        async fn update(&self, entity: Prediction) -> Result<Prediction>;
+        async fn delete(&self, id: Uuid) -> Result<Prediction>;
+    }
+    #[async_trait]
+    impl PredictionRepository for PredictionRepository {
+        async fn get_by_user_id(&self, user_id: Uuid) -> Result<Vec<Prediction>>;
+        async fn get_by_user_id_and_id(&self, user_id: Uuid, id: Uuid) -> Result<Option<Prediction>>;
+        async fn get_by_company_id(&self, company_id: Uuid) -> Result<Vec<Prediction>>;
+        async fn get_all(&self) -> Result<Vec<Prediction>>;
+        async fn assign_plot_by_ids_and_user_id(&self, prediction_ids: Vec<Uuid>, user_id: Uuid, company_id: Option<Uuid>, plot_id: Option<Uuid>) -> Result<Vec<Prediction>>;
+        async fn has_unassigned_predictions(&self, user_id: Uuid) -> Result<bool>;
+        #[allow(clippy::too_many_arguments)]
+        async fn filter(&self, company_ids: Vec<Option<Uuid>>, user_ids: Option<Vec<Uuid>>, labels: Option<Vec<String>>, model_versions: Option<Vec<String>>, plot_ids: Option<Vec<Option<Uuid>>>, min_date: Option<chrono::DateTime<Utc>>, max_date: Option<chrono::DateTime<Utc>>, offset: u64, limit: u64) -> Result<(u64, Vec<Prediction>)>;
+        async fn get_detailed_by_user_id_and_id(&self, user_id: Uuid, prediction_id: Uuid) -> Result<Option<PredictionDetailed>>;
+    }
+}
+
+mock! {
+    pub InferenceJobRepository {}
+    #[async_trait]
+    impl CrudRepository<InferenceJob, Uuid> for InferenceJobRepository {
+        async fn get_by_id(&self, id: Uuid) -> Result<Option<InferenceJob>>;
+        async fn create(&self, entity: InferenceJob) -> Result<InferenceJob>;
+        async fn update(&self, entity: InferenceJob) -> Result<InferenceJob>;
+        async fn delete(&self, id: Uuid) -> Result<InferenceJob>;
+    }
+    #[async_trait]
+    impl InferenceJobRepository for InferenceJobRepository {
+        async fn create_many(&self, jobs: Vec<InferenceJob>) -> Result<()>;
+        async fn claim_next(&self, now: DateTime<Utc>, locked_until: DateTime<Utc>) -> Result<Option<InferenceJob>>;
+        async fn get_by_user_id(&self, user_id: Uuid, status: Option<JobStatus>) -> Result<Vec<InferenceJob>>;
+        async fn get_by_company_id(&self, company_id: Uuid) -> Result<Vec<InferenceJob>>;
+    }
+}
+
+mock! {
+    pub BlobStorageClient {}
+    #[async_trait]
+    impl IntegrationClient for BlobStorageClient {
+        fn name(&self) -> &'static str;
+        async fn health_check(&self) -> Result<()>;
+    }
+    #[async_trait]
+    impl BlobStorageClient for BlobStorageClient {
+        async fn upload(&self, file_content: Bytes, destination: &str) -> Result<String>;
+        async fn download(&self, source: &str) -> Result<Bytes>;
+        async fn delete(&self, path: &str) -> Result<()>;
+        async fn delete_directory(&self, prefix: &str) -> Result<()>;
+    }
+}
+
+mock! {
+    pub LabelRepository {}
+    #[async_trait]
+    impl CrudRepository<Label, i32> for LabelRepository {
+        async fn get_by_id(&self, id: i32) -> Result<Option<Label>>;
+        async fn create(&self, entity: Label) -> Result<Label>;
+        async fn update(&self, entity: Label) -> Result<Label>;
+        async fn delete(&self, id: i32) -> Result<Label>;
+    }
+    #[async_trait]
+    impl LabelRepository for LabelRepository {
+        async fn get_by_name(&self, name: &str) -> Result<Option<Label>>;
+        async fn get_by_severity(&self, percentage: f32) -> Result<Option<Label>>;
+        async fn get_all(&self) -> Result<Vec<Label>>;
+    }
+}
+
+mock! {
+    pub DashboardSummaryRepository {}
+    #[async_trait]
+    impl DashboardSummaryRepository for DashboardSummaryRepository {
+        async fn get_summary(&self, company_ids: Vec<Uuid>, users_ids: Option<Vec<Uuid>>, min_date: Option<DateTime<Utc>>, max_date: Option<DateTime<Utc>>, plot_ids: Vec<Option<Uuid>>, labels: Option<Vec<String>>, model_versions: Option<Vec<String>>) -> Result<DashboardSummary>;
+        async fn get_counts(&self, company_ids: Vec<Uuid>, users_ids: Option<Vec<Uuid>>, min_date: Option<DateTime<Utc>>, max_date: Option<DateTime<Utc>>, plot_ids: Vec<Option<Uuid>>, labels: Option<Vec<String>>, model_versions: Option<Vec<String>>, last_n: u64) -> Result<DashboardCounts>;
+        async fn get_summary_detailed_plot_by_id(&self, company_id: Uuid, plot_id: Uuid, company_ids: Vec<Uuid>, users_ids: Option<Vec<Uuid>>, min_date: Option<DateTime<Utc>>, max_date: Option<DateTime<Utc>>, labels: Option<Vec<String>>, model_versions: Option<Vec<String>>) -> Result<Option<DashboardDetailedPlot>>;
+        async fn get_default_summary_detailed_plot(&self, company_id: Uuid, company_ids: Vec<Uuid>, users_ids: Option<Vec<Uuid>>, min_date: Option<DateTime<Utc>>, max_date: Option<DateTime<Utc>>, plot_ids: Vec<Option<Uuid>>, labels: Option<Vec<String>>, model_versions: Option<Vec<String>>) -> Result<Option<DashboardDetailedPlot>>;
+        async fn get_compare(&self, company_ids: Vec<Uuid>, users_ids: Option<Vec<Uuid>>, min_date: Option<DateTime<Utc>>, max_date: Option<DateTime<Utc>>, plot_ids: Vec<Option<Uuid>>, labels: Option<Vec<String>>, model_versions: Option<Vec<String>>) -> Result<Vec<DashboardSummary>>;
+        async fn get_model_versions(&self, company_ids: Vec<Uuid>, users_ids: Option<Vec<Uuid>>) -> Result<Vec<String>>;
+    }
+}
+
+mock! {
+    pub UsageRepository {}
+    #[async_trait]
+    impl UsageRepository for UsageRepository {
+        async fn increment(&self, user_id: Uuid, company_id: Option<Uuid>, period: NaiveDate, predictions: u64, stored_bytes: u64) -> Result<()>;
+        async fn reserve(&self, user_id: Uuid, company_id: Option<Uuid>, period: NaiveDate, stored_bytes: u64, quota: &UsageQuota) -> Result<Option<QuotaLimit>>;
+        async fn release(&self, user_id: Uuid, period: NaiveDate, predictions: u64, stored_bytes: u64) -> Result<()>;
+        async fn get_by_user(&self, user_id: Uuid, period: NaiveDate) -> Result<Option<UsageRecord>>;
+        async fn get_by_company(&self, company_id: Uuid, period: NaiveDate) -> Result<Vec<UsageRecord>>;
+    }
+}
+
+mock! {
+    pub CompanyQuotaRepository {}
+    #[async_trait]
+    impl CrudRepository<CompanyQuota, Uuid> for CompanyQuotaRepository {
+        async fn get_by_id(&self, company_id: Uuid) -> Result<Option<CompanyQuota>>;
+        async fn create(&self, entity: CompanyQuota) -> Result<CompanyQuota>;
+        async fn update(&self, entity: CompanyQuota) -> Result<CompanyQuota>;
+        async fn delete(&self, company_id: Uuid) -> Result<CompanyQuota>;
+    }
+    #[async_trait]
+    impl CompanyQuotaRepository for CompanyQuotaRepository {}
+}
+
+mock! {
+    pub TwoFactorRepository {}
+    #[async_trait]
+    impl CrudRepository<TwoFactor, Uuid> for TwoFactorRepository {
+        async fn get_by_id(&self, id: Uuid) -> Result<Option<TwoFactor>>;
+        async fn create(&self, entity: TwoFactor) -> Result<TwoFactor>;
+        async fn update(&self, entity: TwoFactor) -> Result<TwoFactor>;
+        async fn delete(&self, id: Uuid) -> Result<TwoFactor>;
+    }
+    #[async_trait]
+    impl TwoFactorRepository for TwoFactorRepository {
+        async fn enable(&self, user_id: Uuid) -> Result<bool>;
+    }
+}
+
+mock! {
+    pub RecoveryCodeRepository {}
+    #[async_trait]
+    impl CrudRepository<RecoveryCode, Uuid> for RecoveryCodeRepository {
+        async fn get_by_id(&self, id: Uuid) -> Result<Option<RecoveryCode>>;
+        async fn create(&self, entity: RecoveryCode) -> Result<RecoveryCode>;
+        async fn update(&self, entity: RecoveryCode) -> Result<RecoveryCode>;
+        async fn delete(&self, id: Uuid) -> Result<RecoveryCode>;
+    }
+    #[async_trait]
+    impl RecoveryCodeRepository for RecoveryCodeRepository {
+        async fn get_by_code_hash(&self, user_id: Uuid, code_hash: &str) -> Result<Option<RecoveryCode>>;
+        async fn mark_used(&self, id: Uuid) -> Result<bool>;
+        async fn count_unused(&self, user_id: Uuid) -> Result<u64>;
+        async fn delete_by_user_id(&self, user_id: Uuid) -> Result<u64>;
+    }
+}
+
+mock! {
+    pub TwoFactorChallengeRepository {}
+    #[async_trait]
+    impl CrudRepository<TwoFactorChallenge, Uuid> for TwoFactorChallengeRepository {
+        async fn get_by_id(&self, id: Uuid) -> Result<Option<TwoFactorChallenge>>;
+        async fn create(&self, entity: TwoFactorChallenge) -> Result<TwoFactorChallenge>;
+        async fn update(&self, entity: TwoFactorChallenge) -> Result<TwoFactorChallenge>;
+        async fn delete(&self, id: Uuid) -> Result<TwoFactorChallenge>;
+    }
+    #[async_trait]
+    impl TwoFactorChallengeRepository for TwoFactorChallengeRepository {
+        async fn get_by_token_hash(&self, token_hash: &str) -> Result<Option<TwoFactorChallenge>>;
+        async fn mark_used(&self, id: Uuid) -> Result<bool>;
+    }
+}
+
+mock! {
+    pub TwoFactorProvider {}
+    impl TwoFactorProvider for TwoFactorProvider {
+        fn generate_secret(&self) -> String;
+        fn provisioning_uri(&self, secret: &str, account_name: &str) -> Result<String>;
+        fn verify(&self, secret: &str, code: &str) -> Result<bool>;
+        fn generate_recovery_code(&self) -> String;
+    }
+}
+
+mock! {
+    pub ServiceAccountRepository {}
+    #[async_trait]
+    impl CrudRepository<ServiceAccount, Uuid> for ServiceAccountRepository {
+        async fn get_by_id(&self, id: Uuid) -> Result<Option<ServiceAccount>>;
+        async fn create(&self, entity: ServiceAccount) -> Result<ServiceAccount>;
+        async fn update(&self, entity: ServiceAccount) -> Result<ServiceAccount>;
+        async fn delete(&self, id: Uuid) -> Result<ServiceAccount>;
+    }
+    #[async_trait]
+    impl ServiceAccountRepository for ServiceAccountRepository {
+        async fn get_by_company_id(&self, company_id: Uuid) -> Result<Vec<ServiceAccount>>;
+    }
+}
+
+mock! {
+    pub ApiKeyRepository {}
+    #[async_trait]
+    impl CrudRepository<ApiKey, Uuid> for ApiKeyRepository {
+        async fn get_by_id(&self, id: Uuid) -> Result<Option<ApiKey>>;
+        async fn create(&self, entity: ApiKey) -> Result<ApiKey>;
+        async fn update(&self, entity: ApiKey) -> Result<ApiKey>;
+        async fn delete(&self, id: Uuid) -> Result<ApiKey>;
+    }
+    #[async_trait]
+    impl ApiKeyRepository for ApiKeyRepository {
+        async fn get_by_key_hash(&self, key_hash: &str) -> Result<Option<ApiKey>>;
+        async fn get_by_service_account_id(&self, service_account_id: Uuid) -> Result<Vec<ApiKey>>;
+        async fn revoke(&self, id: Uuid) -> Result<bool>;
+        async fn touch(&self, id: Uuid, used_at: DateTime<Utc>) -> Result<()>;
+    }
+}
+
+mock! {
+    pub IdentityProviderRepository {}
+    #[async_trait]
+    impl CrudRepository<IdentityProvider, Uuid> for IdentityProviderRepository {
+        async fn get_by_id(&self, company_id: Uuid) -> Result<Option<IdentityProvider>>;
+        async fn create(&self, entity: IdentityProvider) -> Result<IdentityProvider>;
+        async fn update(&self, entity: IdentityProvider) -> Result<IdentityProvider>;
+        async fn delete(&self, company_id: Uuid) -> Result<IdentityProvider>;
+    }
+    #[async_trait]
+    impl IdentityProviderRepository for IdentityProviderRepository {}
+}
+
+mock! {
+    pub UserIdentityRepository {}
+    #[async_trait]
+    impl CrudRepository<UserIdentity, Uuid> for UserIdentityRepository {
+        async fn get_by_id(&self, id: Uuid) -> Result<Option<UserIdentity>>;
+        async fn create(&self, entity: UserIdentity) -> Result<UserIdentity>;
+        async fn update(&self, entity: UserIdentity) -> Result<UserIdentity>;
+        async fn delete(&self, id: Uuid) -> Result<UserIdentity>;
+    }
+    #[async_trait]
+    impl UserIdentityRepository for UserIdentityRepository {
+        async fn get_by_subject(&self, issuer: &str, subject: &str) -> Result<Option<UserIdentity>>;
+    }
+}
+
+mock! {
+    pub OidcLoginStateRepository {}
+    #[async_trait]
+    impl CrudRepository<OidcLoginState, Uuid> for OidcLoginStateRepository {
+        async fn get_by_id(&self, id: Uuid) -> Result<Option<OidcLoginState>>;
+        async fn create(&self, entity: OidcLoginState) -> Result<OidcLoginState>;
+        async fn update(&self, entity: OidcLoginState) -> Result<OidcLoginState>;
+        async fn delete(&self, id: Uuid) -> Result<OidcLoginState>;
+    }
+    #[async_trait]
+    impl OidcLoginStateRepository for OidcLoginStateRepository {
+        async fn get_by_state_hash(&self, state_hash: &str) -> Result<Option<OidcLoginState>>;
+        async fn mark_used(&self, id: Uuid) -> Result<bool>;
+    }
+}
+
+mock! {
+    pub OidcClient {}
+    #[async_trait]
+    impl OidcClient for OidcClient {
+        async fn authorization_url(
+            &self,
+            provider: &IdentityProvider,
+            redirect_uri: &str,
+            state: &str,
+            nonce: &str,
+            code_verifier: &str,
+        ) -> Result<String>;
+        async fn exchange_code(
+            &self,
+            provider: &IdentityProvider,
+            code: &str,
+            redirect_uri: &str,
+            code_verifier: &str,
+            nonce: &str,
+        ) -> Result<OidcClaims>;
+    }
+}
+
+mock! {
+    pub TokenGenerator {}
+    impl TokenGenerator for TokenGenerator {
+        fn generate(&self, sub: &str, claims: serde_json::Value) -> Result<String>;
+        fn validate(&self, token: &str) -> Result<serde_json::Value>;
+    }
+}
+
+mock! {
+    pub SessionRepository {}
+    #[async_trait]
+    impl CrudRepository<Session, Uuid> for SessionRepository {
+        async fn get_by_id(&self, id: Uuid) -> Result<Option<Session>>;
+        async fn create(&self, entity: Session) -> Result<Session>;
+        async fn update(&self, entity: Session) -> Result<Session>;
+        async fn delete(&self, id: Uuid) -> Result<Session>;
+    }
+    #[async_trait]
+    impl SessionRepository for SessionRepository {
+        async fn revoke(&self, id: Uuid) -> Result<bool>;
+        async fn revoke_by_user_id(&self, user_id: Uuid) -> Result<u64>;
+        async fn get_active_by_user_id(&self, user_id: Uuid) -> Result<Vec<Session>>;
+        async fn touch(&self, id: Uuid, seen_at: DateTime<Utc>, ip_address: Option<String>) -> Result<()>;
+    }
+}
+
+mock! {
+    pub RefreshTokenRepository {}
+    #[async_trait]
+    impl CrudRepository<RefreshToken, Uuid> for RefreshTokenRepository {
+        async fn get_by_id(&self, id: Uuid) -> Result<Option<RefreshToken>>;
+        async fn create(&self, entity: RefreshToken) -> Result<RefreshToken>;
+        async fn update(&self, entity: RefreshToken) -> Result<RefreshToken>;
+        async fn delete(&self, id: Uuid) -> Result<RefreshToken>;
+    }
+    #[async_trait]
+    impl RefreshTokenRepository for RefreshTokenRepository {
+        async fn get_by_token_hash(&self, token_hash: &str) -> Result<Option<RefreshToken>>;
+        async fn mark_used(&self, id: Uuid) -> Result<bool>;
+    }
+}
+
+mock! {
+    pub LoginAttemptStore {}
+    #[async_trait]
+    impl LoginAttemptStore for LoginAttemptStore {
+        async fn get(&self, user_id: Uuid) -> Result<Option<LoginAttempts>>;
+        async fn record_failure(&self, user_id: Uuid) -> Result<LoginAttempts>;
+        async fn lock(&self, user_id: Uuid, until: DateTime<Utc>) -> Result<LoginAttempts>;
+        async fn clear(&self, user_id: Uuid) -> Result<()>;
+    }
+}
+
+mock! {
+    pub PasswordResetTokenRepository {}
+    #[async_trait]
+    impl CrudRepository<PasswordResetToken, Uuid> for PasswordResetTokenRepository {
+        async fn get_by_id(&self, id: Uuid) -> Result<Option<PasswordResetToken>>;
+        async fn create(&self, entity: PasswordResetToken) -> Result<PasswordResetToken>;
+        async fn update(&self, entity: PasswordResetToken) -> Result<PasswordResetToken>;
+        async fn delete(&self, id: Uuid) -> Result<PasswordResetToken>;
+    }
+    #[async_trait]
+    impl PasswordResetTokenRepository for PasswordResetTokenRepository {
+        async fn get_by_token_hash(&self, token_hash: &str) -> Result<Option<PasswordResetToken>>;
+        async fn mark_used(&self, id: Uuid) -> Result<bool>;
+        async fn invalidate_by_user_id(&self, user_id: Uuid) -> Result<u64>;
+    }
+}
+
+mock! {
+    pub EmailVerificationTokenRepository {}
+    #[async_trait]
+    impl CrudRepository<EmailVerificationToken, Uuid> for EmailVerificationTokenRepository {
+        async fn get_by_id(&self, id: Uuid) -> Result<Option<EmailVerificationToken>>;
+        async fn create(&self, entity: EmailVerificationToken) -> Result<EmailVerificationToken>;
+        async fn update(&self, entity: EmailVerificationToken) -> Result<EmailVerificationToken>;
+        async fn delete(&self, id: Uuid) -> Result<EmailVerificationToken>;
+    }
+    #[async_trait]
+    impl EmailVerificationTokenRepository for EmailVerificationTokenRepository {
+        async fn get_by_token_hash(&self, token_hash: &str) -> Result<Option<EmailVerificationToken>>;
+        async fn mark_used(&self, id: Uuid) -> Result<bool>;
+        async fn invalidate_by_user_id(&self, user_id: Uuid) -> Result<u64>;
+    }
+}
+
+mock! {
+    pub ImpersonationRepository {}
+    #[async_trait]
+    impl CrudRepository<Impersonation, Uuid> for ImpersonationRepository {
+        async fn get_by_id(&self, id: Uuid) -> Result<Option<Impersonation>>;
+        async fn create(&self, entity: Impersonation) -> Result<Impersonation>;
+        async fn update(&self, entity: Impersonation) -> Result<Impersonation>;
+        async fn delete(&self, id: Uuid) -> Result<Impersonation>;
+    }
+    #[async_trait]
+    impl ImpersonationRepository for ImpersonationRepository {
+        async fn end(&self, id: Uuid) -> Result<bool>;
+        async fn get_by_user_id(&self, user_id: Uuid) -> Result<Vec<Impersonation>>;
+    }
+}
+
+mock! {
+    pub ImpersonationActionRepository {}
+    #[async_trait]
+    impl CrudRepository<ImpersonationAction, Uuid> for ImpersonationActionRepository {
+        async fn get_by_id(&self, id: Uuid) -> Result<Option<ImpersonationAction>>;
+        async fn create(&self, entity: ImpersonationAction) -> Result<ImpersonationAction>;
+        async fn update(&self, entity: ImpersonationAction) -> Result<ImpersonationAction>;
+        async fn delete(&self, id: Uuid) -> Result<ImpersonationAction>;
+    }
+    #[async_trait]
+    impl ImpersonationActionRepository for ImpersonationActionRepository {
+        async fn get_by_impersonation_id(&self, impersonation_id: Uuid) -> Result<Vec<ImpersonationAction>>;
+    }
+}
+
+mock! {
+    pub MarkTypeRepository {}
+    #[async_trait]
+    impl CrudRepository<MarkType, i32> for MarkTypeRepository {
+        async fn get_by_id(&self, id: i32) -> Result<Option<MarkType>>;
+        async fn create(&self, entity: MarkType) -> Result<MarkType>;
+        async fn update(&self, entity: MarkType) -> Result<MarkType>;
+        async fn delete(&self, id: i32) -> Result<MarkType>;
+    }
+    #[async_trait]
+    impl MarkTypeRepository for MarkTypeRepository {
+        async fn get_by_ids(&self, ids: Vec<i32>) -> Result<Vec<MarkType>>;
+        async fn get_by_name(&self, name: &str) -> Result<Option<MarkType>>;
+        async fn get_all(&self) -> Result<Vec<MarkType>>;
+    }
+}
+
+mock! {
+    pub ImageRepository {}
+    #[async_trait]
+    impl ImageRepository for ImageRepository {
+        async fn create(&self, image: Image) -> Result<Image>;
+        async fn get_by_id(&self, id: Uuid) -> Result<Option<Image>>;
+        async fn get_by_user_id(&self, user_id: Uuid) -> Result<Vec<Image>>;
+        async fn update(&self, image: Image) -> Result<Image>;
+        async fn delete(&self, id: Uuid) -> Result<()>;
+    }
+}
+
+mock! {
+    pub PredictionMarkRepository {}
+    #[async_trait]
+    impl CrudRepository<PredictionMark, Uuid> for PredictionMarkRepository {
+        async fn get_by_id(&self, id: Uuid) -> Result<Option<PredictionMark>>;
+        async fn create(&self, entity: PredictionMark) -> Result<PredictionMark>;
+        async fn update(&self, entity: PredictionMark) -> Result<PredictionMark>;
+        async fn delete(&self, id: Uuid) -> Result<PredictionMark>;
+    }
+    #[async_trait]
+    impl PredictionMarkRepository for PredictionMarkRepository {
+        async fn get_by_ids(&self, ids: Vec<Uuid>) -> Result<Vec<PredictionMark>>;
+        async fn create_many(&self, marks: Vec<PredictionMark>) -> Result<Vec<PredictionMark>>;
+        async fn get_by_prediction_id(&self, prediction_id: Uuid) -> Result<Vec<PredictionMark>>;
+        async fn get_by_predictions_ids(&self, prediction_id: Vec<Uuid>) -> Result<Vec<PredictionMark>>;
+    }
+}
+
+mock! {
+    pub PredictionBatchRepository {}
+    #[async_trait]
+    impl CrudRepository<PredictionBatch, Uuid> for PredictionBatchRepository {
+        async fn get_by_id(&self, id: Uuid) -> Result<Option<PredictionBatch>>;
+        async fn create(&self, entity: PredictionBatch) -> Result<PredictionBatch>;
+        async fn update(&self, entity: PredictionBatch) -> Result<PredictionBatch>;
+        async fn delete(&self, id: Uuid) -> Result<PredictionBatch>;
+    }
+    #[async_trait]
+    impl PredictionBatchRepository for PredictionBatchRepository {
+        async fn get_by_user_id(&self, user_id: Uuid) -> Result<Vec<PredictionBatch>>;
+        async fn refresh_progress(&self, id: Uuid) -> Result<PredictionBatch>;
+    }
+}
+
+mock! {
+    pub PredictionBatchItemRepository {}
+    #[async_trait]
+    impl CrudRepository<PredictionBatchItem, Uuid> for PredictionBatchItemRepository {
+        async fn get_by_id(&self, id: Uuid) -> Result<Option<PredictionBatchItem>>;
+        async fn create(&self, entity: PredictionBatchItem) -> Result<PredictionBatchItem>;
+        async fn update(&self, entity: PredictionBatchItem) -> Result<PredictionBatchItem>;
+        async fn delete(&self, id: Uuid) -> Result<PredictionBatchItem>;
+    }
+    #[async_trait]
+    impl PredictionBatchItemRepository for PredictionBatchItemRepository {
+        async fn create_many(&self, items: Vec<PredictionBatchItem>) -> Result<()>;
+        async fn get_by_batch_id(&self, batch_id: Uuid) -> Result<Vec<PredictionBatchItem>>;
+    }
+}
+
+mock! {
+    pub ModelPredictionClient {}
+    #[async_trait]
+    impl IntegrationClient for ModelPredictionClient {
+        fn name(&self) -> &'static str;
+        async fn health_check(&self) -> Result<()>;
+    }
+    #[async_trait]
+    impl ModelPredictionClient for ModelPredictionClient {
+        async fn predict(&self, image_bytes: &[u8]) -> Result<PredictionResult>;
+        fn get_image_size(&self) -> u32;
+    }
+}
+
+mock! {
+    #[derive(Clone)]
+    pub RecommendationRepository {}
+    #[async_trait]
+    impl CrudRepository<Recommendation, Uuid> for RecommendationRepository {
+        async fn get_by_id(&self, id: Uuid) -> Result<Option<Recommendation>>;
+        async fn create(&self, entity: Recommendation) -> Result<Recommendation>;
+        async fn update(&self, entity: Recommendation) -> Result<Recommendation>;
+        async fn delete(&self, id: Uuid) -> Result<Recommendation>;
+    }
+    #[async_trait]
+    impl RecommendationRepository for RecommendationRepository {
+        async fn get_all(&self) -> Result<Vec<Recommendation>>;
+        async fn get_by_severity(&self, percentage: f32) -> Result<Vec<Recommendation>>;
+    }
+}
@@ -0,0 +1,57 @@
+#![allow(dead_code)]
+
+pub mod mocks;
+
+use chrono::Utc;
+use spl_domain::entities::company::Company;
+use spl_domain::entities::user::{PermissionScope, Role, RolePermission, User};
+use uuid::Uuid;
+
+pub fn create_company() -> Company {
+    Company {
+        id: Uuid::new_v4(),
+        name: "Company".to_string(),
+        description: None,
+        parent_id: None,
+        two_factor_required_level: None,
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+    }
+}
+
+/// Role whose id is its level
+pub fn create_role(name: &str, level: i16) -> Role {
+    Role {
+        id: level as i32,
+        name: name.to_string(),
+        level,
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+    }
+}
+
+pub fn create_user(role: &str, level: i16, company: Option<Company>) -> User {
+    User {
+        id: Uuid::new_v4(),
+        username: role.to_string(),
+        email: None,
+        email_verified_at: None,
+        password_hash: "hash".to_string(),
+        name: None,
+        surname: None,
+        role: create_role(role, level),
+        company,
+        deactivated_at: None,
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+    }
+}
+
+pub fn grant(role: &str, permission: &str, scope: PermissionScope) -> RolePermission {
+    RolePermission {
+        role_id: 0,
+        role: role.to_string(),
+        permission: permission.to_string(),
+        scope,
+    }
+}
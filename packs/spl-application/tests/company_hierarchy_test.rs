@@ -0,0 +1,551 @@
+mod common;
+
+use chrono::Utc;
+use common::mocks::{
+    MockCompanyRepository, MockCompanySettingsRepository, MockDashboardSummaryRepository,
+    MockLabelRepository, MockMembershipRepository, MockPermissionRepository, MockPlotRepository,
+    MockPredictionRepository, MockTeamRepository, MockUserCache, MockUserRepository,
+};
+use common::{create_company, create_user, grant};
+use mockall::predicate::*;
+use spl_application::dtos::dashboard::{DashboardFiltersDto, DashboardSummaryDto};
+use spl_application::services::access_control::AccessControlService;
+use spl_application::services::company::CompanyService;
+use spl_application::services::company_settings::CompanySettingsService;
+use spl_application::services::dashboard::DashboardService;
+use spl_application::services::plot::PlotService;
+use spl_application::services::policy::PolicyService;
+use spl_domain::entities::company::{Company, CompanySettings};
+use spl_domain::entities::dashboard::DashboardSummary;
+use spl_domain::entities::image::ImageFormat;
+use spl_domain::entities::plot::Plot;
+use spl_domain::entities::user::{permissions, CompanyMembership, PermissionScope};
+use spl_shared::error::AppError;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// A cooperative with two member farms, the first one with a farm of its own
+struct Hierarchy {
+    cooperative: Company,
+    north: Company,
+    south: Company,
+    north_annex: Company,
+}
+
+impl Hierarchy {
+    fn new() -> Self {
+        let cooperative = create_member("Cooperative", None);
+        let north = create_member("North", Some(cooperative.id));
+        let south = create_member("South", Some(cooperative.id));
+        let north_annex = create_member("North Annex", Some(north.id));
+
+        Self {
+            cooperative,
+            north,
+            south,
+            north_annex,
+        }
+    }
+
+    fn companies(&self) -> Vec<Company> {
+        vec![
+            self.cooperative.clone(),
+            self.north.clone(),
+            self.south.clone(),
+            self.north_annex.clone(),
+        ]
+    }
+}
+
+struct Mocks {
+    company_repo: MockCompanyRepository,
+    user_repo: MockUserRepository,
+    membership_repo: MockMembershipRepository,
+    plot_repo: MockPlotRepository,
+    label_repo: MockLabelRepository,
+    dashboard_repo: MockDashboardSummaryRepository,
+}
+
+impl Mocks {
+    fn new(hierarchy: &Hierarchy) -> Self {
+        let mut company_repo = MockCompanyRepository::new();
+        let companies = hierarchy.companies();
+        company_repo
+            .expect_get_by_parent_ids()
+            .returning(move |parent_ids| {
+                Ok(companies
+                    .iter()
+                    .filter(|c| c.parent_id.is_some_and(|p| parent_ids.contains(&p)))
+                    .cloned()
+                    .collect())
+            });
+        let companies = hierarchy.companies();
+        company_repo
+            .expect_get_by_id()
+            .returning(move |id| Ok(companies.iter().find(|c| c.id == id).cloned()));
+
+        Self {
+            company_repo,
+            user_repo: MockUserRepository::new(),
+            membership_repo: MockMembershipRepository::new(),
+            plot_repo: MockPlotRepository::new(),
+            label_repo: MockLabelRepository::new(),
+            dashboard_repo: MockDashboardSummaryRepository::new(),
+        }
+    }
+
+    fn into_services(self) -> Services {
+        let mut permission_repo = MockPermissionRepository::new();
+        permission_repo.expect_get_grants().returning(|| {
+            Ok(vec![
+                grant("admin", permissions::COMPANIES_MANAGE, PermissionScope::Any),
+                grant("manager", permissions::PLOTS_READ, PermissionScope::Company),
+                grant(
+                    "manager",
+                    permissions::PREDICTIONS_READ,
+                    PermissionScope::Company,
+                ),
+                grant(
+                    "manager",
+                    permissions::COMPANIES_MANAGE,
+                    PermissionScope::Company,
+                ),
+            ])
+        });
+
+        let company_repo = Arc::new(self.company_repo);
+        let user_repo = Arc::new(self.user_repo);
+        let plot_repo = Arc::new(self.plot_repo);
+        let access_control = Arc::new(AccessControlService::new(
+            company_repo.clone(),
+            Arc::new(MockTeamRepository::new()),
+            Arc::new(PolicyService::new(Arc::new(permission_repo), 30)),
+        ));
+        let company_settings = Arc::new(CompanySettingsService::new(
+            Arc::new(MockCompanySettingsRepository::new()),
+            company_repo.clone(),
+            access_control.clone(),
+            Arc::new(MockUserCache::new()),
+            defaults(),
+        ));
+
+        Services {
+            company: CompanyService::new(company_repo, access_control.clone()),
+            plot: PlotService::new(
+                plot_repo.clone(),
+                Arc::new(MockPredictionRepository::new()),
+                access_control.clone(),
+                company_settings,
+            ),
+            dashboard: DashboardService::new(
+                Arc::new(self.dashboard_repo),
+                Arc::new(self.label_repo),
+                plot_repo,
+                user_repo,
+                Arc::new(self.membership_repo),
+                access_control,
+            ),
+        }
+    }
+}
+
+struct Services {
+    company: CompanyService,
+    plot: PlotService,
+    dashboard: DashboardService,
+}
+
+fn defaults() -> CompanySettings {
+    CompanySettings {
+        timezone: "UTC".to_string(),
+        default_language: "es".to_string(),
+        data_retention_days: 0,
+        two_factor_required_level: None,
+        default_page_size: 16,
+        allowed_upload_formats: ImageFormat::ALL.to_vec(),
+        alert_severity_threshold: 50.0,
+        alert_confidence_threshold: 0.8,
+    }
+}
+
+fn create_member(name: &str, parent_id: Option<Uuid>) -> Company {
+    Company {
+        name: name.to_string(),
+        parent_id,
+        ..create_company()
+    }
+}
+
+fn create_plot(company_id: Uuid, name: &str) -> Plot {
+    Plot {
+        id: Uuid::new_v4(),
+        company_id,
+        name: name.to_string(),
+        description: None,
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+    }
+}
+
+/// The default plot every company lists for its unassigned predictions
+fn default_plot(company_id: Uuid) -> Plot {
+    Plot {
+        id: Uuid::nil(),
+        ..create_plot(company_id, "Default")
+    }
+}
+
+fn empty_summary() -> DashboardSummary {
+    DashboardSummary {
+        total: 0,
+        plots: 0,
+        mean_severity: 0.0,
+        distribution: None,
+    }
+}
+
+#[tokio::test]
+async fn test_cooperative_lists_plots_of_every_member_farm() {
+    let hierarchy = Hierarchy::new();
+    let manager = create_user("manager", 80, Some(hierarchy.cooperative.clone()));
+
+    let mut mocks = Mocks::new(&hierarchy);
+    for company in hierarchy.companies() {
+        let plot = create_plot(company.id, &company.name);
+        mocks
+            .plot_repo
+            .expect_get_by_company_id()
+            .with(eq(company.id))
+            .returning(move |_| Ok(vec![plot.clone()]));
+    }
+    let services = mocks.into_services();
+
+    let plots = services.plot.get_all_by_user(&manager, None).await.unwrap();
+
+    let mut names: Vec<String> = plots.into_iter().map(|p| p.name).collect();
+    names.sort();
+    assert_eq!(names, vec!["Cooperative", "North", "North Annex", "South"]);
+}
+
+#[tokio::test]
+async fn test_cooperative_narrows_plots_to_one_member_farm() {
+    let hierarchy = Hierarchy::new();
+    let manager = create_user("manager", 80, Some(hierarchy.cooperative.clone()));
+    let north_id = hierarchy.north.id;
+    let annex_id = hierarchy.north_annex.id;
+
+    let mut mocks = Mocks::new(&hierarchy);
+    mocks
+        .plot_repo
+        .expect_get_by_company_id()
+        .withf(move |id| *id == north_id || *id == annex_id)
+        .times(2)
+        .returning(|company_id| Ok(vec![create_plot(company_id, "Plot")]));
+    let services = mocks.into_services();
+
+    let plots = services
+        .plot
+        .get_all_by_user(&manager, Some(north_id))
+        .await
+        .unwrap();
+
+    assert_eq!(plots.len(), 2);
+}
+
+#[tokio::test]
+async fn test_member_farm_cannot_read_sibling_plots() {
+    let hierarchy = Hierarchy::new();
+    let manager = create_user("manager", 80, Some(hierarchy.north.clone()));
+
+    let mut mocks = Mocks::new(&hierarchy);
+    mocks.plot_repo.expect_get_by_company_id().never();
+    let services = mocks.into_services();
+
+    let result = services
+        .plot
+        .get_all_by_user(&manager, Some(hierarchy.south.id))
+        .await;
+
+    assert!(matches!(result, Err(AppError::Forbidden)));
+}
+
+#[tokio::test]
+async fn test_member_farm_cannot_read_cooperative_plots() {
+    let hierarchy = Hierarchy::new();
+    let manager = create_user("manager", 80, Some(hierarchy.south.clone()));
+
+    let mut mocks = Mocks::new(&hierarchy);
+    mocks.plot_repo.expect_get_by_company_id().never();
+    let services = mocks.into_services();
+
+    let result = services
+        .plot
+        .get_all_by_user(&manager, Some(hierarchy.cooperative.id))
+        .await;
+
+    assert!(matches!(result, Err(AppError::Forbidden)));
+}
+
+#[tokio::test]
+async fn test_cooperative_dashboard_filters_cover_member_farms() {
+    let hierarchy = Hierarchy::new();
+    let manager = create_user("manager", 80, Some(hierarchy.cooperative.clone()));
+
+    let mut mocks = Mocks::new(&hierarchy);
+    mocks.label_repo.expect_get_all().returning(|| Ok(vec![]));
+    mocks
+        .plot_repo
+        .expect_get_all_by_company_id()
+        .returning(|company_id| {
+            Ok(vec![
+                create_plot(company_id, "Plot"),
+                default_plot(company_id),
+            ])
+        });
+    mocks
+        .user_repo
+        .expect_get_by_company_id()
+        .times(4)
+        .returning(|company_id| {
+            let company = create_company();
+            Ok(vec![create_user(
+                "user",
+                10,
+                Some(Company {
+                    id: company_id,
+                    ..company
+                }),
+            )])
+        });
+    mocks
+        .membership_repo
+        .expect_get_by_company_id()
+        .times(4)
+        .returning(|_| Ok(vec![]));
+    mocks
+        .dashboard_repo
+        .expect_get_model_versions()
+        .withf(|company_ids, users_ids| company_ids.len() == 4 && users_ids.is_none())
+        .times(1)
+        .returning(|_, _| Ok(vec!["1".to_string(), "2".to_string()]));
+    let services = mocks.into_services();
+
+    let filters = services
+        .dashboard
+        .get_filters(manager, DashboardFiltersDto { company_id: None })
+        .await
+        .unwrap();
+
+    assert_eq!(filters.users.len(), 4);
+    // A plot per company and a single default plot
+    assert_eq!(filters.plots.len(), 5);
+    assert_eq!(filters.plots.iter().filter(|p| p.id.is_nil()).count(), 1);
+    assert_eq!(filters.model_versions, vec!["1", "2"]);
+}
+
+#[tokio::test]
+async fn test_member_farm_dashboard_excludes_siblings() {
+    let hierarchy = Hierarchy::new();
+    let manager = create_user("manager", 80, Some(hierarchy.south.clone()));
+    let south_id = hierarchy.south.id;
+
+    let mut mocks = Mocks::new(&hierarchy);
+    mocks
+        .plot_repo
+        .expect_get_all_by_company_id()
+        .with(eq(south_id))
+        .times(1)
+        .returning(|company_id| Ok(vec![default_plot(company_id)]));
+    mocks
+        .dashboard_repo
+        .expect_get_summary()
+        .withf(move |company_ids, users_ids, _, _, _, _, _| {
+            company_ids == &vec![south_id] && users_ids.is_none()
+        })
+        .times(1)
+        .returning(|_, _, _, _, _, _, _| Ok(empty_summary()));
+    let services = mocks.into_services();
+
+    services
+        .dashboard
+        .get_summary(
+            manager,
+            DashboardSummaryDto {
+                users_ids: None,
+                min_date: None,
+                max_date: None,
+                plot_ids: None,
+                labels: None,
+                model_versions: None,
+            },
+        )
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_member_farm_dashboard_lists_consultants_of_other_companies() {
+    let hierarchy = Hierarchy::new();
+    let manager = create_user("manager", 80, Some(hierarchy.south.clone()));
+    // Works for an outside company and advises the farm through a membership
+    let consultant = create_user("user", 10, Some(create_company()));
+    let consultant_id = consultant.id;
+    let south_id = hierarchy.south.id;
+
+    let mut mocks = Mocks::new(&hierarchy);
+    mocks.label_repo.expect_get_all().returning(|| Ok(vec![]));
+    mocks
+        .plot_repo
+        .expect_get_all_by_company_id()
+        .returning(|company_id| Ok(vec![default_plot(company_id)]));
+    mocks
+        .user_repo
+        .expect_get_by_company_id()
+        .with(eq(south_id))
+        .returning(|_| Ok(vec![]));
+    let south = hierarchy.south.clone();
+    let role = consultant.role.clone();
+    mocks
+        .membership_repo
+        .expect_get_by_company_id()
+        .with(eq(south_id))
+        .returning(move |_| {
+            Ok(vec![CompanyMembership {
+                user_id: consultant_id,
+                company: south.clone(),
+                role: role.clone(),
+                created_at: Utc::now(),
+            }])
+        });
+    mocks
+        .user_repo
+        .expect_get_by_ids()
+        .withf(move |ids| ids == &vec![consultant_id])
+        .times(1)
+        .returning(move |_| Ok(vec![consultant.clone()]));
+    // Their predictions are read through the company they were made in
+    mocks
+        .dashboard_repo
+        .expect_get_model_versions()
+        .withf(move |company_ids, users_ids| company_ids == &vec![south_id] && users_ids.is_none())
+        .times(1)
+        .returning(|_, _| Ok(vec![]));
+    let services = mocks.into_services();
+
+    let filters = services
+        .dashboard
+        .get_filters(manager, DashboardFiltersDto { company_id: None })
+        .await
+        .unwrap();
+
+    assert_eq!(
+        filters.users.iter().map(|u| u.id).collect::<Vec<_>>(),
+        vec![consultant_id]
+    );
+}
+
+#[tokio::test]
+async fn test_member_farm_cannot_filter_dashboard_by_sibling() {
+    let hierarchy = Hierarchy::new();
+    let manager = create_user("manager", 80, Some(hierarchy.north.clone()));
+
+    let mut mocks = Mocks::new(&hierarchy);
+    mocks.label_repo.expect_get_all().never();
+    let services = mocks.into_services();
+
+    let result = services
+        .dashboard
+        .get_filters(
+            manager,
+            DashboardFiltersDto {
+                company_id: Some(hierarchy.south.id),
+            },
+        )
+        .await;
+
+    assert!(matches!(result, Err(AppError::Forbidden)));
+}
+
+#[tokio::test]
+async fn test_cooperative_reads_member_farm_details() {
+    let hierarchy = Hierarchy::new();
+    let manager = create_user("manager", 80, Some(hierarchy.cooperative.clone()));
+
+    let services = Mocks::new(&hierarchy).into_services();
+
+    let company = services
+        .company
+        .get_by_id(&manager, hierarchy.north_annex.id)
+        .await
+        .unwrap();
+    let children = services
+        .company
+        .get_children(&manager, hierarchy.cooperative.id)
+        .await
+        .unwrap();
+
+    assert_eq!(company.map(|c| c.id), Some(hierarchy.north_annex.id));
+    assert_eq!(children.len(), 2);
+}
+
+#[tokio::test]
+async fn test_set_parent_rejects_company_below_itself() {
+    let hierarchy = Hierarchy::new();
+    let admin = create_user("admin", 100, None);
+
+    let mut mocks = Mocks::new(&hierarchy);
+    mocks.company_repo.expect_update().never();
+    let services = mocks.into_services();
+
+    let result = services
+        .company
+        .set_parent(
+            &admin,
+            hierarchy.cooperative.id,
+            Some(hierarchy.north_annex.id),
+        )
+        .await;
+
+    assert!(matches!(result, Err(AppError::ValidationError(_))));
+}
+
+#[tokio::test]
+async fn test_set_parent_moves_company() {
+    let hierarchy = Hierarchy::new();
+    let admin = create_user("admin", 100, None);
+    let cooperative_id = hierarchy.cooperative.id;
+
+    let mut mocks = Mocks::new(&hierarchy);
+    mocks
+        .company_repo
+        .expect_update()
+        .withf(move |c| c.parent_id == Some(cooperative_id))
+        .times(1)
+        .returning(Ok);
+    let services = mocks.into_services();
+
+    let company = services
+        .company
+        .set_parent(&admin, hierarchy.north_annex.id, Some(cooperative_id))
+        .await
+        .unwrap();
+
+    assert_eq!(company.parent_id, Some(cooperative_id));
+}
+
+#[tokio::test]
+async fn test_set_parent_requires_managing_every_company() {
+    let hierarchy = Hierarchy::new();
+    let manager = create_user("manager", 80, Some(hierarchy.cooperative.clone()));
+
+    let mut mocks = Mocks::new(&hierarchy);
+    mocks.company_repo.expect_update().never();
+    let services = mocks.into_services();
+
+    let result = services
+        .company
+        .set_parent(&manager, hierarchy.south.id, None)
+        .await;
+
+    assert!(matches!(result, Err(AppError::Forbidden)));
+}
@@ -0,0 +1,299 @@
+mod common;
+
+use chrono::Utc;
+use common::mocks::{
+    MockCompanyRepository, MockCompanySettingsRepository, MockPermissionRepository,
+    MockTeamRepository, MockUserCache,
+};
+use common::{create_company, create_user, grant};
+use mockall::predicate::*;
+use spl_application::dtos::company::UpdateCompanySettingsDto;
+use spl_application::services::access_control::AccessControlService;
+use spl_application::services::company_settings::CompanySettingsService;
+use spl_application::services::policy::PolicyService;
+use spl_domain::entities::company::{CompanySettings, CompanySettingsOverride};
+use spl_domain::entities::image::ImageFormat;
+use spl_domain::entities::user::{permissions, PermissionScope};
+use spl_shared::error::AppError;
+use std::sync::Arc;
+use uuid::Uuid;
+
+struct Mocks {
+    settings_repo: MockCompanySettingsRepository,
+    company_repo: MockCompanyRepository,
+    user_cache: MockUserCache,
+}
+
+impl Mocks {
+    fn new() -> Self {
+        Self {
+            settings_repo: MockCompanySettingsRepository::new(),
+            company_repo: MockCompanyRepository::new(),
+            user_cache: MockUserCache::new(),
+        }
+    }
+
+    fn into_service(self) -> CompanySettingsService {
+        let mut permission_repo = MockPermissionRepository::new();
+        permission_repo.expect_get_grants().returning(|| {
+            Ok(vec![
+                grant("admin", permissions::SETTINGS_MANAGE, PermissionScope::Any),
+                grant(
+                    "supervisor",
+                    permissions::SETTINGS_MANAGE,
+                    PermissionScope::Company,
+                ),
+            ])
+        });
+
+        let company_repo = Arc::new(self.company_repo);
+        let access_control = Arc::new(AccessControlService::new(
+            company_repo.clone(),
+            Arc::new(MockTeamRepository::new()),
+            Arc::new(PolicyService::new(Arc::new(permission_repo), 30)),
+        ));
+
+        CompanySettingsService::new(
+            Arc::new(self.settings_repo),
+            company_repo,
+            access_control,
+            Arc::new(self.user_cache),
+            defaults(),
+        )
+    }
+}
+
+fn defaults() -> CompanySettings {
+    CompanySettings {
+        timezone: "UTC".to_string(),
+        default_language: "es".to_string(),
+        data_retention_days: 0,
+        two_factor_required_level: None,
+        default_page_size: 16,
+        allowed_upload_formats: ImageFormat::ALL.to_vec(),
+        alert_severity_threshold: 50.0,
+        alert_confidence_threshold: 0.8,
+    }
+}
+
+fn overrides(company_id: Uuid) -> CompanySettingsOverride {
+    CompanySettingsOverride {
+        company_id,
+        timezone: Some("America/Lima".to_string()),
+        default_language: None,
+        data_retention_days: Some(90),
+        default_page_size: None,
+        allowed_upload_formats: Some(vec![ImageFormat::Jpeg]),
+        alert_severity_threshold: None,
+        alert_confidence_threshold: None,
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+    }
+}
+
+#[tokio::test]
+async fn test_user_without_company_follows_server_defaults() {
+    let service = Mocks::new().into_service();
+    let user = create_user("user", 10, None);
+
+    let settings = service.for_user(&user).await.unwrap();
+
+    assert_eq!(settings.timezone, "UTC");
+    assert_eq!(settings.default_page_size, 16);
+    assert_eq!(settings.allowed_upload_formats, ImageFormat::ALL.to_vec());
+}
+
+#[tokio::test]
+async fn test_company_overrides_are_merged_over_defaults() {
+    let mut company = create_company();
+    company.two_factor_required_level = Some(50);
+    let company_id = company.id;
+    let mut mocks = Mocks::new();
+    mocks
+        .settings_repo
+        .expect_get_by_id()
+        .with(eq(company_id))
+        .returning(|id| Ok(Some(overrides(id))));
+    let service = mocks.into_service();
+    let user = create_user("user", 10, Some(company));
+
+    let settings = service.for_user(&user).await.unwrap();
+
+    assert_eq!(settings.timezone, "America/Lima");
+    assert_eq!(settings.default_language, "es");
+    assert_eq!(settings.data_retention_days, 90);
+    assert_eq!(settings.two_factor_required_level, Some(50));
+    assert_eq!(settings.allowed_upload_formats, vec![ImageFormat::Jpeg]);
+}
+
+#[tokio::test]
+async fn test_member_reads_settings_of_own_company() {
+    let company = create_company();
+    let company_id = company.id;
+    let mut mocks = Mocks::new();
+    let found = company.clone();
+    mocks
+        .company_repo
+        .expect_get_by_id()
+        .with(eq(company_id))
+        .returning(move |_| Ok(Some(found.clone())));
+    mocks
+        .settings_repo
+        .expect_get_by_id()
+        .returning(|_| Ok(None));
+    let service = mocks.into_service();
+    let user = create_user("user", 10, Some(company));
+
+    let settings = service.get(&user, company_id).await.unwrap();
+
+    assert_eq!(settings, defaults());
+}
+
+#[tokio::test]
+async fn test_supervisor_cannot_read_settings_of_other_company() {
+    let service = Mocks::new().into_service();
+    let supervisor = create_user("supervisor", 50, Some(create_company()));
+
+    let result = service.get(&supervisor, Uuid::new_v4()).await;
+
+    assert!(matches!(result, Err(AppError::Forbidden)));
+}
+
+#[tokio::test]
+async fn test_update_stores_overrides_and_two_factor_requirement() {
+    let company = create_company();
+    let company_id = company.id;
+    let mut mocks = Mocks::new();
+    let found = company.clone();
+    mocks
+        .company_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(found.clone())));
+    mocks
+        .company_repo
+        .expect_update()
+        .withf(|company| company.two_factor_required_level == Some(50))
+        .times(1)
+        .returning(Ok);
+    mocks
+        .settings_repo
+        .expect_get_by_id()
+        .returning(|_| Ok(None));
+    mocks
+        .settings_repo
+        .expect_create()
+        .withf(|settings| {
+            settings.allowed_upload_formats == Some(vec![ImageFormat::Jpeg, ImageFormat::Png])
+        })
+        .times(1)
+        .returning(Ok);
+    mocks.user_cache.expect_clear().times(1).returning(|| ());
+    let service = mocks.into_service();
+    let supervisor = create_user("supervisor", 50, Some(company));
+
+    let settings = service
+        .update(
+            &supervisor,
+            company_id,
+            UpdateCompanySettingsDto {
+                timezone: Some("America/Lima".to_string()),
+                two_factor_required_level: Some(50),
+                allowed_upload_formats: Some(vec![
+                    "JPG".to_string(),
+                    "png".to_string(),
+                    "jpeg".to_string(),
+                ]),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(settings.timezone, "America/Lima");
+    assert_eq!(settings.two_factor_required_level, Some(50));
+    assert_eq!(settings.default_page_size, 16);
+}
+
+#[tokio::test]
+async fn test_update_rejects_unknown_upload_format() {
+    let company = create_company();
+    let company_id = company.id;
+    let mut mocks = Mocks::new();
+    let found = company.clone();
+    mocks
+        .company_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(found.clone())));
+    mocks.settings_repo.expect_create().never();
+    let service = mocks.into_service();
+    let supervisor = create_user("supervisor", 50, Some(company));
+
+    let result = service
+        .update(
+            &supervisor,
+            company_id,
+            UpdateCompanySettingsDto {
+                allowed_upload_formats: Some(vec!["svg".to_string()]),
+                ..Default::default()
+            },
+        )
+        .await;
+
+    assert!(matches!(result, Err(AppError::ValidationError(_))));
+}
+
+#[tokio::test]
+async fn test_update_rejects_two_factor_level_below_minimum() {
+    let company = create_company();
+    let company_id = company.id;
+    let mut mocks = Mocks::new();
+    let found = company.clone();
+    mocks
+        .company_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(found.clone())));
+    mocks.company_repo.expect_update().never();
+    let service = mocks.into_service();
+    let supervisor = create_user("supervisor", 50, Some(company));
+
+    let result = service
+        .update(
+            &supervisor,
+            company_id,
+            UpdateCompanySettingsDto {
+                two_factor_required_level: Some(10),
+                ..Default::default()
+            },
+        )
+        .await;
+
+    assert!(matches!(result, Err(AppError::ValidationError(_))));
+}
+
+#[tokio::test]
+async fn test_reset_removes_overrides() {
+    let company = create_company();
+    let company_id = company.id;
+    let mut mocks = Mocks::new();
+    let found = company.clone();
+    mocks
+        .company_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(found.clone())));
+    mocks
+        .settings_repo
+        .expect_get_by_id()
+        .returning(|id| Ok(Some(overrides(id))));
+    mocks
+        .settings_repo
+        .expect_delete()
+        .with(eq(company_id))
+        .times(1)
+        .returning(|id| Ok(overrides(id)));
+    let service = mocks.into_service();
+    let admin = create_user("admin", 100, None);
+
+    let settings = service.reset(&admin, company_id).await.unwrap();
+
+    assert_eq!(settings, defaults());
+}
@@ -0,0 +1,277 @@
+mod common;
+
+use chrono::{Duration, Utc};
+use common::create_user;
+use common::mocks::{
+    MockEmailVerificationTokenRepository, MockMailer, MockOpaqueTokenGenerator, MockUserCache,
+    MockUserRepository,
+};
+use mockall::predicate::*;
+use spl_application::services::email_verification::EmailVerificationService;
+use spl_domain::entities::auth::EmailVerificationToken;
+use spl_domain::entities::user::User;
+use spl_shared::error::AppError;
+use std::sync::Arc;
+use uuid::Uuid;
+
+struct Mocks {
+    user_repo: MockUserRepository,
+    token_repo: MockEmailVerificationTokenRepository,
+    mailer: MockMailer,
+    user_cache: MockUserCache,
+}
+
+impl Mocks {
+    fn new() -> Self {
+        Self {
+            user_repo: MockUserRepository::new(),
+            token_repo: MockEmailVerificationTokenRepository::new(),
+            mailer: MockMailer::new(),
+            user_cache: MockUserCache::new(),
+        }
+    }
+
+    fn into_service(self) -> EmailVerificationService {
+        let mut opaque = MockOpaqueTokenGenerator::new();
+        opaque
+            .expect_generate()
+            .returning(|| "verification_token".to_string());
+        opaque
+            .expect_hash()
+            .returning(|token| format!("hash_{}", token));
+
+        EmailVerificationService::new(
+            Arc::new(self.user_repo),
+            Arc::new(self.token_repo),
+            Arc::new(opaque),
+            Arc::new(self.mailer),
+            Arc::new(self.user_cache),
+            Some("https://app.example.com/".to_string()),
+            48,
+        )
+    }
+}
+
+fn create_user_with_email(id: Uuid, email: Option<&str>) -> User {
+    User {
+        id,
+        email: email.map(str::to_string),
+        ..create_user("user", 1, None)
+    }
+}
+
+fn create_token(user_id: Uuid, email: &str, expires_in_hours: i64) -> EmailVerificationToken {
+    EmailVerificationToken {
+        id: Uuid::new_v4(),
+        user_id,
+        email: email.to_string(),
+        token_hash: "hash_verification_token".to_string(),
+        expires_at: Utc::now() + Duration::hours(expires_in_hours),
+        used_at: None,
+        created_at: Utc::now(),
+    }
+}
+
+#[tokio::test]
+async fn test_send_verification_emails_link_for_current_address() {
+    let mut mocks = Mocks::new();
+    let user_id = Uuid::new_v4();
+    let user = create_user_with_email(user_id, Some("test@example.com"));
+
+    mocks
+        .token_repo
+        .expect_invalidate_by_user_id()
+        .with(eq(user_id))
+        .times(1)
+        .returning(|_| Ok(1));
+    mocks
+        .token_repo
+        .expect_create()
+        .withf(move |token| {
+            token.user_id == user_id
+                && token.email == "test@example.com"
+                && token.token_hash == "hash_verification_token"
+                && token.expires_at > Utc::now() + Duration::hours(47)
+        })
+        .times(1)
+        .returning(Ok);
+
+    mocks
+        .mailer
+        .expect_send()
+        .withf(|message| {
+            message.to == "test@example.com"
+                && message
+                    .body
+                    .contains("https://app.example.com/verify-email?token=verification_token")
+        })
+        .times(1)
+        .returning(|_| Ok(()));
+
+    let result = mocks.into_service().send_verification(&user).await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_send_verification_without_email_fails() {
+    let mut mocks = Mocks::new();
+    mocks.mailer.expect_send().never();
+
+    let user = create_user_with_email(Uuid::new_v4(), None);
+
+    let result = mocks.into_service().send_verification(&user).await;
+
+    assert!(matches!(result, Err(AppError::ValidationError(_))));
+}
+
+#[tokio::test]
+async fn test_send_verification_already_verified_conflicts() {
+    let mut mocks = Mocks::new();
+    mocks.mailer.expect_send().never();
+
+    let mut user = create_user_with_email(Uuid::new_v4(), Some("test@example.com"));
+    user.email_verified_at = Some(Utc::now());
+
+    let result = mocks.into_service().send_verification(&user).await;
+
+    assert!(matches!(result, Err(AppError::Conflict(_))));
+}
+
+#[tokio::test]
+async fn test_email_changed_ignores_mailer_failures() {
+    let mut mocks = Mocks::new();
+    mocks
+        .token_repo
+        .expect_invalidate_by_user_id()
+        .returning(|_| Ok(0));
+    mocks.token_repo.expect_create().returning(Ok);
+    mocks
+        .mailer
+        .expect_send()
+        .times(1)
+        .returning(|_| Err(AppError::IntegrationUnavailable("SMTP down".to_string())));
+
+    let user = create_user_with_email(Uuid::new_v4(), Some("test@example.com"));
+
+    // Does not fail, the user can ask for a new link
+    mocks.into_service().email_changed(&user).await;
+}
+
+#[tokio::test]
+async fn test_verify_marks_email_as_verified() {
+    let mut mocks = Mocks::new();
+    let user_id = Uuid::new_v4();
+    let user = create_user_with_email(user_id, Some("test@example.com"));
+    let token = create_token(user_id, "test@example.com", 1);
+    let token_id = token.id;
+
+    mocks
+        .token_repo
+        .expect_get_by_token_hash()
+        .with(eq("hash_verification_token"))
+        .times(1)
+        .returning(move |_| Ok(Some(token.clone())));
+    mocks
+        .token_repo
+        .expect_mark_used()
+        .with(eq(token_id))
+        .times(1)
+        .returning(|_| Ok(true));
+
+    mocks
+        .user_repo
+        .expect_get_by_id()
+        .with(eq(user_id))
+        .times(1)
+        .returning(move |_| Ok(Some(user.clone())));
+    mocks
+        .user_repo
+        .expect_update()
+        .withf(|user| user.email_verified_at.is_some())
+        .times(1)
+        .returning(Ok);
+
+    mocks
+        .user_cache
+        .expect_invalidate()
+        .with(eq(user_id))
+        .times(1)
+        .returning(|_| ());
+
+    let user = mocks
+        .into_service()
+        .verify("verification_token")
+        .await
+        .unwrap();
+
+    assert!(user.is_email_verified());
+}
+
+#[tokio::test]
+async fn test_verify_expired_token_fails() {
+    let mut mocks = Mocks::new();
+    let token = create_token(Uuid::new_v4(), "test@example.com", -1);
+
+    mocks
+        .token_repo
+        .expect_get_by_token_hash()
+        .returning(move |_| Ok(Some(token.clone())));
+    mocks.token_repo.expect_mark_used().never();
+    mocks.user_repo.expect_update().never();
+
+    let result = mocks.into_service().verify("verification_token").await;
+
+    assert!(matches!(result, Err(AppError::ValidationError(_))));
+}
+
+#[tokio::test]
+async fn test_verify_token_of_changed_email_fails() {
+    let mut mocks = Mocks::new();
+    let user_id = Uuid::new_v4();
+    let user = create_user_with_email(user_id, Some("new@example.com"));
+    let token = create_token(user_id, "old@example.com", 1);
+
+    mocks
+        .token_repo
+        .expect_get_by_token_hash()
+        .returning(move |_| Ok(Some(token.clone())));
+    mocks.token_repo.expect_mark_used().never();
+    mocks
+        .user_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(user.clone())));
+    mocks.user_repo.expect_update().never();
+
+    let result = mocks.into_service().verify("verification_token").await;
+
+    assert!(matches!(result, Err(AppError::ValidationError(_))));
+}
+
+#[tokio::test]
+async fn test_verify_used_token_fails() {
+    let mut mocks = Mocks::new();
+    let user_id = Uuid::new_v4();
+    let user = create_user_with_email(user_id, Some("test@example.com"));
+    let token = create_token(user_id, "test@example.com", 1);
+
+    mocks
+        .token_repo
+        .expect_get_by_token_hash()
+        .returning(move |_| Ok(Some(token.clone())));
+    // Consumed concurrently
+    mocks
+        .token_repo
+        .expect_mark_used()
+        .times(1)
+        .returning(|_| Ok(false));
+    mocks
+        .user_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(user.clone())));
+    mocks.user_repo.expect_update().never();
+
+    let result = mocks.into_service().verify("verification_token").await;
+
+    assert!(matches!(result, Err(AppError::ValidationError(_))));
+}
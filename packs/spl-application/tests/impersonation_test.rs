@@ -0,0 +1,409 @@
+mod common;
+
+use chrono::{Duration, Utc};
+use common::mocks::{
+    MockImpersonationActionRepository, MockImpersonationRepository, MockPermissionRepository,
+    MockTokenGenerator, MockUserRepository,
+};
+use common::{create_user, grant};
+use mockall::predicate::*;
+use spl_application::dtos::auth::ClientInfoDto;
+use spl_application::services::impersonation::ImpersonationService;
+use spl_application::services::policy::PolicyService;
+use spl_domain::entities::auth::{Impersonation, ImpersonationAction};
+use spl_domain::entities::user::{permissions, PermissionScope};
+use spl_shared::error::AppError;
+use std::sync::Arc;
+use uuid::Uuid;
+
+struct Mocks {
+    impersonation_repo: MockImpersonationRepository,
+    action_repo: MockImpersonationActionRepository,
+    user_repo: MockUserRepository,
+    token_generator: MockTokenGenerator,
+}
+
+impl Mocks {
+    fn new() -> Self {
+        Self {
+            impersonation_repo: MockImpersonationRepository::new(),
+            action_repo: MockImpersonationActionRepository::new(),
+            user_repo: MockUserRepository::new(),
+            token_generator: MockTokenGenerator::new(),
+        }
+    }
+
+    fn into_service(self) -> ImpersonationService {
+        let mut permission_repo = MockPermissionRepository::new();
+        permission_repo.expect_get_grants().returning(|| {
+            Ok(vec![grant(
+                "admin",
+                permissions::USERS_IMPERSONATE,
+                PermissionScope::Any,
+            )])
+        });
+
+        ImpersonationService::new(
+            Arc::new(self.impersonation_repo),
+            Arc::new(self.action_repo),
+            Arc::new(self.user_repo),
+            Arc::new(self.token_generator),
+            Arc::new(PolicyService::new(Arc::new(permission_repo), 30)),
+            900,
+        )
+    }
+}
+
+fn create_impersonation(actor_id: Uuid, expires_in_seconds: i64) -> Impersonation {
+    Impersonation {
+        id: Uuid::new_v4(),
+        actor_id: Some(actor_id),
+        subject_id: Some(Uuid::new_v4()),
+        reason: "Ticket #42".to_string(),
+        ip_address: None,
+        started_at: Utc::now(),
+        expires_at: Utc::now() + Duration::seconds(expires_in_seconds),
+        ended_at: None,
+    }
+}
+
+#[tokio::test]
+async fn test_start_issues_token_carrying_actor_and_subject() {
+    let mut mocks = Mocks::new();
+    let admin = create_user("admin", 100, None);
+    let subject = create_user("user", 10, None);
+    let subject_id = subject.id;
+    let admin_id = admin.id;
+
+    mocks
+        .user_repo
+        .expect_get_by_id()
+        .with(eq(subject_id))
+        .returning(move |_| Ok(Some(subject.clone())));
+
+    mocks
+        .impersonation_repo
+        .expect_create()
+        .withf(move |impersonation| {
+            impersonation.actor_id == Some(admin_id)
+                && impersonation.subject_id == Some(subject_id)
+                && impersonation.reason == "Ticket #42"
+                && impersonation.ip_address.as_deref() == Some("10.0.0.1")
+                && impersonation.expires_at > Utc::now() + Duration::seconds(890)
+        })
+        .times(1)
+        .returning(Ok);
+
+    mocks
+        .token_generator
+        .expect_generate()
+        .withf(move |sub, claims| {
+            sub == subject_id.to_string()
+                && claims["act"]["sub"] == admin_id.to_string()
+                && claims["imp"].is_string()
+                && claims["role"] == "user"
+                && claims["sid"].is_null()
+        })
+        .times(1)
+        .returning(|_, _| Ok("impersonation_token".to_string()));
+
+    let result = mocks
+        .into_service()
+        .start(
+            &admin,
+            subject_id,
+            " Ticket #42 ".to_string(),
+            ClientInfoDto {
+                user_agent: None,
+                ip_address: Some("10.0.0.1".to_string()),
+            },
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(result.access_token, "impersonation_token");
+    assert_eq!(result.expires_in, 900);
+    assert_eq!(result.impersonation.subject_id, Some(subject_id));
+}
+
+#[tokio::test]
+async fn test_start_same_or_higher_level_is_forbidden() {
+    let mut mocks = Mocks::new();
+    let admin = create_user("admin", 100, None);
+    let other_admin = create_user("supervisor", 100, None);
+    let other_id = other_admin.id;
+
+    mocks
+        .user_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(other_admin.clone())));
+    mocks.impersonation_repo.expect_create().never();
+    mocks.token_generator.expect_generate().never();
+
+    let result = mocks
+        .into_service()
+        .start(
+            &admin,
+            other_id,
+            "Ticket".to_string(),
+            ClientInfoDto::default(),
+        )
+        .await;
+
+    assert!(matches!(result, Err(AppError::Forbidden)));
+}
+
+#[tokio::test]
+async fn test_start_user_who_can_impersonate_is_forbidden() {
+    let mut mocks = Mocks::new();
+    let admin = create_user("admin", 100, None);
+    // Lower level, but granted the same permission
+    let mut subject = create_user("admin", 50, None);
+    subject.username = "junior_admin".to_string();
+    let subject_id = subject.id;
+
+    mocks
+        .user_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(subject.clone())));
+    mocks.impersonation_repo.expect_create().never();
+
+    let result = mocks
+        .into_service()
+        .start(
+            &admin,
+            subject_id,
+            "Ticket".to_string(),
+            ClientInfoDto::default(),
+        )
+        .await;
+
+    assert!(matches!(result, Err(AppError::Forbidden)));
+}
+
+#[tokio::test]
+async fn test_start_without_permission_is_forbidden() {
+    let mut mocks = Mocks::new();
+    let supervisor = create_user("supervisor", 50, None);
+    let subject = create_user("user", 10, None);
+    let subject_id = subject.id;
+
+    mocks
+        .user_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(subject.clone())));
+    mocks.impersonation_repo.expect_create().never();
+
+    let result = mocks
+        .into_service()
+        .start(
+            &supervisor,
+            subject_id,
+            "Ticket".to_string(),
+            ClientInfoDto::default(),
+        )
+        .await;
+
+    assert!(matches!(result, Err(AppError::Forbidden)));
+}
+
+#[tokio::test]
+async fn test_start_validates_reason_and_subject() {
+    let admin = create_user("admin", 100, None);
+
+    let result = Mocks::new()
+        .into_service()
+        .start(
+            &admin,
+            Uuid::new_v4(),
+            "   ".to_string(),
+            ClientInfoDto::default(),
+        )
+        .await;
+    assert!(matches!(result, Err(AppError::ValidationError(_))));
+
+    let result = Mocks::new()
+        .into_service()
+        .start(
+            &admin,
+            admin.id,
+            "Ticket".to_string(),
+            ClientInfoDto::default(),
+        )
+        .await;
+    assert!(matches!(result, Err(AppError::ValidationError(_))));
+
+    let mut mocks = Mocks::new();
+    let mut subject = create_user("user", 10, None);
+    subject.deactivated_at = Some(Utc::now());
+    let subject_id = subject.id;
+    mocks
+        .user_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(subject.clone())));
+    mocks.impersonation_repo.expect_create().never();
+
+    let result = mocks
+        .into_service()
+        .start(
+            &admin,
+            subject_id,
+            "Ticket".to_string(),
+            ClientInfoDto::default(),
+        )
+        .await;
+    assert!(matches!(result, Err(AppError::ValidationError(_))));
+}
+
+#[tokio::test]
+async fn test_check_active() {
+    let actor = create_user("admin", 100, None);
+    let actor_id = actor.id;
+
+    // Active impersonation of an active actor
+    let mut mocks = Mocks::new();
+    let impersonation = create_impersonation(actor_id, 60);
+    let id = impersonation.id;
+    mocks
+        .impersonation_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(impersonation.clone())));
+    let active_actor = actor.clone();
+    mocks
+        .user_repo
+        .expect_get_by_id()
+        .with(eq(actor_id))
+        .returning(move |_| Ok(Some(active_actor.clone())));
+    assert!(mocks
+        .into_service()
+        .check_active(id, actor_id)
+        .await
+        .unwrap());
+
+    // Ended
+    let mut mocks = Mocks::new();
+    let mut impersonation = create_impersonation(actor_id, 60);
+    impersonation.ended_at = Some(Utc::now());
+    let id = impersonation.id;
+    mocks
+        .impersonation_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(impersonation.clone())));
+    assert!(!mocks
+        .into_service()
+        .check_active(id, actor_id)
+        .await
+        .unwrap());
+
+    // Expired
+    let mut mocks = Mocks::new();
+    let impersonation = create_impersonation(actor_id, -1);
+    let id = impersonation.id;
+    mocks
+        .impersonation_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(impersonation.clone())));
+    assert!(!mocks
+        .into_service()
+        .check_active(id, actor_id)
+        .await
+        .unwrap());
+
+    // Started by someone else
+    let mut mocks = Mocks::new();
+    let impersonation = create_impersonation(Uuid::new_v4(), 60);
+    let id = impersonation.id;
+    mocks
+        .impersonation_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(impersonation.clone())));
+    assert!(!mocks
+        .into_service()
+        .check_active(id, actor_id)
+        .await
+        .unwrap());
+
+    // Actor deactivated since
+    let mut mocks = Mocks::new();
+    let impersonation = create_impersonation(actor_id, 60);
+    let id = impersonation.id;
+    mocks
+        .impersonation_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(impersonation.clone())));
+    let mut deactivated = actor.clone();
+    deactivated.deactivated_at = Some(Utc::now());
+    mocks
+        .user_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(deactivated.clone())));
+    assert!(!mocks
+        .into_service()
+        .check_active(id, actor_id)
+        .await
+        .unwrap());
+}
+
+#[tokio::test]
+async fn test_end_already_ended_conflicts() {
+    let mut mocks = Mocks::new();
+    let id = Uuid::new_v4();
+    mocks
+        .impersonation_repo
+        .expect_end()
+        .with(eq(id))
+        .times(1)
+        .returning(|_| Ok(false));
+
+    let result = mocks.into_service().end(id).await;
+
+    assert!(matches!(result, Err(AppError::Conflict(_))));
+}
+
+#[tokio::test]
+async fn test_get_audit_requires_permission() {
+    let mut mocks = Mocks::new();
+    mocks.impersonation_repo.expect_get_by_id().never();
+
+    let supervisor = create_user("supervisor", 50, None);
+    let result = mocks
+        .into_service()
+        .get_audit(&supervisor, Uuid::new_v4())
+        .await;
+
+    assert!(matches!(result, Err(AppError::Forbidden)));
+}
+
+#[tokio::test]
+async fn test_get_audit_returns_actions() {
+    let mut mocks = Mocks::new();
+    let admin = create_user("admin", 100, None);
+    let impersonation = create_impersonation(admin.id, 60);
+    let id = impersonation.id;
+
+    mocks
+        .impersonation_repo
+        .expect_get_by_id()
+        .with(eq(id))
+        .returning(move |_| Ok(Some(impersonation.clone())));
+    mocks
+        .action_repo
+        .expect_get_by_impersonation_id()
+        .with(eq(id))
+        .returning(move |impersonation_id| {
+            Ok(vec![ImpersonationAction {
+                id: Uuid::new_v4(),
+                impersonation_id,
+                method: "GET".to_string(),
+                path: "/api/v1/dashboard".to_string(),
+                status_code: 200,
+                created_at: Utc::now(),
+            }])
+        });
+
+    let audit = mocks.into_service().get_audit(&admin, id).await.unwrap();
+
+    assert_eq!(audit.impersonation.id, id);
+    assert_eq!(audit.actions.len(), 1);
+    assert_eq!(audit.actions[0].path, "/api/v1/dashboard");
+}
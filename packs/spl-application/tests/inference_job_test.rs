@@ -0,0 +1,758 @@
+mod common;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use chrono::Utc;
+use common::mocks::{
+    MockCompanyQuotaRepository, MockCompanyRepository, MockCompanySettingsRepository,
+    MockImageRepository, MockInferenceJobRepository, MockLabelRepository, MockMarkTypeRepository,
+    MockMembershipRepository, MockModelPredictionClient, MockPermissionRepository,
+    MockPredictionBatchItemRepository, MockPredictionBatchRepository, MockPredictionMarkRepository,
+    MockPredictionRepository, MockRecommendationRepository, MockRoleRepository, MockTeamRepository,
+    MockUsageRepository, MockUserCache, MockUserRepository,
+};
+use common::{create_company, create_user};
+use spl_application::dtos::diagnostics::UploadedFileDto;
+use spl_application::services::access_control::AccessControlService;
+use spl_application::services::company_settings::CompanySettingsService;
+use spl_application::services::diagnostics::{InferenceJobService, PredictionService};
+use spl_application::services::policy::PolicyService;
+use spl_application::services::usage::UsageService;
+use spl_application::services::user::MembershipService;
+use spl_domain::entities::company::CompanySettings;
+use spl_domain::entities::diagnostics::{
+    BatchItemStatus, BatchStatus, InferenceJob, JobPolicy, JobStatus, Label, MarkType,
+    PredictionBatch, PredictionBatchItem, PredictionModel,
+};
+use spl_domain::entities::image::ImageFormat;
+use spl_domain::entities::usage::{QuotaEnforcement, UsageQuota};
+use spl_domain::ports::integrations::{BlobStorageClient, IntegrationClient, PredictionResult};
+use spl_shared::error::{AppError, Result};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use uuid::Uuid;
+
+/// Storage kept in memory, to check what a job leaves behind
+#[derive(Default, Clone)]
+struct MemoryStorage {
+    files: Arc<Mutex<HashMap<String, Bytes>>>,
+    fail_after: Option<usize>,
+}
+
+impl MemoryStorage {
+    fn paths(&self) -> Vec<String> {
+        let mut paths: Vec<String> = self.files.lock().unwrap().keys().cloned().collect();
+        paths.sort();
+        paths
+    }
+}
+
+#[async_trait]
+impl IntegrationClient for MemoryStorage {
+    fn name(&self) -> &'static str {
+        "memory"
+    }
+
+    async fn health_check(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl BlobStorageClient for MemoryStorage {
+    async fn upload(&self, file_content: Bytes, destination: &str) -> Result<String> {
+        let mut files = self.files.lock().unwrap();
+        if self.fail_after.is_some_and(|limit| files.len() >= limit) {
+            return Err(AppError::Unknown("Storage unavailable".to_string()));
+        }
+        files.insert(destination.to_string(), file_content);
+        Ok(destination.to_string())
+    }
+
+    async fn download(&self, source: &str) -> Result<Bytes> {
+        self.files
+            .lock()
+            .unwrap()
+            .get(source)
+            .cloned()
+            .ok_or_else(|| AppError::NotFound(format!("{} not found", source)))
+    }
+
+    async fn delete(&self, path: &str) -> Result<()> {
+        self.files.lock().unwrap().remove(path);
+        Ok(())
+    }
+
+    async fn delete_directory(&self, prefix: &str) -> Result<()> {
+        self.files
+            .lock()
+            .unwrap()
+            .retain(|path, _| !path.starts_with(prefix));
+        Ok(())
+    }
+}
+
+struct Mocks {
+    job_repo: MockInferenceJobRepository,
+    batch_repo: MockPredictionBatchRepository,
+    item_repo: MockPredictionBatchItemRepository,
+    user_repo: MockUserRepository,
+    membership_repo: MockMembershipRepository,
+    prediction_repo: MockPredictionRepository,
+    image_repo: MockImageRepository,
+    label_repo: MockLabelRepository,
+    mark_repo: MockPredictionMarkRepository,
+    mark_type_repo: MockMarkTypeRepository,
+    usage_repo: MockUsageRepository,
+    model_client: MockModelPredictionClient,
+    storage: MemoryStorage,
+}
+
+impl Mocks {
+    fn new() -> Self {
+        Self {
+            job_repo: MockInferenceJobRepository::new(),
+            batch_repo: MockPredictionBatchRepository::new(),
+            item_repo: MockPredictionBatchItemRepository::new(),
+            user_repo: MockUserRepository::new(),
+            membership_repo: MockMembershipRepository::new(),
+            prediction_repo: MockPredictionRepository::new(),
+            image_repo: MockImageRepository::new(),
+            label_repo: MockLabelRepository::new(),
+            mark_repo: MockPredictionMarkRepository::new(),
+            mark_type_repo: MockMarkTypeRepository::new(),
+            usage_repo: MockUsageRepository::new(),
+            model_client: MockModelPredictionClient::new(),
+            storage: MemoryStorage::default(),
+        }
+    }
+
+    fn into_service(self) -> Arc<InferenceJobService> {
+        let company_repo: Arc<MockCompanyRepository> = Arc::new(MockCompanyRepository::new());
+        let user_repo = Arc::new(self.user_repo);
+        let storage: Arc<dyn BlobStorageClient> = Arc::new(self.storage);
+
+        let access_control = Arc::new(AccessControlService::new(
+            company_repo.clone(),
+            Arc::new(MockTeamRepository::new()),
+            Arc::new(PolicyService::new(
+                Arc::new(MockPermissionRepository::new()),
+                30,
+            )),
+        ));
+
+        let company_settings = Arc::new(CompanySettingsService::new(
+            Arc::new(MockCompanySettingsRepository::new()),
+            company_repo.clone(),
+            access_control.clone(),
+            Arc::new(MockUserCache::new()),
+            CompanySettings {
+                timezone: "UTC".to_string(),
+                default_language: "es".to_string(),
+                data_retention_days: 0,
+                two_factor_required_level: None,
+                default_page_size: 16,
+                allowed_upload_formats: ImageFormat::ALL.to_vec(),
+                alert_severity_threshold: 50.0,
+                alert_confidence_threshold: 0.8,
+            },
+        ));
+
+        let usage = Arc::new(UsageService::new(
+            Arc::new(self.usage_repo),
+            Arc::new(MockCompanyQuotaRepository::new()),
+            company_repo,
+            access_control.clone(),
+            UsageQuota {
+                predictions_per_month: None,
+                predictions_per_user_per_month: None,
+                stored_bytes_per_month: None,
+                warning_ratio: 0.8,
+                enforcement: QuotaEnforcement::Hard,
+            },
+        ));
+
+        let prediction_service = Arc::new(PredictionService::new(
+            Arc::new(self.prediction_repo),
+            user_repo.clone(),
+            Arc::new(self.image_repo),
+            Arc::new(self.label_repo),
+            Arc::new(self.mark_repo),
+            Arc::new(self.mark_type_repo),
+            Arc::new(MockRecommendationRepository::new()),
+            storage.clone(),
+            Arc::new(self.model_client),
+            access_control.clone(),
+            company_settings,
+            usage,
+        ));
+
+        Arc::new(InferenceJobService::new(
+            Arc::new(self.job_repo),
+            Arc::new(self.batch_repo),
+            Arc::new(self.item_repo),
+            user_repo.clone(),
+            prediction_service,
+            Arc::new(MembershipService::new(
+                Arc::new(self.membership_repo),
+                user_repo,
+                Arc::new(MockRoleRepository::new()),
+                access_control,
+            )),
+            storage,
+            JobPolicy {
+                max_attempts: 3,
+                retry_delay_seconds: 30,
+                lease_seconds: 300,
+            },
+        ))
+    }
+
+    /// Stores the upload of the job and hands the job to the next worker
+    fn claim(&mut self, job: &InferenceJob) {
+        self.storage.files.lock().unwrap().insert(
+            job.upload_path.clone().unwrap(),
+            Bytes::from_static(&[0xFF, 0xD8, 0xFF, 0xE0]),
+        );
+
+        let claimed = job.clone();
+        self.job_repo
+            .expect_claim_next()
+            .times(1)
+            .returning(move |_, _| Ok(Some(claimed.clone())));
+    }
+
+    /// Lets the model predict the upload once and saves what the prediction needs
+    fn expect_prediction(&mut self) {
+        self.model_client.expect_predict().times(1).returning(|_| {
+            Ok(PredictionResult {
+                image: Bytes::from_static(b"resized"),
+                leaf_mask: Bytes::from_static(b"leaf"),
+                lesion_mask: Bytes::from_static(b"lesion"),
+                leaf_confidence: 0.9,
+                lesion_confidence: 0.7,
+                severity: 12.0,
+                model: PredictionModel {
+                    name: "leaf".to_string(),
+                    version: None,
+                    provider: "tensorflow".to_string(),
+                    image_size: 256,
+                    leaf_threshold: 0.5,
+                    lesion_threshold: 0.5,
+                },
+            })
+        });
+        self.label_repo.expect_get_by_severity().returning(|_| {
+            Ok(Some(Label {
+                id: 1,
+                name: "low".to_string(),
+                description: None,
+                min: 0.0,
+                max: 25.0,
+                weight: 1,
+                created_at: Utc::now(),
+                updated_at: Utc::now(),
+            }))
+        });
+        self.mark_type_repo.expect_get_by_name().returning(|name| {
+            Ok(Some(MarkType {
+                id: 1,
+                name: name.to_string(),
+                description: None,
+                created_at: Utc::now(),
+            }))
+        });
+        self.image_repo.expect_create().returning(Ok);
+        self.image_repo.expect_update().returning(Ok);
+        self.mark_repo.expect_create_many().returning(Ok);
+        self.usage_repo
+            .expect_reserve()
+            .returning(|_, _, _, _, _| Ok(None));
+        self.usage_repo
+            .expect_increment()
+            .returning(|_, _, _, _, _| Ok(()));
+    }
+
+    /// Records the jobs saved by the service
+    fn record_updates(&mut self) -> Arc<Mutex<Vec<InferenceJob>>> {
+        let updates = Arc::new(Mutex::new(Vec::new()));
+        let recorded = updates.clone();
+        self.job_repo.expect_update().returning(move |job| {
+            recorded.lock().unwrap().push(job.clone());
+            Ok(job)
+        });
+        updates
+    }
+}
+
+/// Job as handed to a worker for its `attempts`-th attempt
+fn create_job(user_id: Uuid, attempts: u32) -> InferenceJob {
+    let mut job = InferenceJob::queued(
+        user_id,
+        None,
+        None,
+        "leaf.jpg".to_string(),
+        format!("{}/jobs/{}", user_id, Uuid::new_v4()),
+    );
+    job.status = JobStatus::Running;
+    job.attempts = attempts;
+    job.locked_until = Some(Utc::now() + chrono::Duration::minutes(5));
+    job
+}
+
+fn create_item(batch_id: Uuid, job: &InferenceJob) -> PredictionBatchItem {
+    PredictionBatchItem {
+        id: job.batch_item_id.unwrap(),
+        batch_id,
+        position: 0,
+        filename: job.filename.clone(),
+        upload_path: job.upload_path.clone(),
+        status: BatchItemStatus::Pending,
+        prediction_id: None,
+        error: None,
+        created_at: job.created_at,
+        updated_at: job.created_at,
+    }
+}
+
+#[tokio::test]
+async fn test_enqueue_stores_upload_before_queueing() {
+    let user = create_user("user", 10, None);
+    let mut mocks = Mocks::new();
+    let storage = mocks.storage.clone();
+
+    let stored = storage.clone();
+    mocks
+        .job_repo
+        .expect_create()
+        .withf(move |job| {
+            job.status == JobStatus::Queued
+                && job.attempts == 0
+                && stored.paths() == vec![job.upload_path.clone().unwrap()]
+        })
+        .times(1)
+        .returning(Ok);
+
+    let service = mocks.into_service();
+    let mut events = service.subscribe();
+
+    let job = service
+        .enqueue(
+            &user,
+            UploadedFileDto {
+                filename: Some("leaf.jpg".to_string()),
+                content: b"image".to_vec(),
+            },
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(job.user_id, user.id);
+    assert_eq!(job.filename, "leaf.jpg");
+    assert!(job
+        .upload_path
+        .as_deref()
+        .unwrap()
+        .starts_with(&format!("{}/jobs/", user.id)));
+
+    let event = events.try_recv().unwrap();
+    assert_eq!(event.id, job.id);
+    assert_eq!(event.status, JobStatus::Queued);
+}
+
+#[tokio::test]
+async fn test_enqueue_deletes_upload_when_job_cannot_be_saved() {
+    let mut mocks = Mocks::new();
+    let storage = mocks.storage.clone();
+
+    mocks
+        .job_repo
+        .expect_create()
+        .returning(|_| Err(AppError::DatabaseError("connection lost".to_string())));
+
+    let service = mocks.into_service();
+    let result = service
+        .enqueue(
+            &create_user("user", 10, None),
+            UploadedFileDto {
+                filename: None,
+                content: b"image".to_vec(),
+            },
+        )
+        .await;
+
+    assert!(matches!(result, Err(AppError::DatabaseError(_))));
+    assert!(storage.paths().is_empty());
+}
+
+#[tokio::test]
+async fn test_enqueue_records_the_company_acted_in() {
+    // A consultant of one company acting in another one through a membership
+    let advised = create_company();
+    let user = create_user("user", 10, Some(advised.clone()));
+    let mut mocks = Mocks::new();
+    mocks.job_repo.expect_create().times(1).returning(Ok);
+
+    let service = mocks.into_service();
+    let job = service
+        .enqueue(
+            &user,
+            UploadedFileDto {
+                filename: None,
+                content: b"image".to_vec(),
+            },
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(job.company_id, Some(advised.id));
+}
+
+#[tokio::test]
+async fn test_run_next_fails_jobs_whose_membership_has_ended() {
+    let user = create_user("user", 10, Some(create_company()));
+    let advised_id = Uuid::new_v4();
+    let mut job = create_job(user.id, 1);
+    job.company_id = Some(advised_id);
+
+    let mut mocks = Mocks::new();
+    mocks.claim(&job);
+    let storage = mocks.storage.clone();
+    let updates = mocks.record_updates();
+    mocks
+        .user_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(user.clone())));
+    // The uploader is predicted in the company of the job, not in their own
+    mocks
+        .membership_repo
+        .expect_get()
+        .withf(move |_, company_id| *company_id == advised_id)
+        .times(1)
+        .returning(|_, _| Ok(None));
+
+    let service = mocks.into_service();
+    assert!(service.run_next().await.unwrap());
+
+    let saved = updates.lock().unwrap().last().cloned().unwrap();
+    assert_eq!(saved.status, JobStatus::Failed);
+    assert!(saved
+        .error
+        .as_deref()
+        .unwrap()
+        .contains("membership has ended"));
+    assert!(storage.paths().is_empty());
+}
+
+#[tokio::test]
+async fn test_run_next_without_jobs_due() {
+    let mut mocks = Mocks::new();
+    mocks
+        .job_repo
+        .expect_claim_next()
+        .withf(|now, locked_until| *locked_until - *now == chrono::Duration::seconds(300))
+        .returning(|_, _| Ok(None));
+    mocks.job_repo.expect_update().never();
+
+    let service = mocks.into_service();
+
+    assert!(!service.run_next().await.unwrap());
+}
+
+#[tokio::test]
+async fn test_run_next_retries_transient_failures_later() {
+    let job = create_job(Uuid::new_v4(), 2);
+    let mut mocks = Mocks::new();
+    mocks.claim(&job);
+    let storage = mocks.storage.clone();
+    let updates = mocks.record_updates();
+
+    mocks
+        .user_repo
+        .expect_get_by_id()
+        .returning(|_| Err(AppError::DatabaseError("connection lost".to_string())));
+
+    let service = mocks.into_service();
+    let before = Utc::now();
+    assert!(service.run_next().await.unwrap());
+
+    let saved = updates.lock().unwrap().last().cloned().unwrap();
+    assert_eq!(saved.status, JobStatus::Queued);
+    assert_eq!(saved.attempts, 2);
+    assert!(saved.error.as_deref().unwrap().contains("connection lost"));
+    assert!(saved.locked_until.is_none());
+    assert!(saved.completed_at.is_none());
+    // The delay doubles with every attempt
+    assert!(saved.run_after >= before + chrono::Duration::seconds(60));
+    assert!(saved.run_after < before + chrono::Duration::seconds(120));
+    assert_eq!(storage.paths(), vec![job.upload_path.unwrap()]);
+}
+
+#[tokio::test]
+async fn test_run_next_dead_letters_after_the_last_attempt() {
+    let job = create_job(Uuid::new_v4(), 3);
+    let mut mocks = Mocks::new();
+    mocks.claim(&job);
+    let storage = mocks.storage.clone();
+    let updates = mocks.record_updates();
+
+    mocks
+        .user_repo
+        .expect_get_by_id()
+        .returning(|_| Err(AppError::DatabaseError("connection lost".to_string())));
+
+    let service = mocks.into_service();
+    assert!(service.run_next().await.unwrap());
+
+    let saved = updates.lock().unwrap().last().cloned().unwrap();
+    assert_eq!(saved.status, JobStatus::Dead);
+    assert!(saved.completed_at.is_some());
+    // Kept to be retried by hand
+    assert_eq!(saved.upload_path, job.upload_path);
+    assert_eq!(storage.paths(), vec![job.upload_path.unwrap()]);
+}
+
+#[tokio::test]
+async fn test_run_next_dead_letters_jobs_of_stopped_workers() {
+    let job = create_job(Uuid::new_v4(), 4);
+    let mut mocks = Mocks::new();
+    mocks.claim(&job);
+    let updates = mocks.record_updates();
+    mocks.user_repo.expect_get_by_id().never();
+
+    let service = mocks.into_service();
+    assert!(service.run_next().await.unwrap());
+
+    let saved = updates.lock().unwrap().last().cloned().unwrap();
+    assert_eq!(saved.status, JobStatus::Dead);
+    assert!(saved.error.as_deref().unwrap().contains("worker stopped"));
+}
+
+#[tokio::test]
+async fn test_run_next_does_not_predict_again_after_failing_to_save_the_outcome() {
+    let user = create_user("user", 10, None);
+    let job = create_job(user.id, 1);
+
+    let mut mocks = Mocks::new();
+    mocks.claim(&job);
+    mocks.expect_prediction();
+    mocks
+        .user_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(user.clone())));
+    let created = Arc::new(Mutex::new(None));
+    let recorded = created.clone();
+    mocks
+        .prediction_repo
+        .expect_create()
+        .times(1)
+        .returning(move |prediction| {
+            *recorded.lock().unwrap() = Some(prediction.clone());
+            Ok(prediction)
+        });
+    // The job is saved before predicting, saving its outcome fails
+    let updates = Arc::new(Mutex::new(Vec::new()));
+    let recorded = updates.clone();
+    mocks.job_repo.expect_update().returning(move |job| {
+        let mut updates = recorded.lock().unwrap();
+        updates.push(job.clone());
+        match updates.len() {
+            1 => Ok(job),
+            _ => Err(AppError::DatabaseError("connection lost".to_string())),
+        }
+    });
+
+    let service = mocks.into_service();
+    assert!(service.run_next().await.is_err());
+
+    let prediction = created.lock().unwrap().clone().unwrap();
+    let prediction_id = prediction.id;
+    let saved = updates.lock().unwrap()[0].clone();
+    assert_eq!(saved.prediction_id, Some(prediction_id));
+
+    // The lease runs out and the job is claimed again
+    let mut job = saved;
+    job.attempts = 2;
+    let mut mocks = Mocks::new();
+    mocks.claim(&job);
+    let storage = mocks.storage.clone();
+    let updates = mocks.record_updates();
+    mocks.model_client.expect_predict().never();
+    mocks
+        .prediction_repo
+        .expect_get_by_id()
+        .withf(move |id| *id == prediction_id)
+        .returning(move |_| Ok(Some(prediction.clone())));
+
+    let service = mocks.into_service();
+    assert!(service.run_next().await.unwrap());
+
+    let updates = updates.lock().unwrap();
+    assert_eq!(updates.len(), 1);
+    assert_eq!(updates[0].status, JobStatus::Completed);
+    assert_eq!(updates[0].prediction_id, Some(prediction_id));
+    assert!(updates[0].error.is_none());
+    assert!(storage.paths().is_empty());
+}
+
+#[tokio::test]
+async fn test_run_next_fails_images_that_cannot_be_predicted() {
+    let user_id = Uuid::new_v4();
+    let batch_id = Uuid::new_v4();
+    let mut job = create_job(user_id, 1);
+    job.batch_item_id = Some(Uuid::new_v4());
+    let item = create_item(batch_id, &job);
+
+    let mut mocks = Mocks::new();
+    mocks.claim(&job);
+    let storage = mocks.storage.clone();
+    let updates = mocks.record_updates();
+
+    // The uploader is gone, so the image cannot be predicted
+    mocks.user_repo.expect_get_by_id().returning(|_| Ok(None));
+
+    mocks
+        .item_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(item.clone())));
+    let synced = Arc::new(Mutex::new(None));
+    let recorded = synced.clone();
+    mocks
+        .item_repo
+        .expect_update()
+        .times(1)
+        .returning(move |item| {
+            *recorded.lock().unwrap() = Some(item.clone());
+            Ok(item)
+        });
+    mocks
+        .batch_repo
+        .expect_refresh_progress()
+        .withf(move |id| *id == batch_id)
+        .times(1)
+        .returning(move |id| {
+            Ok(PredictionBatch {
+                id,
+                user_id,
+                company_id: None,
+                status: BatchStatus::Completed,
+                items_total: 1,
+                items_completed: 0,
+                items_failed: 1,
+                created_at: Utc::now(),
+                updated_at: Utc::now(),
+                completed_at: Some(Utc::now()),
+            })
+        });
+
+    let service = mocks.into_service();
+    assert!(service.run_next().await.unwrap());
+
+    let saved = updates.lock().unwrap().last().cloned().unwrap();
+    assert_eq!(saved.status, JobStatus::Failed);
+    assert!(saved.upload_path.is_none());
+    assert!(saved.error.as_deref().unwrap().contains("not found"));
+    assert!(storage.paths().is_empty());
+
+    let item = synced.lock().unwrap().clone().unwrap();
+    assert_eq!(item.status, BatchItemStatus::Failed);
+    assert_eq!(item.error, saved.error);
+    assert!(item.upload_path.is_none());
+}
+
+#[tokio::test]
+async fn test_retry_requeues_dead_job_and_reopens_its_batch_item() {
+    let user = create_user("user", 10, None);
+    let batch_id = Uuid::new_v4();
+    let mut job = create_job(user.id, 3);
+    job.status = JobStatus::Dead;
+    job.error = Some("Integration timeout".to_string());
+    job.completed_at = Some(Utc::now());
+    job.batch_item_id = Some(Uuid::new_v4());
+    let mut item = create_item(batch_id, &job);
+    item.status = BatchItemStatus::Failed;
+
+    let mut mocks = Mocks::new();
+    let stored = job.clone();
+    mocks
+        .job_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(stored.clone())));
+    let updates = mocks.record_updates();
+    mocks
+        .item_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(item.clone())));
+    mocks
+        .item_repo
+        .expect_update()
+        .withf(|item| item.status == BatchItemStatus::Pending && item.error.is_none())
+        .times(1)
+        .returning(Ok);
+    let user_id = user.id;
+    mocks
+        .batch_repo
+        .expect_refresh_progress()
+        .times(1)
+        .returning(move |id| {
+            Ok(PredictionBatch {
+                id,
+                user_id,
+                company_id: None,
+                status: BatchStatus::Processing,
+                items_total: 1,
+                items_completed: 0,
+                items_failed: 0,
+                created_at: Utc::now(),
+                updated_at: Utc::now(),
+                completed_at: None,
+            })
+        });
+
+    let service = mocks.into_service();
+    let retried = service.retry(&user, job.id).await.unwrap();
+
+    assert_eq!(retried.status, JobStatus::Queued);
+    assert_eq!(retried.attempts, 0);
+    assert!(retried.error.is_none());
+    assert!(retried.completed_at.is_none());
+    assert_eq!(retried.upload_path, job.upload_path);
+    assert_eq!(updates.lock().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_retry_requires_a_dead_job() {
+    let user = create_user("user", 10, None);
+    let mut job = create_job(user.id, 1);
+    job.status = JobStatus::Failed;
+    let id = job.id;
+
+    let mut mocks = Mocks::new();
+    mocks
+        .job_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(job.clone())));
+    mocks.job_repo.expect_update().never();
+
+    let service = mocks.into_service();
+    let result = service.retry(&user, id).await;
+
+    assert!(matches!(result, Err(AppError::Conflict(_))));
+}
+
+#[tokio::test]
+async fn test_job_of_another_user_is_not_found() {
+    let job = create_job(Uuid::new_v4(), 1);
+    let id = job.id;
+
+    let mut mocks = Mocks::new();
+    mocks
+        .job_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(job.clone())));
+
+    let service = mocks.into_service();
+    let result = service.get(&create_user("user", 10, None), id).await;
+
+    assert!(matches!(result, Err(AppError::NotFound(_))));
+}
@@ -0,0 +1,485 @@
+mod common;
+
+use chrono::{Duration, Utc};
+use common::mocks::{
+    MockBreachedPasswordList, MockCompanyPasswordPolicyRepository, MockCompanyRepository,
+    MockInvitationRepository, MockMailer, MockOpaqueTokenGenerator, MockPasswordEncoder,
+    MockPasswordHistoryRepository, MockPermissionRepository, MockRoleRepository,
+    MockTeamRepository, MockUserRepository,
+};
+use common::{create_company, create_role, create_user, grant};
+use mockall::predicate::*;
+use spl_application::dtos::user::{AcceptInvitationDto, CreateInvitationDto};
+use spl_application::services::access_control::AccessControlService;
+use spl_application::services::password_policy::PasswordPolicyService;
+use spl_application::services::policy::PolicyService;
+use spl_application::services::user::InvitationService;
+use spl_domain::entities::auth::PasswordPolicy;
+use spl_domain::entities::company::Company;
+use spl_domain::entities::user::{permissions, Invitation, PermissionScope, Role};
+use spl_shared::error::AppError;
+use std::sync::Arc;
+use uuid::Uuid;
+
+struct Mocks {
+    invitation_repo: MockInvitationRepository,
+    user_repo: MockUserRepository,
+    role_repo: MockRoleRepository,
+    company_repo: MockCompanyRepository,
+    encoder: MockPasswordEncoder,
+    opaque: MockOpaqueTokenGenerator,
+    mailer: MockMailer,
+}
+
+impl Mocks {
+    /// Every role and the given company exist
+    fn new(company: Company) -> Self {
+        let mut role_repo = MockRoleRepository::new();
+        role_repo
+            .expect_get_by_name()
+            .returning(|name| Ok(role_by_name(name)));
+        role_repo
+            .expect_get_by_id()
+            .returning(|id| Ok(role_by_id(id)));
+
+        let mut company_repo = MockCompanyRepository::new();
+        let company_id = company.id;
+        company_repo
+            .expect_get_by_id()
+            .with(eq(company_id))
+            .returning(move |_| Ok(Some(company.clone())));
+
+        let mut opaque = MockOpaqueTokenGenerator::new();
+        opaque
+            .expect_generate()
+            .returning(|| "invitation_token".to_string());
+        opaque
+            .expect_hash()
+            .returning(|token| format!("hash_{}", token));
+
+        Self {
+            invitation_repo: MockInvitationRepository::new(),
+            user_repo: MockUserRepository::new(),
+            role_repo,
+            company_repo,
+            encoder: MockPasswordEncoder::new(),
+            opaque,
+            mailer: MockMailer::new(),
+        }
+    }
+
+    fn into_service(self) -> InvitationService {
+        let user_repo = Arc::new(self.user_repo);
+        let company_repo = Arc::new(self.company_repo);
+        let access_control = Arc::new(AccessControlService::new(
+            company_repo.clone(),
+            Arc::new(MockTeamRepository::new()),
+            policy(),
+        ));
+
+        // Server rules only, the invitee's password is accepted
+        let mut policy_repo = MockCompanyPasswordPolicyRepository::new();
+        policy_repo.expect_get_by_id().returning(|_| Ok(None));
+        let mut history_repo = MockPasswordHistoryRepository::new();
+        history_repo.expect_prune().returning(|_, _| Ok(0));
+        let mut breached_passwords = MockBreachedPasswordList::new();
+        breached_passwords.expect_contains().returning(|_| false);
+
+        let password_policy = Arc::new(PasswordPolicyService::new(
+            Arc::new(policy_repo),
+            Arc::new(history_repo),
+            company_repo.clone(),
+            Arc::new(breached_passwords),
+            Arc::new(MockPasswordEncoder::new()),
+            access_control.clone(),
+            PasswordPolicy {
+                min_length: 8,
+                require_uppercase: false,
+                require_lowercase: false,
+                require_digit: false,
+                require_symbol: false,
+                history_size: 0,
+            },
+        ));
+
+        InvitationService::new(
+            Arc::new(self.invitation_repo),
+            user_repo,
+            Arc::new(self.role_repo),
+            company_repo,
+            Arc::new(self.encoder),
+            Arc::new(self.opaque),
+            Arc::new(self.mailer),
+            access_control,
+            password_policy,
+            Some("https://app.example.com/".to_string()),
+            72,
+        )
+    }
+}
+
+fn role_by_name(name: &str) -> Option<Role> {
+    match name {
+        "admin" => Some(create_role("admin", 100)),
+        "supervisor" => Some(create_role("supervisor", 50)),
+        "user" => Some(create_role("user", 10)),
+        _ => None,
+    }
+}
+
+fn role_by_id(id: i32) -> Option<Role> {
+    match id {
+        100 => role_by_name("admin"),
+        50 => role_by_name("supervisor"),
+        10 => role_by_name("user"),
+        _ => None,
+    }
+}
+
+fn create_invitation(company_id: Uuid, role_id: i32) -> Invitation {
+    Invitation {
+        id: Uuid::new_v4(),
+        company_id,
+        email: "invitee@example.com".to_string(),
+        role_id,
+        token_hash: "hash_invitation_token".to_string(),
+        invited_by: None,
+        expires_at: Utc::now() + Duration::hours(72),
+        accepted_at: None,
+        cancelled_at: None,
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+    }
+}
+
+fn create_dto(role: Option<&str>) -> CreateInvitationDto {
+    CreateInvitationDto {
+        email: " Invitee@Example.com ".to_string(),
+        role: role.map(str::to_string),
+        company_id: None,
+    }
+}
+
+fn accept_dto() -> AcceptInvitationDto {
+    AcceptInvitationDto {
+        token: "invitation_token".to_string(),
+        username: "invitee".to_string(),
+        password: "new_password".to_string(),
+        name: Some("New".to_string()),
+        surname: None,
+    }
+}
+
+fn policy() -> Arc<PolicyService> {
+    let mut permission_repo = MockPermissionRepository::new();
+    permission_repo.expect_get_grants().returning(|| {
+        Ok(vec![
+            grant("admin", permissions::USERS_MANAGE, PermissionScope::Any),
+            grant(
+                "supervisor",
+                permissions::USERS_MANAGE,
+                PermissionScope::Company,
+            ),
+        ])
+    });
+    Arc::new(PolicyService::new(Arc::new(permission_repo), 30))
+}
+
+#[tokio::test]
+async fn test_supervisor_invites_user_into_own_company() {
+    let company = Company {
+        name: "Potato Farms".to_string(),
+        ..create_company()
+    };
+    let company_id = company.id;
+    let supervisor = create_user("supervisor", 50, Some(company.clone()));
+    let supervisor_id = supervisor.id;
+    let mut mocks = Mocks::new(company);
+
+    mocks
+        .user_repo
+        .expect_get_by_username_or_email_and_company()
+        .with(
+            eq(None),
+            eq(Some("invitee@example.com".to_string())),
+            eq(Some(company_id)),
+        )
+        .returning(|_, _, _| Ok(None));
+    mocks
+        .invitation_repo
+        .expect_get_pending_by_email()
+        .returning(|_, _| Ok(None));
+    mocks
+        .invitation_repo
+        .expect_create()
+        .withf(move |invitation| {
+            invitation.company_id == company_id
+                && invitation.email == "invitee@example.com"
+                && invitation.role_id == 10
+                && invitation.token_hash == "hash_invitation_token"
+                && invitation.invited_by == Some(supervisor_id)
+                && invitation.is_pending()
+        })
+        .times(1)
+        .returning(Ok);
+    mocks
+        .mailer
+        .expect_send()
+        .withf(|message| {
+            message.to == "invitee@example.com"
+                && message.subject.contains("Potato Farms")
+                && message
+                    .body
+                    .contains("https://app.example.com/accept-invitation?token=invitation_token")
+        })
+        .times(1)
+        .returning(|_| Ok(()));
+
+    let service = mocks.into_service();
+
+    let invitation = service.create(&supervisor, create_dto(None)).await.unwrap();
+    assert_eq!(invitation.company_id, company_id);
+}
+
+#[tokio::test]
+async fn test_supervisor_cannot_invite_supervisor() {
+    let company = create_company();
+    let supervisor = create_user("supervisor", 50, Some(company.clone()));
+    let mut mocks = Mocks::new(company);
+
+    mocks.invitation_repo.expect_create().never();
+    mocks.mailer.expect_send().never();
+
+    let service = mocks.into_service();
+
+    let result = service
+        .create(&supervisor, create_dto(Some("supervisor")))
+        .await;
+    assert!(matches!(result, Err(AppError::Forbidden)));
+}
+
+#[tokio::test]
+async fn test_admin_cannot_invite_admin() {
+    let company = create_company();
+    let admin = create_user("admin", 100, None);
+    let mut mocks = Mocks::new(company.clone());
+
+    mocks.invitation_repo.expect_create().never();
+
+    let service = mocks.into_service();
+
+    let dto = CreateInvitationDto {
+        company_id: Some(company.id),
+        ..create_dto(Some("admin"))
+    };
+
+    let result = service.create(&admin, dto).await;
+    assert!(matches!(result, Err(AppError::ValidationError(_))));
+}
+
+#[tokio::test]
+async fn test_create_with_pending_invitation_is_conflict() {
+    let company = create_company();
+    let company_id = company.id;
+    let supervisor = create_user("supervisor", 50, Some(company.clone()));
+    let mut mocks = Mocks::new(company);
+
+    mocks
+        .user_repo
+        .expect_get_by_username_or_email_and_company()
+        .returning(|_, _, _| Ok(None));
+    mocks
+        .invitation_repo
+        .expect_get_pending_by_email()
+        .with(eq(company_id), eq("invitee@example.com"))
+        .returning(move |company_id, _| Ok(Some(create_invitation(company_id, 10))));
+    mocks.invitation_repo.expect_create().never();
+
+    let service = mocks.into_service();
+
+    let result = service.create(&supervisor, create_dto(None)).await;
+    assert!(matches!(result, Err(AppError::Conflict(_))));
+}
+
+#[tokio::test]
+async fn test_create_for_existing_user_fails() {
+    let company = create_company();
+    let supervisor = create_user("supervisor", 50, Some(company.clone()));
+    let existing = create_user("user", 10, Some(company.clone()));
+    let mut mocks = Mocks::new(company);
+
+    mocks
+        .user_repo
+        .expect_get_by_username_or_email_and_company()
+        .returning(move |_, _, _| Ok(Some(existing.clone())));
+    mocks.invitation_repo.expect_create().never();
+
+    let service = mocks.into_service();
+
+    let result = service.create(&supervisor, create_dto(None)).await;
+    assert!(matches!(result, Err(AppError::UserAlreadyExists)));
+}
+
+#[tokio::test]
+async fn test_resend_expired_invitation_renews_link() {
+    let company = create_company();
+    let supervisor = create_user("supervisor", 50, Some(company.clone()));
+    let mut invitation = create_invitation(company.id, 10);
+    invitation.token_hash = "hash_old_token".to_string();
+    invitation.expires_at = Utc::now() - Duration::hours(1);
+    let invitation_id = invitation.id;
+    let mut mocks = Mocks::new(company);
+
+    mocks
+        .invitation_repo
+        .expect_get_by_id()
+        .with(eq(invitation_id))
+        .returning(move |_| Ok(Some(invitation.clone())));
+    mocks
+        .invitation_repo
+        .expect_update()
+        .withf(|invitation| {
+            invitation.token_hash == "hash_invitation_token" && invitation.is_pending()
+        })
+        .times(1)
+        .returning(Ok);
+    mocks.mailer.expect_send().times(1).returning(|_| Ok(()));
+
+    let service = mocks.into_service();
+
+    let invitation = service.resend(&supervisor, invitation_id).await.unwrap();
+    assert!(invitation.is_pending());
+}
+
+#[tokio::test]
+async fn test_resend_accepted_invitation_is_conflict() {
+    let company = create_company();
+    let supervisor = create_user("supervisor", 50, Some(company.clone()));
+    let mut invitation = create_invitation(company.id, 10);
+    invitation.accepted_at = Some(Utc::now());
+    let invitation_id = invitation.id;
+    let mut mocks = Mocks::new(company);
+
+    mocks
+        .invitation_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(invitation.clone())));
+    mocks.invitation_repo.expect_update().never();
+    mocks.mailer.expect_send().never();
+
+    let service = mocks.into_service();
+
+    let result = service.resend(&supervisor, invitation_id).await;
+    assert!(matches!(result, Err(AppError::Conflict(_))));
+}
+
+#[tokio::test]
+async fn test_supervisor_cannot_cancel_other_company_invitation() {
+    let company = create_company();
+    let supervisor = create_user("supervisor", 50, Some(create_company()));
+    let invitation = create_invitation(company.id, 10);
+    let invitation_id = invitation.id;
+    let mut mocks = Mocks::new(company);
+
+    mocks
+        .invitation_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(invitation.clone())));
+    mocks.invitation_repo.expect_cancel().never();
+
+    let service = mocks.into_service();
+
+    let result = service.cancel(&supervisor, invitation_id).await;
+    assert!(matches!(result, Err(AppError::Forbidden)));
+}
+
+#[tokio::test]
+async fn test_accept_creates_user_with_invited_role() {
+    let company = create_company();
+    let company_id = company.id;
+    let invitation = create_invitation(company_id, 10);
+    let invitation_id = invitation.id;
+    let mut mocks = Mocks::new(company);
+
+    mocks
+        .invitation_repo
+        .expect_get_by_token_hash()
+        .with(eq("hash_invitation_token"))
+        .returning(move |_| Ok(Some(invitation.clone())));
+    mocks
+        .user_repo
+        .expect_get_by_username_and_company()
+        .with(eq("invitee"), eq(Some(company_id)))
+        .returning(|_, _| Ok(None));
+    mocks
+        .encoder
+        .expect_hash()
+        .with(eq("new_password"))
+        .returning(|_| Ok("new_hash".to_string()));
+    mocks
+        .invitation_repo
+        .expect_mark_accepted()
+        .with(eq(invitation_id))
+        .times(1)
+        .returning(|_| Ok(true));
+    mocks
+        .user_repo
+        .expect_create()
+        .withf(move |user| {
+            user.username == "invitee"
+                && user.email.as_deref() == Some("invitee@example.com")
+                && user.password_hash == "new_hash"
+                && user.role.name == "user"
+                && user.company.as_ref().map(|c| c.id) == Some(company_id)
+        })
+        .times(1)
+        .returning(Ok);
+
+    let service = mocks.into_service();
+
+    let user = service.accept(accept_dto()).await.unwrap();
+    assert_eq!(user.name.as_deref(), Some("New"));
+}
+
+#[tokio::test]
+async fn test_accept_expired_invitation_fails() {
+    let company = create_company();
+    let mut invitation = create_invitation(company.id, 10);
+    invitation.expires_at = Utc::now() - Duration::minutes(1);
+    let mut mocks = Mocks::new(company);
+
+    mocks
+        .invitation_repo
+        .expect_get_by_token_hash()
+        .returning(move |_| Ok(Some(invitation.clone())));
+    mocks.invitation_repo.expect_mark_accepted().never();
+    mocks.user_repo.expect_create().never();
+
+    let service = mocks.into_service();
+
+    let result = service.accept(accept_dto()).await;
+    assert!(matches!(result, Err(AppError::ValidationError(_))));
+}
+
+#[tokio::test]
+async fn test_accept_with_taken_username_keeps_invitation() {
+    let company = create_company();
+    let existing = create_user("user", 10, Some(company.clone()));
+    let invitation = create_invitation(company.id, 10);
+    let mut mocks = Mocks::new(company);
+
+    mocks
+        .invitation_repo
+        .expect_get_by_token_hash()
+        .returning(move |_| Ok(Some(invitation.clone())));
+    mocks
+        .user_repo
+        .expect_get_by_username_and_company()
+        .returning(move |_, _| Ok(Some(existing.clone())));
+    mocks.invitation_repo.expect_mark_accepted().never();
+
+    let service = mocks.into_service();
+
+    let result = service.accept(accept_dto()).await;
+    assert!(matches!(result, Err(AppError::UserAlreadyExists)));
+}
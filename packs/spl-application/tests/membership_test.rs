@@ -0,0 +1,362 @@
+mod common;
+
+use chrono::Utc;
+use common::mocks::{
+    MockCompanyRepository, MockMembershipRepository, MockPermissionRepository, MockRoleRepository,
+    MockTeamRepository, MockUserRepository,
+};
+use common::{create_company, create_role, create_user, grant};
+use mockall::predicate::*;
+use spl_application::dtos::user::SetMembershipDto;
+use spl_application::services::access_control::AccessControlService;
+use spl_application::services::policy::PolicyService;
+use spl_application::services::user::MembershipService;
+use spl_domain::entities::company::Company;
+use spl_domain::entities::user::{permissions, CompanyMembership, PermissionScope, Role, User};
+use spl_shared::error::AppError;
+use std::sync::Arc;
+use uuid::Uuid;
+
+struct Mocks {
+    membership_repo: MockMembershipRepository,
+    user_repo: MockUserRepository,
+    role_repo: MockRoleRepository,
+    company_repo: MockCompanyRepository,
+}
+
+impl Mocks {
+    fn new() -> Self {
+        // Every company targeted by an admin exists
+        let mut company_repo = MockCompanyRepository::new();
+        company_repo.expect_get_by_id().returning(|id| {
+            Ok(Some(Company {
+                id,
+                ..create_company()
+            }))
+        });
+
+        Self {
+            membership_repo: MockMembershipRepository::new(),
+            user_repo: MockUserRepository::new(),
+            role_repo: MockRoleRepository::new(),
+            company_repo,
+        }
+    }
+
+    fn into_service(self) -> MembershipService {
+        let mut permission_repo = MockPermissionRepository::new();
+        permission_repo.expect_get_grants().returning(|| {
+            Ok(vec![
+                grant("admin", permissions::USERS_READ, PermissionScope::Any),
+                grant("admin", permissions::USERS_MANAGE, PermissionScope::Any),
+                grant(
+                    "supervisor",
+                    permissions::USERS_READ,
+                    PermissionScope::Company,
+                ),
+                grant(
+                    "supervisor",
+                    permissions::USERS_MANAGE,
+                    PermissionScope::Company,
+                ),
+                grant("user", permissions::PLOTS_READ, PermissionScope::Own),
+            ])
+        });
+
+        let user_repo = Arc::new(self.user_repo);
+        let access_control = Arc::new(AccessControlService::new(
+            Arc::new(self.company_repo),
+            Arc::new(MockTeamRepository::new()),
+            Arc::new(PolicyService::new(Arc::new(permission_repo), 30)),
+        ));
+
+        MembershipService::new(
+            Arc::new(self.membership_repo),
+            user_repo,
+            Arc::new(self.role_repo),
+            access_control,
+        )
+    }
+}
+
+fn create_membership(user_id: Uuid, company: Company, role: Role) -> CompanyMembership {
+    CompanyMembership {
+        user_id,
+        company,
+        role,
+        created_at: Utc::now(),
+    }
+}
+
+fn expect_user(mocks: &mut Mocks, user: &User) {
+    let stored = user.clone();
+    mocks
+        .user_repo
+        .expect_get_by_id()
+        .with(eq(user.id))
+        .returning(move |_| Ok(Some(stored.clone())));
+}
+
+fn expect_roles(mocks: &mut Mocks) {
+    mocks.role_repo.expect_get_by_name().returning(|name| {
+        Ok(match name {
+            "admin" => Some(create_role("admin", 100)),
+            "supervisor" => Some(create_role("supervisor", 50)),
+            "user" => Some(create_role("user", 10)),
+            _ => None,
+        })
+    });
+}
+
+#[tokio::test]
+async fn test_get_by_user_lists_own_company_first() {
+    let home = create_company();
+    let other = create_company();
+    let user = create_user("user", 10, Some(home.clone()));
+
+    let mut mocks = Mocks::new();
+    expect_user(&mut mocks, &user);
+    let membership = create_membership(user.id, other.clone(), create_role("supervisor", 50));
+    mocks
+        .membership_repo
+        .expect_get_by_user_id()
+        .with(eq(user.id))
+        .returning(move |_| Ok(vec![membership.clone()]));
+    let service = mocks.into_service();
+
+    let memberships = service.get_by_user(user.id).await.unwrap();
+
+    assert_eq!(memberships.len(), 2);
+    assert!(memberships[0].home);
+    assert_eq!(memberships[0].membership.company.id, home.id);
+    assert_eq!(memberships[0].membership.role.name, "user");
+    assert!(!memberships[1].home);
+    assert_eq!(memberships[1].membership.company.id, other.id);
+    assert_eq!(memberships[1].membership.role.name, "supervisor");
+}
+
+#[tokio::test]
+async fn test_admin_adds_consultant_to_company() {
+    let admin = create_user("admin", 100, None);
+    let consultant = create_user("user", 10, Some(create_company()));
+    let target = create_company();
+    let target_id = target.id;
+
+    let mut mocks = Mocks::new();
+    expect_user(&mut mocks, &consultant);
+    expect_roles(&mut mocks);
+    mocks
+        .membership_repo
+        .expect_set()
+        .with(eq(consultant.id), eq(target_id), eq(50))
+        .times(1)
+        .returning(move |user_id, _, _| {
+            Ok(create_membership(
+                user_id,
+                target.clone(),
+                create_role("supervisor", 50),
+            ))
+        });
+    let service = mocks.into_service();
+
+    let membership = service
+        .set(
+            &admin,
+            target_id,
+            consultant.id,
+            SetMembershipDto {
+                role: "supervisor".to_string(),
+            },
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(membership.company.id, target_id);
+    assert_eq!(membership.role.name, "supervisor");
+}
+
+#[tokio::test]
+async fn test_set_rejects_own_company_of_user() {
+    let admin = create_user("admin", 100, None);
+    let company = create_company();
+    let user = create_user("user", 10, Some(company.clone()));
+
+    let mut mocks = Mocks::new();
+    expect_user(&mut mocks, &user);
+    mocks.membership_repo.expect_set().never();
+    let service = mocks.into_service();
+
+    let result = service
+        .set(
+            &admin,
+            company.id,
+            user.id,
+            SetMembershipDto {
+                role: "supervisor".to_string(),
+            },
+        )
+        .await;
+
+    assert!(matches!(result, Err(AppError::ValidationError(_))));
+}
+
+#[tokio::test]
+async fn test_set_rejects_roles_without_company() {
+    let admin = create_user("admin", 100, None);
+    let user = create_user("user", 10, Some(create_company()));
+
+    let mut mocks = Mocks::new();
+    expect_user(&mut mocks, &user);
+    expect_roles(&mut mocks);
+    mocks.membership_repo.expect_set().never();
+    let service = mocks.into_service();
+
+    let result = service
+        .set(
+            &admin,
+            Uuid::new_v4(),
+            user.id,
+            SetMembershipDto {
+                role: "admin".to_string(),
+            },
+        )
+        .await;
+
+    assert!(matches!(result, Err(AppError::ValidationError(_))));
+}
+
+#[tokio::test]
+async fn test_supervisor_cannot_give_their_own_level() {
+    let company = create_company();
+    let supervisor = create_user("supervisor", 50, Some(company.clone()));
+    let consultant = create_user("user", 10, Some(create_company()));
+
+    let mut mocks = Mocks::new();
+    expect_user(&mut mocks, &consultant);
+    expect_roles(&mut mocks);
+    mocks.membership_repo.expect_set().never();
+    let service = mocks.into_service();
+
+    let result = service
+        .set(
+            &supervisor,
+            company.id,
+            consultant.id,
+            SetMembershipDto {
+                role: "supervisor".to_string(),
+            },
+        )
+        .await;
+
+    assert!(matches!(result, Err(AppError::Forbidden)));
+}
+
+#[tokio::test]
+async fn test_supervisor_cannot_add_members_to_other_company() {
+    let supervisor = create_user("supervisor", 50, Some(create_company()));
+    let consultant = create_user("user", 10, Some(create_company()));
+
+    let mut mocks = Mocks::new();
+    mocks.membership_repo.expect_set().never();
+    let service = mocks.into_service();
+
+    let result = service
+        .set(
+            &supervisor,
+            Uuid::new_v4(),
+            consultant.id,
+            SetMembershipDto {
+                role: "user".to_string(),
+            },
+        )
+        .await;
+
+    assert!(matches!(result, Err(AppError::Forbidden)));
+}
+
+#[tokio::test]
+async fn test_user_can_leave_company() {
+    let user = create_user("user", 10, Some(create_company()));
+    let company_id = Uuid::new_v4();
+
+    let mut mocks = Mocks::new();
+    mocks
+        .membership_repo
+        .expect_delete()
+        .with(eq(user.id), eq(company_id))
+        .times(1)
+        .returning(|_, _| Ok(()));
+    let service = mocks.into_service();
+
+    service.remove(&user, company_id, user.id).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_user_cannot_remove_others() {
+    let user = create_user("user", 10, Some(create_company()));
+
+    let mut mocks = Mocks::new();
+    mocks.membership_repo.expect_delete().never();
+    let service = mocks.into_service();
+
+    let result = service.remove(&user, Uuid::new_v4(), Uuid::new_v4()).await;
+
+    assert!(matches!(result, Err(AppError::Forbidden)));
+}
+
+#[tokio::test]
+async fn test_resolve_acts_with_role_of_membership() {
+    let user = create_user("user", 10, Some(create_company()));
+    let other = create_company();
+    let other_id = other.id;
+
+    let mut mocks = Mocks::new();
+    mocks
+        .membership_repo
+        .expect_get()
+        .with(eq(user.id), eq(other_id))
+        .returning(move |user_id, _| {
+            Ok(Some(create_membership(
+                user_id,
+                other.clone(),
+                create_role("supervisor", 50),
+            )))
+        });
+    let service = mocks.into_service();
+
+    let resolved = service.resolve(user.clone(), other_id).await.unwrap();
+
+    assert_eq!(resolved.id, user.id);
+    assert_eq!(resolved.company.map(|c| c.id), Some(other_id));
+    assert_eq!(resolved.role.name, "supervisor");
+}
+
+#[tokio::test]
+async fn test_resolve_rejects_ended_membership() {
+    let user = create_user("user", 10, Some(create_company()));
+
+    let mut mocks = Mocks::new();
+    mocks
+        .membership_repo
+        .expect_get()
+        .returning(|_, _| Ok(None));
+    let service = mocks.into_service();
+
+    let result = service.resolve(user, Uuid::new_v4()).await;
+
+    assert!(matches!(result, Err(AppError::AuthError(_))));
+}
+
+#[tokio::test]
+async fn test_resolve_own_company_keeps_user() {
+    let company = create_company();
+    let user = create_user("user", 10, Some(company.clone()));
+
+    let mut mocks = Mocks::new();
+    mocks.membership_repo.expect_get().never();
+    let service = mocks.into_service();
+
+    let resolved = service.resolve(user.clone(), company.id).await.unwrap();
+
+    assert_eq!(resolved.role.name, "user");
+}
@@ -0,0 +1,692 @@
+mod common;
+
+use bytes::Bytes;
+use chrono::Utc;
+use common::mocks::{
+    MockBlobStorageClient, MockCompanyOffboardingRepository, MockCompanyRepository,
+    MockInferenceJobRepository, MockOffboardingEventRepository, MockPermissionRepository,
+    MockPlotRepository, MockPredictionRepository, MockUserCache, MockUserRepository,
+};
+use common::{create_company, create_user, grant};
+use mockall::predicate::*;
+use spl_application::services::offboarding::OffboardingService;
+use spl_application::services::policy::PolicyService;
+use spl_domain::entities::diagnostics::{
+    InferenceJob, Label, MarkType, Prediction, PredictionMark,
+};
+use spl_domain::entities::image::Image;
+use spl_domain::entities::offboarding::{CompanyOffboarding, OffboardingStatus};
+use spl_domain::entities::user::{permissions, PermissionScope, User};
+use spl_domain::ports::archive::{Archive, ArchiveWriter};
+use spl_shared::error::{AppError, Result};
+use std::sync::{Arc, Mutex};
+use uuid::Uuid;
+
+/// Archive that keeps the paths written to it, to check what an export contains
+#[derive(Default, Clone)]
+struct RecordingArchiveWriter {
+    paths: Arc<Mutex<Vec<String>>>,
+}
+
+struct RecordingArchive {
+    paths: Arc<Mutex<Vec<String>>>,
+}
+
+impl Archive for RecordingArchive {
+    fn add(&mut self, path: &str, _content: &[u8]) -> Result<()> {
+        self.paths.lock().unwrap().push(path.to_string());
+        Ok(())
+    }
+
+    fn finish(self: Box<Self>) -> Result<Bytes> {
+        Ok(Bytes::from_static(b"archive"))
+    }
+}
+
+impl ArchiveWriter for RecordingArchiveWriter {
+    fn create(&self) -> Box<dyn Archive> {
+        Box::new(RecordingArchive {
+            paths: self.paths.clone(),
+        })
+    }
+
+    fn extension(&self) -> &'static str {
+        "zip"
+    }
+
+    fn content_type(&self) -> &'static str {
+        "application/zip"
+    }
+}
+
+struct Mocks {
+    offboarding_repo: MockCompanyOffboardingRepository,
+    event_repo: MockOffboardingEventRepository,
+    company_repo: MockCompanyRepository,
+    user_repo: MockUserRepository,
+    plot_repo: MockPlotRepository,
+    prediction_repo: MockPredictionRepository,
+    job_repo: MockInferenceJobRepository,
+    storage_client: MockBlobStorageClient,
+    user_cache: MockUserCache,
+    archive_writer: RecordingArchiveWriter,
+}
+
+impl Mocks {
+    fn new() -> Self {
+        let mut event_repo = MockOffboardingEventRepository::new();
+        event_repo.expect_create().returning(Ok);
+
+        Self {
+            offboarding_repo: MockCompanyOffboardingRepository::new(),
+            event_repo,
+            company_repo: MockCompanyRepository::new(),
+            user_repo: MockUserRepository::new(),
+            plot_repo: MockPlotRepository::new(),
+            prediction_repo: MockPredictionRepository::new(),
+            job_repo: MockInferenceJobRepository::new(),
+            storage_client: MockBlobStorageClient::new(),
+            user_cache: MockUserCache::new(),
+            archive_writer: RecordingArchiveWriter::default(),
+        }
+    }
+
+    fn into_service(self) -> Arc<OffboardingService> {
+        let mut permission_repo = MockPermissionRepository::new();
+        permission_repo.expect_get_grants().returning(|| {
+            Ok(vec![
+                grant("admin", permissions::COMPANIES_MANAGE, PermissionScope::Any),
+                grant(
+                    "supervisor",
+                    permissions::USERS_MANAGE,
+                    PermissionScope::Company,
+                ),
+            ])
+        });
+
+        Arc::new(OffboardingService::new(
+            Arc::new(self.offboarding_repo),
+            Arc::new(self.event_repo),
+            Arc::new(self.company_repo),
+            Arc::new(self.user_repo),
+            Arc::new(self.plot_repo),
+            Arc::new(self.prediction_repo),
+            Arc::new(self.job_repo),
+            Arc::new(self.storage_client),
+            Arc::new(self.archive_writer),
+            Arc::new(self.user_cache),
+            Arc::new(PolicyService::new(Arc::new(permission_repo), 30)),
+        ))
+    }
+}
+
+fn create_offboarding(company_id: Uuid, status: OffboardingStatus) -> CompanyOffboarding {
+    CompanyOffboarding {
+        id: Uuid::new_v4(),
+        company_id,
+        company_name: "Company".to_string(),
+        requested_by: Uuid::new_v4(),
+        status,
+        export_path: None,
+        export_size: None,
+        users_total: 0,
+        users_deleted: 0,
+        error: None,
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+        completed_at: None,
+    }
+}
+
+fn create_prediction(user: &User, mask_path: &str) -> Prediction {
+    let id = Uuid::new_v4();
+    Prediction {
+        id,
+        user: user.clone(),
+        company_id: user.company.as_ref().map(|c| c.id),
+        image: Image {
+            id: Uuid::new_v4(),
+            user_id: user.id,
+            filename: "leaf.jpg".to_string(),
+            filepath: format!("{}/leaf.jpg", user.id),
+            prediction_id: Some(id),
+            created_at: Utc::now(),
+        },
+        label: Label {
+            id: 1,
+            name: "healthy".to_string(),
+            description: None,
+            min: 0.0,
+            max: 10.0,
+            weight: 1,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        },
+        marks: vec![PredictionMark {
+            id: Uuid::new_v4(),
+            data: serde_json::json!({ "filepath": mask_path }),
+            mark_type: MarkType {
+                id: 1,
+                name: "leaf_mask".to_string(),
+                description: None,
+                created_at: Utc::now(),
+            },
+            prediction_id: id,
+            created_at: Utc::now(),
+        }],
+        plot_id: None,
+        presence_confidence: 0.1,
+        absence_confidence: 0.9,
+        severity: 2.0,
+        model: None,
+        feedback: None,
+        created_at: Utc::now(),
+    }
+}
+
+/// Offboarding repository that keeps the latest version of the offboarding
+fn stateful_offboarding_repo(
+    offboarding: CompanyOffboarding,
+) -> (
+    MockCompanyOffboardingRepository,
+    Arc<Mutex<CompanyOffboarding>>,
+) {
+    let state = Arc::new(Mutex::new(offboarding));
+    let mut repo = MockCompanyOffboardingRepository::new();
+
+    let current = state.clone();
+    repo.expect_get_by_id()
+        .returning(move |_| Ok(Some(current.lock().unwrap().clone())));
+    let current = state.clone();
+    repo.expect_update().returning(move |offboarding| {
+        *current.lock().unwrap() = offboarding.clone();
+        Ok(offboarding)
+    });
+
+    (repo, state)
+}
+
+#[tokio::test]
+async fn test_start_requires_companies_manage() {
+    let service = Mocks::new().into_service();
+    let supervisor = create_user("supervisor", 50, Some(create_company()));
+
+    let result = service.start(&supervisor, Uuid::new_v4()).await;
+
+    assert!(matches!(result, Err(AppError::Forbidden)));
+}
+
+#[tokio::test]
+async fn test_start_rejects_own_company() {
+    let company = create_company();
+    let company_id = company.id;
+    let mut mocks = Mocks::new();
+    let stored = company.clone();
+    mocks
+        .company_repo
+        .expect_get_by_id()
+        .with(eq(company_id))
+        .returning(move |_| Ok(Some(stored.clone())));
+    let service = mocks.into_service();
+    let admin = create_user("admin", 100, Some(company));
+
+    let result = service.start(&admin, company_id).await;
+
+    assert!(matches!(result, Err(AppError::ValidationError(_))));
+}
+
+#[tokio::test]
+async fn test_start_rejects_company_with_pending_offboarding() {
+    let company = create_company();
+    let company_id = company.id;
+    let mut mocks = Mocks::new();
+    mocks
+        .company_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(company.clone())));
+    mocks
+        .offboarding_repo
+        .expect_get_by_company_id()
+        .returning(move |company_id| {
+            Ok(vec![create_offboarding(
+                company_id,
+                OffboardingStatus::Exported,
+            )])
+        });
+    mocks.offboarding_repo.expect_create().never();
+    let service = mocks.into_service();
+    let admin = create_user("admin", 100, None);
+
+    let result = service.start(&admin, company_id).await;
+
+    assert!(matches!(result, Err(AppError::Conflict(_))));
+}
+
+#[tokio::test]
+async fn test_start_creates_exporting_offboarding() {
+    let company = create_company();
+    let company_id = company.id;
+    let mut mocks = Mocks::new();
+    mocks
+        .company_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(company.clone())));
+    // Earlier offboardings that ended do not block a new one
+    mocks
+        .offboarding_repo
+        .expect_get_by_company_id()
+        .returning(move |company_id| {
+            Ok(vec![create_offboarding(
+                company_id,
+                OffboardingStatus::Completed,
+            )])
+        });
+    mocks
+        .offboarding_repo
+        .expect_create()
+        .withf(move |o| o.company_id == company_id && o.status == OffboardingStatus::Exporting)
+        .times(1)
+        .returning(Ok);
+    // The export runs in the background
+    mocks
+        .offboarding_repo
+        .expect_get_by_id()
+        .returning(|_| Ok(None));
+    mocks.event_repo = MockOffboardingEventRepository::new();
+    mocks
+        .event_repo
+        .expect_create()
+        .withf(|e| e.action == "export_started")
+        .times(1)
+        .returning(Ok);
+    let service = mocks.into_service();
+    let admin = create_user("admin", 100, None);
+
+    let offboarding = service.start(&admin, company_id).await.unwrap();
+
+    assert_eq!(offboarding.requested_by, admin.id);
+    assert_eq!(offboarding.company_name, "Company");
+}
+
+#[tokio::test]
+async fn test_export_archives_company_and_marks_exported() {
+    let company = create_company();
+    let company_id = company.id;
+    let user = create_user("user", 10, Some(company.clone()));
+    let prediction = create_prediction(&user, "masks/gone.png");
+    let image_path = prediction.image.filepath.clone();
+    let job = InferenceJob::queued(
+        user.id,
+        Some(company_id),
+        None,
+        "queued.jpg".to_string(),
+        "uploads/queued.jpg".to_string(),
+    );
+    let offboarding = create_offboarding(company_id, OffboardingStatus::Exporting);
+    let offboarding_id = offboarding.id;
+
+    let mut mocks = Mocks::new();
+    let (offboarding_repo, state) = stateful_offboarding_repo(offboarding);
+    mocks.offboarding_repo = offboarding_repo;
+    mocks
+        .company_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(company.clone())));
+    let member = user.clone();
+    mocks
+        .user_repo
+        .expect_get_by_company_id()
+        .returning(move |_| Ok(vec![member.clone()]));
+    mocks
+        .plot_repo
+        .expect_get_by_company_id()
+        .returning(|_| Ok(vec![]));
+    mocks
+        .prediction_repo
+        .expect_get_by_company_id()
+        .with(eq(company_id))
+        .returning(move |_| Ok(vec![prediction.clone()]));
+    mocks
+        .job_repo
+        .expect_get_by_company_id()
+        .with(eq(company_id))
+        .returning(move |_| Ok(vec![job.clone()]));
+    let stored_image = image_path.clone();
+    mocks
+        .storage_client
+        .expect_download()
+        .returning(move |path| {
+            if path == stored_image || path == "uploads/queued.jpg" {
+                Ok(Bytes::from_static(b"image"))
+            } else {
+                Err(AppError::NotFound("File not found".to_string()))
+            }
+        });
+    let export_path = format!("offboarding/{}/{}.zip", company_id, offboarding_id);
+    let expected_path = export_path.clone();
+    mocks
+        .storage_client
+        .expect_upload()
+        .withf(move |_, destination| destination == expected_path)
+        .times(1)
+        .returning(|_, destination| Ok(destination.to_string()));
+    mocks.event_repo = MockOffboardingEventRepository::new();
+    mocks
+        .event_repo
+        .expect_create()
+        .withf(|e| {
+            e.action == "export_completed"
+                && e.detail.as_deref()
+                    == Some("1 users, 0 plots, 1 predictions, 1 jobs, 2 files, 1 missing")
+        })
+        .times(1)
+        .returning(Ok);
+    let archive_writer = mocks.archive_writer.clone();
+    let service = mocks.into_service();
+
+    service.run(offboarding_id).await;
+
+    let offboarding = state.lock().unwrap().clone();
+    assert_eq!(offboarding.status, OffboardingStatus::Exported);
+    assert_eq!(offboarding.export_path, Some(export_path));
+    assert_eq!(offboarding.export_size, Some(7));
+
+    let paths = archive_writer.paths.lock().unwrap().clone();
+    assert!(paths.contains(&format!("files/{}", image_path)));
+    assert!(paths.contains(&"files/uploads/queued.jpg".to_string()));
+    assert!(!paths.contains(&"files/masks/gone.png".to_string()));
+    for file in [
+        "manifest.json",
+        "users.json",
+        "plots.json",
+        "predictions.json",
+        "jobs.json",
+    ] {
+        assert!(paths.contains(&file.to_string()), "missing {}", file);
+    }
+}
+
+#[tokio::test]
+async fn test_confirm_deletion_requires_finished_export() {
+    let offboarding = create_offboarding(Uuid::new_v4(), OffboardingStatus::Exporting);
+    let offboarding_id = offboarding.id;
+    let mut mocks = Mocks::new();
+    mocks
+        .offboarding_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(offboarding.clone())));
+    mocks.offboarding_repo.expect_update().never();
+    let service = mocks.into_service();
+    let admin = create_user("admin", 100, None);
+
+    let result = service.confirm_deletion(&admin, offboarding_id).await;
+
+    assert!(matches!(result, Err(AppError::Conflict(_))));
+}
+
+#[tokio::test]
+async fn test_deletion_removes_users_predictions_and_company() {
+    let company = create_company();
+    let company_id = company.id;
+    let first = create_user("first", 10, Some(company.clone()));
+    let second = create_user("second", 10, Some(company.clone()));
+    let prediction = create_prediction(&first, "masks/first.png");
+    let prediction_id = prediction.id;
+    let image_path = prediction.image.filepath.clone();
+    let job = InferenceJob::queued(
+        second.id,
+        Some(company_id),
+        None,
+        "queued.jpg".to_string(),
+        "uploads/queued.jpg".to_string(),
+    );
+    let job_id = job.id;
+    let mut offboarding = create_offboarding(company_id, OffboardingStatus::Deleting);
+    offboarding.export_path = Some("offboarding/export.zip".to_string());
+    let offboarding_id = offboarding.id;
+
+    let mut mocks = Mocks::new();
+    let (offboarding_repo, state) = stateful_offboarding_repo(offboarding);
+    mocks.offboarding_repo = offboarding_repo;
+    let members = vec![first.clone(), second.clone()];
+    mocks
+        .user_repo
+        .expect_get_by_company_id()
+        .with(eq(company_id))
+        .returning(move |_| Ok(members.clone()));
+    mocks
+        .job_repo
+        .expect_get_by_company_id()
+        .with(eq(company_id))
+        .returning(move |_| Ok(vec![job.clone()]));
+    let deleted_job = InferenceJob::queued(
+        Uuid::new_v4(),
+        Some(company_id),
+        None,
+        "queued.jpg".to_string(),
+        "uploads/queued.jpg".to_string(),
+    );
+    mocks
+        .job_repo
+        .expect_delete()
+        .with(eq(job_id))
+        .times(1)
+        .returning(move |_| Ok(deleted_job.clone()));
+    mocks
+        .prediction_repo
+        .expect_get_by_company_id()
+        .with(eq(company_id))
+        .returning(move |_| Ok(vec![prediction.clone()]));
+    let deleted = create_prediction(&first, "masks/first.png");
+    mocks
+        .prediction_repo
+        .expect_delete()
+        .with(eq(prediction_id))
+        .times(1)
+        .returning(move |_| Ok(deleted.clone()));
+    // The mask was never stored
+    mocks
+        .storage_client
+        .expect_delete()
+        .withf(move |path| {
+            [image_path.as_str(), "masks/first.png", "uploads/queued.jpg"].contains(&path)
+        })
+        .times(3)
+        .returning(|path| {
+            if path == "masks/first.png" {
+                Err(AppError::NotFound("File not found".to_string()))
+            } else {
+                Ok(())
+            }
+        });
+    mocks.storage_client.expect_delete_directory().never();
+    let removed = first.clone();
+    mocks
+        .user_repo
+        .expect_delete()
+        .times(2)
+        .returning(move |_| Ok(removed.clone()));
+    mocks
+        .user_cache
+        .expect_invalidate()
+        .times(2)
+        .returning(|_| ());
+    let stored = company.clone();
+    mocks
+        .company_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(stored.clone())));
+    mocks
+        .company_repo
+        .expect_delete()
+        .with(eq(company_id))
+        .times(1)
+        .returning(move |_| Ok(company.clone()));
+    let service = mocks.into_service();
+
+    service.run(offboarding_id).await;
+
+    let offboarding = state.lock().unwrap().clone();
+    assert_eq!(offboarding.status, OffboardingStatus::Completed);
+    assert_eq!(offboarding.users_total, 2);
+    assert_eq!(offboarding.users_deleted, 2);
+    assert!(offboarding.completed_at.is_some());
+}
+
+#[tokio::test]
+async fn test_predictions_follow_company_they_were_made_in() {
+    let company = create_company();
+    let company_id = company.id;
+    let other = create_company();
+    // Each predicted in the company of the other one under a membership
+    let member = create_user("member", 10, Some(company.clone()));
+    let outsider = create_user("outsider", 10, Some(other.clone()));
+    let mut made_here = create_prediction(&outsider, "masks/here.png");
+    made_here.company_id = Some(company_id);
+    let mut made_elsewhere = create_prediction(&member, "masks/elsewhere.png");
+    made_elsewhere.company_id = Some(other.id);
+    let made_here_id = made_here.id;
+    let kept_paths = [
+        made_elsewhere.image.filepath.clone(),
+        "masks/elsewhere.png".to_string(),
+    ];
+    let offboarding = create_offboarding(company_id, OffboardingStatus::Exporting);
+    let offboarding_id = offboarding.id;
+
+    let mut mocks = Mocks::new();
+    let (offboarding_repo, state) = stateful_offboarding_repo(offboarding);
+    mocks.offboarding_repo = offboarding_repo;
+    let stored = company.clone();
+    mocks
+        .company_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(stored.clone())));
+    mocks
+        .company_repo
+        .expect_delete()
+        .returning(move |_| Ok(company.clone()));
+    let members = vec![member.clone()];
+    mocks
+        .user_repo
+        .expect_get_by_company_id()
+        .returning(move |_| Ok(members.clone()));
+    let removed = member.clone();
+    mocks
+        .user_repo
+        .expect_delete()
+        .with(eq(member.id))
+        .times(1)
+        .returning(move |_| Ok(removed.clone()));
+    mocks.user_cache.expect_invalidate().returning(|_| ());
+    mocks
+        .plot_repo
+        .expect_get_by_company_id()
+        .returning(|_| Ok(vec![]));
+    mocks
+        .job_repo
+        .expect_get_by_company_id()
+        .returning(|_| Ok(vec![]));
+    let predictions = [made_here.clone(), made_elsewhere];
+    mocks
+        .prediction_repo
+        .expect_get_by_company_id()
+        .returning(move |id| {
+            Ok(predictions
+                .iter()
+                .filter(|p| p.company_id == Some(id))
+                .cloned()
+                .collect())
+        });
+    mocks.prediction_repo.expect_get_by_user_id().never();
+    mocks
+        .prediction_repo
+        .expect_delete()
+        .with(eq(made_here_id))
+        .times(1)
+        .returning(move |_| Ok(made_here.clone()));
+    mocks
+        .storage_client
+        .expect_download()
+        .returning(|_| Ok(Bytes::from_static(b"file")));
+    mocks
+        .storage_client
+        .expect_upload()
+        .returning(|_, destination| Ok(destination.to_string()));
+    let deleted_paths = Arc::new(Mutex::new(Vec::new()));
+    let deleted = deleted_paths.clone();
+    mocks.storage_client.expect_delete().returning(move |path| {
+        deleted.lock().unwrap().push(path.to_string());
+        Ok(())
+    });
+    mocks.storage_client.expect_delete_directory().never();
+    let archive_writer = mocks.archive_writer.clone();
+    let service = mocks.into_service();
+
+    service.run(offboarding_id).await;
+    assert_eq!(state.lock().unwrap().status, OffboardingStatus::Exported);
+    state.lock().unwrap().status = OffboardingStatus::Deleting;
+    service.run(offboarding_id).await;
+
+    let offboarding = state.lock().unwrap().clone();
+    assert_eq!(offboarding.status, OffboardingStatus::Completed);
+    let exported = archive_writer.paths.lock().unwrap().clone();
+    let deleted = deleted_paths.lock().unwrap().clone();
+    assert!(exported.contains(&format!("files/{}/leaf.jpg", outsider.id)));
+    assert!(exported.contains(&"files/masks/here.png".to_string()));
+    assert!(deleted.contains(&format!("{}/leaf.jpg", outsider.id)));
+    assert!(deleted.contains(&"masks/here.png".to_string()));
+    for path in kept_paths {
+        assert!(!exported.contains(&format!("files/{}", path)));
+        assert!(!deleted.contains(&path));
+    }
+}
+
+#[tokio::test]
+async fn test_failed_step_marks_offboarding_failed() {
+    let company = create_company();
+    let company_id = company.id;
+    let user = create_user("user", 10, Some(company.clone()));
+    let prediction = create_prediction(&user, "masks/user.png");
+    let mut offboarding = create_offboarding(company_id, OffboardingStatus::Deleting);
+    offboarding.export_path = Some("offboarding/export.zip".to_string());
+    offboarding.users_deleted = 3;
+    let offboarding_id = offboarding.id;
+
+    let mut mocks = Mocks::new();
+    let (offboarding_repo, state) = stateful_offboarding_repo(offboarding);
+    mocks.offboarding_repo = offboarding_repo;
+    mocks
+        .user_repo
+        .expect_get_by_company_id()
+        .returning(move |_| Ok(vec![user.clone()]));
+    mocks
+        .job_repo
+        .expect_get_by_company_id()
+        .returning(|_| Ok(vec![]));
+    mocks
+        .prediction_repo
+        .expect_get_by_company_id()
+        .returning(move |_| Ok(vec![prediction.clone()]));
+    mocks.prediction_repo.expect_delete().never();
+    mocks
+        .storage_client
+        .expect_delete()
+        .returning(|_| Err(AppError::Unknown("storage is down".to_string())));
+    mocks.user_repo.expect_delete().never();
+    mocks.company_repo.expect_delete().never();
+    let service = mocks.into_service();
+
+    service.run(offboarding_id).await;
+
+    let offboarding = state.lock().unwrap().clone();
+    assert_eq!(offboarding.status, OffboardingStatus::Failed);
+    assert!(offboarding
+        .error
+        .as_deref()
+        .unwrap()
+        .contains("storage is down"));
+    // Progress so far is kept for the retry
+    assert_eq!(offboarding.users_deleted, 3);
+    assert_eq!(offboarding.users_total, 4);
+    assert!(offboarding.can_delete());
+}
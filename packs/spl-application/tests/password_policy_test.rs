@@ -0,0 +1,315 @@
+mod common;
+
+use chrono::{Duration, Utc};
+use common::mocks::{
+    MockBreachedPasswordList, MockCompanyPasswordPolicyRepository, MockCompanyRepository,
+    MockPasswordEncoder, MockPasswordHistoryRepository, MockPermissionRepository,
+    MockTeamRepository,
+};
+use common::{create_company, create_user, grant};
+use mockall::predicate::*;
+use spl_application::services::access_control::AccessControlService;
+use spl_application::services::password_policy::PasswordPolicyService;
+use spl_application::services::policy::PolicyService;
+use spl_domain::entities::auth::{CompanyPasswordPolicy, PasswordPolicy};
+use spl_domain::entities::user::{permissions, PermissionScope};
+use spl_shared::error::AppError;
+use std::sync::Arc;
+use uuid::Uuid;
+
+struct Mocks {
+    policy_repo: MockCompanyPasswordPolicyRepository,
+    history_repo: MockPasswordHistoryRepository,
+    company_repo: MockCompanyRepository,
+    encoder: MockPasswordEncoder,
+    breached_passwords: MockBreachedPasswordList,
+    server_policy: PasswordPolicy,
+}
+
+impl Mocks {
+    fn new() -> Self {
+        let mut breached_passwords = MockBreachedPasswordList::new();
+        breached_passwords
+            .expect_contains()
+            .returning(|password| password == "password123");
+
+        Self {
+            policy_repo: MockCompanyPasswordPolicyRepository::new(),
+            history_repo: MockPasswordHistoryRepository::new(),
+            company_repo: MockCompanyRepository::new(),
+            encoder: MockPasswordEncoder::new(),
+            breached_passwords,
+            server_policy: rules(8, 0),
+        }
+    }
+
+    fn into_service(self) -> PasswordPolicyService {
+        let mut permission_repo = MockPermissionRepository::new();
+        permission_repo.expect_get_grants().returning(|| {
+            Ok(vec![
+                grant("admin", permissions::USERS_MANAGE, PermissionScope::Any),
+                grant(
+                    "supervisor",
+                    permissions::USERS_MANAGE,
+                    PermissionScope::Company,
+                ),
+            ])
+        });
+
+        let company_repo = Arc::new(self.company_repo);
+        let access_control = Arc::new(AccessControlService::new(
+            company_repo.clone(),
+            Arc::new(MockTeamRepository::new()),
+            Arc::new(PolicyService::new(Arc::new(permission_repo), 30)),
+        ));
+
+        PasswordPolicyService::new(
+            Arc::new(self.policy_repo),
+            Arc::new(self.history_repo),
+            company_repo,
+            Arc::new(self.breached_passwords),
+            Arc::new(self.encoder),
+            access_control,
+            self.server_policy,
+        )
+    }
+}
+
+fn rules(min_length: u16, history_size: u16) -> PasswordPolicy {
+    PasswordPolicy {
+        min_length,
+        require_uppercase: false,
+        require_lowercase: false,
+        require_digit: false,
+        require_symbol: false,
+        history_size,
+    }
+}
+
+fn company_policy(company_id: Uuid, policy: PasswordPolicy) -> CompanyPasswordPolicy {
+    CompanyPasswordPolicy {
+        company_id,
+        policy,
+        created_at: Utc::now() - Duration::days(1),
+        updated_at: Utc::now() - Duration::days(1),
+    }
+}
+
+#[tokio::test]
+async fn test_company_policy_only_tightens_server_policy() {
+    let company_id = Uuid::new_v4();
+    let mut mocks = Mocks::new();
+    mocks.server_policy = PasswordPolicy {
+        require_digit: true,
+        ..rules(10, 2)
+    };
+    mocks
+        .policy_repo
+        .expect_get_by_id()
+        .with(eq(company_id))
+        .returning(move |id| {
+            Ok(Some(company_policy(
+                id,
+                PasswordPolicy {
+                    require_symbol: true,
+                    ..rules(8, 5)
+                },
+            )))
+        });
+
+    let policy = mocks
+        .into_service()
+        .effective_policy(Some(company_id))
+        .await
+        .unwrap();
+
+    assert_eq!(policy.min_length, 10);
+    assert_eq!(policy.history_size, 5);
+    assert!(policy.require_digit);
+    assert!(policy.require_symbol);
+}
+
+#[tokio::test]
+async fn test_validate_lists_every_broken_rule() {
+    let mut mocks = Mocks::new();
+    mocks.server_policy = PasswordPolicy {
+        require_uppercase: true,
+        require_digit: true,
+        ..rules(12, 0)
+    };
+
+    let result = mocks.into_service().validate(None, "short").await;
+
+    let Err(AppError::ValidationError(message)) = result else {
+        panic!("expected a validation error");
+    };
+    assert!(message.contains("at least 12 characters"));
+    assert!(message.contains("uppercase letter"));
+    assert!(message.contains("digit"));
+}
+
+#[tokio::test]
+async fn test_validate_rejects_breached_password() {
+    let result = Mocks::new()
+        .into_service()
+        .validate(None, "password123")
+        .await;
+
+    assert!(
+        matches!(result, Err(AppError::ValidationError(message)) if message.contains("breached"))
+    );
+}
+
+#[tokio::test]
+async fn test_validate_change_rejects_recent_password() {
+    let user = create_user("user", 10, None);
+    let user_id = user.id;
+
+    let mut mocks = Mocks::new();
+    mocks.server_policy = rules(8, 3);
+    mocks
+        .history_repo
+        .expect_get_recent()
+        .with(eq(user_id), eq(3))
+        .returning(|_, _| Ok(vec!["previous_hash".to_string()]));
+    mocks
+        .encoder
+        .expect_verify()
+        .returning(|password, hash| Ok(password == "reused-password" && hash == "previous_hash"));
+
+    let result = mocks
+        .into_service()
+        .validate_change(&user, "reused-password")
+        .await;
+
+    assert!(
+        matches!(result, Err(AppError::ValidationError(message)) if message.contains("last 3"))
+    );
+}
+
+#[tokio::test]
+async fn test_validate_change_accepts_new_password() {
+    let user = create_user("user", 10, None);
+
+    let mut mocks = Mocks::new();
+    mocks.server_policy = rules(8, 3);
+    mocks
+        .history_repo
+        .expect_get_recent()
+        .returning(|_, _| Ok(vec!["previous_hash".to_string()]));
+    mocks.encoder.expect_verify().returning(|_, _| Ok(false));
+
+    let result = mocks
+        .into_service()
+        .validate_change(&user, "brand-new-password")
+        .await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_remember_keeps_history_size_hashes() {
+    let user = create_user("user", 10, None);
+    let user_id = user.id;
+
+    let mut mocks = Mocks::new();
+    mocks.server_policy = rules(8, 4);
+    mocks
+        .history_repo
+        .expect_add()
+        .with(eq(user_id), eq("hash"))
+        .times(1)
+        .returning(|_, _| Ok(()));
+    mocks
+        .history_repo
+        .expect_prune()
+        .with(eq(user_id), eq(4))
+        .times(1)
+        .returning(|_, _| Ok(1));
+
+    mocks.into_service().remember(&user).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_remember_without_history_only_prunes() {
+    let user = create_user("user", 10, None);
+
+    let mut mocks = Mocks::new();
+    mocks.history_repo.expect_add().never();
+    mocks
+        .history_repo
+        .expect_prune()
+        .with(always(), eq(0))
+        .times(1)
+        .returning(|_, _| Ok(2));
+
+    mocks.into_service().remember(&user).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_supervisor_sets_policy_of_own_company() {
+    let company = create_company();
+    let company_id = company.id;
+    let supervisor = create_user("supervisor", 50, Some(company.clone()));
+
+    let mut mocks = Mocks::new();
+    mocks
+        .company_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(company.clone())));
+    mocks
+        .policy_repo
+        .expect_get_by_id()
+        .returning(move |id| Ok(Some(company_policy(id, rules(8, 0)))));
+    mocks
+        .policy_repo
+        .expect_update()
+        .withf(move |policy| {
+            policy.company_id == company_id
+                && policy.policy.min_length == 14
+                && policy.created_at < policy.updated_at
+        })
+        .times(1)
+        .returning(Ok);
+    mocks.policy_repo.expect_create().never();
+
+    let policy = mocks
+        .into_service()
+        .set_company_policy(&supervisor, company_id, rules(14, 5))
+        .await
+        .unwrap();
+
+    assert_eq!(policy.policy.history_size, 5);
+}
+
+#[tokio::test]
+async fn test_supervisor_cannot_set_policy_of_other_company() {
+    let supervisor = create_user("supervisor", 50, Some(create_company()));
+
+    let mut mocks = Mocks::new();
+    mocks.policy_repo.expect_update().never();
+    mocks.policy_repo.expect_create().never();
+
+    let result = mocks
+        .into_service()
+        .set_company_policy(&supervisor, Uuid::new_v4(), rules(14, 5))
+        .await;
+
+    assert!(matches!(result, Err(AppError::Forbidden)));
+}
+
+#[tokio::test]
+async fn test_delete_missing_policy_is_not_found() {
+    let admin = create_user("admin", 100, None);
+
+    let mut mocks = Mocks::new();
+    mocks.policy_repo.expect_get_by_id().returning(|_| Ok(None));
+    mocks.policy_repo.expect_delete().never();
+
+    let result = mocks
+        .into_service()
+        .delete_company_policy(&admin, Uuid::new_v4())
+        .await;
+
+    assert!(matches!(result, Err(AppError::NotFound(_))));
+}
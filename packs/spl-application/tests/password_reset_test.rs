@@ -0,0 +1,401 @@
+mod common;
+
+use chrono::{Duration, Utc};
+use common::create_user;
+use common::mocks::{
+    MockBreachedPasswordList, MockCompanyPasswordPolicyRepository, MockCompanyRepository,
+    MockMailer, MockOpaqueTokenGenerator, MockPasswordEncoder, MockPasswordHistoryRepository,
+    MockPasswordResetTokenRepository, MockPermissionRepository, MockSessionRepository,
+    MockTeamRepository, MockUserCache, MockUserRepository,
+};
+use mockall::predicate::*;
+use spl_application::dtos::auth::{ForgotPasswordDto, ResetPasswordDto};
+use spl_application::services::access_control::AccessControlService;
+use spl_application::services::password_policy::PasswordPolicyService;
+use spl_application::services::password_reset::PasswordResetService;
+use spl_application::services::policy::PolicyService;
+use spl_domain::entities::auth::{PasswordPolicy, PasswordResetToken};
+use spl_domain::entities::user::User;
+use spl_shared::error::AppError;
+use std::sync::Arc;
+use uuid::Uuid;
+
+struct Mocks {
+    user_repo: MockUserRepository,
+    reset_token_repo: MockPasswordResetTokenRepository,
+    session_repo: MockSessionRepository,
+    encoder: MockPasswordEncoder,
+    opaque: MockOpaqueTokenGenerator,
+    mailer: MockMailer,
+    user_cache: MockUserCache,
+    history_repo: MockPasswordHistoryRepository,
+    breached_passwords: MockBreachedPasswordList,
+}
+
+impl Mocks {
+    fn new() -> Self {
+        let mut opaque = MockOpaqueTokenGenerator::new();
+        opaque
+            .expect_generate()
+            .returning(|| "reset_token".to_string());
+        opaque
+            .expect_hash()
+            .returning(|token| format!("hash_{}", token));
+
+        Self {
+            user_repo: MockUserRepository::new(),
+            reset_token_repo: MockPasswordResetTokenRepository::new(),
+            session_repo: MockSessionRepository::new(),
+            encoder: MockPasswordEncoder::new(),
+            opaque,
+            mailer: MockMailer::new(),
+            user_cache: MockUserCache::new(),
+            history_repo: MockPasswordHistoryRepository::new(),
+            breached_passwords: MockBreachedPasswordList::new(),
+        }
+    }
+
+    fn into_service(self) -> PasswordResetService {
+        let company_repo = Arc::new(MockCompanyRepository::new());
+        let access_control = Arc::new(AccessControlService::new(
+            company_repo.clone(),
+            Arc::new(MockTeamRepository::new()),
+            Arc::new(PolicyService::new(
+                Arc::new(MockPermissionRepository::new()),
+                30,
+            )),
+        ));
+        let password_policy = Arc::new(PasswordPolicyService::new(
+            Arc::new(MockCompanyPasswordPolicyRepository::new()),
+            Arc::new(self.history_repo),
+            company_repo,
+            Arc::new(self.breached_passwords),
+            Arc::new(MockPasswordEncoder::new()),
+            access_control,
+            PasswordPolicy {
+                min_length: 8,
+                require_uppercase: false,
+                require_lowercase: false,
+                require_digit: false,
+                require_symbol: false,
+                history_size: 0,
+            },
+        ));
+
+        PasswordResetService::new(
+            Arc::new(self.user_repo),
+            Arc::new(self.reset_token_repo),
+            Arc::new(self.session_repo),
+            Arc::new(self.encoder),
+            Arc::new(self.opaque),
+            Arc::new(self.mailer),
+            password_policy,
+            Arc::new(self.user_cache),
+            Some("https://app.example.com/".to_string()),
+            30,
+        )
+    }
+}
+
+fn create_user_with_email(id: Uuid, email: Option<&str>) -> User {
+    User {
+        id,
+        email: email.map(str::to_string),
+        email_verified_at: email.map(|_| Utc::now()),
+        ..create_user("user", 1, None)
+    }
+}
+
+fn create_token(user_id: Uuid, expires_in_minutes: i64) -> PasswordResetToken {
+    PasswordResetToken {
+        id: Uuid::new_v4(),
+        user_id,
+        token_hash: "hash_reset_token".to_string(),
+        expires_at: Utc::now() + Duration::minutes(expires_in_minutes),
+        used_at: None,
+        created_at: Utc::now(),
+    }
+}
+
+fn forgot_dto() -> ForgotPasswordDto {
+    ForgotPasswordDto {
+        username: Some("user".to_string()),
+        email: None,
+        company_id: None,
+    }
+}
+
+#[tokio::test]
+async fn test_request_reset_sends_email_with_link() {
+    let mut mocks = Mocks::new();
+    let user_id = Uuid::new_v4();
+    let user = create_user_with_email(user_id, Some("test@example.com"));
+
+    mocks
+        .user_repo
+        .expect_get_by_username_or_email_and_company()
+        .times(1)
+        .returning(move |_, _, _| Ok(Some(user.clone())));
+
+    mocks
+        .reset_token_repo
+        .expect_invalidate_by_user_id()
+        .with(eq(user_id))
+        .times(1)
+        .returning(|_| Ok(1));
+
+    mocks
+        .reset_token_repo
+        .expect_create()
+        .withf(move |token| {
+            token.user_id == user_id
+                && token.token_hash == "hash_reset_token"
+                && token.used_at.is_none()
+                && token.expires_at > Utc::now() + Duration::minutes(29)
+        })
+        .times(1)
+        .returning(Ok);
+
+    mocks
+        .mailer
+        .expect_send()
+        .withf(|message| {
+            message.to == "test@example.com"
+                && message
+                    .body
+                    .contains("https://app.example.com/reset-password?token=reset_token")
+        })
+        .times(1)
+        .returning(|_| Ok(()));
+
+    let result = mocks.into_service().request_reset(forgot_dto()).await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_request_reset_unknown_user_is_silent() {
+    let mut mocks = Mocks::new();
+
+    mocks
+        .user_repo
+        .expect_get_by_username_or_email_and_company()
+        .times(1)
+        .returning(|_, _, _| Ok(None));
+
+    mocks.reset_token_repo.expect_create().never();
+    mocks.mailer.expect_send().never();
+
+    let result = mocks.into_service().request_reset(forgot_dto()).await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_request_reset_user_without_email_is_silent() {
+    let mut mocks = Mocks::new();
+    let user = create_user_with_email(Uuid::new_v4(), None);
+
+    mocks
+        .user_repo
+        .expect_get_by_username_or_email_and_company()
+        .times(1)
+        .returning(move |_, _, _| Ok(Some(user.clone())));
+
+    mocks.reset_token_repo.expect_create().never();
+    mocks.mailer.expect_send().never();
+
+    let result = mocks.into_service().request_reset(forgot_dto()).await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_request_reset_unverified_email_is_silent() {
+    let mut mocks = Mocks::new();
+    let mut user = create_user_with_email(Uuid::new_v4(), Some("claimed@example.com"));
+    user.email_verified_at = None;
+
+    mocks
+        .user_repo
+        .expect_get_by_username_or_email_and_company()
+        .times(1)
+        .returning(move |_, _, _| Ok(Some(user.clone())));
+
+    mocks.reset_token_repo.expect_invalidate_by_user_id().never();
+    mocks.reset_token_repo.expect_create().never();
+    mocks.mailer.expect_send().never();
+
+    let result = mocks
+        .into_service()
+        .request_reset(ForgotPasswordDto {
+            username: None,
+            email: Some("claimed@example.com".to_string()),
+            company_id: None,
+        })
+        .await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_reset_password_updates_hash_and_revokes_sessions() {
+    let mut mocks = Mocks::new();
+    let user_id = Uuid::new_v4();
+    let user = create_user_with_email(user_id, Some("test@example.com"));
+    let token = create_token(user_id, 10);
+    let token_id = token.id;
+
+    mocks
+        .reset_token_repo
+        .expect_get_by_token_hash()
+        .with(eq("hash_reset_token"))
+        .times(1)
+        .returning(move |_| Ok(Some(token.clone())));
+
+    mocks
+        .reset_token_repo
+        .expect_mark_used()
+        .with(eq(token_id))
+        .times(1)
+        .returning(|_| Ok(true));
+
+    mocks
+        .user_repo
+        .expect_get_by_id()
+        .with(eq(user_id))
+        .times(1)
+        .returning(move |_| Ok(Some(user.clone())));
+
+    mocks
+        .encoder
+        .expect_hash()
+        .with(eq("new_password"))
+        .times(1)
+        .returning(|_| Ok("new_hash".to_string()));
+
+    mocks
+        .user_repo
+        .expect_update()
+        .withf(|user| user.password_hash == "new_hash")
+        .times(1)
+        .returning(Ok);
+
+    mocks
+        .session_repo
+        .expect_revoke_by_user_id()
+        .with(eq(user_id))
+        .times(1)
+        .returning(|_| Ok(2));
+
+    mocks
+        .user_cache
+        .expect_invalidate()
+        .with(eq(user_id))
+        .times(1)
+        .returning(|_| ());
+
+    mocks
+        .breached_passwords
+        .expect_contains()
+        .returning(|_| false);
+    mocks.history_repo.expect_prune().returning(|_, _| Ok(0));
+
+    let result = mocks
+        .into_service()
+        .reset_password(ResetPasswordDto {
+            token: "reset_token".to_string(),
+            new_password: "new_password".to_string(),
+        })
+        .await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_reset_password_rejects_expired_token() {
+    let mut mocks = Mocks::new();
+    let token = create_token(Uuid::new_v4(), -1);
+
+    mocks
+        .reset_token_repo
+        .expect_get_by_token_hash()
+        .times(1)
+        .returning(move |_| Ok(Some(token.clone())));
+
+    mocks.reset_token_repo.expect_mark_used().never();
+    mocks.user_repo.expect_update().never();
+
+    let result = mocks
+        .into_service()
+        .reset_password(ResetPasswordDto {
+            token: "reset_token".to_string(),
+            new_password: "new_password".to_string(),
+        })
+        .await;
+
+    assert!(matches!(result, Err(AppError::ValidationError(_))));
+}
+
+#[tokio::test]
+async fn test_reset_password_rejects_used_token() {
+    let mut mocks = Mocks::new();
+    let mut token = create_token(Uuid::new_v4(), 10);
+    token.used_at = Some(Utc::now());
+
+    mocks
+        .reset_token_repo
+        .expect_get_by_token_hash()
+        .times(1)
+        .returning(move |_| Ok(Some(token.clone())));
+
+    mocks.user_repo.expect_update().never();
+
+    let result = mocks
+        .into_service()
+        .reset_password(ResetPasswordDto {
+            token: "reset_token".to_string(),
+            new_password: "new_password".to_string(),
+        })
+        .await;
+
+    assert!(matches!(result, Err(AppError::ValidationError(_))));
+}
+
+#[tokio::test]
+async fn test_reset_password_rejects_breached_password_without_consuming_token() {
+    let mut mocks = Mocks::new();
+    let user_id = Uuid::new_v4();
+    let user = create_user_with_email(user_id, Some("test@example.com"));
+    let token = create_token(user_id, 10);
+
+    mocks
+        .reset_token_repo
+        .expect_get_by_token_hash()
+        .times(1)
+        .returning(move |_| Ok(Some(token.clone())));
+    mocks
+        .user_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(user.clone())));
+    mocks
+        .breached_passwords
+        .expect_contains()
+        .with(eq("password123"))
+        .returning(|_| true);
+
+    mocks.reset_token_repo.expect_mark_used().never();
+    mocks.user_repo.expect_update().never();
+
+    let result = mocks
+        .into_service()
+        .reset_password(ResetPasswordDto {
+            token: "reset_token".to_string(),
+            new_password: "password123".to_string(),
+        })
+        .await;
+
+    assert!(
+        matches!(result, Err(AppError::ValidationError(message)) if message.contains("breached"))
+    );
+}
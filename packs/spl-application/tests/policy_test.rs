@@ -0,0 +1,170 @@
+mod common;
+
+use common::mocks::MockPermissionRepository;
+use common::{create_company, create_user, grant};
+use spl_application::services::policy::{PolicyService, Resource};
+use spl_domain::entities::user::{permissions, PermissionScope, RolePermission};
+use spl_shared::error::AppError;
+use std::sync::Arc;
+
+fn create_policy(grants: Vec<RolePermission>) -> PolicyService {
+    let mut permission_repo = MockPermissionRepository::new();
+    permission_repo
+        .expect_get_grants()
+        .returning(move || Ok(grants.clone()));
+    PolicyService::new(Arc::new(permission_repo), 30)
+}
+
+#[tokio::test]
+async fn test_new_role_is_authorized_by_its_grants() {
+    let policy = create_policy(vec![
+        grant(
+            "agronomist",
+            permissions::PLOTS_READ,
+            PermissionScope::Company,
+        ),
+        grant(
+            "agronomist",
+            permissions::PREDICTIONS_READ,
+            PermissionScope::Company,
+        ),
+    ]);
+    let company = create_company();
+    let agronomist = create_user("agronomist", 30, Some(company.clone()));
+
+    assert!(policy
+        .can(
+            &agronomist,
+            permissions::PLOTS_READ,
+            Resource::Company(company.id)
+        )
+        .await
+        .unwrap());
+    assert!(!policy
+        .can(
+            &agronomist,
+            permissions::PLOTS_MANAGE,
+            Resource::Company(company.id)
+        )
+        .await
+        .unwrap());
+}
+
+#[tokio::test]
+async fn test_company_scope_is_limited_to_own_company() {
+    let policy = create_policy(vec![grant(
+        "agronomist",
+        permissions::USERS_MANAGE,
+        PermissionScope::Company,
+    )]);
+    let company = create_company();
+    let agronomist = create_user("agronomist", 30, Some(company.clone()));
+    let colleague = create_user("user", 30, Some(company));
+    let outsider = create_user("user", 30, Some(create_company()));
+
+    assert!(policy
+        .can(
+            &agronomist,
+            permissions::USERS_MANAGE,
+            Resource::User(&colleague)
+        )
+        .await
+        .unwrap());
+    assert!(!policy
+        .can(
+            &agronomist,
+            permissions::USERS_MANAGE,
+            Resource::User(&outsider)
+        )
+        .await
+        .unwrap());
+    assert!(!policy
+        .can(&agronomist, permissions::USERS_MANAGE, Resource::Global)
+        .await
+        .unwrap());
+}
+
+#[tokio::test]
+async fn test_own_scope_only_covers_the_user() {
+    let policy = create_policy(vec![grant(
+        "user",
+        permissions::PREDICTIONS_READ,
+        PermissionScope::Own,
+    )]);
+    let company = create_company();
+    let user = create_user("user", 30, Some(company.clone()));
+    let colleague = create_user("user", 30, Some(company));
+
+    assert!(policy
+        .can(&user, permissions::PREDICTIONS_READ, Resource::User(&user))
+        .await
+        .unwrap());
+    assert!(!policy
+        .can(
+            &user,
+            permissions::PREDICTIONS_READ,
+            Resource::User(&colleague)
+        )
+        .await
+        .unwrap());
+}
+
+#[tokio::test]
+async fn test_widest_scope_wins() {
+    let policy = create_policy(vec![
+        grant("auditor", permissions::USERS_READ, PermissionScope::Own),
+        grant("auditor", permissions::USERS_READ, PermissionScope::Any),
+        grant("auditor", permissions::USERS_READ, PermissionScope::Company),
+    ]);
+    let auditor = create_user("auditor", 30, None);
+
+    let scope = policy
+        .scope(&auditor, permissions::USERS_READ)
+        .await
+        .unwrap();
+
+    assert_eq!(scope, Some(PermissionScope::Any));
+    assert!(policy.is_global(&auditor.role).await.unwrap());
+}
+
+#[tokio::test]
+async fn test_ensure_forbids_without_grant() {
+    let policy = create_policy(vec![]);
+    let user = create_user("user", 30, Some(create_company()));
+
+    let result = policy
+        .ensure(&user, permissions::CATALOG_MANAGE, Resource::Global)
+        .await;
+
+    assert!(matches!(result, Err(AppError::Forbidden)));
+}
+
+#[tokio::test]
+async fn test_grants_are_cached_until_invalidated() {
+    let mut permission_repo = MockPermissionRepository::new();
+    permission_repo
+        .expect_get_grants()
+        .times(2)
+        .returning(|| Ok(vec![]));
+    let policy = PolicyService::new(Arc::new(permission_repo), 30);
+    let user = create_user("user", 30, None);
+
+    policy.scope(&user, permissions::USERS_READ).await.unwrap();
+    policy.scope(&user, permissions::USERS_READ).await.unwrap();
+    policy.invalidate();
+    policy.scope(&user, permissions::USERS_READ).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_expired_grants_are_reloaded() {
+    let mut permission_repo = MockPermissionRepository::new();
+    permission_repo
+        .expect_get_grants()
+        .times(2)
+        .returning(|| Ok(vec![]));
+    let policy = PolicyService::new(Arc::new(permission_repo), 0);
+    let user = create_user("user", 30, None);
+
+    policy.scope(&user, permissions::USERS_READ).await.unwrap();
+    policy.scope(&user, permissions::USERS_READ).await.unwrap();
+}
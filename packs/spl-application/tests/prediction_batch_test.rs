@@ -0,0 +1,510 @@
+mod common;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use chrono::Utc;
+use common::mocks::{
+    MockCompanyQuotaRepository, MockCompanyRepository, MockCompanySettingsRepository,
+    MockImageRepository, MockInferenceJobRepository, MockLabelRepository, MockMarkTypeRepository,
+    MockMembershipRepository, MockModelPredictionClient, MockPermissionRepository,
+    MockPredictionBatchItemRepository, MockPredictionBatchRepository, MockPredictionMarkRepository,
+    MockPredictionRepository, MockRecommendationRepository, MockRoleRepository, MockTeamRepository,
+    MockUsageRepository, MockUserCache, MockUserRepository,
+};
+use common::{create_company, create_user};
+use spl_application::dtos::diagnostics::UploadedFileDto;
+use spl_application::services::access_control::AccessControlService;
+use spl_application::services::company_settings::CompanySettingsService;
+use spl_application::services::diagnostics::{
+    InferenceJobService, PredictionBatchService, PredictionService,
+};
+use spl_application::services::policy::PolicyService;
+use spl_application::services::usage::UsageService;
+use spl_application::services::user::MembershipService;
+use spl_domain::entities::company::CompanySettings;
+use spl_domain::entities::diagnostics::{
+    BatchItemStatus, BatchLimits, BatchStatus, JobPolicy, PredictionBatch,
+};
+use spl_domain::entities::image::ImageFormat;
+use spl_domain::entities::usage::{QuotaEnforcement, UsageQuota};
+use spl_domain::ports::archive::{ArchiveEntry, ArchiveReader};
+use spl_domain::ports::integrations::{BlobStorageClient, IntegrationClient};
+use spl_shared::error::{AppError, Result};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use uuid::Uuid;
+
+/// Storage kept in memory, to check what a batch leaves behind
+#[derive(Default, Clone)]
+struct MemoryStorage {
+    files: Arc<Mutex<HashMap<String, Bytes>>>,
+    fail_after: Option<usize>,
+}
+
+impl MemoryStorage {
+    fn paths(&self) -> Vec<String> {
+        let mut paths: Vec<String> = self.files.lock().unwrap().keys().cloned().collect();
+        paths.sort();
+        paths
+    }
+}
+
+#[async_trait]
+impl IntegrationClient for MemoryStorage {
+    fn name(&self) -> &'static str {
+        "memory"
+    }
+
+    async fn health_check(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl BlobStorageClient for MemoryStorage {
+    async fn upload(&self, file_content: Bytes, destination: &str) -> Result<String> {
+        let mut files = self.files.lock().unwrap();
+        if self.fail_after.is_some_and(|limit| files.len() >= limit) {
+            return Err(AppError::Unknown("Storage unavailable".to_string()));
+        }
+        files.insert(destination.to_string(), file_content);
+        Ok(destination.to_string())
+    }
+
+    async fn download(&self, source: &str) -> Result<Bytes> {
+        self.files
+            .lock()
+            .unwrap()
+            .get(source)
+            .cloned()
+            .ok_or_else(|| AppError::NotFound(format!("{} not found", source)))
+    }
+
+    async fn delete(&self, path: &str) -> Result<()> {
+        self.files.lock().unwrap().remove(path);
+        Ok(())
+    }
+
+    async fn delete_directory(&self, prefix: &str) -> Result<()> {
+        self.files
+            .lock()
+            .unwrap()
+            .retain(|path, _| !path.starts_with(prefix));
+        Ok(())
+    }
+}
+
+/// Reads uploads that are exactly `b"archive"` as an archive with the given files
+struct StubArchiveReader {
+    entries: Vec<ArchiveEntry>,
+}
+
+impl ArchiveReader for StubArchiveReader {
+    fn is_archive(&self, content: &[u8]) -> bool {
+        content == b"archive"
+    }
+
+    fn extract(&self, _content: &[u8], max_size: u64) -> Result<Vec<ArchiveEntry>> {
+        let size: usize = self.entries.iter().map(|e| e.content.len()).sum();
+        if size as u64 > max_size {
+            return Err(AppError::ValidationError(
+                "Archive is too large".to_string(),
+            ));
+        }
+        Ok(self.entries.clone())
+    }
+}
+
+struct Mocks {
+    batch_repo: MockPredictionBatchRepository,
+    item_repo: MockPredictionBatchItemRepository,
+    job_repo: MockInferenceJobRepository,
+    user_repo: MockUserRepository,
+    storage: MemoryStorage,
+    archive_entries: Vec<ArchiveEntry>,
+    limits: BatchLimits,
+}
+
+impl Mocks {
+    fn new() -> Self {
+        Self {
+            batch_repo: MockPredictionBatchRepository::new(),
+            item_repo: MockPredictionBatchItemRepository::new(),
+            job_repo: MockInferenceJobRepository::new(),
+            user_repo: MockUserRepository::new(),
+            storage: MemoryStorage::default(),
+            archive_entries: vec![],
+            limits: BatchLimits {
+                max_files: 10,
+                max_extracted_bytes: 1024,
+            },
+        }
+    }
+
+    fn into_service(self) -> Arc<PredictionBatchService> {
+        let company_repo: Arc<MockCompanyRepository> = Arc::new(MockCompanyRepository::new());
+        let user_repo = Arc::new(self.user_repo);
+        let storage: Arc<dyn BlobStorageClient> = Arc::new(self.storage);
+
+        let access_control = Arc::new(AccessControlService::new(
+            company_repo.clone(),
+            Arc::new(MockTeamRepository::new()),
+            Arc::new(PolicyService::new(
+                Arc::new(MockPermissionRepository::new()),
+                30,
+            )),
+        ));
+
+        let company_settings = Arc::new(CompanySettingsService::new(
+            Arc::new(MockCompanySettingsRepository::new()),
+            company_repo.clone(),
+            access_control.clone(),
+            Arc::new(MockUserCache::new()),
+            CompanySettings {
+                timezone: "UTC".to_string(),
+                default_language: "es".to_string(),
+                data_retention_days: 0,
+                two_factor_required_level: None,
+                default_page_size: 16,
+                allowed_upload_formats: ImageFormat::ALL.to_vec(),
+                alert_severity_threshold: 50.0,
+                alert_confidence_threshold: 0.8,
+            },
+        ));
+
+        let usage = Arc::new(UsageService::new(
+            Arc::new(MockUsageRepository::new()),
+            Arc::new(MockCompanyQuotaRepository::new()),
+            company_repo,
+            access_control.clone(),
+            UsageQuota {
+                predictions_per_month: None,
+                predictions_per_user_per_month: None,
+                stored_bytes_per_month: None,
+                warning_ratio: 0.8,
+                enforcement: QuotaEnforcement::Hard,
+            },
+        ));
+
+        let prediction_service = Arc::new(PredictionService::new(
+            Arc::new(MockPredictionRepository::new()),
+            user_repo.clone(),
+            Arc::new(MockImageRepository::new()),
+            Arc::new(MockLabelRepository::new()),
+            Arc::new(MockPredictionMarkRepository::new()),
+            Arc::new(MockMarkTypeRepository::new()),
+            Arc::new(MockRecommendationRepository::new()),
+            storage.clone(),
+            Arc::new(MockModelPredictionClient::new()),
+            access_control.clone(),
+            company_settings,
+            usage,
+        ));
+
+        let batch_repo = Arc::new(self.batch_repo);
+        let item_repo = Arc::new(self.item_repo);
+
+        let job_service = Arc::new(InferenceJobService::new(
+            Arc::new(self.job_repo),
+            batch_repo.clone(),
+            item_repo.clone(),
+            user_repo.clone(),
+            prediction_service,
+            Arc::new(MembershipService::new(
+                Arc::new(MockMembershipRepository::new()),
+                user_repo,
+                Arc::new(MockRoleRepository::new()),
+                access_control,
+            )),
+            storage.clone(),
+            JobPolicy {
+                max_attempts: 3,
+                retry_delay_seconds: 30,
+                lease_seconds: 300,
+            },
+        ));
+
+        Arc::new(PredictionBatchService::new(
+            batch_repo,
+            item_repo,
+            job_service,
+            storage,
+            Arc::new(StubArchiveReader {
+                entries: self.archive_entries,
+            }),
+            self.limits,
+        ))
+    }
+}
+
+fn create_batch(user_id: Uuid, items_total: u64) -> PredictionBatch {
+    PredictionBatch {
+        id: Uuid::new_v4(),
+        user_id,
+        company_id: None,
+        status: BatchStatus::Processing,
+        items_total,
+        items_completed: 0,
+        items_failed: 0,
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+        completed_at: None,
+    }
+}
+
+fn file(filename: &str, content: &[u8]) -> UploadedFileDto {
+    UploadedFileDto {
+        filename: Some(filename.to_string()),
+        content: content.to_vec(),
+    }
+}
+
+#[tokio::test]
+async fn test_create_stores_images_and_archive_contents_in_order() {
+    let user = create_user("user", 10, None);
+    let mut mocks = Mocks::new();
+    mocks.archive_entries = vec![
+        ArchiveEntry {
+            path: "field/a.jpg".to_string(),
+            content: b"a".to_vec(),
+        },
+        ArchiveEntry {
+            path: "field/b.jpg".to_string(),
+            content: b"b".to_vec(),
+        },
+    ];
+    let storage = mocks.storage.clone();
+
+    mocks
+        .batch_repo
+        .expect_create()
+        .withf(|batch| batch.items_total == 3 && batch.status == BatchStatus::Processing)
+        .times(1)
+        .returning(Ok);
+
+    let created = Arc::new(Mutex::new(Vec::new()));
+    let recorded = created.clone();
+    mocks
+        .item_repo
+        .expect_create_many()
+        .times(1)
+        .returning(move |items| {
+            *recorded.lock().unwrap() = items;
+            Ok(())
+        });
+
+    let queued = Arc::new(Mutex::new(Vec::new()));
+    let recorded = queued.clone();
+    mocks
+        .job_repo
+        .expect_create_many()
+        .times(1)
+        .returning(move |jobs| {
+            *recorded.lock().unwrap() = jobs;
+            Ok(())
+        });
+
+    let service = mocks.into_service();
+    let batch = service
+        .create(
+            &user,
+            vec![file("first.jpg", b"1"), file("photos.zip", b"archive")],
+        )
+        .await
+        .unwrap();
+
+    let items = created.lock().unwrap().clone();
+    let filenames: Vec<&str> = items.iter().map(|i| i.filename.as_str()).collect();
+    assert_eq!(filenames, ["first.jpg", "field/a.jpg", "field/b.jpg"]);
+    assert!(items.iter().all(|i| i.status == BatchItemStatus::Pending));
+
+    // Each image is predicted by a job of its own, from the stored upload
+    let jobs = queued.lock().unwrap().clone();
+    assert_eq!(jobs.len(), 3);
+    for (job, item) in jobs.iter().zip(&items) {
+        assert_eq!(job.user_id, user.id);
+        assert_eq!(job.batch_item_id, Some(item.id));
+        assert_eq!(job.upload_path, item.upload_path);
+    }
+
+    let prefix = format!("{}/batches/{}/", user.id, batch.id);
+    assert_eq!(
+        storage.paths(),
+        vec![
+            format!("{}0", prefix),
+            format!("{}1", prefix),
+            format!("{}2", prefix)
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_create_rejects_more_images_than_allowed() {
+    let mut mocks = Mocks::new();
+    mocks.limits.max_files = 2;
+
+    let service = mocks.into_service();
+    let result = service
+        .create(
+            &create_user("user", 10, None),
+            vec![
+                file("a.jpg", b"a"),
+                file("b.jpg", b"b"),
+                file("c.jpg", b"c"),
+            ],
+        )
+        .await;
+
+    assert!(matches!(result, Err(AppError::ValidationError(msg)) if msg.contains("more than 2")));
+}
+
+#[tokio::test]
+async fn test_create_rejects_archives_too_large_once_extracted() {
+    let mut mocks = Mocks::new();
+    mocks.limits.max_extracted_bytes = 1;
+    mocks.archive_entries = vec![ArchiveEntry {
+        path: "big.jpg".to_string(),
+        content: b"too large".to_vec(),
+    }];
+
+    let service = mocks.into_service();
+    let result = service
+        .create(
+            &create_user("user", 10, None),
+            vec![file("photos.zip", b"archive")],
+        )
+        .await;
+
+    assert!(matches!(result, Err(AppError::ValidationError(_))));
+}
+
+#[tokio::test]
+async fn test_create_rejects_empty_archives() {
+    let service = Mocks::new().into_service();
+
+    let result = service
+        .create(
+            &create_user("user", 10, None),
+            vec![file("photos.zip", b"archive")],
+        )
+        .await;
+
+    assert!(matches!(result, Err(AppError::ValidationError(msg)) if msg == "No files provided"));
+}
+
+#[tokio::test]
+async fn test_create_discards_batch_when_storing_fails() {
+    let user = create_user("user", 10, None);
+    let mut mocks = Mocks::new();
+    mocks.storage.fail_after = Some(1);
+    let storage = mocks.storage.clone();
+
+    mocks.batch_repo.expect_create().returning(Ok);
+    mocks.batch_repo.expect_delete().times(1).returning(|id| {
+        let mut batch = create_batch(Uuid::new_v4(), 0);
+        batch.id = id;
+        Ok(batch)
+    });
+    mocks.item_repo.expect_create_many().never();
+    mocks.job_repo.expect_create_many().never();
+
+    let service = mocks.into_service();
+    let result = service
+        .create(&user, vec![file("a.jpg", b"a"), file("b.jpg", b"b")])
+        .await;
+
+    assert!(result.is_err());
+    assert!(storage.paths().is_empty());
+}
+
+#[tokio::test]
+async fn test_create_discards_batch_when_queueing_fails() {
+    let mut mocks = Mocks::new();
+    let storage = mocks.storage.clone();
+
+    mocks.batch_repo.expect_create().returning(Ok);
+    mocks.batch_repo.expect_delete().times(1).returning(|id| {
+        let mut batch = create_batch(Uuid::new_v4(), 0);
+        batch.id = id;
+        Ok(batch)
+    });
+    mocks.item_repo.expect_create_many().returning(|_| Ok(()));
+    mocks
+        .job_repo
+        .expect_create_many()
+        .returning(|_| Err(AppError::DatabaseError("connection lost".to_string())));
+
+    let service = mocks.into_service();
+    let result = service
+        .create(&create_user("user", 10, None), vec![file("a.jpg", b"a")])
+        .await;
+
+    assert!(matches!(result, Err(AppError::DatabaseError(_))));
+    assert!(storage.paths().is_empty());
+}
+
+#[tokio::test]
+async fn test_batch_of_another_user_is_not_found() {
+    let batch = create_batch(Uuid::new_v4(), 1);
+    let id = batch.id;
+
+    let mut mocks = Mocks::new();
+    mocks
+        .batch_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(batch.clone())));
+    mocks.item_repo.expect_get_by_batch_id().never();
+
+    let service = mocks.into_service();
+    let result = service
+        .get_detailed(&create_user("user", 10, None), id)
+        .await;
+
+    assert!(matches!(result, Err(AppError::NotFound(_))));
+}
+
+#[tokio::test]
+async fn test_batch_made_in_another_company_is_not_found() {
+    let user = create_user("user", 10, Some(create_company()));
+    let batch = create_batch(user.id, 1);
+    let id = batch.id;
+
+    let mut mocks = Mocks::new();
+    mocks
+        .batch_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(batch.clone())));
+    mocks.item_repo.expect_get_by_batch_id().never();
+
+    let service = mocks.into_service();
+    let result = service.get_detailed(&user, id).await;
+
+    assert!(matches!(result, Err(AppError::NotFound(_))));
+}
+
+#[tokio::test]
+async fn test_get_by_user_lists_batches_of_the_company_acted_in() {
+    let company = create_company();
+    let user = create_user("user", 10, Some(company.clone()));
+    let own = PredictionBatch {
+        company_id: Some(company.id),
+        ..create_batch(user.id, 1)
+    };
+    let other = PredictionBatch {
+        company_id: Some(Uuid::new_v4()),
+        ..create_batch(user.id, 1)
+    };
+    let own_id = own.id;
+
+    let mut mocks = Mocks::new();
+    mocks
+        .batch_repo
+        .expect_get_by_user_id()
+        .returning(move |_| Ok(vec![own.clone(), other.clone()]));
+
+    let service = mocks.into_service();
+    let batches = service.get_by_user(&user).await.unwrap();
+
+    assert_eq!(batches.len(), 1);
+    assert_eq!(batches[0].id, own_id);
+}
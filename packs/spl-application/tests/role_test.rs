@@ -0,0 +1,382 @@
+mod common;
+
+use chrono::{Duration, Utc};
+use common::mocks::{
+    MockInvitationRepository, MockMembershipRepository, MockPermissionRepository,
+    MockRoleRepository, MockUserCache, MockUserRepository,
+};
+use common::{create_company, create_user};
+use mockall::predicate::*;
+use spl_application::dtos::user::{CreateRoleDto, UpdateRoleDto};
+use spl_application::services::policy::PolicyService;
+use spl_application::services::user::role::RoleService;
+use spl_domain::entities::user::{
+    permissions, PermissionGrant, PermissionScope, Role, RolePermission, User,
+};
+use spl_shared::error::AppError;
+use std::sync::Arc;
+
+const ADMIN_ROLE_ID: i32 = 3;
+const AGRONOMIST_ROLE_ID: i32 = 4;
+
+struct Mocks {
+    role_repo: MockRoleRepository,
+    permission_repo: MockPermissionRepository,
+    user_repo: MockUserRepository,
+    invitation_repo: MockInvitationRepository,
+    membership_repo: MockMembershipRepository,
+    policy_repo: MockPermissionRepository,
+    user_cache: MockUserCache,
+}
+
+impl Mocks {
+    fn new() -> Self {
+        let mut policy_repo = MockPermissionRepository::new();
+        policy_repo
+            .expect_get_grants()
+            .returning(|| Ok(admin_grants()));
+
+        Self {
+            role_repo: MockRoleRepository::new(),
+            permission_repo: MockPermissionRepository::new(),
+            user_repo: MockUserRepository::new(),
+            invitation_repo: MockInvitationRepository::new(),
+            membership_repo: MockMembershipRepository::new(),
+            policy_repo,
+            user_cache: MockUserCache::new(),
+        }
+    }
+
+    fn into_service(self) -> RoleService {
+        RoleService::new(
+            Arc::new(self.role_repo),
+            Arc::new(self.permission_repo),
+            Arc::new(self.user_repo),
+            Arc::new(self.invitation_repo),
+            Arc::new(self.membership_repo),
+            Arc::new(PolicyService::new(Arc::new(self.policy_repo), 30)),
+            Arc::new(self.user_cache),
+        )
+    }
+}
+
+fn admin_grants() -> Vec<RolePermission> {
+    vec![RolePermission {
+        role_id: ADMIN_ROLE_ID,
+        role: "admin".to_string(),
+        permission: permissions::ROLES_MANAGE.to_string(),
+        scope: PermissionScope::Any,
+    }]
+}
+
+fn create_role(id: i32, name: &str, level: i16) -> Role {
+    Role {
+        id,
+        name: name.to_string(),
+        level,
+        created_at: Utc::now() - Duration::days(1),
+        updated_at: Utc::now() - Duration::days(1),
+    }
+}
+
+fn create_admin() -> User {
+    User {
+        role: create_role(ADMIN_ROLE_ID, "admin", 100),
+        ..create_user("admin", 100, Some(create_company()))
+    }
+}
+
+fn grant(permission: &str, scope: PermissionScope) -> PermissionGrant {
+    PermissionGrant {
+        permission: permission.to_string(),
+        scope,
+    }
+}
+
+#[tokio::test]
+async fn test_create_role_with_grants() {
+    let mut mocks = Mocks::new();
+    mocks
+        .role_repo
+        .expect_get_by_name()
+        .with(eq("agronomist"))
+        .returning(|_| Ok(None));
+    mocks.role_repo.expect_create().returning(|role| {
+        Ok(Role {
+            id: AGRONOMIST_ROLE_ID,
+            ..role
+        })
+    });
+    mocks
+        .permission_repo
+        .expect_set_role_grants()
+        .withf(|role_id, grants| *role_id == AGRONOMIST_ROLE_ID && grants.len() == 1)
+        .times(1)
+        .returning(|_, _| Ok(()));
+    let service = mocks.into_service();
+
+    let role = service
+        .create(
+            &create_admin(),
+            CreateRoleDto {
+                name: "agronomist".to_string(),
+                level: 20,
+                permissions: vec![grant(permissions::PLOTS_READ, PermissionScope::Company)],
+            },
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(role.role.id, AGRONOMIST_ROLE_ID);
+    assert_eq!(role.permissions.len(), 1);
+}
+
+#[tokio::test]
+async fn test_create_role_rejects_unknown_permission() {
+    let service = Mocks::new().into_service();
+
+    let result = service
+        .create(
+            &create_admin(),
+            CreateRoleDto {
+                name: "agronomist".to_string(),
+                level: 20,
+                permissions: vec![grant("plots:burn", PermissionScope::Any)],
+            },
+        )
+        .await;
+
+    assert!(matches!(result, Err(AppError::ValidationError(_))));
+}
+
+#[tokio::test]
+async fn test_create_role_conflicts_with_existing_name() {
+    let mut mocks = Mocks::new();
+    mocks
+        .role_repo
+        .expect_get_by_name()
+        .returning(|_| Ok(Some(create_role(1, "user", 10))));
+    let service = mocks.into_service();
+
+    let result = service
+        .create(
+            &create_admin(),
+            CreateRoleDto {
+                name: "user".to_string(),
+                level: 10,
+                permissions: vec![],
+            },
+        )
+        .await;
+
+    assert!(matches!(result, Err(AppError::Conflict(_))));
+}
+
+#[tokio::test]
+async fn test_create_role_above_requester_level_is_forbidden() {
+    let service = Mocks::new().into_service();
+
+    let result = service
+        .create(
+            &create_admin(),
+            CreateRoleDto {
+                name: "root".to_string(),
+                level: 200,
+                permissions: vec![],
+            },
+        )
+        .await;
+
+    assert!(matches!(result, Err(AppError::Forbidden)));
+}
+
+#[tokio::test]
+async fn test_manage_roles_without_grant_is_forbidden() {
+    let service = Mocks::new().into_service();
+    let supervisor = create_user("supervisor", 50, Some(create_company()));
+
+    let result = service.get_all_detailed(&supervisor).await;
+
+    assert!(matches!(result, Err(AppError::Forbidden)));
+}
+
+#[tokio::test]
+async fn test_delete_role_still_assigned_to_users() {
+    let mut mocks = Mocks::new();
+    mocks
+        .role_repo
+        .expect_get_by_id()
+        .returning(|id| Ok(Some(create_role(id, "agronomist", 20))));
+    mocks
+        .user_repo
+        .expect_count_by_role_id()
+        .with(eq(AGRONOMIST_ROLE_ID))
+        .returning(|_| Ok(2));
+    mocks.role_repo.expect_delete().never();
+    let service = mocks.into_service();
+
+    let result = service.delete(&create_admin(), AGRONOMIST_ROLE_ID).await;
+
+    assert!(matches!(result, Err(AppError::Conflict(_))));
+}
+
+#[tokio::test]
+async fn test_delete_role_still_used_by_invitations() {
+    let mut mocks = Mocks::new();
+    mocks
+        .role_repo
+        .expect_get_by_id()
+        .returning(|id| Ok(Some(create_role(id, "agronomist", 20))));
+    mocks
+        .user_repo
+        .expect_count_by_role_id()
+        .returning(|_| Ok(0));
+    mocks
+        .invitation_repo
+        .expect_count_by_role_id()
+        .returning(|_| Ok(1));
+    mocks.role_repo.expect_delete().never();
+    let service = mocks.into_service();
+
+    let result = service.delete(&create_admin(), AGRONOMIST_ROLE_ID).await;
+
+    assert!(matches!(result, Err(AppError::Conflict(_))));
+}
+
+#[tokio::test]
+async fn test_delete_role_still_used_by_memberships() {
+    let mut mocks = Mocks::new();
+    mocks
+        .role_repo
+        .expect_get_by_id()
+        .returning(|id| Ok(Some(create_role(id, "agronomist", 20))));
+    mocks
+        .user_repo
+        .expect_count_by_role_id()
+        .returning(|_| Ok(0));
+    mocks
+        .invitation_repo
+        .expect_count_by_role_id()
+        .returning(|_| Ok(0));
+    mocks
+        .membership_repo
+        .expect_count_by_role_id()
+        .returning(|_| Ok(2));
+    mocks.role_repo.expect_delete().never();
+    let service = mocks.into_service();
+
+    let result = service.delete(&create_admin(), AGRONOMIST_ROLE_ID).await;
+
+    assert!(matches!(result, Err(AppError::Conflict(message)) if message.contains("2 memberships")));
+}
+
+#[tokio::test]
+async fn test_update_rejects_removing_last_roles_manager() {
+    let mut mocks = Mocks::new();
+    mocks
+        .role_repo
+        .expect_get_by_id()
+        .returning(|id| Ok(Some(create_role(id, "admin", 100))));
+    mocks
+        .permission_repo
+        .expect_get_grants()
+        .returning(|| Ok(admin_grants()));
+    mocks.permission_repo.expect_set_role_grants().never();
+    let service = mocks.into_service();
+
+    let result = service
+        .update(
+            &create_admin(),
+            ADMIN_ROLE_ID,
+            UpdateRoleDto {
+                name: None,
+                level: None,
+                permissions: Some(vec![grant(permissions::USERS_READ, PermissionScope::Any)]),
+            },
+        )
+        .await;
+
+    assert!(matches!(result, Err(AppError::Conflict(_))));
+}
+
+#[tokio::test]
+async fn test_update_role_refreshes_caches() {
+    let mut mocks = Mocks::new();
+    let mut policy_repo = MockPermissionRepository::new();
+    policy_repo
+        .expect_get_grants()
+        .times(2)
+        .returning(|| Ok(admin_grants()));
+    mocks.policy_repo = policy_repo;
+    mocks
+        .role_repo
+        .expect_get_by_id()
+        .returning(|id| Ok(Some(create_role(id, "agronomist", 20))));
+    mocks.role_repo.expect_update().returning(Ok);
+    mocks
+        .permission_repo
+        .expect_set_role_grants()
+        .times(1)
+        .returning(|_, _| Ok(()));
+    mocks
+        .permission_repo
+        .expect_get_grants()
+        .returning(|| Ok(admin_grants()));
+    mocks
+        .permission_repo
+        .expect_get_by_role_id()
+        .returning(|_| Ok(vec![]));
+    mocks.role_repo.expect_get_all().returning(|| Ok(vec![]));
+    mocks.user_cache.expect_clear().times(1).returning(|| ());
+    let service = mocks.into_service();
+    let admin = create_admin();
+
+    service
+        .update(
+            &admin,
+            AGRONOMIST_ROLE_ID,
+            UpdateRoleDto {
+                name: None,
+                level: Some(25),
+                permissions: Some(vec![]),
+            },
+        )
+        .await
+        .unwrap();
+    service.get_all_detailed(&admin).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_delete_unused_role_clears_user_cache() {
+    let mut mocks = Mocks::new();
+    mocks
+        .role_repo
+        .expect_get_by_id()
+        .returning(|id| Ok(Some(create_role(id, "agronomist", 20))));
+    mocks
+        .user_repo
+        .expect_count_by_role_id()
+        .returning(|_| Ok(0));
+    mocks
+        .invitation_repo
+        .expect_count_by_role_id()
+        .returning(|_| Ok(0));
+    mocks
+        .membership_repo
+        .expect_count_by_role_id()
+        .returning(|_| Ok(0));
+    mocks
+        .role_repo
+        .expect_delete()
+        .times(1)
+        .returning(|id| Ok(create_role(id, "agronomist", 20)));
+    mocks.user_cache.expect_clear().times(1).returning(|| ());
+    let service = mocks.into_service();
+
+    let role = service
+        .delete(&create_admin(), AGRONOMIST_ROLE_ID)
+        .await
+        .unwrap();
+
+    assert_eq!(role.id, AGRONOMIST_ROLE_ID);
+}
@@ -0,0 +1,350 @@
+mod common;
+
+use chrono::{Duration, Utc};
+use common::mocks::{
+    MockApiKeyRepository, MockCompanyRepository, MockOpaqueTokenGenerator, MockPasswordEncoder,
+    MockPermissionRepository, MockRoleRepository, MockServiceAccountRepository, MockTeamRepository,
+    MockUserRepository,
+};
+use common::{create_company, create_role, create_user, grant};
+use mockall::predicate::*;
+use spl_application::dtos::service_account::{CreateApiKeyDto, CreateServiceAccountDto};
+use spl_application::services::access_control::AccessControlService;
+use spl_application::services::policy::PolicyService;
+use spl_application::services::service_account::ServiceAccountService;
+use spl_domain::entities::auth::{ApiKey, ServiceAccount};
+use spl_domain::entities::company::Company;
+use spl_domain::entities::user::{permissions, PermissionScope};
+use spl_shared::error::AppError;
+use std::sync::Arc;
+use uuid::Uuid;
+
+struct Mocks {
+    service_account_repo: MockServiceAccountRepository,
+    api_key_repo: MockApiKeyRepository,
+    user_repo: MockUserRepository,
+    role_repo: MockRoleRepository,
+    company_repo: MockCompanyRepository,
+    encoder: MockPasswordEncoder,
+    opaque: MockOpaqueTokenGenerator,
+}
+
+impl Mocks {
+    fn new() -> Self {
+        let mut opaque = MockOpaqueTokenGenerator::new();
+        opaque.expect_generate().returning(|| "random".to_string());
+        opaque
+            .expect_hash()
+            .returning(|token| format!("hash_{}", token));
+
+        Self {
+            service_account_repo: MockServiceAccountRepository::new(),
+            api_key_repo: MockApiKeyRepository::new(),
+            user_repo: MockUserRepository::new(),
+            role_repo: MockRoleRepository::new(),
+            company_repo: MockCompanyRepository::new(),
+            encoder: MockPasswordEncoder::new(),
+            opaque,
+        }
+    }
+
+    fn into_service(self) -> ServiceAccountService {
+        let access_control = Arc::new(AccessControlService::new(
+            Arc::new(MockCompanyRepository::new()),
+            Arc::new(MockTeamRepository::new()),
+            policy(),
+        ));
+
+        ServiceAccountService::new(
+            Arc::new(self.service_account_repo),
+            Arc::new(self.api_key_repo),
+            Arc::new(self.user_repo),
+            Arc::new(self.role_repo),
+            Arc::new(self.company_repo),
+            Arc::new(self.encoder),
+            Arc::new(self.opaque),
+            access_control,
+        )
+    }
+}
+
+fn create_account(company_id: Uuid) -> ServiceAccount {
+    ServiceAccount {
+        id: Uuid::new_v4(),
+        company_id,
+        name: "Field devices".to_string(),
+        description: None,
+        created_by: None,
+        created_at: Utc::now(),
+    }
+}
+
+fn create_api_key(service_account_id: Uuid) -> ApiKey {
+    ApiKey {
+        id: Uuid::new_v4(),
+        service_account_id,
+        name: "Device".to_string(),
+        key_prefix: "spl_random".to_string(),
+        key_hash: "hash_spl_random".to_string(),
+        scopes: vec!["predictions:write".to_string()],
+        expires_at: None,
+        last_used_at: None,
+        revoked_at: None,
+        created_at: Utc::now(),
+    }
+}
+
+fn key_dto(scopes: &[&str]) -> CreateApiKeyDto {
+    CreateApiKeyDto {
+        name: "Device".to_string(),
+        scopes: scopes.iter().map(|s| s.to_string()).collect(),
+        expires_at: None,
+    }
+}
+
+fn policy() -> Arc<PolicyService> {
+    let mut permission_repo = MockPermissionRepository::new();
+    permission_repo.expect_get_grants().returning(|| {
+        Ok(vec![
+            grant(
+                "admin",
+                permissions::SERVICE_ACCOUNTS_MANAGE,
+                PermissionScope::Any,
+            ),
+            grant(
+                "supervisor",
+                permissions::SERVICE_ACCOUNTS_MANAGE,
+                PermissionScope::Company,
+            ),
+        ])
+    });
+    Arc::new(PolicyService::new(Arc::new(permission_repo), 30))
+}
+
+#[tokio::test]
+async fn test_create_service_account_creates_backing_user() {
+    let mut mocks = Mocks::new();
+    let company = create_company();
+    let company_id = company.id;
+    let supervisor = create_user("supervisor", 50, Some(company));
+    let supervisor_id = supervisor.id;
+
+    mocks
+        .company_repo
+        .expect_get_by_id()
+        .with(eq(company_id))
+        .times(1)
+        .returning(|id| {
+            Ok(Some(Company {
+                id,
+                ..create_company()
+            }))
+        });
+
+    mocks
+        .role_repo
+        .expect_get_by_name()
+        .with(eq("user"))
+        .times(1)
+        .returning(|_| Ok(Some(create_role("user", 10))));
+
+    mocks
+        .encoder
+        .expect_hash()
+        .with(eq("random"))
+        .times(1)
+        .returning(|_| Ok("unusable_hash".to_string()));
+
+    mocks
+        .user_repo
+        .expect_create()
+        .withf(move |user| {
+            user.username.starts_with("svc-")
+                && user.password_hash == "unusable_hash"
+                && user.role.level == 10
+                && user.company.as_ref().map(|c| c.id) == Some(company_id)
+        })
+        .times(1)
+        .returning(Ok);
+
+    mocks
+        .service_account_repo
+        .expect_create()
+        .withf(move |account| {
+            account.company_id == company_id && account.created_by == Some(supervisor_id)
+        })
+        .times(1)
+        .returning(Ok);
+
+    let result = mocks
+        .into_service()
+        .create(
+            &supervisor,
+            CreateServiceAccountDto {
+                name: "Field devices".to_string(),
+                description: None,
+                company_id: None,
+            },
+        )
+        .await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_create_key_returns_prefixed_key_once() {
+    let mut mocks = Mocks::new();
+    let company = create_company();
+    let company_id = company.id;
+    let supervisor = create_user("supervisor", 50, Some(company));
+    let account = create_account(company_id);
+    let account_id = account.id;
+
+    mocks
+        .service_account_repo
+        .expect_get_by_id()
+        .with(eq(account_id))
+        .times(1)
+        .returning(move |_| Ok(Some(account.clone())));
+
+    mocks
+        .api_key_repo
+        .expect_create()
+        .withf(|key| {
+            key.key_hash == "hash_spl_random"
+                && key.key_prefix == "spl_random"
+                && key.scopes == vec!["dashboard:read", "predictions:write"]
+        })
+        .times(1)
+        .returning(Ok);
+
+    let result = mocks
+        .into_service()
+        .create_key(
+            &supervisor,
+            account_id,
+            key_dto(&["predictions:write", "dashboard:read", "predictions:write"]),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(result.key, "spl_random");
+}
+
+#[tokio::test]
+async fn test_create_key_rejects_unknown_scope() {
+    let mut mocks = Mocks::new();
+    let company = create_company();
+    let company_id = company.id;
+    let supervisor = create_user("supervisor", 50, Some(company));
+    let account = create_account(company_id);
+
+    mocks
+        .service_account_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(account.clone())));
+
+    mocks.api_key_repo.expect_create().never();
+
+    let result = mocks
+        .into_service()
+        .create_key(&supervisor, Uuid::new_v4(), key_dto(&["users:write"]))
+        .await;
+
+    assert!(matches!(result, Err(AppError::ValidationError(_))));
+}
+
+#[tokio::test]
+async fn test_create_key_forbidden_for_other_company() {
+    let mut mocks = Mocks::new();
+    let supervisor = create_user("supervisor", 50, Some(create_company()));
+    let account = create_account(Uuid::new_v4());
+
+    mocks
+        .service_account_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(account.clone())));
+
+    mocks.api_key_repo.expect_create().never();
+
+    let result = mocks
+        .into_service()
+        .create_key(&supervisor, Uuid::new_v4(), key_dto(&["dashboard:read"]))
+        .await;
+
+    assert!(matches!(result, Err(AppError::Forbidden)));
+}
+
+#[tokio::test]
+async fn test_authenticate_rejects_expired_key() {
+    let mut mocks = Mocks::new();
+    let mut key = create_api_key(Uuid::new_v4());
+    key.expires_at = Some(Utc::now() - Duration::minutes(1));
+
+    mocks
+        .api_key_repo
+        .expect_get_by_key_hash()
+        .with(eq("hash_spl_device"))
+        .times(1)
+        .returning(move |_| Ok(Some(key.clone())));
+
+    mocks.api_key_repo.expect_touch().never();
+    mocks.user_repo.expect_get_by_id().never();
+
+    let result = mocks.into_service().authenticate("spl_device").await;
+
+    assert!(matches!(result, Err(AppError::AuthError(_))));
+}
+
+#[tokio::test]
+async fn test_authenticate_skips_recent_last_used_update() {
+    let mut mocks = Mocks::new();
+    let company = create_company();
+    let _company_id = company.id;
+    let device = create_user("user", 10, Some(company));
+    let mut key = create_api_key(device.id);
+    key.last_used_at = Some(Utc::now() - Duration::seconds(5));
+
+    mocks
+        .api_key_repo
+        .expect_get_by_key_hash()
+        .times(1)
+        .returning(move |_| Ok(Some(key.clone())));
+
+    mocks
+        .user_repo
+        .expect_get_by_id()
+        .times(1)
+        .returning(move |_| Ok(Some(device.clone())));
+
+    mocks.api_key_repo.expect_touch().never();
+
+    let result = mocks.into_service().authenticate("spl_device").await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_delete_service_account_removes_backing_user() {
+    let mut mocks = Mocks::new();
+    let admin = create_user("admin", 100, Some(create_company()));
+    let account = create_account(Uuid::new_v4());
+    let account_id = account.id;
+
+    mocks
+        .service_account_repo
+        .expect_get_by_id()
+        .times(1)
+        .returning(move |_| Ok(Some(account.clone())));
+
+    mocks
+        .user_repo
+        .expect_delete()
+        .with(eq(account_id))
+        .times(1)
+        .returning(move |_| Ok(create_user("user", 10, Some(create_company()))));
+
+    let result = mocks.into_service().delete(&admin, account_id).await;
+
+    assert!(result.is_ok());
+}
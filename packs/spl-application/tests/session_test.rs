@@ -0,0 +1,217 @@
+mod common;
+
+use chrono::{Duration, Utc};
+use common::mocks::{
+    MockCompanyRepository, MockPermissionRepository, MockSessionRepository, MockTeamRepository,
+    MockUserRepository,
+};
+use common::{create_company, create_user, grant};
+use mockall::predicate::*;
+use spl_application::services::access_control::AccessControlService;
+use spl_application::services::policy::PolicyService;
+use spl_application::services::session::SessionService;
+use spl_domain::entities::auth::Session;
+use spl_domain::entities::user::{permissions, PermissionScope, User};
+use spl_shared::error::AppError;
+use std::sync::Arc;
+use uuid::Uuid;
+
+fn create_session(user_id: Uuid) -> Session {
+    Session {
+        id: Uuid::new_v4(),
+        user_id,
+        expires_at: Utc::now() + Duration::days(30),
+        revoked_at: None,
+        user_agent: Some("Mozilla/5.0".to_string()),
+        ip_address: Some("203.0.113.7".to_string()),
+        active_company_id: None,
+        last_seen_at: Utc::now(),
+        created_at: Utc::now(),
+    }
+}
+
+fn create_service(
+    session_repo: MockSessionRepository,
+    user_repo: MockUserRepository,
+) -> SessionService {
+    let user_repo = Arc::new(user_repo);
+    let access_control = Arc::new(AccessControlService::new(
+        Arc::new(MockCompanyRepository::new()),
+        Arc::new(MockTeamRepository::new()),
+        policy(),
+    ));
+
+    SessionService::new(Arc::new(session_repo), user_repo, access_control)
+}
+
+/// User repository returning `target` for its id
+fn user_repo_with(target: User) -> MockUserRepository {
+    let mut user_repo = MockUserRepository::new();
+    let target_id = target.id;
+    user_repo
+        .expect_get_by_id()
+        .with(eq(target_id))
+        .returning(move |_| Ok(Some(target.clone())));
+    user_repo
+}
+
+fn policy() -> Arc<PolicyService> {
+    let mut permission_repo = MockPermissionRepository::new();
+    permission_repo.expect_get_grants().returning(|| {
+        Ok(vec![
+            grant("admin", permissions::USERS_MANAGE, PermissionScope::Any),
+            grant(
+                "supervisor",
+                permissions::USERS_MANAGE,
+                PermissionScope::Company,
+            ),
+        ])
+    });
+    Arc::new(PolicyService::new(Arc::new(permission_repo), 30))
+}
+
+#[tokio::test]
+async fn test_user_lists_own_sessions() {
+    let user = create_user("user", 10, Some(create_company()));
+    let user_id = user.id;
+
+    let mut session_repo = MockSessionRepository::new();
+    session_repo
+        .expect_get_active_by_user_id()
+        .with(eq(user_id))
+        .times(1)
+        .returning(move |_| Ok(vec![create_session(user_id), create_session(user_id)]));
+
+    let service = create_service(session_repo, MockUserRepository::new());
+
+    let sessions = service.get_active(&user, user_id).await.unwrap();
+    assert_eq!(sessions.len(), 2);
+}
+
+#[tokio::test]
+async fn test_user_revokes_own_session() {
+    let user = create_user("user", 10, Some(create_company()));
+    let session = create_session(user.id);
+    let session_id = session.id;
+
+    let mut session_repo = MockSessionRepository::new();
+    session_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(session.clone())));
+    session_repo
+        .expect_revoke()
+        .with(eq(session_id))
+        .times(1)
+        .returning(|_| Ok(true));
+
+    let service = create_service(session_repo, MockUserRepository::new());
+
+    service.revoke(&user, user.id, session_id).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_revoke_session_of_other_user_is_not_found() {
+    let user = create_user("user", 10, Some(create_company()));
+    let session = create_session(Uuid::new_v4());
+    let session_id = session.id;
+
+    let mut session_repo = MockSessionRepository::new();
+    session_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(session.clone())));
+    session_repo.expect_revoke().never();
+
+    let service = create_service(session_repo, MockUserRepository::new());
+
+    let result = service.revoke(&user, user.id, session_id).await;
+    assert!(matches!(result, Err(AppError::NotFound(_))));
+}
+
+#[tokio::test]
+async fn test_revoke_inactive_session_is_not_found() {
+    let user = create_user("user", 10, Some(create_company()));
+    let mut session = create_session(user.id);
+    session.revoked_at = Some(Utc::now());
+    let session_id = session.id;
+
+    let mut session_repo = MockSessionRepository::new();
+    session_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(session.clone())));
+    session_repo.expect_revoke().never();
+
+    let service = create_service(session_repo, MockUserRepository::new());
+
+    let result = service.revoke(&user, user.id, session_id).await;
+    assert!(matches!(result, Err(AppError::NotFound(_))));
+}
+
+#[tokio::test]
+async fn test_supervisor_revokes_sessions_of_company_user() {
+    let company = create_company();
+    let supervisor = create_user("supervisor", 50, Some(company.clone()));
+    let target = create_user("user", 10, Some(company));
+    let target_id = target.id;
+
+    let mut session_repo = MockSessionRepository::new();
+    session_repo
+        .expect_revoke_by_user_id()
+        .with(eq(target_id))
+        .times(1)
+        .returning(|_| Ok(3));
+
+    let service = create_service(session_repo, user_repo_with(target));
+
+    let revoked = service.revoke_all(&supervisor, target_id).await.unwrap();
+    assert_eq!(revoked, 3);
+}
+
+#[tokio::test]
+async fn test_supervisor_cannot_manage_other_company() {
+    let supervisor = create_user("supervisor", 50, Some(create_company()));
+    let target = create_user("user", 10, Some(create_company()));
+    let target_id = target.id;
+
+    let mut session_repo = MockSessionRepository::new();
+    session_repo.expect_revoke_by_user_id().never();
+
+    let service = create_service(session_repo, user_repo_with(target));
+
+    let result = service.revoke_all(&supervisor, target_id).await;
+    assert!(matches!(result, Err(AppError::Forbidden)));
+}
+
+#[tokio::test]
+async fn test_supervisor_cannot_manage_same_level() {
+    let company = create_company();
+    let supervisor = create_user("supervisor", 50, Some(company.clone()));
+    let peer = create_user("supervisor", 50, Some(company));
+    let peer_id = peer.id;
+
+    let mut session_repo = MockSessionRepository::new();
+    session_repo.expect_get_active_by_user_id().never();
+
+    let service = create_service(session_repo, user_repo_with(peer));
+
+    let result = service.get_active(&supervisor, peer_id).await;
+    assert!(matches!(result, Err(AppError::Forbidden)));
+}
+
+#[tokio::test]
+async fn test_admin_manages_users_without_company() {
+    let admin = create_user("admin", 100, None);
+    let target = create_user("supervisor", 50, None);
+    let target_id = target.id;
+
+    let mut session_repo = MockSessionRepository::new();
+    session_repo
+        .expect_get_active_by_user_id()
+        .with(eq(target_id))
+        .times(1)
+        .returning(move |_| Ok(vec![create_session(target_id)]));
+
+    let service = create_service(session_repo, user_repo_with(target));
+
+    let sessions = service.get_active(&admin, target_id).await.unwrap();
+    assert_eq!(sessions.len(), 1);
+}
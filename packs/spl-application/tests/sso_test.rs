@@ -0,0 +1,589 @@
+mod common;
+
+use chrono::{Duration, Utc};
+use common::mocks::{
+    MockCompanyRepository, MockIdentityProviderRepository, MockLoginAttemptStore,
+    MockMembershipRepository, MockOidcClient, MockOidcLoginStateRepository,
+    MockOpaqueTokenGenerator, MockPasswordEncoder, MockPermissionRepository,
+    MockRecoveryCodeRepository, MockRefreshTokenRepository, MockRoleRepository,
+    MockSessionRepository, MockTeamRepository, MockTokenGenerator,
+    MockTwoFactorChallengeRepository, MockTwoFactorProvider, MockTwoFactorRepository,
+    MockUserCache, MockUserIdentityRepository, MockUserRepository,
+};
+use common::{create_company, create_role, create_user, grant};
+use mockall::predicate::*;
+use spl_application::dtos::auth::ClientInfoDto;
+use spl_application::dtos::sso::{ConfigureIdentityProviderDto, SsoCallbackDto};
+use spl_application::services::access_control::AccessControlService;
+use spl_application::services::auth::AuthService;
+use spl_application::services::login_lockout::{LockoutPolicy, LoginLockoutService};
+use spl_application::services::policy::PolicyService;
+use spl_application::services::sso::SsoService;
+use spl_application::services::two_factor::TwoFactorService;
+use spl_domain::entities::auth::{IdentityProvider, OidcLoginState, UserIdentity};
+use spl_domain::entities::company::Company;
+use spl_domain::entities::user::{permissions, PermissionScope, Role};
+use spl_domain::ports::oidc::OidcClaims;
+use spl_shared::error::AppError;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+const ISSUER: &str = "https://idp.example.com";
+const REDIRECT_URL: &str = "https://app.example.com/auth/callback";
+
+struct Mocks {
+    identity_provider_repo: MockIdentityProviderRepository,
+    user_identity_repo: MockUserIdentityRepository,
+    login_state_repo: MockOidcLoginStateRepository,
+    oidc_client: MockOidcClient,
+    user_repo: MockUserRepository,
+    role_repo: MockRoleRepository,
+    company_repo: MockCompanyRepository,
+    encoder: MockPasswordEncoder,
+    session_repo: MockSessionRepository,
+    refresh_token_repo: MockRefreshTokenRepository,
+    user_cache: MockUserCache,
+}
+
+impl Mocks {
+    fn new() -> Self {
+        Self {
+            identity_provider_repo: MockIdentityProviderRepository::new(),
+            user_identity_repo: MockUserIdentityRepository::new(),
+            login_state_repo: MockOidcLoginStateRepository::new(),
+            oidc_client: MockOidcClient::new(),
+            user_repo: MockUserRepository::new(),
+            role_repo: MockRoleRepository::new(),
+            company_repo: MockCompanyRepository::new(),
+            encoder: MockPasswordEncoder::new(),
+            session_repo: MockSessionRepository::new(),
+            refresh_token_repo: MockRefreshTokenRepository::new(),
+            user_cache: MockUserCache::new(),
+        }
+    }
+
+    /// Provider and valid login state for `company_id`, and a session for any login
+    fn with_login(company_id: Uuid, provider: IdentityProvider) -> Self {
+        let mut mocks = Self::new();
+
+        mocks
+            .identity_provider_repo
+            .expect_get_by_id()
+            .with(eq(company_id))
+            .returning(move |_| Ok(Some(provider.clone())));
+
+        let state = create_login_state(company_id);
+        mocks
+            .login_state_repo
+            .expect_get_by_state_hash()
+            .with(eq("hashed_state"))
+            .returning(move |_| Ok(Some(state.clone())));
+        mocks
+            .login_state_repo
+            .expect_mark_used()
+            .times(1)
+            .returning(|_| Ok(true));
+
+        mocks.session_repo.expect_create().returning(Ok);
+        mocks.refresh_token_repo.expect_create().returning(Ok);
+
+        mocks
+    }
+
+    fn expect_claims(&mut self, claims: OidcClaims) {
+        self.oidc_client
+            .expect_exchange_code()
+            .withf(|_, code, redirect_uri, code_verifier, nonce| {
+                code == "code"
+                    && redirect_uri == REDIRECT_URL
+                    && code_verifier == "verifier"
+                    && nonce == "nonce"
+            })
+            .times(1)
+            .returning(move |_, _, _, _, _| Ok(claims.clone()));
+    }
+
+    fn expect_roles(&mut self, roles: Vec<Role>) {
+        self.role_repo
+            .expect_get_by_name()
+            .returning(move |name| Ok(roles.iter().find(|role| role.name == name).cloned()));
+    }
+
+    fn into_service(self) -> SsoService {
+        let user_repo = Arc::new(self.user_repo);
+        let company_repo = Arc::new(self.company_repo);
+        let encoder = Arc::new(self.encoder);
+
+        let mut token_generator = MockTokenGenerator::new();
+        token_generator
+            .expect_generate()
+            .returning(|_, _| Ok("jwt_token".to_string()));
+
+        let two_factor_service = Arc::new(TwoFactorService::new(
+            Arc::new(MockTwoFactorRepository::new()),
+            Arc::new(MockRecoveryCodeRepository::new()),
+            Arc::new(MockTwoFactorChallengeRepository::new()),
+            Arc::new(MockTwoFactorProvider::new()),
+            Arc::new(opaque_generator()),
+            300,
+        ));
+
+        let login_lockout_service = Arc::new(LoginLockoutService::new(
+            user_repo.clone(),
+            Arc::new(MockLoginAttemptStore::new()),
+            LockoutPolicy {
+                enabled: false,
+                max_attempts: 5,
+                lockout_seconds: 60,
+                max_lockout_seconds: 3600,
+            },
+        ));
+
+        let user_cache = Arc::new(self.user_cache);
+        let auth_service = Arc::new(AuthService::new(
+            user_repo.clone(),
+            Arc::new(MockMembershipRepository::new()),
+            Arc::new(self.session_repo),
+            Arc::new(self.refresh_token_repo),
+            encoder.clone(),
+            Arc::new(token_generator),
+            Arc::new(opaque_generator()),
+            login_lockout_service,
+            two_factor_service,
+            user_cache.clone(),
+            900,
+            30,
+        ));
+
+        let access_control = Arc::new(AccessControlService::new(
+            Arc::new(MockCompanyRepository::new()),
+            Arc::new(MockTeamRepository::new()),
+            policy(),
+        ));
+
+        SsoService::new(
+            Arc::new(self.identity_provider_repo),
+            Arc::new(self.user_identity_repo),
+            Arc::new(self.login_state_repo),
+            user_repo,
+            Arc::new(self.role_repo),
+            company_repo,
+            Arc::new(self.oidc_client),
+            encoder,
+            Arc::new(opaque_generator()),
+            auth_service,
+            access_control,
+            user_cache,
+            Some(REDIRECT_URL.to_string()),
+            600,
+        )
+    }
+}
+
+/// Generates "state", "nonce" and "verifier" in that order, then "random"
+fn opaque_generator() -> MockOpaqueTokenGenerator {
+    let mut generator = MockOpaqueTokenGenerator::new();
+    let mut values = vec!["verifier", "nonce", "state"];
+    generator
+        .expect_generate()
+        .returning(move || values.pop().unwrap_or("random").to_string());
+    generator
+        .expect_hash()
+        .returning(|token| format!("hashed_{token}"));
+    generator
+}
+
+fn create_provider(company_id: Uuid) -> IdentityProvider {
+    IdentityProvider {
+        company_id,
+        issuer: ISSUER.to_string(),
+        client_id: "spl".to_string(),
+        client_secret: None,
+        scopes: "openid email profile".to_string(),
+        role_claim: "groups".to_string(),
+        role_mapping: BTreeMap::from([
+            ("agronomists".to_string(), "supervisor".to_string()),
+            ("field".to_string(), "user".to_string()),
+        ]),
+        default_role: None,
+        auto_provision: false,
+        enabled: true,
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+    }
+}
+
+fn create_login_state(company_id: Uuid) -> OidcLoginState {
+    OidcLoginState {
+        id: Uuid::new_v4(),
+        state_hash: "hashed_state".to_string(),
+        company_id,
+        nonce: "nonce".to_string(),
+        code_verifier: "verifier".to_string(),
+        expires_at: Utc::now() + Duration::minutes(10),
+        used_at: None,
+        created_at: Utc::now(),
+    }
+}
+
+fn create_claims(roles: &[&str]) -> OidcClaims {
+    OidcClaims {
+        subject: "idp-subject".to_string(),
+        email: Some("jdoe@example.com".to_string()),
+        email_verified: true,
+        preferred_username: Some("jdoe".to_string()),
+        given_name: Some("John".to_string()),
+        family_name: Some("Doe".to_string()),
+        roles: roles.iter().map(|r| r.to_string()).collect(),
+    }
+}
+
+fn callback_dto() -> SsoCallbackDto {
+    SsoCallbackDto {
+        code: "code".to_string(),
+        state: "state".to_string(),
+    }
+}
+
+fn policy() -> Arc<PolicyService> {
+    let mut permission_repo = MockPermissionRepository::new();
+    permission_repo.expect_get_grants().returning(|| {
+        Ok(vec![
+            grant("admin", permissions::SSO_MANAGE, PermissionScope::Any),
+            grant(
+                "supervisor",
+                permissions::SSO_MANAGE,
+                PermissionScope::Company,
+            ),
+        ])
+    });
+    Arc::new(PolicyService::new(Arc::new(permission_repo), 30))
+}
+
+#[tokio::test]
+async fn test_authorize_stores_login_state() {
+    let company_id = Uuid::new_v4();
+    let provider = create_provider(company_id);
+    let mut mocks = Mocks::new();
+
+    mocks
+        .identity_provider_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(provider.clone())));
+
+    mocks
+        .oidc_client
+        .expect_authorization_url()
+        .withf(|_, redirect_uri, state, nonce, code_verifier| {
+            redirect_uri == REDIRECT_URL
+                && state == "state"
+                && nonce == "nonce"
+                && code_verifier == "verifier"
+        })
+        .times(1)
+        .returning(|_, _, _, _, _| Ok(format!("{ISSUER}/authorize?state=state")));
+
+    mocks
+        .login_state_repo
+        .expect_create()
+        .withf(move |state| {
+            state.company_id == company_id
+                && state.state_hash == "hashed_state"
+                && state.code_verifier == "verifier"
+                && state.nonce == "nonce"
+                && state.expires_at > Utc::now()
+        })
+        .times(1)
+        .returning(Ok);
+
+    let url = mocks.into_service().authorize(company_id).await.unwrap();
+
+    assert_eq!(url, format!("{ISSUER}/authorize?state=state"));
+}
+
+#[tokio::test]
+async fn test_authorize_rejects_disabled_provider() {
+    let company_id = Uuid::new_v4();
+    let mut provider = create_provider(company_id);
+    provider.enabled = false;
+    let mut mocks = Mocks::new();
+
+    mocks
+        .identity_provider_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(provider.clone())));
+    mocks.oidc_client.expect_authorization_url().never();
+
+    let result = mocks.into_service().authorize(company_id).await;
+
+    assert!(matches!(result, Err(AppError::NotFound(_))));
+}
+
+#[tokio::test]
+async fn test_callback_rejects_used_state() {
+    let company_id = Uuid::new_v4();
+    let mut state = create_login_state(company_id);
+    state.used_at = Some(Utc::now());
+    let mut mocks = Mocks::new();
+
+    mocks
+        .login_state_repo
+        .expect_get_by_state_hash()
+        .returning(move |_| Ok(Some(state.clone())));
+    mocks.login_state_repo.expect_mark_used().never();
+    mocks.oidc_client.expect_exchange_code().never();
+
+    let result = mocks
+        .into_service()
+        .callback(callback_dto(), ClientInfoDto::default())
+        .await;
+
+    assert!(matches!(result, Err(AppError::AuthError(_))));
+}
+
+#[tokio::test]
+async fn test_callback_logs_in_linked_user_and_syncs_role() {
+    let company = create_company();
+    let company_id = company.id;
+    let user = create_user("user", 10, Some(company));
+    let user_id = user.id;
+    let mut mocks = Mocks::with_login(company_id, create_provider(company_id));
+
+    mocks.expect_claims(create_claims(&["field", "agronomists", "unmapped"]));
+    mocks.expect_roles(vec![create_role("user", 10), create_role("supervisor", 50)]);
+
+    mocks
+        .user_identity_repo
+        .expect_get_by_subject()
+        .with(eq(ISSUER), eq("idp-subject"))
+        .returning(move |issuer, subject| {
+            Ok(Some(UserIdentity {
+                id: Uuid::new_v4(),
+                user_id,
+                issuer: issuer.to_string(),
+                subject: subject.to_string(),
+                email: None,
+                created_at: Utc::now(),
+            }))
+        });
+    mocks.user_identity_repo.expect_create().never();
+
+    mocks
+        .user_repo
+        .expect_get_by_id()
+        .with(eq(user_id))
+        .returning(move |_| Ok(Some(user.clone())));
+
+    // The highest mapped role wins
+    mocks
+        .user_repo
+        .expect_update()
+        .withf(move |user| user.id == user_id && user.role.name == "supervisor")
+        .times(1)
+        .returning(Ok);
+    mocks
+        .user_cache
+        .expect_invalidate()
+        .with(eq(user_id))
+        .times(1)
+        .returning(|_| ());
+
+    let tokens = mocks
+        .into_service()
+        .callback(callback_dto(), ClientInfoDto::default())
+        .await
+        .unwrap();
+
+    assert_eq!(tokens.access_token, "jwt_token");
+    assert_eq!(tokens.expires_in, 900);
+}
+
+#[tokio::test]
+async fn test_callback_links_user_with_verified_email() {
+    let company = create_company();
+    let company_id = company.id;
+    let user = create_user("user", 10, Some(company));
+    let user_id = user.id;
+    let mut mocks = Mocks::with_login(company_id, create_provider(company_id));
+
+    mocks.expect_claims(create_claims(&["field"]));
+    mocks.expect_roles(vec![create_role("user", 10)]);
+
+    mocks
+        .user_identity_repo
+        .expect_get_by_subject()
+        .returning(|_, _| Ok(None));
+    mocks
+        .user_repo
+        .expect_get_by_username_or_email_and_company()
+        .with(
+            eq(None),
+            eq(Some("jdoe@example.com".to_string())),
+            eq(Some(company_id)),
+        )
+        .returning(move |_, _, _| Ok(Some(user.clone())));
+    mocks
+        .user_identity_repo
+        .expect_create()
+        .withf(move |identity| {
+            identity.user_id == user_id
+                && identity.issuer == ISSUER
+                && identity.subject == "idp-subject"
+        })
+        .times(1)
+        .returning(Ok);
+    mocks.user_repo.expect_update().never();
+
+    let result = mocks
+        .into_service()
+        .callback(callback_dto(), ClientInfoDto::default())
+        .await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_callback_ignores_unverified_email() {
+    let company_id = Uuid::new_v4();
+    let mut mocks = Mocks::with_login(company_id, create_provider(company_id));
+
+    let mut claims = create_claims(&["field"]);
+    claims.email_verified = false;
+    mocks.expect_claims(claims);
+    mocks.expect_roles(vec![create_role("user", 10)]);
+
+    mocks
+        .user_identity_repo
+        .expect_get_by_subject()
+        .returning(|_, _| Ok(None));
+    mocks
+        .user_repo
+        .expect_get_by_username_or_email_and_company()
+        .never();
+    mocks.user_repo.expect_create().never();
+
+    let result = mocks
+        .into_service()
+        .callback(callback_dto(), ClientInfoDto::default())
+        .await;
+
+    assert!(matches!(result, Err(AppError::AuthError(_))));
+}
+
+#[tokio::test]
+async fn test_callback_provisions_user() {
+    let company = create_company();
+    let company_id = company.id;
+    let mut provider = create_provider(company_id);
+    provider.auto_provision = true;
+    provider.default_role = Some("user".to_string());
+    let mut mocks = Mocks::with_login(company_id, provider);
+
+    mocks.expect_claims(create_claims(&[]));
+    mocks.expect_roles(vec![create_role("user", 10)]);
+
+    mocks
+        .user_identity_repo
+        .expect_get_by_subject()
+        .returning(|_, _| Ok(None));
+    mocks
+        .user_repo
+        .expect_get_by_username_or_email_and_company()
+        .returning(|_, _, _| Ok(None));
+    mocks.company_repo.expect_get_by_id().returning(|id| {
+        Ok(Some(Company {
+            id,
+            ..create_company()
+        }))
+    });
+
+    // "jdoe" is taken inside the company
+    mocks
+        .user_repo
+        .expect_get_by_username_and_company()
+        .returning(move |username, _| {
+            Ok((username == "jdoe").then(|| create_user("user", 10, Some(company.clone()))))
+        });
+    mocks
+        .encoder
+        .expect_hash()
+        .returning(|_| Ok("random_hash".to_string()));
+    mocks
+        .user_repo
+        .expect_create()
+        .withf(move |user| {
+            user.username == "jdoe2"
+                && user.role.name == "user"
+                && user.name.as_deref() == Some("John")
+                && user.company.as_ref().map(|c| c.id) == Some(company_id)
+        })
+        .times(1)
+        .returning(Ok);
+    mocks
+        .user_identity_repo
+        .expect_create()
+        .times(1)
+        .returning(Ok);
+
+    let result = mocks
+        .into_service()
+        .callback(callback_dto(), ClientInfoDto::default())
+        .await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_callback_without_mapped_role_is_forbidden() {
+    let company_id = Uuid::new_v4();
+    let mut mocks = Mocks::with_login(company_id, create_provider(company_id));
+
+    mocks.expect_claims(create_claims(&["guests"]));
+    mocks.user_identity_repo.expect_get_by_subject().never();
+
+    let result = mocks
+        .into_service()
+        .callback(callback_dto(), ClientInfoDto::default())
+        .await;
+
+    assert!(matches!(result, Err(AppError::Forbidden)));
+}
+
+#[tokio::test]
+async fn test_configure_provider_rejects_admin_mapping() {
+    let company = create_company();
+    let company_id = company.id;
+    let supervisor = create_user("supervisor", 50, Some(company));
+    let mut mocks = Mocks::new();
+
+    mocks.company_repo.expect_get_by_id().returning(|id| {
+        Ok(Some(Company {
+            id,
+            ..create_company()
+        }))
+    });
+    mocks.expect_roles(vec![create_role("admin", 100)]);
+    mocks.identity_provider_repo.expect_create().never();
+
+    let result = mocks
+        .into_service()
+        .configure_provider(
+            &supervisor,
+            company_id,
+            ConfigureIdentityProviderDto {
+                issuer: ISSUER.to_string(),
+                client_id: "spl".to_string(),
+                client_secret: None,
+                scopes: None,
+                role_claim: None,
+                role_mapping: BTreeMap::from([("it".to_string(), "admin".to_string())]),
+                default_role: None,
+                auto_provision: false,
+                enabled: true,
+            },
+        )
+        .await;
+
+    assert!(matches!(result, Err(AppError::ValidationError(_))));
+}
@@ -0,0 +1,467 @@
+mod common;
+
+use chrono::Utc;
+use common::mocks::{
+    MockCompanyRepository, MockPermissionRepository, MockPlotRepository, MockTeamRepository,
+    MockUserRepository,
+};
+use common::{create_company, create_user, grant};
+use mockall::predicate::*;
+use spl_application::dtos::team::{CreateTeamDto, SetTeamMemberDto};
+use spl_application::services::access_control::AccessControlService;
+use spl_application::services::policy::PolicyService;
+use spl_application::services::team::TeamService;
+use spl_domain::entities::team::{Team, TeamMember, TeamRole};
+use spl_domain::entities::user::{permissions, PermissionScope};
+use spl_shared::error::AppError;
+use std::sync::Arc;
+use uuid::Uuid;
+
+struct Mocks {
+    team_repo: MockTeamRepository,
+    user_repo: MockUserRepository,
+    plot_repo: MockPlotRepository,
+}
+
+impl Mocks {
+    fn new() -> Self {
+        Self {
+            team_repo: MockTeamRepository::new(),
+            user_repo: MockUserRepository::new(),
+            plot_repo: MockPlotRepository::new(),
+        }
+    }
+
+    fn into_services(self) -> (TeamService, Arc<AccessControlService>) {
+        let mut permission_repo = MockPermissionRepository::new();
+        permission_repo.expect_get_grants().returning(|| {
+            Ok(vec![
+                grant("admin", permissions::USERS_READ, PermissionScope::Any),
+                grant("admin", permissions::TEAMS_MANAGE, PermissionScope::Any),
+                // Company administrators are custom roles with company scoped grants
+                grant("manager", permissions::USERS_READ, PermissionScope::Company),
+                grant("manager", permissions::PLOTS_READ, PermissionScope::Company),
+                grant(
+                    "manager",
+                    permissions::TEAMS_MANAGE,
+                    PermissionScope::Company,
+                ),
+                grant("supervisor", permissions::USERS_READ, PermissionScope::Team),
+                grant("supervisor", permissions::PLOTS_READ, PermissionScope::Team),
+            ])
+        });
+
+        let team_repo = Arc::new(self.team_repo);
+        let user_repo = Arc::new(self.user_repo);
+        let access_control = Arc::new(AccessControlService::new(
+            Arc::new(MockCompanyRepository::new()),
+            team_repo.clone(),
+            Arc::new(PolicyService::new(Arc::new(permission_repo), 30)),
+        ));
+
+        let service = TeamService::new(
+            team_repo,
+            user_repo,
+            Arc::new(self.plot_repo),
+            access_control.clone(),
+        );
+
+        (service, access_control)
+    }
+}
+
+fn create_team(company_id: Uuid, name: &str) -> Team {
+    Team {
+        id: Uuid::new_v4(),
+        company_id,
+        name: name.to_string(),
+        description: None,
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+    }
+}
+
+fn member(team_id: Uuid, user_id: Uuid, role: TeamRole) -> TeamMember {
+    TeamMember {
+        team_id,
+        user_id,
+        role,
+        created_at: Utc::now(),
+    }
+}
+
+#[tokio::test]
+async fn test_supervisor_only_reaches_members_of_supervised_teams() {
+    let company = create_company();
+    let company_id = company.id;
+    let supervisor = create_user("supervisor", 50, Some(company.clone()));
+    let supervisor_id = supervisor.id;
+    let member_user = create_user("user", 10, Some(company.clone()));
+    let member_id = member_user.id;
+    let north = create_team(company_id, "North");
+    let south = create_team(company_id, "South");
+    let north_id = north.id;
+
+    let mut mocks = Mocks::new();
+    let teams = vec![north.clone(), south];
+    mocks
+        .team_repo
+        .expect_get_by_company_id()
+        .with(eq(company_id))
+        .returning(move |_| Ok(teams.clone()));
+    mocks
+        .team_repo
+        .expect_get_supervised_by()
+        .with(eq(supervisor_id))
+        .returning(move |_| Ok(vec![north.clone()]));
+    mocks
+        .team_repo
+        .expect_get_members()
+        .with(eq(vec![north_id]))
+        .returning(move |_| {
+            Ok(vec![
+                member(north_id, supervisor_id, TeamRole::Supervisor),
+                member(north_id, member_id, TeamRole::Member),
+            ])
+        });
+    let (_, access_control) = mocks.into_services();
+
+    let ids = access_control
+        .get_team_user_ids(&supervisor, permissions::USERS_READ)
+        .await
+        .unwrap()
+        .unwrap();
+
+    let mut expected = vec![supervisor_id, member_id];
+    expected.sort();
+    assert_eq!(ids, expected);
+}
+
+#[tokio::test]
+async fn test_supervisor_reaches_whole_company_without_teams() {
+    let company = create_company();
+    let company_id = company.id;
+    let supervisor = create_user("supervisor", 50, Some(company));
+
+    let mut mocks = Mocks::new();
+    mocks
+        .team_repo
+        .expect_get_by_company_id()
+        .with(eq(company_id))
+        .returning(|_| Ok(vec![]));
+    mocks.team_repo.expect_get_supervised_by().never();
+    let (_, access_control) = mocks.into_services();
+
+    let ids = access_control
+        .get_team_user_ids(&supervisor, permissions::USERS_READ)
+        .await
+        .unwrap();
+
+    assert_eq!(ids, None);
+}
+
+#[tokio::test]
+async fn test_teams_supervised_in_other_companies_are_ignored() {
+    let company = create_company();
+    let company_id = company.id;
+    let supervisor = create_user("supervisor", 50, Some(company));
+    let supervisor_id = supervisor.id;
+    let north = create_team(company_id, "North");
+    let north_id = north.id;
+    // Supervised under a membership of another company
+    let elsewhere = create_team(Uuid::new_v4(), "Elsewhere");
+
+    let mut mocks = Mocks::new();
+    let teams = vec![north.clone()];
+    mocks
+        .team_repo
+        .expect_get_by_company_id()
+        .with(eq(company_id))
+        .returning(move |_| Ok(teams.clone()));
+    mocks
+        .team_repo
+        .expect_get_supervised_by()
+        .with(eq(supervisor_id))
+        .returning(move |_| Ok(vec![north.clone(), elsewhere.clone()]));
+    mocks
+        .team_repo
+        .expect_get_members()
+        .with(eq(vec![north_id]))
+        .times(1)
+        .returning(|_| Ok(vec![]));
+    mocks
+        .team_repo
+        .expect_get_plot_ids()
+        .with(eq(vec![north_id]))
+        .times(1)
+        .returning(|_| Ok(vec![]));
+    let (_, access_control) = mocks.into_services();
+
+    access_control
+        .get_team_user_ids(&supervisor, permissions::USERS_READ)
+        .await
+        .unwrap();
+    access_control
+        .get_team_plot_ids(&supervisor, permissions::PLOTS_READ)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_company_scope_is_not_narrowed_by_teams() {
+    let company = create_company();
+    let manager = create_user("manager", 80, Some(company));
+
+    let mut mocks = Mocks::new();
+    mocks.team_repo.expect_get_by_company_id().never();
+    mocks.team_repo.expect_get_supervised_by().never();
+    let (_, access_control) = mocks.into_services();
+
+    let user_ids = access_control
+        .get_team_user_ids(&manager, permissions::USERS_READ)
+        .await
+        .unwrap();
+    let plot_ids = access_control
+        .get_team_plot_ids(&manager, permissions::PLOTS_READ)
+        .await
+        .unwrap();
+
+    assert!(user_ids.is_none());
+    assert!(plot_ids.is_none());
+}
+
+#[tokio::test]
+async fn test_supervisor_only_reaches_plots_of_supervised_teams() {
+    let company = create_company();
+    let company_id = company.id;
+    let supervisor = create_user("supervisor", 50, Some(company));
+    let team = create_team(company_id, "North");
+    let team_id = team.id;
+    let plot_id = Uuid::new_v4();
+
+    let mut mocks = Mocks::new();
+    let teams = vec![team.clone()];
+    mocks
+        .team_repo
+        .expect_get_by_company_id()
+        .returning(move |_| Ok(teams.clone()));
+    mocks
+        .team_repo
+        .expect_get_supervised_by()
+        .returning(move |_| Ok(vec![team.clone()]));
+    mocks
+        .team_repo
+        .expect_get_plot_ids()
+        .with(eq(vec![team_id]))
+        .returning(move |_| Ok(vec![plot_id]));
+    let (_, access_control) = mocks.into_services();
+
+    let plot_ids = access_control
+        .get_team_plot_ids(&supervisor, permissions::PLOTS_READ)
+        .await
+        .unwrap();
+
+    assert_eq!(plot_ids, Some(vec![plot_id]));
+}
+
+#[tokio::test]
+async fn test_supervisor_outside_every_team_reaches_only_themselves() {
+    let company = create_company();
+    let company_id = company.id;
+    let supervisor = create_user("supervisor", 50, Some(company));
+    let supervisor_id = supervisor.id;
+
+    let mut mocks = Mocks::new();
+    mocks
+        .team_repo
+        .expect_get_by_company_id()
+        .returning(move |_| Ok(vec![create_team(company_id, "North")]));
+    mocks
+        .team_repo
+        .expect_get_supervised_by()
+        .returning(|_| Ok(vec![]));
+    mocks.team_repo.expect_get_members().never();
+    mocks.team_repo.expect_get_plot_ids().never();
+    let (_, access_control) = mocks.into_services();
+
+    let user_ids = access_control
+        .get_team_user_ids(&supervisor, permissions::USERS_READ)
+        .await
+        .unwrap();
+    let plot_ids = access_control
+        .get_team_plot_ids(&supervisor, permissions::PLOTS_READ)
+        .await
+        .unwrap();
+
+    assert_eq!(user_ids, Some(vec![supervisor_id]));
+    assert_eq!(plot_ids, Some(vec![]));
+}
+
+#[tokio::test]
+async fn test_supervisor_lists_only_supervised_teams() {
+    let company = create_company();
+    let company_id = company.id;
+    let supervisor = create_user("supervisor", 50, Some(company));
+    let north = create_team(company_id, "North");
+    let south = create_team(company_id, "South");
+    let north_id = north.id;
+
+    let mut mocks = Mocks::new();
+    let teams = vec![north.clone(), south];
+    mocks
+        .team_repo
+        .expect_get_by_company_id()
+        .returning(move |_| Ok(teams.clone()));
+    mocks
+        .team_repo
+        .expect_get_supervised_by()
+        .returning(move |_| Ok(vec![north.clone()]));
+    let (service, _) = mocks.into_services();
+
+    let teams = service
+        .get_by_company(&supervisor, company_id)
+        .await
+        .unwrap();
+
+    assert_eq!(teams.len(), 1);
+    assert_eq!(teams[0].id, north_id);
+}
+
+#[tokio::test]
+async fn test_supervisor_cannot_create_team() {
+    let company = create_company();
+    let company_id = company.id;
+    let supervisor = create_user("supervisor", 50, Some(company));
+
+    let mut mocks = Mocks::new();
+    mocks.team_repo.expect_create().never();
+    let (service, _) = mocks.into_services();
+
+    let result = service
+        .create(
+            &supervisor,
+            CreateTeamDto {
+                company_id,
+                name: "North".to_string(),
+                description: None,
+            },
+        )
+        .await;
+
+    assert!(matches!(result, Err(AppError::Forbidden)));
+}
+
+#[tokio::test]
+async fn test_company_admin_cannot_create_team_in_other_company() {
+    let manager = create_user("manager", 80, Some(create_company()));
+
+    let mut mocks = Mocks::new();
+    mocks.team_repo.expect_create().never();
+    let (service, _) = mocks.into_services();
+
+    let result = service
+        .create(
+            &manager,
+            CreateTeamDto {
+                company_id: Uuid::new_v4(),
+                name: "North".to_string(),
+                description: None,
+            },
+        )
+        .await;
+
+    assert!(matches!(result, Err(AppError::Forbidden)));
+}
+
+#[tokio::test]
+async fn test_create_team_rejects_taken_name() {
+    let company = create_company();
+    let company_id = company.id;
+    let manager = create_user("manager", 80, Some(company));
+
+    let mut mocks = Mocks::new();
+    mocks
+        .team_repo
+        .expect_get_by_company_id()
+        .returning(move |_| Ok(vec![create_team(company_id, "North")]));
+    mocks.team_repo.expect_create().never();
+    let (service, _) = mocks.into_services();
+
+    let result = service
+        .create(
+            &manager,
+            CreateTeamDto {
+                company_id,
+                name: "north".to_string(),
+                description: None,
+            },
+        )
+        .await;
+
+    assert!(matches!(result, Err(AppError::Conflict(_))));
+}
+
+#[tokio::test]
+async fn test_set_member_rejects_user_of_other_company() {
+    let company = create_company();
+    let company_id = company.id;
+    let manager = create_user("manager", 80, Some(company));
+    let team = create_team(company_id, "North");
+    let team_id = team.id;
+    let outsider = create_user("user", 10, Some(create_company()));
+    let outsider_id = outsider.id;
+
+    let mut mocks = Mocks::new();
+    mocks
+        .team_repo
+        .expect_get_by_id()
+        .with(eq(team_id))
+        .returning(move |_| Ok(Some(team.clone())));
+    mocks
+        .user_repo
+        .expect_get_by_id()
+        .with(eq(outsider_id))
+        .returning(move |_| Ok(Some(outsider.clone())));
+    mocks.team_repo.expect_set_member().never();
+    let (service, _) = mocks.into_services();
+
+    let result = service
+        .set_member(
+            &manager,
+            team_id,
+            SetTeamMemberDto {
+                user_id: outsider_id,
+                role: TeamRole::Member,
+            },
+        )
+        .await;
+
+    assert!(matches!(result, Err(AppError::ValidationError(_))));
+}
+
+#[tokio::test]
+async fn test_add_plot_rejects_plot_of_other_company() {
+    let company = create_company();
+    let company_id = company.id;
+    let manager = create_user("manager", 80, Some(company));
+    let team = create_team(company_id, "North");
+    let team_id = team.id;
+    let plot_id = Uuid::new_v4();
+
+    let mut mocks = Mocks::new();
+    mocks
+        .team_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(team.clone())));
+    mocks
+        .plot_repo
+        .expect_get_by_company_id_and_id()
+        .with(eq(company_id), eq(plot_id))
+        .returning(|_, _| Ok(None));
+    mocks.team_repo.expect_add_plot().never();
+    let (service, _) = mocks.into_services();
+
+    let result = service.add_plot(&manager, team_id, plot_id).await;
+
+    assert!(matches!(result, Err(AppError::NotFound(_))));
+}
@@ -0,0 +1,321 @@
+mod common;
+
+use chrono::Utc;
+use common::mocks::{
+    MockOpaqueTokenGenerator, MockRecoveryCodeRepository, MockTwoFactorChallengeRepository,
+    MockTwoFactorProvider, MockTwoFactorRepository,
+};
+use common::{create_company, create_user};
+use mockall::predicate::*;
+use spl_application::dtos::company::UpdateCompanyDto;
+use spl_application::services::two_factor::TwoFactorService;
+use spl_domain::entities::auth::{RecoveryCode, TwoFactor};
+use spl_domain::entities::company::Company;
+use spl_shared::error::{AppError, Result};
+use spl_shared::traits::IntoWithContext;
+use std::sync::Arc;
+use uuid::Uuid;
+
+struct Mocks {
+    two_factor_repo: MockTwoFactorRepository,
+    recovery_code_repo: MockRecoveryCodeRepository,
+    challenge_repo: MockTwoFactorChallengeRepository,
+    provider: MockTwoFactorProvider,
+}
+
+impl Mocks {
+    fn new() -> Self {
+        Self {
+            two_factor_repo: MockTwoFactorRepository::new(),
+            recovery_code_repo: MockRecoveryCodeRepository::new(),
+            challenge_repo: MockTwoFactorChallengeRepository::new(),
+            provider: MockTwoFactorProvider::new(),
+        }
+    }
+
+    fn into_service(self) -> TwoFactorService {
+        let mut opaque = MockOpaqueTokenGenerator::new();
+        opaque
+            .expect_generate()
+            .returning(|| "challenge".to_string());
+        opaque
+            .expect_hash()
+            .returning(|token| format!("hash_{}", token));
+
+        TwoFactorService::new(
+            Arc::new(self.two_factor_repo),
+            Arc::new(self.recovery_code_repo),
+            Arc::new(self.challenge_repo),
+            Arc::new(self.provider),
+            Arc::new(opaque),
+            300,
+        )
+    }
+}
+
+/// Company requiring two-factor authentication from the given role level
+fn company_requiring(two_factor_required_level: Option<i16>) -> Company {
+    Company {
+        two_factor_required_level,
+        ..create_company()
+    }
+}
+
+fn create_two_factor(user_id: Uuid, enabled: bool) -> TwoFactor {
+    TwoFactor {
+        user_id,
+        secret: "SECRET".to_string(),
+        enabled_at: if enabled { Some(Utc::now()) } else { None },
+        created_at: Utc::now(),
+    }
+}
+
+#[tokio::test]
+async fn test_start_enrollment_forbidden_below_supervisor() {
+    let mut mocks = Mocks::new();
+    mocks.two_factor_repo.expect_create().never();
+
+    let result = mocks
+        .into_service()
+        .start_enrollment(&create_user("user", 10, Some(create_company())))
+        .await;
+
+    assert!(matches!(result, Err(AppError::Forbidden)));
+}
+
+#[tokio::test]
+async fn test_start_enrollment_returns_secret_and_recovery_codes() {
+    let mut mocks = Mocks::new();
+    let user = create_user("supervisor", 50, Some(create_company()));
+    let user_id = user.id;
+
+    mocks
+        .two_factor_repo
+        .expect_get_by_id()
+        .returning(|_| Ok(None));
+    mocks
+        .two_factor_repo
+        .expect_create()
+        .withf(move |two_factor| {
+            two_factor.user_id == user_id
+                && two_factor.secret == "SECRET"
+                && !two_factor.is_enabled()
+        })
+        .times(1)
+        .returning(Ok);
+
+    mocks
+        .provider
+        .expect_generate_secret()
+        .returning(|| "SECRET".to_string());
+    mocks
+        .provider
+        .expect_provisioning_uri()
+        .with(eq("SECRET"), eq("supervisor"))
+        .returning(|_, _| Ok("otpauth://totp/SmartPotatoLeaf:supervisor".to_string()));
+    mocks
+        .provider
+        .expect_generate_recovery_code()
+        .returning(|| "ABCDE-FGHJK".to_string());
+
+    mocks
+        .recovery_code_repo
+        .expect_delete_by_user_id()
+        .times(1)
+        .returning(|_| Ok(0));
+    mocks
+        .recovery_code_repo
+        .expect_create()
+        .withf(|code| code.code_hash == "hash_ABCDE-FGHJK")
+        .times(10)
+        .returning(Ok);
+
+    let setup = mocks.into_service().start_enrollment(&user).await.unwrap();
+
+    assert_eq!(setup.secret, "SECRET");
+    assert_eq!(
+        setup.provisioning_uri,
+        "otpauth://totp/SmartPotatoLeaf:supervisor"
+    );
+    assert_eq!(setup.recovery_codes.len(), 10);
+}
+
+#[tokio::test]
+async fn test_start_enrollment_conflicts_when_enabled() {
+    let mut mocks = Mocks::new();
+
+    mocks
+        .two_factor_repo
+        .expect_get_by_id()
+        .returning(|id| Ok(Some(create_two_factor(id, true))));
+    mocks.two_factor_repo.expect_create().never();
+
+    let result = mocks
+        .into_service()
+        .start_enrollment(&create_user("admin", 100, Some(create_company())))
+        .await;
+
+    assert!(matches!(result, Err(AppError::Conflict(_))));
+}
+
+#[tokio::test]
+async fn test_confirm_enrollment_enables_with_valid_code() {
+    let mut mocks = Mocks::new();
+    let user = create_user("supervisor", 50, Some(create_company()));
+
+    mocks
+        .two_factor_repo
+        .expect_get_by_id()
+        .returning(|id| Ok(Some(create_two_factor(id, false))));
+    mocks
+        .provider
+        .expect_verify()
+        .with(eq("SECRET"), eq("123456"))
+        .returning(|_, _| Ok(true));
+    mocks
+        .two_factor_repo
+        .expect_enable()
+        .with(eq(user.id))
+        .times(1)
+        .returning(|_| Ok(true));
+
+    let result = mocks
+        .into_service()
+        .confirm_enrollment(&user, "123456")
+        .await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_disable_refused_when_mandatory() {
+    let mut mocks = Mocks::new();
+    mocks.two_factor_repo.expect_delete().never();
+
+    let result = mocks
+        .into_service()
+        .disable(
+            &create_user("supervisor", 50, Some(company_requiring(Some(50)))),
+            "123456",
+        )
+        .await;
+
+    assert!(matches!(result, Err(AppError::ValidationError(_))));
+}
+
+#[tokio::test]
+async fn test_verify_login_code_accepts_unused_recovery_code_once() {
+    let mut mocks = Mocks::new();
+    let user = create_user("supervisor", 50, Some(create_company()));
+    let recovery_code = RecoveryCode {
+        id: Uuid::new_v4(),
+        user_id: user.id,
+        code_hash: "hash_ABCDE-FGHJK".to_string(),
+        used_at: None,
+        created_at: Utc::now(),
+    };
+    let recovery_code_id = recovery_code.id;
+
+    mocks
+        .two_factor_repo
+        .expect_get_by_id()
+        .returning(|id| Ok(Some(create_two_factor(id, true))));
+    mocks.provider.expect_verify().returning(|_, _| Ok(false));
+    mocks
+        .recovery_code_repo
+        .expect_get_by_code_hash()
+        .with(eq(user.id), eq("hash_ABCDE-FGHJK"))
+        .returning(move |_, _| Ok(Some(recovery_code.clone())));
+    mocks
+        .recovery_code_repo
+        .expect_mark_used()
+        .with(eq(recovery_code_id))
+        .times(1)
+        .returning(|_| Ok(true));
+
+    let verified = mocks
+        .into_service()
+        .verify_login_code(&user, " abcde-fghjk ")
+        .await
+        .unwrap();
+
+    assert!(verified);
+}
+
+#[tokio::test]
+async fn test_verify_login_code_completes_mandatory_enrollment() {
+    let mut mocks = Mocks::new();
+    let user = create_user("supervisor", 50, Some(company_requiring(Some(50))));
+
+    mocks
+        .two_factor_repo
+        .expect_get_by_id()
+        .returning(|id| Ok(Some(create_two_factor(id, false))));
+    mocks.provider.expect_verify().returning(|_, _| Ok(true));
+    mocks
+        .two_factor_repo
+        .expect_enable()
+        .times(1)
+        .returning(|_| Ok(true));
+
+    let verified = mocks
+        .into_service()
+        .verify_login_code(&user, "123456")
+        .await
+        .unwrap();
+
+    assert!(verified);
+}
+
+#[tokio::test]
+async fn test_create_challenge_for_mandatory_user_without_enrollment() {
+    let mut mocks = Mocks::new();
+
+    mocks
+        .two_factor_repo
+        .expect_get_by_id()
+        .returning(|_| Ok(None));
+    mocks
+        .challenge_repo
+        .expect_create()
+        .withf(|challenge| challenge.token_hash == "hash_challenge")
+        .times(1)
+        .returning(Ok);
+
+    let challenge = mocks
+        .into_service()
+        .create_challenge(&create_user(
+            "admin",
+            100,
+            Some(company_requiring(Some(50))),
+        ))
+        .await
+        .unwrap()
+        .expect("challenge");
+
+    assert_eq!(challenge.challenge_token, "challenge");
+    assert!(challenge.setup_required);
+}
+
+#[test]
+fn test_company_two_factor_level_below_supervisor_is_rejected() {
+    let update = UpdateCompanyDto {
+        name: None,
+        description: None,
+        two_factor_required_level: Some(10),
+    };
+
+    let result: Result<Company> = update.into_with_context(create_company());
+    assert!(matches!(result, Err(AppError::ValidationError(_))));
+
+    let update = UpdateCompanyDto {
+        name: None,
+        description: None,
+        two_factor_required_level: Some(0),
+    };
+
+    let company: Company = update
+        .into_with_context(company_requiring(Some(50)))
+        .unwrap();
+    assert_eq!(company.two_factor_required_level, None);
+}
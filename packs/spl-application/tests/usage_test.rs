@@ -0,0 +1,373 @@
+mod common;
+
+use chrono::{Datelike, Utc};
+use common::mocks::{
+    MockCompanyQuotaRepository, MockCompanyRepository, MockPermissionRepository,
+    MockTeamRepository, MockUsageRepository,
+};
+use common::{create_company, create_user, grant};
+use mockall::predicate::*;
+use spl_application::dtos::usage::UpdateCompanyQuotaDto;
+use spl_application::services::access_control::AccessControlService;
+use spl_application::services::policy::PolicyService;
+use spl_application::services::usage::UsageService;
+use spl_domain::entities::usage::{
+    period_of, CompanyQuota, QuotaEnforcement, Usage, UsageQuota, UsageRecord, UsageStatus,
+};
+use spl_domain::entities::user::{permissions, PermissionScope};
+use spl_shared::error::AppError;
+use std::sync::Arc;
+use uuid::Uuid;
+
+struct Mocks {
+    usage_repo: MockUsageRepository,
+    quota_repo: MockCompanyQuotaRepository,
+    company_repo: MockCompanyRepository,
+    server_quota: UsageQuota,
+}
+
+impl Mocks {
+    fn new() -> Self {
+        let mut quota_repo = MockCompanyQuotaRepository::new();
+        quota_repo.expect_get_by_id().returning(|_| Ok(None));
+
+        let mut usage_repo = MockUsageRepository::new();
+        usage_repo.expect_get_by_user().returning(|_, _| Ok(None));
+
+        Self {
+            usage_repo,
+            quota_repo,
+            company_repo: MockCompanyRepository::new(),
+            server_quota: quota(None, None, None, QuotaEnforcement::Hard),
+        }
+    }
+
+    fn into_service(self) -> UsageService {
+        let mut permission_repo = MockPermissionRepository::new();
+        permission_repo.expect_get_grants().returning(|| {
+            Ok(vec![
+                grant("admin", permissions::USAGE_READ, PermissionScope::Any),
+                grant("admin", permissions::COMPANIES_MANAGE, PermissionScope::Any),
+                grant(
+                    "supervisor",
+                    permissions::USAGE_READ,
+                    PermissionScope::Company,
+                ),
+            ])
+        });
+
+        let company_repo = Arc::new(self.company_repo);
+        let access_control = Arc::new(AccessControlService::new(
+            company_repo.clone(),
+            Arc::new(MockTeamRepository::new()),
+            Arc::new(PolicyService::new(Arc::new(permission_repo), 30)),
+        ));
+
+        UsageService::new(
+            Arc::new(self.usage_repo),
+            Arc::new(self.quota_repo),
+            company_repo,
+            access_control,
+            self.server_quota,
+        )
+    }
+}
+
+fn quota(
+    predictions: Option<u64>,
+    per_user: Option<u64>,
+    stored_bytes: Option<u64>,
+    enforcement: QuotaEnforcement,
+) -> UsageQuota {
+    UsageQuota {
+        predictions_per_month: predictions,
+        predictions_per_user_per_month: per_user,
+        stored_bytes_per_month: stored_bytes,
+        warning_ratio: 0.8,
+        enforcement,
+    }
+}
+
+fn record(user_id: Uuid, company_id: Option<Uuid>, predictions: u64, bytes: u64) -> UsageRecord {
+    UsageRecord {
+        user_id,
+        company_id,
+        period: period_of(Utc::now()),
+        predictions,
+        stored_bytes: bytes,
+        updated_at: Utc::now(),
+    }
+}
+
+/// Reserves against fixed usage of the user and of its company
+fn reserve_with(usage_repo: &mut MockUsageRepository, user: Usage, company: Usage) {
+    usage_repo
+        .expect_reserve()
+        .returning(move |_, company_id, _, bytes, quota| {
+            Ok(quota.limit_reached(&user, company_id.map(|_| &company), bytes))
+        });
+}
+
+fn usage(predictions: u64, stored_bytes: u64) -> Usage {
+    Usage {
+        predictions,
+        stored_bytes,
+    }
+}
+
+#[tokio::test]
+async fn test_hard_quota_rejects_prediction_until_next_month() {
+    let company = create_company();
+    let mut mocks = Mocks::new();
+    mocks.server_quota = quota(Some(10), None, None, QuotaEnforcement::Hard);
+    reserve_with(&mut mocks.usage_repo, usage(6, 0), usage(10, 0));
+    let service = mocks.into_service();
+    let user = create_user("user", 10, Some(company));
+
+    let result = service.reserve_prediction(&user, 1024).await;
+
+    match result {
+        Err(AppError::QuotaExceeded { resets_at, .. }) => {
+            assert_eq!(resets_at.day(), 1);
+            assert!(resets_at > Utc::now());
+        }
+        other => panic!("Expected QuotaExceeded, got {:?}", other.err()),
+    }
+}
+
+#[tokio::test]
+async fn test_soft_quota_lets_prediction_through() {
+    let company = create_company();
+    let company_id = company.id;
+    let user = create_user("user", 10, Some(company));
+    let user_id = user.id;
+    let mut mocks = Mocks::new();
+    mocks.server_quota = quota(Some(10), None, None, QuotaEnforcement::Soft);
+    reserve_with(&mut mocks.usage_repo, usage(0, 0), usage(12, 0));
+    // Nothing was reserved over the quota, so the prediction is counted anyway
+    mocks
+        .usage_repo
+        .expect_increment()
+        .with(
+            eq(user_id),
+            eq(Some(company_id)),
+            eq(period_of(Utc::now())),
+            eq(1),
+            eq(1024),
+        )
+        .times(1)
+        .returning(|_, _, _, _, _| Ok(()));
+    let service = mocks.into_service();
+
+    assert!(service.reserve_prediction(&user, 1024).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_user_quota_applies_without_company() {
+    let user = create_user("admin", 100, None);
+    let user_id = user.id;
+    let mut mocks = Mocks::new();
+    mocks.server_quota = quota(None, Some(5), None, QuotaEnforcement::Hard);
+    mocks
+        .usage_repo
+        .expect_reserve()
+        .with(eq(user_id), eq(None), always(), eq(1024), always())
+        .returning(|_, _, _, bytes, quota| Ok(quota.limit_reached(&usage(5, 0), None, bytes)));
+    let service = mocks.into_service();
+
+    let result = service.reserve_prediction(&user, 1024).await;
+
+    assert!(matches!(result, Err(AppError::QuotaExceeded { .. })));
+}
+
+#[tokio::test]
+async fn test_company_quota_replaces_server_quota() {
+    let company = create_company();
+    let mut mocks = Mocks::new();
+    mocks.server_quota = quota(Some(1), None, None, QuotaEnforcement::Hard);
+    mocks.quota_repo = MockCompanyQuotaRepository::new();
+    mocks.quota_repo.expect_get_by_id().returning(|id| {
+        Ok(Some(CompanyQuota {
+            company_id: id,
+            quota: quota(None, None, Some(2048), QuotaEnforcement::Hard),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }))
+    });
+    reserve_with(&mut mocks.usage_repo, usage(50, 0), usage(50, 1024));
+    let service = mocks.into_service();
+    let user = create_user("user", 10, Some(company));
+
+    // Predictions are unlimited for the company, but its storage is not
+    assert!(service.reserve_prediction(&user, 1024).await.is_ok());
+    let result = service.reserve_prediction(&user, 1025).await;
+    assert!(matches!(result, Err(AppError::QuotaExceeded { .. })));
+}
+
+#[tokio::test]
+async fn test_record_prediction_counts_stored_bytes_beyond_reservation() {
+    let company = create_company();
+    let company_id = company.id;
+    let user = create_user("user", 10, Some(company));
+    let user_id = user.id;
+    let mut mocks = Mocks::new();
+    reserve_with(&mut mocks.usage_repo, usage(0, 0), usage(0, 0));
+    mocks
+        .usage_repo
+        .expect_increment()
+        .with(
+            eq(user_id),
+            eq(Some(company_id)),
+            eq(period_of(Utc::now())),
+            eq(0),
+            eq(3072),
+        )
+        .times(1)
+        .returning(|_, _, _, _, _| Ok(()));
+    let service = mocks.into_service();
+
+    let reservation = service.reserve_prediction(&user, 1024).await.unwrap();
+    assert_eq!(reservation.company_id, Some(company_id));
+    service.record_prediction(reservation, 4096).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_release_takes_back_reserved_prediction() {
+    let user = create_user("user", 10, Some(create_company()));
+    let user_id = user.id;
+    let mut mocks = Mocks::new();
+    reserve_with(&mut mocks.usage_repo, usage(0, 0), usage(0, 0));
+    mocks
+        .usage_repo
+        .expect_release()
+        .with(eq(user_id), eq(period_of(Utc::now())), eq(1), eq(1024))
+        .times(1)
+        .returning(|_, _, _, _| Ok(()));
+    let service = mocks.into_service();
+
+    let reservation = service.reserve_prediction(&user, 1024).await.unwrap();
+    service.release(reservation).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_report_sums_users_and_flags_warning() {
+    let company = create_company();
+    let company_id = company.id;
+    let mut mocks = Mocks::new();
+    mocks.server_quota = quota(Some(100), Some(50), None, QuotaEnforcement::Hard);
+    let found = company.clone();
+    mocks
+        .company_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(found.clone())));
+    mocks
+        .usage_repo
+        .expect_get_by_company()
+        .returning(move |_, _| {
+            Ok(vec![
+                record(Uuid::new_v4(), Some(company_id), 50, 2048),
+                record(Uuid::new_v4(), Some(company_id), 35, 1024),
+            ])
+        });
+    let service = mocks.into_service();
+    let supervisor = create_user("supervisor", 50, Some(company));
+
+    let report = service.report(&supervisor, company_id, None).await.unwrap();
+
+    assert_eq!(report.usage.predictions, 85);
+    assert_eq!(report.usage.stored_bytes, 3072);
+    assert_eq!(report.status, UsageStatus::Warning);
+    assert_eq!(report.users[0].status, UsageStatus::Exceeded);
+    assert_eq!(report.users[1].status, UsageStatus::Ok);
+    assert_eq!(report.period, period_of(Utc::now()));
+}
+
+#[tokio::test]
+async fn test_supervisor_cannot_read_usage_of_other_company() {
+    let service = Mocks::new().into_service();
+    let supervisor = create_user("supervisor", 50, Some(create_company()));
+
+    let result = service.report(&supervisor, Uuid::new_v4(), None).await;
+
+    assert!(matches!(result, Err(AppError::Forbidden)));
+}
+
+#[tokio::test]
+async fn test_only_admins_set_company_quota() {
+    let company = create_company();
+    let company_id = company.id;
+    let service = Mocks::new().into_service();
+    let supervisor = create_user("supervisor", 50, Some(company));
+
+    let result = service
+        .set_company_quota(&supervisor, company_id, UpdateCompanyQuotaDto::default())
+        .await;
+
+    assert!(matches!(result, Err(AppError::Forbidden)));
+}
+
+#[tokio::test]
+async fn test_set_company_quota_rejects_unknown_enforcement() {
+    let company = create_company();
+    let company_id = company.id;
+    let mut mocks = Mocks::new();
+    mocks
+        .company_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(company.clone())));
+    mocks.quota_repo.expect_create().never();
+    let service = mocks.into_service();
+    let admin = create_user("admin", 100, None);
+
+    let result = service
+        .set_company_quota(
+            &admin,
+            company_id,
+            UpdateCompanyQuotaDto {
+                predictions_per_month: Some(100),
+                enforcement: Some("strict".to_string()),
+                ..Default::default()
+            },
+        )
+        .await;
+
+    assert!(matches!(result, Err(AppError::ValidationError(_))));
+}
+
+#[tokio::test]
+async fn test_set_company_quota_falls_back_to_server_ratio_and_enforcement() {
+    let company = create_company();
+    let company_id = company.id;
+    let mut mocks = Mocks::new();
+    mocks.server_quota = quota(None, None, None, QuotaEnforcement::Soft);
+    mocks
+        .company_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(company.clone())));
+    mocks
+        .quota_repo
+        .expect_create()
+        .withf(|company| {
+            company.quota.predictions_per_month == Some(100)
+                && company.quota.enforcement == QuotaEnforcement::Soft
+                && company.quota.warning_ratio == 0.8
+        })
+        .times(1)
+        .returning(Ok);
+    let service = mocks.into_service();
+    let admin = create_user("admin", 100, None);
+
+    let company_quota = service
+        .set_company_quota(
+            &admin,
+            company_id,
+            UpdateCompanyQuotaDto {
+                predictions_per_month: Some(100),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(company_quota.company_id, company_id);
+}
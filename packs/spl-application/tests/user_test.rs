@@ -1,16 +1,34 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use mockall::mock;
 use mockall::predicate::*;
-use spl_application::dtos::user::CreateUserDto;
+use spl_application::dtos::user::{ChangePasswordDto, CreateUserDto, UpdateProfileDto};
 use spl_application::services::access_control::AccessControlService;
+use spl_application::services::email_verification::EmailVerificationService;
+use spl_application::services::password_policy::PasswordPolicyService;
+use spl_application::services::policy::PolicyService;
 use spl_application::services::user::UserService;
+use spl_domain::entities::auth::{
+    CompanyPasswordPolicy, EmailVerificationToken, PasswordPolicy, Session,
+};
 use spl_domain::entities::company::Company;
-use spl_domain::entities::user::{Role, User};
-use spl_domain::ports::auth::PasswordEncoder;
+use spl_domain::entities::team::{Team, TeamMember};
+use spl_domain::entities::user::{
+    permissions, PermissionGrant, PermissionScope, Role, RolePermission, User,
+};
+use spl_domain::ports::auth::{BreachedPasswordList, OpaqueTokenGenerator, PasswordEncoder};
+use spl_domain::ports::cache::UserCache;
+use spl_domain::ports::integrations::IntegrationClient;
+use spl_domain::ports::mailer::{EmailMessage, Mailer};
+use spl_domain::ports::repositories::auth::{
+    CompanyPasswordPolicyRepository, EmailVerificationTokenRepository, PasswordHistoryRepository,
+    SessionRepository,
+};
 use spl_domain::ports::repositories::company::CompanyRepository;
 use spl_domain::ports::repositories::crud::CrudRepository;
-use spl_domain::ports::repositories::user::{RoleRepository, UserRepository};
-use spl_shared::error::Result;
+use spl_domain::ports::repositories::team::TeamRepository;
+use spl_domain::ports::repositories::user::{PermissionRepository, RoleRepository, UserRepository};
+use spl_shared::error::{AppError, Result};
 use std::sync::Arc;
 use uuid::Uuid;
 
@@ -19,6 +37,17 @@ const ROLE_SUPERVISOR_ID: i32 = 2;
 const ROLE_USER_ID: i32 = 3;
 
 // Mock definitions
+mock! {
+    pub UserCache {}
+    #[async_trait]
+    impl UserCache for UserCache {
+        async fn get(&self, id: Uuid) -> Option<User>;
+        async fn set(&self, user: &User);
+        async fn invalidate(&self, id: Uuid);
+        async fn clear(&self);
+    }
+}
+
 mock! {
     pub UserRepository {}
     #[async_trait]
@@ -34,6 +63,26 @@ mock! {
         async fn get_by_username_and_company(&self, username: &str, company_id: Option<Uuid>) -> Result<Option<User>>;
         async fn get_by_username_or_email_and_company(&self, username: Option<String>, email: Option<String>, company_id: Option<Uuid>) -> Result<Option<User>>;
         async fn get_by_company_id(&self, company_id: Uuid) -> Result<Vec<User>> ;
+        async fn count_by_role_id(&self, role_id: i32) -> Result<u64>;
+        async fn count_active_by_role_ids(&self, role_ids: Vec<i32>) -> Result<u64>;
+    }
+}
+
+mock! {
+    pub SessionRepository {}
+    #[async_trait]
+    impl CrudRepository<Session, Uuid> for SessionRepository {
+        async fn get_by_id(&self, id: Uuid) -> Result<Option<Session>>;
+        async fn create(&self, entity: Session) -> Result<Session>;
+        async fn update(&self, entity: Session) -> Result<Session>;
+        async fn delete(&self, id: Uuid) -> Result<Session>;
+    }
+    #[async_trait]
+    impl SessionRepository for SessionRepository {
+        async fn revoke(&self, id: Uuid) -> Result<bool>;
+        async fn revoke_by_user_id(&self, user_id: Uuid) -> Result<u64>;
+        async fn get_active_by_user_id(&self, user_id: Uuid) -> Result<Vec<Session>>;
+        async fn touch(&self, id: Uuid, seen_at: DateTime<Utc>, ip_address: Option<String>) -> Result<()>;
     }
 }
 
@@ -73,9 +122,210 @@ mock! {
     #[async_trait]
     impl CompanyRepository for CompanyRepository {
         async fn get_all(&self) -> Result<Vec<Company>>;
+        async fn get_by_parent_ids(&self, parent_ids: Vec<Uuid>) -> Result<Vec<Company>>;
+    }
+}
+
+mock! {
+    pub TeamRepository {}
+    #[async_trait]
+    impl CrudRepository<Team, Uuid> for TeamRepository {
+        async fn get_by_id(&self, id: Uuid) -> Result<Option<Team>>;
+        async fn create(&self, entity: Team) -> Result<Team>;
+        async fn update(&self, entity: Team) -> Result<Team>;
+        async fn delete(&self, id: Uuid) -> Result<Team>;
+    }
+    #[async_trait]
+    impl TeamRepository for TeamRepository {
+        async fn get_by_company_id(&self, company_id: Uuid) -> Result<Vec<Team>>;
+        async fn get_supervised_by(&self, user_id: Uuid) -> Result<Vec<Team>>;
+        async fn get_members(&self, team_ids: Vec<Uuid>) -> Result<Vec<TeamMember>>;
+        async fn set_member(&self, member: TeamMember) -> Result<TeamMember>;
+        async fn remove_member(&self, team_id: Uuid, user_id: Uuid) -> Result<()>;
+        async fn get_plot_ids(&self, team_ids: Vec<Uuid>) -> Result<Vec<Uuid>>;
+        async fn add_plot(&self, team_id: Uuid, plot_id: Uuid) -> Result<()>;
+        async fn remove_plot(&self, team_id: Uuid, plot_id: Uuid) -> Result<()>;
+    }
+}
+
+mock! {
+    pub PermissionRepository {}
+    #[async_trait]
+    impl PermissionRepository for PermissionRepository {
+        async fn get_grants(&self) -> Result<Vec<RolePermission>>;
+        async fn get_by_role_id(&self, role_id: i32) -> Result<Vec<RolePermission>>;
+        async fn set_role_grants(&self, role_id: i32, grants: Vec<PermissionGrant>) -> Result<()>;
+    }
+}
+
+mock! {
+    pub CompanyPasswordPolicyRepository {}
+    #[async_trait]
+    impl CrudRepository<CompanyPasswordPolicy, Uuid> for CompanyPasswordPolicyRepository {
+        async fn get_by_id(&self, company_id: Uuid) -> Result<Option<CompanyPasswordPolicy>>;
+        async fn create(&self, entity: CompanyPasswordPolicy) -> Result<CompanyPasswordPolicy>;
+        async fn update(&self, entity: CompanyPasswordPolicy) -> Result<CompanyPasswordPolicy>;
+        async fn delete(&self, company_id: Uuid) -> Result<CompanyPasswordPolicy>;
+    }
+    #[async_trait]
+    impl CompanyPasswordPolicyRepository for CompanyPasswordPolicyRepository {}
+}
+
+mock! {
+    pub PasswordHistoryRepository {}
+    #[async_trait]
+    impl PasswordHistoryRepository for PasswordHistoryRepository {
+        async fn get_recent(&self, user_id: Uuid, limit: u64) -> Result<Vec<String>>;
+        async fn add(&self, user_id: Uuid, password_hash: &str) -> Result<()>;
+        async fn prune(&self, user_id: Uuid, keep: u64) -> Result<u64>;
+    }
+}
+
+mock! {
+    pub BreachedPasswordList {}
+    impl BreachedPasswordList for BreachedPasswordList {
+        fn contains(&self, password: &str) -> bool;
+    }
+}
+
+mock! {
+    pub EmailVerificationTokenRepository {}
+    #[async_trait]
+    impl CrudRepository<EmailVerificationToken, Uuid> for EmailVerificationTokenRepository {
+        async fn get_by_id(&self, id: Uuid) -> Result<Option<EmailVerificationToken>>;
+        async fn create(&self, entity: EmailVerificationToken) -> Result<EmailVerificationToken>;
+        async fn update(&self, entity: EmailVerificationToken) -> Result<EmailVerificationToken>;
+        async fn delete(&self, id: Uuid) -> Result<EmailVerificationToken>;
+    }
+    #[async_trait]
+    impl EmailVerificationTokenRepository for EmailVerificationTokenRepository {
+        async fn get_by_token_hash(&self, token_hash: &str) -> Result<Option<EmailVerificationToken>>;
+        async fn mark_used(&self, id: Uuid) -> Result<bool>;
+        async fn invalidate_by_user_id(&self, user_id: Uuid) -> Result<u64>;
+    }
+}
+
+mock! {
+    pub OpaqueTokenGenerator {}
+    impl OpaqueTokenGenerator for OpaqueTokenGenerator {
+        fn generate(&self) -> String;
+        fn hash(&self, token: &str) -> String;
     }
 }
 
+mock! {
+    pub Mailer {}
+    #[async_trait]
+    impl IntegrationClient for Mailer {
+        fn name(&self) -> &'static str;
+        async fn health_check(&self) -> Result<()>;
+    }
+    #[async_trait]
+    impl Mailer for Mailer {
+        async fn send(&self, message: EmailMessage) -> Result<()>;
+    }
+}
+
+fn grant(role: &str, permission: &str, scope: PermissionScope) -> RolePermission {
+    RolePermission {
+        role_id: 0,
+        role: role.to_string(),
+        permission: permission.to_string(),
+        scope,
+    }
+}
+
+fn policy() -> Arc<PolicyService> {
+    let mut permission_repo = MockPermissionRepository::new();
+    permission_repo.expect_get_grants().returning(|| {
+        Ok(vec![
+            grant("admin", permissions::USERS_READ, PermissionScope::Any),
+            grant("admin", permissions::USERS_MANAGE, PermissionScope::Any),
+            grant(
+                "supervisor",
+                permissions::USERS_READ,
+                PermissionScope::Company,
+            ),
+            grant(
+                "supervisor",
+                permissions::USERS_MANAGE,
+                PermissionScope::Company,
+            ),
+        ])
+    });
+    Arc::new(PolicyService::new(Arc::new(permission_repo), 30))
+}
+
+/// Server rules only, without history nor breached passwords
+fn password_policy(min_length: u16) -> Arc<PasswordPolicyService> {
+    let mut policy_repo = MockCompanyPasswordPolicyRepository::new();
+    policy_repo.expect_get_by_id().returning(|_| Ok(None));
+
+    let mut history_repo = MockPasswordHistoryRepository::new();
+    history_repo.expect_prune().returning(|_, _| Ok(0));
+
+    let mut breached_passwords = MockBreachedPasswordList::new();
+    breached_passwords.expect_contains().returning(|_| false);
+
+    let company_repo = Arc::new(MockCompanyRepository::new());
+    let access_control = Arc::new(AccessControlService::new(
+        company_repo.clone(),
+        Arc::new(MockTeamRepository::new()),
+        policy(),
+    ));
+
+    Arc::new(PasswordPolicyService::new(
+        Arc::new(policy_repo),
+        Arc::new(history_repo),
+        company_repo,
+        Arc::new(breached_passwords),
+        Arc::new(MockPasswordEncoder::new()),
+        access_control,
+        PasswordPolicy {
+            min_length,
+            require_uppercase: false,
+            require_lowercase: false,
+            require_digit: false,
+            require_symbol: false,
+            history_size: 0,
+        },
+    ))
+}
+
+/// Mailer accepting every email
+fn mailer() -> MockMailer {
+    let mut mailer = MockMailer::new();
+    mailer.expect_send().returning(|_| Ok(()));
+    mailer
+}
+
+/// Verification links are sent through `mailer`
+fn email_verification(mailer: MockMailer) -> Arc<EmailVerificationService> {
+    let mut token_repo = MockEmailVerificationTokenRepository::new();
+    token_repo
+        .expect_invalidate_by_user_id()
+        .returning(|_| Ok(0));
+    token_repo.expect_create().returning(Ok);
+
+    let mut token_generator = MockOpaqueTokenGenerator::new();
+    token_generator
+        .expect_generate()
+        .returning(|| "token".to_string());
+    token_generator
+        .expect_hash()
+        .returning(|token| format!("hash-{token}"));
+
+    Arc::new(EmailVerificationService::new(
+        Arc::new(MockUserRepository::new()),
+        Arc::new(token_repo),
+        Arc::new(token_generator),
+        Arc::new(mailer),
+        Arc::new(MockUserCache::new()),
+        None,
+        48,
+    ))
+}
+
 #[tokio::test]
 async fn test_create_user_admin_creates_admin_success() {
     let mut mock_repo = MockUserRepository::new();
@@ -89,6 +339,7 @@ async fn test_create_user_admin_creates_admin_success() {
         id: Uuid::new_v4(),
         username: "admin".to_string(),
         email: Some("admin@example.com".to_string()),
+        email_verified_at: None,
         password_hash: "hash".to_string(),
         name: None,
         surname: None,
@@ -100,6 +351,7 @@ async fn test_create_user_admin_creates_admin_success() {
             updated_at: chrono::Utc::now(),
         },
         company: None,
+        deactivated_at: None,
         created_at: chrono::Utc::now(),
         updated_at: chrono::Utc::now(),
     };
@@ -108,6 +360,7 @@ async fn test_create_user_admin_creates_admin_success() {
         id: Uuid::new_v4(),
         username: "newadmin".to_string(),
         email: Some("new@example.com".to_string()),
+        email_verified_at: None,
         password_hash: "hashed".to_string(),
         name: None,
         surname: None,
@@ -119,6 +372,7 @@ async fn test_create_user_admin_creates_admin_success() {
             updated_at: chrono::Utc::now(),
         },
         company: None,
+        deactivated_at: None,
         created_at: chrono::Utc::now(),
         updated_at: chrono::Utc::now(),
     };
@@ -131,6 +385,8 @@ async fn test_create_user_admin_creates_admin_success() {
         id: company_id,
         name: "Sup Corp".to_string(),
         description: None,
+        parent_id: None,
+        two_factor_required_level: None,
         created_at: chrono::Utc::now(),
         updated_at: chrono::Utc::now(),
     };
@@ -139,6 +395,7 @@ async fn test_create_user_admin_creates_admin_success() {
         id: Uuid::new_v4(),
         username: "supervisor".to_string(),
         email: Some("sup@example.com".to_string()),
+        email_verified_at: None,
         password_hash: "hash".to_string(),
         name: None,
         surname: None,
@@ -150,6 +407,7 @@ async fn test_create_user_admin_creates_admin_success() {
             updated_at: chrono::Utc::now(),
         },
         company: Some(supervisor_company.clone()),
+        deactivated_at: None,
         created_at: chrono::Utc::now(),
         updated_at: chrono::Utc::now(),
     };
@@ -158,6 +416,7 @@ async fn test_create_user_admin_creates_admin_success() {
         id: Uuid::new_v4(),
         username: "user".to_string(),
         email: Some("user@example.com".to_string()),
+        email_verified_at: None,
         password_hash: "hashed".to_string(),
         name: None,
         surname: None,
@@ -169,6 +428,7 @@ async fn test_create_user_admin_creates_admin_success() {
             updated_at: chrono::Utc::now(),
         },
         company: Some(supervisor_company.clone()),
+        deactivated_at: None,
         created_at: chrono::Utc::now(),
         updated_at: chrono::Utc::now(),
     };
@@ -217,15 +477,20 @@ async fn test_create_user_admin_creates_admin_success() {
     let mock_company_repo = Arc::new(mock_company_repo);
     let access_control = Arc::new(AccessControlService::new(
         mock_company_repo.clone(),
-        mock_repo.clone(),
+        Arc::new(MockTeamRepository::new()),
+        policy(),
     ));
 
     let service = UserService::new(
         mock_repo,
         Arc::new(mock_role_repo),
         mock_company_repo,
+        Arc::new(MockSessionRepository::new()),
         Arc::new(mock_encoder),
         access_control,
+        password_policy(0),
+        email_verification(mailer()),
+        Arc::new(MockUserCache::new()),
     );
 
     let dto = CreateUserDto {
@@ -255,6 +520,8 @@ async fn test_create_user_supervisor_creates_user_success() {
         id: company_id,
         name: "Sup Corp".to_string(),
         description: None,
+        parent_id: None,
+        two_factor_required_level: None,
         created_at: chrono::Utc::now(),
         updated_at: chrono::Utc::now(),
     };
@@ -263,6 +530,7 @@ async fn test_create_user_supervisor_creates_user_success() {
         id: Uuid::new_v4(),
         username: "supervisor".to_string(),
         email: Some("sup@example.com".to_string()),
+        email_verified_at: None,
         password_hash: "hash".to_string(),
         name: None,
         surname: None,
@@ -274,6 +542,7 @@ async fn test_create_user_supervisor_creates_user_success() {
             updated_at: chrono::Utc::now(),
         },
         company: Some(supervisor_company.clone()),
+        deactivated_at: None,
         created_at: chrono::Utc::now(),
         updated_at: chrono::Utc::now(),
     };
@@ -282,6 +551,7 @@ async fn test_create_user_supervisor_creates_user_success() {
         id: Uuid::new_v4(),
         username: "user".to_string(),
         email: Some("user@example.com".to_string()),
+        email_verified_at: None,
         password_hash: "hashed".to_string(),
         name: None,
         surname: None,
@@ -293,6 +563,7 @@ async fn test_create_user_supervisor_creates_user_success() {
             updated_at: chrono::Utc::now(),
         },
         company: Some(supervisor_company.clone()),
+        deactivated_at: None,
         created_at: chrono::Utc::now(),
         updated_at: chrono::Utc::now(),
     };
@@ -337,6 +608,8 @@ async fn test_create_user_supervisor_creates_user_success() {
                 id: company_id,
                 name: "Sup Corp".to_string(),
                 description: None,
+                parent_id: None,
+                two_factor_required_level: None,
                 created_at: chrono::Utc::now(),
                 updated_at: chrono::Utc::now(),
             }))
@@ -359,15 +632,20 @@ async fn test_create_user_supervisor_creates_user_success() {
     let mock_company_repo = Arc::new(mock_company_repo);
     let access_control = Arc::new(AccessControlService::new(
         mock_company_repo.clone(),
-        mock_repo.clone(),
+        Arc::new(MockTeamRepository::new()),
+        policy(),
     ));
 
     let service = UserService::new(
         mock_repo,
         Arc::new(mock_role_repo),
         mock_company_repo,
+        Arc::new(MockSessionRepository::new()),
         Arc::new(mock_encoder),
         access_control,
+        password_policy(0),
+        email_verification(mailer()),
+        Arc::new(MockUserCache::new()),
     );
 
     let dto = CreateUserDto {
@@ -388,3 +666,384 @@ async fn test_create_user_supervisor_creates_user_success() {
     assert!(created.company.is_some());
     assert_eq!(created.company.unwrap().id, company_id);
 }
+
+fn create_company() -> Company {
+    Company {
+        id: Uuid::new_v4(),
+        name: "Company".to_string(),
+        description: None,
+        parent_id: None,
+        two_factor_required_level: None,
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+    }
+}
+
+fn create_user(role: &str, level: i16, company: Option<Company>) -> User {
+    User {
+        id: Uuid::new_v4(),
+        username: role.to_string(),
+        email: None,
+        email_verified_at: None,
+        password_hash: "hash".to_string(),
+        name: None,
+        surname: None,
+        role: Role {
+            id: level as i32,
+            name: role.to_string(),
+            level,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        },
+        company,
+        deactivated_at: None,
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+    }
+}
+
+fn create_service(
+    user_repo: MockUserRepository,
+    session_repo: MockSessionRepository,
+) -> UserService {
+    let mut user_cache = MockUserCache::new();
+    user_cache.expect_invalidate().returning(|_| ());
+
+    create_cached_service(user_repo, session_repo, user_cache)
+}
+
+fn create_cached_service(
+    user_repo: MockUserRepository,
+    session_repo: MockSessionRepository,
+    user_cache: MockUserCache,
+) -> UserService {
+    create_mailing_service(user_repo, session_repo, user_cache, mailer())
+}
+
+fn create_mailing_service(
+    user_repo: MockUserRepository,
+    session_repo: MockSessionRepository,
+    user_cache: MockUserCache,
+    mailer: MockMailer,
+) -> UserService {
+    let user_repo = Arc::new(user_repo);
+    let company_repo = Arc::new(MockCompanyRepository::new());
+    let access_control = Arc::new(AccessControlService::new(
+        company_repo.clone(),
+        Arc::new(MockTeamRepository::new()),
+        policy(),
+    ));
+
+    UserService::new(
+        user_repo,
+        Arc::new(MockRoleRepository::new()),
+        company_repo,
+        Arc::new(session_repo),
+        Arc::new(MockPasswordEncoder::new()),
+        access_control,
+        password_policy(0),
+        email_verification(mailer),
+        Arc::new(user_cache),
+    )
+}
+
+/// User repository returning `target` for its id and echoing updates
+fn user_repo_with(target: User) -> MockUserRepository {
+    let mut user_repo = MockUserRepository::new();
+    let target_id = target.id;
+    user_repo
+        .expect_get_by_id()
+        .with(eq(target_id))
+        .returning(move |_| Ok(Some(target.clone())));
+    user_repo.expect_update().returning(Ok);
+    user_repo
+}
+
+#[tokio::test]
+async fn test_deactivate_user_keeps_user_and_revokes_sessions() {
+    let admin = create_user("admin", 100, None);
+    let target = create_user("user", 10, Some(create_company()));
+    let target_id = target.id;
+
+    let mut user_repo = user_repo_with(target);
+    user_repo.expect_delete().never();
+
+    let mut session_repo = MockSessionRepository::new();
+    session_repo
+        .expect_revoke_by_user_id()
+        .with(eq(target_id))
+        .times(1)
+        .returning(|_| Ok(2));
+
+    let service = create_service(user_repo, session_repo);
+
+    let user = service.deactivate_user(&admin, target_id).await.unwrap();
+    assert!(!user.is_active());
+    assert!(user.deactivated_at.is_some());
+}
+
+#[tokio::test]
+async fn test_deactivate_own_account_fails() {
+    let admin = create_user("admin", 100, None);
+    let admin_id = admin.id;
+
+    let mut session_repo = MockSessionRepository::new();
+    session_repo.expect_revoke_by_user_id().never();
+
+    let service = create_service(user_repo_with(admin.clone()), session_repo);
+
+    let result = service.deactivate_user(&admin, admin_id).await;
+    assert!(matches!(result, Err(AppError::ValidationError(_))));
+}
+
+#[tokio::test]
+async fn test_deactivate_already_deactivated_user_conflicts() {
+    let admin = create_user("admin", 100, None);
+    let mut target = create_user("user", 10, Some(create_company()));
+    target.deactivated_at = Some(Utc::now());
+    let target_id = target.id;
+
+    let service = create_service(user_repo_with(target), MockSessionRepository::new());
+
+    let result = service.deactivate_user(&admin, target_id).await;
+    assert!(matches!(result, Err(AppError::Conflict(_))));
+}
+
+#[tokio::test]
+async fn test_supervisor_cannot_deactivate_user_of_other_company() {
+    let supervisor = create_user("supervisor", 50, Some(create_company()));
+    let target = create_user("user", 10, Some(create_company()));
+    let target_id = target.id;
+
+    let mut user_repo = MockUserRepository::new();
+    user_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(target.clone())));
+    user_repo.expect_update().never();
+
+    let service = create_service(user_repo, MockSessionRepository::new());
+
+    let result = service.deactivate_user(&supervisor, target_id).await;
+    assert!(matches!(result, Err(AppError::Forbidden)));
+}
+
+#[tokio::test]
+async fn test_reactivate_user_clears_deactivation() {
+    let admin = create_user("admin", 100, None);
+    let mut target = create_user("user", 10, Some(create_company()));
+    target.deactivated_at = Some(Utc::now());
+    let target_id = target.id;
+
+    let service = create_service(user_repo_with(target), MockSessionRepository::new());
+
+    let user = service.reactivate_user(&admin, target_id).await.unwrap();
+    assert!(user.is_active());
+}
+
+#[tokio::test]
+async fn test_reactivate_active_user_conflicts() {
+    let admin = create_user("admin", 100, None);
+    let target = create_user("user", 10, Some(create_company()));
+    let target_id = target.id;
+
+    let service = create_service(user_repo_with(target), MockSessionRepository::new());
+
+    let result = service.reactivate_user(&admin, target_id).await;
+    assert!(matches!(result, Err(AppError::Conflict(_))));
+}
+
+#[tokio::test]
+async fn test_get_by_id_returns_cached_user() {
+    let user = create_user("user", 10, Some(create_company()));
+    let user_id = user.id;
+
+    let mut user_repo = MockUserRepository::new();
+    user_repo.expect_get_by_id().never();
+
+    let mut user_cache = MockUserCache::new();
+    user_cache
+        .expect_get()
+        .with(eq(user_id))
+        .returning(move |_| Some(user.clone()));
+
+    let service = create_cached_service(user_repo, MockSessionRepository::new(), user_cache);
+
+    let found = service.get_by_id(user_id).await.unwrap();
+    assert_eq!(found.map(|u| u.id), Some(user_id));
+}
+
+#[tokio::test]
+async fn test_get_by_id_caches_user_on_miss() {
+    let user = create_user("user", 10, Some(create_company()));
+    let user_id = user.id;
+
+    let mut user_cache = MockUserCache::new();
+    user_cache.expect_get().returning(|_| None);
+    user_cache
+        .expect_set()
+        .withf(move |cached| cached.id == user_id)
+        .times(1)
+        .returning(|_| ());
+
+    let service = create_cached_service(
+        user_repo_with(user),
+        MockSessionRepository::new(),
+        user_cache,
+    );
+
+    let found = service.get_by_id(user_id).await.unwrap();
+    assert!(found.is_some());
+}
+
+#[tokio::test]
+async fn test_deactivate_user_invalidates_cached_user() {
+    let admin = create_user("admin", 100, None);
+    let target = create_user("user", 10, Some(create_company()));
+    let target_id = target.id;
+
+    let mut session_repo = MockSessionRepository::new();
+    session_repo.expect_revoke_by_user_id().returning(|_| Ok(1));
+
+    let mut user_cache = MockUserCache::new();
+    user_cache
+        .expect_invalidate()
+        .with(eq(target_id))
+        .times(1)
+        .returning(|_| ());
+
+    let service = create_cached_service(user_repo_with(target), session_repo, user_cache);
+
+    service.deactivate_user(&admin, target_id).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_change_password_rejects_password_breaking_policy() {
+    let user = create_user("user", 10, None);
+
+    let mut encoder = MockPasswordEncoder::new();
+    encoder.expect_verify().returning(|_, _| Ok(true));
+    // Rejected before hashing or saving
+    encoder.expect_hash().never();
+    let mut user_repo = MockUserRepository::new();
+    let stored = user.clone();
+    user_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(stored.clone())));
+    user_repo.expect_update().never();
+
+    let user_repo = Arc::new(user_repo);
+    let company_repo = Arc::new(MockCompanyRepository::new());
+    let access_control = Arc::new(AccessControlService::new(
+        company_repo.clone(),
+        Arc::new(MockTeamRepository::new()),
+        policy(),
+    ));
+    let service = UserService::new(
+        user_repo,
+        Arc::new(MockRoleRepository::new()),
+        company_repo,
+        Arc::new(MockSessionRepository::new()),
+        Arc::new(encoder),
+        access_control,
+        password_policy(12),
+        email_verification(mailer()),
+        Arc::new(MockUserCache::new()),
+    );
+
+    let result = service
+        .change_password(
+            &user,
+            ChangePasswordDto {
+                current_password: "current-password".to_string(),
+                new_password: "too-short".to_string(),
+            },
+        )
+        .await;
+
+    assert!(
+        matches!(result, Err(AppError::ValidationError(message)) if message.contains("at least 12"))
+    );
+}
+
+fn verified_user() -> User {
+    let mut user = create_user("user", 10, Some(create_company()));
+    user.email = Some("old@example.com".to_string());
+    user.email_verified_at = Some(Utc::now());
+    user
+}
+
+#[tokio::test]
+async fn test_update_profile_new_email_requires_verification() {
+    let user = verified_user();
+
+    let mut user_repo = MockUserRepository::new();
+    let stored = user.clone();
+    user_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(stored.clone())));
+    user_repo.expect_update().times(1).returning(Ok);
+
+    let mut user_cache = MockUserCache::new();
+    user_cache.expect_invalidate().returning(|_| ());
+
+    let mut mailer = MockMailer::new();
+    mailer
+        .expect_send()
+        .withf(|message| message.to == "new@example.com")
+        .times(1)
+        .returning(|_| Ok(()));
+
+    let service =
+        create_mailing_service(user_repo, MockSessionRepository::new(), user_cache, mailer);
+
+    let updated = service
+        .update_profile(
+            &user,
+            UpdateProfileDto {
+                name: None,
+                surname: None,
+                email: Some("new@example.com".to_string()),
+            },
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(updated.email.as_deref(), Some("new@example.com"));
+    assert!(updated.email_verified_at.is_none());
+    assert!(!updated.is_email_verified());
+}
+
+#[tokio::test]
+async fn test_update_profile_same_email_keeps_verification() {
+    let user = verified_user();
+
+    let mut user_repo = MockUserRepository::new();
+    let stored = user.clone();
+    user_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(stored.clone())));
+    user_repo.expect_update().times(1).returning(Ok);
+
+    let mut user_cache = MockUserCache::new();
+    user_cache.expect_invalidate().returning(|_| ());
+
+    let mut mailer = MockMailer::new();
+    mailer.expect_send().never();
+
+    let service =
+        create_mailing_service(user_repo, MockSessionRepository::new(), user_cache, mailer);
+
+    let updated = service
+        .update_profile(
+            &user,
+            UpdateProfileDto {
+                name: Some("New name".to_string()),
+                surname: None,
+                email: Some("old@example.com".to_string()),
+            },
+        )
+        .await
+        .unwrap();
+
+    assert!(updated.is_email_verified());
+}
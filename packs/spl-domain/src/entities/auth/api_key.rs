@@ -0,0 +1,42 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Scopes that can be granted to an API key
+pub mod scopes {
+    pub const PREDICTIONS_READ: &str = "predictions:read";
+    pub const PREDICTIONS_WRITE: &str = "predictions:write";
+    pub const DASHBOARD_READ: &str = "dashboard:read";
+
+    pub const ALL: &[&str] = &[PREDICTIONS_READ, PREDICTIONS_WRITE, DASHBOARD_READ];
+
+    pub fn is_valid(scope: &str) -> bool {
+        ALL.contains(&scope)
+    }
+}
+
+/// API key of a service account. Only the hash of the key is stored,
+/// the prefix is kept to tell keys apart.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ApiKey {
+    pub id: Uuid,
+    pub service_account_id: Uuid,
+    pub name: String,
+    pub key_prefix: String,
+    pub key_hash: String,
+    pub scopes: Vec<String>,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub last_used_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ApiKey {
+    pub fn is_active(&self) -> bool {
+        self.revoked_at.is_none() && self.expires_at.is_none_or(|at| at > Utc::now())
+    }
+
+    pub fn has_scope(&self, scope: &str) -> bool {
+        self.scopes.iter().any(|s| s == scope)
+    }
+}
@@ -0,0 +1,28 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use uuid::Uuid;
+
+/// OpenID Connect identity provider of a company, used for single sign-on.
+/// Each company has at most one provider, so it is keyed by company id.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct IdentityProvider {
+    pub company_id: Uuid,
+    /// Issuer URL, the discovery document is served under `/.well-known/openid-configuration`
+    pub issuer: String,
+    pub client_id: String,
+    pub client_secret: Option<String>,
+    /// Space separated scopes requested at the authorization endpoint
+    pub scopes: String,
+    /// ID token claim holding the groups or roles of the user
+    pub role_claim: String,
+    /// Values of the role claim mapped to SPL role names
+    pub role_mapping: BTreeMap<String, String>,
+    /// Role given when none of the claim values is mapped. Without it those users are rejected.
+    pub default_role: Option<String>,
+    /// Creates unknown users on their first login instead of rejecting them
+    pub auto_provision: bool,
+    pub enabled: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
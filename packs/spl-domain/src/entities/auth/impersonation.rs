@@ -0,0 +1,28 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// An administrator acting as another user. The access token issued for it carries
+/// both users and stops working once the impersonation ends or expires.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Impersonation {
+    pub id: Uuid,
+    /// User who started the impersonation, None once the user was deleted
+    pub actor_id: Option<Uuid>,
+    /// User being impersonated, None once the user was deleted
+    pub subject_id: Option<Uuid>,
+    /// Why the actor needed to act as the subject, e.g. a support ticket
+    pub reason: String,
+    /// Address the impersonation was started from
+    pub ip_address: Option<String>,
+    pub started_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    pub ended_at: Option<DateTime<Utc>>,
+}
+
+impl Impersonation {
+    /// Returns true if the impersonation was not ended and has not expired yet
+    pub fn is_active(&self) -> bool {
+        self.ended_at.is_none() && self.expires_at > Utc::now()
+    }
+}
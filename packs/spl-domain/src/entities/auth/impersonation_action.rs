@@ -0,0 +1,15 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// A request made with the token of an impersonation
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ImpersonationAction {
+    pub id: Uuid,
+    pub impersonation_id: Uuid,
+    pub method: String,
+    pub path: String,
+    /// Status the request was answered with
+    pub status_code: u16,
+    pub created_at: DateTime<Utc>,
+}
@@ -0,0 +1,20 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Failed login tracking for a single account
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LoginAttempts {
+    pub user_id: Uuid,
+    /// Failed attempts since the last lockout (or success)
+    pub failed_attempts: u32,
+    /// Lockouts applied so far, used to grow the lockout duration
+    pub lockout_count: u32,
+    pub locked_until: Option<DateTime<Utc>>,
+}
+
+impl LoginAttempts {
+    pub fn is_locked(&self) -> bool {
+        self.locked_until.is_some_and(|until| until > Utc::now())
+    }
+}
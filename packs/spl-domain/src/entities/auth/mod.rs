@@ -0,0 +1,33 @@
+pub mod api_key;
+pub mod email_verification_token;
+pub mod identity_provider;
+pub mod impersonation;
+pub mod impersonation_action;
+pub mod login_attempts;
+pub mod oidc_login_state;
+pub mod password_policy;
+pub mod password_reset_token;
+pub mod recovery_code;
+pub mod refresh_token;
+pub mod service_account;
+pub mod session;
+pub mod two_factor;
+pub mod two_factor_challenge;
+pub mod user_identity;
+
+pub use api_key::ApiKey;
+pub use email_verification_token::EmailVerificationToken;
+pub use identity_provider::IdentityProvider;
+pub use impersonation::Impersonation;
+pub use impersonation_action::ImpersonationAction;
+pub use login_attempts::LoginAttempts;
+pub use oidc_login_state::OidcLoginState;
+pub use password_policy::{CompanyPasswordPolicy, PasswordPolicy};
+pub use password_reset_token::PasswordResetToken;
+pub use recovery_code::RecoveryCode;
+pub use refresh_token::RefreshToken;
+pub use service_account::ServiceAccount;
+pub use session::Session;
+pub use two_factor::TwoFactor;
+pub use two_factor_challenge::TwoFactorChallenge;
+pub use user_identity::UserIdentity;
@@ -0,0 +1,24 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Pending single sign-on login, created when the user is sent to the identity
+/// provider and consumed by the callback. Only the hash of the `state` is stored.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct OidcLoginState {
+    pub id: Uuid,
+    pub state_hash: String,
+    pub company_id: Uuid,
+    pub nonce: String,
+    /// PKCE code verifier, sent with the authorization code
+    pub code_verifier: String,
+    pub expires_at: DateTime<Utc>,
+    pub used_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl OidcLoginState {
+    pub fn is_usable(&self) -> bool {
+        self.used_at.is_none() && self.expires_at > Utc::now()
+    }
+}
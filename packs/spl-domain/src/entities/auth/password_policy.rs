@@ -0,0 +1,64 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Rules a new password must follow
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PasswordPolicy {
+    pub min_length: u16,
+    pub require_uppercase: bool,
+    pub require_lowercase: bool,
+    pub require_digit: bool,
+    pub require_symbol: bool,
+    /// Previous passwords that cannot be reused, 0 to allow any
+    pub history_size: u16,
+}
+
+impl PasswordPolicy {
+    /// Keeps the strictest value of every rule
+    pub fn strictest(&self, other: &PasswordPolicy) -> PasswordPolicy {
+        PasswordPolicy {
+            min_length: self.min_length.max(other.min_length),
+            require_uppercase: self.require_uppercase || other.require_uppercase,
+            require_lowercase: self.require_lowercase || other.require_lowercase,
+            require_digit: self.require_digit || other.require_digit,
+            require_symbol: self.require_symbol || other.require_symbol,
+            history_size: self.history_size.max(other.history_size),
+        }
+    }
+
+    /// Rules the password breaks, empty when it is accepted. History is checked apart.
+    pub fn violations(&self, password: &str) -> Vec<String> {
+        let mut violations = Vec::new();
+
+        if password.chars().count() < self.min_length as usize {
+            violations.push(format!(
+                "must be at least {} characters long",
+                self.min_length
+            ));
+        }
+        if self.require_uppercase && !password.chars().any(char::is_uppercase) {
+            violations.push("must contain an uppercase letter".to_string());
+        }
+        if self.require_lowercase && !password.chars().any(char::is_lowercase) {
+            violations.push("must contain a lowercase letter".to_string());
+        }
+        if self.require_digit && !password.chars().any(|c| c.is_ascii_digit()) {
+            violations.push("must contain a digit".to_string());
+        }
+        if self.require_symbol && password.chars().all(char::is_alphanumeric) {
+            violations.push("must contain a symbol".to_string());
+        }
+
+        violations
+    }
+}
+
+/// Policy a company adds on top of the server one. Both apply, the strictest rule wins.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompanyPasswordPolicy {
+    pub company_id: Uuid,
+    pub policy: PasswordPolicy,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
@@ -0,0 +1,17 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// An opaque refresh token. Only the hash of the token is stored.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RefreshToken {
+    pub id: Uuid,
+    /// Session (token family) this token belongs to
+    pub session_id: Uuid,
+    /// Hash of the opaque token handed to the client
+    pub token_hash: String,
+    pub expires_at: DateTime<Utc>,
+    /// When the token was exchanged. A second exchange means the token was reused.
+    pub used_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
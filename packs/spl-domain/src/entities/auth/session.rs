@@ -0,0 +1,31 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// A login session. Every refresh token issued after a login belongs to the same
+/// session (token family), so revoking the session invalidates the whole chain.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Session {
+    pub id: Uuid,
+    /// User who owns this session
+    pub user_id: Uuid,
+    /// Absolute expiration of the session, refresh tokens never outlive it
+    pub expires_at: DateTime<Utc>,
+    /// When the session was revoked (logout, reuse detection, admin action)
+    pub revoked_at: Option<DateTime<Utc>>,
+    /// `User-Agent` of the client that logged in
+    pub user_agent: Option<String>,
+    /// Address the session was last used from
+    pub ip_address: Option<String>,
+    /// Company of a membership the session acts in, the company of the user when missing
+    pub active_company_id: Option<Uuid>,
+    pub last_seen_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Session {
+    /// Returns true if the session was not revoked and has not expired yet
+    pub fn is_active(&self) -> bool {
+        self.revoked_at.is_none() && self.expires_at > Utc::now()
+    }
+}
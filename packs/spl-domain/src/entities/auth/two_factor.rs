@@ -0,0 +1,20 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// TOTP credential of a user. It only protects logins once `enabled_at` is set,
+/// i.e. after the user proved the authenticator app was configured.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TwoFactor {
+    pub user_id: Uuid,
+    /// Base32 encoded TOTP secret
+    pub secret: String,
+    pub enabled_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl TwoFactor {
+    pub fn is_enabled(&self) -> bool {
+        self.enabled_at.is_some()
+    }
+}
@@ -1,3 +1,4 @@
+use crate::entities::image::ImageFormat;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
@@ -7,6 +8,97 @@ pub struct Company {
     pub id: Uuid,
     pub name: String,
     pub description: Option<String>,
+    /// Company this one belongs to, e.g. the cooperative of a member farm
+    pub parent_id: Option<Uuid>,
+    /// Minimum role level that must use two-factor authentication, `None` when optional
+    pub two_factor_required_level: Option<i16>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Company {
+    pub fn requires_two_factor(&self, role_level: i16) -> bool {
+        self.two_factor_required_level
+            .is_some_and(|required| role_level >= required)
+    }
+}
+
+/// How a company behaves. Values the company did not change are the server defaults.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CompanySettings {
+    /// IANA time zone dates are presented in, e.g. `America/Lima`
+    pub timezone: String,
+    /// Language of the company, e.g. `es` or `en-US`
+    pub default_language: String,
+    /// Days predictions are kept, 0 to keep them forever
+    pub data_retention_days: u32,
+    /// Minimum role level that must use two-factor authentication, `None` when optional
+    pub two_factor_required_level: Option<i16>,
+    /// Items per page of listings that do not ask for a size
+    pub default_page_size: u64,
+    /// Image formats users of the company can upload
+    pub allowed_upload_formats: Vec<ImageFormat>,
+    /// Severity (0-100) from which a prediction needs attention
+    pub alert_severity_threshold: f32,
+    /// Lesion confidence (0-1) from which a prediction needs attention
+    pub alert_confidence_threshold: f32,
+}
+
+impl CompanySettings {
+    /// Settings of the company, `self` being the server defaults
+    pub fn for_company(
+        &self,
+        company: &Company,
+        overrides: Option<&CompanySettingsOverride>,
+    ) -> CompanySettings {
+        let defaults = self.clone();
+        let settings = CompanySettings {
+            two_factor_required_level: company.two_factor_required_level,
+            ..defaults
+        };
+
+        let Some(overrides) = overrides else {
+            return settings;
+        };
+
+        CompanySettings {
+            timezone: overrides.timezone.clone().unwrap_or(settings.timezone),
+            default_language: overrides
+                .default_language
+                .clone()
+                .unwrap_or(settings.default_language),
+            data_retention_days: overrides
+                .data_retention_days
+                .unwrap_or(settings.data_retention_days),
+            two_factor_required_level: settings.two_factor_required_level,
+            default_page_size: overrides
+                .default_page_size
+                .unwrap_or(settings.default_page_size),
+            allowed_upload_formats: overrides
+                .allowed_upload_formats
+                .clone()
+                .unwrap_or(settings.allowed_upload_formats),
+            alert_severity_threshold: overrides
+                .alert_severity_threshold
+                .unwrap_or(settings.alert_severity_threshold),
+            alert_confidence_threshold: overrides
+                .alert_confidence_threshold
+                .unwrap_or(settings.alert_confidence_threshold),
+        }
+    }
+}
+
+/// Settings a company changed, unset ones follow the server defaults
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CompanySettingsOverride {
+    pub company_id: Uuid,
+    pub timezone: Option<String>,
+    pub default_language: Option<String>,
+    pub data_retention_days: Option<u32>,
+    pub default_page_size: Option<u64>,
+    pub allowed_upload_formats: Option<Vec<ImageFormat>>,
+    pub alert_severity_threshold: Option<f32>,
+    pub alert_confidence_threshold: Option<f32>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
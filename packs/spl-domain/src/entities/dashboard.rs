@@ -12,6 +12,8 @@ pub struct DashboardSummaryFilters {
     pub labels: Vec<Label>,
     pub plots: Vec<Plot>,
     pub users: Vec<User>,
+    /// Versions of the models that produced predictions of the users
+    pub model_versions: Vec<String>,
 }
 
 #[derive(Debug, Clone)]
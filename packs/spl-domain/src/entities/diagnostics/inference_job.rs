@@ -0,0 +1,135 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Stage of an inference job
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum JobStatus {
+    /// Waiting for a worker, either for the first time or to be retried
+    Queued,
+    /// A worker is predicting the image
+    Running,
+    /// The prediction was created
+    Completed,
+    /// The image cannot be predicted, e.g. it is not a supported image. Not retried.
+    Failed,
+    /// Every attempt failed. The upload is kept so the job can be retried by hand.
+    Dead,
+}
+
+impl JobStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Queued => "queued",
+            Self::Running => "running",
+            Self::Completed => "completed",
+            Self::Failed => "failed",
+            Self::Dead => "dead",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "queued" => Some(Self::Queued),
+            "running" => Some(Self::Running),
+            "completed" => Some(Self::Completed),
+            "failed" => Some(Self::Failed),
+            "dead" => Some(Self::Dead),
+            _ => None,
+        }
+    }
+}
+
+/// How jobs are retried
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JobPolicy {
+    /// Attempts before a job is dead-lettered
+    pub max_attempts: u32,
+    /// Wait before the first retry, doubled with every further attempt
+    pub retry_delay_seconds: u64,
+    /// Time a worker has to finish a job. Jobs of a worker that stopped are
+    /// picked up again once it runs out.
+    pub lease_seconds: u64,
+}
+
+impl JobPolicy {
+    /// Wait before retrying a job that failed its `attempt`-th attempt
+    pub fn retry_delay(&self, attempt: u32) -> Duration {
+        let factor = 2u64.saturating_pow(attempt.saturating_sub(1).min(16));
+        Duration::seconds(self.retry_delay_seconds.saturating_mul(factor) as i64)
+    }
+
+    pub fn lease(&self) -> Duration {
+        Duration::seconds(self.lease_seconds as i64)
+    }
+}
+
+/// Prediction of an uploaded image run by the workers. The upload is stored before
+/// the job is queued, so jobs survive restarts and are predicted at least once.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct InferenceJob {
+    pub id: Uuid,
+    /// User who uploaded the image, the prediction is created on their behalf
+    pub user_id: Uuid,
+    /// Company the user was acting in, the prediction is made in it
+    pub company_id: Option<Uuid>,
+    /// Image of a batch the job predicts, if any
+    pub batch_item_id: Option<Uuid>,
+    pub filename: String,
+    /// Storage path of the uploaded image, cleared once it is no longer needed
+    pub upload_path: Option<String>,
+    pub status: JobStatus,
+    /// Attempts started so far
+    pub attempts: u32,
+    /// Prediction created by the job, its id is chosen before predicting and the
+    /// prediction exists once the job is completed
+    pub prediction_id: Option<Uuid>,
+    /// Why the last attempt failed
+    pub error: Option<String>,
+    /// The job is not picked up before this time
+    pub run_after: DateTime<Utc>,
+    /// End of the lease of the worker running the job
+    pub locked_until: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+impl InferenceJob {
+    /// Returns a job ready to be picked up
+    pub fn queued(
+        user_id: Uuid,
+        company_id: Option<Uuid>,
+        batch_item_id: Option<Uuid>,
+        filename: String,
+        upload_path: String,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            user_id,
+            company_id,
+            batch_item_id,
+            filename,
+            upload_path: Some(upload_path),
+            status: JobStatus::Queued,
+            attempts: 0,
+            prediction_id: None,
+            error: None,
+            run_after: now,
+            locked_until: None,
+            created_at: now,
+            updated_at: now,
+            completed_at: None,
+        }
+    }
+
+    /// Returns true once no worker will pick the job up again
+    pub fn is_finished(&self) -> bool {
+        matches!(
+            self.status,
+            JobStatus::Completed | JobStatus::Failed | JobStatus::Dead
+        )
+    }
+}
@@ -1,9 +1,15 @@
+pub mod inference_job;
 pub mod label;
 pub mod mark_type;
 pub mod prediction;
+pub mod prediction_batch;
 pub mod prediction_mark;
 
+pub use inference_job::{InferenceJob, JobPolicy, JobStatus};
 pub use label::{Label, RawLabel};
 pub use mark_type::MarkType;
-pub use prediction::Prediction;
+pub use prediction::{Prediction, PredictionModel};
+pub use prediction_batch::{
+    BatchItemStatus, BatchLimits, BatchStatus, PredictionBatch, PredictionBatchItem,
+};
 pub use prediction_mark::{PredictionMark, RawPredictionMark};
@@ -4,16 +4,36 @@ use crate::entities::image::{Image, RawImage};
 use crate::entities::recommendation::Recommendation;
 use crate::entities::user::User;
 use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 use spl_shared::error::AppError;
 use spl_shared::traits::FromWithContext;
 use uuid::Uuid;
 
+/// Model that produced a prediction, as it was served at the time
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PredictionModel {
+    /// Name of the model in the serving provider
+    pub name: String,
+    /// Version that answered, when the provider reports it
+    pub version: Option<String>,
+    /// Provider that served the model (e.g., "tensorflow", "tensorflow_grpc")
+    pub provider: String,
+    /// Width and height the image was resized to
+    pub image_size: u32,
+    /// Probability from which a pixel belongs to the leaf mask
+    pub leaf_threshold: f32,
+    /// Probability from which a pixel belongs to the lesion mask
+    pub lesion_threshold: f32,
+}
+
 /// Represents a disease prediction result from the ML model
 #[derive(Debug, Clone)]
 pub struct Prediction {
     pub id: Uuid,
     /// User who owns this prediction
     pub user: User,
+    /// Company the user was acting in when the prediction was made, the one that reads it
+    pub company_id: Option<Uuid>,
     /// The image used for prediction
     pub image: Image,
     /// The assigned severity label
@@ -27,6 +47,8 @@ pub struct Prediction {
     pub absence_confidence: f32,
     /// Severity percentage of the disease (0.0 - 100.0)
     pub severity: f32,
+    /// Model that produced the prediction, unknown for predictions made before it was recorded
+    pub model: Option<PredictionModel>,
     /// Feedback associated with this prediction (optional, one-to-one)
     pub feedback: Option<Feedback>,
     /// When the prediction was created
@@ -38,6 +60,8 @@ pub struct PredictionDetailed {
     pub id: Uuid,
     /// User who owns this prediction
     pub user: User,
+    /// Company the user was acting in when the prediction was made, the one that reads it
+    pub company_id: Option<Uuid>,
     /// The image used for prediction
     pub image: Image,
     /// The assigned severity label
@@ -51,6 +75,8 @@ pub struct PredictionDetailed {
     pub absence_confidence: f32,
     /// Severity percentage of the disease (0.0 - 100.0)
     pub severity: f32,
+    /// Model that produced the prediction, unknown for predictions made before it was recorded
+    pub model: Option<PredictionModel>,
     /// Feedback associated with this prediction (optional, one-to-one)
     pub feedback: Option<Feedback>,
     /// When the prediction was created
@@ -68,6 +94,8 @@ pub struct RawPrediction {
     pub absence_confidence: f32,
     /// Severity percentage of the disease (0.0 - 100.0)
     pub severity: f32,
+    /// Model that produced the prediction
+    pub model: PredictionModel,
     /// When the prediction was created
     pub created_at: DateTime<Utc>,
     /// The image used for prediction
@@ -87,6 +115,7 @@ impl FromWithContext<Prediction, Vec<Recommendation>> for PredictionDetailed {
         Ok(Self {
             id: item.id,
             user: item.user,
+            company_id: item.company_id,
             image: item.image,
             label: item.label,
             marks: item.marks,
@@ -94,6 +123,7 @@ impl FromWithContext<Prediction, Vec<Recommendation>> for PredictionDetailed {
             presence_confidence: item.presence_confidence,
             absence_confidence: item.absence_confidence,
             severity: item.severity,
+            model: item.model,
             feedback: item.feedback,
             created_at: item.created_at,
             recommendations: context,
@@ -0,0 +1,120 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Stage of a batch of uploads
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum BatchStatus {
+    /// Images are being predicted one at a time
+    Processing,
+    /// Every image was predicted or failed, see the items
+    Completed,
+}
+
+impl BatchStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Processing => "processing",
+            Self::Completed => "completed",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "processing" => Some(Self::Processing),
+            "completed" => Some(Self::Completed),
+            _ => None,
+        }
+    }
+}
+
+/// Outcome of a single image of a batch
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum BatchItemStatus {
+    /// Waiting for its turn
+    Pending,
+    /// The prediction was created
+    Completed,
+    /// The image could not be predicted, see the error
+    Failed,
+}
+
+impl BatchItemStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Completed => "completed",
+            Self::Failed => "failed",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "pending" => Some(Self::Pending),
+            "completed" => Some(Self::Completed),
+            "failed" => Some(Self::Failed),
+            _ => None,
+        }
+    }
+}
+
+/// Limits of a batch upload
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BatchLimits {
+    /// Images of a single batch, counting those inside archives
+    pub max_files: usize,
+    /// Bytes the archives of a batch may take once extracted
+    pub max_extracted_bytes: u64,
+}
+
+/// Images uploaded together and predicted in the background. Every image is
+/// stored before the batch is returned, so an interrupted batch resumes with
+/// the images still pending.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PredictionBatch {
+    pub id: Uuid,
+    /// User who uploaded the images, predictions are created on their behalf
+    pub user_id: Uuid,
+    /// Company the user was acting in, the predictions are made in it
+    pub company_id: Option<Uuid>,
+    pub status: BatchStatus,
+    /// Images of the batch
+    pub items_total: u64,
+    /// Images predicted so far
+    pub items_completed: u64,
+    /// Images that could not be predicted
+    pub items_failed: u64,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+impl PredictionBatch {
+    /// Images still waiting to be predicted
+    pub fn items_pending(&self) -> u64 {
+        self.items_total
+            .saturating_sub(self.items_completed + self.items_failed)
+    }
+}
+
+/// Image of a batch
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PredictionBatchItem {
+    pub id: Uuid,
+    pub batch_id: Uuid,
+    /// Order of the image in the upload, starting at 0
+    pub position: u32,
+    /// Name of the uploaded file, or its path inside the archive it came in
+    pub filename: String,
+    /// Storage path of the uploaded image, cleared once it has been processed
+    pub upload_path: Option<String>,
+    pub status: BatchItemStatus,
+    /// Prediction created from the image
+    pub prediction_id: Option<Uuid>,
+    /// Why the image could not be predicted
+    pub error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
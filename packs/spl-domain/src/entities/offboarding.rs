@@ -0,0 +1,102 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Stage of a company offboarding
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum OffboardingStatus {
+    /// The archive of the company is being built
+    Exporting,
+    /// The archive is ready, data is kept until deletion is confirmed
+    Exported,
+    /// Users, their files and the company are being deleted
+    Deleting,
+    /// Nothing of the company is left besides the archive and the audit trail
+    Completed,
+    /// A step failed, see the error. Deletion can be resumed once exported.
+    Failed,
+}
+
+impl OffboardingStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Exporting => "exporting",
+            Self::Exported => "exported",
+            Self::Deleting => "deleting",
+            Self::Completed => "completed",
+            Self::Failed => "failed",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "exporting" => Some(Self::Exporting),
+            "exported" => Some(Self::Exported),
+            "deleting" => Some(Self::Deleting),
+            "completed" => Some(Self::Completed),
+            "failed" => Some(Self::Failed),
+            _ => None,
+        }
+    }
+}
+
+/// Removal of a company: its data is exported to an archive first, then deleted
+/// once an administrator confirms it. Progress is saved after every step so an
+/// interrupted offboarding resumes where it stopped.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CompanyOffboarding {
+    pub id: Uuid,
+    /// Company being removed, it no longer exists once completed
+    pub company_id: Uuid,
+    /// Name of the company, kept to identify it after deletion
+    pub company_name: String,
+    /// Administrator who started the offboarding
+    pub requested_by: Uuid,
+    pub status: OffboardingStatus,
+    /// Storage path of the archive, set once the export finished
+    pub export_path: Option<String>,
+    /// Size of the archive in bytes
+    pub export_size: Option<u64>,
+    /// Users the company had when deletion started
+    pub users_total: u64,
+    /// Users deleted so far
+    pub users_deleted: u64,
+    /// Why the last step failed
+    pub error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+impl CompanyOffboarding {
+    /// Returns true while a job is exporting or deleting
+    pub fn is_running(&self) -> bool {
+        matches!(
+            self.status,
+            OffboardingStatus::Exporting | OffboardingStatus::Deleting
+        )
+    }
+
+    /// Returns true if deletion can start or resume: only after the data was exported
+    pub fn can_delete(&self) -> bool {
+        self.export_path.is_some()
+            && matches!(
+                self.status,
+                OffboardingStatus::Exported | OffboardingStatus::Failed
+            )
+    }
+}
+
+/// Entry of the audit trail of an offboarding
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct OffboardingEvent {
+    pub id: Uuid,
+    pub offboarding_id: Uuid,
+    /// Administrator behind the event, `None` for steps of the job itself
+    pub actor_id: Option<Uuid>,
+    /// What happened, e.g. `export_completed` or `user_deleted`
+    pub action: String,
+    pub detail: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
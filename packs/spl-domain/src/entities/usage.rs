@@ -0,0 +1,193 @@
+use chrono::{DateTime, Datelike, Months, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Consumption of a user during a month, counted for the company the user belonged to
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UsageRecord {
+    pub user_id: Uuid,
+    pub company_id: Option<Uuid>,
+    /// First day of the month
+    pub period: NaiveDate,
+    pub predictions: u64,
+    /// Bytes of images and masks stored
+    pub stored_bytes: u64,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl UsageRecord {
+    pub fn usage(&self) -> Usage {
+        Usage {
+            predictions: self.predictions,
+            stored_bytes: self.stored_bytes,
+        }
+    }
+}
+
+/// Totals of one or more usage records
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Usage {
+    pub predictions: u64,
+    pub stored_bytes: u64,
+}
+
+impl Usage {
+    pub fn of(records: &[UsageRecord]) -> Self {
+        records.iter().fold(Self::default(), |total, record| Self {
+            predictions: total.predictions + record.predictions,
+            stored_bytes: total.stored_bytes + record.stored_bytes,
+        })
+    }
+}
+
+/// What happens once a quota is exhausted
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum QuotaEnforcement {
+    /// Requests go through and a warning is logged
+    Soft,
+    /// Requests are rejected until the next month
+    Hard,
+}
+
+impl QuotaEnforcement {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Soft => "soft",
+            Self::Hard => "hard",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "soft" => Some(Self::Soft),
+            "hard" => Some(Self::Hard),
+            _ => None,
+        }
+    }
+}
+
+/// How close usage is to its quota, ordered from best to worst
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "lowercase")]
+pub enum UsageStatus {
+    Ok,
+    /// Usage reached the warning ratio of a limit
+    Warning,
+    /// A limit is exhausted
+    Exceeded,
+}
+
+impl UsageStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Ok => "ok",
+            Self::Warning => "warning",
+            Self::Exceeded => "exceeded",
+        }
+    }
+}
+
+/// Monthly limits, `None` when unlimited
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UsageQuota {
+    pub predictions_per_month: Option<u64>,
+    pub predictions_per_user_per_month: Option<u64>,
+    pub stored_bytes_per_month: Option<u64>,
+    /// Fraction of a limit (0-1) from which usage is reported as a warning
+    pub warning_ratio: f32,
+    pub enforcement: QuotaEnforcement,
+}
+
+impl UsageQuota {
+    /// Status of the usage of a whole company
+    pub fn company_status(&self, usage: &Usage) -> UsageStatus {
+        self.status_of(usage.predictions, self.predictions_per_month)
+            .max(self.status_of(usage.stored_bytes, self.stored_bytes_per_month))
+    }
+
+    /// Status of the usage of a single user
+    pub fn user_status(&self, usage: &Usage) -> UsageStatus {
+        self.status_of(usage.predictions, self.predictions_per_user_per_month)
+    }
+
+    /// Limit that one more prediction storing `stored_bytes` would go over, given the usage of
+    /// the user and of its company
+    pub fn limit_reached(
+        &self,
+        user: &Usage,
+        company: Option<&Usage>,
+        stored_bytes: u64,
+    ) -> Option<QuotaLimit> {
+        if let Some(company) = company {
+            if self
+                .predictions_per_month
+                .is_some_and(|limit| company.predictions >= limit)
+            {
+                return Some(QuotaLimit::CompanyPredictions);
+            }
+            if self
+                .stored_bytes_per_month
+                .is_some_and(|limit| company.stored_bytes + stored_bytes > limit)
+            {
+                return Some(QuotaLimit::CompanyStorage);
+            }
+        }
+
+        self.predictions_per_user_per_month
+            .is_some_and(|limit| user.predictions >= limit)
+            .then_some(QuotaLimit::UserPredictions)
+    }
+
+    fn status_of(&self, used: u64, limit: Option<u64>) -> UsageStatus {
+        match limit {
+            Some(limit) if used >= limit => UsageStatus::Exceeded,
+            Some(limit) if used as f64 >= limit as f64 * self.warning_ratio as f64 => {
+                UsageStatus::Warning
+            }
+            _ => UsageStatus::Ok,
+        }
+    }
+}
+
+/// Monthly limit of a quota that is exhausted
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuotaLimit {
+    UserPredictions,
+    CompanyPredictions,
+    CompanyStorage,
+}
+
+impl QuotaLimit {
+    pub fn message(&self) -> &'static str {
+        match self {
+            Self::UserPredictions => "Monthly prediction quota of the user is exhausted",
+            Self::CompanyPredictions => "Monthly prediction quota of the company is exhausted",
+            Self::CompanyStorage => "Monthly storage quota of the company is exhausted",
+        }
+    }
+}
+
+/// Quota an admin set for a company instead of the server quota
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CompanyQuota {
+    pub company_id: Uuid,
+    pub quota: UsageQuota,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Month a moment belongs to, as its first day
+pub fn period_of(moment: DateTime<Utc>) -> NaiveDate {
+    moment.date_naive().with_day(1).unwrap_or_default()
+}
+
+/// Moment counters of a month start over
+pub fn period_end(period: NaiveDate) -> DateTime<Utc> {
+    let next = period
+        .with_day(1)
+        .and_then(|first| first.checked_add_months(Months::new(1)))
+        .unwrap_or(NaiveDate::MAX);
+
+    next.and_hms_opt(0, 0, 0).unwrap_or_default().and_utc()
+}
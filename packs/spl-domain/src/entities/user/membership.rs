@@ -0,0 +1,25 @@
+use super::{Role, User};
+use crate::entities::company::Company;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Access of a user to a company other than their own, with a role of its own.
+/// Lets a consultant advise several companies from a single account.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompanyMembership {
+    pub user_id: Uuid,
+    pub company: Company,
+    /// Role of the user while acting in the company
+    pub role: Role,
+    pub created_at: DateTime<Utc>,
+}
+
+impl CompanyMembership {
+    /// The user acting in the company of the membership, with its role
+    pub fn apply_to(&self, mut user: User) -> User {
+        user.company = Some(self.company.clone());
+        user.role = self.role.clone();
+        user
+    }
+}
@@ -1,5 +1,11 @@
+pub mod invitation;
+pub mod membership;
+pub mod permission;
 pub mod role;
 pub mod user;
 
-pub use role::Role;
+pub use invitation::{Invitation, InvitationStatus};
+pub use membership::CompanyMembership;
+pub use permission::{permissions, PermissionGrant, PermissionScope, RolePermission};
+pub use role::{DetailedRole, Role};
 pub use user::User;
@@ -0,0 +1,96 @@
+use serde::{Deserialize, Serialize};
+
+/// Permissions checked by the application. Roles are granted them in `role_permissions`,
+/// so new roles only need rows there.
+pub mod permissions {
+    pub const USERS_READ: &str = "users:read";
+    pub const USERS_MANAGE: &str = "users:manage";
+    pub const COMPANIES_MANAGE: &str = "companies:manage";
+    pub const PLOTS_READ: &str = "plots:read";
+    pub const PLOTS_MANAGE: &str = "plots:manage";
+    pub const PREDICTIONS_READ: &str = "predictions:read";
+    pub const CATALOG_MANAGE: &str = "catalog:manage";
+    pub const SERVICE_ACCOUNTS_MANAGE: &str = "service_accounts:manage";
+    pub const SSO_MANAGE: &str = "sso:manage";
+    pub const ROLES_MANAGE: &str = "roles:manage";
+    pub const USERS_IMPERSONATE: &str = "users:impersonate";
+    pub const SETTINGS_MANAGE: &str = "settings:manage";
+    pub const USAGE_READ: &str = "usage:read";
+    pub const TEAMS_MANAGE: &str = "teams:manage";
+
+    pub const ALL: &[&str] = &[
+        USERS_READ,
+        USERS_MANAGE,
+        COMPANIES_MANAGE,
+        PLOTS_READ,
+        PLOTS_MANAGE,
+        PREDICTIONS_READ,
+        CATALOG_MANAGE,
+        SERVICE_ACCOUNTS_MANAGE,
+        SSO_MANAGE,
+        ROLES_MANAGE,
+        USERS_IMPERSONATE,
+        SETTINGS_MANAGE,
+        USAGE_READ,
+        TEAMS_MANAGE,
+    ];
+
+    /// Permissions that can be granted with the `team` scope
+    pub const TEAM_SCOPED: &[&str] = &[USERS_READ, PLOTS_READ, PREDICTIONS_READ];
+
+    pub fn is_valid(permission: &str) -> bool {
+        ALL.contains(&permission)
+    }
+}
+
+/// Reach of a granted permission, ordered from narrowest to widest
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum PermissionScope {
+    /// Only resources of the user itself
+    Own,
+    /// Resources of the teams the user supervises, the whole company while it has no teams
+    Team,
+    /// Resources of the company of the user
+    Company,
+    /// Resources of every company
+    Any,
+}
+
+impl PermissionScope {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Own => "own",
+            Self::Team => "team",
+            Self::Company => "company",
+            Self::Any => "any",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "own" => Some(Self::Own),
+            "team" => Some(Self::Team),
+            "company" => Some(Self::Company),
+            "any" => Some(Self::Any),
+            _ => None,
+        }
+    }
+}
+
+/// A permission granted to a role
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RolePermission {
+    pub role_id: i32,
+    /// Name of the role, grants are looked up by the role of the user
+    pub role: String,
+    pub permission: String,
+    pub scope: PermissionScope,
+}
+
+/// A permission to grant, the role is given separately
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PermissionGrant {
+    pub permission: String,
+    pub scope: PermissionScope,
+}
@@ -1,3 +1,4 @@
+use crate::entities::user::PermissionGrant;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
@@ -21,3 +22,10 @@ impl Ord for Role {
         self.level.cmp(&other.level)
     }
 }
+
+/// A role with the permissions granted to it
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DetailedRole {
+    pub role: Role,
+    pub permissions: Vec<PermissionGrant>,
+}
@@ -9,11 +9,25 @@ pub struct User {
     pub id: Uuid,
     pub username: String,
     pub email: Option<String>,
+    /// When the current email was verified. Changing the email clears it.
+    pub email_verified_at: Option<DateTime<Utc>>,
     pub password_hash: String,
     pub name: Option<String>,
     pub surname: Option<String>,
     pub role: Role,
     pub company: Option<Company>,
+    /// When the user was deactivated. Deactivated users cannot log in but keep their data.
+    pub deactivated_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
+
+impl User {
+    pub fn is_active(&self) -> bool {
+        self.deactivated_at.is_none()
+    }
+
+    pub fn is_email_verified(&self) -> bool {
+        self.email.is_some() && self.email_verified_at.is_some()
+    }
+}
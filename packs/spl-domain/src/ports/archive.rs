@@ -0,0 +1,41 @@
+use bytes::Bytes;
+use spl_shared::error::Result;
+
+/// Archive being written, files are added one at a time
+pub trait Archive: Send {
+    /// Adds a file at `path` inside the archive
+    fn add(&mut self, path: &str, content: &[u8]) -> Result<()>;
+
+    /// Finishes the archive and returns its content
+    fn finish(self: Box<Self>) -> Result<Bytes>;
+}
+
+/// Port for building downloadable archives, e.g. data exports
+pub trait ArchiveWriter: Send + Sync {
+    /// Starts an empty archive
+    fn create(&self) -> Box<dyn Archive>;
+
+    /// Extension of the archives written, without the dot
+    fn extension(&self) -> &'static str;
+
+    /// Media type of the archives written
+    fn content_type(&self) -> &'static str;
+}
+
+/// File read from an archive
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArchiveEntry {
+    /// Path of the file inside the archive
+    pub path: String,
+    pub content: Vec<u8>,
+}
+
+/// Port for reading uploaded archives, e.g. batches of images
+pub trait ArchiveReader: Send + Sync {
+    /// Whether the content is an archive this reader understands
+    fn is_archive(&self, content: &[u8]) -> bool;
+
+    /// Files of the archive, directories left out. Fails once their uncompressed
+    /// size goes over `max_size` bytes.
+    fn extract(&self, content: &[u8], max_size: u64) -> Result<Vec<ArchiveEntry>>;
+}
@@ -1,14 +1,70 @@
+use crate::entities::auth::LoginAttempts;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use spl_shared::error::Result;
+use uuid::Uuid;
 
 #[async_trait]
 pub trait PasswordEncoder: Send + Sync {
     fn hash(&self, password: &str) -> Result<String>;
     fn verify(&self, password: &str, hash: &str) -> Result<bool>;
+
+    /// Whether the hash was made with other parameters than the current ones,
+    /// and should be replaced the next time the password is known
+    fn needs_rehash(&self, _hash: &str) -> bool {
+        false
+    }
+}
+
+/// Passwords known to have leaked, which must not be chosen
+pub trait BreachedPasswordList: Send + Sync {
+    fn contains(&self, password: &str) -> bool;
 }
 
 #[async_trait]
 pub trait TokenGenerator: Send + Sync {
     fn generate(&self, sub: &str, claims: serde_json::Value) -> Result<String>;
     fn validate(&self, token: &str) -> Result<serde_json::Value>;
+
+    /// Public verification keys as a JWK set. Empty when tokens are signed with a shared secret.
+    fn jwks(&self) -> serde_json::Value {
+        serde_json::json!({ "keys": [] })
+    }
+}
+
+/// Generates high-entropy opaque tokens (refresh, reset, invitation...) and the
+/// hashes that are persisted instead of the raw values.
+pub trait OpaqueTokenGenerator: Send + Sync {
+    fn generate(&self) -> String;
+    fn hash(&self, token: &str) -> String;
+}
+
+/// Per-account failed login counters. Implementations forget an account after a
+/// period without failures, so counters do not grow forever.
+#[async_trait]
+pub trait LoginAttemptStore: Send + Sync {
+    async fn get(&self, user_id: Uuid) -> Result<Option<LoginAttempts>>;
+
+    /// Increments the failed attempts counter and returns the updated state
+    async fn record_failure(&self, user_id: Uuid) -> Result<LoginAttempts>;
+
+    /// Locks the account until `until`, resetting the failed attempts counter
+    async fn lock(&self, user_id: Uuid, until: DateTime<Utc>) -> Result<LoginAttempts>;
+
+    async fn clear(&self, user_id: Uuid) -> Result<()>;
+}
+
+/// Time-based one-time passwords (RFC 6238) used as second login factor
+pub trait TwoFactorProvider: Send + Sync {
+    /// New random secret, base32 encoded
+    fn generate_secret(&self) -> String;
+
+    /// `otpauth://` URI that authenticator apps import, usually rendered as a QR code
+    fn provisioning_uri(&self, secret: &str, account_name: &str) -> Result<String>;
+
+    /// Checks a code against the secret, tolerating one step of clock drift
+    fn verify(&self, secret: &str, code: &str) -> Result<bool>;
+
+    /// Human-typeable single-use recovery code
+    fn generate_recovery_code(&self) -> String;
 }
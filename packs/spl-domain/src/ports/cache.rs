@@ -0,0 +1,21 @@
+use crate::entities::user::User;
+use async_trait::async_trait;
+use uuid::Uuid;
+
+/// Short-lived copies of users, read on every authenticated request. Entries expire
+/// on their own; writers invalidate them so changes are seen right away.
+///
+/// The cache is best effort: implementations log their failures and behave as a miss.
+/// Cached users may come without their password hash, which is only read from the
+/// repository.
+#[async_trait]
+pub trait UserCache: Send + Sync {
+    async fn get(&self, id: Uuid) -> Option<User>;
+
+    async fn set(&self, user: &User);
+
+    async fn invalidate(&self, id: Uuid);
+
+    /// Drops every user, for changes shared by many of them such as a role
+    async fn clear(&self);
+}
@@ -1,3 +1,4 @@
+use crate::entities::diagnostics::PredictionModel;
 use async_trait::async_trait;
 use bytes::Bytes;
 use spl_shared::error::Result;
@@ -28,6 +29,8 @@ pub struct PredictionResult {
     pub lesion_confidence: f32,
     /// Disease severity percentage (0.0 to 100.0)
     pub severity: f32,
+    /// Model that produced the result
+    pub model: PredictionModel,
 }
 
 /// Port for ML model prediction services
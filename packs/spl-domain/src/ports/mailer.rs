@@ -0,0 +1,18 @@
+use crate::ports::integrations::IntegrationClient;
+use async_trait::async_trait;
+use spl_shared::error::Result;
+
+/// A plain text email
+#[derive(Debug, Clone, PartialEq)]
+pub struct EmailMessage {
+    /// Recipient address
+    pub to: String,
+    pub subject: String,
+    pub body: String,
+}
+
+/// Port for outgoing email
+#[async_trait]
+pub trait Mailer: IntegrationClient {
+    async fn send(&self, message: EmailMessage) -> Result<()>;
+}
@@ -1,5 +1,9 @@
+pub mod archive;
 pub mod auth;
+pub mod cache;
 pub mod integrations;
+pub mod mailer;
+pub mod oidc;
 pub mod prediction;
 pub mod repositories;
 pub mod storage;
@@ -0,0 +1,42 @@
+use crate::entities::auth::IdentityProvider;
+use async_trait::async_trait;
+use spl_shared::error::Result;
+
+/// Claims of a validated ID token
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct OidcClaims {
+    pub subject: String,
+    pub email: Option<String>,
+    pub email_verified: bool,
+    pub preferred_username: Option<String>,
+    pub given_name: Option<String>,
+    pub family_name: Option<String>,
+    /// Values of the provider's role claim
+    pub roles: Vec<String>,
+}
+
+/// Relying party side of the OpenID Connect authorization code flow with PKCE
+#[async_trait]
+pub trait OidcClient: Send + Sync {
+    /// URL of the provider's authorization endpoint the user is redirected to.
+    /// The PKCE challenge is derived from `code_verifier` with S256.
+    async fn authorization_url(
+        &self,
+        provider: &IdentityProvider,
+        redirect_uri: &str,
+        state: &str,
+        nonce: &str,
+        code_verifier: &str,
+    ) -> Result<String>;
+
+    /// Exchanges the authorization code and validates the returned ID token
+    /// (signature against the provider's JWKS, issuer, audience, expiration and nonce)
+    async fn exchange_code(
+        &self,
+        provider: &IdentityProvider,
+        code: &str,
+        redirect_uri: &str,
+        code_verifier: &str,
+        nonce: &str,
+    ) -> Result<OidcClaims>;
+}
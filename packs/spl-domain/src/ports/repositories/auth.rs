@@ -0,0 +1,140 @@
+use crate::entities::auth::{
+    ApiKey, CompanyPasswordPolicy, EmailVerificationToken, IdentityProvider, Impersonation,
+    ImpersonationAction, OidcLoginState, PasswordResetToken, RecoveryCode, RefreshToken,
+    ServiceAccount, Session, TwoFactor, TwoFactorChallenge, UserIdentity,
+};
+use chrono::{DateTime, Utc};
+use crate::ports::repositories::crud::CrudRepository;
+use async_trait::async_trait;
+use spl_shared::error::Result;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait SessionRepository: CrudRepository<Session, Uuid> {
+    /// Marks the session as revoked. Returns false if it was already revoked.
+    async fn revoke(&self, id: Uuid) -> Result<bool>;
+    async fn revoke_by_user_id(&self, user_id: Uuid) -> Result<u64>;
+    /// Sessions of the user that are neither revoked nor expired, most recent first
+    async fn get_active_by_user_id(&self, user_id: Uuid) -> Result<Vec<Session>>;
+    /// Records activity on the session, keeping the previous address when none is given
+    async fn touch(
+        &self,
+        id: Uuid,
+        seen_at: DateTime<Utc>,
+        ip_address: Option<String>,
+    ) -> Result<()>;
+}
+
+#[async_trait]
+pub trait RefreshTokenRepository: CrudRepository<RefreshToken, Uuid> {
+    async fn get_by_token_hash(&self, token_hash: &str) -> Result<Option<RefreshToken>>;
+    /// Atomically marks the token as used. Returns false if it had already been used.
+    async fn mark_used(&self, id: Uuid) -> Result<bool>;
+}
+
+#[async_trait]
+pub trait PasswordResetTokenRepository: CrudRepository<PasswordResetToken, Uuid> {
+    async fn get_by_token_hash(&self, token_hash: &str) -> Result<Option<PasswordResetToken>>;
+    /// Atomically marks the token as used. Returns false if it had already been used.
+    async fn mark_used(&self, id: Uuid) -> Result<bool>;
+    /// Marks every pending token of the user as used
+    async fn invalidate_by_user_id(&self, user_id: Uuid) -> Result<u64>;
+}
+
+#[async_trait]
+pub trait ImpersonationRepository: CrudRepository<Impersonation, Uuid> {
+    /// Marks the impersonation as ended. Returns false if it had already ended.
+    async fn end(&self, id: Uuid) -> Result<bool>;
+    /// Impersonations the user started or was the subject of, most recent first
+    async fn get_by_user_id(&self, user_id: Uuid) -> Result<Vec<Impersonation>>;
+}
+
+#[async_trait]
+pub trait ImpersonationActionRepository: CrudRepository<ImpersonationAction, Uuid> {
+    /// Actions taken during the impersonation, oldest first
+    async fn get_by_impersonation_id(
+        &self,
+        impersonation_id: Uuid,
+    ) -> Result<Vec<ImpersonationAction>>;
+}
+
+#[async_trait]
+pub trait EmailVerificationTokenRepository: CrudRepository<EmailVerificationToken, Uuid> {
+    async fn get_by_token_hash(&self, token_hash: &str) -> Result<Option<EmailVerificationToken>>;
+    /// Atomically marks the token as used. Returns false if it had already been used.
+    async fn mark_used(&self, id: Uuid) -> Result<bool>;
+    /// Marks every pending token of the user as used
+    async fn invalidate_by_user_id(&self, user_id: Uuid) -> Result<u64>;
+}
+
+/// TOTP credentials, keyed by user id
+#[async_trait]
+pub trait TwoFactorRepository: CrudRepository<TwoFactor, Uuid> {
+    /// Marks the credential as enabled. Returns false if it already was.
+    async fn enable(&self, user_id: Uuid) -> Result<bool>;
+}
+
+#[async_trait]
+pub trait RecoveryCodeRepository: CrudRepository<RecoveryCode, Uuid> {
+    async fn get_by_code_hash(
+        &self,
+        user_id: Uuid,
+        code_hash: &str,
+    ) -> Result<Option<RecoveryCode>>;
+    /// Atomically marks the code as used. Returns false if it had already been used.
+    async fn mark_used(&self, id: Uuid) -> Result<bool>;
+    async fn count_unused(&self, user_id: Uuid) -> Result<u64>;
+    async fn delete_by_user_id(&self, user_id: Uuid) -> Result<u64>;
+}
+
+#[async_trait]
+pub trait TwoFactorChallengeRepository: CrudRepository<TwoFactorChallenge, Uuid> {
+    async fn get_by_token_hash(&self, token_hash: &str) -> Result<Option<TwoFactorChallenge>>;
+    /// Atomically marks the challenge as used. Returns false if it had already been used.
+    async fn mark_used(&self, id: Uuid) -> Result<bool>;
+}
+
+/// Service accounts, keyed by the id of their backing user
+#[async_trait]
+pub trait ServiceAccountRepository: CrudRepository<ServiceAccount, Uuid> {
+    async fn get_by_company_id(&self, company_id: Uuid) -> Result<Vec<ServiceAccount>>;
+}
+
+#[async_trait]
+pub trait ApiKeyRepository: CrudRepository<ApiKey, Uuid> {
+    async fn get_by_key_hash(&self, key_hash: &str) -> Result<Option<ApiKey>>;
+    async fn get_by_service_account_id(&self, service_account_id: Uuid) -> Result<Vec<ApiKey>>;
+    /// Marks the key as revoked. Returns false if it was already revoked.
+    async fn revoke(&self, id: Uuid) -> Result<bool>;
+    async fn touch(&self, id: Uuid, used_at: DateTime<Utc>) -> Result<()>;
+}
+
+/// Single sign-on providers, keyed by company id
+#[async_trait]
+pub trait IdentityProviderRepository: CrudRepository<IdentityProvider, Uuid> {}
+
+#[async_trait]
+pub trait UserIdentityRepository: CrudRepository<UserIdentity, Uuid> {
+    async fn get_by_subject(&self, issuer: &str, subject: &str) -> Result<Option<UserIdentity>>;
+}
+
+#[async_trait]
+pub trait OidcLoginStateRepository: CrudRepository<OidcLoginState, Uuid> {
+    async fn get_by_state_hash(&self, state_hash: &str) -> Result<Option<OidcLoginState>>;
+    /// Atomically marks the state as used. Returns false if it had already been used.
+    async fn mark_used(&self, id: Uuid) -> Result<bool>;
+}
+
+/// Password policies of companies, keyed by company id
+#[async_trait]
+pub trait CompanyPasswordPolicyRepository: CrudRepository<CompanyPasswordPolicy, Uuid> {}
+
+/// Hashes of the passwords users had, to refuse reusing them
+#[async_trait]
+pub trait PasswordHistoryRepository: Send + Sync {
+    /// Most recent hashes first
+    async fn get_recent(&self, user_id: Uuid, limit: u64) -> Result<Vec<String>>;
+    async fn add(&self, user_id: Uuid, password_hash: &str) -> Result<()>;
+    /// Deletes every hash but the `keep` most recent ones
+    async fn prune(&self, user_id: Uuid, keep: u64) -> Result<u64>;
+}
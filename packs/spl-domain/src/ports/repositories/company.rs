@@ -1,4 +1,4 @@
-use crate::entities::company::Company;
+use crate::entities::company::{Company, CompanySettingsOverride};
 use crate::ports::repositories::crud::CrudRepository;
 use async_trait::async_trait;
 use spl_shared::error::Result;
@@ -7,4 +7,11 @@ use uuid::Uuid;
 #[async_trait]
 pub trait CompanyRepository: CrudRepository<Company, Uuid> {
     async fn get_all(&self) -> Result<Vec<Company>>;
+
+    /// Companies whose parent is one of the given companies
+    async fn get_by_parent_ids(&self, parent_ids: Vec<Uuid>) -> Result<Vec<Company>>;
 }
+
+/// Settings changed by each company, keyed by company id
+#[async_trait]
+pub trait CompanySettingsRepository: CrudRepository<CompanySettingsOverride, Uuid> {}
@@ -5,56 +5,79 @@ use uuid::Uuid;
 
 #[async_trait::async_trait]
 pub trait DashboardSummaryRepository: Send + Sync {
-    /// The general summary of the dashboard.
+    /// The general summary of the dashboard, over the predictions made in the companies
+    /// by the users if given.
+    #[allow(clippy::too_many_arguments)]
     async fn get_summary(
         &self,
-        users_ids: Vec<Uuid>,
+        company_ids: Vec<Uuid>,
+        users_ids: Option<Vec<Uuid>>,
         min_date: Option<DateTime<Utc>>,
         max_date: Option<DateTime<Utc>>,
         plot_ids: Vec<Option<Uuid>>,
         labels: Option<Vec<String>>,
+        model_versions: Option<Vec<String>>,
     ) -> Result<DashboardSummary>;
 
     /// The summary of the dashboard with the latest predictions.
+    #[allow(clippy::too_many_arguments)]
     async fn get_counts(
         &self,
-        users_ids: Vec<Uuid>,
+        company_ids: Vec<Uuid>,
+        users_ids: Option<Vec<Uuid>>,
         min_date: Option<DateTime<Utc>>,
         max_date: Option<DateTime<Utc>>,
         plot_ids: Vec<Option<Uuid>>,
         labels: Option<Vec<String>>,
+        model_versions: Option<Vec<String>>,
         last_n: u64,
     ) -> Result<DashboardCounts>;
 
     /// The dashboard summary with a detailed plot.
+    #[allow(clippy::too_many_arguments)]
     async fn get_summary_detailed_plot_by_id(
         &self,
         company_id: Uuid,
         plot_id: Uuid,
-        users_ids: Vec<Uuid>,
+        company_ids: Vec<Uuid>,
+        users_ids: Option<Vec<Uuid>>,
         min_date: Option<DateTime<Utc>>,
         max_date: Option<DateTime<Utc>>,
         labels: Option<Vec<String>>,
+        model_versions: Option<Vec<String>>,
     ) -> Result<Option<DashboardDetailedPlot>>;
 
     /// The dashboard summary with a detailed (default) plot.
+    #[allow(clippy::too_many_arguments)]
     async fn get_default_summary_detailed_plot(
         &self,
         company_id: Uuid,
-        users_ids: Vec<Uuid>,
+        company_ids: Vec<Uuid>,
+        users_ids: Option<Vec<Uuid>>,
         min_date: Option<DateTime<Utc>>,
         max_date: Option<DateTime<Utc>>,
         plot_ids: Vec<Option<Uuid>>,
         labels: Option<Vec<String>>,
+        model_versions: Option<Vec<String>>,
     ) -> Result<Option<DashboardDetailedPlot>>;
 
     /// The dashboard summaries for compare plots
+    #[allow(clippy::too_many_arguments)]
     async fn get_compare(
         &self,
-        users_ids: Vec<Uuid>,
+        company_ids: Vec<Uuid>,
+        users_ids: Option<Vec<Uuid>>,
         min_date: Option<DateTime<Utc>>,
         max_date: Option<DateTime<Utc>>,
         plot_ids: Vec<Option<Uuid>>,
         labels: Option<Vec<String>>,
+        model_versions: Option<Vec<String>>,
     ) -> Result<Vec<DashboardSummary>>;
+
+    /// Model versions that produced predictions in the companies, for filtering.
+    async fn get_model_versions(
+        &self,
+        company_ids: Vec<Uuid>,
+        users_ids: Option<Vec<Uuid>>,
+    ) -> Result<Vec<String>>;
 }
@@ -0,0 +1,27 @@
+use crate::entities::diagnostics::{InferenceJob, JobStatus};
+use crate::ports::repositories::crud::CrudRepository;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use spl_shared::error::Result;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait InferenceJobRepository: CrudRepository<InferenceJob, Uuid> {
+    async fn create_many(&self, jobs: Vec<InferenceJob>) -> Result<()>;
+    /// Takes the oldest job due at `now`, queued or whose worker lease ran out, and
+    /// marks it running until `locked_until`, counting a new attempt. A job is only
+    /// handed to one worker at a time, even across servers.
+    async fn claim_next(
+        &self,
+        now: DateTime<Utc>,
+        locked_until: DateTime<Utc>,
+    ) -> Result<Option<InferenceJob>>;
+    /// Jobs of the user, most recent first, optionally only those with the status
+    async fn get_by_user_id(
+        &self,
+        user_id: Uuid,
+        status: Option<JobStatus>,
+    ) -> Result<Vec<InferenceJob>>;
+    /// Jobs whose prediction is made in the company, most recent first
+    async fn get_by_company_id(&self, company_id: Uuid) -> Result<Vec<InferenceJob>>;
+}
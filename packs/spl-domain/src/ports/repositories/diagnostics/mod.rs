@@ -1,9 +1,13 @@
+pub mod inference_job;
 pub mod label;
 pub mod mark_type;
 pub mod prediction;
+pub mod prediction_batch;
 pub mod prediction_mark;
 
+pub use inference_job::InferenceJobRepository;
 pub use label::LabelRepository;
 pub use mark_type::MarkTypeRepository;
 pub use prediction::PredictionRepository;
+pub use prediction_batch::{PredictionBatchItemRepository, PredictionBatchRepository};
 pub use prediction_mark::PredictionMarkRepository;
@@ -9,25 +9,31 @@ use uuid::Uuid;
 pub trait PredictionRepository: CrudRepository<Prediction, Uuid> {
     async fn get_by_user_id(&self, user_id: Uuid) -> Result<Vec<Prediction>>;
     async fn get_by_user_id_and_id(&self, user_id: Uuid, id: Uuid) -> Result<Option<Prediction>>;
+    /// Predictions made in the company, by its users or by members of other companies
+    async fn get_by_company_id(&self, company_id: Uuid) -> Result<Vec<Prediction>>;
     async fn get_all(&self) -> Result<Vec<Prediction>>;
 
-    /// Assign multiple predictions to a plot (or unassign if plot_id is None)
+    /// Assign multiple predictions made in the company to a plot (or unassign if plot_id is None)
     async fn assign_plot_by_ids_and_user_id(
         &self,
         prediction_ids: Vec<Uuid>,
         user_id: Uuid,
+        company_id: Option<Uuid>,
         plot_id: Option<Uuid>,
     ) -> Result<Vec<Prediction>>;
 
     /// Check if user has any predictions without an assigned plot
     async fn has_unassigned_predictions(&self, user_id: Uuid) -> Result<bool>;
 
-    /// Filter predictions
+    /// Filter predictions made in the companies, `None` standing for predictions made
+    /// without company, by the users if given
     #[allow(clippy::too_many_arguments)]
     async fn filter(
         &self,
-        user_ids: Vec<Uuid>,
+        company_ids: Vec<Option<Uuid>>,
+        user_ids: Option<Vec<Uuid>>,
         labels: Option<Vec<String>>,
+        model_versions: Option<Vec<String>>,
         plot_ids: Option<Vec<Option<Uuid>>>,
         min_date: Option<chrono::DateTime<chrono::Utc>>,
         max_date: Option<chrono::DateTime<chrono::Utc>>,
@@ -0,0 +1,21 @@
+use crate::entities::diagnostics::{PredictionBatch, PredictionBatchItem};
+use crate::ports::repositories::crud::CrudRepository;
+use async_trait::async_trait;
+use spl_shared::error::Result;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait PredictionBatchRepository: CrudRepository<PredictionBatch, Uuid> {
+    /// Batches of the user, most recent first
+    async fn get_by_user_id(&self, user_id: Uuid) -> Result<Vec<PredictionBatch>>;
+    /// Counts the items of the batch again and completes it once none is pending, or
+    /// reopens it when an item went back to pending. Concurrent calls are serialized.
+    async fn refresh_progress(&self, id: Uuid) -> Result<PredictionBatch>;
+}
+
+#[async_trait]
+pub trait PredictionBatchItemRepository: CrudRepository<PredictionBatchItem, Uuid> {
+    async fn create_many(&self, items: Vec<PredictionBatchItem>) -> Result<()>;
+    /// Items of the batch in upload order
+    async fn get_by_batch_id(&self, batch_id: Uuid) -> Result<Vec<PredictionBatchItem>>;
+}
@@ -1,9 +1,13 @@
+pub mod auth;
 pub mod company;
 pub mod crud;
 pub mod diagnostics;
 pub mod feedback;
 pub mod image;
+pub mod offboarding;
 pub mod plot;
 pub mod recommendation;
+pub mod team;
+pub mod usage;
 pub mod user;
 pub mod dashboard;
@@ -0,0 +1,19 @@
+use crate::entities::offboarding::{CompanyOffboarding, OffboardingEvent};
+use crate::ports::repositories::crud::CrudRepository;
+use async_trait::async_trait;
+use spl_shared::error::Result;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait CompanyOffboardingRepository: CrudRepository<CompanyOffboarding, Uuid> {
+    /// Offboardings of the company, most recent first
+    async fn get_by_company_id(&self, company_id: Uuid) -> Result<Vec<CompanyOffboarding>>;
+    /// Offboardings left exporting or deleting, e.g. by a restart of the server
+    async fn get_running(&self) -> Result<Vec<CompanyOffboarding>>;
+}
+
+#[async_trait]
+pub trait OffboardingEventRepository: CrudRepository<OffboardingEvent, Uuid> {
+    /// Audit trail of the offboarding, oldest first
+    async fn get_by_offboarding_id(&self, offboarding_id: Uuid) -> Result<Vec<OffboardingEvent>>;
+}
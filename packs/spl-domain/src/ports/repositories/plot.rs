@@ -16,12 +16,14 @@ pub trait PlotRepository: CrudRepository<Plot, Uuid> {
     /// Get a plot by ID only if it belongs to the company
     async fn get_by_company_id_and_id(&self, company_id: Uuid, id: Uuid) -> Result<Option<Plot>>;
 
-    /// Get detailed statistics for all plots of a company (paginated). 
+    /// Get detailed statistics for all plots of the companies (paginated). 
     /// 
     /// The default plot (unassigned predictions) is included with id = None.
+    /// When `plot_ids` is given, only those plots are included, without the default plot.
     async fn get_detailed(
         &self,
-        company_id: Uuid,
+        company_ids: Vec<Uuid>,
+        plot_ids: Option<Vec<Uuid>>,
         offset: u64,
         limit: u64,
         labels: Vec<String>,
@@ -0,0 +1,31 @@
+use crate::entities::team::{Team, TeamMember};
+use crate::ports::repositories::crud::CrudRepository;
+use async_trait::async_trait;
+use spl_shared::error::Result;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait TeamRepository: CrudRepository<Team, Uuid> {
+    async fn get_by_company_id(&self, company_id: Uuid) -> Result<Vec<Team>>;
+
+    /// Teams the user is a supervisor of
+    async fn get_supervised_by(&self, user_id: Uuid) -> Result<Vec<Team>>;
+
+    /// Members of every given team, a user in several of them appears once per team
+    async fn get_members(&self, team_ids: Vec<Uuid>) -> Result<Vec<TeamMember>>;
+
+    /// Adds the user to the team, or changes their role if already in it
+    async fn set_member(&self, member: TeamMember) -> Result<TeamMember>;
+
+    /// Fails with `NotFound` when the user is not in the team
+    async fn remove_member(&self, team_id: Uuid, user_id: Uuid) -> Result<()>;
+
+    /// Plots assigned to any of the given teams
+    async fn get_plot_ids(&self, team_ids: Vec<Uuid>) -> Result<Vec<Uuid>>;
+
+    /// Assigns the plot to the team, doing nothing if it already is
+    async fn add_plot(&self, team_id: Uuid, plot_id: Uuid) -> Result<()>;
+
+    /// Fails with `NotFound` when the plot is not assigned to the team
+    async fn remove_plot(&self, team_id: Uuid, plot_id: Uuid) -> Result<()>;
+}
@@ -0,0 +1,50 @@
+use crate::entities::usage::{CompanyQuota, QuotaLimit, UsageQuota, UsageRecord};
+use crate::ports::repositories::crud::CrudRepository;
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use spl_shared::error::Result;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait UsageRepository: Send + Sync {
+    /// Adds to the counters of a user for a month, creating them when missing
+    async fn increment(
+        &self,
+        user_id: Uuid,
+        company_id: Option<Uuid>,
+        period: NaiveDate,
+        predictions: u64,
+        stored_bytes: u64,
+    ) -> Result<()>;
+
+    /// Counts one prediction of the user and its bytes unless that goes over a limit of
+    /// `quota`, which is returned instead. Reservations of the same user or company wait
+    /// for each other, so concurrent ones cannot go over a limit together
+    async fn reserve(
+        &self,
+        user_id: Uuid,
+        company_id: Option<Uuid>,
+        period: NaiveDate,
+        stored_bytes: u64,
+        quota: &UsageQuota,
+    ) -> Result<Option<QuotaLimit>>;
+
+    /// Takes back counters of a user for a month, without going below zero
+    async fn release(
+        &self,
+        user_id: Uuid,
+        period: NaiveDate,
+        predictions: u64,
+        stored_bytes: u64,
+    ) -> Result<()>;
+
+    async fn get_by_user(&self, user_id: Uuid, period: NaiveDate) -> Result<Option<UsageRecord>>;
+
+    /// Counters of every user counted for the company in a month
+    async fn get_by_company(&self, company_id: Uuid, period: NaiveDate)
+        -> Result<Vec<UsageRecord>>;
+}
+
+/// Quotas set for each company, keyed by company id
+#[async_trait]
+pub trait CompanyQuotaRepository: CrudRepository<CompanyQuota, Uuid> {}
@@ -1,4 +1,6 @@
-use crate::entities::user::{Role, User};
+use crate::entities::user::{
+    CompanyMembership, Invitation, PermissionGrant, Role, RolePermission, User,
+};
 use crate::ports::repositories::crud::CrudRepository;
 use async_trait::async_trait;
 use spl_shared::error::Result;
@@ -21,6 +23,10 @@ pub trait UserRepository: CrudRepository<User, Uuid> {
     ) -> Result<Option<User>>;
 
     async fn get_by_company_id(&self, company_id: Uuid) -> Result<Vec<User>>;
+    /// Users with the role, deactivated ones included
+    async fn count_by_role_id(&self, role_id: i32) -> Result<u64>;
+    /// Active users with any of the roles
+    async fn count_active_by_role_ids(&self, role_ids: Vec<i32>) -> Result<u64>;
 }
 
 #[async_trait]
@@ -28,3 +34,52 @@ pub trait RoleRepository: CrudRepository<Role, i32> {
     async fn get_by_name(&self, name: &str) -> Result<Option<Role>>;
     async fn get_all(&self) -> Result<Vec<Role>>;
 }
+
+/// Permissions granted to roles
+#[async_trait]
+pub trait PermissionRepository: Send + Sync {
+    async fn get_grants(&self) -> Result<Vec<RolePermission>>;
+    async fn get_by_role_id(&self, role_id: i32) -> Result<Vec<RolePermission>>;
+    /// Replaces the permissions granted to the role
+    async fn set_role_grants(&self, role_id: i32, grants: Vec<PermissionGrant>) -> Result<()>;
+}
+
+#[async_trait]
+pub trait InvitationRepository: CrudRepository<Invitation, Uuid> {
+    async fn get_by_token_hash(&self, token_hash: &str) -> Result<Option<Invitation>>;
+    /// Invitations of the company, most recent first
+    async fn get_by_company_id(&self, company_id: Uuid) -> Result<Vec<Invitation>>;
+    /// Invitation of the company for the address that is neither accepted, cancelled nor expired
+    async fn get_pending_by_email(
+        &self,
+        company_id: Uuid,
+        email: &str,
+    ) -> Result<Option<Invitation>>;
+    /// Atomically marks the invitation as accepted. Returns false if it was already
+    /// accepted or cancelled.
+    async fn mark_accepted(&self, id: Uuid) -> Result<bool>;
+    /// Marks the invitation as cancelled. Returns false if it was already accepted or cancelled.
+    async fn cancel(&self, id: Uuid) -> Result<bool>;
+    /// Invitations with the role, accepted and cancelled ones included
+    async fn count_by_role_id(&self, role_id: i32) -> Result<u64>;
+}
+
+/// Companies users belong to besides their own
+#[async_trait]
+pub trait MembershipRepository: Send + Sync {
+    /// Memberships of the user, oldest first
+    async fn get_by_user_id(&self, user_id: Uuid) -> Result<Vec<CompanyMembership>>;
+    /// Memberships into the company, oldest first
+    async fn get_by_company_id(&self, company_id: Uuid) -> Result<Vec<CompanyMembership>>;
+    async fn get(&self, user_id: Uuid, company_id: Uuid) -> Result<Option<CompanyMembership>>;
+    /// Adds the user to the company, or changes their role in it
+    async fn set(
+        &self,
+        user_id: Uuid,
+        company_id: Uuid,
+        role_id: i32,
+    ) -> Result<CompanyMembership>;
+    /// Fails with `NotFound` when the user is not a member of the company
+    async fn delete(&self, user_id: Uuid, company_id: Uuid) -> Result<()>;
+    async fn count_by_role_id(&self, role_id: i32) -> Result<u64>;
+}
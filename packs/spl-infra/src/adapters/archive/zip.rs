@@ -0,0 +1,148 @@
+use bytes::Bytes;
+use spl_domain::ports::archive::{Archive, ArchiveEntry, ArchiveReader, ArchiveWriter};
+use spl_shared::error::{AppError, Result};
+use std::io::{Cursor, Read, Write};
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipWriter};
+
+/// Writes zip archives in memory
+#[derive(Default)]
+pub struct ZipArchiveWriter;
+
+impl ZipArchiveWriter {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl ArchiveWriter for ZipArchiveWriter {
+    fn create(&self) -> Box<dyn Archive> {
+        Box::new(ZipArchive {
+            writer: ZipWriter::new(Cursor::new(Vec::new())),
+        })
+    }
+
+    fn extension(&self) -> &'static str {
+        "zip"
+    }
+
+    fn content_type(&self) -> &'static str {
+        "application/zip"
+    }
+}
+
+struct ZipArchive {
+    writer: ZipWriter<Cursor<Vec<u8>>>,
+}
+
+impl Archive for ZipArchive {
+    fn add(&mut self, path: &str, content: &[u8]) -> Result<()> {
+        // Images are already compressed, deflating them again only costs time
+        let method = if path.ends_with(".json") {
+            CompressionMethod::Deflated
+        } else {
+            CompressionMethod::Stored
+        };
+
+        self.writer
+            .start_file(
+                path,
+                SimpleFileOptions::default()
+                    .compression_method(method)
+                    .large_file(content.len() as u64 >= u32::MAX as u64),
+            )
+            .map_err(|e| map_zip_error(e, path))?;
+
+        self.writer
+            .write_all(content)
+            .map_err(|e| AppError::Unknown(format!("Failed to write {} to archive: {}", path, e)))
+    }
+
+    fn finish(self: Box<Self>) -> Result<Bytes> {
+        let cursor = self
+            .writer
+            .finish()
+            .map_err(|e| AppError::Unknown(format!("Failed to finish archive: {}", e)))?;
+
+        Ok(Bytes::from(cursor.into_inner()))
+    }
+}
+
+/// Reads zip archives held in memory
+#[derive(Default)]
+pub struct ZipArchiveReader;
+
+impl ZipArchiveReader {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl ArchiveReader for ZipArchiveReader {
+    fn is_archive(&self, content: &[u8]) -> bool {
+        // Local file header, or the end of an empty archive
+        content.starts_with(b"PK\x03\x04") || content.starts_with(b"PK\x05\x06")
+    }
+
+    fn extract(&self, content: &[u8], max_size: u64) -> Result<Vec<ArchiveEntry>> {
+        let mut archive = zip::ZipArchive::new(Cursor::new(content))
+            .map_err(|e| AppError::ValidationError(format!("Invalid zip archive: {}", e)))?;
+
+        let mut entries = Vec::new();
+        let mut remaining = max_size;
+
+        for index in 0..archive.len() {
+            let file = archive
+                .by_index(index)
+                .map_err(|e| AppError::ValidationError(format!("Invalid zip archive: {}", e)))?;
+
+            let Some(path) = file.enclosed_name() else {
+                continue;
+            };
+            let path = path.to_string_lossy().replace('\\', "/");
+
+            if file.is_dir() || is_metadata(&path) {
+                continue;
+            }
+
+            // The declared size can lie, only the bytes actually read are trusted
+            let mut data = Vec::new();
+            file.take(remaining + 1)
+                .read_to_end(&mut data)
+                .map_err(|e| {
+                    AppError::ValidationError(format!(
+                        "Failed to read {} from archive: {}",
+                        path, e
+                    ))
+                })?;
+
+            if data.len() as u64 > remaining {
+                return Err(AppError::ValidationError(format!(
+                    "Archive is larger than {} bytes once extracted",
+                    max_size
+                )));
+            }
+            remaining -= data.len() as u64;
+
+            entries.push(ArchiveEntry {
+                path,
+                content: data,
+            });
+        }
+
+        Ok(entries)
+    }
+}
+
+/// Files archivers add on their own, e.g. the resource forks of macOS
+fn is_metadata(path: &str) -> bool {
+    path.starts_with("__MACOSX/")
+        || path
+            .rsplit('/')
+            .next()
+            .is_some_and(|name| name.starts_with('.'))
+}
+
+fn map_zip_error(error: zip::result::ZipError, path: &str) -> AppError {
+    AppError::Unknown(format!("Failed to add {} to archive: {}", path, error))
+}
@@ -0,0 +1,49 @@
+use spl_domain::ports::auth::BreachedPasswordList;
+use spl_shared::error::{AppError, Result};
+use std::collections::HashSet;
+
+/// Breached passwords loaded from a local file, one per line and compared case-insensitively
+#[derive(Default)]
+pub struct FileBreachedPasswordList {
+    passwords: HashSet<String>,
+}
+
+impl FileBreachedPasswordList {
+    /// List without passwords, used when no file is configured
+    pub fn empty() -> Self {
+        Self::default()
+    }
+
+    pub fn load(path: &str) -> Result<Self> {
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            AppError::ConfigError(config::ConfigError::Message(format!(
+                "Failed to read breached passwords file '{path}': {e}"
+            )))
+        })?;
+        Ok(Self::from_lines(&content))
+    }
+
+    pub fn from_lines(content: &str) -> Self {
+        let passwords = content
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(str::to_lowercase)
+            .collect();
+        Self { passwords }
+    }
+
+    pub fn len(&self) -> usize {
+        self.passwords.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.passwords.is_empty()
+    }
+}
+
+impl BreachedPasswordList for FileBreachedPasswordList {
+    fn contains(&self, password: &str) -> bool {
+        self.passwords.contains(&password.to_lowercase())
+    }
+}
@@ -1,12 +1,34 @@
-use jsonwebtoken::{decode, encode, Algorithm, DecodingKey, EncodingKey, Header, Validation};
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use jsonwebtoken::{
+    decode, decode_header, encode, Algorithm, DecodingKey, EncodingKey, Header, Validation,
+};
+use rsa::pkcs1::DecodeRsaPublicKey;
+use rsa::traits::PublicKeyParts;
+use rsa::RsaPublicKey;
 use serde::{Deserialize, Serialize};
 use spl_domain::ports::auth::TokenGenerator;
-use spl_shared::config::AppConfig;
+use spl_shared::config::{AppConfig, JwtKeyConfig};
 use spl_shared::error::{AppError, Result};
+use std::collections::HashMap;
 use std::sync::Arc;
 
 pub struct JwtTokenGenerator {
     config: Arc<AppConfig>,
+    signing_key: SigningKey,
+    verification_keys: HashMap<String, VerificationKey>,
+    jwks: serde_json::Value,
+}
+
+struct SigningKey {
+    kid: Option<String>,
+    algorithm: Algorithm,
+    key: EncodingKey,
+}
+
+struct VerificationKey {
+    algorithm: Algorithm,
+    key: DecodingKey,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -19,16 +41,123 @@ struct Claims {
 }
 
 impl JwtTokenGenerator {
-    pub fn new(config: Arc<AppConfig>) -> Self {
-        Self { config }
+    /// Builds the generator from config. Without `jwt_keys` tokens are signed with HS256
+    /// using `jwt_secret`, otherwise the configured asymmetric keys are loaded.
+    pub fn new(config: Arc<AppConfig>) -> Result<Self> {
+        let keys = config.server.jwt_keys.clone().unwrap_or_default();
+
+        if keys.is_empty() {
+            let secret = config.server.jwt_secret.as_bytes();
+            return Ok(Self {
+                signing_key: SigningKey {
+                    kid: None,
+                    algorithm: Algorithm::HS256,
+                    key: EncodingKey::from_secret(secret),
+                },
+                verification_keys: HashMap::new(),
+                jwks: serde_json::json!({ "keys": [] }),
+                config,
+            });
+        }
+
+        let signing_kid = match &config.server.jwt_signing_kid {
+            Some(kid) => kid.clone(),
+            None => keys
+                .iter()
+                .find(|key| key.private_key_path.is_some())
+                .map(|key| key.kid.clone())
+                .ok_or_else(|| {
+                    AppError::ConfigError(config::ConfigError::Message(
+                        "No JWT key with a private key configured".to_string(),
+                    ))
+                })?,
+        };
+
+        let mut signing_key = None;
+        let mut verification_keys = HashMap::new();
+        let mut jwks = Vec::new();
+
+        for key_config in &keys {
+            let algorithm = parse_algorithm(&key_config.algorithm)?;
+            let public_pem = read_pem(&key_config.public_key_path)?;
+
+            let decoding_key = match algorithm {
+                Algorithm::RS256 => DecodingKey::from_rsa_pem(&public_pem),
+                _ => DecodingKey::from_ed_pem(&public_pem),
+            }
+            .map_err(|e| key_error(key_config, e))?;
+
+            jwks.push(public_jwk(key_config, algorithm, &decoding_key)?);
+
+            if key_config.kid == signing_kid {
+                let private_key_path = key_config.private_key_path.as_ref().ok_or_else(|| {
+                    key_error(key_config, "signing key requires a private_key_path")
+                })?;
+                let private_pem = read_pem(private_key_path)?;
+
+                let encoding_key = match algorithm {
+                    Algorithm::RS256 => EncodingKey::from_rsa_pem(&private_pem),
+                    _ => EncodingKey::from_ed_pem(&private_pem),
+                }
+                .map_err(|e| key_error(key_config, e))?;
+
+                signing_key = Some(SigningKey {
+                    kid: Some(key_config.kid.clone()),
+                    algorithm,
+                    key: encoding_key,
+                });
+            }
+
+            verification_keys.insert(
+                key_config.kid.clone(),
+                VerificationKey {
+                    algorithm,
+                    key: decoding_key,
+                },
+            );
+        }
+
+        let signing_key = signing_key.ok_or_else(|| {
+            AppError::ConfigError(config::ConfigError::Message(format!(
+                "JWT signing key '{signing_kid}' not found"
+            )))
+        })?;
+
+        Ok(Self {
+            config,
+            signing_key,
+            verification_keys,
+            jwks: serde_json::json!({ "keys": jwks }),
+        })
+    }
+
+    fn decoding_key_for(&self, token: &str) -> Result<(DecodingKey, Algorithm)> {
+        if self.verification_keys.is_empty() {
+            let secret = &self.config.server.jwt_secret;
+            return Ok((DecodingKey::from_secret(secret.as_ref()), Algorithm::HS256));
+        }
+
+        let header = decode_header(token)
+            .map_err(|e| AppError::AuthError(format!("Failed to validate token: {e}")))?;
+
+        let kid = header
+            .kid
+            .ok_or_else(|| AppError::AuthError("Missing kid in token header".to_string()))?;
+
+        let key = self
+            .verification_keys
+            .get(&kid)
+            .ok_or_else(|| AppError::AuthError(format!("Unknown signing key {kid}")))?;
+
+        Ok((key.key.clone(), key.algorithm))
     }
 }
 
 impl TokenGenerator for JwtTokenGenerator {
     fn generate(&self, sub: &str, claims: serde_json::Value) -> Result<String> {
         let expiration = chrono::Utc::now()
-            .checked_add_signed(chrono::Duration::hours(
-                self.config.server.jwt_expiration_hours as i64,
+            .checked_add_signed(chrono::Duration::seconds(
+                self.config.server.access_token_ttl_seconds(),
             ))
             .expect("valid timestamp")
             .timestamp() as usize;
@@ -51,32 +180,87 @@ impl TokenGenerator for JwtTokenGenerator {
             extra,
         };
 
-        // Use secret from config
-        let secret = &self.config.server.jwt_secret;
+        let mut header = Header::new(self.signing_key.algorithm);
+        header.kid = self.signing_key.kid.clone();
 
-        encode(
-            &Header::default(),
-            &claims,
-            &EncodingKey::from_secret(secret.as_ref()),
-        )
-        .map_err(|e| AppError::AuthError(format!("Failed to generate token for {sub}: {e}")))
+        encode(&header, &claims, &self.signing_key.key)
+            .map_err(|e| AppError::AuthError(format!("Failed to generate token for {sub}: {e}")))
     }
 
     fn validate(&self, token: &str) -> Result<serde_json::Value> {
-        let secret = &self.config.server.jwt_secret;
+        let (key, algorithm) = self.decoding_key_for(token)?;
 
-        let token_data = decode::<Claims>(
-            token,
-            &DecodingKey::from_secret(secret.as_ref()),
-            &Validation::new(Algorithm::HS256),
-        )
-        .map_err(|e| AppError::AuthError(format!("Failed to validate token: {e}")))?;
+        let token_data = decode::<Claims>(token, &key, &Validation::new(algorithm))
+            .map_err(|e| AppError::AuthError(format!("Failed to validate token: {e}")))?;
 
         let value = serde_json::to_value(token_data.claims).unwrap_or_default();
-        // Ensure role is kept in the returned value if needed, or structured differently.
         // The flatten behavior puts extra fields at top level. 'role' is a struct field.
         // serde_json::to_value(Claims { ... }) will produce { "sub":..., "exp":..., "role":..., ...extra }
-        // So it works as expected.
         Ok(value)
     }
+
+    fn jwks(&self) -> serde_json::Value {
+        self.jwks.clone()
+    }
+}
+
+fn parse_algorithm(algorithm: &str) -> Result<Algorithm> {
+    match algorithm {
+        "RS256" => Ok(Algorithm::RS256),
+        "EdDSA" => Ok(Algorithm::EdDSA),
+        other => Err(AppError::ConfigError(config::ConfigError::Message(format!(
+            "Unsupported JWT algorithm '{other}', expected RS256 or EdDSA"
+        )))),
+    }
+}
+
+fn read_pem(path: &str) -> Result<Vec<u8>> {
+    std::fs::read(path).map_err(|e| {
+        AppError::ConfigError(config::ConfigError::Message(format!(
+            "Failed to read JWT key {path}: {e}"
+        )))
+    })
+}
+
+fn key_error(key_config: &JwtKeyConfig, error: impl std::fmt::Display) -> AppError {
+    AppError::ConfigError(config::ConfigError::Message(format!(
+        "Invalid JWT key '{}': {error}",
+        key_config.kid
+    )))
+}
+
+/// Builds the public JWK published on the JWKS endpoint
+fn public_jwk(
+    key_config: &JwtKeyConfig,
+    algorithm: Algorithm,
+    key: &DecodingKey,
+) -> Result<serde_json::Value> {
+    let der = match key.kind() {
+        jsonwebtoken::DecodingKeyKind::SecretOrDer(der) => der,
+        _ => return Err(key_error(key_config, "unexpected public key encoding")),
+    };
+
+    match algorithm {
+        Algorithm::RS256 => {
+            let public_key =
+                RsaPublicKey::from_pkcs1_der(der).map_err(|e| key_error(key_config, e))?;
+
+            Ok(serde_json::json!({
+                "kty": "RSA",
+                "use": "sig",
+                "alg": "RS256",
+                "kid": key_config.kid,
+                "n": URL_SAFE_NO_PAD.encode(public_key.n().to_bytes_be()),
+                "e": URL_SAFE_NO_PAD.encode(public_key.e().to_bytes_be()),
+            }))
+        }
+        _ => Ok(serde_json::json!({
+            "kty": "OKP",
+            "crv": "Ed25519",
+            "use": "sig",
+            "alg": "EdDSA",
+            "kid": key_config.kid,
+            "x": URL_SAFE_NO_PAD.encode(der),
+        })),
+    }
 }
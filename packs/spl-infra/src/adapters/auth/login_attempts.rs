@@ -0,0 +1,123 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use redis::AsyncCommands;
+use spl_domain::entities::auth::LoginAttempts;
+use spl_domain::ports::auth::LoginAttemptStore;
+use spl_shared::adapters::redis::RedisPool;
+use spl_shared::error::{AppError, Result};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+const FAILED_ATTEMPTS: &str = "failed";
+const LOCKOUT_COUNT: &str = "lockouts";
+const LOCKED_UNTIL: &str = "locked_until";
+
+/// Redis backed login attempt store. Each account is a hash that expires
+/// `failure_window_seconds` after the last failure (or after the lockout ends).
+pub struct RedisLoginAttemptStore {
+    pool: RedisPool,
+    window_seconds: u64,
+}
+
+impl RedisLoginAttemptStore {
+    pub fn new(pool: RedisPool, failure_window_seconds: u64) -> Self {
+        Self {
+            pool,
+            window_seconds: failure_window_seconds,
+        }
+    }
+
+    fn key(user_id: Uuid) -> String {
+        format!("login_attempts:{}", user_id)
+    }
+}
+
+fn connection_error(e: impl std::fmt::Display) -> AppError {
+    AppError::Unknown(format!("Failed to get Redis connection: {}", e))
+}
+
+fn parse(user_id: Uuid, values: HashMap<String, i64>) -> Option<LoginAttempts> {
+    if values.is_empty() {
+        return None;
+    }
+
+    let field = |name: &str| values.get(name).copied().unwrap_or(0).max(0);
+
+    Some(LoginAttempts {
+        user_id,
+        failed_attempts: field(FAILED_ATTEMPTS) as u32,
+        lockout_count: field(LOCKOUT_COUNT) as u32,
+        locked_until: values
+            .get(LOCKED_UNTIL)
+            .and_then(|timestamp| DateTime::from_timestamp(*timestamp, 0)),
+    })
+}
+
+#[async_trait]
+impl LoginAttemptStore for RedisLoginAttemptStore {
+    async fn get(&self, user_id: Uuid) -> Result<Option<LoginAttempts>> {
+        let mut conn = self.pool.get().await.map_err(connection_error)?;
+
+        let values: HashMap<String, i64> = conn
+            .hgetall(Self::key(user_id))
+            .await
+            .map_err(|e| AppError::Unknown(format!("Redis HGETALL failed: {}", e)))?;
+
+        Ok(parse(user_id, values))
+    }
+
+    async fn record_failure(&self, user_id: Uuid) -> Result<LoginAttempts> {
+        let key = Self::key(user_id);
+        let mut conn = self.pool.get().await.map_err(connection_error)?;
+
+        let (values,): (HashMap<String, i64>,) = redis::pipe()
+            .atomic()
+            .hincr(&key, FAILED_ATTEMPTS, 1)
+            .ignore()
+            .expire(&key, self.window_seconds as i64)
+            .ignore()
+            .hgetall(&key)
+            .query_async(&mut *conn)
+            .await
+            .map_err(|e| AppError::Unknown(format!("Redis login attempt update failed: {}", e)))?;
+
+        parse(user_id, values)
+            .ok_or_else(|| AppError::Unknown("Login attempts not stored".to_string()))
+    }
+
+    async fn lock(&self, user_id: Uuid, until: DateTime<Utc>) -> Result<LoginAttempts> {
+        let key = Self::key(user_id);
+        let ttl = (until - Utc::now()).num_seconds().max(0) + self.window_seconds as i64;
+        let mut conn = self.pool.get().await.map_err(connection_error)?;
+
+        let (values,): (HashMap<String, i64>,) = redis::pipe()
+            .atomic()
+            .hset_multiple(
+                &key,
+                &[(FAILED_ATTEMPTS, 0), (LOCKED_UNTIL, until.timestamp())],
+            )
+            .ignore()
+            .hincr(&key, LOCKOUT_COUNT, 1)
+            .ignore()
+            .expire(&key, ttl)
+            .ignore()
+            .hgetall(&key)
+            .query_async(&mut *conn)
+            .await
+            .map_err(|e| AppError::Unknown(format!("Redis login lockout failed: {}", e)))?;
+
+        parse(user_id, values)
+            .ok_or_else(|| AppError::Unknown("Login attempts not stored".to_string()))
+    }
+
+    async fn clear(&self, user_id: Uuid) -> Result<()> {
+        let mut conn = self.pool.get().await.map_err(connection_error)?;
+
+        let _: () = conn
+            .del(Self::key(user_id))
+            .await
+            .map_err(|e| AppError::Unknown(format!("Redis DEL failed: {}", e)))?;
+
+        Ok(())
+    }
+}
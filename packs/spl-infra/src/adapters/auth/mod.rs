@@ -1,2 +1,7 @@
+pub mod breached_passwords;
 pub mod jwt;
+pub mod login_attempts;
+pub mod oidc;
+pub mod opaque;
 pub mod password;
+pub mod totp;
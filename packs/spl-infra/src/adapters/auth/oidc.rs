@@ -0,0 +1,313 @@
+use async_trait::async_trait;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use jsonwebtoken::jwk::JwkSet;
+use jsonwebtoken::{decode, decode_header, AlgorithmFamily, DecodingKey, Validation};
+use reqwest::{Client, Url};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use spl_domain::entities::auth::IdentityProvider;
+use spl_domain::ports::oidc::{OidcClaims, OidcClient};
+use spl_shared::error::{AppError, Result};
+use std::collections::HashMap;
+use std::sync::RwLock;
+use std::time::{Duration, Instant};
+
+/// Discovery documents and key sets are refreshed after this long
+const METADATA_TTL: Duration = Duration::from_secs(3600);
+
+#[derive(Debug, Clone, Deserialize)]
+struct ProviderMetadata {
+    issuer: String,
+    authorization_endpoint: String,
+    token_endpoint: String,
+    jwks_uri: String,
+}
+
+#[derive(Clone)]
+struct CachedProvider {
+    metadata: ProviderMetadata,
+    jwks: JwkSet,
+    fetched_at: Instant,
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    id_token: String,
+}
+
+/// OpenID Connect relying party over HTTP. Discovery documents and JWKS are cached
+/// per issuer; the key set is fetched again when a token is signed with an unknown key.
+pub struct HttpOidcClient {
+    client: Client,
+    cache: RwLock<HashMap<String, CachedProvider>>,
+}
+
+impl HttpOidcClient {
+    pub fn new(timeout: Duration) -> Self {
+        let client = Client::builder()
+            .timeout(timeout)
+            .build()
+            .expect("Failed to build HTTP client");
+
+        Self {
+            client,
+            cache: RwLock::new(HashMap::new()),
+        }
+    }
+
+    async fn provider(&self, issuer: &str, refresh: bool) -> Result<CachedProvider> {
+        if !refresh {
+            let cached = self.cache.read().ok().and_then(|cache| {
+                cache
+                    .get(issuer)
+                    .filter(|entry| entry.fetched_at.elapsed() < METADATA_TTL)
+                    .cloned()
+            });
+
+            if let Some(cached) = cached {
+                return Ok(cached);
+            }
+        }
+
+        let discovery_url = format!(
+            "{}/.well-known/openid-configuration",
+            issuer.trim_end_matches('/')
+        );
+        let metadata: ProviderMetadata = self.get_json(&discovery_url).await?;
+
+        if metadata.issuer.trim_end_matches('/') != issuer.trim_end_matches('/') {
+            return Err(integration_error(format!(
+                "Discovery document issuer '{}' does not match '{}'",
+                metadata.issuer, issuer
+            )));
+        }
+
+        let jwks: JwkSet = self.get_json(&metadata.jwks_uri).await?;
+
+        let entry = CachedProvider {
+            metadata,
+            jwks,
+            fetched_at: Instant::now(),
+        };
+
+        if let Ok(mut cache) = self.cache.write() {
+            cache.insert(issuer.to_string(), entry.clone());
+        }
+
+        Ok(entry)
+    }
+
+    async fn get_json<T: DeserializeOwned>(&self, url: &str) -> Result<T> {
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|e| integration_error(e.to_string()))?;
+
+        if !response.status().is_success() {
+            return Err(integration_error(format!(
+                "GET {} returned HTTP {}",
+                url,
+                response.status()
+            )));
+        }
+
+        response
+            .json()
+            .await
+            .map_err(|e| integration_error(e.to_string()))
+    }
+
+    fn validate_id_token(
+        &self,
+        provider: &IdentityProvider,
+        metadata: &ProviderMetadata,
+        jwks: &JwkSet,
+        id_token: &str,
+    ) -> Result<Option<serde_json::Value>> {
+        let header = decode_header(id_token)
+            .map_err(|_| AppError::AuthError("Invalid ID token".to_string()))?;
+
+        let jwk = match &header.kid {
+            Some(kid) => jwks.find(kid),
+            None if jwks.keys.len() == 1 => jwks.keys.first(),
+            None => None,
+        };
+
+        // Unknown key: the provider may have rotated its keys
+        let Some(jwk) = jwk else {
+            return Ok(None);
+        };
+
+        let key = DecodingKey::from_jwk(jwk)
+            .map_err(|_| AppError::AuthError("Unsupported ID token key".to_string()))?;
+
+        if key.family() == AlgorithmFamily::Hmac || !key.family().algorithms().contains(&header.alg)
+        {
+            return Err(AppError::AuthError(
+                "Unexpected ID token algorithm".to_string(),
+            ));
+        }
+
+        let mut validation = Validation::new(header.alg);
+        validation.set_issuer(&[&metadata.issuer]);
+        validation.set_audience(&[&provider.client_id]);
+
+        let data = decode::<serde_json::Value>(id_token, &key, &validation)
+            .map_err(|e| AppError::AuthError(format!("Invalid ID token: {}", e)))?;
+
+        Ok(Some(data.claims))
+    }
+}
+
+#[async_trait]
+impl OidcClient for HttpOidcClient {
+    async fn authorization_url(
+        &self,
+        provider: &IdentityProvider,
+        redirect_uri: &str,
+        state: &str,
+        nonce: &str,
+        code_verifier: &str,
+    ) -> Result<String> {
+        let cached = self.provider(&provider.issuer, false).await?;
+
+        let mut url = Url::parse(&cached.metadata.authorization_endpoint)
+            .map_err(|e| integration_error(e.to_string()))?;
+
+        url.query_pairs_mut()
+            .append_pair("response_type", "code")
+            .append_pair("client_id", &provider.client_id)
+            .append_pair("redirect_uri", redirect_uri)
+            .append_pair("scope", &provider.scopes)
+            .append_pair("state", state)
+            .append_pair("nonce", nonce)
+            .append_pair("code_challenge", &pkce_challenge(code_verifier))
+            .append_pair("code_challenge_method", "S256");
+
+        Ok(url.to_string())
+    }
+
+    async fn exchange_code(
+        &self,
+        provider: &IdentityProvider,
+        code: &str,
+        redirect_uri: &str,
+        code_verifier: &str,
+        nonce: &str,
+    ) -> Result<OidcClaims> {
+        let cached = self.provider(&provider.issuer, false).await?;
+
+        let mut form = vec![
+            ("grant_type", "authorization_code"),
+            ("code", code),
+            ("redirect_uri", redirect_uri),
+            ("client_id", provider.client_id.as_str()),
+            ("code_verifier", code_verifier),
+        ];
+        if let Some(secret) = &provider.client_secret {
+            form.push(("client_secret", secret));
+        }
+
+        let response = self
+            .client
+            .post(&cached.metadata.token_endpoint)
+            .form(&form)
+            .send()
+            .await
+            .map_err(|e| integration_error(e.to_string()))?;
+
+        if !response.status().is_success() {
+            return Err(AppError::AuthError(format!(
+                "Authorization code rejected by the identity provider (HTTP {})",
+                response.status()
+            )));
+        }
+
+        let tokens: TokenResponse = response
+            .json()
+            .await
+            .map_err(|e| integration_error(e.to_string()))?;
+
+        let claims = match self.validate_id_token(
+            provider,
+            &cached.metadata,
+            &cached.jwks,
+            &tokens.id_token,
+        )? {
+            Some(claims) => claims,
+            None => {
+                let refreshed = self.provider(&provider.issuer, true).await?;
+                self.validate_id_token(
+                    provider,
+                    &refreshed.metadata,
+                    &refreshed.jwks,
+                    &tokens.id_token,
+                )?
+                .ok_or_else(|| AppError::AuthError("Unknown ID token key".to_string()))?
+            }
+        };
+
+        if claims.get("nonce").and_then(|v| v.as_str()) != Some(nonce) {
+            return Err(AppError::AuthError("ID token nonce mismatch".to_string()));
+        }
+
+        Ok(map_claims(&claims, &provider.role_claim))
+    }
+}
+
+/// S256 PKCE challenge (RFC 7636)
+pub fn pkce_challenge(code_verifier: &str) -> String {
+    URL_SAFE_NO_PAD.encode(Sha256::digest(code_verifier.as_bytes()))
+}
+
+fn map_claims(claims: &serde_json::Value, role_claim: &str) -> OidcClaims {
+    let string = |name: &str| {
+        claims
+            .get(name)
+            .and_then(|v| v.as_str())
+            .map(str::to_string)
+    };
+
+    // Some providers send `email_verified` as a string
+    let email_verified = match claims.get("email_verified") {
+        Some(serde_json::Value::Bool(verified)) => *verified,
+        Some(serde_json::Value::String(verified)) => verified == "true",
+        _ => false,
+    };
+
+    // Dotted paths reach nested claims, e.g. `realm_access.roles`
+    let roles = role_claim
+        .split('.')
+        .try_fold(claims, |value, key| value.get(key))
+        .map(|value| match value {
+            serde_json::Value::String(role) => vec![role.clone()],
+            serde_json::Value::Array(roles) => roles
+                .iter()
+                .filter_map(|v| v.as_str())
+                .map(str::to_string)
+                .collect(),
+            _ => Vec::new(),
+        })
+        .unwrap_or_default();
+
+    OidcClaims {
+        subject: string("sub").unwrap_or_default(),
+        email: string("email"),
+        email_verified,
+        preferred_username: string("preferred_username"),
+        given_name: string("given_name"),
+        family_name: string("family_name"),
+        roles,
+    }
+}
+
+fn integration_error(message: String) -> AppError {
+    AppError::IntegrationError {
+        integration: "oidc".to_string(),
+        message,
+    }
+}
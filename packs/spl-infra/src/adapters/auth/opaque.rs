@@ -0,0 +1,36 @@
+use argon2::password_hash::rand_core::{OsRng, RngCore};
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use sha2::{Digest, Sha256};
+use spl_domain::ports::auth::OpaqueTokenGenerator;
+
+const TOKEN_BYTES: usize = 32;
+
+/// Random 256-bit tokens, url-safe base64 encoded. Tokens are hashed with SHA-256,
+/// a slow hash is not needed since the input already has full entropy.
+pub struct RandomOpaqueTokenGenerator;
+
+impl RandomOpaqueTokenGenerator {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for RandomOpaqueTokenGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OpaqueTokenGenerator for RandomOpaqueTokenGenerator {
+    fn generate(&self) -> String {
+        let mut bytes = [0u8; TOKEN_BYTES];
+        OsRng.fill_bytes(&mut bytes);
+        URL_SAFE_NO_PAD.encode(bytes)
+    }
+
+    fn hash(&self, token: &str) -> String {
+        let digest = Sha256::digest(token.as_bytes());
+        digest.iter().map(|b| format!("{b:02x}")).collect()
+    }
+}
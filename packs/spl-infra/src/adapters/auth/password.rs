@@ -1,23 +1,47 @@
 use argon2::{
     password_hash::{rand_core::OsRng, PasswordHash, PasswordHasher, PasswordVerifier, SaltString},
-    Argon2,
+    Algorithm, Argon2, Params, Version,
 };
 use spl_domain::ports::auth::PasswordEncoder;
 use spl_shared::error::{AppError, Result};
 
-pub struct Argon2PasswordEncoder;
+pub struct Argon2PasswordEncoder {
+    params: Params,
+}
 
 impl Argon2PasswordEncoder {
     pub fn new() -> Self {
-        Self
+        Self {
+            params: Params::default(),
+        }
+    }
+
+    /// Encoder hashing new passwords with the given Argon2id cost
+    pub fn with_params(memory_kib: u32, iterations: u32, parallelism: u32) -> Result<Self> {
+        let params = Params::new(memory_kib, iterations, parallelism, None).map_err(|e| {
+            AppError::ConfigError(config::ConfigError::Message(format!(
+                "Invalid Argon2 parameters: {e}"
+            )))
+        })?;
+        Ok(Self { params })
+    }
+
+    fn argon2(&self) -> Argon2<'static> {
+        Argon2::new(Algorithm::Argon2id, Version::V0x13, self.params.clone())
+    }
+}
+
+impl Default for Argon2PasswordEncoder {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
 impl PasswordEncoder for Argon2PasswordEncoder {
     fn hash(&self, password: &str) -> Result<String> {
         let salt = SaltString::generate(&mut OsRng);
-        let argon2 = Argon2::default();
-        let password_hash = argon2
+        let password_hash = self
+            .argon2()
             .hash_password(password.as_bytes(), &salt)
             .map_err(|e| AppError::AuthError(format!("Failed to hash password: {e}")))?
             .to_string();
@@ -27,8 +51,27 @@ impl PasswordEncoder for Argon2PasswordEncoder {
     fn verify(&self, password: &str, hash: &str) -> Result<bool> {
         let parsed_hash = PasswordHash::new(hash)
             .map_err(|e| AppError::AuthError(format!("Failed to parse hash: {e}")))?;
+        // The cost is read from the hash, older hashes keep verifying
         Ok(Argon2::default()
             .verify_password(password.as_bytes(), &parsed_hash)
             .is_ok())
     }
+
+    fn needs_rehash(&self, hash: &str) -> bool {
+        let Ok(parsed_hash) = PasswordHash::new(hash) else {
+            return false;
+        };
+        if parsed_hash.algorithm != Algorithm::Argon2id.ident() {
+            return true;
+        }
+
+        match Params::try_from(&parsed_hash) {
+            Ok(params) => {
+                params.m_cost() < self.params.m_cost()
+                    || params.t_cost() < self.params.t_cost()
+                    || params.p_cost() < self.params.p_cost()
+            }
+            Err(_) => false,
+        }
+    }
 }
@@ -0,0 +1,79 @@
+use async_trait::async_trait;
+use lru::LruCache;
+use spl_domain::entities::user::User;
+use spl_domain::ports::cache::UserCache;
+use std::num::NonZeroUsize;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use uuid::Uuid;
+
+/// In-process LRU user cache. Every server instance keeps its own, so changes made
+/// through another instance are only seen once the entry expires.
+pub struct InMemoryUserCache {
+    entries: Mutex<LruCache<Uuid, (User, Instant)>>,
+    ttl: Duration,
+}
+
+impl InMemoryUserCache {
+    pub fn new(capacity: usize, ttl_seconds: u64) -> Self {
+        let capacity = NonZeroUsize::new(capacity).unwrap_or(NonZeroUsize::MIN);
+
+        Self {
+            entries: Mutex::new(LruCache::new(capacity)),
+            ttl: Duration::from_secs(ttl_seconds),
+        }
+    }
+}
+
+#[async_trait]
+impl UserCache for InMemoryUserCache {
+    async fn get(&self, id: Uuid) -> Option<User> {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+
+        match entries.get(&id) {
+            Some((user, stored_at)) if stored_at.elapsed() < self.ttl => Some(user.clone()),
+            Some(_) => {
+                entries.pop(&id);
+                None
+            }
+            None => None,
+        }
+    }
+
+    async fn set(&self, user: &User) {
+        self.entries
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .put(user.id, (user.clone(), Instant::now()));
+    }
+
+    async fn invalidate(&self, id: Uuid) {
+        self.entries
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .pop(&id);
+    }
+
+    async fn clear(&self) {
+        self.entries
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clear();
+    }
+}
+
+/// Used when the cache is disabled, every lookup goes to the database
+pub struct NoUserCache;
+
+#[async_trait]
+impl UserCache for NoUserCache {
+    async fn get(&self, _id: Uuid) -> Option<User> {
+        None
+    }
+
+    async fn set(&self, _user: &User) {}
+
+    async fn invalidate(&self, _id: Uuid) {}
+
+    async fn clear(&self) {}
+}
@@ -0,0 +1,164 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use redis::AsyncCommands;
+use serde::{Deserialize, Serialize};
+use spl_domain::entities::company::Company;
+use spl_domain::entities::user::{Role, User};
+use spl_domain::ports::cache::UserCache;
+use spl_shared::adapters::redis::RedisPool;
+use tracing::warn;
+use uuid::Uuid;
+
+const KEY_PREFIX: &str = "user_cache:";
+
+/// Redis backed user cache, shared by every server instance. Users are stored as
+/// JSON under `user_cache:{id}` and expire after `ttl_seconds`, without their
+/// password hash.
+pub struct RedisUserCache {
+    pool: RedisPool,
+    ttl_seconds: u64,
+}
+
+impl RedisUserCache {
+    pub fn new(pool: RedisPool, ttl_seconds: u64) -> Self {
+        Self { pool, ttl_seconds }
+    }
+
+    fn key(id: Uuid) -> String {
+        format!("{}{}", KEY_PREFIX, id)
+    }
+
+    async fn try_get(&self, id: Uuid) -> Result<Option<User>, String> {
+        let mut conn = self.pool.get().await.map_err(|e| e.to_string())?;
+
+        let value: Option<String> = conn.get(Self::key(id)).await.map_err(|e| e.to_string())?;
+
+        value
+            .map(|json| {
+                serde_json::from_str::<CachedUser>(&json)
+                    .map(User::from)
+                    .map_err(|e| e.to_string())
+            })
+            .transpose()
+    }
+
+    async fn try_set(&self, user: &User) -> Result<(), String> {
+        let json = serde_json::to_string(&CachedUser::from(user)).map_err(|e| e.to_string())?;
+        let mut conn = self.pool.get().await.map_err(|e| e.to_string())?;
+
+        conn.set_ex(Self::key(user.id), json, self.ttl_seconds)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn try_invalidate(&self, id: Uuid) -> Result<(), String> {
+        let mut conn = self.pool.get().await.map_err(|e| e.to_string())?;
+
+        conn.del(Self::key(id)).await.map_err(|e| e.to_string())
+    }
+
+    async fn try_clear(&self) -> Result<(), String> {
+        let mut conn = self.pool.get().await.map_err(|e| e.to_string())?;
+
+        let keys: Vec<String> = {
+            let mut iter = conn
+                .scan_match::<_, String>(format!("{}*", KEY_PREFIX))
+                .await
+                .map_err(|e| e.to_string())?;
+
+            let mut keys = Vec::new();
+            while let Some(key) = iter.next_item().await {
+                keys.push(key.map_err(|e| e.to_string())?);
+            }
+            keys
+        };
+
+        if keys.is_empty() {
+            return Ok(());
+        }
+
+        conn.del(keys).await.map_err(|e| e.to_string())
+    }
+}
+
+/// User as stored in Redis. The password hash stays in the database; passwords are
+/// always checked against the user read from the repository.
+#[derive(Serialize, Deserialize)]
+struct CachedUser {
+    id: Uuid,
+    username: String,
+    email: Option<String>,
+    email_verified_at: Option<DateTime<Utc>>,
+    name: Option<String>,
+    surname: Option<String>,
+    role: Role,
+    company: Option<Company>,
+    deactivated_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<&User> for CachedUser {
+    fn from(user: &User) -> Self {
+        Self {
+            id: user.id,
+            username: user.username.clone(),
+            email: user.email.clone(),
+            email_verified_at: user.email_verified_at,
+            name: user.name.clone(),
+            surname: user.surname.clone(),
+            role: user.role.clone(),
+            company: user.company.clone(),
+            deactivated_at: user.deactivated_at,
+            created_at: user.created_at,
+            updated_at: user.updated_at,
+        }
+    }
+}
+
+impl From<CachedUser> for User {
+    fn from(cached: CachedUser) -> Self {
+        Self {
+            id: cached.id,
+            username: cached.username,
+            email: cached.email,
+            email_verified_at: cached.email_verified_at,
+            password_hash: String::new(),
+            name: cached.name,
+            surname: cached.surname,
+            role: cached.role,
+            company: cached.company,
+            deactivated_at: cached.deactivated_at,
+            created_at: cached.created_at,
+            updated_at: cached.updated_at,
+        }
+    }
+}
+
+#[async_trait]
+impl UserCache for RedisUserCache {
+    async fn get(&self, id: Uuid) -> Option<User> {
+        self.try_get(id).await.unwrap_or_else(|e| {
+            warn!(user_id = %id, "User cache read failed: {}", e);
+            None
+        })
+    }
+
+    async fn set(&self, user: &User) {
+        if let Err(e) = self.try_set(user).await {
+            warn!(user_id = %user.id, "User cache write failed: {}", e);
+        }
+    }
+
+    async fn invalidate(&self, id: Uuid) {
+        if let Err(e) = self.try_invalidate(id).await {
+            warn!(user_id = %id, "User cache invalidation failed: {}", e);
+        }
+    }
+
+    async fn clear(&self) {
+        if let Err(e) = self.try_clear().await {
+            warn!("User cache clear failed: {}", e);
+        }
+    }
+}
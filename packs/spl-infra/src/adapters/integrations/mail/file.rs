@@ -0,0 +1,67 @@
+use super::format_message;
+use async_trait::async_trait;
+use spl_domain::ports::integrations::IntegrationClient;
+use spl_domain::ports::mailer::{EmailMessage, Mailer};
+use spl_shared::error::{AppError, Result};
+use std::path::PathBuf;
+use tokio::fs;
+
+/// Mailer that writes every message as an `.eml` file (development/testing)
+pub struct FileMailer {
+    from: String,
+    base_path: PathBuf,
+}
+
+impl FileMailer {
+    pub fn new(from: String, base_path: String) -> Self {
+        Self {
+            from,
+            base_path: PathBuf::from(base_path),
+        }
+    }
+}
+
+#[async_trait]
+impl IntegrationClient for FileMailer {
+    fn name(&self) -> &'static str {
+        "file_mailer"
+    }
+
+    async fn health_check(&self) -> Result<()> {
+        fs::create_dir_all(&self.base_path)
+            .await
+            .map_err(|e| AppError::IntegrationError {
+                integration: "file_mailer".to_string(),
+                message: format!("Base path not accessible: {}", e),
+            })?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Mailer for FileMailer {
+    async fn send(&self, message: EmailMessage) -> Result<()> {
+        fs::create_dir_all(&self.base_path)
+            .await
+            .map_err(|e| AppError::IntegrationError {
+                integration: "file_mailer".to_string(),
+                message: format!("Failed to create directory: {}", e),
+            })?;
+
+        let filename = format!(
+            "{}-{}.eml",
+            chrono::Utc::now().format("%Y%m%dT%H%M%S%.3f"),
+            uuid::Uuid::new_v4()
+        );
+
+        fs::write(
+            self.base_path.join(filename),
+            format_message(&self.from, &message),
+        )
+        .await
+        .map_err(|e| AppError::IntegrationError {
+            integration: "file_mailer".to_string(),
+            message: format!("Failed to write email: {}", e),
+        })
+    }
+}
@@ -0,0 +1,44 @@
+use async_trait::async_trait;
+use spl_domain::ports::integrations::IntegrationClient;
+use spl_domain::ports::mailer::{EmailMessage, Mailer};
+use spl_shared::error::Result;
+use tracing::info;
+
+/// Mailer that only writes messages to the log (development)
+pub struct LogMailer;
+
+impl LogMailer {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for LogMailer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl IntegrationClient for LogMailer {
+    fn name(&self) -> &'static str {
+        "log_mailer"
+    }
+
+    async fn health_check(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Mailer for LogMailer {
+    async fn send(&self, message: EmailMessage) -> Result<()> {
+        info!(
+            to = %message.to,
+            subject = %message.subject,
+            "Email (not sent):\n{}",
+            message.body
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,54 @@
+pub mod file;
+pub mod log;
+pub mod smtp;
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use spl_domain::ports::mailer::EmailMessage;
+
+/// Renders the message as RFC 5322 text with CRLF line endings
+pub(crate) fn format_message(from: &str, message: &EmailMessage) -> String {
+    let domain = address_domain(from);
+    let body = message.body.replace("\r\n", "\n").replace('\n', "\r\n");
+
+    format!(
+        "From: {from}\r\n\
+         To: {}\r\n\
+         Subject: {}\r\n\
+         Date: {}\r\n\
+         Message-ID: <{}@{domain}>\r\n\
+         MIME-Version: 1.0\r\n\
+         Content-Type: text/plain; charset=utf-8\r\n\
+         Content-Transfer-Encoding: 8bit\r\n\
+         \r\n\
+         {body}\r\n",
+        message.to,
+        encode_header(&message.subject),
+        chrono::Utc::now().to_rfc2822(),
+        uuid::Uuid::new_v4(),
+    )
+}
+
+/// Extracts the bare address from values like `Name <user@domain.com>`
+pub(crate) fn bare_address(address: &str) -> &str {
+    match (address.find('<'), address.rfind('>')) {
+        (Some(start), Some(end)) if start < end => &address[start + 1..end],
+        _ => address.trim(),
+    }
+}
+
+fn address_domain(address: &str) -> &str {
+    bare_address(address)
+        .rsplit_once('@')
+        .map(|(_, domain)| domain)
+        .unwrap_or("localhost")
+}
+
+/// RFC 2047 encoding for non-ASCII header values
+fn encode_header(value: &str) -> String {
+    if value.is_ascii() {
+        value.to_string()
+    } else {
+        format!("=?UTF-8?B?{}?=", STANDARD.encode(value))
+    }
+}
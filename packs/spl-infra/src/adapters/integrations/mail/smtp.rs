@@ -0,0 +1,236 @@
+use super::{bare_address, format_message};
+use async_trait::async_trait;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use spl_domain::ports::integrations::IntegrationClient;
+use spl_domain::ports::mailer::{EmailMessage, Mailer};
+use spl_shared::error::{AppError, Result};
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncWrite, AsyncWriteExt, BufStream};
+use tokio::net::TcpStream;
+use tokio_native_tls::{native_tls, TlsConnector};
+
+const INTEGRATION: &str = "smtp";
+
+/// How the connection to the SMTP server is secured
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SmtpSecurity {
+    /// Plain connection upgraded with STARTTLS (usually port 587)
+    StartTls,
+    /// TLS from the first byte (usually port 465)
+    Tls,
+    /// No encryption, only for local relays such as MailHog
+    None,
+}
+
+impl SmtpSecurity {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.to_ascii_lowercase().as_str() {
+            "starttls" => Some(Self::StartTls),
+            "tls" => Some(Self::Tls),
+            "none" => Some(Self::None),
+            _ => None,
+        }
+    }
+}
+
+/// Minimal SMTP client: EHLO, optional STARTTLS, AUTH PLAIN and a single message per connection
+pub struct SmtpMailer {
+    host: String,
+    port: u16,
+    credentials: Option<(String, String)>,
+    security: SmtpSecurity,
+    from: String,
+    timeout: Duration,
+}
+
+impl SmtpMailer {
+    pub fn new(
+        host: String,
+        port: u16,
+        credentials: Option<(String, String)>,
+        security: SmtpSecurity,
+        from: String,
+        timeout_seconds: u64,
+    ) -> Self {
+        Self {
+            host,
+            port,
+            credentials,
+            security,
+            from,
+            timeout: Duration::from_secs(timeout_seconds),
+        }
+    }
+
+    async fn connect(&self) -> Result<TcpStream> {
+        TcpStream::connect((self.host.as_str(), self.port))
+            .await
+            .map_err(|e| AppError::IntegrationUnavailable(format!("SMTP connection failed: {e}")))
+    }
+
+    async fn tls<S>(&self, stream: S) -> Result<tokio_native_tls::TlsStream<S>>
+    where
+        S: AsyncRead + AsyncWrite + Unpin,
+    {
+        let connector = native_tls::TlsConnector::new().map_err(smtp_error)?;
+        TlsConnector::from(connector)
+            .connect(&self.host, stream)
+            .await
+            .map_err(smtp_error)
+    }
+
+    async fn deliver(&self, message: &EmailMessage) -> Result<()> {
+        let stream = self.connect().await?;
+
+        match self.security {
+            SmtpSecurity::Tls => {
+                let stream = self.tls(stream).await?;
+                self.session(BufStream::new(stream), false, message).await
+            }
+            SmtpSecurity::StartTls => {
+                let mut stream = BufStream::new(stream);
+                expect_reply(&mut stream, 220).await?;
+                command(&mut stream, "EHLO spl-backend", 250).await?;
+                command(&mut stream, "STARTTLS", 220).await?;
+
+                let stream = self.tls(stream.into_inner()).await?;
+                self.session(BufStream::new(stream), true, message).await
+            }
+            SmtpSecurity::None => self.session(BufStream::new(stream), false, message).await,
+        }
+    }
+
+    async fn session<S>(
+        &self,
+        mut stream: BufStream<S>,
+        greeted: bool,
+        message: &EmailMessage,
+    ) -> Result<()>
+    where
+        S: AsyncRead + AsyncWrite + Unpin,
+    {
+        if !greeted {
+            expect_reply(&mut stream, 220).await?;
+        }
+        command(&mut stream, "EHLO spl-backend", 250).await?;
+
+        if let Some((username, password)) = &self.credentials {
+            let auth = STANDARD.encode(format!("\0{username}\0{password}"));
+            command(&mut stream, &format!("AUTH PLAIN {auth}"), 235).await?;
+        }
+
+        command(
+            &mut stream,
+            &format!("MAIL FROM:<{}>", bare_address(&self.from)),
+            250,
+        )
+        .await?;
+        command(
+            &mut stream,
+            &format!("RCPT TO:<{}>", bare_address(&message.to)),
+            250,
+        )
+        .await?;
+        command(&mut stream, "DATA", 354).await?;
+
+        let data = dot_stuff(&format_message(&self.from, message));
+        stream
+            .write_all(data.as_bytes())
+            .await
+            .map_err(smtp_error)?;
+        command(&mut stream, ".", 250).await?;
+
+        // The message is accepted at this point, a failed QUIT is not an error
+        let _ = command(&mut stream, "QUIT", 221).await;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl IntegrationClient for SmtpMailer {
+    fn name(&self) -> &'static str {
+        "smtp"
+    }
+
+    async fn health_check(&self) -> Result<()> {
+        tokio::time::timeout(self.timeout, self.connect())
+            .await
+            .map_err(|_| AppError::IntegrationTimeout(format!("SMTP {}", self.host)))??;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Mailer for SmtpMailer {
+    async fn send(&self, message: EmailMessage) -> Result<()> {
+        tokio::time::timeout(self.timeout, self.deliver(&message))
+            .await
+            .map_err(|_| AppError::IntegrationTimeout(format!("SMTP {}", self.host)))?
+    }
+}
+
+async fn command<S>(stream: &mut BufStream<S>, line: &str, expected: u16) -> Result<()>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    stream
+        .write_all(format!("{line}\r\n").as_bytes())
+        .await
+        .map_err(smtp_error)?;
+    stream.flush().await.map_err(smtp_error)?;
+
+    expect_reply(stream, expected).await
+}
+
+/// Reads a (possibly multi-line) reply and checks its status code
+async fn expect_reply<S>(stream: &mut BufStream<S>, expected: u16) -> Result<()>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    loop {
+        let mut line = String::new();
+        let read = stream.read_line(&mut line).await.map_err(smtp_error)?;
+
+        if read == 0 {
+            return Err(smtp_error("connection closed by server"));
+        }
+
+        let code = line.get(..3).and_then(|code| code.parse::<u16>().ok());
+
+        // "250-..." continues the reply, "250 ..." ends it
+        if line.as_bytes().get(3) == Some(&b'-') {
+            continue;
+        }
+
+        return match code {
+            Some(code) if code == expected => Ok(()),
+            _ => Err(smtp_error(format!(
+                "expected {expected}, got '{}'",
+                line.trim_end()
+            ))),
+        };
+    }
+}
+
+/// Escapes lines starting with a dot so they are not read as the end of DATA
+fn dot_stuff(data: &str) -> String {
+    data.split("\r\n")
+        .map(|line| {
+            if line.starts_with('.') {
+                format!(".{line}")
+            } else {
+                line.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\r\n")
+}
+
+fn smtp_error(error: impl std::fmt::Display) -> AppError {
+    AppError::IntegrationError {
+        integration: INTEGRATION.to_string(),
+        message: error.to_string(),
+    }
+}
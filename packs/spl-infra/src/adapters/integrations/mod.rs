@@ -1,3 +1,4 @@
 pub mod http_client;
+pub mod mail;
 pub mod model_serving;
 pub mod storage;
@@ -1,4 +1,5 @@
 use async_trait::async_trait;
+use spl_domain::entities::diagnostics::PredictionModel;
 use spl_domain::ports::integrations::{IntegrationClient, ModelPredictionClient, PredictionResult};
 use spl_shared::error::Result;
 use std::sync::{Arc, Mutex};
@@ -39,6 +40,14 @@ impl MockModelClient {
             leaf_confidence: 0.85,
             lesion_confidence: 0.75,
             severity: 45.0,
+            model: PredictionModel {
+                name: "mock".to_string(),
+                version: None,
+                provider: "mock".to_string(),
+                image_size: 256,
+                leaf_threshold: 0.5,
+                lesion_threshold: 0.5,
+            },
         }
     }
 }
@@ -1,7 +1,11 @@
 use bytes::Bytes;
+use spl_domain::entities::diagnostics::PredictionModel;
 use spl_domain::ports::integrations::PredictionResult;
 use spl_shared::error::{AppError, Result};
 
+/// Probability from which a pixel belongs to the leaf or lesion mask
+pub const MASK_THRESHOLD: f32 = 0.5;
+
 /// Preprocessed image data ready for model inference
 pub struct PreprocessedImage {
     pub data: Vec<Vec<Vec<f32>>>,   // Normalized tensor [H, W, C]
@@ -41,11 +45,10 @@ pub fn preprocess_image_to_tensor(image_bytes: &[u8], size: &u32) -> Result<Prep
 }
 
 /// Extracts binary mask and confidence from model output
-pub fn extract_mask_data(output: &[Vec<Vec<f32>>]) -> Result<MaskData> {
+pub fn extract_mask_data(output: &[Vec<Vec<f32>>], threshold: f32) -> Result<MaskData> {
     let mut binary_mask = Vec::new();
     let mut prob_sum = 0.0;
     let mut above_threshold_count = 0;
-    let threshold = 0.5;
 
     for row in output {
         for col in row {
@@ -119,15 +122,16 @@ pub fn encode_to_jpeg(
     Ok(Bytes::from(buffer.into_inner()))
 }
 
-/// Converts outputs to PredictionResult
+/// Converts outputs of the given model to PredictionResult
 pub fn build_prediction_result(
     output_0: &[Vec<Vec<f32>>],
     output_1: &[Vec<Vec<f32>>],
     resized_image_bytes: &Bytes,
     size: &u32,
+    model: PredictionModel,
 ) -> Result<PredictionResult> {
-    let leaf_data = extract_mask_data(output_0)?;
-    let lesion_data = extract_mask_data(output_1)?;
+    let leaf_data = extract_mask_data(output_0, model.leaf_threshold)?;
+    let lesion_data = extract_mask_data(output_1, model.lesion_threshold)?;
     let severity = calculate_severity(&leaf_data, &lesion_data);
 
     let encoded_image = encode_to_jpeg(size, size, resized_image_bytes, image::ColorType::Rgb8)?;
@@ -141,5 +145,6 @@ pub fn build_prediction_result(
         leaf_confidence: leaf_data.confidence,
         lesion_confidence: lesion_data.confidence,
         severity,
+        model,
     })
 }
@@ -1,5 +1,5 @@
 use crate::adapters::integrations::model_serving::tensorflow::common::{
-    build_prediction_result, preprocess_image_to_tensor,
+    build_prediction_result, preprocess_image_to_tensor, MASK_THRESHOLD,
 };
 use crate::tensorflow::serving::model_service_client::ModelServiceClient;
 use crate::tensorflow::serving::model_spec::VersionChoice;
@@ -11,6 +11,7 @@ use crate::tensorflow::serving::{
 use crate::tensorflow::tensor_shape_proto::Dim;
 use crate::tensorflow::{DataType, TensorProto, TensorShapeProto};
 use async_trait::async_trait;
+use spl_domain::entities::diagnostics::PredictionModel;
 use spl_domain::ports::integrations::{IntegrationClient, ModelPredictionClient, PredictionResult};
 use spl_shared::error::{AppError, Result};
 use std::collections::HashMap;
@@ -204,6 +205,26 @@ impl ModelPredictionClient for TensorFlowServingGrpcClient {
                 })?;
 
         let predict_response = response.into_inner();
+
+        // The response names the version that answered
+        let version = predict_response
+            .model_spec
+            .as_ref()
+            .and_then(|spec| match &spec.version_choice {
+                Some(VersionChoice::Version(version)) => Some(version.to_string()),
+                Some(VersionChoice::VersionLabel(label)) => Some(label.clone()),
+                None => None,
+            });
+
+        let model = PredictionModel {
+            name: self.model_name.clone(),
+            version,
+            provider: "tensorflow_grpc".to_string(),
+            image_size: size,
+            leaf_threshold: MASK_THRESHOLD,
+            lesion_threshold: MASK_THRESHOLD,
+        };
+
         let (output_0, output_1) = parse_grpc_response(self, predict_response)?;
 
         build_prediction_result(
@@ -211,6 +232,7 @@ impl ModelPredictionClient for TensorFlowServingGrpcClient {
             &output_1,
             &preprocessed.resized_image_bytes,
             &size,
+            model,
         )
     }
 
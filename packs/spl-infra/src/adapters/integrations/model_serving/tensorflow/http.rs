@@ -1,13 +1,18 @@
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
+use spl_domain::entities::diagnostics::PredictionModel;
 use spl_domain::ports::integrations::{IntegrationClient, ModelPredictionClient, PredictionResult};
 use spl_shared::error::{AppError, Result};
 use std::sync::Arc;
-use std::time::Duration;
-use tokio::sync::Semaphore;
+use std::time::{Duration, Instant};
+use tokio::sync::{RwLock, Semaphore};
+use tracing::warn;
 
 use super::super::super::http_client::RetryableHttpClient;
-use super::common::{build_prediction_result, preprocess_image_to_tensor};
+use super::common::{build_prediction_result, preprocess_image_to_tensor, MASK_THRESHOLD};
+
+/// How long the served version is trusted before the model status is read again
+const VERSION_TTL: Duration = Duration::from_secs(60);
 
 pub struct TensorFlowServingClient {
     http_client: RetryableHttpClient,
@@ -15,6 +20,8 @@ pub struct TensorFlowServingClient {
     model_name: String,
     image_size: u32,
     semaphore: Arc<Semaphore>,
+    /// Last version read from the model status, and when
+    version: RwLock<Option<(Instant, String)>>,
 }
 
 impl TensorFlowServingClient {
@@ -34,8 +41,55 @@ impl TensorFlowServingClient {
             model_name,
             image_size,
             semaphore: Arc::new(Semaphore::new(concurrency_limit)),
+            version: RwLock::new(None),
+        }
+    }
+
+    /// Version TF Serving answers with. The REST predict response does not carry it,
+    /// so it comes from the model status: the highest version available.
+    async fn served_version(&self) -> Option<String> {
+        if let Some((read_at, version)) = self.version.read().await.as_ref() {
+            if read_at.elapsed() < VERSION_TTL {
+                return Some(version.clone());
+            }
+        }
+
+        match self.read_version().await {
+            Ok(version) => {
+                *self.version.write().await = Some((Instant::now(), version.clone()));
+                Some(version)
+            }
+            Err(e) => {
+                warn!("Failed to read the version of model {}: {}", self.model_name, e);
+                None
+            }
         }
     }
+
+    async fn read_version(&self) -> Result<String> {
+        let url = format!("{}/v1/models/{}", self.base_url, self.model_name);
+        let status: TFModelStatusResponse = self
+            .http_client
+            .get(&url)
+            .await?
+            .json()
+            .await
+            .map_err(|e| AppError::IntegrationError {
+                integration: "tensorflow_serving".to_string(),
+                message: format!("Failed to parse model status: {}", e),
+            })?;
+
+        status
+            .model_version_status
+            .into_iter()
+            .filter(|v| v.state == "AVAILABLE")
+            .max_by_key(|v| v.version.parse::<i64>().unwrap_or_default())
+            .map(|v| v.version)
+            .ok_or_else(|| AppError::IntegrationError {
+                integration: "tensorflow_serving".to_string(),
+                message: "No model version available".to_string(),
+            })
+    }
 }
 
 #[async_trait]
@@ -96,12 +150,22 @@ impl ModelPredictionClient for TensorFlowServingClient {
             });
         }
 
+        let model = PredictionModel {
+            name: self.model_name.clone(),
+            version: self.served_version().await,
+            provider: "tensorflow".to_string(),
+            image_size: size,
+            leaf_threshold: MASK_THRESHOLD,
+            lesion_threshold: MASK_THRESHOLD,
+        };
+
         let prediction = &tf_response.predictions[0];
         build_prediction_result(
             &prediction.output_0,
             &prediction.output_1,
             &preprocessed.resized_image_bytes,
             &size,
+            model,
         )
     }
 
@@ -125,3 +189,14 @@ struct TFPrediction {
     output_0: Vec<Vec<Vec<f32>>>,
     output_1: Vec<Vec<Vec<f32>>>,
 }
+
+#[derive(Deserialize)]
+struct TFModelStatusResponse {
+    model_version_status: Vec<TFModelVersionStatus>,
+}
+
+#[derive(Deserialize)]
+struct TFModelVersionStatus {
+    version: String,
+    state: String,
+}
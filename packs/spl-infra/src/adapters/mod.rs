@@ -1,4 +1,6 @@
+pub mod archive;
 pub mod auth;
+pub mod cache;
 pub mod integrations;
 pub mod persistence;
 pub mod web;
@@ -0,0 +1,41 @@
+use sea_orm::entity::prelude::*;
+
+use crate::adapters::persistence::entities::auth::service_account;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "api_keys")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    pub service_account_id: Uuid,
+    pub name: String,
+    pub key_prefix: String,
+    #[sea_orm(unique)]
+    pub key_hash: String,
+    /// Space separated list of scopes
+    pub scopes: String,
+    pub expires_at: Option<DateTimeWithTimeZone>,
+    pub last_used_at: Option<DateTimeWithTimeZone>,
+    pub revoked_at: Option<DateTimeWithTimeZone>,
+    pub created_at: DateTimeWithTimeZone,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "service_account::Entity",
+        from = "Column::ServiceAccountId",
+        to = "service_account::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    ServiceAccount,
+}
+
+impl Related<service_account::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ServiceAccount.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
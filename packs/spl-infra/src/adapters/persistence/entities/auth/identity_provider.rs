@@ -0,0 +1,42 @@
+use sea_orm::entity::prelude::*;
+
+use crate::adapters::persistence::entities::company;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "identity_providers")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub company_id: Uuid,
+    pub issuer: String,
+    pub client_id: String,
+    pub client_secret: Option<String>,
+    pub scopes: String,
+    pub role_claim: String,
+    /// JSON object mapping claim values to role names
+    pub role_mapping: String,
+    pub default_role: Option<String>,
+    pub auto_provision: bool,
+    pub enabled: bool,
+    pub created_at: DateTimeWithTimeZone,
+    pub updated_at: DateTimeWithTimeZone,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "company::Entity",
+        from = "Column::CompanyId",
+        to = "company::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    Company,
+}
+
+impl Related<company::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Company.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
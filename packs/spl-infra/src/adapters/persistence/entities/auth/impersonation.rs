@@ -0,0 +1,48 @@
+use sea_orm::entity::prelude::*;
+
+use crate::adapters::persistence::entities::user::user;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "impersonations")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    pub actor_id: Option<Uuid>,
+    pub subject_id: Option<Uuid>,
+    #[sea_orm(column_type = "Text")]
+    pub reason: String,
+    pub ip_address: Option<String>,
+    pub started_at: DateTimeWithTimeZone,
+    pub expires_at: DateTimeWithTimeZone,
+    pub ended_at: Option<DateTimeWithTimeZone>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "user::Entity",
+        from = "Column::ActorId",
+        to = "user::Column::Id",
+        on_update = "NoAction",
+        on_delete = "SetNull"
+    )]
+    Actor,
+    #[sea_orm(
+        belongs_to = "user::Entity",
+        from = "Column::SubjectId",
+        to = "user::Column::Id",
+        on_update = "NoAction",
+        on_delete = "SetNull"
+    )]
+    Subject,
+    #[sea_orm(has_many = "super::impersonation_action::Entity")]
+    ImpersonationAction,
+}
+
+impl Related<super::impersonation_action::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ImpersonationAction.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,34 @@
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "impersonation_actions")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    pub impersonation_id: Uuid,
+    pub method: String,
+    #[sea_orm(column_type = "Text")]
+    pub path: String,
+    pub status_code: i16,
+    pub created_at: DateTimeWithTimeZone,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::impersonation::Entity",
+        from = "Column::ImpersonationId",
+        to = "super::impersonation::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    Impersonation,
+}
+
+impl Related<super::impersonation::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Impersonation.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,38 @@
+use sea_orm::entity::prelude::*;
+
+use crate::adapters::persistence::entities::company;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "oidc_login_states")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    #[sea_orm(unique)]
+    pub state_hash: String,
+    pub company_id: Uuid,
+    pub nonce: String,
+    pub code_verifier: String,
+    pub expires_at: DateTimeWithTimeZone,
+    pub used_at: Option<DateTimeWithTimeZone>,
+    pub created_at: DateTimeWithTimeZone,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "company::Entity",
+        from = "Column::CompanyId",
+        to = "company::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    Company,
+}
+
+impl Related<company::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Company.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,41 @@
+use sea_orm::entity::prelude::*;
+
+use crate::adapters::persistence::entities::company;
+
+/// Settings a company changed, `NULL` columns follow the server defaults
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "company_settings")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub company_id: Uuid,
+    pub timezone: Option<String>,
+    pub default_language: Option<String>,
+    pub data_retention_days: Option<i32>,
+    pub default_page_size: Option<i32>,
+    /// Space separated list of image formats
+    pub allowed_upload_formats: Option<String>,
+    pub alert_severity_threshold: Option<f32>,
+    pub alert_confidence_threshold: Option<f32>,
+    pub created_at: DateTimeWithTimeZone,
+    pub updated_at: DateTimeWithTimeZone,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "company::Entity",
+        from = "Column::CompanyId",
+        to = "company::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    Company,
+}
+
+impl Related<company::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Company.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,28 @@
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "inference_jobs")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub company_id: Option<Uuid>,
+    pub batch_item_id: Option<Uuid>,
+    pub filename: String,
+    pub upload_path: Option<String>,
+    pub status: String,
+    pub attempts: i32,
+    pub prediction_id: Option<Uuid>,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub error: Option<String>,
+    pub run_after: DateTimeWithTimeZone,
+    pub locked_until: Option<DateTimeWithTimeZone>,
+    pub created_at: DateTimeWithTimeZone,
+    pub updated_at: DateTimeWithTimeZone,
+    pub completed_at: Option<DateTimeWithTimeZone>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -8,12 +8,19 @@ pub struct Model {
     #[sea_orm(primary_key, auto_increment = false)]
     pub id: Uuid,
     pub user_id: Uuid,
+    pub company_id: Option<Uuid>,
     pub image_id: Uuid,
     pub label_id: i32,
     pub plot_id: Option<Uuid>,
     pub presence_confidence: f32,
     pub absence_confidence: f32,
     pub severity: f32,
+    pub model_name: Option<String>,
+    pub model_version: Option<String>,
+    pub model_provider: Option<String>,
+    pub model_image_size: Option<i32>,
+    pub model_leaf_threshold: Option<f32>,
+    pub model_lesion_threshold: Option<f32>,
     pub created_at: DateTimeWithTimeZone,
 }
 
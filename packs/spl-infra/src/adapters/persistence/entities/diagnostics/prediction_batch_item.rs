@@ -0,0 +1,38 @@
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "prediction_batch_items")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    pub batch_id: Uuid,
+    pub position: i32,
+    pub filename: String,
+    pub upload_path: Option<String>,
+    pub status: String,
+    pub prediction_id: Option<Uuid>,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub error: Option<String>,
+    pub created_at: DateTimeWithTimeZone,
+    pub updated_at: DateTimeWithTimeZone,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::prediction_batch::Entity",
+        from = "Column::BatchId",
+        to = "super::prediction_batch::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    Batch,
+}
+
+impl Related<super::prediction_batch::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Batch.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,36 @@
+use sea_orm::entity::prelude::*;
+
+/// Not related to the company nor the requester, both can be deleted before it
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "company_offboardings")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    pub company_id: Uuid,
+    pub company_name: String,
+    pub requested_by: Uuid,
+    pub status: String,
+    pub export_path: Option<String>,
+    pub export_size: Option<i64>,
+    pub users_total: i64,
+    pub users_deleted: i64,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub error: Option<String>,
+    pub created_at: DateTimeWithTimeZone,
+    pub updated_at: DateTimeWithTimeZone,
+    pub completed_at: Option<DateTimeWithTimeZone>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(has_many = "super::offboarding_event::Entity")]
+    Events,
+}
+
+impl Related<super::offboarding_event::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Events.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,34 @@
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "offboarding_events")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: Uuid,
+    pub offboarding_id: Uuid,
+    pub actor_id: Option<Uuid>,
+    pub action: String,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub detail: Option<String>,
+    pub created_at: DateTimeWithTimeZone,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::company_offboarding::Entity",
+        from = "Column::OffboardingId",
+        to = "super::company_offboarding::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    Offboarding,
+}
+
+impl Related<super::company_offboarding::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Offboarding.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
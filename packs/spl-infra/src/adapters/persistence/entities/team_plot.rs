@@ -0,0 +1,35 @@
+use sea_orm::entity::prelude::*;
+
+use crate::adapters::persistence::entities::plot;
+
+/// Plot assigned to a team
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "team_plots")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub team_id: Uuid,
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub plot_id: Uuid,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::team::Entity",
+        from = "Column::TeamId",
+        to = "super::team::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    Team,
+    #[sea_orm(
+        belongs_to = "plot::Entity",
+        from = "Column::PlotId",
+        to = "plot::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    Plot,
+}
+
+impl ActiveModelBehavior for ActiveModel {}
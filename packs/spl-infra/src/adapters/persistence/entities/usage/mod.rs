@@ -0,0 +1,2 @@
+pub mod company_quota;
+pub mod usage_counter;
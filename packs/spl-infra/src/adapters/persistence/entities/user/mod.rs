@@ -1,3 +1,7 @@
+pub mod invitation;
+pub mod membership;
+pub mod permission;
 pub mod role;
+pub mod role_permission;
 pub mod user;
 pub use user::*;
@@ -8,6 +8,7 @@ pub struct Model {
     pub id: Uuid,
     pub username: String,
     pub email: Option<String>,
+    pub email_verified_at: Option<DateTimeWithTimeZone>,
     pub password_hash: String,
     #[sea_orm(column_type = "String(StringLen::N(100))")]
     pub name: Option<String>,
@@ -15,6 +16,7 @@ pub struct Model {
     pub surname: Option<String>,
     pub role_id: i32,
     pub company_id: Option<Uuid>,
+    pub deactivated_at: Option<DateTimeWithTimeZone>,
     pub created_at: DateTimeWithTimeZone,
     pub updated_at: DateTimeWithTimeZone,
 }
@@ -0,0 +1,41 @@
+use crate::adapters::persistence::entities::auth::api_key::{ActiveModel, Model};
+use sea_orm::Set;
+use spl_domain::entities::auth::ApiKey;
+
+impl From<Model> for ApiKey {
+    fn from(model: Model) -> Self {
+        Self {
+            id: model.id,
+            service_account_id: model.service_account_id,
+            name: model.name,
+            key_prefix: model.key_prefix,
+            key_hash: model.key_hash,
+            scopes: model
+                .scopes
+                .split_whitespace()
+                .map(str::to_string)
+                .collect(),
+            expires_at: model.expires_at.map(Into::into),
+            last_used_at: model.last_used_at.map(Into::into),
+            revoked_at: model.revoked_at.map(Into::into),
+            created_at: model.created_at.into(),
+        }
+    }
+}
+
+impl From<ApiKey> for ActiveModel {
+    fn from(entity: ApiKey) -> Self {
+        Self {
+            id: Set(entity.id),
+            service_account_id: Set(entity.service_account_id),
+            name: Set(entity.name),
+            key_prefix: Set(entity.key_prefix),
+            key_hash: Set(entity.key_hash),
+            scopes: Set(entity.scopes.join(" ")),
+            expires_at: Set(entity.expires_at.map(Into::into)),
+            last_used_at: Set(entity.last_used_at.map(Into::into)),
+            revoked_at: Set(entity.revoked_at.map(Into::into)),
+            created_at: Set(entity.created_at.into()),
+        }
+    }
+}
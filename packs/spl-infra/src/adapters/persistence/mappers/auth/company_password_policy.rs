@@ -0,0 +1,39 @@
+use crate::adapters::persistence::entities::auth::company_password_policy::{ActiveModel, Model};
+use sea_orm::Set;
+use spl_domain::entities::auth::{CompanyPasswordPolicy, PasswordPolicy};
+
+impl From<Model> for CompanyPasswordPolicy {
+    fn from(model: Model) -> Self {
+        Self {
+            company_id: model.company_id,
+            policy: PasswordPolicy {
+                min_length: model.min_length.max(0) as u16,
+                require_uppercase: model.require_uppercase,
+                require_lowercase: model.require_lowercase,
+                require_digit: model.require_digit,
+                require_symbol: model.require_symbol,
+                history_size: model.history_size.max(0) as u16,
+            },
+            created_at: model.created_at.into(),
+            updated_at: model.updated_at.into(),
+        }
+    }
+}
+
+impl From<CompanyPasswordPolicy> for ActiveModel {
+    fn from(entity: CompanyPasswordPolicy) -> Self {
+        let policy = entity.policy;
+
+        Self {
+            company_id: Set(entity.company_id),
+            min_length: Set(policy.min_length.min(i16::MAX as u16) as i16),
+            require_uppercase: Set(policy.require_uppercase),
+            require_lowercase: Set(policy.require_lowercase),
+            require_digit: Set(policy.require_digit),
+            require_symbol: Set(policy.require_symbol),
+            history_size: Set(policy.history_size.min(i16::MAX as u16) as i16),
+            created_at: Set(entity.created_at.into()),
+            updated_at: Set(entity.updated_at.into()),
+        }
+    }
+}
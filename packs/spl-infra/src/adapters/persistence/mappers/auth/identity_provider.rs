@@ -0,0 +1,41 @@
+use crate::adapters::persistence::entities::auth::identity_provider::{ActiveModel, Model};
+use sea_orm::Set;
+use spl_domain::entities::auth::IdentityProvider;
+
+impl From<Model> for IdentityProvider {
+    fn from(model: Model) -> Self {
+        Self {
+            company_id: model.company_id,
+            issuer: model.issuer,
+            client_id: model.client_id,
+            client_secret: model.client_secret,
+            scopes: model.scopes,
+            role_claim: model.role_claim,
+            role_mapping: serde_json::from_str(&model.role_mapping).unwrap_or_default(),
+            default_role: model.default_role,
+            auto_provision: model.auto_provision,
+            enabled: model.enabled,
+            created_at: model.created_at.into(),
+            updated_at: model.updated_at.into(),
+        }
+    }
+}
+
+impl From<IdentityProvider> for ActiveModel {
+    fn from(entity: IdentityProvider) -> Self {
+        Self {
+            company_id: Set(entity.company_id),
+            issuer: Set(entity.issuer),
+            client_id: Set(entity.client_id),
+            client_secret: Set(entity.client_secret),
+            scopes: Set(entity.scopes),
+            role_claim: Set(entity.role_claim),
+            role_mapping: Set(serde_json::to_string(&entity.role_mapping).unwrap_or_default()),
+            default_role: Set(entity.default_role),
+            auto_provision: Set(entity.auto_provision),
+            enabled: Set(entity.enabled),
+            created_at: Set(entity.created_at.into()),
+            updated_at: Set(entity.updated_at.into()),
+        }
+    }
+}
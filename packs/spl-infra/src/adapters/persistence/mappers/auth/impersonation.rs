@@ -0,0 +1,33 @@
+use crate::adapters::persistence::entities::auth::impersonation::{ActiveModel, Model};
+use sea_orm::Set;
+use spl_domain::entities::auth::Impersonation;
+
+impl From<Model> for Impersonation {
+    fn from(model: Model) -> Self {
+        Self {
+            id: model.id,
+            actor_id: model.actor_id,
+            subject_id: model.subject_id,
+            reason: model.reason,
+            ip_address: model.ip_address,
+            started_at: model.started_at.into(),
+            expires_at: model.expires_at.into(),
+            ended_at: model.ended_at.map(Into::into),
+        }
+    }
+}
+
+impl From<Impersonation> for ActiveModel {
+    fn from(entity: Impersonation) -> Self {
+        Self {
+            id: Set(entity.id),
+            actor_id: Set(entity.actor_id),
+            subject_id: Set(entity.subject_id),
+            reason: Set(entity.reason),
+            ip_address: Set(entity.ip_address),
+            started_at: Set(entity.started_at.into()),
+            expires_at: Set(entity.expires_at.into()),
+            ended_at: Set(entity.ended_at.map(Into::into)),
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use crate::adapters::persistence::entities::auth::impersonation_action::{ActiveModel, Model};
+use sea_orm::Set;
+use spl_domain::entities::auth::ImpersonationAction;
+
+impl From<Model> for ImpersonationAction {
+    fn from(model: Model) -> Self {
+        Self {
+            id: model.id,
+            impersonation_id: model.impersonation_id,
+            method: model.method,
+            path: model.path,
+            status_code: model.status_code.max(0) as u16,
+            created_at: model.created_at.into(),
+        }
+    }
+}
+
+impl From<ImpersonationAction> for ActiveModel {
+    fn from(entity: ImpersonationAction) -> Self {
+        Self {
+            id: Set(entity.id),
+            impersonation_id: Set(entity.impersonation_id),
+            method: Set(entity.method),
+            path: Set(entity.path),
+            status_code: Set(entity.status_code.min(i16::MAX as u16) as i16),
+            created_at: Set(entity.created_at.into()),
+        }
+    }
+}
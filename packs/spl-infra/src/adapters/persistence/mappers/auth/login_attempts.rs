@@ -0,0 +1,13 @@
+use crate::adapters::persistence::entities::auth::login_attempts::Model;
+use spl_domain::entities::auth::LoginAttempts;
+
+impl From<Model> for LoginAttempts {
+    fn from(model: Model) -> Self {
+        Self {
+            user_id: model.user_id,
+            failed_attempts: model.failed_attempts.max(0) as u32,
+            lockout_count: model.lockout_count.max(0) as u32,
+            locked_until: model.locked_until.map(Into::into),
+        }
+    }
+}
@@ -0,0 +1,16 @@
+pub mod api_key;
+pub mod company_password_policy;
+pub mod email_verification_token;
+pub mod identity_provider;
+pub mod impersonation;
+pub mod impersonation_action;
+pub mod login_attempts;
+pub mod oidc_login_state;
+pub mod password_reset_token;
+pub mod recovery_code;
+pub mod refresh_token;
+pub mod service_account;
+pub mod session;
+pub mod two_factor;
+pub mod two_factor_challenge;
+pub mod user_identity;
@@ -0,0 +1,33 @@
+use crate::adapters::persistence::entities::auth::oidc_login_state::{ActiveModel, Model};
+use sea_orm::Set;
+use spl_domain::entities::auth::OidcLoginState;
+
+impl From<Model> for OidcLoginState {
+    fn from(model: Model) -> Self {
+        Self {
+            id: model.id,
+            state_hash: model.state_hash,
+            company_id: model.company_id,
+            nonce: model.nonce,
+            code_verifier: model.code_verifier,
+            expires_at: model.expires_at.into(),
+            used_at: model.used_at.map(Into::into),
+            created_at: model.created_at.into(),
+        }
+    }
+}
+
+impl From<OidcLoginState> for ActiveModel {
+    fn from(entity: OidcLoginState) -> Self {
+        Self {
+            id: Set(entity.id),
+            state_hash: Set(entity.state_hash),
+            company_id: Set(entity.company_id),
+            nonce: Set(entity.nonce),
+            code_verifier: Set(entity.code_verifier),
+            expires_at: Set(entity.expires_at.into()),
+            used_at: Set(entity.used_at.map(Into::into)),
+            created_at: Set(entity.created_at.into()),
+        }
+    }
+}
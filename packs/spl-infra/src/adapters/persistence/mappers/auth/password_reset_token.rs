@@ -0,0 +1,29 @@
+use crate::adapters::persistence::entities::auth::password_reset_token::{ActiveModel, Model};
+use sea_orm::Set;
+use spl_domain::entities::auth::PasswordResetToken;
+
+impl From<Model> for PasswordResetToken {
+    fn from(model: Model) -> Self {
+        Self {
+            id: model.id,
+            user_id: model.user_id,
+            token_hash: model.token_hash,
+            expires_at: model.expires_at.into(),
+            used_at: model.used_at.map(Into::into),
+            created_at: model.created_at.into(),
+        }
+    }
+}
+
+impl From<PasswordResetToken> for ActiveModel {
+    fn from(entity: PasswordResetToken) -> Self {
+        Self {
+            id: Set(entity.id),
+            user_id: Set(entity.user_id),
+            token_hash: Set(entity.token_hash),
+            expires_at: Set(entity.expires_at.into()),
+            used_at: Set(entity.used_at.map(Into::into)),
+            created_at: Set(entity.created_at.into()),
+        }
+    }
+}
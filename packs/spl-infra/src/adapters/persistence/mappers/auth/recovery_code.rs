@@ -0,0 +1,27 @@
+use crate::adapters::persistence::entities::auth::recovery_code::{ActiveModel, Model};
+use sea_orm::Set;
+use spl_domain::entities::auth::RecoveryCode;
+
+impl From<Model> for RecoveryCode {
+    fn from(model: Model) -> Self {
+        Self {
+            id: model.id,
+            user_id: model.user_id,
+            code_hash: model.code_hash,
+            used_at: model.used_at.map(Into::into),
+            created_at: model.created_at.into(),
+        }
+    }
+}
+
+impl From<RecoveryCode> for ActiveModel {
+    fn from(entity: RecoveryCode) -> Self {
+        Self {
+            id: Set(entity.id),
+            user_id: Set(entity.user_id),
+            code_hash: Set(entity.code_hash),
+            used_at: Set(entity.used_at.map(Into::into)),
+            created_at: Set(entity.created_at.into()),
+        }
+    }
+}
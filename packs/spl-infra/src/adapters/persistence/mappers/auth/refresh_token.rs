@@ -0,0 +1,29 @@
+use crate::adapters::persistence::entities::auth::refresh_token::{ActiveModel, Model};
+use sea_orm::Set;
+use spl_domain::entities::auth::RefreshToken;
+
+impl From<Model> for RefreshToken {
+    fn from(model: Model) -> Self {
+        Self {
+            id: model.id,
+            session_id: model.session_id,
+            token_hash: model.token_hash,
+            expires_at: model.expires_at.into(),
+            used_at: model.used_at.map(Into::into),
+            created_at: model.created_at.into(),
+        }
+    }
+}
+
+impl From<RefreshToken> for ActiveModel {
+    fn from(entity: RefreshToken) -> Self {
+        Self {
+            id: Set(entity.id),
+            session_id: Set(entity.session_id),
+            token_hash: Set(entity.token_hash),
+            expires_at: Set(entity.expires_at.into()),
+            used_at: Set(entity.used_at.map(Into::into)),
+            created_at: Set(entity.created_at.into()),
+        }
+    }
+}
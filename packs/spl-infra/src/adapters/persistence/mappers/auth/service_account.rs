@@ -0,0 +1,29 @@
+use crate::adapters::persistence::entities::auth::service_account::{ActiveModel, Model};
+use sea_orm::Set;
+use spl_domain::entities::auth::ServiceAccount;
+
+impl From<Model> for ServiceAccount {
+    fn from(model: Model) -> Self {
+        Self {
+            id: model.id,
+            company_id: model.company_id,
+            name: model.name,
+            description: model.description,
+            created_by: model.created_by,
+            created_at: model.created_at.into(),
+        }
+    }
+}
+
+impl From<ServiceAccount> for ActiveModel {
+    fn from(entity: ServiceAccount) -> Self {
+        Self {
+            id: Set(entity.id),
+            company_id: Set(entity.company_id),
+            name: Set(entity.name),
+            description: Set(entity.description),
+            created_by: Set(entity.created_by),
+            created_at: Set(entity.created_at.into()),
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use crate::adapters::persistence::entities::auth::session::{ActiveModel, Model};
+use sea_orm::Set;
+use spl_domain::entities::auth::Session;
+
+impl From<Model> for Session {
+    fn from(model: Model) -> Self {
+        Self {
+            id: model.id,
+            user_id: model.user_id,
+            expires_at: model.expires_at.into(),
+            revoked_at: model.revoked_at.map(Into::into),
+            user_agent: model.user_agent,
+            ip_address: model.ip_address,
+            active_company_id: model.active_company_id,
+            last_seen_at: model.last_seen_at.into(),
+            created_at: model.created_at.into(),
+        }
+    }
+}
+
+impl From<Session> for ActiveModel {
+    fn from(entity: Session) -> Self {
+        Self {
+            id: Set(entity.id),
+            user_id: Set(entity.user_id),
+            expires_at: Set(entity.expires_at.into()),
+            revoked_at: Set(entity.revoked_at.map(Into::into)),
+            user_agent: Set(entity.user_agent),
+            ip_address: Set(entity.ip_address),
+            active_company_id: Set(entity.active_company_id),
+            last_seen_at: Set(entity.last_seen_at.into()),
+            created_at: Set(entity.created_at.into()),
+        }
+    }
+}
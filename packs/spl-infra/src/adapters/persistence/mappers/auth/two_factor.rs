@@ -0,0 +1,25 @@
+use crate::adapters::persistence::entities::auth::two_factor::{ActiveModel, Model};
+use sea_orm::Set;
+use spl_domain::entities::auth::TwoFactor;
+
+impl From<Model> for TwoFactor {
+    fn from(model: Model) -> Self {
+        Self {
+            user_id: model.user_id,
+            secret: model.secret,
+            enabled_at: model.enabled_at.map(Into::into),
+            created_at: model.created_at.into(),
+        }
+    }
+}
+
+impl From<TwoFactor> for ActiveModel {
+    fn from(entity: TwoFactor) -> Self {
+        Self {
+            user_id: Set(entity.user_id),
+            secret: Set(entity.secret),
+            enabled_at: Set(entity.enabled_at.map(Into::into)),
+            created_at: Set(entity.created_at.into()),
+        }
+    }
+}
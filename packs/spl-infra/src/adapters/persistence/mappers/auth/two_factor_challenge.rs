@@ -0,0 +1,29 @@
+use crate::adapters::persistence::entities::auth::two_factor_challenge::{ActiveModel, Model};
+use sea_orm::Set;
+use spl_domain::entities::auth::TwoFactorChallenge;
+
+impl From<Model> for TwoFactorChallenge {
+    fn from(model: Model) -> Self {
+        Self {
+            id: model.id,
+            user_id: model.user_id,
+            token_hash: model.token_hash,
+            expires_at: model.expires_at.into(),
+            used_at: model.used_at.map(Into::into),
+            created_at: model.created_at.into(),
+        }
+    }
+}
+
+impl From<TwoFactorChallenge> for ActiveModel {
+    fn from(entity: TwoFactorChallenge) -> Self {
+        Self {
+            id: Set(entity.id),
+            user_id: Set(entity.user_id),
+            token_hash: Set(entity.token_hash),
+            expires_at: Set(entity.expires_at.into()),
+            used_at: Set(entity.used_at.map(Into::into)),
+            created_at: Set(entity.created_at.into()),
+        }
+    }
+}
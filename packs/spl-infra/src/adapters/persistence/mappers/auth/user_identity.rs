@@ -0,0 +1,29 @@
+use crate::adapters::persistence::entities::auth::user_identity::{ActiveModel, Model};
+use sea_orm::Set;
+use spl_domain::entities::auth::UserIdentity;
+
+impl From<Model> for UserIdentity {
+    fn from(model: Model) -> Self {
+        Self {
+            id: model.id,
+            user_id: model.user_id,
+            issuer: model.issuer,
+            subject: model.subject,
+            email: model.email,
+            created_at: model.created_at.into(),
+        }
+    }
+}
+
+impl From<UserIdentity> for ActiveModel {
+    fn from(entity: UserIdentity) -> Self {
+        Self {
+            id: Set(entity.id),
+            user_id: Set(entity.user_id),
+            issuer: Set(entity.issuer),
+            subject: Set(entity.subject),
+            email: Set(entity.email),
+            created_at: Set(entity.created_at.into()),
+        }
+    }
+}
@@ -3,12 +3,12 @@ use spl_domain::entities::company::Company;
 use spl_shared::{map_mirror, maps_set};
 
 map_mirror!(Model, Company {
-   id, name, description,
+   id, name, description, parent_id, two_factor_required_level,
     #into [ created_at, updated_at ]
 });
 
 maps_set!(ActiveModel {
-    id, name, description,
+    id, name, description, parent_id, two_factor_required_level,
     #into [ created_at, updated_at ]
   } #from [ Company ]
 );
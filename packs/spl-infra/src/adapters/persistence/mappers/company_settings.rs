@@ -0,0 +1,54 @@
+use crate::adapters::persistence::entities::company_settings::{ActiveModel, Model};
+use sea_orm::Set;
+use spl_domain::entities::company::CompanySettingsOverride;
+use spl_domain::entities::image::ImageFormat;
+
+impl From<Model> for CompanySettingsOverride {
+    fn from(model: Model) -> Self {
+        Self {
+            company_id: model.company_id,
+            timezone: model.timezone,
+            default_language: model.default_language,
+            data_retention_days: model.data_retention_days.map(|days| days.max(0) as u32),
+            default_page_size: model.default_page_size.map(|size| size.max(1) as u64),
+            // Formats no longer supported are dropped
+            allowed_upload_formats: model.allowed_upload_formats.map(|formats| {
+                formats
+                    .split_whitespace()
+                    .filter_map(ImageFormat::parse)
+                    .collect()
+            }),
+            alert_severity_threshold: model.alert_severity_threshold,
+            alert_confidence_threshold: model.alert_confidence_threshold,
+            created_at: model.created_at.into(),
+            updated_at: model.updated_at.into(),
+        }
+    }
+}
+
+impl From<CompanySettingsOverride> for ActiveModel {
+    fn from(entity: CompanySettingsOverride) -> Self {
+        Self {
+            company_id: Set(entity.company_id),
+            timezone: Set(entity.timezone),
+            default_language: Set(entity.default_language),
+            data_retention_days: Set(entity
+                .data_retention_days
+                .map(|days| days.min(i32::MAX as u32) as i32)),
+            default_page_size: Set(entity
+                .default_page_size
+                .map(|size| size.min(i32::MAX as u64) as i32)),
+            allowed_upload_formats: Set(entity.allowed_upload_formats.map(|formats| {
+                formats
+                    .iter()
+                    .map(ImageFormat::as_str)
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            })),
+            alert_severity_threshold: Set(entity.alert_severity_threshold),
+            alert_confidence_threshold: Set(entity.alert_confidence_threshold),
+            created_at: Set(entity.created_at.into()),
+            updated_at: Set(entity.updated_at.into()),
+        }
+    }
+}
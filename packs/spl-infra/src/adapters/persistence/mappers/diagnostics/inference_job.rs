@@ -0,0 +1,48 @@
+use crate::adapters::persistence::entities::diagnostics::inference_job::{ActiveModel, Model};
+use sea_orm::Set;
+use spl_domain::entities::diagnostics::{InferenceJob, JobStatus};
+
+impl From<Model> for InferenceJob {
+    fn from(model: Model) -> Self {
+        Self {
+            id: model.id,
+            user_id: model.user_id,
+            company_id: model.company_id,
+            batch_item_id: model.batch_item_id,
+            filename: model.filename,
+            upload_path: model.upload_path,
+            // Unknown values are dead-lettered rather than predicted twice
+            status: JobStatus::parse(&model.status).unwrap_or(JobStatus::Dead),
+            attempts: model.attempts.max(0) as u32,
+            prediction_id: model.prediction_id,
+            error: model.error,
+            run_after: model.run_after.into(),
+            locked_until: model.locked_until.map(Into::into),
+            created_at: model.created_at.into(),
+            updated_at: model.updated_at.into(),
+            completed_at: model.completed_at.map(Into::into),
+        }
+    }
+}
+
+impl From<InferenceJob> for ActiveModel {
+    fn from(entity: InferenceJob) -> Self {
+        Self {
+            id: Set(entity.id),
+            user_id: Set(entity.user_id),
+            company_id: Set(entity.company_id),
+            batch_item_id: Set(entity.batch_item_id),
+            filename: Set(entity.filename),
+            upload_path: Set(entity.upload_path),
+            status: Set(entity.status.as_str().to_string()),
+            attempts: Set(entity.attempts as i32),
+            prediction_id: Set(entity.prediction_id),
+            error: Set(entity.error),
+            run_after: Set(entity.run_after.into()),
+            locked_until: Set(entity.locked_until.map(Into::into)),
+            created_at: Set(entity.created_at.into()),
+            updated_at: Set(entity.updated_at.into()),
+            completed_at: Set(entity.completed_at.map(Into::into)),
+        }
+    }
+}
@@ -1,4 +1,7 @@
+pub mod inference_job;
 pub mod label;
 pub mod mark_type;
 pub mod prediction;
+pub mod prediction_batch;
+pub mod prediction_batch_item;
 pub mod prediction_mark;
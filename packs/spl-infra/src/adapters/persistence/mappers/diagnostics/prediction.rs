@@ -1,6 +1,6 @@
 use crate::adapters::persistence::entities::diagnostics::prediction::{ActiveModel, Model};
 use sea_orm::Set;
-use spl_domain::entities::diagnostics::{Label, Prediction, PredictionMark};
+use spl_domain::entities::diagnostics::{Label, Prediction, PredictionMark, PredictionModel};
 use spl_domain::entities::feedback::Feedback;
 use spl_domain::entities::image::Image;
 use spl_domain::entities::user::User;
@@ -16,6 +16,20 @@ pub struct PredictionMapperContext {
     pub feedback: Option<Feedback>
 }
 
+impl Model {
+    /// Model recorded with the prediction, none for predictions made before it was recorded
+    fn prediction_model(&self) -> Option<PredictionModel> {
+        Some(PredictionModel {
+            name: self.model_name.clone()?,
+            version: self.model_version.clone(),
+            provider: self.model_provider.clone()?,
+            image_size: self.model_image_size? as u32,
+            leaf_threshold: self.model_leaf_threshold?,
+            lesion_threshold: self.model_lesion_threshold?,
+        })
+    }
+}
+
 impl IntoWithContext<Prediction, PredictionMapperContext> for Model {
     type Error = AppError;
 
@@ -23,9 +37,12 @@ impl IntoWithContext<Prediction, PredictionMapperContext> for Model {
         self,
         context: PredictionMapperContext,
     ) -> Result<Prediction, Self::Error> {
+        let model = self.prediction_model();
+
         Ok(Prediction {
             id: self.id,
             user: context.user,
+            company_id: self.company_id,
             image: context.image,
             label: context.label,
             marks: context.marks,
@@ -33,6 +50,7 @@ impl IntoWithContext<Prediction, PredictionMapperContext> for Model {
             presence_confidence: self.presence_confidence,
             absence_confidence: self.absence_confidence,
             severity: self.severity,
+            model,
             feedback: context.feedback,
             created_at: self.created_at.into(),
         })
@@ -44,12 +62,19 @@ impl From<Prediction> for ActiveModel {
         Self {
             id: Set(entity.id),
             user_id: Set(entity.user.id),
+            company_id: Set(entity.company_id),
             image_id: Set(entity.image.id),
             label_id: Set(entity.label.id),
             plot_id: Set(entity.plot_id),
             presence_confidence: Set(entity.presence_confidence),
             absence_confidence: Set(entity.absence_confidence),
             severity: Set(entity.severity),
+            model_name: Set(entity.model.as_ref().map(|m| m.name.clone())),
+            model_version: Set(entity.model.as_ref().and_then(|m| m.version.clone())),
+            model_provider: Set(entity.model.as_ref().map(|m| m.provider.clone())),
+            model_image_size: Set(entity.model.as_ref().map(|m| m.image_size as i32)),
+            model_leaf_threshold: Set(entity.model.as_ref().map(|m| m.leaf_threshold)),
+            model_lesion_threshold: Set(entity.model.as_ref().map(|m| m.lesion_threshold)),
             created_at: Set(entity.created_at.into()),
         }
     }
@@ -60,12 +85,19 @@ impl From<Prediction> for Model {
         Self {
             id: entity.id,
             user_id: entity.user.id,
+            company_id: entity.company_id,
             image_id: entity.image.id,
             label_id: entity.label.id,
             plot_id: entity.plot_id,
             presence_confidence: entity.presence_confidence,
             absence_confidence: entity.absence_confidence,
             severity: entity.severity,
+            model_name: entity.model.as_ref().map(|m| m.name.clone()),
+            model_version: entity.model.as_ref().and_then(|m| m.version.clone()),
+            model_provider: entity.model.as_ref().map(|m| m.provider.clone()),
+            model_image_size: entity.model.as_ref().map(|m| m.image_size as i32),
+            model_leaf_threshold: entity.model.as_ref().map(|m| m.leaf_threshold),
+            model_lesion_threshold: entity.model.as_ref().map(|m| m.lesion_threshold),
             created_at: entity.created_at.into(),
         }
     }
@@ -0,0 +1,38 @@
+use crate::adapters::persistence::entities::diagnostics::prediction_batch::{ActiveModel, Model};
+use sea_orm::Set;
+use spl_domain::entities::diagnostics::{BatchStatus, PredictionBatch};
+
+impl From<Model> for PredictionBatch {
+    fn from(model: Model) -> Self {
+        Self {
+            id: model.id,
+            user_id: model.user_id,
+            company_id: model.company_id,
+            // Unknown values are left alone rather than processed again
+            status: BatchStatus::parse(&model.status).unwrap_or(BatchStatus::Completed),
+            items_total: model.items_total.max(0) as u64,
+            items_completed: model.items_completed.max(0) as u64,
+            items_failed: model.items_failed.max(0) as u64,
+            created_at: model.created_at.into(),
+            updated_at: model.updated_at.into(),
+            completed_at: model.completed_at.map(Into::into),
+        }
+    }
+}
+
+impl From<PredictionBatch> for ActiveModel {
+    fn from(entity: PredictionBatch) -> Self {
+        Self {
+            id: Set(entity.id),
+            user_id: Set(entity.user_id),
+            company_id: Set(entity.company_id),
+            status: Set(entity.status.as_str().to_string()),
+            items_total: Set(entity.items_total as i64),
+            items_completed: Set(entity.items_completed as i64),
+            items_failed: Set(entity.items_failed as i64),
+            created_at: Set(entity.created_at.into()),
+            updated_at: Set(entity.updated_at.into()),
+            completed_at: Set(entity.completed_at.map(Into::into)),
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use crate::adapters::persistence::entities::diagnostics::prediction_batch_item::{
+    ActiveModel, Model,
+};
+use sea_orm::Set;
+use spl_domain::entities::diagnostics::{BatchItemStatus, PredictionBatchItem};
+
+impl From<Model> for PredictionBatchItem {
+    fn from(model: Model) -> Self {
+        Self {
+            id: model.id,
+            batch_id: model.batch_id,
+            position: model.position.max(0) as u32,
+            filename: model.filename,
+            upload_path: model.upload_path,
+            // Unknown values are left alone rather than predicted twice
+            status: BatchItemStatus::parse(&model.status).unwrap_or(BatchItemStatus::Failed),
+            prediction_id: model.prediction_id,
+            error: model.error,
+            created_at: model.created_at.into(),
+            updated_at: model.updated_at.into(),
+        }
+    }
+}
+
+impl From<PredictionBatchItem> for ActiveModel {
+    fn from(entity: PredictionBatchItem) -> Self {
+        Self {
+            id: Set(entity.id),
+            batch_id: Set(entity.batch_id),
+            position: Set(entity.position as i32),
+            filename: Set(entity.filename),
+            upload_path: Set(entity.upload_path),
+            status: Set(entity.status.as_str().to_string()),
+            prediction_id: Set(entity.prediction_id),
+            error: Set(entity.error),
+            created_at: Set(entity.created_at.into()),
+            updated_at: Set(entity.updated_at.into()),
+        }
+    }
+}
@@ -1,8 +1,14 @@
+pub mod auth;
 pub mod company;
+pub mod company_settings;
 pub mod diagnostics;
 pub mod feedback;
 pub mod image;
+pub mod offboarding;
 pub mod plot;
 pub mod recommendation;
+pub mod team;
+pub mod team_member;
+pub mod usage;
 pub mod user;
 pub mod dashboard;
@@ -0,0 +1,46 @@
+use crate::adapters::persistence::entities::offboarding::company_offboarding::{
+    ActiveModel, Model,
+};
+use sea_orm::Set;
+use spl_domain::entities::offboarding::{CompanyOffboarding, OffboardingStatus};
+
+impl From<Model> for CompanyOffboarding {
+    fn from(model: Model) -> Self {
+        Self {
+            id: model.id,
+            company_id: model.company_id,
+            company_name: model.company_name,
+            requested_by: model.requested_by,
+            // Unknown values stop the job rather than run a step by mistake
+            status: OffboardingStatus::parse(&model.status).unwrap_or(OffboardingStatus::Failed),
+            export_path: model.export_path,
+            export_size: model.export_size.map(|size| size.max(0) as u64),
+            users_total: model.users_total.max(0) as u64,
+            users_deleted: model.users_deleted.max(0) as u64,
+            error: model.error,
+            created_at: model.created_at.into(),
+            updated_at: model.updated_at.into(),
+            completed_at: model.completed_at.map(Into::into),
+        }
+    }
+}
+
+impl From<CompanyOffboarding> for ActiveModel {
+    fn from(entity: CompanyOffboarding) -> Self {
+        Self {
+            id: Set(entity.id),
+            company_id: Set(entity.company_id),
+            company_name: Set(entity.company_name),
+            requested_by: Set(entity.requested_by),
+            status: Set(entity.status.as_str().to_string()),
+            export_path: Set(entity.export_path),
+            export_size: Set(entity.export_size.map(|size| size as i64)),
+            users_total: Set(entity.users_total as i64),
+            users_deleted: Set(entity.users_deleted as i64),
+            error: Set(entity.error),
+            created_at: Set(entity.created_at.into()),
+            updated_at: Set(entity.updated_at.into()),
+            completed_at: Set(entity.completed_at.map(Into::into)),
+        }
+    }
+}
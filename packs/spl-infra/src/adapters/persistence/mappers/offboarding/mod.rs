@@ -0,0 +1,2 @@
+pub mod company_offboarding;
+pub mod offboarding_event;
@@ -0,0 +1,29 @@
+use crate::adapters::persistence::entities::offboarding::offboarding_event::{ActiveModel, Model};
+use sea_orm::Set;
+use spl_domain::entities::offboarding::OffboardingEvent;
+
+impl From<Model> for OffboardingEvent {
+    fn from(model: Model) -> Self {
+        Self {
+            id: model.id,
+            offboarding_id: model.offboarding_id,
+            actor_id: model.actor_id,
+            action: model.action,
+            detail: model.detail,
+            created_at: model.created_at.into(),
+        }
+    }
+}
+
+impl From<OffboardingEvent> for ActiveModel {
+    fn from(entity: OffboardingEvent) -> Self {
+        Self {
+            id: Set(entity.id),
+            offboarding_id: Set(entity.offboarding_id),
+            actor_id: Set(entity.actor_id),
+            action: Set(entity.action),
+            detail: Set(entity.detail),
+            created_at: Set(entity.created_at.into()),
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use crate::adapters::persistence::entities::team::{ActiveModel, Model};
+use sea_orm::Set;
+use spl_domain::entities::team::Team;
+
+impl From<Model> for Team {
+    fn from(model: Model) -> Self {
+        Self {
+            id: model.id,
+            company_id: model.company_id,
+            name: model.name,
+            description: model.description,
+            created_at: model.created_at.into(),
+            updated_at: model.updated_at.into(),
+        }
+    }
+}
+
+impl From<Team> for ActiveModel {
+    fn from(entity: Team) -> Self {
+        Self {
+            id: Set(entity.id),
+            company_id: Set(entity.company_id),
+            name: Set(entity.name),
+            description: Set(entity.description),
+            created_at: Set(entity.created_at.into()),
+            updated_at: Set(entity.updated_at.into()),
+        }
+    }
+}
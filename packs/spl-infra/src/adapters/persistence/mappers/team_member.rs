@@ -0,0 +1,26 @@
+use crate::adapters::persistence::entities::team_member::{ActiveModel, Model};
+use sea_orm::Set;
+use spl_domain::entities::team::{TeamMember, TeamRole};
+
+impl From<Model> for TeamMember {
+    fn from(model: Model) -> Self {
+        Self {
+            team_id: model.team_id,
+            user_id: model.user_id,
+            // Unknown values grant no visibility
+            role: TeamRole::parse(&model.role).unwrap_or(TeamRole::Member),
+            created_at: model.created_at.into(),
+        }
+    }
+}
+
+impl From<TeamMember> for ActiveModel {
+    fn from(entity: TeamMember) -> Self {
+        Self {
+            team_id: Set(entity.team_id),
+            user_id: Set(entity.user_id),
+            role: Set(entity.role.as_str().to_string()),
+            created_at: Set(entity.created_at.into()),
+        }
+    }
+}
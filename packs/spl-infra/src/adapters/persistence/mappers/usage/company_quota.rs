@@ -0,0 +1,45 @@
+use crate::adapters::persistence::entities::usage::company_quota::{ActiveModel, Model};
+use sea_orm::Set;
+use spl_domain::entities::usage::{CompanyQuota, QuotaEnforcement, UsageQuota};
+
+fn to_limit(value: Option<u64>) -> Option<i64> {
+    value.map(|limit| limit.min(i64::MAX as u64) as i64)
+}
+
+impl From<Model> for CompanyQuota {
+    fn from(model: Model) -> Self {
+        Self {
+            company_id: model.company_id,
+            quota: UsageQuota {
+                predictions_per_month: model.predictions_per_month.map(|l| l.max(0) as u64),
+                predictions_per_user_per_month: model
+                    .predictions_per_user_per_month
+                    .map(|l| l.max(0) as u64),
+                stored_bytes_per_month: model.stored_bytes_per_month.map(|l| l.max(0) as u64),
+                warning_ratio: model.warning_ratio,
+                // Unknown values are enforced rather than ignored
+                enforcement: QuotaEnforcement::parse(&model.enforcement)
+                    .unwrap_or(QuotaEnforcement::Hard),
+            },
+            created_at: model.created_at.into(),
+            updated_at: model.updated_at.into(),
+        }
+    }
+}
+
+impl From<CompanyQuota> for ActiveModel {
+    fn from(entity: CompanyQuota) -> Self {
+        let quota = entity.quota;
+
+        Self {
+            company_id: Set(entity.company_id),
+            predictions_per_month: Set(to_limit(quota.predictions_per_month)),
+            predictions_per_user_per_month: Set(to_limit(quota.predictions_per_user_per_month)),
+            stored_bytes_per_month: Set(to_limit(quota.stored_bytes_per_month)),
+            warning_ratio: Set(quota.warning_ratio),
+            enforcement: Set(quota.enforcement.as_str().to_string()),
+            created_at: Set(entity.created_at.into()),
+            updated_at: Set(entity.updated_at.into()),
+        }
+    }
+}
@@ -0,0 +1,15 @@
+use crate::adapters::persistence::entities::usage::usage_counter::Model;
+use spl_domain::entities::usage::UsageRecord;
+
+impl From<Model> for UsageRecord {
+    fn from(model: Model) -> Self {
+        Self {
+            user_id: model.user_id,
+            company_id: model.company_id,
+            period: model.period,
+            predictions: model.predictions.max(0) as u64,
+            stored_bytes: model.stored_bytes.max(0) as u64,
+            updated_at: model.updated_at.into(),
+        }
+    }
+}
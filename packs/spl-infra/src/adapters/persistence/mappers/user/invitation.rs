@@ -0,0 +1,39 @@
+use crate::adapters::persistence::entities::user::invitation::{ActiveModel, Model};
+use sea_orm::Set;
+use spl_domain::entities::user::Invitation;
+
+impl From<Model> for Invitation {
+    fn from(model: Model) -> Self {
+        Self {
+            id: model.id,
+            company_id: model.company_id,
+            email: model.email,
+            role_id: model.role_id,
+            token_hash: model.token_hash,
+            invited_by: model.invited_by,
+            expires_at: model.expires_at.into(),
+            accepted_at: model.accepted_at.map(Into::into),
+            cancelled_at: model.cancelled_at.map(Into::into),
+            created_at: model.created_at.into(),
+            updated_at: model.updated_at.into(),
+        }
+    }
+}
+
+impl From<Invitation> for ActiveModel {
+    fn from(entity: Invitation) -> Self {
+        Self {
+            id: Set(entity.id),
+            company_id: Set(entity.company_id),
+            email: Set(entity.email),
+            role_id: Set(entity.role_id),
+            token_hash: Set(entity.token_hash),
+            invited_by: Set(entity.invited_by),
+            expires_at: Set(entity.expires_at.into()),
+            accepted_at: Set(entity.accepted_at.map(Into::into)),
+            cancelled_at: Set(entity.cancelled_at.map(Into::into)),
+            created_at: Set(entity.created_at.into()),
+            updated_at: Set(entity.updated_at.into()),
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use crate::adapters::persistence::entities::user::membership::Model;
+use spl_domain::entities::company::Company;
+use spl_domain::entities::user::{CompanyMembership, Role};
+use spl_shared::error::AppError;
+use spl_shared::traits::IntoWithContext;
+
+pub struct MembershipMapperContext {
+    pub company: Company,
+    pub role: Role,
+}
+
+impl IntoWithContext<CompanyMembership, MembershipMapperContext> for Model {
+    type Error = AppError;
+
+    fn into_with_context(
+        self,
+        context: MembershipMapperContext,
+    ) -> Result<CompanyMembership, Self::Error> {
+        Ok(CompanyMembership {
+            user_id: self.user_id,
+            company: context.company,
+            role: context.role,
+            created_at: self.created_at.into(),
+        })
+    }
+}
@@ -1,2 +1,4 @@
+pub mod invitation;
+pub mod membership;
 pub mod role;
 pub mod user;
@@ -18,11 +18,13 @@ impl IntoWithContext<User, UserMapperContext> for Model {
             id: self.id,
             username: self.username,
             email: self.email,
+            email_verified_at: self.email_verified_at.map(Into::into),
             password_hash: self.password_hash,
             name: self.name,
             surname: self.surname,
             role: context.role,
             company: context.company,
+            deactivated_at: self.deactivated_at.map(Into::into),
             created_at: self.created_at.into(),
             updated_at: self.updated_at.into(),
         })
@@ -35,11 +37,13 @@ impl From<User> for ActiveModel {
             id: Set(entity.id),
             username: Set(entity.username),
             email: Set(entity.email),
+            email_verified_at: Set(entity.email_verified_at.map(Into::into)),
             password_hash: Set(entity.password_hash),
             name: Set(entity.name),
             surname: Set(entity.surname),
             role_id: Set(entity.role.id),
             company_id: Set(entity.company.map(|c| c.id)), // Extract ID from nested company
+            deactivated_at: Set(entity.deactivated_at.map(Into::into)),
             created_at: Set(entity.created_at.into()),
             updated_at: Set(entity.updated_at.into()),
         }
@@ -0,0 +1,92 @@
+use crate::adapters::persistence::entities::auth::api_key;
+use chrono::{DateTime, Utc};
+use sea_orm::prelude::Expr;
+use sea_orm::*;
+use spl_domain::entities::auth::ApiKey;
+use spl_domain::ports::repositories::auth::ApiKeyRepository;
+use spl_domain::ports::repositories::crud::CrudRepository;
+use spl_shared::adapters::persistence::repository::crud;
+use spl_shared::error::{AppError, Result};
+use uuid::Uuid;
+
+pub struct DbApiKeyRepository {
+    db: DatabaseConnection,
+}
+
+impl DbApiKeyRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait::async_trait]
+impl CrudRepository<ApiKey, Uuid> for DbApiKeyRepository {
+    async fn get_by_id(&self, id: Uuid) -> Result<Option<ApiKey>> {
+        crud::get_by_id::<api_key::Entity, ApiKey, Uuid>(&self.db, id).await
+    }
+
+    async fn create(&self, entity: ApiKey) -> Result<ApiKey> {
+        crud::create::<api_key::Entity, ApiKey>(&self.db, entity).await
+    }
+
+    async fn update(&self, entity: ApiKey) -> Result<ApiKey> {
+        crud::update::<api_key::Entity, ApiKey>(&self.db, entity).await
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<ApiKey> {
+        crud::delete::<api_key::Entity, ApiKey, Uuid>(&self.db, id).await
+    }
+}
+
+#[async_trait::async_trait]
+impl ApiKeyRepository for DbApiKeyRepository {
+    async fn get_by_key_hash(&self, key_hash: &str) -> Result<Option<ApiKey>> {
+        let model = api_key::Entity::find()
+            .filter(api_key::Column::KeyHash.eq(key_hash))
+            .one(&self.db)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(model.map(Into::into))
+    }
+
+    async fn get_by_service_account_id(&self, service_account_id: Uuid) -> Result<Vec<ApiKey>> {
+        let models = api_key::Entity::find()
+            .filter(api_key::Column::ServiceAccountId.eq(service_account_id))
+            .order_by_asc(api_key::Column::CreatedAt)
+            .all(&self.db)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(models.into_iter().map(Into::into).collect())
+    }
+
+    async fn revoke(&self, id: Uuid) -> Result<bool> {
+        let result = api_key::Entity::update_many()
+            .col_expr(
+                api_key::Column::RevokedAt,
+                Expr::value(Utc::now().fixed_offset()),
+            )
+            .filter(api_key::Column::Id.eq(id))
+            .filter(api_key::Column::RevokedAt.is_null())
+            .exec(&self.db)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    async fn touch(&self, id: Uuid, used_at: DateTime<Utc>) -> Result<()> {
+        api_key::Entity::update_many()
+            .col_expr(
+                api_key::Column::LastUsedAt,
+                Expr::value(used_at.fixed_offset()),
+            )
+            .filter(api_key::Column::Id.eq(id))
+            .exec(&self.db)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(())
+    }
+}
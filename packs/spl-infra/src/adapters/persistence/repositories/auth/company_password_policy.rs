@@ -0,0 +1,48 @@
+use crate::adapters::persistence::entities::auth::company_password_policy;
+use sea_orm::*;
+use spl_domain::entities::auth::CompanyPasswordPolicy;
+use spl_domain::ports::repositories::auth::CompanyPasswordPolicyRepository;
+use spl_domain::ports::repositories::crud::CrudRepository;
+use spl_shared::adapters::persistence::repository::crud;
+use spl_shared::error::Result;
+use uuid::Uuid;
+
+pub struct DbCompanyPasswordPolicyRepository {
+    db: DatabaseConnection,
+}
+
+impl DbCompanyPasswordPolicyRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait::async_trait]
+impl CrudRepository<CompanyPasswordPolicy, Uuid> for DbCompanyPasswordPolicyRepository {
+    async fn get_by_id(&self, company_id: Uuid) -> Result<Option<CompanyPasswordPolicy>> {
+        crud::get_by_id::<company_password_policy::Entity, CompanyPasswordPolicy, Uuid>(
+            &self.db, company_id,
+        )
+        .await
+    }
+
+    async fn create(&self, entity: CompanyPasswordPolicy) -> Result<CompanyPasswordPolicy> {
+        crud::create::<company_password_policy::Entity, CompanyPasswordPolicy>(&self.db, entity)
+            .await
+    }
+
+    async fn update(&self, entity: CompanyPasswordPolicy) -> Result<CompanyPasswordPolicy> {
+        crud::update::<company_password_policy::Entity, CompanyPasswordPolicy>(&self.db, entity)
+            .await
+    }
+
+    async fn delete(&self, company_id: Uuid) -> Result<CompanyPasswordPolicy> {
+        crud::delete::<company_password_policy::Entity, CompanyPasswordPolicy, Uuid>(
+            &self.db, company_id,
+        )
+        .await
+    }
+}
+
+#[async_trait::async_trait]
+impl CompanyPasswordPolicyRepository for DbCompanyPasswordPolicyRepository {}
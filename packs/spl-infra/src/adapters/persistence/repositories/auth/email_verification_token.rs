@@ -0,0 +1,88 @@
+use crate::adapters::persistence::entities::auth::email_verification_token;
+use chrono::Utc;
+use sea_orm::prelude::Expr;
+use sea_orm::*;
+use spl_domain::entities::auth::EmailVerificationToken;
+use spl_domain::ports::repositories::auth::EmailVerificationTokenRepository;
+use spl_domain::ports::repositories::crud::CrudRepository;
+use spl_shared::adapters::persistence::repository::crud;
+use spl_shared::error::{AppError, Result};
+use uuid::Uuid;
+
+pub struct DbEmailVerificationTokenRepository {
+    db: DatabaseConnection,
+}
+
+impl DbEmailVerificationTokenRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait::async_trait]
+impl CrudRepository<EmailVerificationToken, Uuid> for DbEmailVerificationTokenRepository {
+    async fn get_by_id(&self, id: Uuid) -> Result<Option<EmailVerificationToken>> {
+        crud::get_by_id::<email_verification_token::Entity, EmailVerificationToken, Uuid>(
+            &self.db, id,
+        )
+        .await
+    }
+
+    async fn create(&self, entity: EmailVerificationToken) -> Result<EmailVerificationToken> {
+        crud::create::<email_verification_token::Entity, EmailVerificationToken>(&self.db, entity)
+            .await
+    }
+
+    async fn update(&self, entity: EmailVerificationToken) -> Result<EmailVerificationToken> {
+        crud::update::<email_verification_token::Entity, EmailVerificationToken>(&self.db, entity)
+            .await
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<EmailVerificationToken> {
+        crud::delete::<email_verification_token::Entity, EmailVerificationToken, Uuid>(&self.db, id)
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl EmailVerificationTokenRepository for DbEmailVerificationTokenRepository {
+    async fn get_by_token_hash(&self, token_hash: &str) -> Result<Option<EmailVerificationToken>> {
+        let model = email_verification_token::Entity::find()
+            .filter(email_verification_token::Column::TokenHash.eq(token_hash))
+            .one(&self.db)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(model.map(Into::into))
+    }
+
+    async fn mark_used(&self, id: Uuid) -> Result<bool> {
+        let result = email_verification_token::Entity::update_many()
+            .col_expr(
+                email_verification_token::Column::UsedAt,
+                Expr::value(Utc::now().fixed_offset()),
+            )
+            .filter(email_verification_token::Column::Id.eq(id))
+            .filter(email_verification_token::Column::UsedAt.is_null())
+            .exec(&self.db)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    async fn invalidate_by_user_id(&self, user_id: Uuid) -> Result<u64> {
+        let result = email_verification_token::Entity::update_many()
+            .col_expr(
+                email_verification_token::Column::UsedAt,
+                Expr::value(Utc::now().fixed_offset()),
+            )
+            .filter(email_verification_token::Column::UserId.eq(user_id))
+            .filter(email_verification_token::Column::UsedAt.is_null())
+            .exec(&self.db)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(result.rows_affected)
+    }
+}
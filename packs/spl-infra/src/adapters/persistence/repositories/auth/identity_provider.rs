@@ -0,0 +1,42 @@
+use crate::adapters::persistence::entities::auth::identity_provider;
+use sea_orm::*;
+use spl_domain::entities::auth::IdentityProvider;
+use spl_domain::ports::repositories::auth::IdentityProviderRepository;
+use spl_domain::ports::repositories::crud::CrudRepository;
+use spl_shared::adapters::persistence::repository::crud;
+use spl_shared::error::Result;
+use uuid::Uuid;
+
+pub struct DbIdentityProviderRepository {
+    db: DatabaseConnection,
+}
+
+impl DbIdentityProviderRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait::async_trait]
+impl CrudRepository<IdentityProvider, Uuid> for DbIdentityProviderRepository {
+    async fn get_by_id(&self, company_id: Uuid) -> Result<Option<IdentityProvider>> {
+        crud::get_by_id::<identity_provider::Entity, IdentityProvider, Uuid>(&self.db, company_id)
+            .await
+    }
+
+    async fn create(&self, entity: IdentityProvider) -> Result<IdentityProvider> {
+        crud::create::<identity_provider::Entity, IdentityProvider>(&self.db, entity).await
+    }
+
+    async fn update(&self, entity: IdentityProvider) -> Result<IdentityProvider> {
+        crud::update::<identity_provider::Entity, IdentityProvider>(&self.db, entity).await
+    }
+
+    async fn delete(&self, company_id: Uuid) -> Result<IdentityProvider> {
+        crud::delete::<identity_provider::Entity, IdentityProvider, Uuid>(&self.db, company_id)
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl IdentityProviderRepository for DbIdentityProviderRepository {}
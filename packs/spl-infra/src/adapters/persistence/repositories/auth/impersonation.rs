@@ -0,0 +1,72 @@
+use crate::adapters::persistence::entities::auth::impersonation;
+use chrono::Utc;
+use sea_orm::prelude::Expr;
+use sea_orm::*;
+use spl_domain::entities::auth::Impersonation;
+use spl_domain::ports::repositories::auth::ImpersonationRepository;
+use spl_domain::ports::repositories::crud::CrudRepository;
+use spl_shared::adapters::persistence::repository::crud;
+use spl_shared::error::{AppError, Result};
+use uuid::Uuid;
+
+pub struct DbImpersonationRepository {
+    db: DatabaseConnection,
+}
+
+impl DbImpersonationRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait::async_trait]
+impl CrudRepository<Impersonation, Uuid> for DbImpersonationRepository {
+    async fn get_by_id(&self, id: Uuid) -> Result<Option<Impersonation>> {
+        crud::get_by_id::<impersonation::Entity, Impersonation, Uuid>(&self.db, id).await
+    }
+
+    async fn create(&self, entity: Impersonation) -> Result<Impersonation> {
+        crud::create::<impersonation::Entity, Impersonation>(&self.db, entity).await
+    }
+
+    async fn update(&self, entity: Impersonation) -> Result<Impersonation> {
+        crud::update::<impersonation::Entity, Impersonation>(&self.db, entity).await
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<Impersonation> {
+        crud::delete::<impersonation::Entity, Impersonation, Uuid>(&self.db, id).await
+    }
+}
+
+#[async_trait::async_trait]
+impl ImpersonationRepository for DbImpersonationRepository {
+    async fn end(&self, id: Uuid) -> Result<bool> {
+        let result = impersonation::Entity::update_many()
+            .col_expr(
+                impersonation::Column::EndedAt,
+                Expr::value(Utc::now().fixed_offset()),
+            )
+            .filter(impersonation::Column::Id.eq(id))
+            .filter(impersonation::Column::EndedAt.is_null())
+            .exec(&self.db)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    async fn get_by_user_id(&self, user_id: Uuid) -> Result<Vec<Impersonation>> {
+        let models = impersonation::Entity::find()
+            .filter(
+                Condition::any()
+                    .add(impersonation::Column::ActorId.eq(user_id))
+                    .add(impersonation::Column::SubjectId.eq(user_id)),
+            )
+            .order_by_desc(impersonation::Column::StartedAt)
+            .all(&self.db)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(models.into_iter().map(Into::into).collect())
+    }
+}
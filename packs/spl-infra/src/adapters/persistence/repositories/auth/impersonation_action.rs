@@ -0,0 +1,55 @@
+use crate::adapters::persistence::entities::auth::impersonation_action;
+use sea_orm::*;
+use spl_domain::entities::auth::ImpersonationAction;
+use spl_domain::ports::repositories::auth::ImpersonationActionRepository;
+use spl_domain::ports::repositories::crud::CrudRepository;
+use spl_shared::adapters::persistence::repository::crud;
+use spl_shared::error::{AppError, Result};
+use uuid::Uuid;
+
+pub struct DbImpersonationActionRepository {
+    db: DatabaseConnection,
+}
+
+impl DbImpersonationActionRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait::async_trait]
+impl CrudRepository<ImpersonationAction, Uuid> for DbImpersonationActionRepository {
+    async fn get_by_id(&self, id: Uuid) -> Result<Option<ImpersonationAction>> {
+        crud::get_by_id::<impersonation_action::Entity, ImpersonationAction, Uuid>(&self.db, id)
+            .await
+    }
+
+    async fn create(&self, entity: ImpersonationAction) -> Result<ImpersonationAction> {
+        crud::create::<impersonation_action::Entity, ImpersonationAction>(&self.db, entity).await
+    }
+
+    async fn update(&self, entity: ImpersonationAction) -> Result<ImpersonationAction> {
+        crud::update::<impersonation_action::Entity, ImpersonationAction>(&self.db, entity).await
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<ImpersonationAction> {
+        crud::delete::<impersonation_action::Entity, ImpersonationAction, Uuid>(&self.db, id).await
+    }
+}
+
+#[async_trait::async_trait]
+impl ImpersonationActionRepository for DbImpersonationActionRepository {
+    async fn get_by_impersonation_id(
+        &self,
+        impersonation_id: Uuid,
+    ) -> Result<Vec<ImpersonationAction>> {
+        let models = impersonation_action::Entity::find()
+            .filter(impersonation_action::Column::ImpersonationId.eq(impersonation_id))
+            .order_by_asc(impersonation_action::Column::CreatedAt)
+            .all(&self.db)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(models.into_iter().map(Into::into).collect())
+    }
+}
@@ -0,0 +1,139 @@
+use crate::adapters::persistence::entities::auth::login_attempts;
+use chrono::{DateTime, Duration, Utc};
+use sea_orm::prelude::Expr;
+use sea_orm::sea_query::OnConflict;
+use sea_orm::*;
+use spl_domain::entities::auth::LoginAttempts;
+use spl_domain::ports::auth::LoginAttemptStore;
+use spl_shared::error::{AppError, Result};
+use uuid::Uuid;
+
+/// Database backed login attempt store, used when Redis is not available.
+/// Rows are kept until `expires_at`; expired rows are ignored and overwritten.
+pub struct DbLoginAttemptStore {
+    db: DatabaseConnection,
+    window: Duration,
+}
+
+impl DbLoginAttemptStore {
+    pub fn new(db: DatabaseConnection, failure_window_seconds: u64) -> Self {
+        Self {
+            db,
+            window: Duration::seconds(failure_window_seconds as i64),
+        }
+    }
+
+    /// Replaces a missing or expired row with a new one
+    async fn reset(
+        &self,
+        user_id: Uuid,
+        failed_attempts: i32,
+        lockout_count: i32,
+        locked_until: Option<DateTime<Utc>>,
+        expires_at: DateTime<Utc>,
+    ) -> Result<LoginAttempts> {
+        let model = login_attempts::ActiveModel {
+            user_id: Set(user_id),
+            failed_attempts: Set(failed_attempts),
+            lockout_count: Set(lockout_count),
+            locked_until: Set(locked_until.map(Into::into)),
+            expires_at: Set(expires_at.into()),
+        };
+
+        let model = login_attempts::Entity::insert(model)
+            .on_conflict(
+                OnConflict::column(login_attempts::Column::UserId)
+                    .update_columns([
+                        login_attempts::Column::FailedAttempts,
+                        login_attempts::Column::LockoutCount,
+                        login_attempts::Column::LockedUntil,
+                        login_attempts::Column::ExpiresAt,
+                    ])
+                    .to_owned(),
+            )
+            .exec_with_returning(&self.db)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(model.into())
+    }
+}
+
+#[async_trait::async_trait]
+impl LoginAttemptStore for DbLoginAttemptStore {
+    async fn get(&self, user_id: Uuid) -> Result<Option<LoginAttempts>> {
+        let model = login_attempts::Entity::find_by_id(user_id)
+            .filter(login_attempts::Column::ExpiresAt.gt(Utc::now().fixed_offset()))
+            .one(&self.db)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(model.map(Into::into))
+    }
+
+    async fn record_failure(&self, user_id: Uuid) -> Result<LoginAttempts> {
+        let now = Utc::now();
+        let expires_at = now + self.window;
+
+        // Atomic increment of a live row
+        let updated = login_attempts::Entity::update_many()
+            .col_expr(
+                login_attempts::Column::FailedAttempts,
+                Expr::col(login_attempts::Column::FailedAttempts).add(1),
+            )
+            .col_expr(
+                login_attempts::Column::ExpiresAt,
+                Expr::value(expires_at.fixed_offset()),
+            )
+            .filter(login_attempts::Column::UserId.eq(user_id))
+            .filter(login_attempts::Column::ExpiresAt.gt(now.fixed_offset()))
+            .exec_with_returning(&self.db)
+            .await
+            .map_err(AppError::from)?;
+
+        match updated.into_iter().next() {
+            Some(model) => Ok(model.into()),
+            None => self.reset(user_id, 1, 0, None, expires_at).await,
+        }
+    }
+
+    async fn lock(&self, user_id: Uuid, until: DateTime<Utc>) -> Result<LoginAttempts> {
+        let now = Utc::now();
+        let expires_at = until + self.window;
+
+        let updated = login_attempts::Entity::update_many()
+            .col_expr(login_attempts::Column::FailedAttempts, Expr::value(0))
+            .col_expr(
+                login_attempts::Column::LockoutCount,
+                Expr::col(login_attempts::Column::LockoutCount).add(1),
+            )
+            .col_expr(
+                login_attempts::Column::LockedUntil,
+                Expr::value(until.fixed_offset()),
+            )
+            .col_expr(
+                login_attempts::Column::ExpiresAt,
+                Expr::value(expires_at.fixed_offset()),
+            )
+            .filter(login_attempts::Column::UserId.eq(user_id))
+            .filter(login_attempts::Column::ExpiresAt.gt(now.fixed_offset()))
+            .exec_with_returning(&self.db)
+            .await
+            .map_err(AppError::from)?;
+
+        match updated.into_iter().next() {
+            Some(model) => Ok(model.into()),
+            None => self.reset(user_id, 0, 1, Some(until), expires_at).await,
+        }
+    }
+
+    async fn clear(&self, user_id: Uuid) -> Result<()> {
+        login_attempts::Entity::delete_many()
+            .filter(login_attempts::Column::UserId.eq(user_id))
+            .exec(&self.db)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(())
+    }
+}
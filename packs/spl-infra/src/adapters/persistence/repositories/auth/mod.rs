@@ -0,0 +1,35 @@
+pub mod api_key;
+pub mod company_password_policy;
+pub mod email_verification_token;
+pub mod identity_provider;
+pub mod impersonation;
+pub mod impersonation_action;
+pub mod login_attempts;
+pub mod oidc_login_state;
+pub mod password_history;
+pub mod password_reset_token;
+pub mod recovery_code;
+pub mod refresh_token;
+pub mod service_account;
+pub mod session;
+pub mod two_factor;
+pub mod two_factor_challenge;
+pub mod user_identity;
+
+pub use api_key::DbApiKeyRepository;
+pub use company_password_policy::DbCompanyPasswordPolicyRepository;
+pub use email_verification_token::DbEmailVerificationTokenRepository;
+pub use identity_provider::DbIdentityProviderRepository;
+pub use impersonation::DbImpersonationRepository;
+pub use impersonation_action::DbImpersonationActionRepository;
+pub use login_attempts::DbLoginAttemptStore;
+pub use oidc_login_state::DbOidcLoginStateRepository;
+pub use password_history::DbPasswordHistoryRepository;
+pub use password_reset_token::DbPasswordResetTokenRepository;
+pub use recovery_code::DbRecoveryCodeRepository;
+pub use refresh_token::DbRefreshTokenRepository;
+pub use service_account::DbServiceAccountRepository;
+pub use session::DbSessionRepository;
+pub use two_factor::DbTwoFactorRepository;
+pub use two_factor_challenge::DbTwoFactorChallengeRepository;
+pub use user_identity::DbUserIdentityRepository;
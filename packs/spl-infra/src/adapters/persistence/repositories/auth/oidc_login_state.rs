@@ -0,0 +1,67 @@
+use crate::adapters::persistence::entities::auth::oidc_login_state;
+use chrono::Utc;
+use sea_orm::prelude::Expr;
+use sea_orm::*;
+use spl_domain::entities::auth::OidcLoginState;
+use spl_domain::ports::repositories::auth::OidcLoginStateRepository;
+use spl_domain::ports::repositories::crud::CrudRepository;
+use spl_shared::adapters::persistence::repository::crud;
+use spl_shared::error::{AppError, Result};
+use uuid::Uuid;
+
+pub struct DbOidcLoginStateRepository {
+    db: DatabaseConnection,
+}
+
+impl DbOidcLoginStateRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait::async_trait]
+impl CrudRepository<OidcLoginState, Uuid> for DbOidcLoginStateRepository {
+    async fn get_by_id(&self, id: Uuid) -> Result<Option<OidcLoginState>> {
+        crud::get_by_id::<oidc_login_state::Entity, OidcLoginState, Uuid>(&self.db, id).await
+    }
+
+    async fn create(&self, entity: OidcLoginState) -> Result<OidcLoginState> {
+        crud::create::<oidc_login_state::Entity, OidcLoginState>(&self.db, entity).await
+    }
+
+    async fn update(&self, entity: OidcLoginState) -> Result<OidcLoginState> {
+        crud::update::<oidc_login_state::Entity, OidcLoginState>(&self.db, entity).await
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<OidcLoginState> {
+        crud::delete::<oidc_login_state::Entity, OidcLoginState, Uuid>(&self.db, id).await
+    }
+}
+
+#[async_trait::async_trait]
+impl OidcLoginStateRepository for DbOidcLoginStateRepository {
+    async fn get_by_state_hash(&self, state_hash: &str) -> Result<Option<OidcLoginState>> {
+        let model = oidc_login_state::Entity::find()
+            .filter(oidc_login_state::Column::StateHash.eq(state_hash))
+            .one(&self.db)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(model.map(Into::into))
+    }
+
+    async fn mark_used(&self, id: Uuid) -> Result<bool> {
+        let result = oidc_login_state::Entity::update_many()
+            .col_expr(
+                oidc_login_state::Column::UsedAt,
+                Expr::value(Utc::now().fixed_offset()),
+            )
+            .filter(oidc_login_state::Column::Id.eq(id))
+            .filter(oidc_login_state::Column::UsedAt.is_null())
+            .exec(&self.db)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(result.rows_affected > 0)
+    }
+}
@@ -0,0 +1,72 @@
+use crate::adapters::persistence::entities::auth::password_history;
+use chrono::Utc;
+use sea_orm::*;
+use spl_domain::ports::repositories::auth::PasswordHistoryRepository;
+use spl_shared::error::{AppError, Result};
+use uuid::Uuid;
+
+pub struct DbPasswordHistoryRepository {
+    db: DatabaseConnection,
+}
+
+impl DbPasswordHistoryRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait::async_trait]
+impl PasswordHistoryRepository for DbPasswordHistoryRepository {
+    async fn get_recent(&self, user_id: Uuid, limit: u64) -> Result<Vec<String>> {
+        let models = password_history::Entity::find()
+            .filter(password_history::Column::UserId.eq(user_id))
+            .order_by_desc(password_history::Column::CreatedAt)
+            .limit(limit)
+            .all(&self.db)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(models
+            .into_iter()
+            .map(|model| model.password_hash)
+            .collect())
+    }
+
+    async fn add(&self, user_id: Uuid, password_hash: &str) -> Result<()> {
+        let model = password_history::ActiveModel {
+            id: Set(Uuid::new_v4()),
+            user_id: Set(user_id),
+            password_hash: Set(password_hash.to_string()),
+            created_at: Set(Utc::now().fixed_offset()),
+        };
+
+        password_history::Entity::insert(model)
+            .exec_without_returning(&self.db)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(())
+    }
+
+    async fn prune(&self, user_id: Uuid, keep: u64) -> Result<u64> {
+        let kept: Vec<Uuid> = password_history::Entity::find()
+            .select_only()
+            .column(password_history::Column::Id)
+            .filter(password_history::Column::UserId.eq(user_id))
+            .order_by_desc(password_history::Column::CreatedAt)
+            .limit(keep)
+            .into_tuple()
+            .all(&self.db)
+            .await
+            .map_err(AppError::from)?;
+
+        let result = password_history::Entity::delete_many()
+            .filter(password_history::Column::UserId.eq(user_id))
+            .filter(password_history::Column::Id.is_not_in(kept))
+            .exec(&self.db)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(result.rows_affected)
+    }
+}
@@ -0,0 +1,83 @@
+use crate::adapters::persistence::entities::auth::password_reset_token;
+use chrono::Utc;
+use sea_orm::prelude::Expr;
+use sea_orm::*;
+use spl_domain::entities::auth::PasswordResetToken;
+use spl_domain::ports::repositories::auth::PasswordResetTokenRepository;
+use spl_domain::ports::repositories::crud::CrudRepository;
+use spl_shared::adapters::persistence::repository::crud;
+use spl_shared::error::{AppError, Result};
+use uuid::Uuid;
+
+pub struct DbPasswordResetTokenRepository {
+    db: DatabaseConnection,
+}
+
+impl DbPasswordResetTokenRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait::async_trait]
+impl CrudRepository<PasswordResetToken, Uuid> for DbPasswordResetTokenRepository {
+    async fn get_by_id(&self, id: Uuid) -> Result<Option<PasswordResetToken>> {
+        crud::get_by_id::<password_reset_token::Entity, PasswordResetToken, Uuid>(&self.db, id)
+            .await
+    }
+
+    async fn create(&self, entity: PasswordResetToken) -> Result<PasswordResetToken> {
+        crud::create::<password_reset_token::Entity, PasswordResetToken>(&self.db, entity).await
+    }
+
+    async fn update(&self, entity: PasswordResetToken) -> Result<PasswordResetToken> {
+        crud::update::<password_reset_token::Entity, PasswordResetToken>(&self.db, entity).await
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<PasswordResetToken> {
+        crud::delete::<password_reset_token::Entity, PasswordResetToken, Uuid>(&self.db, id).await
+    }
+}
+
+#[async_trait::async_trait]
+impl PasswordResetTokenRepository for DbPasswordResetTokenRepository {
+    async fn get_by_token_hash(&self, token_hash: &str) -> Result<Option<PasswordResetToken>> {
+        let model = password_reset_token::Entity::find()
+            .filter(password_reset_token::Column::TokenHash.eq(token_hash))
+            .one(&self.db)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(model.map(Into::into))
+    }
+
+    async fn mark_used(&self, id: Uuid) -> Result<bool> {
+        let result = password_reset_token::Entity::update_many()
+            .col_expr(
+                password_reset_token::Column::UsedAt,
+                Expr::value(Utc::now().fixed_offset()),
+            )
+            .filter(password_reset_token::Column::Id.eq(id))
+            .filter(password_reset_token::Column::UsedAt.is_null())
+            .exec(&self.db)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    async fn invalidate_by_user_id(&self, user_id: Uuid) -> Result<u64> {
+        let result = password_reset_token::Entity::update_many()
+            .col_expr(
+                password_reset_token::Column::UsedAt,
+                Expr::value(Utc::now().fixed_offset()),
+            )
+            .filter(password_reset_token::Column::UserId.eq(user_id))
+            .filter(password_reset_token::Column::UsedAt.is_null())
+            .exec(&self.db)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(result.rows_affected)
+    }
+}
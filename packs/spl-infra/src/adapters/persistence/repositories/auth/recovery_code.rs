@@ -0,0 +1,91 @@
+use crate::adapters::persistence::entities::auth::recovery_code;
+use chrono::Utc;
+use sea_orm::prelude::Expr;
+use sea_orm::*;
+use spl_domain::entities::auth::RecoveryCode;
+use spl_domain::ports::repositories::auth::RecoveryCodeRepository;
+use spl_domain::ports::repositories::crud::CrudRepository;
+use spl_shared::adapters::persistence::repository::crud;
+use spl_shared::error::{AppError, Result};
+use uuid::Uuid;
+
+pub struct DbRecoveryCodeRepository {
+    db: DatabaseConnection,
+}
+
+impl DbRecoveryCodeRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait::async_trait]
+impl CrudRepository<RecoveryCode, Uuid> for DbRecoveryCodeRepository {
+    async fn get_by_id(&self, id: Uuid) -> Result<Option<RecoveryCode>> {
+        crud::get_by_id::<recovery_code::Entity, RecoveryCode, Uuid>(&self.db, id).await
+    }
+
+    async fn create(&self, entity: RecoveryCode) -> Result<RecoveryCode> {
+        crud::create::<recovery_code::Entity, RecoveryCode>(&self.db, entity).await
+    }
+
+    async fn update(&self, entity: RecoveryCode) -> Result<RecoveryCode> {
+        crud::update::<recovery_code::Entity, RecoveryCode>(&self.db, entity).await
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<RecoveryCode> {
+        crud::delete::<recovery_code::Entity, RecoveryCode, Uuid>(&self.db, id).await
+    }
+}
+
+#[async_trait::async_trait]
+impl RecoveryCodeRepository for DbRecoveryCodeRepository {
+    async fn get_by_code_hash(
+        &self,
+        user_id: Uuid,
+        code_hash: &str,
+    ) -> Result<Option<RecoveryCode>> {
+        let model = recovery_code::Entity::find()
+            .filter(recovery_code::Column::UserId.eq(user_id))
+            .filter(recovery_code::Column::CodeHash.eq(code_hash))
+            .one(&self.db)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(model.map(Into::into))
+    }
+
+    async fn mark_used(&self, id: Uuid) -> Result<bool> {
+        let result = recovery_code::Entity::update_many()
+            .col_expr(
+                recovery_code::Column::UsedAt,
+                Expr::value(Utc::now().fixed_offset()),
+            )
+            .filter(recovery_code::Column::Id.eq(id))
+            .filter(recovery_code::Column::UsedAt.is_null())
+            .exec(&self.db)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    async fn count_unused(&self, user_id: Uuid) -> Result<u64> {
+        recovery_code::Entity::find()
+            .filter(recovery_code::Column::UserId.eq(user_id))
+            .filter(recovery_code::Column::UsedAt.is_null())
+            .count(&self.db)
+            .await
+            .map_err(AppError::from)
+    }
+
+    async fn delete_by_user_id(&self, user_id: Uuid) -> Result<u64> {
+        let result = recovery_code::Entity::delete_many()
+            .filter(recovery_code::Column::UserId.eq(user_id))
+            .exec(&self.db)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(result.rows_affected)
+    }
+}
@@ -0,0 +1,68 @@
+use crate::adapters::persistence::entities::auth::refresh_token;
+use chrono::Utc;
+use sea_orm::prelude::Expr;
+use sea_orm::*;
+use spl_domain::entities::auth::RefreshToken;
+use spl_domain::ports::repositories::auth::RefreshTokenRepository;
+use spl_domain::ports::repositories::crud::CrudRepository;
+use spl_shared::adapters::persistence::repository::crud;
+use spl_shared::error::{AppError, Result};
+use uuid::Uuid;
+
+pub struct DbRefreshTokenRepository {
+    db: DatabaseConnection,
+}
+
+impl DbRefreshTokenRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait::async_trait]
+impl CrudRepository<RefreshToken, Uuid> for DbRefreshTokenRepository {
+    async fn get_by_id(&self, id: Uuid) -> Result<Option<RefreshToken>> {
+        crud::get_by_id::<refresh_token::Entity, RefreshToken, Uuid>(&self.db, id).await
+    }
+
+    async fn create(&self, entity: RefreshToken) -> Result<RefreshToken> {
+        crud::create::<refresh_token::Entity, RefreshToken>(&self.db, entity).await
+    }
+
+    async fn update(&self, entity: RefreshToken) -> Result<RefreshToken> {
+        crud::update::<refresh_token::Entity, RefreshToken>(&self.db, entity).await
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<RefreshToken> {
+        crud::delete::<refresh_token::Entity, RefreshToken, Uuid>(&self.db, id).await
+    }
+}
+
+#[async_trait::async_trait]
+impl RefreshTokenRepository for DbRefreshTokenRepository {
+    async fn get_by_token_hash(&self, token_hash: &str) -> Result<Option<RefreshToken>> {
+        let model = refresh_token::Entity::find()
+            .filter(refresh_token::Column::TokenHash.eq(token_hash))
+            .one(&self.db)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(model.map(Into::into))
+    }
+
+    async fn mark_used(&self, id: Uuid) -> Result<bool> {
+        // Conditional update so two concurrent refreshes cannot both consume the token
+        let result = refresh_token::Entity::update_many()
+            .col_expr(
+                refresh_token::Column::UsedAt,
+                Expr::value(Utc::now().fixed_offset()),
+            )
+            .filter(refresh_token::Column::Id.eq(id))
+            .filter(refresh_token::Column::UsedAt.is_null())
+            .exec(&self.db)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(result.rows_affected > 0)
+    }
+}
@@ -0,0 +1,51 @@
+use crate::adapters::persistence::entities::auth::service_account;
+use sea_orm::*;
+use spl_domain::entities::auth::ServiceAccount;
+use spl_domain::ports::repositories::auth::ServiceAccountRepository;
+use spl_domain::ports::repositories::crud::CrudRepository;
+use spl_shared::adapters::persistence::repository::crud;
+use spl_shared::error::{AppError, Result};
+use uuid::Uuid;
+
+pub struct DbServiceAccountRepository {
+    db: DatabaseConnection,
+}
+
+impl DbServiceAccountRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait::async_trait]
+impl CrudRepository<ServiceAccount, Uuid> for DbServiceAccountRepository {
+    async fn get_by_id(&self, id: Uuid) -> Result<Option<ServiceAccount>> {
+        crud::get_by_id::<service_account::Entity, ServiceAccount, Uuid>(&self.db, id).await
+    }
+
+    async fn create(&self, entity: ServiceAccount) -> Result<ServiceAccount> {
+        crud::create::<service_account::Entity, ServiceAccount>(&self.db, entity).await
+    }
+
+    async fn update(&self, entity: ServiceAccount) -> Result<ServiceAccount> {
+        crud::update::<service_account::Entity, ServiceAccount>(&self.db, entity).await
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<ServiceAccount> {
+        crud::delete::<service_account::Entity, ServiceAccount, Uuid>(&self.db, id).await
+    }
+}
+
+#[async_trait::async_trait]
+impl ServiceAccountRepository for DbServiceAccountRepository {
+    async fn get_by_company_id(&self, company_id: Uuid) -> Result<Vec<ServiceAccount>> {
+        let models = service_account::Entity::find()
+            .filter(service_account::Column::CompanyId.eq(company_id))
+            .order_by_asc(service_account::Column::CreatedAt)
+            .all(&self.db)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(models.into_iter().map(Into::into).collect())
+    }
+}
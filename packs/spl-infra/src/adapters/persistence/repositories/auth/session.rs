@@ -0,0 +1,107 @@
+use crate::adapters::persistence::entities::auth::session;
+use chrono::{DateTime, Utc};
+use sea_orm::prelude::Expr;
+use sea_orm::*;
+use spl_domain::entities::auth::Session;
+use spl_domain::ports::repositories::auth::SessionRepository;
+use spl_domain::ports::repositories::crud::CrudRepository;
+use spl_shared::adapters::persistence::repository::crud;
+use spl_shared::error::{AppError, Result};
+use uuid::Uuid;
+
+pub struct DbSessionRepository {
+    db: DatabaseConnection,
+}
+
+impl DbSessionRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait::async_trait]
+impl CrudRepository<Session, Uuid> for DbSessionRepository {
+    async fn get_by_id(&self, id: Uuid) -> Result<Option<Session>> {
+        crud::get_by_id::<session::Entity, Session, Uuid>(&self.db, id).await
+    }
+
+    async fn create(&self, entity: Session) -> Result<Session> {
+        crud::create::<session::Entity, Session>(&self.db, entity).await
+    }
+
+    async fn update(&self, entity: Session) -> Result<Session> {
+        crud::update::<session::Entity, Session>(&self.db, entity).await
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<Session> {
+        crud::delete::<session::Entity, Session, Uuid>(&self.db, id).await
+    }
+}
+
+#[async_trait::async_trait]
+impl SessionRepository for DbSessionRepository {
+    async fn revoke(&self, id: Uuid) -> Result<bool> {
+        let result = session::Entity::update_many()
+            .col_expr(
+                session::Column::RevokedAt,
+                Expr::value(Utc::now().fixed_offset()),
+            )
+            .filter(session::Column::Id.eq(id))
+            .filter(session::Column::RevokedAt.is_null())
+            .exec(&self.db)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(result.rows_affected > 0)
+    }
+
+    async fn revoke_by_user_id(&self, user_id: Uuid) -> Result<u64> {
+        let result = session::Entity::update_many()
+            .col_expr(
+                session::Column::RevokedAt,
+                Expr::value(Utc::now().fixed_offset()),
+            )
+            .filter(session::Column::UserId.eq(user_id))
+            .filter(session::Column::RevokedAt.is_null())
+            .exec(&self.db)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(result.rows_affected)
+    }
+
+    async fn get_active_by_user_id(&self, user_id: Uuid) -> Result<Vec<Session>> {
+        let models = session::Entity::find()
+            .filter(session::Column::UserId.eq(user_id))
+            .filter(session::Column::RevokedAt.is_null())
+            .filter(session::Column::ExpiresAt.gt(Utc::now().fixed_offset()))
+            .order_by_desc(session::Column::LastSeenAt)
+            .all(&self.db)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(models.into_iter().map(Into::into).collect())
+    }
+
+    async fn touch(
+        &self,
+        id: Uuid,
+        seen_at: DateTime<Utc>,
+        ip_address: Option<String>,
+    ) -> Result<()> {
+        let mut update = session::Entity::update_many()
+            .col_expr(
+                session::Column::LastSeenAt,
+                Expr::value(seen_at.fixed_offset()),
+            )
+            .filter(session::Column::Id.eq(id));
+
+        if let Some(ip_address) = ip_address {
+            update = update.col_expr(session::Column::IpAddress, Expr::value(ip_address));
+        }
+
+        update.exec(&self.db).await.map_err(AppError::from)?;
+
+        Ok(())
+    }
+}
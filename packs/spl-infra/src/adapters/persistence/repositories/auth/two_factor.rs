@@ -0,0 +1,57 @@
+use crate::adapters::persistence::entities::auth::two_factor;
+use chrono::Utc;
+use sea_orm::prelude::Expr;
+use sea_orm::*;
+use spl_domain::entities::auth::TwoFactor;
+use spl_domain::ports::repositories::auth::TwoFactorRepository;
+use spl_domain::ports::repositories::crud::CrudRepository;
+use spl_shared::adapters::persistence::repository::crud;
+use spl_shared::error::{AppError, Result};
+use uuid::Uuid;
+
+pub struct DbTwoFactorRepository {
+    db: DatabaseConnection,
+}
+
+impl DbTwoFactorRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait::async_trait]
+impl CrudRepository<TwoFactor, Uuid> for DbTwoFactorRepository {
+    async fn get_by_id(&self, user_id: Uuid) -> Result<Option<TwoFactor>> {
+        crud::get_by_id::<two_factor::Entity, TwoFactor, Uuid>(&self.db, user_id).await
+    }
+
+    async fn create(&self, entity: TwoFactor) -> Result<TwoFactor> {
+        crud::create::<two_factor::Entity, TwoFactor>(&self.db, entity).await
+    }
+
+    async fn update(&self, entity: TwoFactor) -> Result<TwoFactor> {
+        crud::update::<two_factor::Entity, TwoFactor>(&self.db, entity).await
+    }
+
+    async fn delete(&self, user_id: Uuid) -> Result<TwoFactor> {
+        crud::delete::<two_factor::Entity, TwoFactor, Uuid>(&self.db, user_id).await
+    }
+}
+
+#[async_trait::async_trait]
+impl TwoFactorRepository for DbTwoFactorRepository {
+    async fn enable(&self, user_id: Uuid) -> Result<bool> {
+        let result = two_factor::Entity::update_many()
+            .col_expr(
+                two_factor::Column::EnabledAt,
+                Expr::value(Utc::now().fixed_offset()),
+            )
+            .filter(two_factor::Column::UserId.eq(user_id))
+            .filter(two_factor::Column::EnabledAt.is_null())
+            .exec(&self.db)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(result.rows_affected > 0)
+    }
+}
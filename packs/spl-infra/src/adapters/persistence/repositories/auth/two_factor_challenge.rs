@@ -0,0 +1,68 @@
+use crate::adapters::persistence::entities::auth::two_factor_challenge;
+use chrono::Utc;
+use sea_orm::prelude::Expr;
+use sea_orm::*;
+use spl_domain::entities::auth::TwoFactorChallenge;
+use spl_domain::ports::repositories::auth::TwoFactorChallengeRepository;
+use spl_domain::ports::repositories::crud::CrudRepository;
+use spl_shared::adapters::persistence::repository::crud;
+use spl_shared::error::{AppError, Result};
+use uuid::Uuid;
+
+pub struct DbTwoFactorChallengeRepository {
+    db: DatabaseConnection,
+}
+
+impl DbTwoFactorChallengeRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait::async_trait]
+impl CrudRepository<TwoFactorChallenge, Uuid> for DbTwoFactorChallengeRepository {
+    async fn get_by_id(&self, id: Uuid) -> Result<Option<TwoFactorChallenge>> {
+        crud::get_by_id::<two_factor_challenge::Entity, TwoFactorChallenge, Uuid>(&self.db, id)
+            .await
+    }
+
+    async fn create(&self, entity: TwoFactorChallenge) -> Result<TwoFactorChallenge> {
+        crud::create::<two_factor_challenge::Entity, TwoFactorChallenge>(&self.db, entity).await
+    }
+
+    async fn update(&self, entity: TwoFactorChallenge) -> Result<TwoFactorChallenge> {
+        crud::update::<two_factor_challenge::Entity, TwoFactorChallenge>(&self.db, entity).await
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<TwoFactorChallenge> {
+        crud::delete::<two_factor_challenge::Entity, TwoFactorChallenge, Uuid>(&self.db, id).await
+    }
+}
+
+#[async_trait::async_trait]
+impl TwoFactorChallengeRepository for DbTwoFactorChallengeRepository {
+    async fn get_by_token_hash(&self, token_hash: &str) -> Result<Option<TwoFactorChallenge>> {
+        let model = two_factor_challenge::Entity::find()
+            .filter(two_factor_challenge::Column::TokenHash.eq(token_hash))
+            .one(&self.db)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(model.map(Into::into))
+    }
+
+    async fn mark_used(&self, id: Uuid) -> Result<bool> {
+        let result = two_factor_challenge::Entity::update_many()
+            .col_expr(
+                two_factor_challenge::Column::UsedAt,
+                Expr::value(Utc::now().fixed_offset()),
+            )
+            .filter(two_factor_challenge::Column::Id.eq(id))
+            .filter(two_factor_challenge::Column::UsedAt.is_null())
+            .exec(&self.db)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(result.rows_affected > 0)
+    }
+}
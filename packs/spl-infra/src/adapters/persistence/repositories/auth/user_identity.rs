@@ -0,0 +1,51 @@
+use crate::adapters::persistence::entities::auth::user_identity;
+use sea_orm::*;
+use spl_domain::entities::auth::UserIdentity;
+use spl_domain::ports::repositories::auth::UserIdentityRepository;
+use spl_domain::ports::repositories::crud::CrudRepository;
+use spl_shared::adapters::persistence::repository::crud;
+use spl_shared::error::{AppError, Result};
+use uuid::Uuid;
+
+pub struct DbUserIdentityRepository {
+    db: DatabaseConnection,
+}
+
+impl DbUserIdentityRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait::async_trait]
+impl CrudRepository<UserIdentity, Uuid> for DbUserIdentityRepository {
+    async fn get_by_id(&self, id: Uuid) -> Result<Option<UserIdentity>> {
+        crud::get_by_id::<user_identity::Entity, UserIdentity, Uuid>(&self.db, id).await
+    }
+
+    async fn create(&self, entity: UserIdentity) -> Result<UserIdentity> {
+        crud::create::<user_identity::Entity, UserIdentity>(&self.db, entity).await
+    }
+
+    async fn update(&self, entity: UserIdentity) -> Result<UserIdentity> {
+        crud::update::<user_identity::Entity, UserIdentity>(&self.db, entity).await
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<UserIdentity> {
+        crud::delete::<user_identity::Entity, UserIdentity, Uuid>(&self.db, id).await
+    }
+}
+
+#[async_trait::async_trait]
+impl UserIdentityRepository for DbUserIdentityRepository {
+    async fn get_by_subject(&self, issuer: &str, subject: &str) -> Result<Option<UserIdentity>> {
+        let model = user_identity::Entity::find()
+            .filter(user_identity::Column::Issuer.eq(issuer))
+            .filter(user_identity::Column::Subject.eq(subject))
+            .one(&self.db)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(model.map(Into::into))
+    }
+}
@@ -4,7 +4,7 @@ use spl_domain::entities::company::Company;
 use spl_domain::ports::repositories::company::CompanyRepository;
 use spl_domain::ports::repositories::crud::CrudRepository;
 use spl_shared::adapters::persistence::repository::crud;
-use spl_shared::error::Result;
+use spl_shared::error::{AppError, Result};
 use uuid::Uuid;
 
 pub struct DbCompanyRepository {
@@ -41,4 +41,19 @@ impl CompanyRepository for DbCompanyRepository {
     async fn get_all(&self) -> Result<Vec<Company>> {
         crud::get_all::<company::Entity, Company>(&self.db).await
     }
+
+    async fn get_by_parent_ids(&self, parent_ids: Vec<Uuid>) -> Result<Vec<Company>> {
+        if parent_ids.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let models = company::Entity::find()
+            .filter(company::Column::ParentId.is_in(parent_ids))
+            .order_by_asc(company::Column::Name)
+            .all(&self.db)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(models.into_iter().map(Company::from).collect())
+    }
 }
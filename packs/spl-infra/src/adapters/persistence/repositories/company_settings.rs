@@ -0,0 +1,46 @@
+use crate::adapters::persistence::entities::company_settings;
+use sea_orm::*;
+use spl_domain::entities::company::CompanySettingsOverride;
+use spl_domain::ports::repositories::company::CompanySettingsRepository;
+use spl_domain::ports::repositories::crud::CrudRepository;
+use spl_shared::adapters::persistence::repository::crud;
+use spl_shared::error::Result;
+use uuid::Uuid;
+
+pub struct DbCompanySettingsRepository {
+    db: DatabaseConnection,
+}
+
+impl DbCompanySettingsRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait::async_trait]
+impl CrudRepository<CompanySettingsOverride, Uuid> for DbCompanySettingsRepository {
+    async fn get_by_id(&self, company_id: Uuid) -> Result<Option<CompanySettingsOverride>> {
+        crud::get_by_id::<company_settings::Entity, CompanySettingsOverride, Uuid>(
+            &self.db, company_id,
+        )
+        .await
+    }
+
+    async fn create(&self, entity: CompanySettingsOverride) -> Result<CompanySettingsOverride> {
+        crud::create::<company_settings::Entity, CompanySettingsOverride>(&self.db, entity).await
+    }
+
+    async fn update(&self, entity: CompanySettingsOverride) -> Result<CompanySettingsOverride> {
+        crud::update::<company_settings::Entity, CompanySettingsOverride>(&self.db, entity).await
+    }
+
+    async fn delete(&self, company_id: Uuid) -> Result<CompanySettingsOverride> {
+        crud::delete::<company_settings::Entity, CompanySettingsOverride, Uuid>(
+            &self.db, company_id,
+        )
+        .await
+    }
+}
+
+#[async_trait::async_trait]
+impl CompanySettingsRepository for DbCompanySettingsRepository {}
@@ -1,5 +1,4 @@
 use crate::adapters::persistence::entities::diagnostics::{label, prediction};
-use crate::adapters::persistence::entities::user;
 use crate::adapters::persistence::repositories::DbPredictionRepository;
 use chrono::{DateTime, Utc};
 use futures::future::try_join_all;
@@ -81,21 +80,27 @@ pub struct DistributionQueryResult {
 impl DashboardSummaryRepository for DbDashboardSummaryRepository {
     async fn get_summary(
         &self,
-        users_ids: Vec<Uuid>,
+        company_ids: Vec<Uuid>,
+        users_ids: Option<Vec<Uuid>>,
         min_date: Option<DateTime<Utc>>,
         max_date: Option<DateTime<Utc>>,
         plot_ids: Vec<Option<Uuid>>,
         labels: Option<Vec<String>>,
+        model_versions: Option<Vec<String>>,
     ) -> Result<DashboardSummary> {
-        if users_ids.is_empty() {
+        if company_ids.is_empty() {
             return Err(AppError::NoContent(
-                "Cannot generate a dashboard without users".to_string(),
+                "Cannot generate a dashboard without companies".to_string(),
             ));
         }
 
+        let company_ids: Vec<Option<Uuid>> = company_ids.into_iter().map(Some).collect();
+
         let query = DbPredictionRepository::build_filter_query(
+            company_ids.clone(),
             users_ids.clone(),
             labels.clone(),
+            model_versions.clone(),
             Some(plot_ids.clone()),
             min_date,
             max_date,
@@ -105,8 +110,10 @@ impl DashboardSummaryRepository for DbDashboardSummaryRepository {
             label::Entity::find()
                 .join(JoinType::LeftJoin, label::Relation::Prediction.def())
                 .column_as(prediction::Column::Id.count(), "count"),
+            company_ids.clone(),
             users_ids.clone(),
             labels,
+            model_versions,
             Some(plot_ids.clone()),
             min_date,
             max_date,
@@ -115,15 +122,18 @@ impl DashboardSummaryRepository for DbDashboardSummaryRepository {
         .group_by(label::Column::Name);
 
         let plot_conditions = DbPredictionRepository::build_plots_condition(plot_ids);
+        let mut plots_select = prediction::Entity::find().filter(plot_conditions).filter(
+            DbPredictionRepository::build_companies_condition(company_ids),
+        );
+        if let Some(users_ids) = users_ids {
+            plots_select = plots_select.filter(prediction::Column::UserId.is_in(users_ids));
+        }
 
         let monthly_expr = Expr::cust("TO_CHAR(\"predictions\".\"created_at\", 'YYYY-MM')");
 
         let (total, plot_count, labels, distributions) = tokio::try_join!(
             query.clone().select_only().count(&self.db),
-            prediction::Entity::find()
-                .inner_join(user::Entity)
-                .filter(plot_conditions)
-                .filter(user::Column::Id.is_in(users_ids))
+            plots_select
                 .select_only()
                 .column_as(
                     Expr::col(prediction::Column::PlotId).count_distinct().add(
@@ -190,25 +200,31 @@ impl DashboardSummaryRepository for DbDashboardSummaryRepository {
 
     async fn get_counts(
         &self,
-        users_ids: Vec<Uuid>,
+        company_ids: Vec<Uuid>,
+        users_ids: Option<Vec<Uuid>>,
         min_date: Option<DateTime<Utc>>,
         max_date: Option<DateTime<Utc>>,
         plot_ids: Vec<Option<Uuid>>,
         labels: Option<Vec<String>>,
+        model_versions: Option<Vec<String>>,
         last_n: u64,
     ) -> Result<DashboardCounts> {
         // Get summary statistics by reusing get_summary
         let (summary, predictions) = tokio::try_join!(
             self.get_summary(
+                company_ids.clone(),
                 users_ids.clone(),
                 min_date,
                 max_date,
                 plot_ids.clone(),
-                labels.clone()
+                labels.clone(),
+                model_versions.clone()
             ),
             self.prediction_repository.filter(
+                company_ids.into_iter().map(Some).collect(),
                 users_ids,
                 labels,
+                model_versions,
                 Some(plot_ids),
                 min_date,
                 max_date,
@@ -230,10 +246,12 @@ impl DashboardSummaryRepository for DbDashboardSummaryRepository {
         &self,
         company_id: Uuid,
         plot_id: Uuid,
-        users_ids: Vec<Uuid>,
+        company_ids: Vec<Uuid>,
+        users_ids: Option<Vec<Uuid>>,
         min_date: Option<DateTime<Utc>>,
         max_date: Option<DateTime<Utc>>,
         labels: Option<Vec<String>>,
+        model_versions: Option<Vec<String>>,
     ) -> Result<Option<DashboardDetailedPlot>> {
         let (detailed, summary) = tokio::try_join!(
             self.plot_repository.get_detailed_by_id(
@@ -241,7 +259,15 @@ impl DashboardSummaryRepository for DbDashboardSummaryRepository {
                 plot_id,
                 labels.clone().unwrap_or(vec![])
             ),
-            self.get_summary(users_ids, min_date, max_date, vec![Some(plot_id)], labels)
+            self.get_summary(
+                company_ids,
+                users_ids,
+                min_date,
+                max_date,
+                vec![Some(plot_id)],
+                labels,
+                model_versions
+            )
         )?;
 
         let detailed = detailed
@@ -253,16 +279,26 @@ impl DashboardSummaryRepository for DbDashboardSummaryRepository {
     async fn get_default_summary_detailed_plot(
         &self,
         company_id: Uuid,
-        users_ids: Vec<Uuid>,
+        company_ids: Vec<Uuid>,
+        users_ids: Option<Vec<Uuid>>,
         min_date: Option<DateTime<Utc>>,
         max_date: Option<DateTime<Utc>>,
         plot_ids: Vec<Option<Uuid>>,
         labels: Option<Vec<String>>,
+        model_versions: Option<Vec<String>>,
     ) -> Result<Option<DashboardDetailedPlot>> {
         let (detailed, summary) = tokio::try_join!(
             self.plot_repository
                 .get_default_detailed(company_id, labels.clone().unwrap_or(vec![])),
-            self.get_summary(users_ids, min_date, max_date, plot_ids, labels)
+            self.get_summary(
+                company_ids,
+                users_ids,
+                min_date,
+                max_date,
+                plot_ids,
+                labels,
+                model_versions
+            )
         )?;
 
         let detailed = detailed
@@ -273,26 +309,53 @@ impl DashboardSummaryRepository for DbDashboardSummaryRepository {
 
     async fn get_compare(
         &self,
-        users_ids: Vec<Uuid>,
+        company_ids: Vec<Uuid>,
+        users_ids: Option<Vec<Uuid>>,
         min_date: Option<DateTime<Utc>>,
         max_date: Option<DateTime<Utc>>,
         plot_ids: Vec<Option<Uuid>>,
         labels: Option<Vec<String>>,
+        model_versions: Option<Vec<String>>,
     ) -> Result<Vec<DashboardSummary>> {
         let futures = plot_ids
             .into_iter()
             .unique()
             .map(|el| {
                 self.get_summary(
+                    company_ids.clone(),
                     users_ids.clone(),
                     min_date,
                     max_date,
                     vec![el],
                     labels.clone(),
+                    model_versions.clone(),
                 )
             })
             .collect::<Vec<_>>();
 
         try_join_all(futures).await
     }
+
+    async fn get_model_versions(
+        &self,
+        company_ids: Vec<Uuid>,
+        users_ids: Option<Vec<Uuid>>,
+    ) -> Result<Vec<String>> {
+        let mut query = prediction::Entity::find()
+            .select_only()
+            .column(prediction::Column::ModelVersion)
+            .distinct()
+            .filter(prediction::Column::CompanyId.is_in(company_ids));
+        if let Some(users_ids) = users_ids {
+            query = query.filter(prediction::Column::UserId.is_in(users_ids));
+        }
+
+        query
+            .filter(prediction::Column::ModelVersion.is_not_null())
+            .order_by_asc(prediction::Column::ModelVersion)
+            .into_tuple::<String>()
+            .all(&self.db)
+            .await
+            .map_err(AppError::from)
+    }
 }
@@ -0,0 +1,135 @@
+use crate::adapters::persistence::entities::diagnostics::inference_job;
+use chrono::{DateTime, Utc};
+use sea_orm::sea_query::{LockBehavior, LockType};
+use sea_orm::*;
+use spl_domain::entities::diagnostics::{InferenceJob, JobStatus};
+use spl_domain::ports::repositories::crud::CrudRepository;
+use spl_domain::ports::repositories::diagnostics::InferenceJobRepository;
+use spl_shared::adapters::persistence::repository::crud;
+use spl_shared::error::{AppError, Result};
+use uuid::Uuid;
+
+pub struct DbInferenceJobRepository {
+    db: DatabaseConnection,
+}
+
+impl DbInferenceJobRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait::async_trait]
+impl CrudRepository<InferenceJob, Uuid> for DbInferenceJobRepository {
+    async fn get_by_id(&self, id: Uuid) -> Result<Option<InferenceJob>> {
+        crud::get_by_id::<inference_job::Entity, InferenceJob, Uuid>(&self.db, id).await
+    }
+
+    async fn create(&self, entity: InferenceJob) -> Result<InferenceJob> {
+        crud::create::<inference_job::Entity, InferenceJob>(&self.db, entity).await
+    }
+
+    async fn update(&self, entity: InferenceJob) -> Result<InferenceJob> {
+        crud::update::<inference_job::Entity, InferenceJob>(&self.db, entity).await
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<InferenceJob> {
+        crud::delete::<inference_job::Entity, InferenceJob, Uuid>(&self.db, id).await
+    }
+}
+
+#[async_trait::async_trait]
+impl InferenceJobRepository for DbInferenceJobRepository {
+    async fn create_many(&self, jobs: Vec<InferenceJob>) -> Result<()> {
+        if jobs.is_empty() {
+            return Ok(());
+        }
+
+        let models: Vec<inference_job::ActiveModel> = jobs.into_iter().map(Into::into).collect();
+
+        inference_job::Entity::insert_many(models)
+            .exec(&self.db)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(())
+    }
+
+    async fn claim_next(
+        &self,
+        now: DateTime<Utc>,
+        locked_until: DateTime<Utc>,
+    ) -> Result<Option<InferenceJob>> {
+        let txn = self.db.begin().await.map_err(AppError::from)?;
+
+        // Rows locked by another worker are skipped instead of waited for
+        let model = inference_job::Entity::find()
+            .filter(
+                Condition::any()
+                    .add(
+                        Condition::all()
+                            .add(inference_job::Column::Status.eq(JobStatus::Queued.as_str()))
+                            .add(inference_job::Column::RunAfter.lte(now)),
+                    )
+                    .add(
+                        Condition::all()
+                            .add(inference_job::Column::Status.eq(JobStatus::Running.as_str()))
+                            .add(inference_job::Column::LockedUntil.lt(now)),
+                    ),
+            )
+            .order_by_asc(inference_job::Column::RunAfter)
+            .lock_with_behavior(LockType::Update, LockBehavior::SkipLocked)
+            .one(&txn)
+            .await
+            .map_err(AppError::from)?;
+
+        let Some(model) = model else {
+            txn.commit().await.map_err(AppError::from)?;
+            return Ok(None);
+        };
+
+        let attempts = model.attempts + 1;
+        let mut active: inference_job::ActiveModel = model.into();
+        active.status = Set(JobStatus::Running.as_str().to_string());
+        active.attempts = Set(attempts);
+        active.locked_until = Set(Some(locked_until.into()));
+        active.updated_at = Set(now.into());
+
+        let model = active.update(&txn).await.map_err(AppError::from)?;
+        txn.commit().await.map_err(AppError::from)?;
+
+        Ok(Some(model.into()))
+    }
+
+    async fn get_by_user_id(
+        &self,
+        user_id: Uuid,
+        status: Option<JobStatus>,
+    ) -> Result<Vec<InferenceJob>> {
+        let mut query =
+            inference_job::Entity::find().filter(inference_job::Column::UserId.eq(user_id));
+
+        if let Some(status) = status {
+            query = query.filter(inference_job::Column::Status.eq(status.as_str()));
+        }
+
+        let models = query
+            .order_by_desc(inference_job::Column::CreatedAt)
+            .all(&self.db)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(models.into_iter().map(Into::into).collect())
+    }
+
+    async fn get_by_company_id(&self, company_id: Uuid) -> Result<Vec<InferenceJob>> {
+        let models = inference_job::Entity::find()
+            .filter(inference_job::Column::CompanyId.eq(company_id))
+            .order_by_desc(inference_job::Column::CreatedAt)
+            .all(&self.db)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(models.into_iter().map(Into::into).collect())
+    }
+}
@@ -1,9 +1,15 @@
+pub mod inference_job;
 pub mod label;
 pub mod mark_type;
 pub mod prediction;
+pub mod prediction_batch;
+pub mod prediction_batch_item;
 pub mod prediction_mark;
 
+pub use inference_job::DbInferenceJobRepository;
 pub use label::DbLabelRepository;
 pub use mark_type::DbMarkTypeRepository;
 pub use prediction::DbPredictionRepository;
+pub use prediction_batch::DbPredictionBatchRepository;
+pub use prediction_batch_item::DbPredictionBatchItemRepository;
 pub use prediction_mark::DbPredictionMarkRepository;
@@ -262,6 +262,11 @@ impl PredictionRepository for DbPredictionRepository {
             .await
     }
 
+    async fn get_by_company_id(&self, company_id: Uuid) -> Result<Vec<Prediction>> {
+        self.find(prediction::Entity::find().filter(prediction::Column::CompanyId.eq(company_id)))
+            .await
+    }
+
     async fn get_by_user_id_and_id(&self, user_id: Uuid, id: Uuid) -> Result<Option<Prediction>> {
         let result = self
             .find(
@@ -285,17 +290,21 @@ impl PredictionRepository for DbPredictionRepository {
         &self,
         prediction_ids: Vec<Uuid>,
         user_id: Uuid,
+        company_id: Option<Uuid>,
         plot_id: Option<Uuid>,
     ) -> Result<Vec<Prediction>> {
         if prediction_ids.is_empty() {
             return Ok(Vec::new());
         }
 
+        let company_condition = Self::build_companies_condition(vec![company_id]);
+
         // Update predictions that belong to the user and match the IDs
         prediction::Entity::update_many()
             .col_expr(prediction::Column::PlotId, Expr::value(plot_id))
             .filter(prediction::Column::Id.is_in(prediction_ids.clone()))
             .filter(prediction::Column::UserId.eq(user_id))
+            .filter(company_condition.clone())
             .exec(&self.db)
             .await
             .map_err(AppError::from)?;
@@ -304,7 +313,8 @@ impl PredictionRepository for DbPredictionRepository {
         self.find(
             prediction::Entity::find()
                 .filter(prediction::Column::Id.is_in(prediction_ids))
-                .filter(prediction::Column::UserId.eq(user_id)),
+                .filter(prediction::Column::UserId.eq(user_id))
+                .filter(company_condition),
         )
         .await
     }
@@ -322,15 +332,25 @@ impl PredictionRepository for DbPredictionRepository {
 
     async fn filter(
         &self,
-        user_ids: Vec<Uuid>,
+        company_ids: Vec<Option<Uuid>>,
+        user_ids: Option<Vec<Uuid>>,
         labels: Option<Vec<String>>,
+        model_versions: Option<Vec<String>>,
         plot_ids: Option<Vec<Option<Uuid>>>,
         min_date: Option<chrono::DateTime<chrono::Utc>>,
         max_date: Option<chrono::DateTime<chrono::Utc>>,
         offset: u64,
         limit: u64,
     ) -> Result<(u64, Vec<Prediction>)> {
-        let query = Self::build_filter_query(user_ids, labels, plot_ids, min_date, max_date);
+        let query = Self::build_filter_query(
+            company_ids,
+            user_ids,
+            labels,
+            model_versions,
+            plot_ids,
+            min_date,
+            max_date,
+        );
 
         // Count total before pagination
         let total = query
@@ -401,10 +421,28 @@ impl DbPredictionRepository {
         condition
     }
 
+    /// Predictions made in the companies, `None` standing for those made without company
+    pub fn build_companies_condition(company_ids: Vec<Option<Uuid>>) -> Condition {
+        let mut condition = Condition::any();
+        let ids: Vec<Uuid> = company_ids.iter().flatten().copied().collect();
+
+        if !ids.is_empty() {
+            condition = condition.add(prediction::Column::CompanyId.is_in(ids));
+        }
+        if company_ids.contains(&None) {
+            condition = condition.add(prediction::Column::CompanyId.is_null());
+        }
+
+        condition
+    }
+
+    #[allow(clippy::too_many_arguments)]
     pub fn add_filter_query<E>(
         select: Select<E>,
-        user_ids: Vec<Uuid>,
+        company_ids: Vec<Option<Uuid>>,
+        user_ids: Option<Vec<Uuid>>,
         labels: Option<Vec<String>>,
+        model_versions: Option<Vec<String>>,
         plot_ids: Option<Vec<Option<Uuid>>>,
         min_date: Option<chrono::DateTime<chrono::Utc>>,
         max_date: Option<chrono::DateTime<chrono::Utc>>,
@@ -412,12 +450,20 @@ impl DbPredictionRepository {
     where
         E: EntityTrait,
     {
-        let mut query = select.filter(prediction::Column::UserId.is_in(user_ids));
+        let mut query = select.filter(Self::build_companies_condition(company_ids));
+
+        if let Some(user_ids) = user_ids {
+            query = query.filter(prediction::Column::UserId.is_in(user_ids));
+        }
 
         if let Some(labels) = labels {
             query = query.filter(label::Column::Name.is_in(labels));
         }
 
+        if let Some(model_versions) = model_versions {
+            query = query.filter(prediction::Column::ModelVersion.is_in(model_versions));
+        }
+
         if let Some(plot_ids) = plot_ids {
             let condition = DbPredictionRepository::build_plots_condition(plot_ids);
             query = query.filter(condition);
@@ -435,8 +481,10 @@ impl DbPredictionRepository {
     }
 
     pub fn build_filter_query(
-        user_ids: Vec<Uuid>,
+        company_ids: Vec<Option<Uuid>>,
+        user_ids: Option<Vec<Uuid>>,
         labels: Option<Vec<String>>,
+        model_versions: Option<Vec<String>>,
         plot_ids: Option<Vec<Option<Uuid>>>,
         min_date: Option<chrono::DateTime<chrono::Utc>>,
         max_date: Option<chrono::DateTime<chrono::Utc>>,
@@ -448,8 +496,14 @@ impl DbPredictionRepository {
         }
 
         Self::add_filter_query(
-            query, user_ids, labels, plot_ids, // plot_ids will be handled separately
-            min_date, max_date,
+            query,
+            company_ids,
+            user_ids,
+            labels,
+            model_versions,
+            plot_ids, // plot_ids will be handled separately
+            min_date,
+            max_date,
         )
     }
 }
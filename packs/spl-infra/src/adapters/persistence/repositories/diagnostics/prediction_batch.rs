@@ -0,0 +1,106 @@
+use crate::adapters::persistence::entities::diagnostics::{
+    prediction_batch, prediction_batch_item,
+};
+use chrono::Utc;
+use sea_orm::*;
+use spl_domain::entities::diagnostics::{BatchItemStatus, BatchStatus, PredictionBatch};
+use spl_domain::ports::repositories::crud::CrudRepository;
+use spl_domain::ports::repositories::diagnostics::PredictionBatchRepository;
+use spl_shared::adapters::persistence::repository::crud;
+use spl_shared::error::{AppError, Result};
+use uuid::Uuid;
+
+pub struct DbPredictionBatchRepository {
+    db: DatabaseConnection,
+}
+
+impl DbPredictionBatchRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait::async_trait]
+impl CrudRepository<PredictionBatch, Uuid> for DbPredictionBatchRepository {
+    async fn get_by_id(&self, id: Uuid) -> Result<Option<PredictionBatch>> {
+        crud::get_by_id::<prediction_batch::Entity, PredictionBatch, Uuid>(&self.db, id).await
+    }
+
+    async fn create(&self, entity: PredictionBatch) -> Result<PredictionBatch> {
+        crud::create::<prediction_batch::Entity, PredictionBatch>(&self.db, entity).await
+    }
+
+    async fn update(&self, entity: PredictionBatch) -> Result<PredictionBatch> {
+        crud::update::<prediction_batch::Entity, PredictionBatch>(&self.db, entity).await
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<PredictionBatch> {
+        crud::delete::<prediction_batch::Entity, PredictionBatch, Uuid>(&self.db, id).await
+    }
+}
+
+#[async_trait::async_trait]
+impl PredictionBatchRepository for DbPredictionBatchRepository {
+    async fn get_by_user_id(&self, user_id: Uuid) -> Result<Vec<PredictionBatch>> {
+        let models = prediction_batch::Entity::find()
+            .filter(prediction_batch::Column::UserId.eq(user_id))
+            .order_by_desc(prediction_batch::Column::CreatedAt)
+            .all(&self.db)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(models.into_iter().map(Into::into).collect())
+    }
+
+    async fn refresh_progress(&self, id: Uuid) -> Result<PredictionBatch> {
+        let txn = self.db.begin().await.map_err(AppError::from)?;
+
+        // Locking the batch first makes concurrent refreshes wait for each other, so
+        // the last one always counts every item updated before it
+        let model = prediction_batch::Entity::find_by_id(id)
+            .lock_exclusive()
+            .one(&txn)
+            .await
+            .map_err(AppError::from)?
+            .ok_or_else(|| AppError::NotFound("Batch not found".to_string()))?;
+
+        let counts: Vec<(String, i64)> = prediction_batch_item::Entity::find()
+            .select_only()
+            .column(prediction_batch_item::Column::Status)
+            .column_as(prediction_batch_item::Column::Id.count(), "count")
+            .filter(prediction_batch_item::Column::BatchId.eq(id))
+            .group_by(prediction_batch_item::Column::Status)
+            .into_tuple()
+            .all(&txn)
+            .await
+            .map_err(AppError::from)?;
+
+        let count = |status: BatchItemStatus| {
+            counts
+                .iter()
+                .find(|(value, _)| value == status.as_str())
+                .map_or(0, |(_, count)| *count)
+        };
+
+        let now = Utc::now();
+        let pending = count(BatchItemStatus::Pending);
+        let mut active: prediction_batch::ActiveModel = model.into();
+        active.items_completed = Set(count(BatchItemStatus::Completed));
+        active.items_failed = Set(count(BatchItemStatus::Failed));
+        active.updated_at = Set(now.into());
+        if pending == 0 {
+            if active.status.as_ref() != BatchStatus::Completed.as_str() {
+                active.status = Set(BatchStatus::Completed.as_str().to_string());
+                active.completed_at = Set(Some(now.into()));
+            }
+        } else {
+            active.status = Set(BatchStatus::Processing.as_str().to_string());
+            active.completed_at = Set(None);
+        }
+
+        let model = active.update(&txn).await.map_err(AppError::from)?;
+        txn.commit().await.map_err(AppError::from)?;
+
+        Ok(model.into())
+    }
+}
@@ -0,0 +1,68 @@
+use crate::adapters::persistence::entities::diagnostics::prediction_batch_item;
+use sea_orm::*;
+use spl_domain::entities::diagnostics::PredictionBatchItem;
+use spl_domain::ports::repositories::crud::CrudRepository;
+use spl_domain::ports::repositories::diagnostics::PredictionBatchItemRepository;
+use spl_shared::adapters::persistence::repository::crud;
+use spl_shared::error::{AppError, Result};
+use uuid::Uuid;
+
+pub struct DbPredictionBatchItemRepository {
+    db: DatabaseConnection,
+}
+
+impl DbPredictionBatchItemRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait::async_trait]
+impl CrudRepository<PredictionBatchItem, Uuid> for DbPredictionBatchItemRepository {
+    async fn get_by_id(&self, id: Uuid) -> Result<Option<PredictionBatchItem>> {
+        crud::get_by_id::<prediction_batch_item::Entity, PredictionBatchItem, Uuid>(&self.db, id)
+            .await
+    }
+
+    async fn create(&self, entity: PredictionBatchItem) -> Result<PredictionBatchItem> {
+        crud::create::<prediction_batch_item::Entity, PredictionBatchItem>(&self.db, entity).await
+    }
+
+    async fn update(&self, entity: PredictionBatchItem) -> Result<PredictionBatchItem> {
+        crud::update::<prediction_batch_item::Entity, PredictionBatchItem>(&self.db, entity).await
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<PredictionBatchItem> {
+        crud::delete::<prediction_batch_item::Entity, PredictionBatchItem, Uuid>(&self.db, id).await
+    }
+}
+
+#[async_trait::async_trait]
+impl PredictionBatchItemRepository for DbPredictionBatchItemRepository {
+    async fn create_many(&self, items: Vec<PredictionBatchItem>) -> Result<()> {
+        if items.is_empty() {
+            return Ok(());
+        }
+
+        let models: Vec<prediction_batch_item::ActiveModel> =
+            items.into_iter().map(Into::into).collect();
+
+        prediction_batch_item::Entity::insert_many(models)
+            .exec(&self.db)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(())
+    }
+
+    async fn get_by_batch_id(&self, batch_id: Uuid) -> Result<Vec<PredictionBatchItem>> {
+        let models = prediction_batch_item::Entity::find()
+            .filter(prediction_batch_item::Column::BatchId.eq(batch_id))
+            .order_by_asc(prediction_batch_item::Column::Position)
+            .all(&self.db)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(models.into_iter().map(Into::into).collect())
+    }
+}
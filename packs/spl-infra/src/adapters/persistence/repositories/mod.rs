@@ -1,16 +1,29 @@
+pub mod auth;
 pub mod company;
+pub mod company_settings;
 pub mod diagnostics;
 pub mod feedback;
 pub mod image;
+pub mod offboarding;
 pub mod plot;
 pub mod recommendation;
+pub mod team;
+pub mod usage;
 pub mod user;
 pub mod dashboard;
 
+pub use auth::{
+    DbLoginAttemptStore, DbPasswordResetTokenRepository, DbRefreshTokenRepository,
+    DbSessionRepository,
+};
 pub use company::DbCompanyRepository;
+pub use company_settings::DbCompanySettingsRepository;
 pub use diagnostics::{DbLabelRepository, DbMarkTypeRepository, DbPredictionRepository};
 pub use feedback::{status::DbFeedbackStatusRepository, DbFeedbackRepository};
 pub use image::DbImageRepository;
+pub use offboarding::{DbCompanyOffboardingRepository, DbOffboardingEventRepository};
 pub use plot::DbPlotRepository;
 pub use recommendation::{DbCategoryRepository, DbRecommendationRepository};
-pub use user::{DbRoleRepository, DbUserRepository};
+pub use team::DbTeamRepository;
+pub use usage::{DbCompanyQuotaRepository, DbUsageRepository};
+pub use user::{DbMembershipRepository, DbRoleRepository, DbUserRepository};
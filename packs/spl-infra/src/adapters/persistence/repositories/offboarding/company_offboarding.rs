@@ -0,0 +1,65 @@
+use crate::adapters::persistence::entities::offboarding::company_offboarding;
+use sea_orm::*;
+use spl_domain::entities::offboarding::{CompanyOffboarding, OffboardingStatus};
+use spl_domain::ports::repositories::crud::CrudRepository;
+use spl_domain::ports::repositories::offboarding::CompanyOffboardingRepository;
+use spl_shared::adapters::persistence::repository::crud;
+use spl_shared::error::{AppError, Result};
+use uuid::Uuid;
+
+pub struct DbCompanyOffboardingRepository {
+    db: DatabaseConnection,
+}
+
+impl DbCompanyOffboardingRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait::async_trait]
+impl CrudRepository<CompanyOffboarding, Uuid> for DbCompanyOffboardingRepository {
+    async fn get_by_id(&self, id: Uuid) -> Result<Option<CompanyOffboarding>> {
+        crud::get_by_id::<company_offboarding::Entity, CompanyOffboarding, Uuid>(&self.db, id).await
+    }
+
+    async fn create(&self, entity: CompanyOffboarding) -> Result<CompanyOffboarding> {
+        crud::create::<company_offboarding::Entity, CompanyOffboarding>(&self.db, entity).await
+    }
+
+    async fn update(&self, entity: CompanyOffboarding) -> Result<CompanyOffboarding> {
+        crud::update::<company_offboarding::Entity, CompanyOffboarding>(&self.db, entity).await
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<CompanyOffboarding> {
+        crud::delete::<company_offboarding::Entity, CompanyOffboarding, Uuid>(&self.db, id).await
+    }
+}
+
+#[async_trait::async_trait]
+impl CompanyOffboardingRepository for DbCompanyOffboardingRepository {
+    async fn get_by_company_id(&self, company_id: Uuid) -> Result<Vec<CompanyOffboarding>> {
+        let models = company_offboarding::Entity::find()
+            .filter(company_offboarding::Column::CompanyId.eq(company_id))
+            .order_by_desc(company_offboarding::Column::CreatedAt)
+            .all(&self.db)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(models.into_iter().map(Into::into).collect())
+    }
+
+    async fn get_running(&self) -> Result<Vec<CompanyOffboarding>> {
+        let models = company_offboarding::Entity::find()
+            .filter(company_offboarding::Column::Status.is_in([
+                OffboardingStatus::Exporting.as_str(),
+                OffboardingStatus::Deleting.as_str(),
+            ]))
+            .order_by_asc(company_offboarding::Column::CreatedAt)
+            .all(&self.db)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(models.into_iter().map(Into::into).collect())
+    }
+}
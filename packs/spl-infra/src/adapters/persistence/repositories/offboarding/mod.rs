@@ -0,0 +1,5 @@
+pub mod company_offboarding;
+pub mod offboarding_event;
+
+pub use company_offboarding::DbCompanyOffboardingRepository;
+pub use offboarding_event::DbOffboardingEventRepository;
@@ -0,0 +1,51 @@
+use crate::adapters::persistence::entities::offboarding::offboarding_event;
+use sea_orm::*;
+use spl_domain::entities::offboarding::OffboardingEvent;
+use spl_domain::ports::repositories::crud::CrudRepository;
+use spl_domain::ports::repositories::offboarding::OffboardingEventRepository;
+use spl_shared::adapters::persistence::repository::crud;
+use spl_shared::error::{AppError, Result};
+use uuid::Uuid;
+
+pub struct DbOffboardingEventRepository {
+    db: DatabaseConnection,
+}
+
+impl DbOffboardingEventRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait::async_trait]
+impl CrudRepository<OffboardingEvent, Uuid> for DbOffboardingEventRepository {
+    async fn get_by_id(&self, id: Uuid) -> Result<Option<OffboardingEvent>> {
+        crud::get_by_id::<offboarding_event::Entity, OffboardingEvent, Uuid>(&self.db, id).await
+    }
+
+    async fn create(&self, entity: OffboardingEvent) -> Result<OffboardingEvent> {
+        crud::create::<offboarding_event::Entity, OffboardingEvent>(&self.db, entity).await
+    }
+
+    async fn update(&self, entity: OffboardingEvent) -> Result<OffboardingEvent> {
+        crud::update::<offboarding_event::Entity, OffboardingEvent>(&self.db, entity).await
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<OffboardingEvent> {
+        crud::delete::<offboarding_event::Entity, OffboardingEvent, Uuid>(&self.db, id).await
+    }
+}
+
+#[async_trait::async_trait]
+impl OffboardingEventRepository for DbOffboardingEventRepository {
+    async fn get_by_offboarding_id(&self, offboarding_id: Uuid) -> Result<Vec<OffboardingEvent>> {
+        let models = offboarding_event::Entity::find()
+            .filter(offboarding_event::Column::OffboardingId.eq(offboarding_id))
+            .order_by_asc(offboarding_event::Column::CreatedAt)
+            .all(&self.db)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(models.into_iter().map(Into::into).collect())
+    }
+}
@@ -1,4 +1,3 @@
-use crate::adapters::persistence::entities::user::user;
 use crate::adapters::persistence::entities::{diagnostics::label, diagnostics::prediction, plot};
 use chrono::{DateTime, Utc};
 use sea_orm::prelude::DateTimeWithTimeZone;
@@ -101,15 +100,14 @@ impl DbPlotRepository {
     }
 
     fn find_default_detailed(
-        company_id: Uuid,
+        company_ids: &[Uuid],
         labels: &Vec<String>,
     ) -> (Select<prediction::Entity>, Select<prediction::Entity>) {
         // Base query for unassigned predictions for the company
         let base_select = prediction::Entity::find()
-            .join(JoinType::InnerJoin, prediction::Relation::User.def())
             .left_join(label::Entity)
             .filter(prediction::Column::PlotId.is_null())
-            .filter(user::Column::CompanyId.eq(company_id));
+            .filter(prediction::Column::CompanyId.is_in(company_ids.to_vec()));
 
         // Build the default select with aggregations, similar to the detailed plot query but for unassigned predictions
         let mut default_select = base_select.clone().select_only();
@@ -221,12 +219,7 @@ impl PlotRepository for DbPlotRepository {
         let exists_default_query = Query::select()
             .expr(Expr::val(1))
             .from(prediction::Entity)
-            .inner_join(
-                user::Entity,
-                Expr::col((user::Entity, user::Column::Id))
-                    .equals((prediction::Entity, prediction::Column::UserId)),
-            )
-            .and_where(user::Column::CompanyId.eq(company_id))
+            .and_where(prediction::Column::CompanyId.eq(company_id))
             .and_where(prediction::Column::PlotId.is_null())
             .to_owned();
 
@@ -291,7 +284,8 @@ impl PlotRepository for DbPlotRepository {
 
     async fn get_detailed(
         &self,
-        company_id: Uuid,
+        company_ids: Vec<Uuid>,
+        plot_ids: Option<Vec<Uuid>>,
         offset: u64,
         limit: u64,
         labels: Vec<String>,
@@ -299,13 +293,31 @@ impl PlotRepository for DbPlotRepository {
         // Base Query: plot left join prediction left join label
         // Use LEFT JOIN to ensure plots without predictions are included.
         // DO NOT apply WHERE filters on predictions/labels to ensure ALL company plots are listed.
-        let query = plot::Entity::find()
-            .filter(plot::Column::CompanyId.eq(company_id))
+        let mut query = plot::Entity::find()
+            .filter(plot::Column::CompanyId.is_in(company_ids.clone()))
             .left_join(prediction::Entity)
             .join(JoinType::LeftJoin, prediction::Relation::Label.def());
 
         // The count query must count ALL company plots, regardless of label filters
-        let count_query = plot::Entity::find().filter(plot::Column::CompanyId.eq(company_id));
+        let mut count_query =
+            plot::Entity::find().filter(plot::Column::CompanyId.is_in(company_ids.clone()));
+
+        // Only some plots, e.g. those of a team: the default plot is left out
+        if let Some(plot_ids) = plot_ids {
+            query = query.filter(plot::Column::Id.is_in(plot_ids.clone()));
+            count_query = count_query.filter(plot::Column::Id.is_in(plot_ids));
+
+            let detailed_select = DbPlotRepository::create_plot_detailed_query(query, &labels)
+                .offset(offset)
+                .limit(limit)
+                .into_model::<DetailedPlotQueryResult>();
+
+            let (total, results) = tokio::try_join!(self.get_total(count_query), async {
+                detailed_select.all(&self.db).await.map_err(AppError::from)
+            })?;
+
+            return Ok((total, results.into_iter().map(Into::into).collect()));
+        }
 
         // Creates detailed select for plots with aggregations
         let detailed_select = DbPlotRepository::create_plot_detailed_query(query, &labels)
@@ -313,7 +325,7 @@ impl PlotRepository for DbPlotRepository {
             .limit(limit);
 
         // Creates detailed select for default plot (unassigned predictions) with aggregations
-        let (_, default_select) = DbPlotRepository::find_default_detailed(company_id, &labels);
+        let (_, default_select) = DbPlotRepository::find_default_detailed(&company_ids, &labels);
 
         // 3. Execute Total Count (Query 1) and Fetch Detailed Plots (Query 2) concurrently
         let (mut total, mut results) = tokio::try_join!(
@@ -370,7 +382,7 @@ impl PlotRepository for DbPlotRepository {
         labels: Vec<String>,
     ) -> Result<Option<DetailedPlot>> {
         // Base query for unassigned predictions for the company
-        let (base_query, query) = DbPlotRepository::find_default_detailed(company_id, &labels);
+        let (base_query, query) = DbPlotRepository::find_default_detailed(&[company_id], &labels);
 
         let total_diagnosis = self.get_total(base_query.clone()).await?;
 
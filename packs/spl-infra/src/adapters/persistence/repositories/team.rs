@@ -0,0 +1,170 @@
+use crate::adapters::persistence::entities::{team, team_member, team_plot};
+use sea_orm::sea_query::OnConflict;
+use sea_orm::*;
+use spl_domain::entities::team::{Team, TeamMember, TeamRole};
+use spl_domain::ports::repositories::crud::CrudRepository;
+use spl_domain::ports::repositories::team::TeamRepository;
+use spl_shared::adapters::persistence::repository::crud;
+use spl_shared::error::{AppError, Result};
+use uuid::Uuid;
+
+pub struct DbTeamRepository {
+    db: DatabaseConnection,
+}
+
+impl DbTeamRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait::async_trait]
+impl CrudRepository<Team, Uuid> for DbTeamRepository {
+    async fn get_by_id(&self, id: Uuid) -> Result<Option<Team>> {
+        crud::get_by_id::<team::Entity, Team, Uuid>(&self.db, id).await
+    }
+
+    async fn create(&self, entity: Team) -> Result<Team> {
+        crud::create::<team::Entity, Team>(&self.db, entity).await
+    }
+
+    async fn update(&self, entity: Team) -> Result<Team> {
+        crud::update::<team::Entity, Team>(&self.db, entity).await
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<Team> {
+        crud::delete::<team::Entity, Team, Uuid>(&self.db, id).await
+    }
+}
+
+#[async_trait::async_trait]
+impl TeamRepository for DbTeamRepository {
+    async fn get_by_company_id(&self, company_id: Uuid) -> Result<Vec<Team>> {
+        let models = team::Entity::find()
+            .filter(team::Column::CompanyId.eq(company_id))
+            .order_by_asc(team::Column::Name)
+            .all(&self.db)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(models.into_iter().map(Into::into).collect())
+    }
+
+    async fn get_supervised_by(&self, user_id: Uuid) -> Result<Vec<Team>> {
+        let models = team::Entity::find()
+            .inner_join(team_member::Entity)
+            .filter(team_member::Column::UserId.eq(user_id))
+            .filter(team_member::Column::Role.eq(TeamRole::Supervisor.as_str()))
+            .order_by_asc(team::Column::Name)
+            .all(&self.db)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(models.into_iter().map(Into::into).collect())
+    }
+
+    async fn get_members(&self, team_ids: Vec<Uuid>) -> Result<Vec<TeamMember>> {
+        if team_ids.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let models = team_member::Entity::find()
+            .filter(team_member::Column::TeamId.is_in(team_ids))
+            .order_by_asc(team_member::Column::CreatedAt)
+            .all(&self.db)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(models.into_iter().map(Into::into).collect())
+    }
+
+    async fn set_member(&self, member: TeamMember) -> Result<TeamMember> {
+        let model: team_member::ActiveModel = member.into();
+
+        team_member::Entity::insert(model)
+            .on_conflict(
+                OnConflict::columns([team_member::Column::TeamId, team_member::Column::UserId])
+                    .update_column(team_member::Column::Role)
+                    .to_owned(),
+            )
+            .exec_with_returning(&self.db)
+            .await
+            .map(Into::into)
+            .map_err(AppError::from)
+    }
+
+    async fn remove_member(&self, team_id: Uuid, user_id: Uuid) -> Result<()> {
+        let result = team_member::Entity::delete_many()
+            .filter(team_member::Column::TeamId.eq(team_id))
+            .filter(team_member::Column::UserId.eq(user_id))
+            .exec(&self.db)
+            .await
+            .map_err(AppError::from)?;
+
+        if result.rows_affected == 0 {
+            return Err(AppError::NotFound(format!(
+                "User {} is not a member of team {}",
+                user_id, team_id
+            )));
+        }
+
+        Ok(())
+    }
+
+    async fn get_plot_ids(&self, team_ids: Vec<Uuid>) -> Result<Vec<Uuid>> {
+        if team_ids.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let mut plot_ids: Vec<Uuid> = team_plot::Entity::find()
+            .select_only()
+            .column(team_plot::Column::PlotId)
+            .filter(team_plot::Column::TeamId.is_in(team_ids))
+            .into_tuple()
+            .all(&self.db)
+            .await
+            .map_err(AppError::from)?;
+
+        plot_ids.sort();
+        plot_ids.dedup();
+
+        Ok(plot_ids)
+    }
+
+    async fn add_plot(&self, team_id: Uuid, plot_id: Uuid) -> Result<()> {
+        let model = team_plot::ActiveModel {
+            team_id: Set(team_id),
+            plot_id: Set(plot_id),
+        };
+
+        team_plot::Entity::insert(model)
+            .on_conflict(
+                OnConflict::columns([team_plot::Column::TeamId, team_plot::Column::PlotId])
+                    .do_nothing()
+                    .to_owned(),
+            )
+            .exec_without_returning(&self.db)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(())
+    }
+
+    async fn remove_plot(&self, team_id: Uuid, plot_id: Uuid) -> Result<()> {
+        let result = team_plot::Entity::delete_many()
+            .filter(team_plot::Column::TeamId.eq(team_id))
+            .filter(team_plot::Column::PlotId.eq(plot_id))
+            .exec(&self.db)
+            .await
+            .map_err(AppError::from)?;
+
+        if result.rows_affected == 0 {
+            return Err(AppError::NotFound(format!(
+                "Plot {} is not assigned to team {}",
+                plot_id, team_id
+            )));
+        }
+
+        Ok(())
+    }
+}
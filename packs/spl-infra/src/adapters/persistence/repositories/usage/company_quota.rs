@@ -0,0 +1,40 @@
+use crate::adapters::persistence::entities::usage::company_quota;
+use sea_orm::*;
+use spl_domain::entities::usage::CompanyQuota;
+use spl_domain::ports::repositories::crud::CrudRepository;
+use spl_domain::ports::repositories::usage::CompanyQuotaRepository;
+use spl_shared::adapters::persistence::repository::crud;
+use spl_shared::error::Result;
+use uuid::Uuid;
+
+pub struct DbCompanyQuotaRepository {
+    db: DatabaseConnection,
+}
+
+impl DbCompanyQuotaRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait::async_trait]
+impl CrudRepository<CompanyQuota, Uuid> for DbCompanyQuotaRepository {
+    async fn get_by_id(&self, company_id: Uuid) -> Result<Option<CompanyQuota>> {
+        crud::get_by_id::<company_quota::Entity, CompanyQuota, Uuid>(&self.db, company_id).await
+    }
+
+    async fn create(&self, entity: CompanyQuota) -> Result<CompanyQuota> {
+        crud::create::<company_quota::Entity, CompanyQuota>(&self.db, entity).await
+    }
+
+    async fn update(&self, entity: CompanyQuota) -> Result<CompanyQuota> {
+        crud::update::<company_quota::Entity, CompanyQuota>(&self.db, entity).await
+    }
+
+    async fn delete(&self, company_id: Uuid) -> Result<CompanyQuota> {
+        crud::delete::<company_quota::Entity, CompanyQuota, Uuid>(&self.db, company_id).await
+    }
+}
+
+#[async_trait::async_trait]
+impl CompanyQuotaRepository for DbCompanyQuotaRepository {}
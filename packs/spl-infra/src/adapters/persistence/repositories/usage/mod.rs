@@ -0,0 +1,5 @@
+pub mod company_quota;
+pub mod usage_counter;
+
+pub use company_quota::DbCompanyQuotaRepository;
+pub use usage_counter::DbUsageRepository;
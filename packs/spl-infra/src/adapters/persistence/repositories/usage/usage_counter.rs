@@ -0,0 +1,217 @@
+use crate::adapters::persistence::entities::company;
+use crate::adapters::persistence::entities::usage::usage_counter;
+use chrono::{NaiveDate, Utc};
+use sea_orm::prelude::Expr;
+use sea_orm::sea_query::{Func, OnConflict, SimpleExpr};
+use sea_orm::*;
+use spl_domain::entities::usage::{QuotaLimit, Usage, UsageQuota, UsageRecord};
+use spl_domain::ports::repositories::usage::UsageRepository;
+use spl_shared::error::{AppError, Result};
+use uuid::Uuid;
+
+pub struct DbUsageRepository {
+    db: DatabaseConnection,
+}
+
+impl DbUsageRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+async fn find_by_company<C: ConnectionTrait>(
+    conn: &C,
+    company_id: Uuid,
+    period: NaiveDate,
+) -> Result<Vec<usage_counter::Model>> {
+    usage_counter::Entity::find()
+        .filter(usage_counter::Column::CompanyId.eq(company_id))
+        .filter(usage_counter::Column::Period.eq(period))
+        .order_by_desc(usage_counter::Column::Predictions)
+        .all(conn)
+        .await
+        .map_err(AppError::from)
+}
+
+#[async_trait::async_trait]
+impl UsageRepository for DbUsageRepository {
+    async fn increment(
+        &self,
+        user_id: Uuid,
+        company_id: Option<Uuid>,
+        period: NaiveDate,
+        predictions: u64,
+        stored_bytes: u64,
+    ) -> Result<()> {
+        let predictions = predictions.min(i64::MAX as u64) as i64;
+        let stored_bytes = stored_bytes.min(i64::MAX as u64) as i64;
+
+        let model = usage_counter::ActiveModel {
+            user_id: Set(user_id),
+            period: Set(period),
+            company_id: Set(company_id),
+            predictions: Set(predictions),
+            stored_bytes: Set(stored_bytes),
+            updated_at: Set(Utc::now().into()),
+        };
+
+        // Atomic upsert, the counters are added to and follow the current company of the user
+        usage_counter::Entity::insert(model)
+            .on_conflict(
+                OnConflict::columns([usage_counter::Column::UserId, usage_counter::Column::Period])
+                    .value(
+                        usage_counter::Column::Predictions,
+                        Expr::col((usage_counter::Entity, usage_counter::Column::Predictions))
+                            .add(predictions),
+                    )
+                    .value(
+                        usage_counter::Column::StoredBytes,
+                        Expr::col((usage_counter::Entity, usage_counter::Column::StoredBytes))
+                            .add(stored_bytes),
+                    )
+                    .update_columns([
+                        usage_counter::Column::CompanyId,
+                        usage_counter::Column::UpdatedAt,
+                    ])
+                    .to_owned(),
+            )
+            .exec_without_returning(&self.db)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(())
+    }
+
+    async fn reserve(
+        &self,
+        user_id: Uuid,
+        company_id: Option<Uuid>,
+        period: NaiveDate,
+        stored_bytes: u64,
+        quota: &UsageQuota,
+    ) -> Result<Option<QuotaLimit>> {
+        let txn = self.db.begin().await.map_err(AppError::from)?;
+
+        // Locking the company first makes reservations of its users wait for each other,
+        // and the counters of the user do the same for users without company
+        if let Some(company_id) = company_id {
+            company::Entity::find_by_id(company_id)
+                .lock_exclusive()
+                .one(&txn)
+                .await
+                .map_err(AppError::from)?;
+        }
+
+        let empty = usage_counter::ActiveModel {
+            user_id: Set(user_id),
+            period: Set(period),
+            company_id: Set(company_id),
+            predictions: Set(0),
+            stored_bytes: Set(0),
+            updated_at: Set(Utc::now().into()),
+        };
+        usage_counter::Entity::insert(empty)
+            .on_conflict(
+                OnConflict::columns([usage_counter::Column::UserId, usage_counter::Column::Period])
+                    .do_nothing()
+                    .to_owned(),
+            )
+            .exec_without_returning(&txn)
+            .await
+            .map_err(AppError::from)?;
+
+        let model = usage_counter::Entity::find_by_id((user_id, period))
+            .lock_exclusive()
+            .one(&txn)
+            .await
+            .map_err(AppError::from)?
+            .ok_or_else(|| AppError::Unknown("Usage counters not found".to_string()))?;
+
+        let user_usage = UsageRecord::from(model.clone()).usage();
+        let company_usage = match company_id {
+            Some(company_id) => {
+                let records: Vec<UsageRecord> = find_by_company(&txn, company_id, period)
+                    .await?
+                    .into_iter()
+                    .map(Into::into)
+                    .collect();
+                Some(Usage::of(&records))
+            }
+            None => None,
+        };
+
+        if let Some(limit) = quota.limit_reached(&user_usage, company_usage.as_ref(), stored_bytes)
+        {
+            return Ok(Some(limit));
+        }
+
+        let stored_bytes = stored_bytes.min(i64::MAX as u64) as i64;
+        let predictions = model.predictions.saturating_add(1);
+        let total_bytes = model.stored_bytes.saturating_add(stored_bytes);
+        let mut active: usage_counter::ActiveModel = model.into();
+        active.company_id = Set(company_id);
+        active.predictions = Set(predictions);
+        active.stored_bytes = Set(total_bytes);
+        active.updated_at = Set(Utc::now().into());
+        active.update(&txn).await.map_err(AppError::from)?;
+
+        txn.commit().await.map_err(AppError::from)?;
+
+        Ok(None)
+    }
+
+    async fn release(
+        &self,
+        user_id: Uuid,
+        period: NaiveDate,
+        predictions: u64,
+        stored_bytes: u64,
+    ) -> Result<()> {
+        let predictions = predictions.min(i64::MAX as u64) as i64;
+        let stored_bytes = stored_bytes.min(i64::MAX as u64) as i64;
+
+        let subtract = |column: usage_counter::Column, amount: i64| -> SimpleExpr {
+            Func::greatest([Expr::col(column).sub(amount), Expr::val(0i64).into()]).into()
+        };
+
+        usage_counter::Entity::update_many()
+            .col_expr(
+                usage_counter::Column::Predictions,
+                subtract(usage_counter::Column::Predictions, predictions),
+            )
+            .col_expr(
+                usage_counter::Column::StoredBytes,
+                subtract(usage_counter::Column::StoredBytes, stored_bytes),
+            )
+            .col_expr(
+                usage_counter::Column::UpdatedAt,
+                Expr::value(Utc::now().fixed_offset()),
+            )
+            .filter(usage_counter::Column::UserId.eq(user_id))
+            .filter(usage_counter::Column::Period.eq(period))
+            .exec(&self.db)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(())
+    }
+
+    async fn get_by_user(&self, user_id: Uuid, period: NaiveDate) -> Result<Option<UsageRecord>> {
+        let model = usage_counter::Entity::find_by_id((user_id, period))
+            .one(&self.db)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(model.map(Into::into))
+    }
+
+    async fn get_by_company(
+        &self,
+        company_id: Uuid,
+        period: NaiveDate,
+    ) -> Result<Vec<UsageRecord>> {
+        let models = find_by_company(&self.db, company_id, period).await?;
+
+        Ok(models.into_iter().map(Into::into).collect())
+    }
+}
@@ -0,0 +1,114 @@
+use crate::adapters::persistence::entities::user::invitation;
+use chrono::Utc;
+use sea_orm::prelude::Expr;
+use sea_orm::*;
+use spl_domain::entities::user::Invitation;
+use spl_domain::ports::repositories::crud::CrudRepository;
+use spl_domain::ports::repositories::user::InvitationRepository;
+use spl_shared::adapters::persistence::repository::crud;
+use spl_shared::error::{AppError, Result};
+use uuid::Uuid;
+
+pub struct DbInvitationRepository {
+    db: DatabaseConnection,
+}
+
+impl DbInvitationRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// Sets `column` to now on the invitation if it is still open
+    async fn close(&self, id: Uuid, column: invitation::Column) -> Result<bool> {
+        let now = Utc::now().fixed_offset();
+
+        let result = invitation::Entity::update_many()
+            .col_expr(column, Expr::value(now))
+            .col_expr(invitation::Column::UpdatedAt, Expr::value(now))
+            .filter(invitation::Column::Id.eq(id))
+            .filter(invitation::Column::AcceptedAt.is_null())
+            .filter(invitation::Column::CancelledAt.is_null())
+            .exec(&self.db)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(result.rows_affected > 0)
+    }
+}
+
+#[async_trait::async_trait]
+impl CrudRepository<Invitation, Uuid> for DbInvitationRepository {
+    async fn get_by_id(&self, id: Uuid) -> Result<Option<Invitation>> {
+        crud::get_by_id::<invitation::Entity, Invitation, Uuid>(&self.db, id).await
+    }
+
+    async fn create(&self, entity: Invitation) -> Result<Invitation> {
+        crud::create::<invitation::Entity, Invitation>(&self.db, entity).await
+    }
+
+    async fn update(&self, entity: Invitation) -> Result<Invitation> {
+        crud::update::<invitation::Entity, Invitation>(&self.db, entity).await
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<Invitation> {
+        crud::delete::<invitation::Entity, Invitation, Uuid>(&self.db, id).await
+    }
+}
+
+#[async_trait::async_trait]
+impl InvitationRepository for DbInvitationRepository {
+    async fn get_by_token_hash(&self, token_hash: &str) -> Result<Option<Invitation>> {
+        let model = invitation::Entity::find()
+            .filter(invitation::Column::TokenHash.eq(token_hash))
+            .one(&self.db)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(model.map(Into::into))
+    }
+
+    async fn get_by_company_id(&self, company_id: Uuid) -> Result<Vec<Invitation>> {
+        let models = invitation::Entity::find()
+            .filter(invitation::Column::CompanyId.eq(company_id))
+            .order_by_desc(invitation::Column::CreatedAt)
+            .all(&self.db)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(models.into_iter().map(Into::into).collect())
+    }
+
+    async fn get_pending_by_email(
+        &self,
+        company_id: Uuid,
+        email: &str,
+    ) -> Result<Option<Invitation>> {
+        let model = invitation::Entity::find()
+            .filter(invitation::Column::CompanyId.eq(company_id))
+            .filter(invitation::Column::Email.eq(email))
+            .filter(invitation::Column::AcceptedAt.is_null())
+            .filter(invitation::Column::CancelledAt.is_null())
+            .filter(invitation::Column::ExpiresAt.gt(Utc::now().fixed_offset()))
+            .one(&self.db)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(model.map(Into::into))
+    }
+
+    async fn mark_accepted(&self, id: Uuid) -> Result<bool> {
+        self.close(id, invitation::Column::AcceptedAt).await
+    }
+
+    async fn cancel(&self, id: Uuid) -> Result<bool> {
+        self.close(id, invitation::Column::CancelledAt).await
+    }
+
+    async fn count_by_role_id(&self, role_id: i32) -> Result<u64> {
+        invitation::Entity::find()
+            .filter(invitation::Column::RoleId.eq(role_id))
+            .count(&self.db)
+            .await
+            .map_err(AppError::from)
+    }
+}
@@ -0,0 +1,135 @@
+use crate::adapters::persistence::entities::company;
+use crate::adapters::persistence::entities::user::{membership, role};
+use crate::adapters::persistence::mappers::user::membership::MembershipMapperContext;
+use chrono::Utc;
+use sea_orm::sea_query::OnConflict;
+use sea_orm::*;
+use spl_domain::entities::user::CompanyMembership;
+use spl_domain::ports::repositories::user::MembershipRepository;
+use spl_shared::error::{AppError, Result};
+use spl_shared::traits::IntoWithContext;
+use uuid::Uuid;
+
+type MembershipRow = (
+    membership::Model,
+    Option<role::Model>,
+    Option<company::Model>,
+);
+
+pub struct DbMembershipRepository {
+    db: DatabaseConnection,
+}
+
+impl DbMembershipRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    fn map_row((model, role, company): MembershipRow) -> Result<CompanyMembership> {
+        match (role, company) {
+            (Some(role), Some(company)) => model.into_with_context(MembershipMapperContext {
+                company: company.into(),
+                role: role.into(),
+            }),
+            _ => Err(AppError::NotFound(format!(
+                "Membership of user {} in company {} has missing relations (integrity error)",
+                model.user_id, model.company_id
+            ))),
+        }
+    }
+
+    async fn find(&self, condition: Condition) -> Result<Vec<CompanyMembership>> {
+        let rows = membership::Entity::find()
+            .filter(condition)
+            .find_also_related(role::Entity)
+            .find_also_related(company::Entity)
+            .order_by_asc(membership::Column::CreatedAt)
+            .all(&self.db)
+            .await
+            .map_err(AppError::from)?;
+
+        rows.into_iter().map(Self::map_row).collect()
+    }
+}
+
+#[async_trait::async_trait]
+impl MembershipRepository for DbMembershipRepository {
+    async fn get_by_user_id(&self, user_id: Uuid) -> Result<Vec<CompanyMembership>> {
+        self.find(Condition::all().add(membership::Column::UserId.eq(user_id)))
+            .await
+    }
+
+    async fn get_by_company_id(&self, company_id: Uuid) -> Result<Vec<CompanyMembership>> {
+        self.find(Condition::all().add(membership::Column::CompanyId.eq(company_id)))
+            .await
+    }
+
+    async fn get(&self, user_id: Uuid, company_id: Uuid) -> Result<Option<CompanyMembership>> {
+        let memberships = self
+            .find(
+                Condition::all()
+                    .add(membership::Column::UserId.eq(user_id))
+                    .add(membership::Column::CompanyId.eq(company_id)),
+            )
+            .await?;
+
+        Ok(memberships.into_iter().next())
+    }
+
+    async fn set(
+        &self,
+        user_id: Uuid,
+        company_id: Uuid,
+        role_id: i32,
+    ) -> Result<CompanyMembership> {
+        let model = membership::ActiveModel {
+            user_id: Set(user_id),
+            company_id: Set(company_id),
+            role_id: Set(role_id),
+            created_at: Set(Utc::now().into()),
+        };
+
+        membership::Entity::insert(model)
+            .on_conflict(
+                OnConflict::columns([membership::Column::UserId, membership::Column::CompanyId])
+                    .update_column(membership::Column::RoleId)
+                    .to_owned(),
+            )
+            .exec_without_returning(&self.db)
+            .await
+            .map_err(AppError::from)?;
+
+        self.get(user_id, company_id)
+            .await?
+            .ok_or(AppError::NotFound(format!(
+                "No membership of user {} in company {}",
+                user_id, company_id
+            )))
+    }
+
+    async fn delete(&self, user_id: Uuid, company_id: Uuid) -> Result<()> {
+        let result = membership::Entity::delete_many()
+            .filter(membership::Column::UserId.eq(user_id))
+            .filter(membership::Column::CompanyId.eq(company_id))
+            .exec(&self.db)
+            .await
+            .map_err(AppError::from)?;
+
+        if result.rows_affected == 0 {
+            return Err(AppError::NotFound(format!(
+                "No membership of user {} in company {}",
+                user_id, company_id
+            )));
+        }
+
+        Ok(())
+    }
+
+    async fn count_by_role_id(&self, role_id: i32) -> Result<u64> {
+        membership::Entity::find()
+            .filter(membership::Column::RoleId.eq(role_id))
+            .count(&self.db)
+            .await
+            .map_err(AppError::from)
+    }
+}
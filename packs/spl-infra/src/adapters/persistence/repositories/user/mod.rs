@@ -1,5 +1,11 @@
+pub mod invitation;
+pub mod membership;
+pub mod permission;
 pub mod role;
 pub mod user;
 
+pub use invitation::*;
+pub use membership::*;
+pub use permission::*;
 pub use role::*;
 pub use user::*;
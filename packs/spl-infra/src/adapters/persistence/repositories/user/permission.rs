@@ -0,0 +1,108 @@
+use crate::adapters::persistence::entities::user::{permission, role, role_permission};
+use async_trait::async_trait;
+use sea_orm::*;
+use spl_domain::entities::user::{PermissionGrant, PermissionScope, RolePermission};
+use spl_domain::ports::repositories::user::PermissionRepository;
+use spl_shared::error::{AppError, Result};
+use std::collections::HashMap;
+use tracing::warn;
+
+pub struct DbPermissionRepository {
+    db: DatabaseConnection,
+}
+
+impl DbPermissionRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    async fn find_grants(&self, role_id: Option<i32>) -> Result<Vec<RolePermission>> {
+        let mut query = role_permission::Entity::find();
+        if let Some(role_id) = role_id {
+            query = query.filter(role_permission::Column::RoleId.eq(role_id));
+        }
+
+        let rows = query
+            .find_also_related(role::Entity)
+            .find_also_related(permission::Entity)
+            .all(&self.db)
+            .await
+            .map_err(AppError::from)?;
+
+        let grants = rows
+            .into_iter()
+            .filter_map(|(grant, role, permission)| {
+                let scope = PermissionScope::parse(&grant.scope).or_else(|| {
+                    warn!(
+                        role_id = grant.role_id,
+                        scope = %grant.scope,
+                        "Ignoring grant with unknown scope"
+                    );
+                    None
+                })?;
+
+                Some(RolePermission {
+                    role_id: grant.role_id,
+                    role: role?.name,
+                    permission: permission?.name,
+                    scope,
+                })
+            })
+            .collect();
+
+        Ok(grants)
+    }
+}
+
+#[async_trait]
+impl PermissionRepository for DbPermissionRepository {
+    async fn get_grants(&self) -> Result<Vec<RolePermission>> {
+        self.find_grants(None).await
+    }
+
+    async fn get_by_role_id(&self, role_id: i32) -> Result<Vec<RolePermission>> {
+        self.find_grants(Some(role_id)).await
+    }
+
+    async fn set_role_grants(&self, role_id: i32, grants: Vec<PermissionGrant>) -> Result<()> {
+        let names: Vec<String> = grants.iter().map(|g| g.permission.clone()).collect();
+        let ids: HashMap<String, i32> = permission::Entity::find()
+            .filter(permission::Column::Name.is_in(names))
+            .all(&self.db)
+            .await
+            .map_err(AppError::from)?
+            .into_iter()
+            .map(|p| (p.name, p.id))
+            .collect();
+
+        let mut models = Vec::with_capacity(grants.len());
+        for grant in grants {
+            let permission_id = *ids.get(&grant.permission).ok_or_else(|| {
+                AppError::ValidationError(format!("Unknown permission: {}", grant.permission))
+            })?;
+
+            models.push(role_permission::ActiveModel {
+                role_id: Set(role_id),
+                permission_id: Set(permission_id),
+                scope: Set(grant.scope.as_str().to_string()),
+            });
+        }
+
+        let txn = self.db.begin().await.map_err(AppError::from)?;
+
+        role_permission::Entity::delete_many()
+            .filter(role_permission::Column::RoleId.eq(role_id))
+            .exec(&txn)
+            .await
+            .map_err(AppError::from)?;
+
+        if !models.is_empty() {
+            role_permission::Entity::insert_many(models)
+                .exec(&txn)
+                .await
+                .map_err(AppError::from)?;
+        }
+
+        txn.commit().await.map_err(AppError::from)
+    }
+}
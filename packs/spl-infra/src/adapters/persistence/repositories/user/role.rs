@@ -24,7 +24,13 @@ impl CrudRepository<Role, i32> for DbRoleRepository {
     }
 
     async fn create(&self, entity: Role) -> Result<Role> {
-        crud::create::<role::Entity, Role>(&self.db, entity).await
+        // Seeded roles take the first ids, let the sequence pick the next one
+        let mut model: role::ActiveModel = entity.into();
+        model.id = NotSet;
+
+        let model = model.insert(&self.db).await.map_err(AppError::from)?;
+
+        Ok(model.into())
     }
 
     async fn update(&self, entity: Role) -> Result<Role> {
@@ -245,4 +245,21 @@ impl UserRepository for DbUserRepository {
         }
         Ok(users)
     }
+
+    async fn count_by_role_id(&self, role_id: i32) -> Result<u64> {
+        user::Entity::find()
+            .filter(user::Column::RoleId.eq(role_id))
+            .count(&self.db)
+            .await
+            .map_err(AppError::from)
+    }
+
+    async fn count_active_by_role_ids(&self, role_ids: Vec<i32>) -> Result<u64> {
+        user::Entity::find()
+            .filter(user::Column::RoleId.is_in(role_ids))
+            .filter(user::Column::DeactivatedAt.is_null())
+            .count(&self.db)
+            .await
+            .map_err(AppError::from)
+    }
 }
@@ -1,9 +1,14 @@
-use crate::adapters::web::middleware::auth::AuthUser;
+use crate::adapters::web::middleware::auth::{AuthUser, ClientInfo};
 use crate::adapters::web::models::{
-    auth::{LoginRequest, RegisterRequest, TokenResponse},
+    auth::{
+        ForgotPasswordRequest, LoginRequest, RefreshTokenRequest, RegisterRequest,
+        ResetPasswordRequest, TokenResponse, TwoFactorChallengeResponse, TwoFactorSetupRequest,
+        TwoFactorSetupResponse, VerifyEmailRequest, VerifyTwoFactorRequest,
+    },
     health::HealthResponse,
     user::{SimplifiedRoleResponse, UserResponse},
 };
+use spl_application::dtos::auth::LoginResultDto;
 use spl_shared::http::responses::{ok_iter_if_or_not_found, StatusResponse};
 
 use crate::adapters::web::state::AppState;
@@ -26,8 +31,8 @@ use utoipa::OpenApi;
 
 #[derive(OpenApi)]
 #[openapi(
-    paths(login, register, health_check, validate, get_all_roles),
-    components(schemas(LoginRequest, TokenResponse, RegisterRequest, UserResponse, HealthResponse, StatusResponse, SimplifiedRoleResponse)),
+    paths(login, verify_two_factor, setup_two_factor, refresh, logout, forgot_password, reset_password, verify_email, register, health_check, validate, get_all_roles),
+    components(schemas(LoginRequest, TokenResponse, TwoFactorChallengeResponse, VerifyTwoFactorRequest, TwoFactorSetupRequest, TwoFactorSetupResponse, RefreshTokenRequest, ForgotPasswordRequest, ResetPasswordRequest, VerifyEmailRequest, RegisterRequest, UserResponse, HealthResponse, StatusResponse, SimplifiedRoleResponse)),
     tags((name = "auth", description = "Authentication endpoints"))
 )]
 pub struct AuthApi;
@@ -38,11 +43,42 @@ pub fn router(rate_limit_state: Arc<RateLimitState>) -> Router<Arc<AppState>> {
             "/auth/login",
             post(login)
                 .route_layer(middleware::from_fn_with_state(
-                    rate_limit_state,
+                    rate_limit_state.clone(),
                     local_rate_limit_middleware,
                 ))
                 .layer(Extension(EndpointRateLimit::new(5).with_window(60))),
         )
+        .route(
+            "/auth/2fa/verify",
+            post(verify_two_factor)
+                .route_layer(middleware::from_fn_with_state(
+                    rate_limit_state.clone(),
+                    local_rate_limit_middleware,
+                ))
+                .layer(Extension(EndpointRateLimit::new(5).with_window(60))),
+        )
+        .route(
+            "/auth/2fa/setup",
+            post(setup_two_factor)
+                .route_layer(middleware::from_fn_with_state(
+                    rate_limit_state.clone(),
+                    local_rate_limit_middleware,
+                ))
+                .layer(Extension(EndpointRateLimit::new(3).with_window(300))),
+        )
+        .route(
+            "/auth/password/forgot",
+            post(forgot_password)
+                .route_layer(middleware::from_fn_with_state(
+                    rate_limit_state.clone(),
+                    local_rate_limit_middleware,
+                ))
+                .layer(Extension(EndpointRateLimit::new(3).with_window(300))),
+        )
+        .route("/auth/password/reset", post(reset_password))
+        .route("/auth/email/verify", post(verify_email))
+        .route("/auth/refresh", post(refresh))
+        .route("/auth/logout", post(logout))
         .route("/auth/register", post(register))
         .route("/auth/health", get(health_check))
         .route("/auth/validate", post(validate))
@@ -70,14 +106,17 @@ async fn health_check() -> impl IntoResponse {
     request_body = LoginRequest,
     responses(
         (status = 200, description = "Login successful", body = TokenResponse),
+        (status = 202, description = "Password accepted, second factor required", body = TwoFactorChallengeResponse),
         (status = 401, description = "Invalid credentials", body = StatusResponse),
         (status = 404, description = "User not found", body = StatusResponse),
+        (status = 423, description = "Account locked after too many failed attempts", body = StatusResponse),
         (status = 500, description = "Internal Server Error", body = StatusResponse)
     ),
     tag = "auth"
 )]
 async fn login(
     State(state): State<Arc<AppState>>,
+    ClientInfo(client): ClientInfo,
     ValidatedJson(payload): ValidatedJson<LoginRequest>,
 ) -> impl IntoResponse {
     // Validate that at least one identifier is provided
@@ -93,12 +132,199 @@ async fn login(
             .into_response();
     }
 
-    match state.auth_service.login(payload.into()).await {
-        Ok(token) => (StatusCode::OK, Json(TokenResponse { token })).into_response(),
+    match state.auth_service.login(payload.into(), client).await {
+        Ok(LoginResultDto::Authenticated(tokens)) => {
+            (StatusCode::OK, Json(TokenResponse::from(tokens))).into_response()
+        }
+        Ok(LoginResultDto::TwoFactorRequired(challenge)) => (
+            StatusCode::ACCEPTED,
+            Json(TwoFactorChallengeResponse::from(challenge)),
+        )
+            .into_response(),
         Err(e) => e.into_response(),
     }
 }
 
+#[utoipa::path(
+    post,
+    path = "/auth/2fa/verify",
+    request_body = VerifyTwoFactorRequest,
+    responses(
+        (status = 200, description = "Second factor accepted", body = TokenResponse),
+        (status = 401, description = "Invalid code or expired challenge", body = StatusResponse),
+        (status = 423, description = "Account locked after too many failed attempts", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    tag = "auth"
+)]
+async fn verify_two_factor(
+    State(state): State<Arc<AppState>>,
+    ClientInfo(client): ClientInfo,
+    ValidatedJson(payload): ValidatedJson<VerifyTwoFactorRequest>,
+) -> Result<impl IntoResponse> {
+    let tokens = state
+        .auth_service
+        .verify_two_factor(payload.into(), client)
+        .await?;
+
+    Ok((StatusCode::OK, Json(TokenResponse::from(tokens))))
+}
+
+#[utoipa::path(
+    post,
+    path = "/auth/2fa/setup",
+    request_body = TwoFactorSetupRequest,
+    responses(
+        (status = 200, description = "Secret and recovery codes for mandatory enrollment", body = TwoFactorSetupResponse),
+        (status = 401, description = "Invalid or expired challenge", body = StatusResponse),
+        (status = 403, description = "Two-factor authentication is not mandatory for this user", body = StatusResponse),
+        (status = 409, description = "Two-factor authentication already enabled", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    tag = "auth"
+)]
+async fn setup_two_factor(
+    State(state): State<Arc<AppState>>,
+    ValidatedJson(payload): ValidatedJson<TwoFactorSetupRequest>,
+) -> Result<impl IntoResponse> {
+    let setup = state
+        .auth_service
+        .setup_two_factor(&payload.challenge_token)
+        .await?;
+
+    Ok(Json(TwoFactorSetupResponse::from(setup)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/auth/refresh",
+    request_body = RefreshTokenRequest,
+    responses(
+        (status = 200, description = "Tokens refreshed", body = TokenResponse),
+        (status = 401, description = "Invalid, expired or reused refresh token", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    tag = "auth"
+)]
+async fn refresh(
+    State(state): State<Arc<AppState>>,
+    ClientInfo(client): ClientInfo,
+    ValidatedJson(payload): ValidatedJson<RefreshTokenRequest>,
+) -> Result<impl IntoResponse> {
+    let tokens = state
+        .auth_service
+        .refresh(&payload.refresh_token, client)
+        .await?;
+
+    Ok((StatusCode::OK, Json(TokenResponse::from(tokens))))
+}
+
+#[utoipa::path(
+    post,
+    path = "/auth/logout",
+    request_body = RefreshTokenRequest,
+    responses(
+        (status = 200, description = "Session revoked", body = StatusResponse),
+        (status = 401, description = "Invalid refresh token", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    tag = "auth"
+)]
+async fn logout(
+    State(state): State<Arc<AppState>>,
+    ValidatedJson(payload): ValidatedJson<RefreshTokenRequest>,
+) -> Result<impl IntoResponse> {
+    state.auth_service.logout(&payload.refresh_token).await?;
+
+    Ok(Json(StatusResponse {
+        success: true,
+        code: 200,
+        message: "Session revoked".to_string(),
+    }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/auth/password/forgot",
+    request_body = ForgotPasswordRequest,
+    responses(
+        (status = 200, description = "Reset email sent if the account exists", body = StatusResponse),
+        (status = 400, description = "Validation Error", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    tag = "auth"
+)]
+async fn forgot_password(
+    State(state): State<Arc<AppState>>,
+    ValidatedJson(payload): ValidatedJson<ForgotPasswordRequest>,
+) -> Result<impl IntoResponse> {
+    state
+        .password_reset_service
+        .request_reset(payload.into())
+        .await?;
+
+    // Same answer whether or not the account exists
+    Ok(Json(StatusResponse {
+        success: true,
+        code: 200,
+        message: "If the account exists, a password reset email has been sent".to_string(),
+    }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/auth/password/reset",
+    request_body = ResetPasswordRequest,
+    responses(
+        (status = 200, description = "Password updated", body = StatusResponse),
+        (status = 400, description = "Invalid or expired token", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    tag = "auth"
+)]
+async fn reset_password(
+    State(state): State<Arc<AppState>>,
+    ValidatedJson(payload): ValidatedJson<ResetPasswordRequest>,
+) -> Result<impl IntoResponse> {
+    state
+        .password_reset_service
+        .reset_password(payload.into())
+        .await?;
+
+    Ok(Json(StatusResponse {
+        success: true,
+        code: 200,
+        message: "Password updated".to_string(),
+    }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/auth/email/verify",
+    request_body = VerifyEmailRequest,
+    responses(
+        (status = 200, description = "Email verified", body = StatusResponse),
+        (status = 400, description = "Invalid or expired token", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    tag = "auth"
+)]
+async fn verify_email(
+    State(state): State<Arc<AppState>>,
+    ValidatedJson(payload): ValidatedJson<VerifyEmailRequest>,
+) -> Result<impl IntoResponse> {
+    state
+        .email_verification_service
+        .verify(&payload.token)
+        .await?;
+
+    Ok(Json(StatusResponse {
+        success: true,
+        code: 200,
+        message: "Email verified".to_string(),
+    }))
+}
+
 #[utoipa::path(
     post,
     path = "/auth/register",
@@ -1,15 +1,17 @@
+use spl_domain::entities::user::{permissions, PermissionScope};
 use spl_shared::error::Result;
 use spl_shared::http::extractor::ValidatedJson;
 
 use crate::adapters::web::{
     middleware::{
         auth::AuthUser,
-        permissions::{permission_check, RequiredRoles, RoleValidation},
+        permissions::{permission_check, RequiredPermission},
     },
     models::{
         common::SimplifiedQuery,
         company::{
-            CompanyResponse, CreateCompanyRequest, SimplifiedCompanyResponse, UpdateCompanyRequest,
+            CompanyResponse, CreateCompanyRequest, SetCompanyParentRequest,
+            SimplifiedCompanyResponse, UpdateCompanyRequest,
         },
         user::{SimplifiedUserResponse, UserResponse},
     },
@@ -40,11 +42,12 @@ enum CompanyOrSimplifiedResponse {
 
 #[derive(OpenApi)]
 #[openapi(
-    paths(get_all_public_companies, get_all_companies, create_company, get_company, update_company, delete_company, get_company_users),
+    paths(get_all_public_companies, get_all_companies, create_company, get_company, update_company, delete_company, get_company_users, get_company_children, set_company_parent),
     components(schemas(
         CreateCompanyRequest,
         CompanyResponse,
         UpdateCompanyRequest,
+        SetCompanyParentRequest,
         SimplifiedCompanyResponse,
         CompanyOrSimplifiedResponse,
         StatusResponse,
@@ -56,15 +59,15 @@ pub struct CompaniesApi;
 
 pub fn router(state: Arc<AppState>) -> Router<Arc<AppState>> {
     let admin_only_layer = middleware::from_fn_with_state(state.clone(), permission_check);
-    let admin_extension_roles = Extension(RequiredRoles(
-        vec!["admin".to_string()],
-        RoleValidation::Higher,
+    let admin_extension_permission = Extension(RequiredPermission(
+        permissions::COMPANIES_MANAGE,
+        PermissionScope::Any,
     ));
 
     let supervisor_layer = middleware::from_fn_with_state(state.clone(), permission_check);
-    let supervisor_extension_roles = Extension(RequiredRoles(
-        vec!["supervisor".to_string()],
-        RoleValidation::Higher,
+    let supervisor_extension_permission = Extension(RequiredPermission(
+        permissions::USERS_READ,
+        PermissionScope::Team,
     ));
 
     let admin_router = Router::new()
@@ -73,18 +76,20 @@ pub fn router(state: Arc<AppState>) -> Router<Arc<AppState>> {
             "/companies/{id}",
             put(update_company).delete(delete_company),
         )
+        .route("/companies/{id}/parent", put(set_company_parent))
         .route_layer(admin_only_layer)
-        .route_layer(admin_extension_roles)
+        .route_layer(admin_extension_permission)
         .with_state(state.clone());
 
     let supervisor_router = Router::new()
         .route("/companies/{id}/users", get(get_company_users))
         .route_layer(supervisor_layer)
-        .route_layer(supervisor_extension_roles)
+        .route_layer(supervisor_extension_permission)
         .with_state(state.clone());
 
     let authenticated_router = Router::new()
         .route("/companies/{id}", get(get_company))
+        .route("/companies/{id}/children", get(get_company_children))
         .with_state(state.clone());
 
     let public_router = Router::new()
@@ -239,6 +244,73 @@ async fn delete_company(
     ))
 }
 
+#[utoipa::path(
+    put,
+    path = "/companies/{id}/parent",
+    params(
+        ("id" = Uuid, Path, description = "Company ID")
+    ),
+    request_body = SetCompanyParentRequest,
+    responses(
+        (status = 200, description = "Company moved below its new parent", body = CompanyResponse),
+        (status = 400, description = "The parent is the company itself or a company below it", body = StatusResponse),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 403, description = "Forbidden - Admin access required", body = StatusResponse),
+        (status = 404, description = "Company or parent not found", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    security(
+        ("jwt_auth" = [])
+    ),
+    tag = "companies"
+)]
+async fn set_company_parent(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+    AuthUser(user): AuthUser,
+    ValidatedJson(payload): ValidatedJson<SetCompanyParentRequest>,
+) -> Result<impl IntoResponse> {
+    let company = state
+        .company_service
+        .set_parent(&user, id, payload.parent_id)
+        .await?;
+
+    Ok(Json(CompanyResponse::from(company)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/companies/{id}/children",
+    params(
+        ("id" = Uuid, Path, description = "Company ID")
+    ),
+    responses(
+        (status = 200, description = "Companies right below the company", body = Vec<CompanyResponse>),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 403, description = "Forbidden - Access denied", body = StatusResponse),
+        (status = 404, description = "Company not found", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    security(
+        ("jwt_auth" = [])
+    ),
+    tag = "companies"
+)]
+async fn get_company_children(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+    AuthUser(user): AuthUser,
+) -> Result<impl IntoResponse> {
+    let children = state.company_service.get_children(&user, id).await?;
+
+    Ok(Json(
+        children
+            .into_iter()
+            .map(CompanyResponse::from)
+            .collect::<Vec<_>>(),
+    ))
+}
+
 #[utoipa::path(
     get,
     path = "/public/companies",
@@ -0,0 +1,127 @@
+use crate::adapters::web::middleware::auth::AuthUser;
+use crate::adapters::web::models::company_settings::{
+    CompanySettingsRequest, CompanySettingsResponse,
+};
+use crate::adapters::web::state::AppState;
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+    routing::get,
+    Json, Router,
+};
+use spl_shared::error::Result;
+use spl_shared::http::extractor::ValidatedJson;
+use spl_shared::http::responses::StatusResponse;
+use std::sync::Arc;
+use utoipa::OpenApi;
+use uuid::Uuid;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(get_company_settings, update_company_settings, reset_company_settings),
+    components(schemas(CompanySettingsRequest, CompanySettingsResponse, StatusResponse)),
+    tags((name = "company_settings", description = "Settings of a company over the server defaults"))
+)]
+pub struct CompanySettingsApi;
+
+/// Members read the settings of their company, so access is checked by the service
+pub fn router(state: Arc<AppState>) -> Router<Arc<AppState>> {
+    Router::new()
+        .route(
+            "/companies/{id}/settings",
+            get(get_company_settings)
+                .put(update_company_settings)
+                .delete(reset_company_settings),
+        )
+        .with_state(state)
+}
+
+#[utoipa::path(
+    get,
+    path = "/companies/{id}/settings",
+    params(
+        ("id" = Uuid, Path, description = "Company ID")
+    ),
+    responses(
+        (status = 200, description = "Settings of the company, with the server defaults for those it did not change", body = CompanySettingsResponse),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 403, description = "Forbidden - Access denied", body = StatusResponse),
+        (status = 404, description = "Company not found", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    security(
+        ("jwt_auth" = [])
+    ),
+    tag = "company_settings"
+)]
+async fn get_company_settings(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+    AuthUser(user): AuthUser,
+) -> Result<impl IntoResponse> {
+    let settings = state.company_settings_service.get(&user, id).await?;
+
+    Ok(Json(CompanySettingsResponse::from(settings)))
+}
+
+#[utoipa::path(
+    put,
+    path = "/companies/{id}/settings",
+    params(
+        ("id" = Uuid, Path, description = "Company ID")
+    ),
+    request_body = CompanySettingsRequest,
+    responses(
+        (status = 200, description = "Settings replaced, missing fields follow the server defaults", body = CompanySettingsResponse),
+        (status = 400, description = "Invalid input", body = StatusResponse),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 403, description = "Forbidden - Access denied", body = StatusResponse),
+        (status = 404, description = "Company not found", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    security(
+        ("jwt_auth" = [])
+    ),
+    tag = "company_settings"
+)]
+async fn update_company_settings(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+    AuthUser(user): AuthUser,
+    ValidatedJson(payload): ValidatedJson<CompanySettingsRequest>,
+) -> Result<impl IntoResponse> {
+    let settings = state
+        .company_settings_service
+        .update(&user, id, payload.into())
+        .await?;
+
+    Ok(Json(CompanySettingsResponse::from(settings)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/companies/{id}/settings",
+    params(
+        ("id" = Uuid, Path, description = "Company ID")
+    ),
+    responses(
+        (status = 200, description = "Every setting is back to the server default", body = CompanySettingsResponse),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 403, description = "Forbidden - Access denied", body = StatusResponse),
+        (status = 404, description = "Company not found", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    security(
+        ("jwt_auth" = [])
+    ),
+    tag = "company_settings"
+)]
+async fn reset_company_settings(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+    AuthUser(user): AuthUser,
+) -> Result<impl IntoResponse> {
+    let settings = state.company_settings_service.reset(&user, id).await?;
+
+    Ok(Json(CompanySettingsResponse::from(settings)))
+}
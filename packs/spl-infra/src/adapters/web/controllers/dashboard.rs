@@ -1,3 +1,5 @@
+use spl_domain::entities::auth::api_key::scopes;
+use spl_domain::entities::user::{permissions, PermissionScope};
 use spl_shared::error::Result;
 use spl_shared::http::extractor::ValidatedJson;
 use spl_shared::http::responses::{
@@ -5,7 +7,7 @@ use spl_shared::http::responses::{
 };
 
 use crate::adapters::web::{
-    middleware::auth::AuthUser,
+    middleware::auth::{AuthUser, RequiredScope},
     models::{
         common::SimplifiedQuery,
         dashboard::{
@@ -28,9 +30,7 @@ use axum::{
     Extension, Json, Router,
 };
 
-use crate::adapters::web::middleware::permissions::{
-    permission_check, RequiredRoles, RoleValidation,
-};
+use crate::adapters::web::middleware::permissions::{permission_check, RequiredPermission};
 use crate::adapters::web::models::dashboard::SimplifiedDashboardCountsResponse;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
@@ -86,9 +86,9 @@ pub struct DashboardApi;
 
 pub fn router(state: Arc<AppState>) -> Router<Arc<AppState>> {
     let admin_only_layer = middleware::from_fn_with_state(state.clone(), permission_check);
-    let admin_extension_roles = Extension(RequiredRoles(
-        vec!["admin".to_string()],
-        RoleValidation::Higher,
+    let admin_extension_permission = Extension(RequiredPermission(
+        permissions::PREDICTIONS_READ,
+        PermissionScope::Any,
     ));
 
     Router::new()
@@ -97,7 +97,7 @@ pub fn router(state: Arc<AppState>) -> Router<Arc<AppState>> {
             "/dashboard/filters",
             post(get_filters_admin)
                 .route_layer(admin_only_layer.clone())
-                .route_layer(admin_extension_roles.clone()),
+                .route_layer(admin_extension_permission.clone()),
         )
         .route("/dashboard/summary", post(get_summary))
         .route("/dashboard/counts", post(get_summary_counts))
@@ -110,6 +110,7 @@ pub fn router(state: Arc<AppState>) -> Router<Arc<AppState>> {
             "/dashboard/plots/{id}/summary",
             post(get_summary_detailed_plot_by_id),
         )
+        .route_layer(Extension(RequiredScope(scopes::DASHBOARD_READ)))
         .with_state(state)
 }
 
@@ -148,7 +149,8 @@ async fn _get_filters(
         (status = 500, description = "Internal Server Error", body = StatusResponse)
     ),
     security(
-        ("jwt_auth" = [])
+        ("jwt_auth" = []),
+        ("api_key" = ["dashboard:read"])
     ),
     tag = "dashboard"
 )]
@@ -181,7 +183,8 @@ async fn get_filters(
         (status = 500, description = "Internal Server Error", body = StatusResponse)
     ),
     security(
-        ("jwt_auth" = [])
+        ("jwt_auth" = []),
+        ("api_key" = ["dashboard:read"])
     ),
     tag = "dashboard"
 )]
@@ -206,7 +209,8 @@ async fn get_filters_admin(
         (status = 500, description = "Internal Server Error", body = StatusResponse)
     ),
     security(
-        ("jwt_auth" = [])
+        ("jwt_auth" = []),
+        ("api_key" = ["dashboard:read"])
     ),
     tag = "dashboard"
 )]
@@ -241,7 +245,8 @@ async fn get_summary(
         (status = 500, description = "Internal Server Error", body = StatusResponse)
     ),
     security(
-        ("jwt_auth" = [])
+        ("jwt_auth" = []),
+        ("api_key" = ["dashboard:read"])
     ),
     tag = "dashboard"
 )]
@@ -278,7 +283,8 @@ async fn get_summary_counts(
         (status = 500, description = "Internal Server Error", body = StatusResponse)
     ),
     security(
-        ("jwt_auth" = [])
+        ("jwt_auth" = []),
+        ("api_key" = ["dashboard:read"])
     ),
     tag = "dashboard"
 )]
@@ -314,7 +320,8 @@ async fn get_summary_compare(
         (status = 500, description = "Internal Server Error", body = StatusResponse)
     ),
     security(
-        ("jwt_auth" = [])
+        ("jwt_auth" = []),
+        ("api_key" = ["dashboard:read"])
     ),
     tag = "dashboard"
 )]
@@ -353,7 +360,8 @@ async fn get_summary_detailed_plot_default(
         (status = 500, description = "Internal Server Error", body = StatusResponse)
     ),
     security(
-        ("jwt_auth" = [])
+        ("jwt_auth" = []),
+        ("api_key" = ["dashboard:read"])
     ),
     tag = "dashboard"
 )]
@@ -0,0 +1,277 @@
+use crate::adapters::web::middleware::auth::{AuthUser, RequiredScope};
+use crate::adapters::web::models::diagnostics::inference_job::{
+    CreateInferenceJobRequest, InferenceJobQuery, InferenceJobResponse,
+};
+use crate::adapters::web::state::AppState;
+use axum::{
+    extract::{Multipart, Path, Query, State},
+    http::StatusCode,
+    response::{
+        sse::{Event, KeepAlive, Sse},
+        IntoResponse,
+    },
+    routing::{get, post},
+    Extension, Json, Router,
+};
+use chrono::{DateTime, Utc};
+use spl_application::dtos::diagnostics::UploadedFileDto;
+use spl_domain::entities::auth::api_key::scopes;
+use spl_domain::entities::diagnostics::{InferenceJob, JobStatus};
+use spl_domain::entities::user::User;
+use spl_shared::error::{AppError, Result};
+use spl_shared::http::extractor::multipart::extract_file;
+use spl_shared::http::responses::StatusResponse;
+use std::convert::Infallible;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::broadcast::{self, error::RecvError};
+use tokio::time::Instant;
+use utoipa::OpenApi;
+use uuid::Uuid;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(create_job, get_jobs, get_job, get_job_events, retry_job),
+    components(schemas(CreateInferenceJobRequest, InferenceJobResponse, StatusResponse)),
+    tags((name = "diagnostics/predictions/jobs", description = "Images predicted by background workers"))
+)]
+pub struct InferenceJobApi;
+
+pub fn router(state: Arc<AppState>) -> Router<Arc<AppState>> {
+    let read_router = Router::new()
+        .route("/diagnostics/predictions/jobs", get(get_jobs))
+        .route("/diagnostics/predictions/jobs/{id}", get(get_job))
+        .route(
+            "/diagnostics/predictions/jobs/{id}/events",
+            get(get_job_events),
+        )
+        .route_layer(Extension(RequiredScope(scopes::PREDICTIONS_READ)));
+
+    let write_router = Router::new()
+        .route("/diagnostics/predictions/jobs", post(create_job))
+        .route("/diagnostics/predictions/jobs/{id}/retry", post(retry_job))
+        .route_layer(Extension(RequiredScope(scopes::PREDICTIONS_WRITE)));
+
+    read_router.merge(write_router).with_state(state)
+}
+
+#[utoipa::path(
+    post,
+    path = "/diagnostics/predictions/jobs",
+    request_body(content = CreateInferenceJobRequest, content_type = "multipart/form-data"),
+    responses(
+        (status = 202, description = "Image stored, it is predicted by the workers", body = InferenceJobResponse),
+        (status = 400, description = "Invalid input", body = StatusResponse),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    security(("jwt_auth" = []), ("api_key" = ["predictions:write"])),
+    tag = "diagnostics/predictions/jobs"
+)]
+async fn create_job(
+    State(state): State<Arc<AppState>>,
+    AuthUser(user): AuthUser,
+    mut multipart: Multipart,
+) -> Result<impl IntoResponse> {
+    let (content, filename) = extract_file("file", &mut multipart).await?;
+
+    let job = state
+        .inference_job_service
+        .enqueue(&user, UploadedFileDto { filename, content })
+        .await?;
+
+    Ok((StatusCode::ACCEPTED, Json(InferenceJobResponse::from(job))))
+}
+
+#[utoipa::path(
+    get,
+    path = "/diagnostics/predictions/jobs",
+    params(InferenceJobQuery),
+    responses(
+        (status = 200, description = "Jobs of the user, most recent first", body = Vec<InferenceJobResponse>),
+        (status = 400, description = "Unknown status", body = StatusResponse),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    security(("jwt_auth" = []), ("api_key" = ["predictions:read"])),
+    tag = "diagnostics/predictions/jobs"
+)]
+async fn get_jobs(
+    State(state): State<Arc<AppState>>,
+    AuthUser(user): AuthUser,
+    Query(query): Query<InferenceJobQuery>,
+) -> Result<impl IntoResponse> {
+    let status = query
+        .status
+        .map(|value| {
+            JobStatus::parse(&value)
+                .ok_or_else(|| AppError::ValidationError(format!("Unknown job status: {}", value)))
+        })
+        .transpose()?;
+
+    let jobs = state
+        .inference_job_service
+        .get_by_user(&user, status)
+        .await?;
+
+    Ok(Json(
+        jobs.into_iter()
+            .map(InferenceJobResponse::from)
+            .collect::<Vec<_>>(),
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "/diagnostics/predictions/jobs/{id}",
+    params(("id" = Uuid, Path, description = "Job ID")),
+    responses(
+        (status = 200, description = "Current state of the job", body = InferenceJobResponse),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 404, description = "Job not found", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    security(("jwt_auth" = []), ("api_key" = ["predictions:read"])),
+    tag = "diagnostics/predictions/jobs"
+)]
+async fn get_job(
+    State(state): State<Arc<AppState>>,
+    AuthUser(user): AuthUser,
+    Path(id): Path<Uuid>,
+) -> Result<impl IntoResponse> {
+    let job = state.inference_job_service.get(&user, id).await?;
+
+    Ok(Json(InferenceJobResponse::from(job)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/diagnostics/predictions/jobs/{id}/events",
+    params(("id" = Uuid, Path, description = "Job ID")),
+    responses(
+        (status = 200, description = "Server-sent `job` events with the state of the job, from the current one until it finishes", content_type = "text/event-stream", body = InferenceJobResponse),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 404, description = "Job not found", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    security(("jwt_auth" = []), ("api_key" = ["predictions:read"])),
+    tag = "diagnostics/predictions/jobs"
+)]
+async fn get_job_events(
+    State(state): State<Arc<AppState>>,
+    AuthUser(user): AuthUser,
+    Path(id): Path<Uuid>,
+) -> Result<impl IntoResponse> {
+    // Subscribed first so no change is missed between both calls
+    let events = state.inference_job_service.subscribe();
+    let job = state.inference_job_service.get(&user, id).await?;
+
+    let poll_interval = state
+        .config
+        .inference_jobs
+        .clone()
+        .unwrap_or_default()
+        .poll_interval_seconds();
+
+    let watch = JobWatch {
+        state,
+        user,
+        id,
+        events,
+        poll_interval: Duration::from_secs(poll_interval),
+        current: Some(job),
+        last_update: None,
+        finished: false,
+    };
+
+    // The stream ends with the event of the finished job
+    let stream = futures::stream::unfold(watch, |mut watch| async move {
+        if watch.finished {
+            return None;
+        }
+
+        let job = match watch.current.take() {
+            Some(job) => job,
+            None => watch.changed().await?,
+        };
+
+        watch.last_update = Some(job.updated_at);
+        watch.finished = job.is_finished();
+
+        let event = Event::default()
+            .event("job")
+            .json_data(InferenceJobResponse::from(job))
+            .ok()?;
+
+        Some((Ok::<_, Infallible>(event), watch))
+    });
+
+    Ok(Sse::new(stream).keep_alive(KeepAlive::default()))
+}
+
+#[utoipa::path(
+    post,
+    path = "/diagnostics/predictions/jobs/{id}/retry",
+    params(("id" = Uuid, Path, description = "Job ID")),
+    responses(
+        (status = 200, description = "Job queued again with a fresh set of attempts", body = InferenceJobResponse),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 404, description = "Job not found", body = StatusResponse),
+        (status = 409, description = "Only dead jobs can be retried", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    security(("jwt_auth" = []), ("api_key" = ["predictions:write"])),
+    tag = "diagnostics/predictions/jobs"
+)]
+async fn retry_job(
+    State(state): State<Arc<AppState>>,
+    AuthUser(user): AuthUser,
+    Path(id): Path<Uuid>,
+) -> Result<impl IntoResponse> {
+    let job = state.inference_job_service.retry(&user, id).await?;
+
+    Ok(Json(InferenceJobResponse::from(job)))
+}
+
+/// Follows a job for a client. Changes made by this server arrive as events; the
+/// job is read again every `poll_interval` for those made by other servers.
+struct JobWatch {
+    state: Arc<AppState>,
+    user: User,
+    id: Uuid,
+    events: broadcast::Receiver<InferenceJob>,
+    poll_interval: Duration,
+    /// State to send before waiting for changes
+    current: Option<InferenceJob>,
+    last_update: Option<DateTime<Utc>>,
+    finished: bool,
+}
+
+impl JobWatch {
+    /// Waits for the next state of the job, None once it can no longer be read
+    async fn changed(&mut self) -> Option<InferenceJob> {
+        loop {
+            let deadline = Instant::now() + self.poll_interval;
+            loop {
+                match tokio::time::timeout_at(deadline, self.events.recv()).await {
+                    Ok(Ok(job)) if job.id == self.id => return Some(job),
+                    Ok(Ok(_)) => continue,
+                    Ok(Err(RecvError::Closed)) => return None,
+                    // Missed events are made up for by reading the job
+                    Ok(Err(RecvError::Lagged(_))) | Err(_) => break,
+                }
+            }
+
+            let job = self
+                .state
+                .inference_job_service
+                .get(&self.user, self.id)
+                .await
+                .ok()?;
+
+            if Some(job.updated_at) != self.last_update {
+                return Some(job);
+            }
+        }
+    }
+}
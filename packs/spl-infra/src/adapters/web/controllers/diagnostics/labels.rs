@@ -1,3 +1,4 @@
+use spl_domain::entities::user::{permissions, PermissionScope};
 use spl_shared::error::Result;
 use spl_shared::http::extractor::ValidatedJson;
 
@@ -21,9 +22,7 @@ use axum::{
     Extension, Json, Router,
 };
 
-use crate::adapters::web::middleware::permissions::{
-    permission_check, RequiredRoles, RoleValidation,
-};
+use crate::adapters::web::middleware::permissions::{permission_check, RequiredPermission};
 use axum::routing::{post, put};
 use serde::{Deserialize, Serialize};
 use spl_shared::http::responses::{ok_if_or_not_found, ok_iter_if_or_not_found, StatusResponse};
@@ -54,9 +53,9 @@ enum LabelOrSimplifiedResponse {
 
 pub fn router(state: Arc<AppState>) -> Router<Arc<AppState>> {
     let admin_only_layer = middleware::from_fn_with_state(state.clone(), permission_check);
-    let admin_extension_roles = Extension(RequiredRoles(
-        vec!["admin".to_string()],
-        RoleValidation::Higher,
+    let admin_extension_permission = Extension(RequiredPermission(
+        permissions::CATALOG_MANAGE,
+        PermissionScope::Any,
     ));
 
     let admin_router = Router::new()
@@ -66,7 +65,7 @@ pub fn router(state: Arc<AppState>) -> Router<Arc<AppState>> {
             put(update_label).delete(delete_label),
         )
         .route_layer(admin_only_layer)
-        .route_layer(admin_extension_roles)
+        .route_layer(admin_extension_permission)
         .with_state(state.clone());
 
     let public_router = Router::new()
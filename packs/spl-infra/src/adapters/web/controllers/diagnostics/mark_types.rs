@@ -1,10 +1,11 @@
+use spl_domain::entities::user::{permissions, PermissionScope};
 use spl_shared::error::Result;
 use spl_shared::http::extractor::ValidatedJson;
 
 use crate::adapters::web::{
     middleware::{
         auth::AuthUser,
-        permissions::{permission_check, RequiredRoles, RoleValidation},
+        permissions::{permission_check, RequiredPermission},
     },
     models::{
         common::SimplifiedQuery,
@@ -55,9 +56,9 @@ pub struct MarkTypesApi;
 
 pub fn router(state: Arc<AppState>) -> Router<Arc<AppState>> {
     let admin_only_layer = middleware::from_fn_with_state(state.clone(), permission_check);
-    let admin_extension_roles = Extension(RequiredRoles(
-        vec!["admin".to_string()],
-        RoleValidation::Higher,
+    let admin_extension_permission = Extension(RequiredPermission(
+        permissions::CATALOG_MANAGE,
+        PermissionScope::Any,
     ));
 
     let admin_router = Router::new()
@@ -67,7 +68,7 @@ pub fn router(state: Arc<AppState>) -> Router<Arc<AppState>> {
             put(update_mark_type).delete(delete_mark_type),
         )
         .route_layer(admin_only_layer)
-        .route_layer(admin_extension_roles)
+        .route_layer(admin_extension_permission)
         .with_state(state.clone());
 
     let public_router = Router::new()
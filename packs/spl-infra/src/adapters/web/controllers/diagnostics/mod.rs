@@ -1,3 +1,5 @@
+pub mod inference_job;
 pub mod labels;
 pub mod mark_types;
 pub mod prediction;
+pub mod prediction_batch;
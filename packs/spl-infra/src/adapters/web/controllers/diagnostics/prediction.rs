@@ -1,5 +1,5 @@
 use crate::adapters::web::mappers::diagnostics::prediction::FilterPredictionMapperContext;
-use crate::adapters::web::middleware::auth::AuthUser;
+use crate::adapters::web::middleware::auth::{AuthUser, RequiredScope};
 use crate::adapters::web::models::diagnostics::label::RawLabelResponse;
 use crate::adapters::web::models::diagnostics::prediction_mark::RawPredictionMarkResponse;
 use crate::adapters::web::models::diagnostics::{
@@ -10,23 +10,26 @@ use crate::adapters::web::models::{
     common::SimplifiedQuery,
     diagnostics::{
         prediction::{
-            CreatePredictionRequest, FilterPredictionsRequest, PredictionResponse,
-            PredictionsListResponse, SimplifiedPredictionResponse,
+            CreatePredictionRequest, FilterPredictionsRequest, PredictionModelResponse,
+            PredictionResponse, PredictionsListResponse, SimplifiedPredictionResponse,
         },
         prediction_mark::PredictionMarkResponse,
         LabelResponse, MarkTypeResponse,
     },
 };
+use crate::adapters::web::controllers::usage::QUOTA_STATUS_HEADER;
 use crate::adapters::web::state::AppState;
 use axum::{
     extract::{Multipart, Path, Query, State},
-    http::{HeaderMap, StatusCode},
+    http::{HeaderMap, HeaderValue, StatusCode},
     middleware,
     response::IntoResponse,
-    routing::{get, post},
+    routing::{delete, get, post},
     Extension, Json, Router,
 };
 use serde::{Deserialize, Serialize};
+use spl_domain::entities::auth::api_key::scopes;
+use spl_domain::entities::usage::UsageStatus;
 use spl_shared::error::AppError;
 use spl_shared::error::Result;
 use spl_shared::http::extractor::multipart::extract_file;
@@ -42,6 +45,7 @@ use uuid::Uuid;
 
 #[derive(Debug, Serialize, ToSchema, Clone, Deserialize)]
 #[serde(untagged)]
+#[allow(clippy::large_enum_variant)]
 enum PredictionOrSimplifiedResponse {
     Prediction(PredictionResponse),
     Simplified(SimplifiedPredictionResponse),
@@ -49,6 +53,7 @@ enum PredictionOrSimplifiedResponse {
 
 #[derive(Debug, Serialize, ToSchema, Clone, Deserialize)]
 #[serde(untagged)]
+#[allow(clippy::large_enum_variant)]
 enum PredictionDetailedOrSimplifiedResponse {
     Prediction(PredictionDetailedResponse),
     Simplified(SimplifiedPredictionDetailedResponse),
@@ -60,6 +65,7 @@ enum PredictionDetailedOrSimplifiedResponse {
     components(schemas(
         CreatePredictionRequest,
         PredictionResponse,
+        PredictionModelResponse,
         PredictionMarkResponse,
         FilterPredictionsRequest,
         PredictionsListResponse,
@@ -102,21 +108,25 @@ pub fn router(state: Arc<AppState>, limit_state: Arc<RateLimitState>) -> Router<
         )
     }
 
-    router
-        .route(
-            "/diagnostics/predictions",
-            post(create_prediction).get(get_all_by_user_id),
-        )
+    let read_router = Router::new()
+        .route("/diagnostics/predictions", get(get_all_by_user_id))
         .route("/diagnostics/predictions/filter", post(filter))
-        .route(
-            "/diagnostics/predictions/{id}",
-            get(get_prediction_by_id).delete(delete_prediction),
-        )
+        .route("/diagnostics/predictions/{id}", get(get_prediction_by_id))
         .route(
             "/diagnostics/predictions/{id}/recommendations",
             get(get_prediction_with_recommendations),
         )
         .route("/diagnostics/predictions/blobs/{*path}", get(read_blob))
+        .route_layer(Extension(RequiredScope(scopes::PREDICTIONS_READ)));
+
+    let write_router = Router::new()
+        .route("/diagnostics/predictions", post(create_prediction))
+        .route("/diagnostics/predictions/{id}", delete(delete_prediction))
+        .route_layer(Extension(RequiredScope(scopes::PREDICTIONS_WRITE)));
+
+    router
+        .merge(read_router)
+        .merge(write_router)
         .with_state(state)
 }
 
@@ -154,12 +164,13 @@ async fn predict(
     path = "/diagnostics/predictions",
     request_body(content = CreatePredictionRequest, content_type = "multipart/form-data"),
     responses(
-        (status = 201, description = "Prediction created", body = PredictionResponse),
+        (status = 201, description = "Prediction created, with `X-Quota-Status` when the quota is close to or past its limits", body = PredictionResponse),
         (status = 400, description = "Invalid input", body = StatusResponse),
         (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 402, description = "Monthly quota exhausted", body = StatusResponse),
         (status = 500, description = "Internal Server Error", body = StatusResponse)
     ),
-    security(("jwt_auth" = [])),
+    security(("jwt_auth" = []), ("api_key" = ["predictions:write"])),
     tag = "diagnostics/predictions"
 )]
 async fn create_prediction(
@@ -172,16 +183,33 @@ async fn create_prediction(
     let prediction = state
         .prediction_service
         .predict_and_create(
-            user.id,
+            user,
             bytes,
             filename.unwrap_or(Uuid::new_v4().to_string()),
         )
         .await?;
 
-    Ok((
+    // The prediction is already stored, so the quota status is only a hint
+    let status = state
+        .usage_service
+        .status_for(&prediction.user)
+        .await
+        .unwrap_or(UsageStatus::Ok);
+
+    let mut response = (
         StatusCode::CREATED,
         Json(PredictionResponse::from(prediction)),
-    ))
+    )
+        .into_response();
+
+    if status != UsageStatus::Ok {
+        response.headers_mut().insert(
+            QUOTA_STATUS_HEADER,
+            HeaderValue::from_static(status.as_str()),
+        );
+    }
+
+    Ok(response)
 }
 
 #[utoipa::path(
@@ -196,7 +224,7 @@ async fn create_prediction(
         (status = 404, description = "No predictions found", body = StatusResponse),
         (status = 500, description = "Internal Server Error", body = StatusResponse)
     ),
-    security(("jwt_auth" = [])),
+    security(("jwt_auth" = []), ("api_key" = ["predictions:read"])),
     tag = "diagnostics/predictions"
 )]
 async fn get_all_by_user_id(
@@ -204,7 +232,7 @@ async fn get_all_by_user_id(
     Query(query): Query<SimplifiedQuery>,
     AuthUser(user): AuthUser,
 ) -> Result<impl IntoResponse> {
-    let predictions = state.prediction_service.get_by_user_id(user.id).await?;
+    let predictions = state.prediction_service.get_by_user(&user).await?;
 
     ok_iter_if_or_not_found(
         predictions,
@@ -228,7 +256,7 @@ async fn get_all_by_user_id(
         (status = 401, description = "Unauthorized", body = StatusResponse),
         (status = 500, description = "Internal Server Error", body = StatusResponse)
     ),
-    security(("jwt_auth" = [])),
+    security(("jwt_auth" = []), ("api_key" = ["predictions:read"])),
     tag = "diagnostics/predictions"
 )]
 async fn get_prediction_by_id(
@@ -239,7 +267,7 @@ async fn get_prediction_by_id(
 ) -> Result<impl IntoResponse> {
     let result = state
         .prediction_service
-        .get_by_user_id_and_id(user.id, id)
+        .get_by_user_and_id(&user, id)
         .await?;
 
     ok_if_or_not_found(
@@ -261,7 +289,7 @@ async fn get_prediction_by_id(
         (status = 401, description = "Unauthorized", body = StatusResponse),
         (status = 500, description = "Internal Server Error", body = StatusResponse)
     ),
-    security(("jwt_auth" = [])),
+    security(("jwt_auth" = []), ("api_key" = ["predictions:write"])),
     tag = "diagnostics/predictions"
 )]
 async fn delete_prediction(
@@ -269,7 +297,7 @@ async fn delete_prediction(
     AuthUser(user): AuthUser,
     Path(id): Path<Uuid>,
 ) -> Result<impl IntoResponse> {
-    let _ = state.prediction_service.delete(user.id, id).await?;
+    let _ = state.prediction_service.delete(&user, id).await?;
     Ok((
         StatusCode::OK,
         Json(StatusResponse {
@@ -289,7 +317,7 @@ async fn delete_prediction(
         (status = 401, description = "Unauthorized", body = StatusResponse),
         (status = 500, description = "Internal Server Error", body = StatusResponse)
     ),
-    security(("jwt_auth" = [])),
+    security(("jwt_auth" = []), ("api_key" = ["predictions:read"])),
     tag = "diagnostics/predictions"
 )]
 async fn filter(
@@ -301,21 +329,13 @@ async fn filter(
         requester: user.clone(),
     };
 
-    let dto = payload.clone().into_with_context(context)?;
+    let dto = payload.into_with_context(context)?;
 
-    let (total, items) = state.prediction_service.filter(dto, &user).await?;
-
-    let limit = payload.limit.unwrap_or(16);
-    let page = payload.page.unwrap_or(1);
+    let predictions = state.prediction_service.filter(dto, &user).await?;
 
     Ok((
         StatusCode::OK,
-        Json(PredictionsListResponse {
-            total,
-            page,
-            limit,
-            items: items.into_iter().map(|p| p.into()).collect(),
-        }),
+        Json(PredictionsListResponse::from(predictions)),
     ))
 }
 
@@ -330,7 +350,7 @@ async fn filter(
         (status = 403, description = "Forbidden", body = StatusResponse),
         (status = 500, description = "Internal Server Error", body = StatusResponse)
     ),
-    security(("jwt_auth" = [])),
+    security(("jwt_auth" = []), ("api_key" = ["predictions:read"])),
     tag = "diagnostics/predictions"
 )]
 async fn read_blob(
@@ -369,7 +389,7 @@ async fn read_blob(
         (status = 401, description = "Unauthorized", body = StatusResponse),
         (status = 500, description = "Internal Server Error", body = StatusResponse)
     ),
-    security(("jwt_auth" = [])),
+    security(("jwt_auth" = []), ("api_key" = ["predictions:read"])),
     tag = "diagnostics/predictions"
 )]
 async fn get_prediction_with_recommendations(
@@ -380,7 +400,7 @@ async fn get_prediction_with_recommendations(
 ) -> Result<impl IntoResponse> {
     let result = state
         .prediction_service
-        .get_detailed_by_user_id_and_id(user.id, id)
+        .get_detailed_by_user_and_id(&user, id)
         .await?;
 
     ok_if_or_not_found(
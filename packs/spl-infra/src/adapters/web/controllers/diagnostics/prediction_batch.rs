@@ -0,0 +1,142 @@
+use crate::adapters::web::middleware::auth::{AuthUser, RequiredScope};
+use crate::adapters::web::models::diagnostics::prediction_batch::{
+    CreatePredictionBatchRequest, PredictionBatchDetailResponse, PredictionBatchItemResponse,
+    PredictionBatchResponse,
+};
+use crate::adapters::web::state::AppState;
+use axum::{
+    extract::{DefaultBodyLimit, Multipart, Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    routing::{get, post},
+    Extension, Json, Router,
+};
+use spl_application::dtos::diagnostics::UploadedFileDto;
+use spl_domain::entities::auth::api_key::scopes;
+use spl_shared::error::Result;
+use spl_shared::http::extractor::multipart::extract_files;
+use spl_shared::http::responses::StatusResponse;
+use std::sync::Arc;
+use utoipa::OpenApi;
+use uuid::Uuid;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(create_batch, get_batches, get_batch),
+    components(schemas(
+        CreatePredictionBatchRequest,
+        PredictionBatchResponse,
+        PredictionBatchItemResponse,
+        PredictionBatchDetailResponse,
+        StatusResponse
+    )),
+    tags((name = "diagnostics/predictions/batches", description = "Many images predicted in the background"))
+)]
+pub struct PredictionBatchApi;
+
+pub fn router(state: Arc<AppState>) -> Router<Arc<AppState>> {
+    let max_upload_bytes = state
+        .config
+        .batch_uploads
+        .clone()
+        .unwrap_or_default()
+        .max_upload_bytes();
+
+    let read_router = Router::new()
+        .route("/diagnostics/predictions/batches", get(get_batches))
+        .route("/diagnostics/predictions/batches/{id}", get(get_batch))
+        .route_layer(Extension(RequiredScope(scopes::PREDICTIONS_READ)));
+
+    let write_router = Router::new()
+        .route(
+            "/diagnostics/predictions/batches",
+            post(create_batch).layer(DefaultBodyLimit::max(max_upload_bytes)),
+        )
+        .route_layer(Extension(RequiredScope(scopes::PREDICTIONS_WRITE)));
+
+    read_router.merge(write_router).with_state(state)
+}
+
+#[utoipa::path(
+    post,
+    path = "/diagnostics/predictions/batches",
+    request_body(content = CreatePredictionBatchRequest, content_type = "multipart/form-data"),
+    responses(
+        (status = 202, description = "Images stored, they are predicted in the background", body = PredictionBatchResponse),
+        (status = 400, description = "No files, too many of them or an invalid archive", body = StatusResponse),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 413, description = "Upload larger than allowed"),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    security(("jwt_auth" = []), ("api_key" = ["predictions:write"])),
+    tag = "diagnostics/predictions/batches"
+)]
+async fn create_batch(
+    State(state): State<Arc<AppState>>,
+    AuthUser(user): AuthUser,
+    mut multipart: Multipart,
+) -> Result<impl IntoResponse> {
+    let files = extract_files("file", &mut multipart)
+        .await?
+        .into_iter()
+        .map(|(content, filename)| UploadedFileDto { filename, content })
+        .collect();
+
+    let batch = state.prediction_batch_service.create(&user, files).await?;
+
+    Ok((
+        StatusCode::ACCEPTED,
+        Json(PredictionBatchResponse::from(batch)),
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "/diagnostics/predictions/batches",
+    responses(
+        (status = 200, description = "Batches of the user, most recent first", body = Vec<PredictionBatchResponse>),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    security(("jwt_auth" = []), ("api_key" = ["predictions:read"])),
+    tag = "diagnostics/predictions/batches"
+)]
+async fn get_batches(
+    State(state): State<Arc<AppState>>,
+    AuthUser(user): AuthUser,
+) -> Result<impl IntoResponse> {
+    let batches = state.prediction_batch_service.get_by_user(&user).await?;
+
+    Ok(Json(
+        batches
+            .into_iter()
+            .map(PredictionBatchResponse::from)
+            .collect::<Vec<_>>(),
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "/diagnostics/predictions/batches/{id}",
+    params(("id" = Uuid, Path, description = "Batch ID")),
+    responses(
+        (status = 200, description = "Progress of the batch with the outcome of each image", body = PredictionBatchDetailResponse),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 404, description = "Batch not found", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    security(("jwt_auth" = []), ("api_key" = ["predictions:read"])),
+    tag = "diagnostics/predictions/batches"
+)]
+async fn get_batch(
+    State(state): State<Arc<AppState>>,
+    AuthUser(user): AuthUser,
+    Path(id): Path<Uuid>,
+) -> Result<impl IntoResponse> {
+    let batch = state
+        .prediction_batch_service
+        .get_detailed(&user, id)
+        .await?;
+
+    Ok(Json(PredictionBatchDetailResponse::from(batch)))
+}
@@ -1,7 +1,5 @@
 use crate::adapters::web::middleware::auth::AuthUser;
-use crate::adapters::web::middleware::permissions::{
-    permission_check, RequiredRoles, RoleValidation,
-};
+use crate::adapters::web::middleware::permissions::{permission_check, RequiredPermission};
 use crate::adapters::web::models::common::SimplifiedQuery;
 use crate::adapters::web::models::feedback::status::{
     CreateFeedbackStatusRequest, FeedbackStatusResponse, SimplifiedFeedbackStatusResponse,
@@ -17,6 +15,7 @@ use axum::{
     routing::{get, post, put},
     Extension, Json, Router,
 };
+use spl_domain::entities::user::{permissions, PermissionScope};
 use spl_shared::error::Result;
 use spl_shared::http::extractor::ValidatedJson;
 use spl_shared::http::responses::{ok_if_or_not_found, ok_iter_if_or_not_found, StatusResponse};
@@ -40,16 +39,16 @@ pub struct FeedbackStatusApi;
 
 pub fn router(state: Arc<AppState>) -> Router<Arc<AppState>> {
     let admin_only_layer = middleware::from_fn_with_state(state.clone(), permission_check);
-    let admin_extension_roles = Extension(RequiredRoles(
-        vec!["admin".to_string()],
-        RoleValidation::Higher,
+    let admin_extension_permission = Extension(RequiredPermission(
+        permissions::CATALOG_MANAGE,
+        PermissionScope::Any,
     ));
 
     let admin_router = Router::new()
         .route("/feedback/statuses", post(create_feedback_status))
         .route("/feedback/statuses/{id}", put(update_feedback_status).delete(delete_status))
         .route_layer(admin_only_layer)
-        .route_layer(admin_extension_roles)
+        .route_layer(admin_extension_permission)
         .with_state(state.clone());
 
     let authenticated_router = Router::new()
@@ -0,0 +1,194 @@
+use crate::adapters::web::middleware::auth::{AccountOwner, AuthUser, ClientInfo, Impersonator};
+use crate::adapters::web::middleware::permissions::{permission_check, RequiredPermission};
+use crate::adapters::web::models::impersonation::{
+    ImpersonationActionResponse, ImpersonationAuditResponse, ImpersonationQuery,
+    ImpersonationResponse, ImpersonationTokenResponse, StartImpersonationRequest,
+};
+use crate::adapters::web::state::AppState;
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{delete, get, post},
+    Extension, Json, Router,
+};
+use spl_domain::entities::user::{permissions, PermissionScope};
+use spl_shared::error::{AppError, Result};
+use spl_shared::http::extractor::ValidatedJson;
+use spl_shared::http::responses::StatusResponse;
+use std::sync::Arc;
+use utoipa::OpenApi;
+use uuid::Uuid;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(start_impersonation, end_impersonation, get_impersonations, get_impersonation),
+    components(schemas(
+        StartImpersonationRequest,
+        ImpersonationResponse,
+        ImpersonationTokenResponse,
+        ImpersonationActionResponse,
+        ImpersonationAuditResponse,
+        StatusResponse
+    )),
+    tags((name = "impersonations", description = "Administrators acting as other users, with every action recorded")),
+    security(("jwt_auth" = []))
+)]
+pub struct ImpersonationsApi;
+
+pub fn router(state: Arc<AppState>) -> Router<Arc<AppState>> {
+    let admin_only_layer = middleware::from_fn_with_state(state.clone(), permission_check);
+    let admin_extension_permission = Extension(RequiredPermission(
+        permissions::USERS_IMPERSONATE,
+        PermissionScope::Any,
+    ));
+
+    let management = Router::new()
+        .route("/auth/impersonate/{user_id}", post(start_impersonation))
+        .route("/impersonations", get(get_impersonations))
+        .route("/impersonations/{id}", get(get_impersonation))
+        .route_layer(admin_only_layer)
+        .route_layer(admin_extension_permission);
+
+    Router::new()
+        .route("/auth/impersonate", delete(end_impersonation))
+        .merge(management)
+        .with_state(state)
+}
+
+#[utoipa::path(
+    post,
+    path = "/auth/impersonate/{user_id}",
+    params(
+        ("user_id" = Uuid, Path, description = "User to impersonate")
+    ),
+    request_body = StartImpersonationRequest,
+    responses(
+        (status = 201, description = "Access token acting as the user, requests made with it are recorded", body = ImpersonationTokenResponse),
+        (status = 400, description = "Invalid input, own or deactivated user", body = StatusResponse),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 403, description = "Forbidden - Admin access required, or the user has the same or higher privileges", body = StatusResponse),
+        (status = 404, description = "User not found", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    security(
+        ("jwt_auth" = [])
+    ),
+    tag = "impersonations"
+)]
+async fn start_impersonation(
+    State(state): State<Arc<AppState>>,
+    Path(user_id): Path<Uuid>,
+    AccountOwner(user): AccountOwner,
+    ClientInfo(client): ClientInfo,
+    ValidatedJson(payload): ValidatedJson<StartImpersonationRequest>,
+) -> Result<impl IntoResponse> {
+    let token = state
+        .impersonation_service
+        .start(&user, user_id, payload.reason, client)
+        .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ImpersonationTokenResponse::from(token)),
+    ))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/auth/impersonate",
+    responses(
+        (status = 200, description = "Impersonation ended, its token no longer works", body = StatusResponse),
+        (status = 400, description = "The token is not an impersonation token", body = StatusResponse),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    security(
+        ("jwt_auth" = [])
+    ),
+    tag = "impersonations"
+)]
+async fn end_impersonation(
+    State(state): State<Arc<AppState>>,
+    _user: AuthUser,
+    impersonator: Option<Extension<Impersonator>>,
+) -> Result<impl IntoResponse> {
+    let Some(Extension(impersonator)) = impersonator else {
+        return Err(AppError::ValidationError(
+            "The token is not an impersonation token".to_string(),
+        ));
+    };
+
+    state
+        .impersonation_service
+        .end(impersonator.impersonation_id)
+        .await?;
+
+    Ok(Json(StatusResponse {
+        success: true,
+        code: 200,
+        message: "Impersonation ended".to_string(),
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/impersonations",
+    params(ImpersonationQuery),
+    responses(
+        (status = 200, description = "Impersonations the user started or was the subject of, most recent first", body = Vec<ImpersonationResponse>),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 403, description = "Forbidden - Admin access required", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    security(
+        ("jwt_auth" = [])
+    ),
+    tag = "impersonations"
+)]
+async fn get_impersonations(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<ImpersonationQuery>,
+    AuthUser(user): AuthUser,
+) -> Result<impl IntoResponse> {
+    let impersonations = state
+        .impersonation_service
+        .get_by_user(&user, query.user_id)
+        .await?;
+
+    Ok(Json(
+        impersonations
+            .into_iter()
+            .map(ImpersonationResponse::from)
+            .collect::<Vec<_>>(),
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "/impersonations/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Impersonation ID")
+    ),
+    responses(
+        (status = 200, description = "Impersonation with every request made during it", body = ImpersonationAuditResponse),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 403, description = "Forbidden - Admin access required", body = StatusResponse),
+        (status = 404, description = "Impersonation not found", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    security(
+        ("jwt_auth" = [])
+    ),
+    tag = "impersonations"
+)]
+async fn get_impersonation(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+    AuthUser(user): AuthUser,
+) -> Result<impl IntoResponse> {
+    let audit = state.impersonation_service.get_audit(&user, id).await?;
+
+    Ok(Json(ImpersonationAuditResponse::from(audit)))
+}
@@ -0,0 +1,205 @@
+use crate::adapters::web::middleware::auth::AuthUser;
+use crate::adapters::web::middleware::permissions::{permission_check, RequiredPermission};
+use crate::adapters::web::models::invitation::{
+    AcceptInvitationRequest, CreateInvitationRequest, InvitationQuery, InvitationResponse,
+};
+use crate::adapters::web::models::user::UserResponse;
+use crate::adapters::web::state::AppState;
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{delete, get, post},
+    Extension, Json, Router,
+};
+use spl_domain::entities::user::{permissions, PermissionScope};
+use spl_shared::error::Result;
+use spl_shared::http::extractor::ValidatedJson;
+use spl_shared::http::responses::StatusResponse;
+use std::sync::Arc;
+use utoipa::OpenApi;
+use uuid::Uuid;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(create_invitation, get_invitations, resend_invitation, cancel_invitation, accept_invitation),
+    components(schemas(
+        CreateInvitationRequest,
+        AcceptInvitationRequest,
+        InvitationResponse,
+        UserResponse,
+        StatusResponse
+    )),
+    tags((name = "invitations", description = "Email invitations of new users into a company"))
+)]
+pub struct InvitationsApi;
+
+pub fn router(state: Arc<AppState>) -> Router<Arc<AppState>> {
+    let supervisor_layer = middleware::from_fn_with_state(state.clone(), permission_check);
+    let supervisor_extension_permission = Extension(RequiredPermission(
+        permissions::USERS_MANAGE,
+        PermissionScope::Company,
+    ));
+
+    let management = Router::new()
+        .route("/invitations", get(get_invitations).post(create_invitation))
+        .route("/invitations/{id}", delete(cancel_invitation))
+        .route("/invitations/{id}/resend", post(resend_invitation))
+        .route_layer(supervisor_layer)
+        .route_layer(supervisor_extension_permission);
+
+    Router::new()
+        .route("/auth/invitations/accept", post(accept_invitation))
+        .merge(management)
+        .with_state(state)
+}
+
+#[utoipa::path(
+    post,
+    path = "/invitations",
+    request_body = CreateInvitationRequest,
+    responses(
+        (status = 201, description = "Invitation created and emailed", body = InvitationResponse),
+        (status = 400, description = "Invalid input", body = StatusResponse),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 403, description = "Forbidden - Supervisor access required", body = StatusResponse),
+        (status = 409, description = "User or pending invitation already exists", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    security(
+        ("jwt_auth" = [])
+    ),
+    tag = "invitations"
+)]
+async fn create_invitation(
+    State(state): State<Arc<AppState>>,
+    AuthUser(user): AuthUser,
+    ValidatedJson(payload): ValidatedJson<CreateInvitationRequest>,
+) -> Result<impl IntoResponse> {
+    let invitation = state
+        .invitation_service
+        .create(&user, payload.into())
+        .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(InvitationResponse::from(invitation)),
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "/invitations",
+    params(InvitationQuery),
+    responses(
+        (status = 200, description = "Invitations of the company, most recent first", body = Vec<InvitationResponse>),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 403, description = "Forbidden - Supervisor access required", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    security(
+        ("jwt_auth" = [])
+    ),
+    tag = "invitations"
+)]
+async fn get_invitations(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<InvitationQuery>,
+    AuthUser(user): AuthUser,
+) -> Result<impl IntoResponse> {
+    let invitations = state
+        .invitation_service
+        .get_by_company(&user, query.company_id)
+        .await?;
+
+    Ok(Json(
+        invitations
+            .into_iter()
+            .map(InvitationResponse::from)
+            .collect::<Vec<_>>(),
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/invitations/{id}/resend",
+    params(
+        ("id" = Uuid, Path, description = "Invitation ID")
+    ),
+    responses(
+        (status = 200, description = "New link emailed, the previous one no longer works", body = InvitationResponse),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 403, description = "Forbidden - Access denied", body = StatusResponse),
+        (status = 404, description = "Invitation not found", body = StatusResponse),
+        (status = 409, description = "Invitation already accepted or cancelled", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    security(
+        ("jwt_auth" = [])
+    ),
+    tag = "invitations"
+)]
+async fn resend_invitation(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+    AuthUser(user): AuthUser,
+) -> Result<impl IntoResponse> {
+    let invitation = state.invitation_service.resend(&user, id).await?;
+
+    Ok(Json(InvitationResponse::from(invitation)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/invitations/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Invitation ID")
+    ),
+    responses(
+        (status = 200, description = "Invitation cancelled", body = StatusResponse),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 403, description = "Forbidden - Access denied", body = StatusResponse),
+        (status = 404, description = "Invitation not found", body = StatusResponse),
+        (status = 409, description = "Invitation already accepted or cancelled", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    security(
+        ("jwt_auth" = [])
+    ),
+    tag = "invitations"
+)]
+async fn cancel_invitation(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+    AuthUser(user): AuthUser,
+) -> Result<impl IntoResponse> {
+    state.invitation_service.cancel(&user, id).await?;
+
+    Ok(Json(StatusResponse {
+        success: true,
+        code: 200,
+        message: "Invitation cancelled".to_string(),
+    }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/auth/invitations/accept",
+    request_body = AcceptInvitationRequest,
+    responses(
+        (status = 201, description = "Account created", body = UserResponse),
+        (status = 400, description = "Invalid or expired invitation", body = StatusResponse),
+        (status = 409, description = "Username already exists", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    tag = "invitations"
+)]
+async fn accept_invitation(
+    State(state): State<Arc<AppState>>,
+    ValidatedJson(payload): ValidatedJson<AcceptInvitationRequest>,
+) -> Result<impl IntoResponse> {
+    let user = state.invitation_service.accept(payload.into()).await?;
+
+    Ok((StatusCode::CREATED, Json(UserResponse::from(user))))
+}
@@ -0,0 +1,265 @@
+use crate::adapters::web::middleware::auth::{AccountOwner, AuthUser, CurrentSession};
+use crate::adapters::web::middleware::permissions::{permission_check, RequiredPermission};
+use crate::adapters::web::models::auth::TokenResponse;
+use crate::adapters::web::models::membership::{
+    MembershipResponse, SetMembershipRequest, SwitchCompanyRequest, UserMembershipResponse,
+};
+use crate::adapters::web::state::AppState;
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{delete, get, put},
+    Extension, Json, Router,
+};
+use spl_domain::entities::user::{permissions, PermissionScope};
+use spl_shared::error::{AppError, Result};
+use spl_shared::http::extractor::ValidatedJson;
+use spl_shared::http::responses::StatusResponse;
+use spl_shared::traits::IntoWithContext;
+use std::sync::Arc;
+use utoipa::OpenApi;
+use uuid::Uuid;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        get_my_companies,
+        switch_company,
+        leave_company,
+        get_company_memberships,
+        set_membership,
+        remove_membership
+    ),
+    components(schemas(
+        SetMembershipRequest,
+        SwitchCompanyRequest,
+        MembershipResponse,
+        UserMembershipResponse,
+        TokenResponse,
+        StatusResponse
+    )),
+    tags((name = "memberships", description = "Access of users to companies other than their own")),
+    security(("jwt_auth" = []))
+)]
+pub struct MembershipsApi;
+
+pub fn router(state: Arc<AppState>) -> Router<Arc<AppState>> {
+    let permission_layer = middleware::from_fn_with_state(state.clone(), permission_check);
+
+    let read_extension_permission = Extension(RequiredPermission(
+        permissions::USERS_READ,
+        PermissionScope::Company,
+    ));
+
+    let manage_extension_permission = Extension(RequiredPermission(
+        permissions::USERS_MANAGE,
+        PermissionScope::Company,
+    ));
+
+    let read_router = Router::new()
+        .route("/companies/{id}/memberships", get(get_company_memberships))
+        .route_layer(permission_layer.clone())
+        .route_layer(read_extension_permission)
+        .with_state(state.clone());
+
+    let manage_router = Router::new()
+        .route(
+            "/companies/{id}/memberships/{user_id}",
+            put(set_membership).delete(remove_membership),
+        )
+        .route_layer(permission_layer)
+        .route_layer(manage_extension_permission)
+        .with_state(state.clone());
+
+    Router::new()
+        .route("/users/me/companies", get(get_my_companies))
+        .route("/users/me/companies/{company_id}", delete(leave_company))
+        .route("/users/me/company", put(switch_company))
+        .merge(read_router)
+        .merge(manage_router)
+        .with_state(state)
+}
+
+#[utoipa::path(
+    get,
+    path = "/users/me/companies",
+    responses(
+        (status = 200, description = "Companies of the current user, their own company first", body = Vec<UserMembershipResponse>),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    tag = "memberships"
+)]
+async fn get_my_companies(
+    State(state): State<Arc<AppState>>,
+    AuthUser(user): AuthUser,
+) -> Result<impl IntoResponse> {
+    let memberships = state.membership_service.get_by_user(user.id).await?;
+    let active_company_id = user.company.as_ref().map(|c| c.id);
+
+    let responses = memberships
+        .into_iter()
+        .map(|m| m.into_with_context(active_company_id))
+        .collect::<Result<Vec<UserMembershipResponse>>>()?;
+
+    Ok(Json(responses))
+}
+
+#[utoipa::path(
+    put,
+    path = "/users/me/company",
+    request_body = SwitchCompanyRequest,
+    responses(
+        (status = 200, description = "Tokens acting in the company, the session keeps it on refresh", body = TokenResponse),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 403, description = "Tokens without a session or issued by an impersonation", body = StatusResponse),
+        (status = 404, description = "The user has no membership in the company", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    tag = "memberships"
+)]
+async fn switch_company(
+    State(state): State<Arc<AppState>>,
+    AccountOwner(user): AccountOwner,
+    session: Option<Extension<CurrentSession>>,
+    ValidatedJson(payload): ValidatedJson<SwitchCompanyRequest>,
+) -> Result<impl IntoResponse> {
+    let Some(Extension(CurrentSession(session_id))) = session else {
+        return Err(AppError::Forbidden);
+    };
+
+    let tokens = state
+        .auth_service
+        .switch_company(user.id, session_id, payload.company_id)
+        .await?;
+
+    Ok(Json(TokenResponse::from(tokens)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/users/me/companies/{company_id}",
+    params(
+        ("company_id" = Uuid, Path, description = "Company ID")
+    ),
+    responses(
+        (status = 204, description = "Membership ended"),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 404, description = "The user has no membership in the company", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    tag = "memberships"
+)]
+async fn leave_company(
+    State(state): State<Arc<AppState>>,
+    Path(company_id): Path<Uuid>,
+    AccountOwner(user): AccountOwner,
+) -> Result<impl IntoResponse> {
+    state
+        .membership_service
+        .remove(&user, company_id, user.id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    get,
+    path = "/companies/{id}/memberships",
+    params(
+        ("id" = Uuid, Path, description = "Company ID")
+    ),
+    responses(
+        (status = 200, description = "Users of other companies with access to the company", body = Vec<MembershipResponse>),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 403, description = "Forbidden - Access denied", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    security(
+        ("jwt_auth" = [])
+    ),
+    tag = "memberships"
+)]
+async fn get_company_memberships(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+    AuthUser(user): AuthUser,
+) -> Result<impl IntoResponse> {
+    let memberships = state.membership_service.get_by_company(&user, id).await?;
+
+    Ok(Json(
+        memberships
+            .into_iter()
+            .map(MembershipResponse::from)
+            .collect::<Vec<_>>(),
+    ))
+}
+
+#[utoipa::path(
+    put,
+    path = "/companies/{id}/memberships/{user_id}",
+    params(
+        ("id" = Uuid, Path, description = "Company ID"),
+        ("user_id" = Uuid, Path, description = "User ID")
+    ),
+    request_body = SetMembershipRequest,
+    responses(
+        (status = 200, description = "Membership created or its role changed", body = MembershipResponse),
+        (status = 400, description = "Invalid role, or the user already belongs to the company", body = StatusResponse),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 403, description = "Forbidden - Access denied", body = StatusResponse),
+        (status = 404, description = "User not found", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    security(
+        ("jwt_auth" = [])
+    ),
+    tag = "memberships"
+)]
+async fn set_membership(
+    State(state): State<Arc<AppState>>,
+    Path((id, user_id)): Path<(Uuid, Uuid)>,
+    AuthUser(user): AuthUser,
+    ValidatedJson(payload): ValidatedJson<SetMembershipRequest>,
+) -> Result<impl IntoResponse> {
+    let membership = state
+        .membership_service
+        .set(&user, id, user_id, payload.into())
+        .await?;
+
+    Ok(Json(MembershipResponse::from(membership)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/companies/{id}/memberships/{user_id}",
+    params(
+        ("id" = Uuid, Path, description = "Company ID"),
+        ("user_id" = Uuid, Path, description = "User ID")
+    ),
+    responses(
+        (status = 204, description = "Membership removed, its sessions go back to the own company of the user"),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 403, description = "Forbidden - Access denied", body = StatusResponse),
+        (status = 404, description = "Membership not found", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    security(
+        ("jwt_auth" = [])
+    ),
+    tag = "memberships"
+)]
+async fn remove_membership(
+    State(state): State<Arc<AppState>>,
+    Path((id, user_id)): Path<(Uuid, Uuid)>,
+    AuthUser(user): AuthUser,
+) -> Result<impl IntoResponse> {
+    state
+        .membership_service
+        .remove(&user, id, user_id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -1,8 +1,21 @@
 pub mod auth;
 pub mod companies;
+pub mod company_settings;
 pub mod dashboard;
 pub mod diagnostics;
 pub mod feedback;
+pub mod impersonations;
+pub mod invitations;
+pub mod memberships;
+pub mod offboardings;
+pub mod password_policies;
 pub mod plots;
 pub mod recommendation;
+pub mod roles;
+pub mod service_accounts;
+pub mod sessions;
+pub mod sso;
+pub mod teams;
+pub mod usage;
 pub mod user;
+pub mod well_known;
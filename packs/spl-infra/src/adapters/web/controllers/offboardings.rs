@@ -0,0 +1,226 @@
+use crate::adapters::web::middleware::auth::AuthUser;
+use crate::adapters::web::middleware::permissions::{permission_check, RequiredPermission};
+use crate::adapters::web::models::offboarding::{
+    OffboardingAuditResponse, OffboardingEventResponse, OffboardingResponse,
+};
+use crate::adapters::web::state::AppState;
+use axum::{
+    extract::{Path, State},
+    http::{header, StatusCode},
+    middleware,
+    response::IntoResponse,
+    routing::{get, post},
+    Extension, Json, Router,
+};
+use spl_domain::entities::user::{permissions, PermissionScope};
+use spl_shared::error::Result;
+use spl_shared::http::responses::StatusResponse;
+use std::sync::Arc;
+use utoipa::OpenApi;
+use uuid::Uuid;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        start_offboarding,
+        get_company_offboardings,
+        get_offboarding,
+        download_export,
+        confirm_deletion
+    ),
+    components(schemas(
+        OffboardingResponse,
+        OffboardingEventResponse,
+        OffboardingAuditResponse,
+        StatusResponse
+    )),
+    tags((name = "offboardings", description = "Export and removal of companies, with an audit trail")),
+    security(("jwt_auth" = []))
+)]
+pub struct OffboardingsApi;
+
+pub fn router(state: Arc<AppState>) -> Router<Arc<AppState>> {
+    let admin_only_layer = middleware::from_fn_with_state(state.clone(), permission_check);
+    let admin_extension_permission = Extension(RequiredPermission(
+        permissions::COMPANIES_MANAGE,
+        PermissionScope::Any,
+    ));
+
+    Router::new()
+        .route(
+            "/companies/{id}/offboardings",
+            get(get_company_offboardings).post(start_offboarding),
+        )
+        .route("/offboardings/{id}", get(get_offboarding))
+        .route("/offboardings/{id}/export", get(download_export))
+        .route("/offboardings/{id}/deletion", post(confirm_deletion))
+        .route_layer(admin_only_layer)
+        .route_layer(admin_extension_permission)
+        .with_state(state)
+}
+
+#[utoipa::path(
+    post,
+    path = "/companies/{id}/offboardings",
+    params(
+        ("id" = Uuid, Path, description = "Company ID")
+    ),
+    responses(
+        (status = 202, description = "Export of the company started, its data is kept until deletion is confirmed", body = OffboardingResponse),
+        (status = 400, description = "The company is the one of the requester", body = StatusResponse),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 403, description = "Forbidden - Admin access required", body = StatusResponse),
+        (status = 404, description = "Company not found", body = StatusResponse),
+        (status = 409, description = "The company already has an offboarding in progress", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    security(
+        ("jwt_auth" = [])
+    ),
+    tag = "offboardings"
+)]
+async fn start_offboarding(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+    AuthUser(user): AuthUser,
+) -> Result<impl IntoResponse> {
+    let offboarding = state.offboarding_service.start(&user, id).await?;
+
+    Ok((
+        StatusCode::ACCEPTED,
+        Json(OffboardingResponse::from(offboarding)),
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "/companies/{id}/offboardings",
+    params(
+        ("id" = Uuid, Path, description = "Company ID, also of deleted companies")
+    ),
+    responses(
+        (status = 200, description = "Offboardings of the company, most recent first", body = Vec<OffboardingResponse>),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 403, description = "Forbidden - Admin access required", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    security(
+        ("jwt_auth" = [])
+    ),
+    tag = "offboardings"
+)]
+async fn get_company_offboardings(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+    AuthUser(user): AuthUser,
+) -> Result<impl IntoResponse> {
+    let offboardings = state.offboarding_service.get_by_company(&user, id).await?;
+
+    Ok(Json(
+        offboardings
+            .into_iter()
+            .map(OffboardingResponse::from)
+            .collect::<Vec<_>>(),
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "/offboardings/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Offboarding ID")
+    ),
+    responses(
+        (status = 200, description = "Progress of the offboarding with its audit trail", body = OffboardingAuditResponse),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 403, description = "Forbidden - Admin access required", body = StatusResponse),
+        (status = 404, description = "Offboarding not found", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    security(
+        ("jwt_auth" = [])
+    ),
+    tag = "offboardings"
+)]
+async fn get_offboarding(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+    AuthUser(user): AuthUser,
+) -> Result<impl IntoResponse> {
+    let audit = state.offboarding_service.get_audit(&user, id).await?;
+
+    Ok(Json(OffboardingAuditResponse::from(audit)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/offboardings/{id}/export",
+    params(
+        ("id" = Uuid, Path, description = "Offboarding ID")
+    ),
+    responses(
+        (status = 200, description = "Zip archive with the company, its users, plots, predictions and images", content_type = "application/zip"),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 403, description = "Forbidden - Admin access required", body = StatusResponse),
+        (status = 404, description = "Offboarding not found", body = StatusResponse),
+        (status = 409, description = "The export is not ready yet", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    security(
+        ("jwt_auth" = [])
+    ),
+    tag = "offboardings"
+)]
+async fn download_export(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+    AuthUser(user): AuthUser,
+) -> Result<impl IntoResponse> {
+    let export = state.offboarding_service.download_export(&user, id).await?;
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, export.content_type),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{}\"", export.filename),
+            ),
+        ],
+        export.content,
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/offboardings/{id}/deletion",
+    params(
+        ("id" = Uuid, Path, description = "Offboarding ID")
+    ),
+    responses(
+        (status = 202, description = "Deletion of the company started, or resumed after a failure", body = OffboardingResponse),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 403, description = "Forbidden - Admin access required", body = StatusResponse),
+        (status = 404, description = "Offboarding not found", body = StatusResponse),
+        (status = 409, description = "The company was not exported yet, or is already being deleted", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    security(
+        ("jwt_auth" = [])
+    ),
+    tag = "offboardings"
+)]
+async fn confirm_deletion(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+    AuthUser(user): AuthUser,
+) -> Result<impl IntoResponse> {
+    let offboarding = state
+        .offboarding_service
+        .confirm_deletion(&user, id)
+        .await?;
+
+    Ok((
+        StatusCode::ACCEPTED,
+        Json(OffboardingResponse::from(offboarding)),
+    ))
+}
@@ -0,0 +1,147 @@
+use crate::adapters::web::middleware::auth::AuthUser;
+use crate::adapters::web::middleware::permissions::{permission_check, RequiredPermission};
+use crate::adapters::web::models::password_policy::{
+    PasswordPolicyRequest, PasswordPolicyResponse,
+};
+use crate::adapters::web::state::AppState;
+use axum::{
+    extract::{Path, State},
+    middleware,
+    response::IntoResponse,
+    routing::get,
+    Extension, Json, Router,
+};
+use spl_domain::entities::user::{permissions, PermissionScope};
+use spl_shared::error::Result;
+use spl_shared::http::extractor::ValidatedJson;
+use spl_shared::http::responses::StatusResponse;
+use std::sync::Arc;
+use utoipa::OpenApi;
+use uuid::Uuid;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(get_password_policy, set_password_policy, delete_password_policy),
+    components(schemas(PasswordPolicyRequest, PasswordPolicyResponse, StatusResponse)),
+    tags((name = "password_policies", description = "Password rules of a company"))
+)]
+pub struct PasswordPoliciesApi;
+
+pub fn router(state: Arc<AppState>) -> Router<Arc<AppState>> {
+    let supervisor_layer = middleware::from_fn_with_state(state.clone(), permission_check);
+    let supervisor_extension_permission = Extension(RequiredPermission(
+        permissions::USERS_MANAGE,
+        PermissionScope::Company,
+    ));
+
+    Router::new()
+        .route(
+            "/companies/{id}/password-policy",
+            get(get_password_policy)
+                .put(set_password_policy)
+                .delete(delete_password_policy),
+        )
+        .route_layer(supervisor_layer)
+        .route_layer(supervisor_extension_permission)
+        .with_state(state)
+}
+
+#[utoipa::path(
+    get,
+    path = "/companies/{id}/password-policy",
+    params(
+        ("id" = Uuid, Path, description = "Company ID")
+    ),
+    responses(
+        (status = 200, description = "Password policy of the company", body = PasswordPolicyResponse),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 403, description = "Forbidden - Access denied", body = StatusResponse),
+        (status = 404, description = "Password policy not found", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    security(
+        ("jwt_auth" = [])
+    ),
+    tag = "password_policies"
+)]
+async fn get_password_policy(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+    AuthUser(user): AuthUser,
+) -> Result<impl IntoResponse> {
+    let policy = state
+        .password_policy_service
+        .get_company_policy(&user, id)
+        .await?;
+
+    Ok(Json(PasswordPolicyResponse::from(policy)))
+}
+
+#[utoipa::path(
+    put,
+    path = "/companies/{id}/password-policy",
+    params(
+        ("id" = Uuid, Path, description = "Company ID")
+    ),
+    request_body = PasswordPolicyRequest,
+    responses(
+        (status = 200, description = "Password policy configured", body = PasswordPolicyResponse),
+        (status = 400, description = "Invalid input", body = StatusResponse),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 403, description = "Forbidden - Access denied", body = StatusResponse),
+        (status = 404, description = "Company not found", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    security(
+        ("jwt_auth" = [])
+    ),
+    tag = "password_policies"
+)]
+async fn set_password_policy(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+    AuthUser(user): AuthUser,
+    ValidatedJson(payload): ValidatedJson<PasswordPolicyRequest>,
+) -> Result<impl IntoResponse> {
+    let policy = state
+        .password_policy_service
+        .set_company_policy(&user, id, payload.into())
+        .await?;
+
+    Ok(Json(PasswordPolicyResponse::from(policy)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/companies/{id}/password-policy",
+    params(
+        ("id" = Uuid, Path, description = "Company ID")
+    ),
+    responses(
+        (status = 200, description = "Password policy removed", body = StatusResponse),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 403, description = "Forbidden - Access denied", body = StatusResponse),
+        (status = 404, description = "Password policy not found", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    security(
+        ("jwt_auth" = [])
+    ),
+    tag = "password_policies"
+)]
+async fn delete_password_policy(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+    AuthUser(user): AuthUser,
+) -> Result<impl IntoResponse> {
+    state
+        .password_policy_service
+        .delete_company_policy(&user, id)
+        .await?;
+
+    Ok(Json(StatusResponse {
+        success: true,
+        code: 200,
+        message: "Password policy removed".to_string(),
+    }))
+}
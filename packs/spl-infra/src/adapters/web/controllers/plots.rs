@@ -1,8 +1,6 @@
 use crate::adapters::web::mappers::plot::CreatePlotContext;
 use crate::adapters::web::middleware::auth::AuthUser;
-use crate::adapters::web::middleware::permissions::{
-    permission_check, RequiredRoles, RoleValidation,
-};
+use crate::adapters::web::middleware::permissions::{permission_check, RequiredPermission};
 use crate::adapters::web::models::{
     common::SimplifiedQuery,
     plot::{
@@ -17,6 +15,7 @@ use axum::http::StatusCode;
 use axum::routing::{get, post, put};
 use axum::{middleware, response::IntoResponse, Extension, Json, Router};
 use serde::{Deserialize, Serialize};
+use spl_domain::entities::user::{permissions, PermissionScope};
 use spl_shared::error::Result;
 use spl_shared::http::extractor::ValidatedJson;
 use spl_shared::http::responses::{
@@ -72,14 +71,14 @@ pub struct PlotsApi;
 pub fn router(state: Arc<AppState>) -> Router<Arc<AppState>> {
     let permission_layer = middleware::from_fn_with_state(state.clone(), permission_check);
 
-    let supervisor_lower_extension_roles = Extension(RequiredRoles(
-        vec!["supervisor".to_string()],
-        RoleValidation::Lower,
+    let read_extension_permission = Extension(RequiredPermission(
+        permissions::PLOTS_READ,
+        PermissionScope::Own,
     ));
 
-    let supervisor_higher_extension_roles = Extension(RequiredRoles(
-        vec!["supervisor".to_string()],
-        RoleValidation::Higher,
+    let manage_extension_permission = Extension(RequiredPermission(
+        permissions::PLOTS_MANAGE,
+        PermissionScope::Company,
     ));
 
     let supervisor_lower_router = Router::new()
@@ -91,14 +90,14 @@ pub fn router(state: Arc<AppState>) -> Router<Arc<AppState>> {
         .route("/plots/detailed/{id}", get(get_detailed_plot))
         .route("/plots/default/detailed", get(get_default_detailed))
         .route_layer(permission_layer.clone())
-        .route_layer(supervisor_lower_extension_roles.clone())
+        .route_layer(read_extension_permission.clone())
         .with_state(state.clone());
 
     let supervisor_only_router = Router::new()
         .route("/plots", post(create_plot))
         .route("/plots/{id}", put(update_plot).delete(delete_plot))
         .route_layer(permission_layer.clone())
-        .route_layer(supervisor_higher_extension_roles.clone())
+        .route_layer(manage_extension_permission.clone())
         .with_state(state);
 
     Router::new()
@@ -2,7 +2,7 @@ use crate::adapters::web::state::AppState;
 use crate::adapters::web::{
     middleware::{
         auth::AuthUser,
-        permissions::{permission_check, RequiredRoles, RoleValidation},
+        permissions::{permission_check, RequiredPermission},
     },
     models::{
         common::SimplifiedQuery,
@@ -21,6 +21,7 @@ use axum::{
     Extension, Json, Router,
 };
 use serde::{Deserialize, Serialize};
+use spl_domain::entities::user::{permissions, PermissionScope};
 use spl_shared::error::Result;
 use spl_shared::http::extractor::ValidatedJson;
 use spl_shared::http::responses::{ok_if_or_not_found, ok_iter_if_or_not_found, StatusResponse};
@@ -52,9 +53,9 @@ pub struct CategoryApi;
 
 pub fn router(state: Arc<AppState>) -> Router<Arc<AppState>> {
     let admin_only_layer = middleware::from_fn_with_state(state.clone(), permission_check);
-    let admin_extension_roles = Extension(RequiredRoles(
-        vec!["admin".to_string()],
-        RoleValidation::Higher,
+    let admin_extension_permission = Extension(RequiredPermission(
+        permissions::CATALOG_MANAGE,
+        PermissionScope::Any,
     ));
 
     let admin_router = Router::new()
@@ -64,7 +65,7 @@ pub fn router(state: Arc<AppState>) -> Router<Arc<AppState>> {
             delete(delete_recommendation_category).put(update_recommendation_category),
         )
         .route_layer(admin_only_layer)
-        .route_layer(admin_extension_roles)
+        .route_layer(admin_extension_permission)
         .with_state(state.clone());
 
     let public_router = Router::new()
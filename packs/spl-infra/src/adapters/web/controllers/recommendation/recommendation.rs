@@ -1,7 +1,7 @@
 use crate::adapters::web::{
     middleware::{
         auth::AuthUser,
-        permissions::{permission_check, RequiredRoles, RoleValidation},
+        permissions::{permission_check, RequiredPermission},
     },
     models::{
         common::SimplifiedQuery,
@@ -11,6 +11,7 @@ use crate::adapters::web::{
         },
     },
 };
+use spl_domain::entities::user::{permissions, PermissionScope};
 
 use crate::adapters::web::state::AppState;
 use axum::{
@@ -53,9 +54,9 @@ pub struct RecommendationApi;
 
 pub fn router(state: Arc<AppState>) -> Router<Arc<AppState>> {
     let admin_only_layer = middleware::from_fn_with_state(state.clone(), permission_check);
-    let admin_extension_roles = Extension(RequiredRoles(
-        vec!["admin".to_string()],
-        RoleValidation::Higher,
+    let admin_extension_permission = Extension(RequiredPermission(
+        permissions::CATALOG_MANAGE,
+        PermissionScope::Any,
     ));
 
     let admin_router = Router::new()
@@ -65,7 +66,7 @@ pub fn router(state: Arc<AppState>) -> Router<Arc<AppState>> {
             put(update_recommendation).delete(delete_recommendation),
         )
         .route_layer(admin_only_layer)
-        .route_layer(admin_extension_roles)
+        .route_layer(admin_extension_permission)
         .with_state(state.clone());
 
     let public_router = Router::new()
@@ -0,0 +1,206 @@
+use crate::adapters::web::middleware::auth::AuthUser;
+use crate::adapters::web::middleware::permissions::{permission_check, RequiredPermission};
+use crate::adapters::web::models::role::{
+    CreateRoleRequest, DetailedRoleResponse, GrantScope, PermissionGrantRequest,
+    PermissionGrantResponse, UpdateRoleRequest,
+};
+use crate::adapters::web::state::AppState;
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::get,
+    Extension, Json, Router,
+};
+use spl_domain::entities::user::{permissions, PermissionScope};
+use spl_shared::error::Result;
+use spl_shared::http::extractor::ValidatedJson;
+use spl_shared::http::responses::StatusResponse;
+use std::sync::Arc;
+use utoipa::OpenApi;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(get_roles, get_role, create_role, update_role, delete_role),
+    components(schemas(
+        CreateRoleRequest,
+        UpdateRoleRequest,
+        PermissionGrantRequest,
+        PermissionGrantResponse,
+        GrantScope,
+        DetailedRoleResponse,
+        StatusResponse
+    )),
+    tags((name = "roles", description = "Roles and the permissions granted to them"))
+)]
+pub struct RolesApi;
+
+pub fn router(state: Arc<AppState>) -> Router<Arc<AppState>> {
+    let admin_layer = middleware::from_fn_with_state(state.clone(), permission_check);
+    let admin_extension_permission = Extension(RequiredPermission(
+        permissions::ROLES_MANAGE,
+        PermissionScope::Any,
+    ));
+
+    Router::new()
+        .route("/roles", get(get_roles).post(create_role))
+        .route(
+            "/roles/{id}",
+            get(get_role).put(update_role).delete(delete_role),
+        )
+        .route_layer(admin_layer)
+        .route_layer(admin_extension_permission)
+        .with_state(state)
+}
+
+#[utoipa::path(
+    get,
+    path = "/roles",
+    responses(
+        (status = 200, description = "Roles with their permissions, lowest level first", body = Vec<DetailedRoleResponse>),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 403, description = "Forbidden - Admin access required", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    security(
+        ("jwt_auth" = [])
+    ),
+    tag = "roles"
+)]
+async fn get_roles(
+    State(state): State<Arc<AppState>>,
+    AuthUser(user): AuthUser,
+) -> Result<impl IntoResponse> {
+    let roles = state.role_service.get_all_detailed(&user).await?;
+
+    Ok(Json(
+        roles
+            .into_iter()
+            .map(DetailedRoleResponse::from)
+            .collect::<Vec<_>>(),
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "/roles/{id}",
+    params(
+        ("id" = i32, Path, description = "Role ID")
+    ),
+    responses(
+        (status = 200, description = "Role with its permissions", body = DetailedRoleResponse),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 403, description = "Forbidden - Admin access required", body = StatusResponse),
+        (status = 404, description = "Role not found", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    security(
+        ("jwt_auth" = [])
+    ),
+    tag = "roles"
+)]
+async fn get_role(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<i32>,
+    AuthUser(user): AuthUser,
+) -> Result<impl IntoResponse> {
+    let role = state.role_service.get_detailed(&user, id).await?;
+
+    Ok(Json(DetailedRoleResponse::from(role)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/roles",
+    request_body = CreateRoleRequest,
+    responses(
+        (status = 201, description = "Role created", body = DetailedRoleResponse),
+        (status = 400, description = "Invalid input or unknown permission", body = StatusResponse),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 403, description = "Forbidden - Level above your own", body = StatusResponse),
+        (status = 409, description = "Role already exists", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    security(
+        ("jwt_auth" = [])
+    ),
+    tag = "roles"
+)]
+async fn create_role(
+    State(state): State<Arc<AppState>>,
+    AuthUser(user): AuthUser,
+    ValidatedJson(payload): ValidatedJson<CreateRoleRequest>,
+) -> Result<impl IntoResponse> {
+    let role = state.role_service.create(&user, payload.into()).await?;
+
+    Ok((StatusCode::CREATED, Json(DetailedRoleResponse::from(role))))
+}
+
+#[utoipa::path(
+    put,
+    path = "/roles/{id}",
+    params(
+        ("id" = i32, Path, description = "Role ID")
+    ),
+    request_body = UpdateRoleRequest,
+    responses(
+        (status = 200, description = "Role updated", body = DetailedRoleResponse),
+        (status = 400, description = "Invalid input or unknown permission", body = StatusResponse),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 403, description = "Forbidden - Level above your own", body = StatusResponse),
+        (status = 404, description = "Role not found", body = StatusResponse),
+        (status = 409, description = "Name taken, or nobody could manage roles afterwards", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    security(
+        ("jwt_auth" = [])
+    ),
+    tag = "roles"
+)]
+async fn update_role(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<i32>,
+    AuthUser(user): AuthUser,
+    ValidatedJson(payload): ValidatedJson<UpdateRoleRequest>,
+) -> Result<impl IntoResponse> {
+    let role = state.role_service.update(&user, id, payload.into()).await?;
+
+    Ok(Json(DetailedRoleResponse::from(role)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/roles/{id}",
+    params(
+        ("id" = i32, Path, description = "Role ID")
+    ),
+    responses(
+        (status = 200, description = "Role deleted", body = StatusResponse),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 403, description = "Forbidden - Level above your own", body = StatusResponse),
+        (status = 404, description = "Role not found", body = StatusResponse),
+        (status = 409, description = "Role still assigned to users or invitations", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    security(
+        ("jwt_auth" = [])
+    ),
+    tag = "roles"
+)]
+async fn delete_role(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<i32>,
+    AuthUser(user): AuthUser,
+) -> Result<impl IntoResponse> {
+    let _ = state.role_service.delete(&user, id).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(StatusResponse {
+            success: true,
+            code: 200,
+            message: "Role deleted successfully".to_string(),
+        }),
+    ))
+}
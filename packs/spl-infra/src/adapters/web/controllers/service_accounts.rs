@@ -0,0 +1,271 @@
+use spl_domain::entities::user::{permissions, PermissionScope};
+use spl_shared::error::Result;
+use spl_shared::http::extractor::ValidatedJson;
+
+use crate::adapters::web::{
+    middleware::{
+        auth::AccountOwner,
+        permissions::{permission_check, RequiredPermission},
+    },
+    models::service_account::{
+        ApiKeyResponse, CreateApiKeyRequest, CreateServiceAccountRequest, CreatedApiKeyResponse,
+        ServiceAccountQuery, ServiceAccountResponse,
+    },
+    state::AppState,
+};
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{delete, get},
+    Extension, Json, Router,
+};
+
+use spl_shared::http::responses::StatusResponse;
+use std::sync::Arc;
+use utoipa::OpenApi;
+use uuid::Uuid;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(create_service_account, get_service_accounts, delete_service_account, create_api_key, get_api_keys, revoke_api_key),
+    components(schemas(
+        CreateServiceAccountRequest,
+        ServiceAccountResponse,
+        CreateApiKeyRequest,
+        ApiKeyResponse,
+        CreatedApiKeyResponse,
+        StatusResponse
+    )),
+    tags((name = "service-accounts", description = "Service accounts and API keys for devices and integrations"))
+)]
+pub struct ServiceAccountsApi;
+
+pub fn router(state: Arc<AppState>) -> Router<Arc<AppState>> {
+    let supervisor_layer = middleware::from_fn_with_state(state.clone(), permission_check);
+    let supervisor_extension_permission = Extension(RequiredPermission(
+        permissions::SERVICE_ACCOUNTS_MANAGE,
+        PermissionScope::Company,
+    ));
+
+    Router::new()
+        .route(
+            "/service-accounts",
+            get(get_service_accounts).post(create_service_account),
+        )
+        .route("/service-accounts/{id}", delete(delete_service_account))
+        .route(
+            "/service-accounts/{id}/keys",
+            get(get_api_keys).post(create_api_key),
+        )
+        .route(
+            "/service-accounts/{id}/keys/{key_id}",
+            delete(revoke_api_key),
+        )
+        .route_layer(supervisor_layer)
+        .route_layer(supervisor_extension_permission)
+        .with_state(state)
+}
+
+#[utoipa::path(
+    post,
+    path = "/service-accounts",
+    request_body = CreateServiceAccountRequest,
+    responses(
+        (status = 201, description = "Service account created", body = ServiceAccountResponse),
+        (status = 400, description = "Invalid input", body = StatusResponse),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 403, description = "Forbidden - Supervisor access required, or impersonating", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    security(
+        ("jwt_auth" = [])
+    ),
+    tag = "service-accounts"
+)]
+async fn create_service_account(
+    State(state): State<Arc<AppState>>,
+    AccountOwner(user): AccountOwner,
+    ValidatedJson(payload): ValidatedJson<CreateServiceAccountRequest>,
+) -> Result<impl IntoResponse> {
+    let result = state
+        .service_account_service
+        .create(&user, payload.into())
+        .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ServiceAccountResponse::from(result)),
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "/service-accounts",
+    params(ServiceAccountQuery),
+    responses(
+        (status = 200, description = "Service accounts of the company", body = Vec<ServiceAccountResponse>),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 403, description = "Forbidden - Supervisor access required, or impersonating", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    security(
+        ("jwt_auth" = [])
+    ),
+    tag = "service-accounts"
+)]
+async fn get_service_accounts(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<ServiceAccountQuery>,
+    AccountOwner(user): AccountOwner,
+) -> Result<impl IntoResponse> {
+    let accounts = state
+        .service_account_service
+        .get_by_company(&user, query.company_id)
+        .await?;
+
+    Ok(Json(
+        accounts
+            .into_iter()
+            .map(ServiceAccountResponse::from)
+            .collect::<Vec<_>>(),
+    ))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/service-accounts/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Service account ID")
+    ),
+    responses(
+        (status = 200, description = "Service account and its keys deleted", body = StatusResponse),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 403, description = "Forbidden - Access denied, or impersonating", body = StatusResponse),
+        (status = 404, description = "Service account not found", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    security(
+        ("jwt_auth" = [])
+    ),
+    tag = "service-accounts"
+)]
+async fn delete_service_account(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+    AccountOwner(user): AccountOwner,
+) -> Result<impl IntoResponse> {
+    let _ = state.service_account_service.delete(&user, id).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(StatusResponse {
+            success: true,
+            code: 200,
+            message: "Service account deleted successfully".to_string(),
+        }),
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/service-accounts/{id}/keys",
+    params(
+        ("id" = Uuid, Path, description = "Service account ID")
+    ),
+    request_body = CreateApiKeyRequest,
+    responses(
+        (status = 201, description = "API key created, the key is only shown once", body = CreatedApiKeyResponse),
+        (status = 400, description = "Invalid input or unknown scope", body = StatusResponse),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 403, description = "Forbidden - Access denied, or impersonating", body = StatusResponse),
+        (status = 404, description = "Service account not found", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    security(
+        ("jwt_auth" = [])
+    ),
+    tag = "service-accounts"
+)]
+async fn create_api_key(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+    AccountOwner(user): AccountOwner,
+    ValidatedJson(payload): ValidatedJson<CreateApiKeyRequest>,
+) -> Result<impl IntoResponse> {
+    let result = state
+        .service_account_service
+        .create_key(&user, id, payload.into())
+        .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(CreatedApiKeyResponse::from(result)),
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "/service-accounts/{id}/keys",
+    params(
+        ("id" = Uuid, Path, description = "Service account ID")
+    ),
+    responses(
+        (status = 200, description = "Keys of the service account", body = Vec<ApiKeyResponse>),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 403, description = "Forbidden - Access denied, or impersonating", body = StatusResponse),
+        (status = 404, description = "Service account not found", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    security(
+        ("jwt_auth" = [])
+    ),
+    tag = "service-accounts"
+)]
+async fn get_api_keys(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+    AccountOwner(user): AccountOwner,
+) -> Result<impl IntoResponse> {
+    let keys = state.service_account_service.get_keys(&user, id).await?;
+
+    Ok(Json(
+        keys.into_iter()
+            .map(ApiKeyResponse::from)
+            .collect::<Vec<_>>(),
+    ))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/service-accounts/{id}/keys/{key_id}",
+    params(
+        ("id" = Uuid, Path, description = "Service account ID"),
+        ("key_id" = Uuid, Path, description = "API key ID")
+    ),
+    responses(
+        (status = 200, description = "API key revoked", body = ApiKeyResponse),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 403, description = "Forbidden - Access denied, or impersonating", body = StatusResponse),
+        (status = 404, description = "API key not found", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    security(
+        ("jwt_auth" = [])
+    ),
+    tag = "service-accounts"
+)]
+async fn revoke_api_key(
+    State(state): State<Arc<AppState>>,
+    Path((id, key_id)): Path<(Uuid, Uuid)>,
+    AccountOwner(user): AccountOwner,
+) -> Result<impl IntoResponse> {
+    let key = state
+        .service_account_service
+        .revoke_key(&user, id, key_id)
+        .await?;
+
+    Ok(Json(ApiKeyResponse::from(key)))
+}
@@ -0,0 +1,229 @@
+use crate::adapters::web::middleware::auth::{AccountOwner, CurrentSession};
+use crate::adapters::web::middleware::permissions::{permission_check, RequiredPermission};
+use crate::adapters::web::models::session::{RevokedSessionsResponse, SessionResponse};
+use crate::adapters::web::state::AppState;
+use axum::{
+    extract::{Path, State},
+    middleware,
+    response::IntoResponse,
+    routing::{delete, get},
+    Extension, Json, Router,
+};
+use spl_domain::entities::auth::Session;
+use spl_domain::entities::user::{permissions, PermissionScope};
+use spl_shared::error::Result;
+use spl_shared::http::responses::StatusResponse;
+use std::sync::Arc;
+use utoipa::OpenApi;
+use uuid::Uuid;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        get_my_sessions,
+        revoke_my_sessions,
+        revoke_my_session,
+        get_user_sessions,
+        revoke_user_sessions,
+        revoke_user_session
+    ),
+    components(schemas(SessionResponse, RevokedSessionsResponse, StatusResponse)),
+    tags((name = "sessions", description = "Active sessions of users")),
+    security(("jwt_auth" = []))
+)]
+pub struct SessionsApi;
+
+pub fn router(state: Arc<AppState>) -> Router<Arc<AppState>> {
+    let supervisor_layer = middleware::from_fn_with_state(state.clone(), permission_check);
+    let supervisor_extension_permission = Extension(RequiredPermission(
+        permissions::USERS_MANAGE,
+        PermissionScope::Company,
+    ));
+
+    let management = Router::new()
+        .route(
+            "/users/{id}/sessions",
+            get(get_user_sessions).delete(revoke_user_sessions),
+        )
+        .route(
+            "/users/{id}/sessions/{session_id}",
+            delete(revoke_user_session),
+        )
+        .route_layer(supervisor_layer)
+        .route_layer(supervisor_extension_permission);
+
+    Router::new()
+        .route(
+            "/users/me/sessions",
+            get(get_my_sessions).delete(revoke_my_sessions),
+        )
+        .route("/users/me/sessions/{session_id}", delete(revoke_my_session))
+        .merge(management)
+        .with_state(state)
+}
+
+fn session_responses(
+    sessions: Vec<Session>,
+    current: Option<Extension<CurrentSession>>,
+) -> Vec<SessionResponse> {
+    let current = current.map(|Extension(CurrentSession(id))| id);
+
+    sessions
+        .into_iter()
+        .map(|session| SessionResponse::from_session(session, current))
+        .collect()
+}
+
+#[utoipa::path(
+    get,
+    path = "/users/me/sessions",
+    responses(
+        (status = 200, description = "Active sessions of the current user", body = Vec<SessionResponse>),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 403, description = "Forbidden - Not allowed while impersonating", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    tag = "sessions"
+)]
+async fn get_my_sessions(
+    State(state): State<Arc<AppState>>,
+    AccountOwner(user): AccountOwner,
+    current: Option<Extension<CurrentSession>>,
+) -> Result<impl IntoResponse> {
+    let sessions = state.session_service.get_active(&user, user.id).await?;
+
+    Ok(Json(session_responses(sessions, current)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/users/me/sessions",
+    responses(
+        (status = 200, description = "Every session revoked, including the current one", body = RevokedSessionsResponse),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 403, description = "Forbidden - Not allowed while impersonating", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    tag = "sessions"
+)]
+async fn revoke_my_sessions(
+    State(state): State<Arc<AppState>>,
+    AccountOwner(user): AccountOwner,
+) -> Result<impl IntoResponse> {
+    let revoked = state.session_service.revoke_all(&user, user.id).await?;
+
+    Ok(Json(RevokedSessionsResponse { revoked }))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/users/me/sessions/{session_id}",
+    params(
+        ("session_id" = Uuid, Path, description = "Session ID")
+    ),
+    responses(
+        (status = 200, description = "Session revoked", body = StatusResponse),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 403, description = "Forbidden - Not allowed while impersonating", body = StatusResponse),
+        (status = 404, description = "Session not found", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    tag = "sessions"
+)]
+async fn revoke_my_session(
+    State(state): State<Arc<AppState>>,
+    Path(session_id): Path<Uuid>,
+    AccountOwner(user): AccountOwner,
+) -> Result<impl IntoResponse> {
+    state
+        .session_service
+        .revoke(&user, user.id, session_id)
+        .await?;
+
+    Ok(Json(StatusResponse {
+        success: true,
+        code: 200,
+        message: "Session revoked".to_string(),
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/users/{id}/sessions",
+    params(
+        ("id" = Uuid, Path, description = "User ID")
+    ),
+    responses(
+        (status = 200, description = "Active sessions of the user", body = Vec<SessionResponse>),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 403, description = "Forbidden - Access denied, or impersonating", body = StatusResponse),
+        (status = 404, description = "User not found", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    tag = "sessions"
+)]
+async fn get_user_sessions(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+    AccountOwner(user): AccountOwner,
+    current: Option<Extension<CurrentSession>>,
+) -> Result<impl IntoResponse> {
+    let sessions = state.session_service.get_active(&user, id).await?;
+
+    Ok(Json(session_responses(sessions, current)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/users/{id}/sessions",
+    params(
+        ("id" = Uuid, Path, description = "User ID")
+    ),
+    responses(
+        (status = 200, description = "Every session of the user revoked", body = RevokedSessionsResponse),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 403, description = "Forbidden - Access denied, or impersonating", body = StatusResponse),
+        (status = 404, description = "User not found", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    tag = "sessions"
+)]
+async fn revoke_user_sessions(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+    AccountOwner(user): AccountOwner,
+) -> Result<impl IntoResponse> {
+    let revoked = state.session_service.revoke_all(&user, id).await?;
+
+    Ok(Json(RevokedSessionsResponse { revoked }))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/users/{id}/sessions/{session_id}",
+    params(
+        ("id" = Uuid, Path, description = "User ID"),
+        ("session_id" = Uuid, Path, description = "Session ID")
+    ),
+    responses(
+        (status = 200, description = "Session revoked", body = StatusResponse),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 403, description = "Forbidden - Access denied, or impersonating", body = StatusResponse),
+        (status = 404, description = "User or session not found", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    tag = "sessions"
+)]
+async fn revoke_user_session(
+    State(state): State<Arc<AppState>>,
+    Path((id, session_id)): Path<(Uuid, Uuid)>,
+    AccountOwner(user): AccountOwner,
+) -> Result<impl IntoResponse> {
+    state.session_service.revoke(&user, id, session_id).await?;
+
+    Ok(Json(StatusResponse {
+        success: true,
+        code: 200,
+        message: "Session revoked".to_string(),
+    }))
+}
@@ -0,0 +1,204 @@
+use spl_domain::entities::user::{permissions, PermissionScope};
+use spl_shared::error::Result;
+use spl_shared::http::extractor::ValidatedJson;
+
+use crate::adapters::web::{
+    middleware::{
+        auth::{AuthUser, ClientInfo},
+        permissions::{permission_check, RequiredPermission},
+    },
+    models::{
+        auth::TokenResponse,
+        sso::{IdentityProviderRequest, IdentityProviderResponse, SsoCallbackRequest},
+    },
+    state::AppState,
+};
+
+use axum::{
+    extract::{Path, State},
+    middleware,
+    response::{IntoResponse, Redirect},
+    routing::{get, post},
+    Extension, Json, Router,
+};
+
+use spl_shared::http::responses::StatusResponse;
+use std::sync::Arc;
+use utoipa::OpenApi;
+use uuid::Uuid;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(authorize, callback, get_identity_provider, configure_identity_provider, delete_identity_provider),
+    components(schemas(
+        IdentityProviderRequest,
+        IdentityProviderResponse,
+        SsoCallbackRequest,
+        TokenResponse,
+        StatusResponse
+    )),
+    tags((name = "sso", description = "OpenID Connect single sign-on"))
+)]
+pub struct SsoApi;
+
+pub fn router(state: Arc<AppState>) -> Router<Arc<AppState>> {
+    let supervisor_layer = middleware::from_fn_with_state(state.clone(), permission_check);
+    let supervisor_extension_permission = Extension(RequiredPermission(
+        permissions::SSO_MANAGE,
+        PermissionScope::Company,
+    ));
+
+    let management = Router::new()
+        .route(
+            "/companies/{id}/sso",
+            get(get_identity_provider)
+                .put(configure_identity_provider)
+                .delete(delete_identity_provider),
+        )
+        .route_layer(supervisor_layer)
+        .route_layer(supervisor_extension_permission);
+
+    Router::new()
+        .route("/auth/oidc/{company_id}/authorize", get(authorize))
+        .route("/auth/oidc/callback", post(callback))
+        .merge(management)
+        .with_state(state)
+}
+
+#[utoipa::path(
+    get,
+    path = "/auth/oidc/{company_id}/authorize",
+    params(
+        ("company_id" = Uuid, Path, description = "Company ID")
+    ),
+    responses(
+        (status = 303, description = "Redirect to the identity provider"),
+        (status = 404, description = "Single sign-on not enabled for the company", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    tag = "sso"
+)]
+async fn authorize(
+    State(state): State<Arc<AppState>>,
+    Path(company_id): Path<Uuid>,
+) -> Result<impl IntoResponse> {
+    let url = state.sso_service.authorize(company_id).await?;
+
+    Ok(Redirect::to(&url))
+}
+
+#[utoipa::path(
+    post,
+    path = "/auth/oidc/callback",
+    request_body = SsoCallbackRequest,
+    responses(
+        (status = 200, description = "Login successful", body = TokenResponse),
+        (status = 401, description = "Invalid state, code or ID token", body = StatusResponse),
+        (status = 403, description = "No role mapped for the user", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    tag = "sso"
+)]
+async fn callback(
+    State(state): State<Arc<AppState>>,
+    ClientInfo(client): ClientInfo,
+    ValidatedJson(payload): ValidatedJson<SsoCallbackRequest>,
+) -> Result<impl IntoResponse> {
+    let tokens = state.sso_service.callback(payload.into(), client).await?;
+
+    Ok(Json(TokenResponse::from(tokens)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/companies/{id}/sso",
+    params(
+        ("id" = Uuid, Path, description = "Company ID")
+    ),
+    responses(
+        (status = 200, description = "Identity provider of the company", body = IdentityProviderResponse),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 403, description = "Forbidden - Access denied", body = StatusResponse),
+        (status = 404, description = "Identity provider not found", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    security(
+        ("jwt_auth" = [])
+    ),
+    tag = "sso"
+)]
+async fn get_identity_provider(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+    AuthUser(user): AuthUser,
+) -> Result<impl IntoResponse> {
+    let provider = state.sso_service.get_provider(&user, id).await?;
+
+    Ok(Json(IdentityProviderResponse::from(provider)))
+}
+
+#[utoipa::path(
+    put,
+    path = "/companies/{id}/sso",
+    params(
+        ("id" = Uuid, Path, description = "Company ID")
+    ),
+    request_body = IdentityProviderRequest,
+    responses(
+        (status = 200, description = "Identity provider configured", body = IdentityProviderResponse),
+        (status = 400, description = "Invalid input or role", body = StatusResponse),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 403, description = "Forbidden - Access denied", body = StatusResponse),
+        (status = 404, description = "Company not found", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    security(
+        ("jwt_auth" = [])
+    ),
+    tag = "sso"
+)]
+async fn configure_identity_provider(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+    AuthUser(user): AuthUser,
+    ValidatedJson(payload): ValidatedJson<IdentityProviderRequest>,
+) -> Result<impl IntoResponse> {
+    let provider = state
+        .sso_service
+        .configure_provider(&user, id, payload.into())
+        .await?;
+
+    Ok(Json(IdentityProviderResponse::from(provider)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/companies/{id}/sso",
+    params(
+        ("id" = Uuid, Path, description = "Company ID")
+    ),
+    responses(
+        (status = 200, description = "Identity provider removed", body = StatusResponse),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 403, description = "Forbidden - Access denied", body = StatusResponse),
+        (status = 404, description = "Identity provider not found", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    security(
+        ("jwt_auth" = [])
+    ),
+    tag = "sso"
+)]
+async fn delete_identity_provider(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+    AuthUser(user): AuthUser,
+) -> Result<impl IntoResponse> {
+    state.sso_service.delete_provider(&user, id).await?;
+
+    Ok(Json(StatusResponse {
+        success: true,
+        code: 200,
+        message: "Identity provider removed".to_string(),
+    }))
+}
@@ -0,0 +1,365 @@
+use crate::adapters::web::middleware::auth::AuthUser;
+use crate::adapters::web::middleware::permissions::{permission_check, RequiredPermission};
+use crate::adapters::web::models::team::{
+    CreateTeamRequest, DetailedTeamResponse, SetTeamMemberRequest, TeamMemberResponse,
+    TeamResponse, TeamRoleRequest, UpdateTeamRequest,
+};
+use crate::adapters::web::state::AppState;
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{get, post, put},
+    Extension, Json, Router,
+};
+use spl_domain::entities::user::{permissions, PermissionScope};
+use spl_shared::error::Result;
+use spl_shared::http::extractor::ValidatedJson;
+use spl_shared::http::responses::StatusResponse;
+use spl_shared::traits::IntoWithContext;
+use std::sync::Arc;
+use utoipa::OpenApi;
+use uuid::Uuid;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        get_company_teams,
+        create_team,
+        get_team,
+        update_team,
+        delete_team,
+        set_team_member,
+        remove_team_member,
+        add_team_plot,
+        remove_team_plot
+    ),
+    components(schemas(
+        CreateTeamRequest,
+        UpdateTeamRequest,
+        SetTeamMemberRequest,
+        TeamRoleRequest,
+        TeamResponse,
+        TeamMemberResponse,
+        DetailedTeamResponse,
+        StatusResponse
+    )),
+    tags((name = "teams", description = "Teams that scope what their supervisors see")),
+    security(("jwt_auth" = []))
+)]
+pub struct TeamsApi;
+
+pub fn router(state: Arc<AppState>) -> Router<Arc<AppState>> {
+    let permission_layer = middleware::from_fn_with_state(state.clone(), permission_check);
+
+    let read_extension_permission = Extension(RequiredPermission(
+        permissions::USERS_READ,
+        PermissionScope::Team,
+    ));
+
+    let manage_extension_permission = Extension(RequiredPermission(
+        permissions::TEAMS_MANAGE,
+        PermissionScope::Company,
+    ));
+
+    let supervisor_router = Router::new()
+        .route("/companies/{id}/teams", get(get_company_teams))
+        .route("/teams/{id}", get(get_team))
+        .route_layer(permission_layer.clone())
+        .route_layer(read_extension_permission)
+        .with_state(state.clone());
+
+    let manager_router = Router::new()
+        .route("/companies/{id}/teams", post(create_team))
+        .route("/teams/{id}", put(update_team).delete(delete_team))
+        .route(
+            "/teams/{id}/members/{user_id}",
+            put(set_team_member).delete(remove_team_member),
+        )
+        .route(
+            "/teams/{id}/plots/{plot_id}",
+            put(add_team_plot).delete(remove_team_plot),
+        )
+        .route_layer(permission_layer)
+        .route_layer(manage_extension_permission)
+        .with_state(state);
+
+    Router::new().merge(supervisor_router).merge(manager_router)
+}
+
+#[utoipa::path(
+    get,
+    path = "/companies/{id}/teams",
+    params(
+        ("id" = Uuid, Path, description = "Company ID")
+    ),
+    responses(
+        (status = 200, description = "Teams of the company by name, only the supervised ones for team supervisors", body = Vec<TeamResponse>),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 403, description = "Forbidden - Access denied", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    security(
+        ("jwt_auth" = [])
+    ),
+    tag = "teams"
+)]
+async fn get_company_teams(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+    AuthUser(user): AuthUser,
+) -> Result<impl IntoResponse> {
+    let teams = state.team_service.get_by_company(&user, id).await?;
+
+    Ok(Json(
+        teams
+            .into_iter()
+            .map(TeamResponse::from)
+            .collect::<Vec<_>>(),
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/companies/{id}/teams",
+    params(
+        ("id" = Uuid, Path, description = "Company ID")
+    ),
+    request_body = CreateTeamRequest,
+    responses(
+        (status = 201, description = "Team created", body = TeamResponse),
+        (status = 400, description = "Invalid input", body = StatusResponse),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 403, description = "Forbidden - Access denied", body = StatusResponse),
+        (status = 409, description = "The company already has a team with that name", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    security(
+        ("jwt_auth" = [])
+    ),
+    tag = "teams"
+)]
+async fn create_team(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+    AuthUser(user): AuthUser,
+    ValidatedJson(payload): ValidatedJson<CreateTeamRequest>,
+) -> Result<impl IntoResponse> {
+    let team = state
+        .team_service
+        .create(&user, payload.into_with_context(id)?)
+        .await?;
+
+    Ok((StatusCode::CREATED, Json(TeamResponse::from(team))))
+}
+
+#[utoipa::path(
+    get,
+    path = "/teams/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Team ID")
+    ),
+    responses(
+        (status = 200, description = "Team with its members and plots", body = DetailedTeamResponse),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 403, description = "Forbidden - Access denied", body = StatusResponse),
+        (status = 404, description = "Team not found", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    security(
+        ("jwt_auth" = [])
+    ),
+    tag = "teams"
+)]
+async fn get_team(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+    AuthUser(user): AuthUser,
+) -> Result<impl IntoResponse> {
+    let details = state.team_service.get_details(&user, id).await?;
+
+    Ok(Json(DetailedTeamResponse::from(details)))
+}
+
+#[utoipa::path(
+    put,
+    path = "/teams/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Team ID")
+    ),
+    request_body = UpdateTeamRequest,
+    responses(
+        (status = 200, description = "Team updated", body = TeamResponse),
+        (status = 400, description = "Invalid input", body = StatusResponse),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 403, description = "Forbidden - Access denied", body = StatusResponse),
+        (status = 404, description = "Team not found", body = StatusResponse),
+        (status = 409, description = "The company already has a team with that name", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    security(
+        ("jwt_auth" = [])
+    ),
+    tag = "teams"
+)]
+async fn update_team(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+    AuthUser(user): AuthUser,
+    ValidatedJson(payload): ValidatedJson<UpdateTeamRequest>,
+) -> Result<impl IntoResponse> {
+    let team = state.team_service.update(&user, id, payload.into()).await?;
+
+    Ok(Json(TeamResponse::from(team)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/teams/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Team ID")
+    ),
+    responses(
+        (status = 200, description = "Team deleted, its members and plots are kept", body = TeamResponse),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 403, description = "Forbidden - Access denied", body = StatusResponse),
+        (status = 404, description = "Team not found", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    security(
+        ("jwt_auth" = [])
+    ),
+    tag = "teams"
+)]
+async fn delete_team(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+    AuthUser(user): AuthUser,
+) -> Result<impl IntoResponse> {
+    let team = state.team_service.delete(&user, id).await?;
+
+    Ok(Json(TeamResponse::from(team)))
+}
+
+#[utoipa::path(
+    put,
+    path = "/teams/{id}/members/{user_id}",
+    params(
+        ("id" = Uuid, Path, description = "Team ID"),
+        ("user_id" = Uuid, Path, description = "User ID")
+    ),
+    request_body = SetTeamMemberRequest,
+    responses(
+        (status = 200, description = "User added to the team, or its role changed", body = TeamMemberResponse),
+        (status = 400, description = "The user belongs to another company", body = StatusResponse),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 403, description = "Forbidden - Access denied", body = StatusResponse),
+        (status = 404, description = "Team or user not found", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    security(
+        ("jwt_auth" = [])
+    ),
+    tag = "teams"
+)]
+async fn set_team_member(
+    State(state): State<Arc<AppState>>,
+    Path((id, user_id)): Path<(Uuid, Uuid)>,
+    AuthUser(user): AuthUser,
+    ValidatedJson(payload): ValidatedJson<SetTeamMemberRequest>,
+) -> Result<impl IntoResponse> {
+    let member = state
+        .team_service
+        .set_member(&user, id, payload.into_with_context(user_id)?)
+        .await?;
+
+    Ok(Json(TeamMemberResponse::from(member)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/teams/{id}/members/{user_id}",
+    params(
+        ("id" = Uuid, Path, description = "Team ID"),
+        ("user_id" = Uuid, Path, description = "User ID")
+    ),
+    responses(
+        (status = 204, description = "User removed from the team"),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 403, description = "Forbidden - Access denied", body = StatusResponse),
+        (status = 404, description = "Team not found or the user is not in it", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    security(
+        ("jwt_auth" = [])
+    ),
+    tag = "teams"
+)]
+async fn remove_team_member(
+    State(state): State<Arc<AppState>>,
+    Path((id, user_id)): Path<(Uuid, Uuid)>,
+    AuthUser(user): AuthUser,
+) -> Result<impl IntoResponse> {
+    state.team_service.remove_member(&user, id, user_id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    put,
+    path = "/teams/{id}/plots/{plot_id}",
+    params(
+        ("id" = Uuid, Path, description = "Team ID"),
+        ("plot_id" = Uuid, Path, description = "Plot ID")
+    ),
+    responses(
+        (status = 204, description = "Plot assigned to the team"),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 403, description = "Forbidden - Access denied", body = StatusResponse),
+        (status = 404, description = "Team not found, or the plot is not of its company", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    security(
+        ("jwt_auth" = [])
+    ),
+    tag = "teams"
+)]
+async fn add_team_plot(
+    State(state): State<Arc<AppState>>,
+    Path((id, plot_id)): Path<(Uuid, Uuid)>,
+    AuthUser(user): AuthUser,
+) -> Result<impl IntoResponse> {
+    state.team_service.add_plot(&user, id, plot_id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    delete,
+    path = "/teams/{id}/plots/{plot_id}",
+    params(
+        ("id" = Uuid, Path, description = "Team ID"),
+        ("plot_id" = Uuid, Path, description = "Plot ID")
+    ),
+    responses(
+        (status = 204, description = "Plot no longer assigned to the team"),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 403, description = "Forbidden - Access denied", body = StatusResponse),
+        (status = 404, description = "Team not found or the plot is not assigned to it", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    security(
+        ("jwt_auth" = [])
+    ),
+    tag = "teams"
+)]
+async fn remove_team_plot(
+    State(state): State<Arc<AppState>>,
+    Path((id, plot_id)): Path<(Uuid, Uuid)>,
+    AuthUser(user): AuthUser,
+) -> Result<impl IntoResponse> {
+    state.team_service.remove_plot(&user, id, plot_id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,140 @@
+use crate::adapters::web::mappers::usage::parse_month;
+use crate::adapters::web::middleware::auth::AuthUser;
+use crate::adapters::web::models::usage::{
+    CompanyQuotaRequest, QuotaResponse, UsageQuery, UsageReportResponse, UserUsageResponse,
+};
+use crate::adapters::web::state::AppState;
+use axum::{
+    extract::{Path, Query, State},
+    response::IntoResponse,
+    routing::{get, put},
+    Json, Router,
+};
+use spl_shared::error::Result;
+use spl_shared::http::extractor::ValidatedJson;
+use spl_shared::http::responses::StatusResponse;
+use std::sync::Arc;
+use utoipa::OpenApi;
+use uuid::Uuid;
+
+/// Header set on created predictions when the quota is close to or past its limits
+pub const QUOTA_STATUS_HEADER: &str = "x-quota-status";
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(get_usage, set_quota, delete_quota),
+    components(schemas(
+        CompanyQuotaRequest,
+        QuotaResponse,
+        UsageReportResponse,
+        UserUsageResponse,
+        StatusResponse
+    )),
+    tags((name = "usage", description = "Monthly usage and quotas of companies"))
+)]
+pub struct UsageApi;
+
+pub fn router(state: Arc<AppState>) -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/companies/{id}/usage", get(get_usage))
+        .route("/companies/{id}/quota", put(set_quota).delete(delete_quota))
+        .with_state(state)
+}
+
+#[utoipa::path(
+    get,
+    path = "/companies/{id}/usage",
+    params(
+        ("id" = Uuid, Path, description = "Company ID"),
+        UsageQuery
+    ),
+    responses(
+        (status = 200, description = "Usage of the company during the month", body = UsageReportResponse),
+        (status = 400, description = "Invalid month", body = StatusResponse),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 403, description = "Forbidden - Access denied", body = StatusResponse),
+        (status = 404, description = "Company not found", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    security(
+        ("jwt_auth" = [])
+    ),
+    tag = "usage"
+)]
+async fn get_usage(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<UsageQuery>,
+    AuthUser(user): AuthUser,
+) -> Result<impl IntoResponse> {
+    let period = query.month.as_deref().map(parse_month).transpose()?;
+
+    let report = state.usage_service.report(&user, id, period).await?;
+
+    Ok(Json(UsageReportResponse::from(report)))
+}
+
+#[utoipa::path(
+    put,
+    path = "/companies/{id}/quota",
+    params(
+        ("id" = Uuid, Path, description = "Company ID")
+    ),
+    request_body = CompanyQuotaRequest,
+    responses(
+        (status = 200, description = "Quota of the company replaced", body = QuotaResponse),
+        (status = 400, description = "Invalid input", body = StatusResponse),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 403, description = "Forbidden - Access denied", body = StatusResponse),
+        (status = 404, description = "Company not found", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    security(
+        ("jwt_auth" = [])
+    ),
+    tag = "usage"
+)]
+async fn set_quota(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+    AuthUser(user): AuthUser,
+    ValidatedJson(payload): ValidatedJson<CompanyQuotaRequest>,
+) -> Result<impl IntoResponse> {
+    let quota = state
+        .usage_service
+        .set_company_quota(&user, id, payload.into())
+        .await?;
+
+    Ok(Json(QuotaResponse::from(quota)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/companies/{id}/quota",
+    params(
+        ("id" = Uuid, Path, description = "Company ID")
+    ),
+    responses(
+        (status = 200, description = "Quota removed, the company follows the server quota", body = QuotaResponse),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 403, description = "Forbidden - Access denied", body = StatusResponse),
+        (status = 404, description = "Company quota not found", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    security(
+        ("jwt_auth" = [])
+    ),
+    tag = "usage"
+)]
+async fn delete_quota(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+    AuthUser(user): AuthUser,
+) -> Result<impl IntoResponse> {
+    let quota = state
+        .usage_service
+        .delete_company_quota(&user, id)
+        .await?;
+
+    Ok(Json(QuotaResponse::from(quota)))
+}
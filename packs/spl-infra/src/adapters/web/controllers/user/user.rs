@@ -1,9 +1,11 @@
-use crate::adapters::web::middleware::auth::AuthUser;
-use crate::adapters::web::middleware::permissions::{
-    permission_check, RequiredRoles, RoleValidation,
+use crate::adapters::web::middleware::auth::{AccountOwner, AuthUser, Impersonator};
+use crate::adapters::web::middleware::permissions::{permission_check, RequiredPermission};
+use crate::adapters::web::models::auth::{
+    TwoFactorCodeRequest, TwoFactorSetupResponse, TwoFactorStatusResponse,
 };
 use crate::adapters::web::models::user::{
-    ChangePasswordRequest, FullUserResponse, UpdateProfileRequest, UpdateUserRequest, UserResponse,
+    ChangePasswordRequest, FullUserResponse, LoginLockoutResponse, UpdateProfileRequest,
+    UpdateUserRequest, UserResponse,
 };
 use crate::adapters::web::state::AppState;
 use axum::{
@@ -11,9 +13,10 @@ use axum::{
     http::StatusCode,
     middleware,
     response::IntoResponse,
-    routing::{get, put},
+    routing::{get, post, put},
     Extension, Json, Router,
 };
+use spl_domain::entities::user::{permissions, PermissionScope};
 use spl_shared::error::Result;
 use spl_shared::http::extractor::ValidatedJson;
 use spl_shared::http::responses::StatusResponse;
@@ -23,13 +26,31 @@ use uuid::Uuid;
 
 #[derive(OpenApi)]
 #[openapi(
-    paths(me, update_profile, change_password, update_user, delete_user),
+    paths(
+        me,
+        update_profile,
+        change_password,
+        send_email_verification,
+        update_user,
+        delete_user,
+        reactivate_user,
+        get_lockout,
+        unlock_user,
+        get_two_factor,
+        enroll_two_factor,
+        confirm_two_factor,
+        disable_two_factor
+    ),
     components(schemas(
         UserResponse,
         FullUserResponse,
         UpdateUserRequest,
         UpdateProfileRequest,
         ChangePasswordRequest,
+        LoginLockoutResponse,
+        TwoFactorCodeRequest,
+        TwoFactorSetupResponse,
+        TwoFactorStatusResponse,
         StatusResponse
     )),
     tags((name = "users", description = "User endpoints")),
@@ -39,20 +60,44 @@ pub struct UserApi;
 
 pub fn router(state: Arc<AppState>) -> Router<Arc<AppState>> {
     let admin_only_layer = middleware::from_fn_with_state(state.clone(), permission_check);
-    let admin_extension_roles = Extension(RequiredRoles(
-        vec!["admin".to_string()],
-        RoleValidation::Higher,
+    let admin_extension_permission = Extension(RequiredPermission(
+        permissions::USERS_MANAGE,
+        PermissionScope::Any,
     ));
 
     Router::new()
         .route("/users/me", get(me).put(update_profile))
         .route("/users/me/password", put(change_password))
+        .route(
+            "/users/me/email/verification",
+            post(send_email_verification),
+        )
+        .route(
+            "/users/me/2fa",
+            get(get_two_factor)
+                .post(enroll_two_factor)
+                .delete(disable_two_factor),
+        )
+        .route("/users/me/2fa/confirm", post(confirm_two_factor))
         .route(
             "/users/{id}",
             put(update_user)
                 .delete(delete_user)
+                .route_layer(admin_only_layer.clone())
+                .route_layer(admin_extension_permission.clone()),
+        )
+        .route(
+            "/users/{id}/reactivate",
+            post(reactivate_user)
+                .route_layer(admin_only_layer.clone())
+                .route_layer(admin_extension_permission.clone()),
+        )
+        .route(
+            "/users/{id}/lockout",
+            get(get_lockout)
+                .delete(unlock_user)
                 .route_layer(admin_only_layer)
-                .route_layer(admin_extension_roles),
+                .route_layer(admin_extension_permission),
         )
 }
 
@@ -69,8 +114,14 @@ pub fn router(state: Arc<AppState>) -> Router<Arc<AppState>> {
     ),
     tag = "users"
 )]
-async fn me(AuthUser(user): AuthUser) -> impl IntoResponse {
-    Json(FullUserResponse::from(user))
+async fn me(
+    AuthUser(user): AuthUser,
+    impersonator: Option<Extension<Impersonator>>,
+) -> impl IntoResponse {
+    let mut response = FullUserResponse::from(user);
+    response.impersonated_by = impersonator.map(|Extension(impersonator)| impersonator.actor_id);
+
+    Json(response)
 }
 
 #[utoipa::path(
@@ -81,6 +132,7 @@ async fn me(AuthUser(user): AuthUser) -> impl IntoResponse {
         (status = 200, description = "Profile updated successfully", body = FullUserResponse),
         (status = 400, description = "Invalid input", body = StatusResponse),
         (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 403, description = "Forbidden - Not allowed while impersonating", body = StatusResponse),
         (status = 500, description = "Internal Server Error", body = StatusResponse)
     ),
     security(
@@ -90,7 +142,7 @@ async fn me(AuthUser(user): AuthUser) -> impl IntoResponse {
 )]
 async fn update_profile(
     State(state): State<Arc<AppState>>,
-    AuthUser(user): AuthUser,
+    AccountOwner(user): AccountOwner,
     ValidatedJson(payload): ValidatedJson<UpdateProfileRequest>,
 ) -> Result<impl IntoResponse> {
     let updated = state
@@ -109,6 +161,7 @@ async fn update_profile(
         (status = 200, description = "Password changed successfully", body = StatusResponse),
         (status = 400, description = "Invalid input", body = StatusResponse),
         (status = 401, description = "Unauthorized / Invalid current password", body = StatusResponse),
+        (status = 403, description = "Forbidden - Not allowed while impersonating", body = StatusResponse),
         (status = 500, description = "Internal Server Error", body = StatusResponse)
     ),
     security(
@@ -118,7 +171,7 @@ async fn update_profile(
 )]
 async fn change_password(
     State(state): State<Arc<AppState>>,
-    AuthUser(user): AuthUser,
+    AccountOwner(user): AccountOwner,
     ValidatedJson(payload): ValidatedJson<ChangePasswordRequest>,
 ) -> Result<impl IntoResponse> {
     state
@@ -136,6 +189,37 @@ async fn change_password(
     ))
 }
 
+#[utoipa::path(
+    post,
+    path = "/users/me/email/verification",
+    responses(
+        (status = 200, description = "Verification email sent", body = StatusResponse),
+        (status = 400, description = "User has no email", body = StatusResponse),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 409, description = "Email already verified", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    security(
+        ("jwt_auth" = [])
+    ),
+    tag = "users"
+)]
+async fn send_email_verification(
+    State(state): State<Arc<AppState>>,
+    AuthUser(user): AuthUser,
+) -> Result<impl IntoResponse> {
+    state
+        .email_verification_service
+        .send_verification(&user)
+        .await?;
+
+    Ok(Json(StatusResponse {
+        success: true,
+        code: 200,
+        message: "Verification email sent".to_string(),
+    }))
+}
+
 #[utoipa::path(
     put,
     path = "/users/{id}",
@@ -185,10 +269,12 @@ async fn update_user(
         ("id" = Uuid, Path, description = "User ID")
     ),
     responses(
-        (status = 200, description = "User deleted successfully", body = StatusResponse),
+        (status = 200, description = "User deactivated, its sessions are revoked", body = StatusResponse),
+        (status = 400, description = "Cannot deactivate your own account", body = StatusResponse),
         (status = 401, description = "Unauthorized", body = StatusResponse),
         (status = 403, description = "Forbidden - Insufficient permissions", body = StatusResponse),
         (status = 404, description = "User not found", body = StatusResponse),
+        (status = 409, description = "User is already deactivated", body = StatusResponse),
         (status = 500, description = "Internal Server Error", body = StatusResponse)
     ),
     security(
@@ -201,14 +287,219 @@ async fn delete_user(
     Path(id): Path<Uuid>,
     AuthUser(requester): AuthUser,
 ) -> Result<impl IntoResponse> {
-    state.user_service.delete_user(&requester, id).await?;
+    state.user_service.deactivate_user(&requester, id).await?;
+
+    Ok((
+        StatusCode::OK,
+        Json(StatusResponse {
+            success: true,
+            code: 200,
+            message: "User deactivated".to_string(),
+        }),
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/users/{id}/reactivate",
+    params(
+        ("id" = Uuid, Path, description = "User ID")
+    ),
+    responses(
+        (status = 200, description = "User reactivated", body = UserResponse),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 403, description = "Forbidden - Insufficient permissions", body = StatusResponse),
+        (status = 404, description = "User not found", body = StatusResponse),
+        (status = 409, description = "User is already active", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    security(
+        ("jwt_auth" = [])
+    ),
+    tag = "users"
+)]
+async fn reactivate_user(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+    AuthUser(requester): AuthUser,
+) -> Result<impl IntoResponse> {
+    let user = state.user_service.reactivate_user(&requester, id).await?;
+
+    Ok(Json(UserResponse::from(user)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/users/{id}/lockout",
+    params(
+        ("id" = Uuid, Path, description = "User ID")
+    ),
+    responses(
+        (status = 200, description = "Failed logins and lockout of the user", body = LoginLockoutResponse),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 403, description = "Forbidden - Insufficient permissions", body = StatusResponse),
+        (status = 404, description = "User not found", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    security(
+        ("jwt_auth" = [])
+    ),
+    tag = "users"
+)]
+async fn get_lockout(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+) -> Result<impl IntoResponse> {
+    let status = state.login_lockout_service.get_status(id).await?;
+
+    Ok(Json(LoginLockoutResponse::from(status)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/users/{id}/lockout",
+    params(
+        ("id" = Uuid, Path, description = "User ID")
+    ),
+    responses(
+        (status = 200, description = "User unlocked successfully", body = StatusResponse),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 403, description = "Forbidden - Insufficient permissions", body = StatusResponse),
+        (status = 404, description = "User not found", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    security(
+        ("jwt_auth" = [])
+    ),
+    tag = "users"
+)]
+async fn unlock_user(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<Uuid>,
+) -> Result<impl IntoResponse> {
+    state.login_lockout_service.unlock(id).await?;
 
     Ok((
         StatusCode::OK,
         Json(StatusResponse {
             success: true,
             code: 200,
-            message: "User deleted successfully".to_string(),
+            message: "User unlocked successfully".to_string(),
         }),
     ))
 }
+
+#[utoipa::path(
+    get,
+    path = "/users/me/2fa",
+    responses(
+        (status = 200, description = "Two-factor authentication status", body = TwoFactorStatusResponse),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    security(
+        ("jwt_auth" = [])
+    ),
+    tag = "users"
+)]
+async fn get_two_factor(
+    State(state): State<Arc<AppState>>,
+    AuthUser(user): AuthUser,
+) -> Result<impl IntoResponse> {
+    let status = state.two_factor_service.get_status(&user).await?;
+
+    Ok(Json(TwoFactorStatusResponse::from(status)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/users/me/2fa",
+    responses(
+        (status = 200, description = "Secret, provisioning URI and recovery codes. Confirm with a code to enable.", body = TwoFactorSetupResponse),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 403, description = "Forbidden - Not allowed while impersonating", body = StatusResponse),
+        (status = 403, description = "Forbidden - Only supervisors and admins can enroll", body = StatusResponse),
+        (status = 409, description = "Two-factor authentication already enabled", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    security(
+        ("jwt_auth" = [])
+    ),
+    tag = "users"
+)]
+async fn enroll_two_factor(
+    State(state): State<Arc<AppState>>,
+    AccountOwner(user): AccountOwner,
+) -> Result<impl IntoResponse> {
+    let setup = state.two_factor_service.start_enrollment(&user).await?;
+
+    Ok(Json(TwoFactorSetupResponse::from(setup)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/users/me/2fa/confirm",
+    request_body = TwoFactorCodeRequest,
+    responses(
+        (status = 200, description = "Two-factor authentication enabled", body = StatusResponse),
+        (status = 400, description = "Invalid code or enrollment not started", body = StatusResponse),
+        (status = 401, description = "Unauthorized", body = StatusResponse),
+        (status = 403, description = "Forbidden - Not allowed while impersonating", body = StatusResponse),
+        (status = 409, description = "Two-factor authentication already enabled", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    security(
+        ("jwt_auth" = [])
+    ),
+    tag = "users"
+)]
+async fn confirm_two_factor(
+    State(state): State<Arc<AppState>>,
+    AccountOwner(user): AccountOwner,
+    ValidatedJson(payload): ValidatedJson<TwoFactorCodeRequest>,
+) -> Result<impl IntoResponse> {
+    state
+        .two_factor_service
+        .confirm_enrollment(&user, &payload.code)
+        .await?;
+
+    Ok(Json(StatusResponse {
+        success: true,
+        code: 200,
+        message: "Two-factor authentication enabled".to_string(),
+    }))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/users/me/2fa",
+    request_body = TwoFactorCodeRequest,
+    responses(
+        (status = 200, description = "Two-factor authentication disabled", body = StatusResponse),
+        (status = 400, description = "Two-factor authentication is mandatory for the role", body = StatusResponse),
+        (status = 401, description = "Unauthorized / Invalid code", body = StatusResponse),
+        (status = 403, description = "Forbidden - Not allowed while impersonating", body = StatusResponse),
+        (status = 404, description = "Two-factor authentication is not enabled", body = StatusResponse),
+        (status = 500, description = "Internal Server Error", body = StatusResponse)
+    ),
+    security(
+        ("jwt_auth" = [])
+    ),
+    tag = "users"
+)]
+async fn disable_two_factor(
+    State(state): State<Arc<AppState>>,
+    AccountOwner(user): AccountOwner,
+    ValidatedJson(payload): ValidatedJson<TwoFactorCodeRequest>,
+) -> Result<impl IntoResponse> {
+    state
+        .two_factor_service
+        .disable(&user, &payload.code)
+        .await?;
+
+    Ok(Json(StatusResponse {
+        success: true,
+        code: 200,
+        message: "Two-factor authentication disabled".to_string(),
+    }))
+}
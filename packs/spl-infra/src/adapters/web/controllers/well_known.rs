@@ -0,0 +1,21 @@
+use crate::adapters::web::state::AppState;
+use axum::{
+    extract::State,
+    http::header,
+    response::IntoResponse,
+    routing::get,
+    Json, Router,
+};
+use std::sync::Arc;
+
+/// Routes served at the root path, outside of the versioned API
+pub fn router() -> Router<Arc<AppState>> {
+    Router::new().route("/.well-known/jwks.json", get(jwks))
+}
+
+async fn jwks(State(state): State<Arc<AppState>>) -> impl IntoResponse {
+    (
+        [(header::CACHE_CONTROL, "public, max-age=300")],
+        Json(state.auth_service.jwks()),
+    )
+}
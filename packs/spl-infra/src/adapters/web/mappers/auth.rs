@@ -0,0 +1,62 @@
+use crate::adapters::web::models::auth::{
+    ForgotPasswordRequest, ResetPasswordRequest, TokenResponse, TwoFactorChallengeResponse,
+    TwoFactorSetupResponse, TwoFactorStatusResponse, VerifyTwoFactorRequest,
+};
+use spl_application::dtos::auth::{
+    AuthTokensDto, ForgotPasswordDto, ResetPasswordDto, TwoFactorChallengeDto, TwoFactorSetupDto,
+    TwoFactorStatusDto, VerifyTwoFactorDto,
+};
+use spl_shared::{map_mirror, maps_to};
+
+map_mirror!(
+    ForgotPasswordRequest,
+    ForgotPasswordDto {
+        username,
+        email,
+        company_id,
+    }
+);
+
+map_mirror!(
+    ResetPasswordRequest,
+    ResetPasswordDto {
+        token,
+        new_password,
+    }
+);
+
+map_mirror!(
+    VerifyTwoFactorRequest,
+    VerifyTwoFactorDto {
+        challenge_token,
+        code,
+    }
+);
+
+maps_to!(TwoFactorChallengeResponse {
+    challenge_token,
+    expires_in,
+    setup_required,
+} #from [TwoFactorChallengeDto]);
+
+maps_to!(TwoFactorSetupResponse {
+    secret,
+    provisioning_uri,
+    recovery_codes,
+} #from [TwoFactorSetupDto]);
+
+maps_to!(TwoFactorStatusResponse {
+    enabled,
+    required,
+    recovery_codes_remaining,
+} #from [TwoFactorStatusDto]);
+
+impl From<AuthTokensDto> for TokenResponse {
+    fn from(dto: AuthTokensDto) -> Self {
+        Self {
+            token: dto.access_token,
+            refresh_token: dto.refresh_token,
+            expires_in: dto.expires_in,
+        }
+    }
+}
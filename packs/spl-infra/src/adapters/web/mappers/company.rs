@@ -5,9 +5,23 @@ use spl_application::dtos::company::{CreateCompanyDto, UpdateCompanyDto};
 use spl_domain::entities::company::Company;
 use spl_shared::{map_mirror, maps_to};
 
-map_mirror!(CreateCompanyRequest, CreateCompanyDto { name, description });
+map_mirror!(
+    CreateCompanyRequest,
+    CreateCompanyDto {
+        name,
+        description,
+        parent_id
+    }
+);
 
-map_mirror!(UpdateCompanyRequest, UpdateCompanyDto { name, description });
+map_mirror!(
+    UpdateCompanyRequest,
+    UpdateCompanyDto {
+        name,
+        description,
+        two_factor_required_level
+    }
+);
 
 map_mirror!(
     CompanyResponse,
@@ -15,6 +29,8 @@ map_mirror!(
         id,
         name,
         description,
+        parent_id,
+        two_factor_required_level,
         created_at,
         updated_at,
     }
@@ -0,0 +1,36 @@
+use crate::adapters::web::models::company_settings::{
+    CompanySettingsRequest, CompanySettingsResponse,
+};
+use spl_application::dtos::company::UpdateCompanySettingsDto;
+use spl_domain::entities::company::CompanySettings;
+use spl_shared::maps_to;
+
+maps_to!(UpdateCompanySettingsDto {
+    timezone,
+    default_language,
+    data_retention_days,
+    two_factor_required_level,
+    default_page_size,
+    allowed_upload_formats,
+    alert_severity_threshold,
+    alert_confidence_threshold
+} #from [ CompanySettingsRequest ]);
+
+impl From<CompanySettings> for CompanySettingsResponse {
+    fn from(settings: CompanySettings) -> Self {
+        Self {
+            timezone: settings.timezone,
+            default_language: settings.default_language,
+            data_retention_days: settings.data_retention_days,
+            two_factor_required_level: settings.two_factor_required_level,
+            default_page_size: settings.default_page_size,
+            allowed_upload_formats: settings
+                .allowed_upload_formats
+                .iter()
+                .map(|format| format.as_str().to_string())
+                .collect(),
+            alert_severity_threshold: settings.alert_severity_threshold,
+            alert_confidence_threshold: settings.alert_confidence_threshold,
+        }
+    }
+}
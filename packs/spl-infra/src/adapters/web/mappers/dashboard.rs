@@ -24,6 +24,7 @@ map_mirror!(
         max_date,
         plot_ids,
         labels,
+        model_versions,
     }
 );
 
@@ -35,6 +36,7 @@ map_mirror!(
         max_date,
         plot_ids,
         labels,
+        model_versions,
         last_n,
     }
 );
@@ -47,6 +49,7 @@ map_mirror!(
         min_date,
         max_date,
         labels,
+        model_versions,
     }
 );
 
@@ -56,6 +59,7 @@ impl From<DashboardSummaryFilters> for DashboardFiltersResponse {
             labels: value.labels.into_iter().map(Into::into).collect(),
             plots: value.plots.into_iter().map(Into::into).collect(),
             users: value.users.into_iter().map(Into::into).collect(),
+            model_versions: value.model_versions,
         }
     }
 }
@@ -66,6 +70,7 @@ impl From<DashboardSummaryFilters> for SimplifiedDashboardFiltersResponse {
             labels: value.labels.into_iter().map(Into::into).collect(),
             plots: value.plots.into_iter().map(Into::into).collect(),
             users: value.users.into_iter().map(Into::into).collect(),
+            model_versions: value.model_versions,
         }
     }
 }
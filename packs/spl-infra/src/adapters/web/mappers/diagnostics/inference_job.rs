@@ -0,0 +1,25 @@
+use crate::adapters::web::models::diagnostics::inference_job::InferenceJobResponse;
+use spl_domain::entities::diagnostics::{InferenceJob, JobStatus};
+
+impl From<InferenceJob> for InferenceJobResponse {
+    fn from(job: InferenceJob) -> Self {
+        // Chosen before predicting, the prediction exists once the job completed
+        let prediction_id = job
+            .prediction_id
+            .filter(|_| job.status == JobStatus::Completed);
+
+        Self {
+            id: job.id,
+            status: job.status.as_str().to_string(),
+            filename: job.filename,
+            batch_item_id: job.batch_item_id,
+            attempts: job.attempts,
+            prediction_id,
+            error: job.error,
+            run_after: job.run_after,
+            created_at: job.created_at,
+            updated_at: job.updated_at,
+            completed_at: job.completed_at,
+        }
+    }
+}
@@ -1,4 +1,6 @@
+mod inference_job;
 pub mod label;
 pub mod mark_type;
 pub mod prediction;
+mod prediction_batch;
 mod prediction_mark;
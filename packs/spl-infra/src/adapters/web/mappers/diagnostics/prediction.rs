@@ -1,14 +1,25 @@
 use crate::adapters::web::models::diagnostics::{
-    FilterPredictionsRequest, PredictionDetailedResponse, PredictionResponse,
-    RawPredictionResponse, SimplifiedPredictionDetailedResponse, SimplifiedPredictionResponse,
+    FilterPredictionsRequest, PredictionDetailedResponse, PredictionModelResponse,
+    PredictionResponse, PredictionsListResponse, RawPredictionResponse,
+    SimplifiedPredictionDetailedResponse, SimplifiedPredictionResponse,
 };
-use spl_application::dtos::diagnostics::FilterPredictionDto;
+use spl_application::dtos::diagnostics::{FilterPredictionDto, PaginatedPredictions};
 use spl_domain::entities::diagnostics::prediction::{PredictionDetailed, RawPrediction};
-use spl_domain::entities::diagnostics::Prediction;
+use spl_domain::entities::diagnostics::{Prediction, PredictionModel};
 use spl_domain::entities::user::User;
 use spl_shared::error::AppError;
+use spl_shared::maps_to;
 use spl_shared::traits::IntoWithContext;
 
+maps_to!(PredictionModelResponse {
+    name,
+    version,
+    provider,
+    image_size,
+    leaf_threshold,
+    lesion_threshold,
+} #from [PredictionModel]);
+
 impl From<Prediction> for PredictionResponse {
     fn from(param: Prediction) -> Self {
         Self {
@@ -23,6 +34,7 @@ impl From<Prediction> for PredictionResponse {
             label: param.label.into(),
             marks: param.marks.into_iter().map(Into::into).collect(),
             feedback: param.feedback.map(Into::into),
+            model: param.model.map(Into::into),
         }
     }
 }
@@ -45,6 +57,7 @@ impl IntoWithContext<FilterPredictionDto, FilterPredictionMapperContext>
             company_id: self.company_id,
             target_user_ids: self.user_ids,
             labels: self.labels,
+            model_versions: self.model_versions,
             plot_ids: self.plot_ids,
             min_date: self.min_date,
             max_date: self.max_date,
@@ -54,6 +67,17 @@ impl IntoWithContext<FilterPredictionDto, FilterPredictionMapperContext>
     }
 }
 
+impl From<PaginatedPredictions> for PredictionsListResponse {
+    fn from(paginated: PaginatedPredictions) -> Self {
+        Self {
+            total: paginated.total,
+            page: paginated.page,
+            limit: paginated.limit,
+            items: paginated.items.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
 impl From<Prediction> for SimplifiedPredictionResponse {
     fn from(param: Prediction) -> Self {
         Self {
@@ -82,6 +106,7 @@ impl From<RawPrediction> for RawPredictionResponse {
             image: value.image.into(),
             label: value.label.into(),
             marks: value.marks.into_iter().map(Into::into).collect(),
+            model: value.model.into(),
         }
     }
 }
@@ -100,6 +125,7 @@ impl From<PredictionDetailed> for PredictionDetailedResponse {
             marks: param.marks.into_iter().map(Into::into).collect(),
             created_at: param.created_at,
             feedback: param.feedback.map(Into::into),
+            model: param.model.map(Into::into),
             recommendations: param.recommendations.into_iter().map(Into::into).collect(),
         }
     }
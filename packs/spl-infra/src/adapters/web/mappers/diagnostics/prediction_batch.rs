@@ -0,0 +1,44 @@
+use crate::adapters::web::models::diagnostics::prediction_batch::{
+    PredictionBatchDetailResponse, PredictionBatchItemResponse, PredictionBatchResponse,
+};
+use spl_application::dtos::diagnostics::PredictionBatchDetailDto;
+use spl_domain::entities::diagnostics::{PredictionBatch, PredictionBatchItem};
+
+impl From<PredictionBatch> for PredictionBatchResponse {
+    fn from(batch: PredictionBatch) -> Self {
+        Self {
+            id: batch.id,
+            status: batch.status.as_str().to_string(),
+            items_total: batch.items_total,
+            items_completed: batch.items_completed,
+            items_failed: batch.items_failed,
+            items_pending: batch.items_pending(),
+            created_at: batch.created_at,
+            updated_at: batch.updated_at,
+            completed_at: batch.completed_at,
+        }
+    }
+}
+
+impl From<PredictionBatchItem> for PredictionBatchItemResponse {
+    fn from(item: PredictionBatchItem) -> Self {
+        Self {
+            id: item.id,
+            position: item.position,
+            filename: item.filename,
+            status: item.status.as_str().to_string(),
+            prediction_id: item.prediction_id,
+            error: item.error,
+            updated_at: item.updated_at,
+        }
+    }
+}
+
+impl From<PredictionBatchDetailDto> for PredictionBatchDetailResponse {
+    fn from(dto: PredictionBatchDetailDto) -> Self {
+        Self {
+            batch: dto.batch.into(),
+            items: dto.items.into_iter().map(Into::into).collect(),
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use crate::adapters::web::models::impersonation::{
+    ImpersonationActionResponse, ImpersonationAuditResponse, ImpersonationResponse,
+    ImpersonationTokenResponse,
+};
+use spl_application::dtos::auth::{ImpersonationAuditDto, ImpersonationTokenDto};
+use spl_domain::entities::auth::{Impersonation, ImpersonationAction};
+
+impl From<Impersonation> for ImpersonationResponse {
+    fn from(impersonation: Impersonation) -> Self {
+        Self {
+            active: impersonation.is_active(),
+            id: impersonation.id,
+            actor_id: impersonation.actor_id,
+            subject_id: impersonation.subject_id,
+            reason: impersonation.reason,
+            ip_address: impersonation.ip_address,
+            started_at: impersonation.started_at,
+            expires_at: impersonation.expires_at,
+            ended_at: impersonation.ended_at,
+        }
+    }
+}
+
+impl From<ImpersonationTokenDto> for ImpersonationTokenResponse {
+    fn from(dto: ImpersonationTokenDto) -> Self {
+        Self {
+            token: dto.access_token,
+            expires_in: dto.expires_in,
+            impersonated: true,
+            impersonation: dto.impersonation.into(),
+        }
+    }
+}
+
+impl From<ImpersonationAction> for ImpersonationActionResponse {
+    fn from(action: ImpersonationAction) -> Self {
+        Self {
+            method: action.method,
+            path: action.path,
+            status_code: action.status_code,
+            created_at: action.created_at,
+        }
+    }
+}
+
+impl From<ImpersonationAuditDto> for ImpersonationAuditResponse {
+    fn from(dto: ImpersonationAuditDto) -> Self {
+        Self {
+            impersonation: dto.impersonation.into(),
+            actions: dto.actions.into_iter().map(Into::into).collect(),
+        }
+    }
+}
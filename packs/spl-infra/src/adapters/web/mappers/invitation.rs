@@ -0,0 +1,43 @@
+use crate::adapters::web::models::invitation::{
+    AcceptInvitationRequest, CreateInvitationRequest, InvitationResponse,
+};
+use spl_application::dtos::user::{AcceptInvitationDto, CreateInvitationDto};
+use spl_domain::entities::user::Invitation;
+use spl_shared::map_mirror;
+
+map_mirror!(
+    CreateInvitationRequest,
+    CreateInvitationDto {
+        email,
+        role,
+        company_id
+    }
+);
+
+map_mirror!(
+    AcceptInvitationRequest,
+    AcceptInvitationDto {
+        token,
+        username,
+        password,
+        name,
+        surname
+    }
+);
+
+impl From<Invitation> for InvitationResponse {
+    fn from(invitation: Invitation) -> Self {
+        Self {
+            status: invitation.status().as_str().to_string(),
+            id: invitation.id,
+            company_id: invitation.company_id,
+            email: invitation.email,
+            role_id: invitation.role_id,
+            invited_by: invitation.invited_by,
+            expires_at: invitation.expires_at,
+            accepted_at: invitation.accepted_at,
+            cancelled_at: invitation.cancelled_at,
+            created_at: invitation.created_at,
+        }
+    }
+}
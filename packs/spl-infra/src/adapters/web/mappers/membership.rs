@@ -0,0 +1,42 @@
+use crate::adapters::web::models::membership::{
+    MembershipResponse, SetMembershipRequest, UserMembershipResponse,
+};
+use spl_application::dtos::user::{SetMembershipDto, UserMembershipDto};
+use spl_domain::entities::user::CompanyMembership;
+use spl_shared::error::{AppError, Result};
+use spl_shared::traits::IntoWithContext;
+use uuid::Uuid;
+
+impl From<SetMembershipRequest> for SetMembershipDto {
+    fn from(request: SetMembershipRequest) -> Self {
+        Self { role: request.role }
+    }
+}
+
+impl From<CompanyMembership> for MembershipResponse {
+    fn from(membership: CompanyMembership) -> Self {
+        Self {
+            user_id: membership.user_id,
+            company: membership.company.into(),
+            role: membership.role.into(),
+            created_at: membership.created_at,
+        }
+    }
+}
+
+/// The context is the company the request acts in
+impl IntoWithContext<UserMembershipResponse, Option<Uuid>> for UserMembershipDto {
+    type Error = AppError;
+
+    fn into_with_context(self, active_company_id: Option<Uuid>) -> Result<UserMembershipResponse> {
+        let membership = self.membership;
+
+        Ok(UserMembershipResponse {
+            active: Some(membership.company.id) == active_company_id,
+            company: membership.company.into(),
+            role: membership.role.into(),
+            home: self.home,
+            created_at: membership.created_at,
+        })
+    }
+}
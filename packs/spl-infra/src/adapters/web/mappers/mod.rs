@@ -1,8 +1,21 @@
+pub mod auth;
 pub mod company;
+pub mod company_settings;
 pub mod dashboard;
 pub mod diagnostics;
 pub mod feedback;
 pub mod image;
+pub mod impersonation;
+pub mod invitation;
+pub mod membership;
+pub mod offboarding;
+pub mod password_policy;
 pub mod plot;
 pub mod recommendation;
+pub mod role;
+pub mod service_account;
+pub mod session;
+pub mod sso;
+pub mod team;
+pub mod usage;
 pub mod user;
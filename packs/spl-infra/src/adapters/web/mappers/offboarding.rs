@@ -0,0 +1,45 @@
+use crate::adapters::web::models::offboarding::{
+    OffboardingAuditResponse, OffboardingEventResponse, OffboardingResponse,
+};
+use spl_application::dtos::offboarding::OffboardingAuditDto;
+use spl_domain::entities::offboarding::{CompanyOffboarding, OffboardingEvent};
+
+impl From<CompanyOffboarding> for OffboardingResponse {
+    fn from(offboarding: CompanyOffboarding) -> Self {
+        Self {
+            id: offboarding.id,
+            company_id: offboarding.company_id,
+            company_name: offboarding.company_name,
+            requested_by: offboarding.requested_by,
+            status: offboarding.status.as_str().to_string(),
+            export_ready: offboarding.export_path.is_some(),
+            export_size: offboarding.export_size,
+            users_total: offboarding.users_total,
+            users_deleted: offboarding.users_deleted,
+            error: offboarding.error,
+            created_at: offboarding.created_at,
+            updated_at: offboarding.updated_at,
+            completed_at: offboarding.completed_at,
+        }
+    }
+}
+
+impl From<OffboardingEvent> for OffboardingEventResponse {
+    fn from(event: OffboardingEvent) -> Self {
+        Self {
+            actor_id: event.actor_id,
+            action: event.action,
+            detail: event.detail,
+            created_at: event.created_at,
+        }
+    }
+}
+
+impl From<OffboardingAuditDto> for OffboardingAuditResponse {
+    fn from(dto: OffboardingAuditDto) -> Self {
+        Self {
+            offboarding: dto.offboarding.into(),
+            events: dto.events.into_iter().map(Into::into).collect(),
+        }
+    }
+}
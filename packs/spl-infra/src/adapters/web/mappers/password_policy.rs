@@ -0,0 +1,35 @@
+use crate::adapters::web::models::password_policy::{
+    PasswordPolicyRequest, PasswordPolicyResponse,
+};
+use spl_domain::entities::auth::{CompanyPasswordPolicy, PasswordPolicy};
+
+impl From<PasswordPolicyRequest> for PasswordPolicy {
+    fn from(request: PasswordPolicyRequest) -> Self {
+        Self {
+            min_length: request.min_length,
+            require_uppercase: request.require_uppercase,
+            require_lowercase: request.require_lowercase,
+            require_digit: request.require_digit,
+            require_symbol: request.require_symbol,
+            history_size: request.history_size,
+        }
+    }
+}
+
+impl From<CompanyPasswordPolicy> for PasswordPolicyResponse {
+    fn from(company_policy: CompanyPasswordPolicy) -> Self {
+        let policy = company_policy.policy;
+
+        Self {
+            company_id: company_policy.company_id,
+            min_length: policy.min_length,
+            require_uppercase: policy.require_uppercase,
+            require_lowercase: policy.require_lowercase,
+            require_digit: policy.require_digit,
+            require_symbol: policy.require_symbol,
+            history_size: policy.history_size,
+            created_at: company_policy.created_at,
+            updated_at: company_policy.updated_at,
+        }
+    }
+}
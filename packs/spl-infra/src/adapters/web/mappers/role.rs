@@ -0,0 +1,81 @@
+use crate::adapters::web::models::role::{
+    CreateRoleRequest, DetailedRoleResponse, GrantScope, PermissionGrantRequest,
+    PermissionGrantResponse, UpdateRoleRequest,
+};
+use spl_application::dtos::user::{CreateRoleDto, UpdateRoleDto};
+use spl_domain::entities::user::{DetailedRole, PermissionGrant, PermissionScope};
+
+impl From<GrantScope> for PermissionScope {
+    fn from(scope: GrantScope) -> Self {
+        match scope {
+            GrantScope::Own => Self::Own,
+            GrantScope::Team => Self::Team,
+            GrantScope::Company => Self::Company,
+            GrantScope::Any => Self::Any,
+        }
+    }
+}
+
+impl From<PermissionScope> for GrantScope {
+    fn from(scope: PermissionScope) -> Self {
+        match scope {
+            PermissionScope::Own => Self::Own,
+            PermissionScope::Team => Self::Team,
+            PermissionScope::Company => Self::Company,
+            PermissionScope::Any => Self::Any,
+        }
+    }
+}
+
+impl From<PermissionGrantRequest> for PermissionGrant {
+    fn from(request: PermissionGrantRequest) -> Self {
+        Self {
+            permission: request.permission,
+            scope: request.scope.into(),
+        }
+    }
+}
+
+impl From<PermissionGrant> for PermissionGrantResponse {
+    fn from(grant: PermissionGrant) -> Self {
+        Self {
+            permission: grant.permission,
+            scope: grant.scope.into(),
+        }
+    }
+}
+
+impl From<CreateRoleRequest> for CreateRoleDto {
+    fn from(request: CreateRoleRequest) -> Self {
+        Self {
+            name: request.name,
+            level: request.level,
+            permissions: request.permissions.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<UpdateRoleRequest> for UpdateRoleDto {
+    fn from(request: UpdateRoleRequest) -> Self {
+        Self {
+            name: request.name,
+            level: request.level,
+            permissions: request
+                .permissions
+                .map(|grants| grants.into_iter().map(Into::into).collect()),
+        }
+    }
+}
+
+impl From<DetailedRole> for DetailedRoleResponse {
+    fn from(detailed: DetailedRole) -> Self {
+        Self {
+            id: detailed.role.id,
+            name: detailed.role.name,
+            level: detailed.role.level,
+            permissions: detailed.permissions.into_iter().map(Into::into).collect(),
+            created_at: detailed.role.created_at,
+            updated_at: detailed.role.updated_at,
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use crate::adapters::web::models::service_account::{
+    ApiKeyResponse, CreateApiKeyRequest, CreateServiceAccountRequest, CreatedApiKeyResponse,
+    ServiceAccountResponse,
+};
+use spl_application::dtos::service_account::{
+    CreateApiKeyDto, CreateServiceAccountDto, CreatedApiKeyDto,
+};
+use spl_domain::entities::auth::{ApiKey, ServiceAccount};
+use spl_shared::{map_mirror, maps_to};
+
+map_mirror!(
+    CreateServiceAccountRequest,
+    CreateServiceAccountDto {
+        name,
+        description,
+        company_id
+    }
+);
+
+map_mirror!(
+    CreateApiKeyRequest,
+    CreateApiKeyDto {
+        name,
+        scopes,
+        expires_at
+    }
+);
+
+maps_to!(ServiceAccountResponse {
+    id,
+    company_id,
+    name,
+    description,
+    created_by,
+    created_at,
+} #from [ServiceAccount]);
+
+maps_to!(ApiKeyResponse {
+    id,
+    service_account_id,
+    name,
+    key_prefix,
+    scopes,
+    expires_at,
+    last_used_at,
+    revoked_at,
+    created_at,
+} #from [ApiKey]);
+
+maps_to!(CreatedApiKeyResponse {
+    key,
+    #into [api_key]
+} #from [CreatedApiKeyDto]);
@@ -0,0 +1,18 @@
+use crate::adapters::web::models::session::SessionResponse;
+use spl_domain::entities::auth::Session;
+use uuid::Uuid;
+
+impl SessionResponse {
+    /// `current_session` is the session of the request, if it was authenticated with one
+    pub fn from_session(session: Session, current_session: Option<Uuid>) -> Self {
+        Self {
+            current: current_session == Some(session.id),
+            id: session.id,
+            user_agent: session.user_agent,
+            ip_address: session.ip_address,
+            created_at: session.created_at,
+            last_seen_at: session.last_seen_at,
+            expires_at: session.expires_at,
+        }
+    }
+}
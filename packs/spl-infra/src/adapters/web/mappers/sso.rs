@@ -0,0 +1,42 @@
+use crate::adapters::web::models::sso::{
+    IdentityProviderRequest, IdentityProviderResponse, SsoCallbackRequest,
+};
+use spl_application::dtos::sso::{ConfigureIdentityProviderDto, SsoCallbackDto};
+use spl_domain::entities::auth::IdentityProvider;
+use spl_shared::map_mirror;
+
+map_mirror!(
+    IdentityProviderRequest,
+    ConfigureIdentityProviderDto {
+        issuer,
+        client_id,
+        client_secret,
+        scopes,
+        role_claim,
+        role_mapping,
+        default_role,
+        auto_provision,
+        enabled
+    }
+);
+
+map_mirror!(SsoCallbackRequest, SsoCallbackDto { code, state });
+
+impl From<IdentityProvider> for IdentityProviderResponse {
+    fn from(provider: IdentityProvider) -> Self {
+        Self {
+            company_id: provider.company_id,
+            issuer: provider.issuer,
+            client_id: provider.client_id,
+            has_client_secret: provider.client_secret.is_some(),
+            scopes: provider.scopes,
+            role_claim: provider.role_claim,
+            role_mapping: provider.role_mapping,
+            default_role: provider.default_role,
+            auto_provision: provider.auto_provision,
+            enabled: provider.enabled,
+            created_at: provider.created_at,
+            updated_at: provider.updated_at,
+        }
+    }
+}
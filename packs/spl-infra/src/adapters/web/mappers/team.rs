@@ -0,0 +1,83 @@
+use crate::adapters::web::models::team::{
+    CreateTeamRequest, DetailedTeamResponse, SetTeamMemberRequest, TeamMemberResponse,
+    TeamResponse, TeamRoleRequest, UpdateTeamRequest,
+};
+use spl_application::dtos::team::{CreateTeamDto, SetTeamMemberDto, TeamDetailsDto, UpdateTeamDto};
+use spl_domain::entities::team::{Team, TeamMember, TeamRole};
+use spl_shared::error::{AppError, Result};
+use spl_shared::traits::IntoWithContext;
+use uuid::Uuid;
+
+impl From<TeamRoleRequest> for TeamRole {
+    fn from(role: TeamRoleRequest) -> Self {
+        match role {
+            TeamRoleRequest::Member => TeamRole::Member,
+            TeamRoleRequest::Supervisor => TeamRole::Supervisor,
+        }
+    }
+}
+
+impl IntoWithContext<CreateTeamDto, Uuid> for CreateTeamRequest {
+    type Error = AppError;
+
+    fn into_with_context(self, company_id: Uuid) -> Result<CreateTeamDto> {
+        Ok(CreateTeamDto {
+            company_id,
+            name: self.name,
+            description: self.description,
+        })
+    }
+}
+
+impl From<UpdateTeamRequest> for UpdateTeamDto {
+    fn from(request: UpdateTeamRequest) -> Self {
+        Self {
+            name: request.name,
+            description: request.description,
+        }
+    }
+}
+
+impl IntoWithContext<SetTeamMemberDto, Uuid> for SetTeamMemberRequest {
+    type Error = AppError;
+
+    fn into_with_context(self, user_id: Uuid) -> Result<SetTeamMemberDto> {
+        Ok(SetTeamMemberDto {
+            user_id,
+            role: self.role.into(),
+        })
+    }
+}
+
+impl From<Team> for TeamResponse {
+    fn from(team: Team) -> Self {
+        Self {
+            id: team.id,
+            company_id: team.company_id,
+            name: team.name,
+            description: team.description,
+            created_at: team.created_at,
+            updated_at: team.updated_at,
+        }
+    }
+}
+
+impl From<TeamMember> for TeamMemberResponse {
+    fn from(member: TeamMember) -> Self {
+        Self {
+            user_id: member.user_id,
+            role: member.role.as_str().to_string(),
+            created_at: member.created_at,
+        }
+    }
+}
+
+impl From<TeamDetailsDto> for DetailedTeamResponse {
+    fn from(dto: TeamDetailsDto) -> Self {
+        Self {
+            team: dto.team.into(),
+            members: dto.members.into_iter().map(Into::into).collect(),
+            plot_ids: dto.plot_ids,
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use crate::adapters::web::models::usage::{
+    CompanyQuotaRequest, QuotaResponse, UsageReportResponse, UserUsageResponse,
+};
+use chrono::NaiveDate;
+use spl_application::dtos::usage::{UpdateCompanyQuotaDto, UsageReport, UserUsageReport};
+use spl_domain::entities::usage::{CompanyQuota, UsageQuota};
+use spl_shared::error::{AppError, Result};
+use spl_shared::maps_to;
+
+maps_to!(UpdateCompanyQuotaDto {
+    predictions_per_month,
+    predictions_per_user_per_month,
+    stored_bytes_per_month,
+    warning_ratio,
+    enforcement
+} #from [ CompanyQuotaRequest ]);
+
+/// First day of a `YYYY-MM` month
+pub fn parse_month(month: &str) -> Result<NaiveDate> {
+    NaiveDate::parse_from_str(&format!("{}-01", month), "%Y-%m-%d").map_err(|_| {
+        AppError::ValidationError(format!("Invalid month {}, expected YYYY-MM", month))
+    })
+}
+
+impl From<UsageQuota> for QuotaResponse {
+    fn from(quota: UsageQuota) -> Self {
+        Self {
+            predictions_per_month: quota.predictions_per_month,
+            predictions_per_user_per_month: quota.predictions_per_user_per_month,
+            stored_bytes_per_month: quota.stored_bytes_per_month,
+            warning_ratio: quota.warning_ratio,
+            enforcement: quota.enforcement.as_str().to_string(),
+        }
+    }
+}
+
+impl From<CompanyQuota> for QuotaResponse {
+    fn from(company: CompanyQuota) -> Self {
+        company.quota.into()
+    }
+}
+
+impl From<UserUsageReport> for UserUsageResponse {
+    fn from(report: UserUsageReport) -> Self {
+        Self {
+            user_id: report.user_id,
+            predictions: report.usage.predictions,
+            stored_bytes: report.usage.stored_bytes,
+            status: report.status.as_str().to_string(),
+        }
+    }
+}
+
+impl From<UsageReport> for UsageReportResponse {
+    fn from(report: UsageReport) -> Self {
+        Self {
+            company_id: report.company_id,
+            month: report.period.format("%Y-%m").to_string(),
+            resets_at: report.resets_at,
+            predictions: report.usage.predictions,
+            stored_bytes: report.usage.stored_bytes,
+            status: report.status.as_str().to_string(),
+            quota: report.quota.into(),
+            users: report.users.into_iter().map(Into::into).collect(),
+        }
+    }
+}
@@ -2,13 +2,14 @@ use crate::adapters::web::models::user::SimplifiedUserResponse;
 use crate::adapters::web::models::{
     auth::{LoginRequest, RegisterRequest},
     user::{
-        ChangePasswordRequest, FullUserResponse, RoleResponse, SimplifiedRoleResponse,
-        UpdateProfileRequest, UpdateUserRequest, UserResponse,
+        ChangePasswordRequest, FullUserResponse, LoginLockoutResponse, RoleResponse,
+        SimplifiedRoleResponse, UpdateProfileRequest, UpdateUserRequest, UserResponse,
     },
 };
 use spl_application::dtos::user::{
     ChangePasswordDto, CreateUserDto, LoginDto, UpdateProfileDto, UpdateUserDto,
 };
+use spl_domain::entities::auth::LoginAttempts;
 use spl_domain::entities::user::{Role, User};
 use spl_shared::map_mirror;
 
@@ -79,14 +80,18 @@ impl From<User> for SimplifiedUserResponse {
 
 impl From<User> for UserResponse {
     fn from(user: User) -> Self {
+        let email_verified = user.is_email_verified();
+
         Self {
             id: user.id,
             username: user.username,
             email: user.email,
+            email_verified,
             name: user.name,
             surname: user.surname,
             role: user.role.name,
             company_id: user.company.map(|c| c.id),
+            deactivated_at: user.deactivated_at,
             created_at: user.created_at,
         }
     }
@@ -94,15 +99,20 @@ impl From<User> for UserResponse {
 
 impl From<User> for FullUserResponse {
     fn from(user: User) -> Self {
+        let email_verified = user.is_email_verified();
+
         Self {
             id: user.id,
             username: user.username,
             email: user.email,
+            email_verified,
             name: user.name,
             surname: user.surname,
             role: user.role.name,
             company: user.company.map(|c| c.into()),
+            deactivated_at: user.deactivated_at,
             created_at: user.created_at,
+            impersonated_by: None,
         }
     }
 }
@@ -125,3 +135,15 @@ impl From<Role> for SimplifiedRoleResponse {
         }
     }
 }
+
+impl From<LoginAttempts> for LoginLockoutResponse {
+    fn from(attempts: LoginAttempts) -> Self {
+        Self {
+            user_id: attempts.user_id,
+            locked: attempts.is_locked(),
+            locked_until: attempts.locked_until,
+            failed_attempts: attempts.failed_attempts,
+            lockout_count: attempts.lockout_count,
+        }
+    }
+}
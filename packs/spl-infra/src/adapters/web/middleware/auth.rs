@@ -1,15 +1,97 @@
+use crate::adapters::web::middleware::impersonation::ImpersonationSlot;
 use crate::adapters::web::state::AppState;
 use axum::{
-    extract::FromRequestParts,
+    extract::{ConnectInfo, FromRequestParts},
     http::{header, request::Parts},
     response::{IntoResponse, Response},
 };
+use spl_application::dtos::auth::ClientInfoDto;
 use spl_domain::entities::user::User;
 use spl_shared::error::AppError;
+use spl_shared::http::middleware::client_ip;
+use std::convert::Infallible;
+use std::net::SocketAddr;
 use std::sync::Arc;
+use uuid::Uuid;
+
+/// Header carrying the API key of a service account
+pub const API_KEY_HEADER: &str = "x-api-key";
+
+/// Scope an API key needs to reach a route. Routes without it only accept JWTs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RequiredScope(pub &'static str);
+
+/// Longest `User-Agent` kept on a session
+const MAX_USER_AGENT_LENGTH: usize = 512;
 
 pub struct AuthUser(pub User);
 
+/// Session of the access token that authenticated the request. Set by `AuthUser`,
+/// missing for API keys.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CurrentSession(pub Uuid);
+
+/// Administrator acting as the authenticated user. Set by `AuthUser` for tokens
+/// issued by an impersonation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Impersonator {
+    pub impersonation_id: Uuid,
+    pub actor_id: Uuid,
+}
+
+impl Impersonator {
+    /// Reads the `imp` and `act` claims, absent for regular tokens
+    pub fn from_claims(claims: &serde_json::Value) -> Result<Option<Self>, AppError> {
+        let Some(imp) = claims["imp"].as_str() else {
+            return Ok(None);
+        };
+
+        let invalid = || AppError::AuthError("Invalid impersonation claims".to_string());
+
+        let impersonation_id = Uuid::parse_str(imp).map_err(|_| invalid())?;
+        let actor_id = claims["act"]["sub"]
+            .as_str()
+            .and_then(|sub| Uuid::parse_str(sub).ok())
+            .ok_or_else(invalid)?;
+
+        Ok(Some(Self {
+            impersonation_id,
+            actor_id,
+        }))
+    }
+}
+
+/// Authenticated user acting on its own behalf. Rejects impersonation tokens, for routes
+/// handling credentials that must stay with the user.
+pub struct AccountOwner(pub User);
+
+/// Client of the request, recorded on the sessions it opens
+pub struct ClientInfo(pub ClientInfoDto);
+
+impl<S: Send + Sync> FromRequestParts<S> for ClientInfo {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let user_agent = parts
+            .headers
+            .get(header::USER_AGENT)
+            .and_then(|value| value.to_str().ok())
+            .map(|value| value.chars().take(MAX_USER_AGENT_LENGTH).collect());
+
+        Ok(ClientInfo(ClientInfoDto {
+            user_agent,
+            ip_address: request_ip(parts),
+        }))
+    }
+}
+
+fn request_ip(parts: &Parts) -> Option<String> {
+    client_ip(
+        &parts.headers,
+        parts.extensions.get::<ConnectInfo<SocketAddr>>(),
+    )
+}
+
 impl FromRequestParts<Arc<AppState>> for AuthUser {
     type Rejection = Response;
 
@@ -17,6 +99,14 @@ impl FromRequestParts<Arc<AppState>> for AuthUser {
         parts: &mut Parts,
         state: &Arc<AppState>,
     ) -> Result<Self, Self::Rejection> {
+        if let Some(key) = parts.headers.get(API_KEY_HEADER) {
+            let key = key.to_str().map_err(|_| {
+                AppError::AuthError("Invalid API key".to_string()).into_response()
+            })?;
+
+            return authenticate_api_key(parts, state, key).await;
+        }
+
         let auth_header = parts
             .headers
             .get(header::AUTHORIZATION)
@@ -48,6 +138,49 @@ impl FromRequestParts<Arc<AppState>> for AuthUser {
             AppError::AuthError("Invalid user id in token".to_string()).into_response()
         })?;
 
+        // Impersonation tokens are rejected once the impersonation ends
+        if let Some(impersonator) =
+            Impersonator::from_claims(&claims).map_err(|e| e.into_response())?
+        {
+            let active = state
+                .impersonation_service
+                .check_active(impersonator.impersonation_id, impersonator.actor_id)
+                .await
+                .map_err(|e| e.into_response())?;
+
+            if !active {
+                return Err(
+                    AppError::AuthError("Impersonation has ended".to_string()).into_response(),
+                );
+            }
+
+            if let Some(slot) = parts.extensions.get::<ImpersonationSlot>() {
+                slot.set(impersonator);
+            }
+            parts.extensions.insert(impersonator);
+        }
+
+        // Tokens bound to a session are rejected once the session is revoked
+        if let Some(sid) = claims["sid"].as_str() {
+            let session_id = uuid::Uuid::parse_str(sid).map_err(|_| {
+                AppError::AuthError("Invalid session id in token".to_string()).into_response()
+            })?;
+
+            let active = state
+                .auth_service
+                .check_session(session_id, request_ip(parts))
+                .await
+                .map_err(|e| e.into_response())?;
+
+            if !active {
+                return Err(
+                    AppError::AuthError("Session has been revoked".to_string()).into_response(),
+                );
+            }
+
+            parts.extensions.insert(CurrentSession(session_id));
+        }
+
         // Get user from DB
         let user = state
             .user_service
@@ -61,6 +194,71 @@ impl FromRequestParts<Arc<AppState>> for AuthUser {
                     .into_response()
             })?;
 
+        // Access tokens issued before the deactivation stop working right away
+        if !user.is_active() {
+            return Err(
+                AppError::AuthError("Account is deactivated".to_string()).into_response(),
+            );
+        }
+
+        // Tokens acting in the company of a membership carry its company and role,
+        // and are rejected once the membership is removed
+        let user = match claims["cid"].as_str() {
+            Some(cid) => {
+                let company_id = Uuid::parse_str(cid).map_err(|_| {
+                    AppError::AuthError("Invalid company id in token".to_string())
+                        .into_response()
+                })?;
+
+                state
+                    .membership_service
+                    .resolve(user, company_id)
+                    .await
+                    .map_err(|e| e.into_response())?
+            }
+            None => user,
+        };
+
         Ok(AuthUser(user))
     }
 }
+
+impl FromRequestParts<Arc<AppState>> for AccountOwner {
+    type Rejection = Response;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &Arc<AppState>,
+    ) -> Result<Self, Self::Rejection> {
+        let AuthUser(user) = AuthUser::from_request_parts(parts, state).await?;
+
+        if parts.extensions.get::<Impersonator>().is_some() {
+            return Err(AppError::Forbidden.into_response());
+        }
+
+        Ok(AccountOwner(user))
+    }
+}
+
+async fn authenticate_api_key(
+    parts: &Parts,
+    state: &Arc<AppState>,
+    key: &str,
+) -> Result<AuthUser, Response> {
+    let (user, api_key) = state
+        .service_account_service
+        .authenticate(key)
+        .await
+        .map_err(|e| e.into_response())?;
+
+    let allowed = parts
+        .extensions
+        .get::<RequiredScope>()
+        .is_some_and(|RequiredScope(scope)| api_key.has_scope(scope));
+
+    if !allowed {
+        return Err(AppError::Forbidden.into_response());
+    }
+
+    Ok(AuthUser(user))
+}
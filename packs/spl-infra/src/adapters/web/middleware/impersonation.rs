@@ -0,0 +1,71 @@
+use crate::adapters::web::{middleware::auth::Impersonator, state::AppState};
+use axum::{
+    extract::{Request, State},
+    http::HeaderValue,
+    middleware::Next,
+    response::Response,
+};
+use std::sync::{Arc, OnceLock};
+use tracing::warn;
+
+/// Header flagging responses to impersonation tokens, with the id of the administrator
+pub const IMPERSONATED_BY_HEADER: &str = "x-impersonated-by";
+
+/// Filled by `AuthUser` when the request is authenticated with an impersonation token,
+/// so the audit sees it once the response is ready
+#[derive(Clone, Default)]
+pub struct ImpersonationSlot(Arc<OnceLock<Impersonator>>);
+
+impl ImpersonationSlot {
+    pub fn set(&self, impersonator: Impersonator) {
+        // Extracted again by the handler after a permission check, with the same token
+        let _ = self.0.set(impersonator);
+    }
+
+    pub fn get(&self) -> Option<Impersonator> {
+        self.0.get().copied()
+    }
+}
+
+/// Records every request authenticated with an impersonation token, whatever its
+/// outcome, and flags the response with the administrator behind it
+pub async fn impersonation_audit(
+    State(state): State<Arc<AppState>>,
+    mut request: Request,
+    next: Next,
+) -> Response {
+    let slot = ImpersonationSlot::default();
+    request.extensions_mut().insert(slot.clone());
+
+    let method = request.method().to_string();
+    let path = request.uri().path().to_string();
+
+    let mut response = next.run(request).await;
+
+    let Some(impersonator) = slot.get() else {
+        return response;
+    };
+
+    if let Ok(value) = HeaderValue::from_str(&impersonator.actor_id.to_string()) {
+        response.headers_mut().insert(IMPERSONATED_BY_HEADER, value);
+    }
+
+    if let Err(e) = state
+        .impersonation_service
+        .record_action(
+            impersonator.impersonation_id,
+            method,
+            path,
+            response.status().as_u16(),
+        )
+        .await
+    {
+        warn!(
+            impersonation_id = %impersonator.impersonation_id,
+            error = %e,
+            "Failed to record impersonation action"
+        );
+    }
+
+    response
+}
@@ -1,16 +1,23 @@
 use crate::adapters::web::controllers::{
-    auth, companies, dashboard, diagnostics, feedback, plots, recommendation, user,
+    auth, companies, company_settings, dashboard, diagnostics, feedback, impersonations,
+    invitations, memberships, offboardings, password_policies, plots, recommendation, roles,
+    service_accounts, sessions, sso, teams, usage, user, well_known,
+};
+use crate::adapters::web::middleware::auth::API_KEY_HEADER;
+use crate::adapters::web::controllers::usage::QUOTA_STATUS_HEADER;
+use crate::adapters::web::middleware::impersonation::{
+    impersonation_audit, IMPERSONATED_BY_HEADER,
 };
 use crate::adapters::web::state::AppState;
 use axum::http::HeaderValue;
-use axum::Router;
+use axum::{middleware as axum_middleware, Router};
 use http::{header, Method};
 use spl_shared::config::AppConfig;
 use spl_shared::http::middleware::rate_limit::RateLimitState;
 use std::sync::Arc;
 use tower_http::cors::{AllowOrigin, CorsLayer};
 use tracing::info;
-use utoipa::openapi::security::{HttpAuthScheme, HttpBuilder, SecurityScheme};
+use utoipa::openapi::security::{ApiKey, ApiKeyValue, HttpAuthScheme, HttpBuilder, SecurityScheme};
 use utoipa::{Modify, OpenApi};
 use utoipa_swagger_ui::SwaggerUi;
 
@@ -45,6 +52,10 @@ impl Modify for SecurityAddon {
                         .build(),
                 ),
             );
+            components.add_security_scheme(
+                "api_key",
+                SecurityScheme::ApiKey(ApiKey::Header(ApiKeyValue::new(API_KEY_HEADER))),
+            );
         }
     }
 }
@@ -90,8 +101,17 @@ fn build_cors_layer(config: &AppConfig) -> Option<CorsLayer> {
             Method::DELETE,
             Method::OPTIONS,
         ])
-        .allow_headers([header::AUTHORIZATION, header::ACCEPT, header::CONTENT_TYPE])
-        .expose_headers([header::AUTHORIZATION]);
+        .allow_headers([
+            header::AUTHORIZATION,
+            header::ACCEPT,
+            header::CONTENT_TYPE,
+            header::HeaderName::from_static(API_KEY_HEADER),
+        ])
+        .expose_headers([
+            header::AUTHORIZATION,
+            header::HeaderName::from_static(IMPERSONATED_BY_HEADER),
+            header::HeaderName::from_static(QUOTA_STATUS_HEADER),
+        ]);
 
     Some(if allow_any {
         cors_layer
@@ -107,6 +127,18 @@ pub fn router(state: Arc<AppState>, rate_limit_state: Arc<RateLimitState>) -> Ro
     openapi.merge(auth::AuthApi::openapi());
     openapi.merge(user::UserApi::openapi());
     openapi.merge(companies::CompaniesApi::openapi());
+    openapi.merge(company_settings::CompanySettingsApi::openapi());
+    openapi.merge(usage::UsageApi::openapi());
+    openapi.merge(roles::RolesApi::openapi());
+    openapi.merge(service_accounts::ServiceAccountsApi::openapi());
+    openapi.merge(sessions::SessionsApi::openapi());
+    openapi.merge(impersonations::ImpersonationsApi::openapi());
+    openapi.merge(invitations::InvitationsApi::openapi());
+    openapi.merge(offboardings::OffboardingsApi::openapi());
+    openapi.merge(teams::TeamsApi::openapi());
+    openapi.merge(memberships::MembershipsApi::openapi());
+    openapi.merge(sso::SsoApi::openapi());
+    openapi.merge(password_policies::PasswordPoliciesApi::openapi());
     openapi.merge(dashboard::DashboardApi::openapi());
     openapi.merge(recommendation::CategoryApi::openapi());
     openapi.merge(recommendation::RecommendationApi::openapi());
@@ -114,6 +146,8 @@ pub fn router(state: Arc<AppState>, rate_limit_state: Arc<RateLimitState>) -> Ro
     openapi.merge(diagnostics::labels::LabelsApi::openapi());
     openapi.merge(diagnostics::mark_types::MarkTypesApi::openapi());
     openapi.merge(diagnostics::prediction::PredictionApi::openapi());
+    openapi.merge(diagnostics::prediction_batch::PredictionBatchApi::openapi());
+    openapi.merge(diagnostics::inference_job::InferenceJobApi::openapi());
     openapi.merge(feedback::status::FeedbackStatusApi::openapi());
     openapi.merge(feedback::FeedbackApi::openapi());
 
@@ -129,15 +163,34 @@ pub fn router(state: Arc<AppState>, rate_limit_state: Arc<RateLimitState>) -> Ro
         .nest(base_path, auth::router(rate_limit_state.clone()))
         .nest(base_path, user::router(state.clone()))
         .nest(base_path, companies::router(state.clone()))
+        .nest(base_path, company_settings::router(state.clone()))
+        .nest(base_path, usage::router(state.clone()))
+        .nest(base_path, roles::router(state.clone()))
+        .nest(base_path, service_accounts::router(state.clone()))
+        .nest(base_path, sessions::router(state.clone()))
+        .nest(base_path, impersonations::router(state.clone()))
+        .nest(base_path, invitations::router(state.clone()))
+        .nest(base_path, offboardings::router(state.clone()))
+        .nest(base_path, teams::router(state.clone()))
+        .nest(base_path, memberships::router(state.clone()))
+        .nest(base_path, sso::router(state.clone()))
+        .nest(base_path, password_policies::router(state.clone()))
         .nest(base_path, dashboard::router(state.clone()))
         .nest(base_path, recommendation::category::router(state.clone()))
         .nest(base_path, recommendation::router(state.clone()))
         .nest(base_path, diagnostics::labels::router(state.clone()))
         .nest(base_path, diagnostics::mark_types::router(state.clone()))
         .nest(base_path, diagnostics::prediction::router(state.clone(), rate_limit_state))
+        .nest(base_path, diagnostics::prediction_batch::router(state.clone()))
+        .nest(base_path, diagnostics::inference_job::router(state.clone()))
         .nest(base_path, plots::router(state.clone()))
         .nest(base_path, feedback::status::router(state.clone()))
-        .nest(base_path, feedback::router());
+        .nest(base_path, feedback::router())
+        .merge(well_known::router())
+        .layer(axum_middleware::from_fn_with_state(
+            state.clone(),
+            impersonation_audit,
+        ));
 
     if let Some(cors_layer) = build_cors_layer(state.config.as_ref()) {
         info!("CORS layer enabled");
@@ -32,6 +32,17 @@ impl LoginRequest {
 pub struct TokenResponse {
     /// JWT authentication token
     pub token: String,
+    /// Opaque refresh token, single use
+    pub refresh_token: String,
+    /// Access token lifetime in seconds
+    pub expires_in: i64,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct RefreshTokenRequest {
+    /// Refresh token obtained on login or on the previous refresh
+    #[validate(length(min = 1))]
+    pub refresh_token: String,
 }
 
 #[derive(Debug, Deserialize, Validate, ToSchema)]
@@ -56,3 +67,87 @@ pub struct RegisterRequest {
     /// Role name to assign to the user
     pub role: Option<String>,
 }
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct ForgotPasswordRequest {
+    /// Username of the account
+    #[validate(length(min = 3, max = 32))]
+    pub username: Option<String>,
+    /// Email address of the account
+    #[validate(email)]
+    pub email: Option<String>,
+    /// Optional company ID for multi-tenant accounts
+    pub company_id: Option<Uuid>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct ResetPasswordRequest {
+    /// Token received by email
+    #[validate(length(min = 1))]
+    pub token: String,
+    /// New password (8-128 characters)
+    #[validate(length(min = 8, max = 128))]
+    pub new_password: String,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct VerifyEmailRequest {
+    /// Token received by email
+    #[validate(length(min = 1))]
+    pub token: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TwoFactorChallengeResponse {
+    /// Token to send with the second factor, valid once
+    pub challenge_token: String,
+    /// Challenge lifetime in seconds
+    pub expires_in: i64,
+    /// The role requires two-factor authentication and the user has not enrolled yet.
+    /// Call `/auth/2fa/setup` before sending the first code.
+    pub setup_required: bool,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct VerifyTwoFactorRequest {
+    /// Challenge token returned by the login
+    #[validate(length(min = 1))]
+    pub challenge_token: String,
+    /// 6-digit authenticator code or recovery code
+    #[validate(length(min = 6, max = 32))]
+    pub code: String,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct TwoFactorSetupRequest {
+    /// Challenge token returned by the login
+    #[validate(length(min = 1))]
+    pub challenge_token: String,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct TwoFactorCodeRequest {
+    /// 6-digit authenticator code or recovery code
+    #[validate(length(min = 6, max = 32))]
+    pub code: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TwoFactorSetupResponse {
+    /// Base32 secret, for manual entry in the authenticator app
+    pub secret: String,
+    /// `otpauth://` URI to render as a QR code
+    pub provisioning_uri: String,
+    /// Single-use recovery codes, only shown once
+    pub recovery_codes: Vec<String>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TwoFactorStatusResponse {
+    /// Whether logins require a second factor
+    pub enabled: bool,
+    /// Whether the company makes two-factor authentication mandatory for the role
+    pub required: bool,
+    /// Unused recovery codes
+    pub recovery_codes_remaining: u64,
+}
@@ -12,6 +12,8 @@ pub struct CreateCompanyRequest {
     /// Optional company description (max 500 characters)
     #[validate(length(max = 500))]
     pub description: Option<String>,
+    /// Company the new one belongs to, e.g. the cooperative of a member farm
+    pub parent_id: Option<Uuid>,
 }
 
 #[derive(Debug, Deserialize, Validate, ToSchema)]
@@ -22,6 +24,15 @@ pub struct UpdateCompanyRequest {
     /// New company description (max 500 characters)
     #[validate(length(max = 500))]
     pub description: Option<String>,
+    /// Minimum role level that must use two-factor authentication (50 or more), 0 to make it optional
+    #[validate(range(min = 0, max = 1000))]
+    pub two_factor_required_level: Option<i16>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct SetCompanyParentRequest {
+    /// Company to place this one below, null to make it a top company
+    pub parent_id: Option<Uuid>,
 }
 
 #[derive(Debug, Serialize, ToSchema, Clone, Deserialize)]
@@ -32,6 +43,10 @@ pub struct CompanyResponse {
     pub name: String,
     /// Company description
     pub description: Option<String>,
+    /// Company this one belongs to, e.g. the cooperative of a member farm
+    pub parent_id: Option<Uuid>,
+    /// Minimum role level that must use two-factor authentication
+    pub two_factor_required_level: Option<i16>,
     /// Timestamp when the company was created
     pub created_at: DateTime<Utc>,
     /// Timestamp when the company was last updated
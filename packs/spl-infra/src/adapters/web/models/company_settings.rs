@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+use spl_shared::validation::{validate_language, validate_timezone};
+use utoipa::ToSchema;
+use validator::Validate;
+
+/// Settings of a company. Missing fields follow the server defaults.
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CompanySettingsRequest {
+    /// IANA time zone, e.g. `America/Lima`
+    #[validate(length(max = 64), custom(function = "validate_timezone"))]
+    pub timezone: Option<String>,
+    /// Language code, e.g. `es` or `en-US`
+    #[validate(custom(function = "validate_language"))]
+    pub default_language: Option<String>,
+    /// Days predictions are kept (0-3650), 0 to keep them forever
+    #[validate(range(max = 3650))]
+    pub data_retention_days: Option<u32>,
+    /// Minimum role level that must use two-factor authentication (50 or more), optional when missing or 0
+    #[validate(range(min = 0, max = 1000))]
+    pub two_factor_required_level: Option<i16>,
+    /// Items per page of listings that do not ask for a size (1-100)
+    #[validate(range(min = 1, max = 100))]
+    pub default_page_size: Option<u64>,
+    /// Image formats users can upload: jpeg, png, webp, gif, bmp or tiff
+    #[validate(length(min = 1))]
+    pub allowed_upload_formats: Option<Vec<String>>,
+    /// Severity (0.0-100.0) from which a prediction needs attention
+    #[validate(range(min = 0.0, max = 100.0))]
+    pub alert_severity_threshold: Option<f32>,
+    /// Lesion confidence (0.0-1.0) from which a prediction needs attention
+    #[validate(range(min = 0.0, max = 1.0))]
+    pub alert_confidence_threshold: Option<f32>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CompanySettingsResponse {
+    /// IANA time zone dates are presented in
+    pub timezone: String,
+    /// Language of the company
+    pub default_language: String,
+    /// Days predictions are kept, 0 when kept forever
+    pub data_retention_days: u32,
+    /// Minimum role level that must use two-factor authentication
+    pub two_factor_required_level: Option<i16>,
+    /// Items per page of listings that do not ask for a size
+    pub default_page_size: u64,
+    /// Image formats users can upload
+    pub allowed_upload_formats: Vec<String>,
+    /// Severity from which a prediction needs attention
+    pub alert_severity_threshold: f32,
+    /// Lesion confidence from which a prediction needs attention
+    pub alert_confidence_threshold: f32,
+}
@@ -20,6 +20,8 @@ pub struct DashboardCountsRequest {
     pub plot_ids: Option<Vec<Option<Uuid>>>,
     /// Filter by disease label names
     pub labels: Option<Vec<String>>,
+    /// Filter by versions of the model that produced the predictions
+    pub model_versions: Option<Vec<String>>,
     /// Number of last predictions to include (default: 10)
     #[serde(default = "default_last_n")]
     pub last_n: u64,
@@ -47,6 +49,8 @@ pub struct DashboardSummaryRequest {
     pub plot_ids: Option<Vec<Option<Uuid>>>,
     /// Filter by disease label names
     pub labels: Option<Vec<String>>,
+    /// Filter by versions of the model that produced the predictions
+    pub model_versions: Option<Vec<String>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
@@ -57,6 +61,8 @@ pub struct DashboardFiltersResponse {
     pub plots: Vec<PlotResponse>,
     /// Available users
     pub users: Vec<UserResponse>,
+    /// Available model versions
+    pub model_versions: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
@@ -67,6 +73,8 @@ pub struct SimplifiedDashboardFiltersResponse {
     pub plots: Vec<SimplifiedPlotResponse>,
     /// Available users (simplified)
     pub users: Vec<SimplifiedUserResponse>,
+    /// Available model versions
+    pub model_versions: Vec<String>,
 }
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct DashboardLabelCountResponse {
@@ -138,6 +146,8 @@ pub struct DashboardSummaryPlotRequest {
     pub max_date: Option<DateTime<Utc>>,
     /// Filter by disease label names
     pub labels: Option<Vec<String>>,
+    /// Filter by versions of the model that produced the predictions
+    pub model_versions: Option<Vec<String>>,
 }
 
 /// Response for dashboard detailed plot
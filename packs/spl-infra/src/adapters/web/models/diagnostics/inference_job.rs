@@ -0,0 +1,40 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+#[derive(ToSchema)]
+pub struct CreateInferenceJobRequest {
+    /// Image file to analyze (JPEG/PNG)
+    #[schema(value_type = String, format = Binary)]
+    pub file: Vec<u8>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct InferenceJobQuery {
+    /// Only jobs with this status: `queued`, `running`, `completed`, `failed` or `dead`
+    pub status: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct InferenceJobResponse {
+    /// Unique identifier of the job
+    pub id: Uuid,
+    /// `queued`, `running`, `completed`, `failed` or `dead`
+    pub status: String,
+    /// Name of the uploaded file
+    pub filename: String,
+    /// Batch image the job predicts, if any
+    pub batch_item_id: Option<Uuid>,
+    /// Attempts started so far
+    pub attempts: u32,
+    /// Prediction created by the job, once completed
+    pub prediction_id: Option<Uuid>,
+    /// Why the last attempt failed
+    pub error: Option<String>,
+    /// Time of the next attempt while queued
+    pub run_after: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+}
@@ -1,6 +1,8 @@
+pub mod inference_job;
 pub mod label;
 pub mod mark_type;
 pub mod prediction;
+pub mod prediction_batch;
 pub mod prediction_mark;
 
 pub use label::{CreateLabelRequest, LabelResponse, SimplifiedLabelResponse, UpdateLabelRequest};
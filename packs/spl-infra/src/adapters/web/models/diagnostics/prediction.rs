@@ -15,6 +15,23 @@ use utoipa::ToSchema;
 use uuid::Uuid;
 use validator::{Validate, ValidationError};
 
+/// Model that produced a prediction
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct PredictionModelResponse {
+    /// Name of the model in the serving provider
+    pub name: String,
+    /// Version that answered, when the provider reports it
+    pub version: Option<String>,
+    /// Provider that served the model (tensorflow, tensorflow_grpc or mock)
+    pub provider: String,
+    /// Width and height the image was resized to
+    pub image_size: u32,
+    /// Probability from which a pixel belongs to the leaf mask
+    pub leaf_threshold: f32,
+    /// Probability from which a pixel belongs to the lesion mask
+    pub lesion_threshold: f32,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct PredictionResponse {
     /// Unique identifier of the prediction
@@ -39,6 +56,8 @@ pub struct PredictionResponse {
     pub marks: Vec<PredictionMarkResponse>,
     /// User feedback on the prediction accuracy
     pub feedback: Option<FeedbackResponse>,
+    /// Model that produced the prediction, missing for predictions made before it was recorded
+    pub model: Option<PredictionModelResponse>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
@@ -76,6 +95,8 @@ pub struct FilterPredictionsRequest {
     pub user_ids: Option<Vec<Uuid>>,
     /// Filter by disease label names
     pub labels: Option<Vec<String>>,
+    /// Filter by versions of the model that produced the predictions
+    pub model_versions: Option<Vec<String>>,
     /// Filter by plot IDs (None for unassigned)
     pub plot_ids: Option<Vec<Option<Uuid>>>,
     /// Filter predictions created after this date
@@ -132,6 +153,8 @@ pub struct RawPredictionResponse {
     pub label: RawLabelResponse,
     /// Segmentation marks (masks) with base64 encoded data
     pub marks: Vec<RawPredictionMarkResponse>,
+    /// Model that produced the prediction
+    pub model: PredictionModelResponse,
 }
 
 /// Response containing a prediction with its recommendations
@@ -159,6 +182,8 @@ pub struct PredictionDetailedResponse {
     pub marks: Vec<PredictionMarkResponse>,
     /// User feedback on the prediction accuracy
     pub feedback: Option<FeedbackResponse>,
+    /// Model that produced the prediction, missing for predictions made before it was recorded
+    pub model: Option<PredictionModelResponse>,
     /// List of recommended plots for this prediction based on severity
     pub recommendations: Vec<RecommendationResponse>,
 }
@@ -0,0 +1,53 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(ToSchema)]
+pub struct CreatePredictionBatchRequest {
+    /// Images (JPEG/PNG) or zip archives of images, repeat the field for each file
+    #[schema(value_type = Vec<String>, format = Binary)]
+    pub file: Vec<Vec<u8>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct PredictionBatchResponse {
+    /// Unique identifier of the batch
+    pub id: Uuid,
+    /// `processing` or `completed`
+    pub status: String,
+    /// Images of the batch, counting those inside archives
+    pub items_total: u64,
+    /// Images predicted so far
+    pub items_completed: u64,
+    /// Images that could not be predicted
+    pub items_failed: u64,
+    /// Images still waiting to be predicted
+    pub items_pending: u64,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct PredictionBatchItemResponse {
+    pub id: Uuid,
+    /// Order of the image in the upload, starting at 0
+    pub position: u32,
+    /// Name of the uploaded file, or its path inside the archive it came in
+    pub filename: String,
+    /// `pending`, `completed` or `failed`
+    pub status: String,
+    /// Prediction created from the image
+    pub prediction_id: Option<Uuid>,
+    /// Why the image could not be predicted
+    pub error: Option<String>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct PredictionBatchDetailResponse {
+    pub batch: PredictionBatchResponse,
+    /// Images in upload order
+    pub items: Vec<PredictionBatchItemResponse>,
+}
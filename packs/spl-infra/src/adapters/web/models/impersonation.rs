@@ -0,0 +1,67 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct StartImpersonationRequest {
+    /// Why the user needs to be impersonated, e.g. a support ticket (1-500 characters)
+    #[validate(length(min = 1, max = 500))]
+    pub reason: String,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct ImpersonationQuery {
+    /// User who started or was the subject of the impersonations
+    pub user_id: Uuid,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ImpersonationResponse {
+    /// Unique identifier of the impersonation
+    pub id: Uuid,
+    /// Administrator acting as the subject, absent once the user was deleted
+    pub actor_id: Option<Uuid>,
+    /// User being impersonated, absent once the user was deleted
+    pub subject_id: Option<Uuid>,
+    /// Reason given when it was started
+    pub reason: String,
+    /// Address it was started from
+    pub ip_address: Option<String>,
+    pub started_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    /// Timestamp when it was ended, absent if it was not ended before expiring
+    pub ended_at: Option<DateTime<Utc>>,
+    /// Whether its token is still accepted
+    pub active: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ImpersonationTokenResponse {
+    /// JWT acting as the subject. It cannot be refreshed.
+    pub token: String,
+    /// Access token lifetime in seconds
+    pub expires_in: i64,
+    /// Always true, requests made with the token are recorded
+    pub impersonated: bool,
+    pub impersonation: ImpersonationResponse,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ImpersonationActionResponse {
+    /// HTTP method of the request
+    pub method: String,
+    /// Path of the request
+    pub path: String,
+    /// Status the request was answered with
+    pub status_code: u16,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ImpersonationAuditResponse {
+    pub impersonation: ImpersonationResponse,
+    /// Requests made during the impersonation, oldest first
+    pub actions: Vec<ImpersonationActionResponse>,
+}
@@ -0,0 +1,65 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreateInvitationRequest {
+    /// Email address of the invitee
+    #[validate(email)]
+    pub email: String,
+    /// Role name assigned to the invitee, defaults to "user"
+    pub role: Option<String>,
+    /// Company to invite into, required for admins
+    pub company_id: Option<Uuid>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct InvitationQuery {
+    /// Company to list, required for admins
+    pub company_id: Option<Uuid>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct AcceptInvitationRequest {
+    /// Token received by email
+    #[validate(length(min = 1))]
+    pub token: String,
+    /// Unique username (3-32 characters)
+    #[validate(length(min = 3, max = 32))]
+    pub username: String,
+    /// User password (8-128 characters)
+    #[validate(length(min = 8, max = 128))]
+    pub password: String,
+    /// User's first name (1-100 characters)
+    #[validate(length(min = 1, max = 100))]
+    pub name: Option<String>,
+    /// User's last name (1-100 characters)
+    #[validate(length(min = 1, max = 100))]
+    pub surname: Option<String>,
+}
+
+#[derive(Debug, Serialize, ToSchema, Clone, Deserialize)]
+pub struct InvitationResponse {
+    /// Unique identifier of the invitation
+    pub id: Uuid,
+    /// Company the invitee joins
+    pub company_id: Uuid,
+    /// Email address of the invitee
+    pub email: String,
+    /// Role assigned to the invitee
+    pub role_id: i32,
+    /// One of "pending", "accepted", "cancelled" or "expired"
+    pub status: String,
+    /// User who sent the invitation
+    pub invited_by: Option<Uuid>,
+    /// Timestamp when the current link expires
+    pub expires_at: DateTime<Utc>,
+    /// Timestamp when the invitation was accepted
+    pub accepted_at: Option<DateTime<Utc>>,
+    /// Timestamp when the invitation was cancelled
+    pub cancelled_at: Option<DateTime<Utc>>,
+    /// Timestamp when the invitation was created
+    pub created_at: DateTime<Utc>,
+}
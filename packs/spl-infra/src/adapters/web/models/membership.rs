@@ -0,0 +1,39 @@
+use super::company::SimplifiedCompanyResponse;
+use super::user::SimplifiedRoleResponse;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct SetMembershipRequest {
+    /// Role name the user has in the company, below the level of the requester
+    #[validate(length(min = 1, max = 64))]
+    pub role: String,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct SwitchCompanyRequest {
+    /// Own company of the user or the company of one of their memberships
+    pub company_id: Uuid,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct MembershipResponse {
+    pub user_id: Uuid,
+    pub company: SimplifiedCompanyResponse,
+    pub role: SimplifiedRoleResponse,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct UserMembershipResponse {
+    pub company: SimplifiedCompanyResponse,
+    pub role: SimplifiedRoleResponse,
+    /// The company of the account, the others come from memberships
+    pub home: bool,
+    /// Company the current session acts in
+    pub active: bool,
+    pub created_at: DateTime<Utc>,
+}
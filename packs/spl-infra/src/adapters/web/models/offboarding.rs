@@ -0,0 +1,47 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct OffboardingResponse {
+    /// Unique identifier of the offboarding
+    pub id: Uuid,
+    /// Company being removed, it no longer exists once completed
+    pub company_id: Uuid,
+    pub company_name: String,
+    /// Administrator who started it
+    pub requested_by: Uuid,
+    /// `exporting`, `exported`, `deleting`, `completed` or `failed`
+    pub status: String,
+    /// Whether the archive can be downloaded
+    pub export_ready: bool,
+    /// Size of the archive in bytes
+    pub export_size: Option<u64>,
+    /// Users the company had when deletion started
+    pub users_total: u64,
+    /// Users deleted so far
+    pub users_deleted: u64,
+    /// Why the last step failed
+    pub error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct OffboardingEventResponse {
+    /// Administrator behind the event, absent for steps of the job itself
+    pub actor_id: Option<Uuid>,
+    /// What happened, e.g. `export_completed` or `user_deleted`
+    pub action: String,
+    pub detail: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct OffboardingAuditResponse {
+    pub offboarding: OffboardingResponse,
+    /// Audit trail, oldest first
+    pub events: Vec<OffboardingEventResponse>,
+}
@@ -0,0 +1,50 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct PasswordPolicyRequest {
+    /// Minimum password length (8-128 characters)
+    #[validate(range(min = 8, max = 128))]
+    pub min_length: u16,
+    /// Require an uppercase letter
+    #[serde(default)]
+    pub require_uppercase: bool,
+    /// Require a lowercase letter
+    #[serde(default)]
+    pub require_lowercase: bool,
+    /// Require a digit
+    #[serde(default)]
+    pub require_digit: bool,
+    /// Require a character that is neither a letter nor a digit
+    #[serde(default)]
+    pub require_symbol: bool,
+    /// Previous passwords that cannot be reused (0-24)
+    #[serde(default)]
+    #[validate(range(max = 24))]
+    pub history_size: u16,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PasswordPolicyResponse {
+    /// Company the policy belongs to
+    pub company_id: Uuid,
+    /// Minimum password length
+    pub min_length: u16,
+    /// Whether an uppercase letter is required
+    pub require_uppercase: bool,
+    /// Whether a lowercase letter is required
+    pub require_lowercase: bool,
+    /// Whether a digit is required
+    pub require_digit: bool,
+    /// Whether a symbol is required
+    pub require_symbol: bool,
+    /// Previous passwords that cannot be reused
+    pub history_size: u16,
+    /// Timestamp when the policy was created
+    pub created_at: DateTime<Utc>,
+    /// Timestamp when the policy was last changed
+    pub updated_at: DateTime<Utc>,
+}
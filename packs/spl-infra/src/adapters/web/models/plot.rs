@@ -42,10 +42,9 @@ pub struct DetailedPlotsRequest {
     #[serde(default = "default_page")]
     #[validate(range(min = 1))]
     pub page: u64,
-    /// Items per page
-    #[serde(default = "default_limit")]
+    /// Items per page (1-100), the default page size of the company when missing
     #[validate(range(min = 1, max = 100))]
-    pub limit: u64,
+    pub limit: Option<u64>,
     /// Filter by label names (optional)
     pub labels: Option<Vec<String>>,
 }
@@ -54,10 +53,6 @@ fn default_page() -> u64 {
     1
 }
 
-fn default_limit() -> u64 {
-    16
-}
-
 // ============ RESPONSE MODELS ============
 
 /// Response for a single plot
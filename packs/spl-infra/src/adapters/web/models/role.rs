@@ -0,0 +1,75 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+/// Reach of a granted permission
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum GrantScope {
+    /// Only resources of the user itself
+    Own,
+    /// Resources of the teams the user supervises, the whole company while it has no teams.
+    /// Only for `users:read`, `plots:read` and `predictions:read`.
+    Team,
+    /// Resources of the company of the user
+    Company,
+    /// Resources of every company
+    Any,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct PermissionGrantRequest {
+    /// Permission name, e.g. `plots:read`
+    pub permission: String,
+    /// Reach of the permission
+    pub scope: GrantScope,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreateRoleRequest {
+    /// Unique role name (2-32 characters)
+    #[validate(length(min = 2, max = 32))]
+    pub name: String,
+    /// Role level, users only manage roles below their own
+    #[validate(range(min = 0))]
+    pub level: i16,
+    /// Permissions granted to the role
+    pub permissions: Vec<PermissionGrantRequest>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct UpdateRoleRequest {
+    /// Unique role name (2-32 characters)
+    #[validate(length(min = 2, max = 32))]
+    pub name: Option<String>,
+    /// Role level, users only manage roles below their own
+    #[validate(range(min = 0))]
+    pub level: Option<i16>,
+    /// Replaces every permission of the role when given
+    pub permissions: Option<Vec<PermissionGrantRequest>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct PermissionGrantResponse {
+    /// Permission name
+    pub permission: String,
+    /// Reach of the permission
+    pub scope: GrantScope,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct DetailedRoleResponse {
+    /// Unique identifier of the role
+    pub id: i32,
+    /// Role name
+    pub name: String,
+    /// Role level
+    pub level: i16,
+    /// Permissions granted to the role
+    pub permissions: Vec<PermissionGrantResponse>,
+    /// Timestamp when the role was created
+    pub created_at: DateTime<Utc>,
+    /// Timestamp when the role was last updated
+    pub updated_at: DateTime<Utc>,
+}
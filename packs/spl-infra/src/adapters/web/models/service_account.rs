@@ -0,0 +1,81 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreateServiceAccountRequest {
+    /// Service account name (3-100 characters)
+    #[validate(length(min = 3, max = 100))]
+    pub name: String,
+    /// Optional description (max 500 characters)
+    #[validate(length(max = 500))]
+    pub description: Option<String>,
+    /// Company of the account, required for admins
+    pub company_id: Option<Uuid>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct ServiceAccountQuery {
+    /// Company to list, required for admins
+    pub company_id: Option<Uuid>,
+}
+
+#[derive(Debug, Serialize, ToSchema, Clone, Deserialize)]
+pub struct ServiceAccountResponse {
+    /// Unique identifier of the service account, also the id of its user
+    pub id: Uuid,
+    /// Company the account belongs to
+    pub company_id: Uuid,
+    /// Service account name
+    pub name: String,
+    /// Service account description
+    pub description: Option<String>,
+    /// User who created the account
+    pub created_by: Option<Uuid>,
+    /// Timestamp when the account was created
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreateApiKeyRequest {
+    /// Key name (1-100 characters)
+    #[validate(length(min = 1, max = 100))]
+    pub name: String,
+    /// Granted scopes, e.g. `predictions:write` or `dashboard:read`
+    #[validate(length(min = 1))]
+    pub scopes: Vec<String>,
+    /// Optional expiration, the key never expires when missing
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, ToSchema, Clone, Deserialize)]
+pub struct ApiKeyResponse {
+    /// Unique identifier of the key
+    pub id: Uuid,
+    /// Service account owning the key
+    pub service_account_id: Uuid,
+    /// Key name
+    pub name: String,
+    /// First characters of the key, to tell keys apart
+    pub key_prefix: String,
+    /// Granted scopes
+    pub scopes: Vec<String>,
+    /// Expiration of the key
+    pub expires_at: Option<DateTime<Utc>>,
+    /// Last time the key was used (minute resolution)
+    pub last_used_at: Option<DateTime<Utc>>,
+    /// Timestamp when the key was revoked
+    pub revoked_at: Option<DateTime<Utc>>,
+    /// Timestamp when the key was created
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CreatedApiKeyResponse {
+    /// The API key, send it in the `X-API-Key` header. It is only shown once.
+    pub key: String,
+    /// Details of the key
+    pub api_key: ApiKeyResponse,
+}
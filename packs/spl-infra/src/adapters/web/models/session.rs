@@ -0,0 +1,28 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, ToSchema, Clone, Deserialize)]
+pub struct SessionResponse {
+    /// Unique identifier of the session
+    pub id: Uuid,
+    /// `User-Agent` of the device that logged in
+    pub user_agent: Option<String>,
+    /// Address the session was last used from
+    pub ip_address: Option<String>,
+    /// Timestamp when the session was opened
+    pub created_at: DateTime<Utc>,
+    /// Last time the session was used (minute resolution)
+    pub last_seen_at: DateTime<Utc>,
+    /// The session ends at this time unless it is revoked before
+    pub expires_at: DateTime<Utc>,
+    /// The session making this request
+    pub current: bool,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RevokedSessionsResponse {
+    /// Number of sessions that were still active
+    pub revoked: u64,
+}
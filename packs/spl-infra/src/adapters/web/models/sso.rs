@@ -0,0 +1,76 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct IdentityProviderRequest {
+    /// Issuer URL of the OpenID Connect provider
+    #[validate(url)]
+    pub issuer: String,
+    /// Client id registered at the provider
+    #[validate(length(min = 1, max = 255))]
+    pub client_id: String,
+    /// Client secret, keeps the stored one when omitted. Public clients rely on PKCE only.
+    #[validate(length(min = 1, max = 255))]
+    pub client_secret: Option<String>,
+    /// Space separated scopes, defaults to `openid email profile`
+    pub scopes: Option<String>,
+    /// ID token claim with the user's groups or roles, defaults to `roles`. Dotted paths reach nested claims.
+    pub role_claim: Option<String>,
+    /// Claim values mapped to role names, e.g. `{"agronomists": "supervisor"}`
+    #[serde(default)]
+    pub role_mapping: BTreeMap<String, String>,
+    /// Role of users without any mapped claim value. They are rejected when missing.
+    pub default_role: Option<String>,
+    /// Create unknown users on their first login
+    #[serde(default)]
+    pub auto_provision: bool,
+    /// Whether users can log in through the provider
+    #[serde(default = "enabled_default")]
+    pub enabled: bool,
+}
+
+fn enabled_default() -> bool {
+    true
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct IdentityProviderResponse {
+    /// Company the provider belongs to
+    pub company_id: Uuid,
+    /// Issuer URL of the OpenID Connect provider
+    pub issuer: String,
+    /// Client id registered at the provider
+    pub client_id: String,
+    /// Whether a client secret is stored. The secret itself is never returned.
+    pub has_client_secret: bool,
+    /// Requested scopes
+    pub scopes: String,
+    /// ID token claim with the user's groups or roles
+    pub role_claim: String,
+    /// Claim values mapped to role names
+    pub role_mapping: BTreeMap<String, String>,
+    /// Role of users without any mapped claim value
+    pub default_role: Option<String>,
+    /// Whether unknown users are created on their first login
+    pub auto_provision: bool,
+    /// Whether users can log in through the provider
+    pub enabled: bool,
+    /// Timestamp when the provider was configured
+    pub created_at: DateTime<Utc>,
+    /// Timestamp of the last change
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct SsoCallbackRequest {
+    /// Authorization code returned by the identity provider
+    #[validate(length(min = 1))]
+    pub code: String,
+    /// State returned by the identity provider
+    #[validate(length(min = 1))]
+    pub state: String,
+}
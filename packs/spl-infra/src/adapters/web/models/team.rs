@@ -0,0 +1,64 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+/// Role of a user within a team
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum TeamRoleRequest {
+    /// Seen by the supervisors of the team
+    Member,
+    /// Sees the members and plots of the team
+    Supervisor,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreateTeamRequest {
+    /// Team name, unique within the company (2-64 characters)
+    #[validate(length(min = 2, max = 64))]
+    pub name: String,
+    #[validate(length(max = 255))]
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct UpdateTeamRequest {
+    /// Team name, unique within the company (2-64 characters)
+    #[validate(length(min = 2, max = 64))]
+    pub name: Option<String>,
+    #[validate(length(max = 255))]
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct SetTeamMemberRequest {
+    pub role: TeamRoleRequest,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TeamResponse {
+    pub id: Uuid,
+    pub company_id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TeamMemberResponse {
+    pub user_id: Uuid,
+    /// `member` or `supervisor`
+    pub role: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct DetailedTeamResponse {
+    pub team: TeamResponse,
+    pub members: Vec<TeamMemberResponse>,
+    /// Plots assigned to the team
+    pub plot_ids: Vec<Uuid>,
+}
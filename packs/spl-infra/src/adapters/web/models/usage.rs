@@ -0,0 +1,68 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct UsageQuery {
+    /// Month of the report as `YYYY-MM`, the current one when missing
+    pub month: Option<String>,
+}
+
+/// Quota of a company. Missing limits are unlimited.
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CompanyQuotaRequest {
+    /// Predictions of the company per month
+    pub predictions_per_month: Option<u64>,
+    /// Predictions of each user per month
+    pub predictions_per_user_per_month: Option<u64>,
+    /// Bytes of images the company stores per month
+    pub stored_bytes_per_month: Option<u64>,
+    /// Fraction of a limit (0.0-1.0) from which usage is reported as a warning, the server one when missing
+    #[validate(range(min = 0.0, max = 1.0))]
+    pub warning_ratio: Option<f32>,
+    /// `hard` rejects predictions once a limit is reached, `soft` only warns. The server one when missing
+    pub enforcement: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct QuotaResponse {
+    /// Predictions of the company per month, unlimited when missing
+    pub predictions_per_month: Option<u64>,
+    /// Predictions of each user per month, unlimited when missing
+    pub predictions_per_user_per_month: Option<u64>,
+    /// Bytes of images the company stores per month, unlimited when missing
+    pub stored_bytes_per_month: Option<u64>,
+    /// Fraction of a limit from which usage is reported as a warning
+    pub warning_ratio: f32,
+    /// `hard` or `soft`
+    pub enforcement: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct UserUsageResponse {
+    pub user_id: Uuid,
+    pub predictions: u64,
+    pub stored_bytes: u64,
+    /// `ok`, `warning` or `exceeded` against the limit per user
+    pub status: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct UsageReportResponse {
+    pub company_id: Uuid,
+    /// Month of the report as `YYYY-MM`
+    pub month: String,
+    /// When the counters of the month start over
+    pub resets_at: DateTime<Utc>,
+    /// Predictions made during the month
+    pub predictions: u64,
+    /// Bytes of images and masks stored during the month
+    pub stored_bytes: u64,
+    /// `ok`, `warning` or `exceeded` against the limits of the company
+    pub status: String,
+    pub quota: QuotaResponse,
+    /// Usage of each user, most predictions first
+    pub users: Vec<UserUsageResponse>,
+}
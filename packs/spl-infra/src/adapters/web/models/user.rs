@@ -14,6 +14,8 @@ pub struct UserResponse {
     pub username: String,
     /// Email address
     pub email: Option<String>,
+    /// Whether the email address was verified. Only verified emails can be used to log in.
+    pub email_verified: bool,
     /// User's first name
     pub name: Option<String>,
     /// User's last name
@@ -22,6 +24,8 @@ pub struct UserResponse {
     pub role: String,
     /// Company ID the user belongs to
     pub company_id: Option<Uuid>,
+    /// Timestamp when the user was deactivated, absent for active users
+    pub deactivated_at: Option<DateTime<Utc>>,
     /// Timestamp when the user was created
     pub created_at: DateTime<Utc>,
 }
@@ -48,6 +52,8 @@ pub struct FullUserResponse {
     pub username: String,
     /// Email address
     pub email: Option<String>,
+    /// Whether the email address was verified. Only verified emails can be used to log in.
+    pub email_verified: bool,
     /// User's first name
     pub name: Option<String>,
     /// User's last name
@@ -56,8 +62,13 @@ pub struct FullUserResponse {
     pub role: String,
     /// Full company information
     pub company: Option<CompanyResponse>,
+    /// Timestamp when the user was deactivated, absent for active users
+    pub deactivated_at: Option<DateTime<Utc>>,
     /// Timestamp when the user was created
     pub created_at: DateTime<Utc>,
+    /// Administrator acting as the user, only present for impersonation tokens
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub impersonated_by: Option<Uuid>,
 }
 
 #[derive(Debug, Deserialize, Validate, ToSchema)]
@@ -123,3 +134,17 @@ pub struct SimplifiedRoleResponse {
     /// Role name
     pub name: String,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct LoginLockoutResponse {
+    /// Unique identifier of the user
+    pub user_id: Uuid,
+    /// Whether the account is currently locked
+    pub locked: bool,
+    /// End of the current lockout
+    pub locked_until: Option<DateTime<Utc>>,
+    /// Failed login attempts since the last lockout or successful login
+    pub failed_attempts: u32,
+    /// Lockouts applied since the last successful login
+    pub lockout_count: u32,
+}
@@ -1,13 +1,30 @@
 use spl_application::services::{
     auth::AuthService,
     company::CompanyService,
+    company_settings::CompanySettingsService,
     dashboard::DashboardService,
-    diagnostics::{LabelService, MarkTypeService, PredictionService},
+    diagnostics::{
+        InferenceJobService, LabelService, MarkTypeService, PredictionBatchService,
+        PredictionService,
+    },
+    email_verification::EmailVerificationService,
+    impersonation::ImpersonationService,
     image::ImageService,
+    login_lockout::LoginLockoutService,
+    offboarding::OffboardingService,
+    password_policy::PasswordPolicyService,
+    password_reset::PasswordResetService,
     plot::PlotService,
+    policy::PolicyService,
+    two_factor::TwoFactorService,
     recommendation,
     recommendation::RecommendationService,
-    user::{RoleService, UserService},
+    service_account::ServiceAccountService,
+    session::SessionService,
+    sso::SsoService,
+    team::TeamService,
+    usage::UsageService,
+    user::{InvitationService, MembershipService, RoleService, UserService},
 };
 
 use spl_domain::ports::integrations::{BlobStorageClient, ModelPredictionClient};
@@ -16,25 +33,41 @@ use std::sync::Arc;
 
 use spl_application::services::feedback::status::FeedbackStatusService;
 use spl_application::services::feedback::FeedbackService;
-use std::collections::HashMap;
 
 pub struct AppState {
     pub config: Arc<AppConfig>,
     pub auth_service: Arc<AuthService>,
+    pub password_reset_service: Arc<PasswordResetService>,
+    pub password_policy_service: Arc<PasswordPolicyService>,
+    pub email_verification_service: Arc<EmailVerificationService>,
+    pub impersonation_service: Arc<ImpersonationService>,
+    pub login_lockout_service: Arc<LoginLockoutService>,
+    pub two_factor_service: Arc<TwoFactorService>,
+    pub service_account_service: Arc<ServiceAccountService>,
+    pub sso_service: Arc<SsoService>,
+    pub session_service: Arc<SessionService>,
+    pub invitation_service: Arc<InvitationService>,
     pub role_service: Arc<RoleService>,
     pub user_service: Arc<UserService>,
     pub company_service: Arc<CompanyService>,
+    pub company_settings_service: Arc<CompanySettingsService>,
+    pub usage_service: Arc<UsageService>,
+    pub offboarding_service: Arc<OffboardingService>,
+    pub team_service: Arc<TeamService>,
+    pub membership_service: Arc<MembershipService>,
     pub image_service: Arc<ImageService>,
     pub recommendation_category_service: Arc<recommendation::CategoryService>,
     pub recommendation_service: Arc<RecommendationService>,
     pub label_service: Arc<LabelService>,
     pub mark_type_service: Arc<MarkTypeService>,
     pub prediction_service: Arc<PredictionService>,
+    pub prediction_batch_service: Arc<PredictionBatchService>,
+    pub inference_job_service: Arc<InferenceJobService>,
     pub plot_service: Arc<PlotService>,
     pub dashboard_service: Arc<DashboardService>,
     pub feedback_service: Arc<FeedbackService>,
     pub feedback_status_service: Arc<FeedbackStatusService>,
-    pub roles: HashMap<String, i16>,
+    pub policy_service: Arc<PolicyService>,
     // Integration clients
     pub model_client: Arc<dyn ModelPredictionClient>,
     pub storage_client: Arc<dyn BlobStorageClient>,
@@ -46,40 +79,74 @@ impl AppState {
     pub fn new(
         config: Arc<AppConfig>,
         auth_service: Arc<AuthService>,
+        password_reset_service: Arc<PasswordResetService>,
+        password_policy_service: Arc<PasswordPolicyService>,
+        email_verification_service: Arc<EmailVerificationService>,
+        impersonation_service: Arc<ImpersonationService>,
+        login_lockout_service: Arc<LoginLockoutService>,
+        two_factor_service: Arc<TwoFactorService>,
+        service_account_service: Arc<ServiceAccountService>,
+        sso_service: Arc<SsoService>,
+        session_service: Arc<SessionService>,
+        invitation_service: Arc<InvitationService>,
         role_service: Arc<RoleService>,
         user_service: Arc<UserService>,
         company_service: Arc<CompanyService>,
+        company_settings_service: Arc<CompanySettingsService>,
+        usage_service: Arc<UsageService>,
+        offboarding_service: Arc<OffboardingService>,
+        team_service: Arc<TeamService>,
+        membership_service: Arc<MembershipService>,
         image_service: Arc<ImageService>,
         recommendation_category_service: Arc<recommendation::CategoryService>,
         recommendation_service: Arc<RecommendationService>,
         label_service: Arc<LabelService>,
         mark_type_service: Arc<MarkTypeService>,
         prediction_service: Arc<PredictionService>,
+        prediction_batch_service: Arc<PredictionBatchService>,
+        inference_job_service: Arc<InferenceJobService>,
         plot_service: Arc<PlotService>,
         dashboard_service: Arc<DashboardService>,
         feedback_service: Arc<FeedbackService>,
         feedback_status_service: Arc<FeedbackStatusService>,
-        roles: HashMap<String, i16>,
+        policy_service: Arc<PolicyService>,
         model_client: Arc<dyn ModelPredictionClient>,
         storage_client: Arc<dyn BlobStorageClient>,
     ) -> Self {
         Self {
             config,
             auth_service,
+            password_reset_service,
+            password_policy_service,
+            email_verification_service,
+            impersonation_service,
+            login_lockout_service,
+            two_factor_service,
+            service_account_service,
+            sso_service,
+            session_service,
+            invitation_service,
             role_service,
             user_service,
             company_service,
+            company_settings_service,
+            usage_service,
+            offboarding_service,
+            team_service,
+            membership_service,
             image_service,
             recommendation_category_service,
             recommendation_service,
             label_service,
             mark_type_service,
             prediction_service,
+            prediction_batch_service,
+            inference_job_service,
             plot_service,
             dashboard_service,
             feedback_service,
             feedback_status_service,
-            roles,
+            policy_service,
             model_client,
             storage_client,
         }
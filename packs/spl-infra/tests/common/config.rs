@@ -1,4 +1,7 @@
-use spl_shared::config::{AppConfig, DatabaseConfig, IntegrationsConfig, ModelServingConfig, ServerConfig, StorageConfig};
+use spl_shared::config::{
+    AppConfig, DatabaseConfig, IntegrationsConfig, ModelServingConfig, OidcConfig, ServerConfig,
+    StorageConfig,
+};
 
 pub fn create_config() -> AppConfig {
     AppConfig {
@@ -7,7 +10,17 @@ pub fn create_config() -> AppConfig {
             port: 8080,
             jwt_secret: "test_secret".into(),
             jwt_expiration_hours: 24,
+            access_token_expiration_minutes: None,
+            refresh_token_expiration_days: None,
             cors_allowed_origins: None,
+            jwt_keys: None,
+            jwt_signing_kid: None,
+            frontend_url: None,
+            password_reset_expiration_minutes: None,
+            invitation_expiration_hours: None,
+            email_verification_expiration_hours: None,
+            two_factor_issuer: None,
+            two_factor_challenge_expiration_seconds: None,
         },
 
         database: DatabaseConfig {
@@ -35,7 +48,20 @@ pub fn create_config() -> AppConfig {
                 container_name: None,
                 local_base_path: None,
             },
+            mail: None,
         },
         rate_limiting: None,
+        login_lockout: None,
+        oidc: Some(OidcConfig {
+            redirect_url: Some("http://localhost:3000/auth/callback".into()),
+            ..Default::default()
+        }),
+        user_cache: None,
+        password_policy: None,
+        password_hashing: None,
+        company_settings: None,
+        quotas: None,
+        batch_uploads: None,
+        inference_jobs: None,
     }
 }
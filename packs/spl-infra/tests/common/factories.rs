@@ -0,0 +1,63 @@
+use chrono::Utc;
+use spl_domain::entities::company::Company;
+use spl_domain::entities::user::{Role, User};
+use uuid::Uuid;
+
+pub fn create_company() -> Company {
+    Company {
+        id: Uuid::new_v4(),
+        name: "Test Company".to_string(),
+        description: None,
+        parent_id: None,
+        two_factor_required_level: None,
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+    }
+}
+
+pub fn create_user(role: &str, level: i16, company: Company) -> User {
+    User {
+        id: Uuid::new_v4(),
+        username: format!("{role}_user"),
+        email: None,
+        email_verified_at: None,
+        password_hash: "hashed".to_string(),
+        name: None,
+        surname: None,
+        role: Role {
+            id: level as i32,
+            name: role.to_string(),
+            level,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        },
+        company: Some(company),
+        deactivated_at: None,
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+    }
+}
+
+/// User of no company with an unverified email
+pub fn create_user_with_id(id: Uuid) -> User {
+    User {
+        id,
+        username: "webuser".to_string(),
+        email: Some("web@example.com".to_string()),
+        email_verified_at: None,
+        password_hash: "hashed".to_string(),
+        name: None,
+        surname: None,
+        role: Role {
+            id: 2,
+            name: "user".to_string(),
+            level: 10,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        },
+        company: None,
+        deactivated_at: None,
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+    }
+}
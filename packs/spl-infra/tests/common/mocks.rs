@@ -3,10 +3,16 @@ use chrono::DateTime;
 use chrono::Utc;
 use mockall::mock;
 use spl_domain::entities::dashboard::{DashboardCounts, DashboardDetailedPlot, DashboardSummary};
+use spl_domain::entities::diagnostics::prediction::PredictionDetailed;
 use spl_domain::entities::diagnostics::{MarkType, Prediction, PredictionMark};
 use spl_domain::entities::feedback::Feedback;
 use spl_domain::entities::plot::Plot;
-use spl_domain::ports::auth::{PasswordEncoder, TokenGenerator};
+use spl_domain::ports::auth::{
+    LoginAttemptStore, PasswordEncoder, TokenGenerator, TwoFactorProvider,
+};
+use spl_domain::ports::integrations::IntegrationClient;
+use spl_domain::ports::mailer::{self, EmailMessage};
+use spl_domain::ports::oidc::{OidcClaims, OidcClient};
 use spl_domain::ports::{
     repositories,
     repositories::{
@@ -26,7 +32,6 @@ use spl_domain::{
 };
 use spl_shared::error::Result;
 use uuid::Uuid;
-use spl_domain::entities::diagnostics::prediction::PredictionDetailed;
 
 mock! {
     pub UserRepository {}
@@ -43,6 +48,8 @@ mock! {
         async fn get_by_username_and_company(&self, username: &str, company_id: Option<Uuid>) -> Result<Option<User>>;
         async fn get_by_username_or_email_and_company(&self, username: Option<String>, email: Option<String>, company_id: Option<Uuid>) -> Result<Option<User>>;
         async fn get_by_company_id(&self, company_id: Uuid) -> Result<Vec<User>> ;
+        async fn count_by_role_id(&self, role_id: i32) -> Result<u64>;
+        async fn count_active_by_role_ids(&self, role_ids: Vec<i32>) -> Result<u64>;
     }
 }
 
@@ -59,6 +66,7 @@ mock! {
     impl TokenGenerator for TokenGenerator {
         fn generate(&self, sub: &str, claims: serde_json::Value) -> Result<String>;
         fn validate(&self, token: &str) -> Result<serde_json::Value>;
+        fn jwks(&self) -> serde_json::Value;
     }
 }
 
@@ -90,7 +98,46 @@ mock! {
     #[async_trait]
     impl CompanyRepository for CompanyRepository {
         async fn get_all(&self) -> Result<Vec<Company>>;
+        async fn get_by_parent_ids(&self, parent_ids: Vec<Uuid>) -> Result<Vec<Company>>;
+    }
+}
+
+mock! {
+    pub CompanySettingsRepository {}
+    #[async_trait]
+    impl CrudRepository<entities::company::CompanySettingsOverride, Uuid> for CompanySettingsRepository {
+        async fn get_by_id(&self, company_id: Uuid) -> Result<Option<entities::company::CompanySettingsOverride>>;
+        async fn create(&self, entity: entities::company::CompanySettingsOverride) -> Result<entities::company::CompanySettingsOverride>;
+        async fn update(&self, entity: entities::company::CompanySettingsOverride) -> Result<entities::company::CompanySettingsOverride>;
+        async fn delete(&self, company_id: Uuid) -> Result<entities::company::CompanySettingsOverride>;
+    }
+    #[async_trait]
+    impl repositories::company::CompanySettingsRepository for CompanySettingsRepository {}
+}
+
+mock! {
+    pub UsageRepository {}
+    #[async_trait]
+    impl repositories::usage::UsageRepository for UsageRepository {
+        async fn increment(&self, user_id: Uuid, company_id: Option<Uuid>, period: chrono::NaiveDate, predictions: u64, stored_bytes: u64) -> Result<()>;
+        async fn reserve(&self, user_id: Uuid, company_id: Option<Uuid>, period: chrono::NaiveDate, stored_bytes: u64, quota: &entities::usage::UsageQuota) -> Result<Option<entities::usage::QuotaLimit>>;
+        async fn release(&self, user_id: Uuid, period: chrono::NaiveDate, predictions: u64, stored_bytes: u64) -> Result<()>;
+        async fn get_by_user(&self, user_id: Uuid, period: chrono::NaiveDate) -> Result<Option<entities::usage::UsageRecord>>;
+        async fn get_by_company(&self, company_id: Uuid, period: chrono::NaiveDate) -> Result<Vec<entities::usage::UsageRecord>>;
+    }
+}
+
+mock! {
+    pub CompanyQuotaRepository {}
+    #[async_trait]
+    impl CrudRepository<entities::usage::CompanyQuota, Uuid> for CompanyQuotaRepository {
+        async fn get_by_id(&self, company_id: Uuid) -> Result<Option<entities::usage::CompanyQuota>>;
+        async fn create(&self, entity: entities::usage::CompanyQuota) -> Result<entities::usage::CompanyQuota>;
+        async fn update(&self, entity: entities::usage::CompanyQuota) -> Result<entities::usage::CompanyQuota>;
+        async fn delete(&self, company_id: Uuid) -> Result<entities::usage::CompanyQuota>;
     }
+    #[async_trait]
+    impl repositories::usage::CompanyQuotaRepository for CompanyQuotaRepository {}
 }
 
 mock! {
@@ -176,7 +223,8 @@ mock! {
         async fn get_by_company_id_and_id(&self, company_id: Uuid, id: Uuid) -> Result<Option<entities::plot::Plot>>;
         async fn get_detailed(
             &self,
-            company_id: Uuid,
+            company_ids: Vec<Uuid>,
+            plot_ids: Option<Vec<Uuid>>,
             offset: u64,
             limit: u64,
             labels: Vec<String>,
@@ -211,15 +259,19 @@ mock! {
             &self,
             prediction_ids: Vec<Uuid>,
             user_id: Uuid,
+            company_id: Option<Uuid>,
             plot_id: Option<Uuid>,
         ) -> Result<Vec<Prediction>>;
         async fn has_unassigned_predictions(&self, user_id: Uuid) -> Result<bool>;
         async fn get_all(&self) -> Result<Vec<Prediction>>;
         async fn get_by_user_id_and_id(&self, user_id: Uuid, id: Uuid) -> Result<Option<Prediction>>;
+        async fn get_by_company_id(&self, company_id: Uuid) -> Result<Vec<Prediction>>;
         async fn filter(
             &self,
-            user_id: Vec<Uuid>,
+            company_ids: Vec<Option<Uuid>>,
+            user_ids: Option<Vec<Uuid>>,
             labels: Option<Vec<String>>,
+            model_versions: Option<Vec<String>>,
             plot_ids: Option<Vec<Option<Uuid>>>,
             min_date: Option<chrono::DateTime<chrono::Utc>>,
             max_date: Option<chrono::DateTime<chrono::Utc>>,
@@ -264,6 +316,56 @@ mock! {
     }
 }
 
+mock! {
+    pub PredictionBatchRepository {}
+    #[async_trait]
+    impl CrudRepository<entities::diagnostics::PredictionBatch, Uuid> for PredictionBatchRepository {
+        async fn get_by_id(&self, id: Uuid) -> Result<Option<entities::diagnostics::PredictionBatch>>;
+        async fn create(&self, entity: entities::diagnostics::PredictionBatch) -> Result<entities::diagnostics::PredictionBatch>;
+        async fn update(&self, entity: entities::diagnostics::PredictionBatch) -> Result<entities::diagnostics::PredictionBatch>;
+        async fn delete(&self, id: Uuid) -> Result<entities::diagnostics::PredictionBatch>;
+    }
+    #[async_trait]
+    impl repositories::diagnostics::PredictionBatchRepository for PredictionBatchRepository {
+        async fn get_by_user_id(&self, user_id: Uuid) -> Result<Vec<entities::diagnostics::PredictionBatch>>;
+        async fn refresh_progress(&self, id: Uuid) -> Result<entities::diagnostics::PredictionBatch>;
+    }
+}
+
+mock! {
+    pub PredictionBatchItemRepository {}
+    #[async_trait]
+    impl CrudRepository<entities::diagnostics::PredictionBatchItem, Uuid> for PredictionBatchItemRepository {
+        async fn get_by_id(&self, id: Uuid) -> Result<Option<entities::diagnostics::PredictionBatchItem>>;
+        async fn create(&self, entity: entities::diagnostics::PredictionBatchItem) -> Result<entities::diagnostics::PredictionBatchItem>;
+        async fn update(&self, entity: entities::diagnostics::PredictionBatchItem) -> Result<entities::diagnostics::PredictionBatchItem>;
+        async fn delete(&self, id: Uuid) -> Result<entities::diagnostics::PredictionBatchItem>;
+    }
+    #[async_trait]
+    impl repositories::diagnostics::PredictionBatchItemRepository for PredictionBatchItemRepository {
+        async fn create_many(&self, items: Vec<entities::diagnostics::PredictionBatchItem>) -> Result<()>;
+        async fn get_by_batch_id(&self, batch_id: Uuid) -> Result<Vec<entities::diagnostics::PredictionBatchItem>>;
+    }
+}
+
+mock! {
+    pub InferenceJobRepository {}
+    #[async_trait]
+    impl CrudRepository<entities::diagnostics::InferenceJob, Uuid> for InferenceJobRepository {
+        async fn get_by_id(&self, id: Uuid) -> Result<Option<entities::diagnostics::InferenceJob>>;
+        async fn create(&self, entity: entities::diagnostics::InferenceJob) -> Result<entities::diagnostics::InferenceJob>;
+        async fn update(&self, entity: entities::diagnostics::InferenceJob) -> Result<entities::diagnostics::InferenceJob>;
+        async fn delete(&self, id: Uuid) -> Result<entities::diagnostics::InferenceJob>;
+    }
+    #[async_trait]
+    impl repositories::diagnostics::InferenceJobRepository for InferenceJobRepository {
+        async fn create_many(&self, jobs: Vec<entities::diagnostics::InferenceJob>) -> Result<()>;
+        async fn claim_next(&self, now: DateTime<Utc>, locked_until: DateTime<Utc>) -> Result<Option<entities::diagnostics::InferenceJob>>;
+        async fn get_by_user_id(&self, user_id: Uuid, status: Option<entities::diagnostics::JobStatus>) -> Result<Vec<entities::diagnostics::InferenceJob>>;
+        async fn get_by_company_id(&self, company_id: Uuid) -> Result<Vec<entities::diagnostics::InferenceJob>>;
+    }
+}
+
 mock! {
     pub DashboardSummaryRepository {}
 
@@ -271,50 +373,66 @@ mock! {
     impl repositories::dashboard::DashboardSummaryRepository for DashboardSummaryRepository {
         async fn get_summary(
         &self,
-        users_ids: Vec<Uuid>,
+        company_ids: Vec<Uuid>,
+        users_ids: Option<Vec<Uuid>>,
         min_date: Option<DateTime<Utc>>,
         max_date: Option<DateTime<Utc>>,
         plot_ids: Vec<Option<Uuid>>,
         labels: Option<Vec<String>>,
+        model_versions: Option<Vec<String>>,
     ) -> Result<DashboardSummary>;
         async fn get_counts(
         &self,
-        users_ids: Vec<Uuid>,
+        company_ids: Vec<Uuid>,
+        users_ids: Option<Vec<Uuid>>,
         min_date: Option<DateTime<Utc>>,
         max_date: Option<DateTime<Utc>>,
         plot_ids: Vec<Option<Uuid>>,
         labels: Option<Vec<String>>,
+        model_versions: Option<Vec<String>>,
         last_n: u64,
     ) -> Result<DashboardCounts>;
-        
+
     async fn get_summary_detailed_plot_by_id(
         &self,
         company_id: Uuid,
         plot_id: Uuid,
-        users_ids: Vec<Uuid>,
+        company_ids: Vec<Uuid>,
+        users_ids: Option<Vec<Uuid>>,
         min_date: Option<DateTime<Utc>>,
         max_date: Option<DateTime<Utc>>,
         labels: Option<Vec<String>>,
+        model_versions: Option<Vec<String>>,
     ) -> Result<Option<DashboardDetailedPlot>>;
 
     async fn get_default_summary_detailed_plot(
         &self,
         company_id: Uuid,
-        users_ids: Vec<Uuid>,
+        company_ids: Vec<Uuid>,
+        users_ids: Option<Vec<Uuid>>,
         min_date: Option<DateTime<Utc>>,
         max_date: Option<DateTime<Utc>>,
         plot_ids: Vec<Option<Uuid>>,
         labels: Option<Vec<String>>,
+        model_versions: Option<Vec<String>>,
     ) -> Result<Option<DashboardDetailedPlot>>;
-        
+
     async fn get_compare(
         &self,
-        users_ids: Vec<Uuid>,
+        company_ids: Vec<Uuid>,
+        users_ids: Option<Vec<Uuid>>,
         min_date: Option<DateTime<Utc>>,
         max_date: Option<DateTime<Utc>>,
         plot_ids: Vec<Option<Uuid>>,
         labels: Option<Vec<String>>,
+        model_versions: Option<Vec<String>>,
     ) -> Result<Vec<DashboardSummary>>;
+
+    async fn get_model_versions(
+        &self,
+        company_ids: Vec<Uuid>,
+        users_ids: Option<Vec<Uuid>>,
+    ) -> Result<Vec<String>>;
     }
 }
 
@@ -353,3 +471,633 @@ mock! {
         async fn get_by_predictions_ids(&self, prediction_ids: Vec<Uuid>) -> Result<Vec<Feedback>>;
     }
 }
+
+mock! {
+    pub SessionRepository {}
+    #[async_trait]
+    impl CrudRepository<entities::auth::Session, Uuid> for SessionRepository {
+        async fn get_by_id(&self, id: Uuid) -> Result<Option<entities::auth::Session>>;
+        async fn create(&self, entity: entities::auth::Session) -> Result<entities::auth::Session>;
+        async fn update(&self, entity: entities::auth::Session) -> Result<entities::auth::Session>;
+        async fn delete(&self, id: Uuid) -> Result<entities::auth::Session>;
+    }
+    #[async_trait]
+    impl repositories::auth::SessionRepository for SessionRepository {
+        async fn revoke(&self, id: Uuid) -> Result<bool>;
+        async fn revoke_by_user_id(&self, user_id: Uuid) -> Result<u64>;
+        async fn get_active_by_user_id(&self, user_id: Uuid) -> Result<Vec<entities::auth::Session>>;
+        async fn touch(&self, id: Uuid, seen_at: DateTime<Utc>, ip_address: Option<String>) -> Result<()>;
+    }
+}
+
+mock! {
+    pub RefreshTokenRepository {}
+    #[async_trait]
+    impl CrudRepository<entities::auth::RefreshToken, Uuid> for RefreshTokenRepository {
+        async fn get_by_id(&self, id: Uuid) -> Result<Option<entities::auth::RefreshToken>>;
+        async fn create(&self, entity: entities::auth::RefreshToken) -> Result<entities::auth::RefreshToken>;
+        async fn update(&self, entity: entities::auth::RefreshToken) -> Result<entities::auth::RefreshToken>;
+        async fn delete(&self, id: Uuid) -> Result<entities::auth::RefreshToken>;
+    }
+    #[async_trait]
+    impl repositories::auth::RefreshTokenRepository for RefreshTokenRepository {
+        async fn get_by_token_hash(&self, token_hash: &str) -> Result<Option<entities::auth::RefreshToken>>;
+        async fn mark_used(&self, id: Uuid) -> Result<bool>;
+    }
+}
+
+mock! {
+    pub PasswordResetTokenRepository {}
+    #[async_trait]
+    impl CrudRepository<entities::auth::PasswordResetToken, Uuid> for PasswordResetTokenRepository {
+        async fn get_by_id(&self, id: Uuid) -> Result<Option<entities::auth::PasswordResetToken>>;
+        async fn create(&self, entity: entities::auth::PasswordResetToken) -> Result<entities::auth::PasswordResetToken>;
+        async fn update(&self, entity: entities::auth::PasswordResetToken) -> Result<entities::auth::PasswordResetToken>;
+        async fn delete(&self, id: Uuid) -> Result<entities::auth::PasswordResetToken>;
+    }
+    #[async_trait]
+    impl repositories::auth::PasswordResetTokenRepository for PasswordResetTokenRepository {
+        async fn get_by_token_hash(&self, token_hash: &str) -> Result<Option<entities::auth::PasswordResetToken>>;
+        async fn mark_used(&self, id: Uuid) -> Result<bool>;
+        async fn invalidate_by_user_id(&self, user_id: Uuid) -> Result<u64>;
+    }
+}
+
+mock! {
+    pub EmailVerificationTokenRepository {}
+    #[async_trait]
+    impl CrudRepository<entities::auth::EmailVerificationToken, Uuid> for EmailVerificationTokenRepository {
+        async fn get_by_id(&self, id: Uuid) -> Result<Option<entities::auth::EmailVerificationToken>>;
+        async fn create(&self, entity: entities::auth::EmailVerificationToken) -> Result<entities::auth::EmailVerificationToken>;
+        async fn update(&self, entity: entities::auth::EmailVerificationToken) -> Result<entities::auth::EmailVerificationToken>;
+        async fn delete(&self, id: Uuid) -> Result<entities::auth::EmailVerificationToken>;
+    }
+    #[async_trait]
+    impl repositories::auth::EmailVerificationTokenRepository for EmailVerificationTokenRepository {
+        async fn get_by_token_hash(&self, token_hash: &str) -> Result<Option<entities::auth::EmailVerificationToken>>;
+        async fn mark_used(&self, id: Uuid) -> Result<bool>;
+        async fn invalidate_by_user_id(&self, user_id: Uuid) -> Result<u64>;
+    }
+}
+
+mock! {
+    pub ImpersonationRepository {}
+    #[async_trait]
+    impl CrudRepository<entities::auth::Impersonation, Uuid> for ImpersonationRepository {
+        async fn get_by_id(&self, id: Uuid) -> Result<Option<entities::auth::Impersonation>>;
+        async fn create(&self, entity: entities::auth::Impersonation) -> Result<entities::auth::Impersonation>;
+        async fn update(&self, entity: entities::auth::Impersonation) -> Result<entities::auth::Impersonation>;
+        async fn delete(&self, id: Uuid) -> Result<entities::auth::Impersonation>;
+    }
+    #[async_trait]
+    impl repositories::auth::ImpersonationRepository for ImpersonationRepository {
+        async fn end(&self, id: Uuid) -> Result<bool>;
+        async fn get_by_user_id(&self, user_id: Uuid) -> Result<Vec<entities::auth::Impersonation>>;
+    }
+}
+
+mock! {
+    pub ImpersonationActionRepository {}
+    #[async_trait]
+    impl CrudRepository<entities::auth::ImpersonationAction, Uuid> for ImpersonationActionRepository {
+        async fn get_by_id(&self, id: Uuid) -> Result<Option<entities::auth::ImpersonationAction>>;
+        async fn create(&self, entity: entities::auth::ImpersonationAction) -> Result<entities::auth::ImpersonationAction>;
+        async fn update(&self, entity: entities::auth::ImpersonationAction) -> Result<entities::auth::ImpersonationAction>;
+        async fn delete(&self, id: Uuid) -> Result<entities::auth::ImpersonationAction>;
+    }
+    #[async_trait]
+    impl repositories::auth::ImpersonationActionRepository for ImpersonationActionRepository {
+        async fn get_by_impersonation_id(&self, impersonation_id: Uuid) -> Result<Vec<entities::auth::ImpersonationAction>>;
+    }
+}
+
+mock! {
+    pub CompanyOffboardingRepository {}
+    #[async_trait]
+    impl CrudRepository<entities::offboarding::CompanyOffboarding, Uuid> for CompanyOffboardingRepository {
+        async fn get_by_id(&self, id: Uuid) -> Result<Option<entities::offboarding::CompanyOffboarding>>;
+        async fn create(&self, entity: entities::offboarding::CompanyOffboarding) -> Result<entities::offboarding::CompanyOffboarding>;
+        async fn update(&self, entity: entities::offboarding::CompanyOffboarding) -> Result<entities::offboarding::CompanyOffboarding>;
+        async fn delete(&self, id: Uuid) -> Result<entities::offboarding::CompanyOffboarding>;
+    }
+    #[async_trait]
+    impl repositories::offboarding::CompanyOffboardingRepository for CompanyOffboardingRepository {
+        async fn get_by_company_id(&self, company_id: Uuid) -> Result<Vec<entities::offboarding::CompanyOffboarding>>;
+        async fn get_running(&self) -> Result<Vec<entities::offboarding::CompanyOffboarding>>;
+    }
+}
+
+mock! {
+    pub OffboardingEventRepository {}
+    #[async_trait]
+    impl CrudRepository<entities::offboarding::OffboardingEvent, Uuid> for OffboardingEventRepository {
+        async fn get_by_id(&self, id: Uuid) -> Result<Option<entities::offboarding::OffboardingEvent>>;
+        async fn create(&self, entity: entities::offboarding::OffboardingEvent) -> Result<entities::offboarding::OffboardingEvent>;
+        async fn update(&self, entity: entities::offboarding::OffboardingEvent) -> Result<entities::offboarding::OffboardingEvent>;
+        async fn delete(&self, id: Uuid) -> Result<entities::offboarding::OffboardingEvent>;
+    }
+    #[async_trait]
+    impl repositories::offboarding::OffboardingEventRepository for OffboardingEventRepository {
+        async fn get_by_offboarding_id(&self, offboarding_id: Uuid) -> Result<Vec<entities::offboarding::OffboardingEvent>>;
+    }
+}
+
+mock! {
+    pub TeamRepository {}
+    #[async_trait]
+    impl CrudRepository<entities::team::Team, Uuid> for TeamRepository {
+        async fn get_by_id(&self, id: Uuid) -> Result<Option<entities::team::Team>>;
+        async fn create(&self, entity: entities::team::Team) -> Result<entities::team::Team>;
+        async fn update(&self, entity: entities::team::Team) -> Result<entities::team::Team>;
+        async fn delete(&self, id: Uuid) -> Result<entities::team::Team>;
+    }
+    #[async_trait]
+    impl repositories::team::TeamRepository for TeamRepository {
+        async fn get_by_company_id(&self, company_id: Uuid) -> Result<Vec<entities::team::Team>>;
+        async fn get_supervised_by(&self, user_id: Uuid) -> Result<Vec<entities::team::Team>>;
+        async fn get_members(&self, team_ids: Vec<Uuid>) -> Result<Vec<entities::team::TeamMember>>;
+        async fn set_member(&self, member: entities::team::TeamMember) -> Result<entities::team::TeamMember>;
+        async fn remove_member(&self, team_id: Uuid, user_id: Uuid) -> Result<()>;
+        async fn get_plot_ids(&self, team_ids: Vec<Uuid>) -> Result<Vec<Uuid>>;
+        async fn add_plot(&self, team_id: Uuid, plot_id: Uuid) -> Result<()>;
+        async fn remove_plot(&self, team_id: Uuid, plot_id: Uuid) -> Result<()>;
+    }
+}
+
+mock! {
+    pub MembershipRepository {}
+    #[async_trait]
+    impl repositories::user::MembershipRepository for MembershipRepository {
+        async fn get_by_user_id(&self, user_id: Uuid) -> Result<Vec<entities::user::CompanyMembership>>;
+        async fn get_by_company_id(&self, company_id: Uuid) -> Result<Vec<entities::user::CompanyMembership>>;
+        async fn get(&self, user_id: Uuid, company_id: Uuid) -> Result<Option<entities::user::CompanyMembership>>;
+        async fn set(&self, user_id: Uuid, company_id: Uuid, role_id: i32) -> Result<entities::user::CompanyMembership>;
+        async fn delete(&self, user_id: Uuid, company_id: Uuid) -> Result<()>;
+        async fn count_by_role_id(&self, role_id: i32) -> Result<u64>;
+    }
+}
+
+mock! {
+    pub LoginAttemptStore {}
+    #[async_trait]
+    impl LoginAttemptStore for LoginAttemptStore {
+        async fn get(&self, user_id: Uuid) -> Result<Option<entities::auth::LoginAttempts>>;
+        async fn record_failure(&self, user_id: Uuid) -> Result<entities::auth::LoginAttempts>;
+        async fn lock(&self, user_id: Uuid, until: DateTime<Utc>) -> Result<entities::auth::LoginAttempts>;
+        async fn clear(&self, user_id: Uuid) -> Result<()>;
+    }
+}
+
+mock! {
+    pub TwoFactorRepository {}
+    #[async_trait]
+    impl CrudRepository<entities::auth::TwoFactor, Uuid> for TwoFactorRepository {
+        async fn get_by_id(&self, id: Uuid) -> Result<Option<entities::auth::TwoFactor>>;
+        async fn create(&self, entity: entities::auth::TwoFactor) -> Result<entities::auth::TwoFactor>;
+        async fn update(&self, entity: entities::auth::TwoFactor) -> Result<entities::auth::TwoFactor>;
+        async fn delete(&self, id: Uuid) -> Result<entities::auth::TwoFactor>;
+    }
+    #[async_trait]
+    impl repositories::auth::TwoFactorRepository for TwoFactorRepository {
+        async fn enable(&self, user_id: Uuid) -> Result<bool>;
+    }
+}
+
+mock! {
+    pub RecoveryCodeRepository {}
+    #[async_trait]
+    impl CrudRepository<entities::auth::RecoveryCode, Uuid> for RecoveryCodeRepository {
+        async fn get_by_id(&self, id: Uuid) -> Result<Option<entities::auth::RecoveryCode>>;
+        async fn create(&self, entity: entities::auth::RecoveryCode) -> Result<entities::auth::RecoveryCode>;
+        async fn update(&self, entity: entities::auth::RecoveryCode) -> Result<entities::auth::RecoveryCode>;
+        async fn delete(&self, id: Uuid) -> Result<entities::auth::RecoveryCode>;
+    }
+    #[async_trait]
+    impl repositories::auth::RecoveryCodeRepository for RecoveryCodeRepository {
+        async fn get_by_code_hash(&self, user_id: Uuid, code_hash: &str) -> Result<Option<entities::auth::RecoveryCode>>;
+        async fn mark_used(&self, id: Uuid) -> Result<bool>;
+        async fn count_unused(&self, user_id: Uuid) -> Result<u64>;
+        async fn delete_by_user_id(&self, user_id: Uuid) -> Result<u64>;
+    }
+}
+
+mock! {
+    pub TwoFactorChallengeRepository {}
+    #[async_trait]
+    impl CrudRepository<entities::auth::TwoFactorChallenge, Uuid> for TwoFactorChallengeRepository {
+        async fn get_by_id(&self, id: Uuid) -> Result<Option<entities::auth::TwoFactorChallenge>>;
+        async fn create(&self, entity: entities::auth::TwoFactorChallenge) -> Result<entities::auth::TwoFactorChallenge>;
+        async fn update(&self, entity: entities::auth::TwoFactorChallenge) -> Result<entities::auth::TwoFactorChallenge>;
+        async fn delete(&self, id: Uuid) -> Result<entities::auth::TwoFactorChallenge>;
+    }
+    #[async_trait]
+    impl repositories::auth::TwoFactorChallengeRepository for TwoFactorChallengeRepository {
+        async fn get_by_token_hash(&self, token_hash: &str) -> Result<Option<entities::auth::TwoFactorChallenge>>;
+        async fn mark_used(&self, id: Uuid) -> Result<bool>;
+    }
+}
+
+mock! {
+    pub ServiceAccountRepository {}
+    #[async_trait]
+    impl CrudRepository<entities::auth::ServiceAccount, Uuid> for ServiceAccountRepository {
+        async fn get_by_id(&self, id: Uuid) -> Result<Option<entities::auth::ServiceAccount>>;
+        async fn create(&self, entity: entities::auth::ServiceAccount) -> Result<entities::auth::ServiceAccount>;
+        async fn update(&self, entity: entities::auth::ServiceAccount) -> Result<entities::auth::ServiceAccount>;
+        async fn delete(&self, id: Uuid) -> Result<entities::auth::ServiceAccount>;
+    }
+    #[async_trait]
+    impl repositories::auth::ServiceAccountRepository for ServiceAccountRepository {
+        async fn get_by_company_id(&self, company_id: Uuid) -> Result<Vec<entities::auth::ServiceAccount>>;
+    }
+}
+
+mock! {
+    pub ApiKeyRepository {}
+    #[async_trait]
+    impl CrudRepository<entities::auth::ApiKey, Uuid> for ApiKeyRepository {
+        async fn get_by_id(&self, id: Uuid) -> Result<Option<entities::auth::ApiKey>>;
+        async fn create(&self, entity: entities::auth::ApiKey) -> Result<entities::auth::ApiKey>;
+        async fn update(&self, entity: entities::auth::ApiKey) -> Result<entities::auth::ApiKey>;
+        async fn delete(&self, id: Uuid) -> Result<entities::auth::ApiKey>;
+    }
+    #[async_trait]
+    impl repositories::auth::ApiKeyRepository for ApiKeyRepository {
+        async fn get_by_key_hash(&self, key_hash: &str) -> Result<Option<entities::auth::ApiKey>>;
+        async fn get_by_service_account_id(&self, service_account_id: Uuid) -> Result<Vec<entities::auth::ApiKey>>;
+        async fn revoke(&self, id: Uuid) -> Result<bool>;
+        async fn touch(&self, id: Uuid, used_at: DateTime<Utc>) -> Result<()>;
+    }
+}
+
+mock! {
+    pub IdentityProviderRepository {}
+    #[async_trait]
+    impl CrudRepository<entities::auth::IdentityProvider, Uuid> for IdentityProviderRepository {
+        async fn get_by_id(&self, company_id: Uuid) -> Result<Option<entities::auth::IdentityProvider>>;
+        async fn create(&self, entity: entities::auth::IdentityProvider) -> Result<entities::auth::IdentityProvider>;
+        async fn update(&self, entity: entities::auth::IdentityProvider) -> Result<entities::auth::IdentityProvider>;
+        async fn delete(&self, company_id: Uuid) -> Result<entities::auth::IdentityProvider>;
+    }
+    #[async_trait]
+    impl repositories::auth::IdentityProviderRepository for IdentityProviderRepository {}
+}
+
+mock! {
+    pub CompanyPasswordPolicyRepository {}
+    #[async_trait]
+    impl CrudRepository<entities::auth::CompanyPasswordPolicy, Uuid> for CompanyPasswordPolicyRepository {
+        async fn get_by_id(&self, company_id: Uuid) -> Result<Option<entities::auth::CompanyPasswordPolicy>>;
+        async fn create(&self, entity: entities::auth::CompanyPasswordPolicy) -> Result<entities::auth::CompanyPasswordPolicy>;
+        async fn update(&self, entity: entities::auth::CompanyPasswordPolicy) -> Result<entities::auth::CompanyPasswordPolicy>;
+        async fn delete(&self, company_id: Uuid) -> Result<entities::auth::CompanyPasswordPolicy>;
+    }
+    #[async_trait]
+    impl repositories::auth::CompanyPasswordPolicyRepository for CompanyPasswordPolicyRepository {}
+}
+
+mock! {
+    pub PasswordHistoryRepository {}
+    #[async_trait]
+    impl repositories::auth::PasswordHistoryRepository for PasswordHistoryRepository {
+        async fn get_recent(&self, user_id: Uuid, limit: u64) -> Result<Vec<String>>;
+        async fn add(&self, user_id: Uuid, password_hash: &str) -> Result<()>;
+        async fn prune(&self, user_id: Uuid, keep: u64) -> Result<u64>;
+    }
+}
+
+mock! {
+    pub UserIdentityRepository {}
+    #[async_trait]
+    impl CrudRepository<entities::auth::UserIdentity, Uuid> for UserIdentityRepository {
+        async fn get_by_id(&self, id: Uuid) -> Result<Option<entities::auth::UserIdentity>>;
+        async fn create(&self, entity: entities::auth::UserIdentity) -> Result<entities::auth::UserIdentity>;
+        async fn update(&self, entity: entities::auth::UserIdentity) -> Result<entities::auth::UserIdentity>;
+        async fn delete(&self, id: Uuid) -> Result<entities::auth::UserIdentity>;
+    }
+    #[async_trait]
+    impl repositories::auth::UserIdentityRepository for UserIdentityRepository {
+        async fn get_by_subject(&self, issuer: &str, subject: &str) -> Result<Option<entities::auth::UserIdentity>>;
+    }
+}
+
+mock! {
+    pub OidcLoginStateRepository {}
+    #[async_trait]
+    impl CrudRepository<entities::auth::OidcLoginState, Uuid> for OidcLoginStateRepository {
+        async fn get_by_id(&self, id: Uuid) -> Result<Option<entities::auth::OidcLoginState>>;
+        async fn create(&self, entity: entities::auth::OidcLoginState) -> Result<entities::auth::OidcLoginState>;
+        async fn update(&self, entity: entities::auth::OidcLoginState) -> Result<entities::auth::OidcLoginState>;
+        async fn delete(&self, id: Uuid) -> Result<entities::auth::OidcLoginState>;
+    }
+    #[async_trait]
+    impl repositories::auth::OidcLoginStateRepository for OidcLoginStateRepository {
+        async fn get_by_state_hash(&self, state_hash: &str) -> Result<Option<entities::auth::OidcLoginState>>;
+        async fn mark_used(&self, id: Uuid) -> Result<bool>;
+    }
+}
+
+mock! {
+    pub OidcClient {}
+    #[async_trait]
+    impl OidcClient for OidcClient {
+        async fn authorization_url(
+            &self,
+            provider: &entities::auth::IdentityProvider,
+            redirect_uri: &str,
+            state: &str,
+            nonce: &str,
+            code_verifier: &str,
+        ) -> Result<String>;
+        async fn exchange_code(
+            &self,
+            provider: &entities::auth::IdentityProvider,
+            code: &str,
+            redirect_uri: &str,
+            code_verifier: &str,
+            nonce: &str,
+        ) -> Result<OidcClaims>;
+    }
+}
+
+mock! {
+    pub TwoFactorProvider {}
+    impl TwoFactorProvider for TwoFactorProvider {
+        fn generate_secret(&self) -> String;
+        fn provisioning_uri(&self, secret: &str, account_name: &str) -> Result<String>;
+        fn verify(&self, secret: &str, code: &str) -> Result<bool>;
+        fn generate_recovery_code(&self) -> String;
+    }
+}
+
+mock! {
+    pub Mailer {}
+    #[async_trait]
+    impl IntegrationClient for Mailer {
+        fn name(&self) -> &'static str;
+        async fn health_check(&self) -> Result<()>;
+    }
+    #[async_trait]
+    impl mailer::Mailer for Mailer {
+        async fn send(&self, message: EmailMessage) -> Result<()>;
+    }
+}
+
+mock! {
+    pub InvitationRepository {}
+    #[async_trait]
+    impl CrudRepository<entities::user::Invitation, Uuid> for InvitationRepository {
+        async fn get_by_id(&self, id: Uuid) -> Result<Option<entities::user::Invitation>>;
+        async fn create(&self, entity: entities::user::Invitation) -> Result<entities::user::Invitation>;
+        async fn update(&self, entity: entities::user::Invitation) -> Result<entities::user::Invitation>;
+        async fn delete(&self, id: Uuid) -> Result<entities::user::Invitation>;
+    }
+    #[async_trait]
+    impl repositories::user::InvitationRepository for InvitationRepository {
+        async fn get_by_token_hash(&self, token_hash: &str) -> Result<Option<entities::user::Invitation>>;
+        async fn get_by_company_id(&self, company_id: Uuid) -> Result<Vec<entities::user::Invitation>>;
+        async fn get_pending_by_email(&self, company_id: Uuid, email: &str) -> Result<Option<entities::user::Invitation>>;
+        async fn mark_accepted(&self, id: Uuid) -> Result<bool>;
+        async fn cancel(&self, id: Uuid) -> Result<bool>;
+        async fn count_by_role_id(&self, role_id: i32) -> Result<u64>;
+    }
+}
+
+mock! {
+    pub PermissionRepository {}
+    #[async_trait]
+    impl repositories::user::PermissionRepository for PermissionRepository {
+        async fn get_grants(&self) -> Result<Vec<entities::user::RolePermission>>;
+        async fn get_by_role_id(&self, role_id: i32) -> Result<Vec<entities::user::RolePermission>>;
+        async fn set_role_grants(&self, role_id: i32, grants: Vec<entities::user::PermissionGrant>) -> Result<()>;
+    }
+}
+
+/// Grants seeded by the permissions migration
+pub fn default_grants() -> Vec<entities::user::RolePermission> {
+    use entities::user::{permissions, PermissionScope};
+
+    let grants = [
+        ("user", permissions::PLOTS_READ, PermissionScope::Company),
+        ("user", permissions::PREDICTIONS_READ, PermissionScope::Own),
+        ("supervisor", permissions::USERS_READ, PermissionScope::Team),
+        (
+            "supervisor",
+            permissions::USERS_MANAGE,
+            PermissionScope::Company,
+        ),
+        ("supervisor", permissions::PLOTS_READ, PermissionScope::Team),
+        (
+            "supervisor",
+            permissions::PLOTS_MANAGE,
+            PermissionScope::Company,
+        ),
+        ("supervisor", permissions::PREDICTIONS_READ, PermissionScope::Team),
+        (
+            "supervisor",
+            permissions::SERVICE_ACCOUNTS_MANAGE,
+            PermissionScope::Company,
+        ),
+        (
+            "supervisor",
+            permissions::SSO_MANAGE,
+            PermissionScope::Company,
+        ),
+        (
+            "supervisor",
+            permissions::SETTINGS_MANAGE,
+            PermissionScope::Company,
+        ),
+        ("supervisor", permissions::USAGE_READ, PermissionScope::Company),
+        ("admin", permissions::USERS_READ, PermissionScope::Any),
+        ("admin", permissions::USERS_MANAGE, PermissionScope::Any),
+        ("admin", permissions::COMPANIES_MANAGE, PermissionScope::Any),
+        ("admin", permissions::TEAMS_MANAGE, PermissionScope::Any),
+        ("admin", permissions::PLOTS_MANAGE, PermissionScope::Any),
+        ("admin", permissions::PREDICTIONS_READ, PermissionScope::Any),
+        ("admin", permissions::CATALOG_MANAGE, PermissionScope::Any),
+        (
+            "admin",
+            permissions::SERVICE_ACCOUNTS_MANAGE,
+            PermissionScope::Any,
+        ),
+        ("admin", permissions::SSO_MANAGE, PermissionScope::Any),
+        ("admin", permissions::ROLES_MANAGE, PermissionScope::Any),
+        ("admin", permissions::SETTINGS_MANAGE, PermissionScope::Any),
+        ("admin", permissions::USAGE_READ, PermissionScope::Any),
+        (
+            "admin",
+            permissions::USERS_IMPERSONATE,
+            PermissionScope::Any,
+        ),
+    ];
+
+    grants
+        .into_iter()
+        .map(|(role, permission, scope)| entities::user::RolePermission {
+            role_id: 0,
+            role: role.to_string(),
+            permission: permission.to_string(),
+            scope,
+        })
+        .collect()
+}
+
+/// Auth persistence mocks. The default accepts any session, refresh token and email
+/// and never locks accounts, so tests not concerned with sessions can log in.
+pub struct AuthMocks {
+    pub session_repo: MockSessionRepository,
+    pub refresh_token_repo: MockRefreshTokenRepository,
+    pub password_reset_token_repo: MockPasswordResetTokenRepository,
+    pub email_verification_token_repo: MockEmailVerificationTokenRepository,
+    pub mailer: MockMailer,
+    pub login_attempt_store: MockLoginAttemptStore,
+    pub two_factor_repo: MockTwoFactorRepository,
+    pub recovery_code_repo: MockRecoveryCodeRepository,
+    pub two_factor_challenge_repo: MockTwoFactorChallengeRepository,
+    pub two_factor_provider: MockTwoFactorProvider,
+    pub service_account_repo: MockServiceAccountRepository,
+    pub api_key_repo: MockApiKeyRepository,
+    pub identity_provider_repo: MockIdentityProviderRepository,
+    pub user_identity_repo: MockUserIdentityRepository,
+    pub oidc_login_state_repo: MockOidcLoginStateRepository,
+    pub oidc_client: MockOidcClient,
+    pub invitation_repo: MockInvitationRepository,
+    pub permission_repo: MockPermissionRepository,
+    pub password_policy_repo: MockCompanyPasswordPolicyRepository,
+    pub password_history_repo: MockPasswordHistoryRepository,
+    pub impersonation_repo: MockImpersonationRepository,
+    pub impersonation_action_repo: MockImpersonationActionRepository,
+    pub company_settings_repo: MockCompanySettingsRepository,
+    pub usage_repo: MockUsageRepository,
+    pub company_quota_repo: MockCompanyQuotaRepository,
+    pub offboarding_repo: MockCompanyOffboardingRepository,
+    pub offboarding_event_repo: MockOffboardingEventRepository,
+    pub prediction_batch_repo: MockPredictionBatchRepository,
+    pub prediction_batch_item_repo: MockPredictionBatchItemRepository,
+    pub inference_job_repo: MockInferenceJobRepository,
+    pub team_repo: MockTeamRepository,
+    pub membership_repo: MockMembershipRepository,
+}
+
+impl Default for AuthMocks {
+    fn default() -> Self {
+        let mut session_repo = MockSessionRepository::new();
+        session_repo.expect_create().returning(Ok);
+        session_repo.expect_touch().returning(|_, _, _| Ok(()));
+
+        let mut refresh_token_repo = MockRefreshTokenRepository::new();
+        refresh_token_repo.expect_create().returning(Ok);
+
+        let mut login_attempt_store = MockLoginAttemptStore::new();
+        login_attempt_store.expect_get().returning(|_| Ok(None));
+        login_attempt_store.expect_clear().returning(|_| Ok(()));
+        login_attempt_store
+            .expect_record_failure()
+            .returning(|user_id| {
+                Ok(entities::auth::LoginAttempts {
+                    user_id,
+                    failed_attempts: 1,
+                    lockout_count: 0,
+                    locked_until: None,
+                })
+            });
+
+        let mut permission_repo = MockPermissionRepository::new();
+        permission_repo
+            .expect_get_grants()
+            .returning(|| Ok(default_grants()));
+
+        // No user has a second factor unless a test says otherwise
+        let mut two_factor_repo = MockTwoFactorRepository::new();
+        two_factor_repo.expect_get_by_id().returning(|_| Ok(None));
+
+        // Companies follow the server password policy, without history
+        let mut password_policy_repo = MockCompanyPasswordPolicyRepository::new();
+        password_policy_repo
+            .expect_get_by_id()
+            .returning(|_| Ok(None));
+        let mut password_history_repo = MockPasswordHistoryRepository::new();
+        password_history_repo
+            .expect_get_recent()
+            .returning(|_, _| Ok(vec![]));
+        password_history_repo.expect_add().returning(|_, _| Ok(()));
+        password_history_repo.expect_prune().returning(|_, _| Ok(0));
+
+        // Companies start with the default settings and store whatever they change
+        let mut company_settings_repo = MockCompanySettingsRepository::new();
+        company_settings_repo
+            .expect_get_by_id()
+            .returning(|_| Ok(None));
+        company_settings_repo.expect_create().returning(Ok);
+
+        // Nothing used yet and no company quota, predictions are counted
+        let mut usage_repo = MockUsageRepository::new();
+        usage_repo.expect_get_by_user().returning(|_, _| Ok(None));
+        usage_repo.expect_get_by_company().returning(|_, _| Ok(vec![]));
+        usage_repo
+            .expect_reserve()
+            .returning(|_, _, _, _, _| Ok(None));
+        usage_repo.expect_release().returning(|_, _, _, _| Ok(()));
+        usage_repo
+            .expect_increment()
+            .returning(|_, _, _, _, _| Ok(()));
+        let mut company_quota_repo = MockCompanyQuotaRepository::new();
+        company_quota_repo.expect_get_by_id().returning(|_| Ok(None));
+
+        // Verification links of new emails are sent and forgotten
+        let mut email_verification_token_repo = MockEmailVerificationTokenRepository::new();
+        email_verification_token_repo
+            .expect_invalidate_by_user_id()
+            .returning(|_| Ok(0));
+        email_verification_token_repo.expect_create().returning(Ok);
+        let mut mailer = MockMailer::new();
+        mailer.expect_send().returning(|_| Ok(()));
+
+        // Companies have no teams, supervisors see all of it
+        let mut team_repo = MockTeamRepository::new();
+        team_repo.expect_get_by_company_id().returning(|_| Ok(vec![]));
+
+        // Users only work for their own company
+        let mut membership_repo = MockMembershipRepository::new();
+        membership_repo.expect_get().returning(|_, _| Ok(None));
+        membership_repo
+            .expect_get_by_user_id()
+            .returning(|_| Ok(vec![]));
+
+        Self {
+            session_repo,
+            refresh_token_repo,
+            password_reset_token_repo: MockPasswordResetTokenRepository::new(),
+            email_verification_token_repo,
+            mailer,
+            login_attempt_store,
+            two_factor_repo,
+            recovery_code_repo: MockRecoveryCodeRepository::new(),
+            two_factor_challenge_repo: MockTwoFactorChallengeRepository::new(),
+            two_factor_provider: MockTwoFactorProvider::new(),
+            service_account_repo: MockServiceAccountRepository::new(),
+            api_key_repo: MockApiKeyRepository::new(),
+            identity_provider_repo: MockIdentityProviderRepository::new(),
+            user_identity_repo: MockUserIdentityRepository::new(),
+            oidc_login_state_repo: MockOidcLoginStateRepository::new(),
+            oidc_client: MockOidcClient::new(),
+            invitation_repo: MockInvitationRepository::new(),
+            permission_repo,
+            password_policy_repo,
+            password_history_repo,
+            impersonation_repo: MockImpersonationRepository::new(),
+            impersonation_action_repo: MockImpersonationActionRepository::new(),
+            company_settings_repo,
+            usage_repo,
+            company_quota_repo,
+            offboarding_repo: MockCompanyOffboardingRepository::new(),
+            offboarding_event_repo: MockOffboardingEventRepository::new(),
+            prediction_batch_repo: MockPredictionBatchRepository::new(),
+            prediction_batch_item_repo: MockPredictionBatchItemRepository::new(),
+            inference_job_repo: MockInferenceJobRepository::new(),
+            team_repo,
+            membership_repo,
+        }
+    }
+}
@@ -1,16 +1,45 @@
+#![allow(dead_code)]
+
 use axum::Router;
 use spl_application::services::{
     access_control::AccessControlService,
     auth::AuthService,
     company::CompanyService,
-    diagnostics::{LabelService, MarkTypeService, PredictionService},
+    company_settings::CompanySettingsService,
+    diagnostics::{
+        InferenceJobService, LabelService, MarkTypeService, PredictionBatchService,
+        PredictionService,
+    },
+    email_verification::EmailVerificationService,
+    impersonation::ImpersonationService,
     feedback::FeedbackService,
+    login_lockout::{LockoutPolicy, LoginLockoutService},
+    offboarding::OffboardingService,
+    password_policy::PasswordPolicyService,
+    password_reset::PasswordResetService,
     plot::PlotService,
+    policy::PolicyService,
     recommendation,
     recommendation::RecommendationService,
-    user::{role::RoleService, UserService},
+    service_account::ServiceAccountService,
+    session::SessionService,
+    sso::SsoService,
+    team::TeamService,
+    two_factor::TwoFactorService,
+    usage::UsageService,
+    user::{role::RoleService, InvitationService, MembershipService, UserService},
 };
+use spl_domain::entities::auth::PasswordPolicy;
+use spl_domain::entities::company::{Company, CompanySettings};
+use spl_domain::entities::user::User;
+use spl_domain::entities::diagnostics::{BatchLimits, JobPolicy};
+use spl_domain::entities::image::ImageFormat;
+use spl_domain::entities::usage::{QuotaEnforcement, UsageQuota};
 use spl_domain::ports::integrations::{BlobStorageClient, ModelPredictionClient};
+use spl_infra::adapters::archive::zip::{ZipArchiveReader, ZipArchiveWriter};
+use spl_infra::adapters::auth::breached_passwords::FileBreachedPasswordList;
+use spl_infra::adapters::auth::opaque::RandomOpaqueTokenGenerator;
+use spl_infra::adapters::cache::memory::NoUserCache;
 use spl_infra::adapters::integrations::{
     model_serving::mock::MockModelClient, storage::mock::MockBlobClient,
 };
@@ -20,6 +49,7 @@ use std::net::SocketAddr;
 use std::sync::Arc;
 
 pub mod config;
+pub mod factories;
 pub mod mocks;
 
 use crate::common::config::create_config;
@@ -54,6 +84,31 @@ pub fn build_app(
     )
 }
 
+pub fn build_auth_app(
+    user_repo: MockUserRepository,
+    encoder: MockPasswordEncoder,
+    token_gen: MockTokenGenerator,
+    auth_mocks: AuthMocks,
+) -> Router {
+    build_app_with_auth(
+        user_repo,
+        MockRoleRepository::new(),
+        MockCompanyRepository::new(),
+        MockRecommendationRepository::new(),
+        MockRecommendationCategoryRepository::new(),
+        MockLabelRepository::new(),
+        MockMarkTypeRepository::new(),
+        MockPlotRepository::new(),
+        MockPredictionRepository::new(),
+        MockPredictionMarkRepository::new(),
+        MockImageRepository::new(),
+        encoder,
+        token_gen,
+        auth_mocks,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn build_app_full(
     mock_user_repo: MockUserRepository,
     mock_role_repo: MockRoleRepository,
@@ -69,31 +124,220 @@ pub fn build_app_full(
     mock_encoder: MockPasswordEncoder,
     mock_token: MockTokenGenerator,
 ) -> Router {
+    build_app_with_auth(
+        mock_user_repo,
+        mock_role_repo,
+        mock_company_repo,
+        mock_rec_repo,
+        mock_rec_category_repo,
+        mock_label_repo,
+        mock_mark_type_repo,
+        mock_plot_repo,
+        mock_prediction_repo,
+        mock_prediction_mark_repo,
+        mock_image_repo,
+        mock_encoder,
+        mock_token,
+        AuthMocks::default(),
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn build_app_with_auth(
+    mock_user_repo: MockUserRepository,
+    mock_role_repo: MockRoleRepository,
+    mock_company_repo: MockCompanyRepository,
+    mock_rec_repo: MockRecommendationRepository,
+    mock_rec_category_repo: MockRecommendationCategoryRepository,
+    mock_label_repo: MockLabelRepository,
+    mock_mark_type_repo: MockMarkTypeRepository,
+    mock_plot_repo: MockPlotRepository,
+    mock_prediction_repo: MockPredictionRepository,
+    mock_prediction_mark_repo: MockPredictionMarkRepository,
+    mock_image_repo: MockImageRepository,
+    mock_encoder: MockPasswordEncoder,
+    mock_token: MockTokenGenerator,
+    auth_mocks: AuthMocks,
+) -> Router {
+    let config = Arc::new(create_config());
+
     let user_repo = Arc::new(mock_user_repo);
     let role_repo = Arc::new(mock_role_repo);
     let company_repo = Arc::new(mock_company_repo);
     let encoder = Arc::new(mock_encoder);
     let token_gen = Arc::new(mock_token);
 
+    let session_repo = Arc::new(auth_mocks.session_repo);
+    let mailer = Arc::new(auth_mocks.mailer);
+
+    let lockout_config = config.login_lockout.clone().unwrap_or_default();
+    let login_lockout_service = Arc::new(LoginLockoutService::new(
+        user_repo.clone(),
+        Arc::new(auth_mocks.login_attempt_store),
+        LockoutPolicy {
+            enabled: lockout_config.enabled,
+            max_attempts: lockout_config.max_attempts(),
+            lockout_seconds: lockout_config.lockout_seconds(),
+            max_lockout_seconds: lockout_config.max_lockout_seconds(),
+        },
+    ));
+
+    let two_factor_service = Arc::new(TwoFactorService::new(
+        Arc::new(auth_mocks.two_factor_repo),
+        Arc::new(auth_mocks.recovery_code_repo),
+        Arc::new(auth_mocks.two_factor_challenge_repo),
+        Arc::new(auth_mocks.two_factor_provider),
+        Arc::new(RandomOpaqueTokenGenerator::new()),
+        config.server.two_factor_challenge_ttl_seconds(),
+    ));
+
+    let membership_repo = Arc::new(auth_mocks.membership_repo);
     let auth_service = Arc::new(AuthService::new(
         user_repo.clone(),
+        membership_repo.clone(),
+        session_repo.clone(),
+        Arc::new(auth_mocks.refresh_token_repo),
         encoder.clone(),
-        token_gen,
+        token_gen.clone(),
+        Arc::new(RandomOpaqueTokenGenerator::new()),
+        login_lockout_service.clone(),
+        two_factor_service.clone(),
+        Arc::new(NoUserCache),
+        config.server.access_token_ttl_seconds(),
+        config.server.refresh_token_ttl_days(),
     ));
 
-    let role_service = Arc::new(RoleService::new(role_repo.clone()));
+    let permission_repo = Arc::new(auth_mocks.permission_repo);
+    let invitation_repo = Arc::new(auth_mocks.invitation_repo);
+
+    let policy_service = Arc::new(PolicyService::new(permission_repo.clone(), 30));
+
+    let role_service = Arc::new(RoleService::new(
+        role_repo.clone(),
+        permission_repo,
+        user_repo.clone(),
+        invitation_repo.clone(),
+        membership_repo.clone(),
+        policy_service.clone(),
+        Arc::new(NoUserCache),
+    ));
 
+    let impersonation_service = Arc::new(ImpersonationService::new(
+        Arc::new(auth_mocks.impersonation_repo),
+        Arc::new(auth_mocks.impersonation_action_repo),
+        user_repo.clone(),
+        token_gen.clone(),
+        policy_service.clone(),
+        config.server.access_token_ttl_seconds(),
+    ));
+
+    let team_repo = Arc::new(auth_mocks.team_repo);
     let access_control_service = Arc::new(AccessControlService::new(
         company_repo.clone(),
+        team_repo.clone(),
+        policy_service.clone(),
+    ));
+
+    let password_policy_config = config.password_policy.clone().unwrap_or_default();
+    let password_policy_service = Arc::new(PasswordPolicyService::new(
+        Arc::new(auth_mocks.password_policy_repo),
+        Arc::new(auth_mocks.password_history_repo),
+        company_repo.clone(),
+        Arc::new(FileBreachedPasswordList::empty()),
+        encoder.clone(),
+        access_control_service.clone(),
+        PasswordPolicy {
+            min_length: password_policy_config.min_length(),
+            require_uppercase: password_policy_config.require_uppercase(),
+            require_lowercase: password_policy_config.require_lowercase(),
+            require_digit: password_policy_config.require_digit(),
+            require_symbol: password_policy_config.require_symbol(),
+            history_size: password_policy_config.history_size(),
+        },
+    ));
+
+    let password_reset_service = Arc::new(PasswordResetService::new(
+        user_repo.clone(),
+        Arc::new(auth_mocks.password_reset_token_repo),
+        session_repo.clone(),
+        encoder.clone(),
+        Arc::new(RandomOpaqueTokenGenerator::new()),
+        mailer.clone(),
+        password_policy_service.clone(),
+        Arc::new(NoUserCache),
+        config.server.frontend_url.clone(),
+        config.server.password_reset_ttl_minutes(),
+    ));
+
+    let email_verification_service = Arc::new(EmailVerificationService::new(
+        user_repo.clone(),
+        Arc::new(auth_mocks.email_verification_token_repo),
+        Arc::new(RandomOpaqueTokenGenerator::new()),
+        mailer.clone(),
+        Arc::new(NoUserCache),
+        config.server.frontend_url.clone(),
+        config.server.email_verification_ttl_hours(),
+    ));
+
+    let service_account_service = Arc::new(ServiceAccountService::new(
+        Arc::new(auth_mocks.service_account_repo),
+        Arc::new(auth_mocks.api_key_repo),
+        user_repo.clone(),
+        role_repo.clone(),
+        company_repo.clone(),
+        encoder.clone(),
+        Arc::new(RandomOpaqueTokenGenerator::new()),
+        access_control_service.clone(),
+    ));
+
+    let oidc_config = config.oidc.clone().unwrap_or_default();
+    let sso_service = Arc::new(SsoService::new(
+        Arc::new(auth_mocks.identity_provider_repo),
+        Arc::new(auth_mocks.user_identity_repo),
+        Arc::new(auth_mocks.oidc_login_state_repo),
+        user_repo.clone(),
+        role_repo.clone(),
+        company_repo.clone(),
+        Arc::new(auth_mocks.oidc_client),
+        encoder.clone(),
+        Arc::new(RandomOpaqueTokenGenerator::new()),
+        auth_service.clone(),
+        access_control_service.clone(),
+        Arc::new(NoUserCache),
+        oidc_config.redirect_url(config.server.frontend_url.as_deref()),
+        oidc_config.state_ttl_seconds(),
+    ));
+
+    let session_service = Arc::new(SessionService::new(
+        session_repo.clone(),
+        user_repo.clone(),
+        access_control_service.clone(),
+    ));
+
+    let invitation_service = Arc::new(InvitationService::new(
+        invitation_repo,
         user_repo.clone(),
+        role_repo.clone(),
+        company_repo.clone(),
+        encoder.clone(),
+        Arc::new(RandomOpaqueTokenGenerator::new()),
+        mailer,
+        access_control_service.clone(),
+        password_policy_service.clone(),
+        config.server.frontend_url.clone(),
+        config.server.invitation_ttl_hours(),
     ));
 
     let user_service = Arc::new(UserService::new(
         user_repo.clone(),
-        role_repo,
+        role_repo.clone(),
         company_repo.clone(),
+        session_repo,
         encoder,
         access_control_service.clone(),
+        password_policy_service.clone(),
+        email_verification_service.clone(),
+        Arc::new(NoUserCache),
     ));
 
     let company_service = Arc::new(CompanyService::new(
@@ -101,6 +345,37 @@ pub fn build_app_full(
         access_control_service.clone(),
     ));
 
+    let company_settings_service = Arc::new(CompanySettingsService::new(
+        Arc::new(auth_mocks.company_settings_repo),
+        company_repo.clone(),
+        access_control_service.clone(),
+        Arc::new(NoUserCache),
+        CompanySettings {
+            timezone: "UTC".to_string(),
+            default_language: "es".to_string(),
+            data_retention_days: 0,
+            two_factor_required_level: None,
+            default_page_size: 16,
+            allowed_upload_formats: ImageFormat::ALL.to_vec(),
+            alert_severity_threshold: 50.0,
+            alert_confidence_threshold: 0.8,
+        },
+    ));
+
+    let usage_service = Arc::new(UsageService::new(
+        Arc::new(auth_mocks.usage_repo),
+        Arc::new(auth_mocks.company_quota_repo),
+        company_repo.clone(),
+        access_control_service.clone(),
+        UsageQuota {
+            predictions_per_month: None,
+            predictions_per_user_per_month: None,
+            stored_bytes_per_month: None,
+            warning_ratio: 0.8,
+            enforcement: QuotaEnforcement::Hard,
+        },
+    ));
+
     let rec_repo = Arc::new(mock_rec_repo);
     let rec_category_repo = Arc::new(mock_rec_category_repo);
 
@@ -113,8 +388,14 @@ pub fn build_app_full(
     let label_repo = Arc::new(mock_label_repo);
     let mark_type_repo = Arc::new(mock_mark_type_repo);
 
-    let label_service = Arc::new(LabelService::new(label_repo.clone()));
-    let mark_type_service = Arc::new(MarkTypeService::new(mark_type_repo.clone()));
+    let label_service = Arc::new(LabelService::new(
+        label_repo.clone(),
+        policy_service.clone(),
+    ));
+    let mark_type_service = Arc::new(MarkTypeService::new(
+        mark_type_repo.clone(),
+        policy_service.clone(),
+    ));
 
     let plot_repo = Arc::new(mock_plot_repo);
     let prediction_repo = Arc::new(mock_prediction_repo);
@@ -124,6 +405,13 @@ pub fn build_app_full(
     let model_client: Arc<dyn ModelPredictionClient> = Arc::new(MockModelClient::new());
     let storage_client: Arc<dyn BlobStorageClient> = Arc::new(MockBlobClient::new());
 
+    let membership_service = Arc::new(MembershipService::new(
+        membership_repo.clone(),
+        user_repo.clone(),
+        role_repo,
+        access_control_service.clone(),
+    ));
+
     let prediction_service = Arc::new(PredictionService::new(
         prediction_repo.clone(),
         user_repo.clone(),
@@ -135,12 +423,68 @@ pub fn build_app_full(
         storage_client.clone(),
         model_client.clone(),
         access_control_service.clone(),
+        company_settings_service.clone(),
+        usage_service.clone(),
+    ));
+
+    let prediction_batch_repo = Arc::new(auth_mocks.prediction_batch_repo);
+    let prediction_batch_item_repo = Arc::new(auth_mocks.prediction_batch_item_repo);
+
+    let inference_job_repo = Arc::new(auth_mocks.inference_job_repo);
+
+    let inference_job_service = Arc::new(InferenceJobService::new(
+        inference_job_repo.clone(),
+        prediction_batch_repo.clone(),
+        prediction_batch_item_repo.clone(),
+        user_repo.clone(),
+        prediction_service.clone(),
+        membership_service.clone(),
+        storage_client.clone(),
+        JobPolicy {
+            max_attempts: 3,
+            retry_delay_seconds: 30,
+            lease_seconds: 300,
+        },
+    ));
+
+    let prediction_batch_service = Arc::new(PredictionBatchService::new(
+        prediction_batch_repo,
+        prediction_batch_item_repo,
+        inference_job_service.clone(),
+        storage_client.clone(),
+        Arc::new(ZipArchiveReader::new()),
+        BatchLimits {
+            max_files: 500,
+            max_extracted_bytes: 256 * 1024 * 1024,
+        },
+    ));
+
+    let offboarding_service = Arc::new(OffboardingService::new(
+        Arc::new(auth_mocks.offboarding_repo),
+        Arc::new(auth_mocks.offboarding_event_repo),
+        company_repo.clone(),
+        user_repo.clone(),
+        plot_repo.clone(),
+        prediction_repo.clone(),
+        inference_job_repo,
+        storage_client.clone(),
+        Arc::new(ZipArchiveWriter::new()),
+        Arc::new(NoUserCache),
+        policy_service.clone(),
     ));
 
     let plot_service = Arc::new(PlotService::new(
         plot_repo.clone(),
         prediction_repo.clone(),
-        access_control_service,
+        access_control_service.clone(),
+        company_settings_service.clone(),
+    ));
+
+    let team_service = Arc::new(TeamService::new(
+        team_repo,
+        user_repo.clone(),
+        plot_repo.clone(),
+        access_control_service.clone(),
     ));
 
     // Initialize Dashboard Service
@@ -150,6 +494,8 @@ pub fn build_app_full(
         label_repo.clone(),
         plot_repo.clone(),
         user_repo.clone(),
+        membership_repo,
+        access_control_service,
     ));
 
     let image_repo = Arc::new(mock_image_repo);
@@ -167,30 +513,40 @@ pub fn build_app_full(
         label_repo.clone(),
     ));
 
-    let config = Arc::new(create_config());
-
-    let mut roles = std::collections::HashMap::new();
-    roles.insert("admin".to_string(), 100);
-    roles.insert("supervisor".to_string(), 50);
-    roles.insert("user".to_string(), 10);
-
     let state = Arc::new(AppState::new(
         config,
         auth_service,
+        password_reset_service,
+        password_policy_service,
+        email_verification_service,
+        impersonation_service,
+        login_lockout_service,
+        two_factor_service,
+        service_account_service,
+        sso_service,
+        session_service,
+        invitation_service,
         role_service,
         user_service,
         company_service,
+        company_settings_service,
+        usage_service,
+        offboarding_service,
+        team_service,
+        membership_service,
         image_service,
         rec_category_service,
         rec_service,
         label_service,
         mark_type_service,
         prediction_service,
+        prediction_batch_service,
+        inference_job_service,
         plot_service,
         dashboard_service,
         feedback_service,
         feedback_status_service,
-        roles,
+        policy_service,
         model_client,
         storage_client,
     ));
@@ -201,3 +557,31 @@ pub fn build_app_full(
     let addr = SocketAddr::from(([127, 0, 0, 1], 0));
     router(state, rate_limit_state).layer(Extension(ConnectInfo(addr)))
 }
+
+/// App where the given user is authenticated and belongs to the given company
+pub fn build_company_app(user: User, company: Company) -> Router {
+    let user_id = user.id;
+
+    let mut user_repo = MockUserRepository::new();
+    user_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(user.clone())));
+
+    let mut token_gen = MockTokenGenerator::new();
+    token_gen
+        .expect_validate()
+        .returning(move |_| Ok(serde_json::json!({ "sub": user_id.to_string() })));
+
+    let mut company_repo = MockCompanyRepository::new();
+    company_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(company.clone())));
+
+    build_app(
+        user_repo,
+        MockRoleRepository::new(),
+        company_repo,
+        MockPasswordEncoder::new(),
+        token_gen,
+    )
+}
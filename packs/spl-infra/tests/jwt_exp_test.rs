@@ -10,7 +10,7 @@ fn test_jwt_expiration() {
     let config = create_config();
     let expected_hours = config.server.jwt_expiration_hours;
 
-    let generator = JwtTokenGenerator::new(Arc::new(config));
+    let generator = JwtTokenGenerator::new(Arc::new(config)).unwrap();
     let claims = serde_json::json!({
         "role": "User"
     });
@@ -32,3 +32,28 @@ fn test_jwt_expiration() {
         expected_hours
     );
 }
+
+#[test]
+fn test_jwt_expiration_uses_minutes_when_configured() {
+    let mut config = create_config();
+    config.server.access_token_expiration_minutes = Some(15);
+
+    let generator = JwtTokenGenerator::new(Arc::new(config)).unwrap();
+    let claims = serde_json::json!({
+        "role": "User"
+    });
+
+    let now = chrono::Utc::now().timestamp() as u64;
+    let token = generator.generate("test_user", claims).unwrap();
+    let decoded = generator.validate(&token).unwrap();
+
+    let exp = decoded["exp"].as_u64().unwrap();
+    let expected_exp = now + 15 * 60;
+
+    assert!(
+        exp >= expected_exp - 10 && exp <= expected_exp + 10,
+        "JWT expiration mismatch: exp={}, expected={} (±10s)",
+        exp,
+        expected_exp
+    );
+}
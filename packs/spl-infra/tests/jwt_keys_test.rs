@@ -0,0 +1,162 @@
+use crate::common::config::create_config;
+use spl_domain::ports::auth::TokenGenerator;
+use spl_infra::adapters::auth::jwt::JwtTokenGenerator;
+use spl_shared::config::{AppConfig, JwtKeyConfig};
+use std::sync::Arc;
+
+mod common;
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/jwt/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+fn key(kid: &str, algorithm: &str, name: &str, with_private: bool) -> JwtKeyConfig {
+    JwtKeyConfig {
+        kid: kid.to_string(),
+        algorithm: algorithm.to_string(),
+        private_key_path: with_private.then(|| fixture(&format!("{name}_private.pem"))),
+        public_key_path: fixture(&format!("{name}_public.pem")),
+    }
+}
+
+fn config_with_keys(keys: Vec<JwtKeyConfig>, signing_kid: Option<&str>) -> AppConfig {
+    let mut config = create_config();
+    config.server.jwt_keys = Some(keys);
+    config.server.jwt_signing_kid = signing_kid.map(str::to_string);
+    config
+}
+
+fn header(token: &str) -> serde_json::Value {
+    let header = jsonwebtoken::decode_header(token).unwrap();
+    serde_json::to_value(header).unwrap()
+}
+
+#[test]
+fn test_rs256_token_round_trip() {
+    let config = config_with_keys(vec![key("rsa-1", "RS256", "rsa", true)], None);
+    let generator = JwtTokenGenerator::new(Arc::new(config)).unwrap();
+
+    let token = generator
+        .generate("user", serde_json::json!({ "role": "admin" }))
+        .unwrap();
+
+    let header = header(&token);
+    assert_eq!(header["alg"], "RS256");
+    assert_eq!(header["kid"], "rsa-1");
+
+    let claims = generator.validate(&token).unwrap();
+    assert_eq!(claims["sub"], "user");
+    assert_eq!(claims["role"], "admin");
+}
+
+#[test]
+fn test_eddsa_token_round_trip() {
+    let config = config_with_keys(vec![key("ed-1", "EdDSA", "ed25519", true)], None);
+    let generator = JwtTokenGenerator::new(Arc::new(config)).unwrap();
+
+    let token = generator
+        .generate("user", serde_json::json!({ "role": "user" }))
+        .unwrap();
+
+    assert_eq!(header(&token)["alg"], "EdDSA");
+    assert!(generator.validate(&token).is_ok());
+}
+
+#[test]
+fn test_rotated_key_still_verifies_old_tokens() {
+    let old = JwtTokenGenerator::new(Arc::new(config_with_keys(
+        vec![key("old", "RS256", "rsa_old", true)],
+        None,
+    )))
+    .unwrap();
+    let old_token = old
+        .generate("user", serde_json::json!({ "role": "user" }))
+        .unwrap();
+
+    let rotated = JwtTokenGenerator::new(Arc::new(config_with_keys(
+        vec![
+            key("old", "RS256", "rsa_old", false),
+            key("new", "EdDSA", "ed25519", true),
+        ],
+        Some("new"),
+    )))
+    .unwrap();
+
+    let new_token = rotated
+        .generate("user", serde_json::json!({ "role": "user" }))
+        .unwrap();
+    assert_eq!(header(&new_token)["kid"], "new");
+
+    assert!(rotated.validate(&old_token).is_ok());
+    assert!(rotated.validate(&new_token).is_ok());
+}
+
+#[test]
+fn test_unknown_kid_and_hs256_tokens_are_rejected() {
+    let hs256 = JwtTokenGenerator::new(Arc::new(create_config())).unwrap();
+    let hs256_token = hs256
+        .generate("user", serde_json::json!({ "role": "user" }))
+        .unwrap();
+
+    let other = JwtTokenGenerator::new(Arc::new(config_with_keys(
+        vec![key("other", "RS256", "rsa_old", true)],
+        None,
+    )))
+    .unwrap();
+    let other_token = other
+        .generate("user", serde_json::json!({ "role": "user" }))
+        .unwrap();
+
+    let generator = JwtTokenGenerator::new(Arc::new(config_with_keys(
+        vec![key("rsa-1", "RS256", "rsa", true)],
+        None,
+    )))
+    .unwrap();
+
+    assert!(generator.validate(&hs256_token).is_err());
+    assert!(generator.validate(&other_token).is_err());
+}
+
+#[test]
+fn test_jwks_publishes_all_public_keys() {
+    let generator = JwtTokenGenerator::new(Arc::new(config_with_keys(
+        vec![
+            key("rsa-1", "RS256", "rsa", true),
+            key("ed-1", "EdDSA", "ed25519", false),
+        ],
+        Some("rsa-1"),
+    )))
+    .unwrap();
+
+    let jwks = generator.jwks();
+    let keys = jwks["keys"].as_array().unwrap();
+    assert_eq!(keys.len(), 2);
+
+    assert_eq!(keys[0]["kid"], "rsa-1");
+    assert_eq!(keys[0]["kty"], "RSA");
+    assert_eq!(keys[0]["e"], "AQAB");
+    assert!(keys[0].get("d").is_none());
+
+    assert_eq!(keys[1]["kid"], "ed-1");
+    assert_eq!(keys[1]["kty"], "OKP");
+    assert_eq!(keys[1]["crv"], "Ed25519");
+
+    // The published RSA key verifies tokens signed with the private key
+    let token = generator
+        .generate("user", serde_json::json!({ "role": "user" }))
+        .unwrap();
+    let jwk: jsonwebtoken::jwk::Jwk = serde_json::from_value(keys[0].clone()).unwrap();
+    let decoding_key = jsonwebtoken::DecodingKey::from_jwk(&jwk).unwrap();
+    let decoded = jsonwebtoken::decode::<serde_json::Value>(
+        &token,
+        &decoding_key,
+        &jsonwebtoken::Validation::new(jsonwebtoken::Algorithm::RS256),
+    );
+    assert!(decoded.is_ok());
+}
+
+#[test]
+fn test_hs256_has_empty_jwks() {
+    let generator = JwtTokenGenerator::new(Arc::new(create_config())).unwrap();
+    assert_eq!(generator.jwks(), serde_json::json!({ "keys": [] }));
+}
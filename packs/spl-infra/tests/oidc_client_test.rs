@@ -0,0 +1,287 @@
+use crate::common::config::create_config;
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::routing::{get, post};
+use axum::{Form, Json, Router};
+use chrono::Utc;
+use jsonwebtoken::{encode, Algorithm, EncodingKey, Header};
+use spl_domain::entities::auth::IdentityProvider;
+use spl_domain::ports::auth::TokenGenerator;
+use spl_domain::ports::oidc::OidcClient;
+use spl_infra::adapters::auth::jwt::JwtTokenGenerator;
+use spl_infra::adapters::auth::oidc::{pkce_challenge, HttpOidcClient};
+use spl_shared::config::JwtKeyConfig;
+use spl_shared::error::AppError;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+mod common;
+
+const CLIENT_ID: &str = "spl-backend";
+const CODE: &str = "authorization-code";
+const CODE_VERIFIER: &str = "code-verifier-with-enough-entropy";
+const NONCE: &str = "nonce-value";
+const REDIRECT_URI: &str = "http://localhost:3000/auth/callback";
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/jwt/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+/// Local identity provider: discovery, JWKS and a token endpoint checking PKCE
+struct MockIdp {
+    issuer: String,
+    jwks: serde_json::Value,
+    signing_kid: String,
+    signing_key: EncodingKey,
+    id_token_claims: serde_json::Value,
+    jwks_requests: AtomicUsize,
+}
+
+async fn discovery(State(idp): State<Arc<MockIdp>>) -> Json<serde_json::Value> {
+    Json(serde_json::json!({
+        "issuer": idp.issuer,
+        "authorization_endpoint": format!("{}/authorize", idp.issuer),
+        "token_endpoint": format!("{}/token", idp.issuer),
+        "jwks_uri": format!("{}/jwks", idp.issuer),
+    }))
+}
+
+async fn jwks(State(idp): State<Arc<MockIdp>>) -> Json<serde_json::Value> {
+    idp.jwks_requests.fetch_add(1, Ordering::SeqCst);
+    Json(idp.jwks.clone())
+}
+
+async fn token(
+    State(idp): State<Arc<MockIdp>>,
+    Form(form): Form<HashMap<String, String>>,
+) -> Result<Json<serde_json::Value>, StatusCode> {
+    let field = |name: &str| form.get(name).map(String::as_str);
+
+    let valid = field("grant_type") == Some("authorization_code")
+        && field("code") == Some(CODE)
+        && field("client_id") == Some(CLIENT_ID)
+        && field("redirect_uri") == Some(REDIRECT_URI)
+        && field("code_verifier").map(pkce_challenge) == Some(pkce_challenge(CODE_VERIFIER));
+
+    if !valid {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+
+    let mut header = Header::new(Algorithm::RS256);
+    header.kid = Some(idp.signing_kid.clone());
+    let id_token = encode(&header, &idp.id_token_claims, &idp.signing_key).unwrap();
+
+    Ok(Json(serde_json::json!({
+        "access_token": "idp-access-token",
+        "token_type": "Bearer",
+        "id_token": id_token,
+    })))
+}
+
+fn published_jwks() -> serde_json::Value {
+    let mut config = create_config();
+    config.server.jwt_keys = Some(vec![JwtKeyConfig {
+        kid: "idp-1".to_string(),
+        algorithm: "RS256".to_string(),
+        private_key_path: Some(fixture("rsa_private.pem")),
+        public_key_path: fixture("rsa_public.pem"),
+    }]);
+
+    JwtTokenGenerator::new(Arc::new(config)).unwrap().jwks()
+}
+
+/// Starts the identity provider, `customize` adjusts the ID token before it is signed
+async fn start_idp(
+    signing_kid: &str,
+    key_fixture: &str,
+    customize: impl FnOnce(&mut serde_json::Value),
+) -> Arc<MockIdp> {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let issuer = format!("http://{}", listener.local_addr().unwrap());
+
+    let mut claims = serde_json::json!({
+        "iss": issuer,
+        "aud": CLIENT_ID,
+        "sub": "idp-user-1",
+        "exp": Utc::now().timestamp() + 300,
+        "nonce": NONCE,
+        "email": "jdoe@example.com",
+        "email_verified": true,
+        "preferred_username": "jdoe",
+        "given_name": "John",
+        "family_name": "Doe",
+        "realm_access": { "roles": ["agronomists", "offline_access"] },
+    });
+    customize(&mut claims);
+
+    let pem = std::fs::read(fixture(key_fixture)).unwrap();
+    let idp = Arc::new(MockIdp {
+        issuer,
+        jwks: published_jwks(),
+        signing_kid: signing_kid.to_string(),
+        signing_key: EncodingKey::from_rsa_pem(&pem).unwrap(),
+        id_token_claims: claims,
+        jwks_requests: AtomicUsize::new(0),
+    });
+
+    let app = Router::new()
+        .route("/.well-known/openid-configuration", get(discovery))
+        .route("/jwks", get(jwks))
+        .route("/token", post(token))
+        .with_state(idp.clone());
+
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+    idp
+}
+
+fn provider(issuer: &str) -> IdentityProvider {
+    IdentityProvider {
+        company_id: uuid::Uuid::new_v4(),
+        issuer: issuer.to_string(),
+        client_id: CLIENT_ID.to_string(),
+        client_secret: None,
+        scopes: "openid email profile".to_string(),
+        role_claim: "realm_access.roles".to_string(),
+        role_mapping: BTreeMap::new(),
+        default_role: None,
+        auto_provision: false,
+        enabled: true,
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+    }
+}
+
+fn client() -> HttpOidcClient {
+    HttpOidcClient::new(Duration::from_secs(5))
+}
+
+#[tokio::test]
+async fn test_authorization_url_uses_pkce() {
+    let idp = start_idp("idp-1", "rsa_private.pem", |_| {}).await;
+
+    let url = client()
+        .authorization_url(
+            &provider(&idp.issuer),
+            REDIRECT_URI,
+            "state-1",
+            NONCE,
+            CODE_VERIFIER,
+        )
+        .await
+        .unwrap();
+    let url = reqwest::Url::parse(&url).unwrap();
+    let query: HashMap<_, _> = url.query_pairs().into_owned().collect();
+
+    assert_eq!(url.path(), "/authorize");
+    assert_eq!(query["response_type"], "code");
+    assert_eq!(query["client_id"], CLIENT_ID);
+    assert_eq!(query["redirect_uri"], REDIRECT_URI);
+    assert_eq!(query["state"], "state-1");
+    assert_eq!(query["nonce"], NONCE);
+    assert_eq!(query["code_challenge_method"], "S256");
+    assert_eq!(query["code_challenge"], pkce_challenge(CODE_VERIFIER));
+}
+
+#[tokio::test]
+async fn test_exchange_code_validates_id_token() {
+    let idp = start_idp("idp-1", "rsa_private.pem", |_| {}).await;
+    let client = client();
+    let provider = provider(&idp.issuer);
+
+    let claims = client
+        .exchange_code(&provider, CODE, REDIRECT_URI, CODE_VERIFIER, NONCE)
+        .await
+        .unwrap();
+
+    assert_eq!(claims.subject, "idp-user-1");
+    assert_eq!(claims.email.as_deref(), Some("jdoe@example.com"));
+    assert!(claims.email_verified);
+    assert_eq!(claims.preferred_username.as_deref(), Some("jdoe"));
+    assert_eq!(claims.roles, vec!["agronomists", "offline_access"]);
+
+    // Discovery and keys are cached between logins
+    client
+        .exchange_code(&provider, CODE, REDIRECT_URI, CODE_VERIFIER, NONCE)
+        .await
+        .unwrap();
+    assert_eq!(idp.jwks_requests.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn test_exchange_code_rejects_wrong_verifier() {
+    let idp = start_idp("idp-1", "rsa_private.pem", |_| {}).await;
+
+    let result = client()
+        .exchange_code(
+            &provider(&idp.issuer),
+            CODE,
+            REDIRECT_URI,
+            "other-verifier",
+            NONCE,
+        )
+        .await;
+
+    assert!(matches!(result, Err(AppError::AuthError(_))));
+}
+
+#[tokio::test]
+async fn test_exchange_code_rejects_nonce_mismatch() {
+    let idp = start_idp("idp-1", "rsa_private.pem", |claims| {
+        claims["nonce"] = "replayed".into();
+    })
+    .await;
+
+    let result = client()
+        .exchange_code(
+            &provider(&idp.issuer),
+            CODE,
+            REDIRECT_URI,
+            CODE_VERIFIER,
+            NONCE,
+        )
+        .await;
+
+    assert!(matches!(result, Err(AppError::AuthError(_))));
+}
+
+#[tokio::test]
+async fn test_exchange_code_rejects_other_audience() {
+    let idp = start_idp("idp-1", "rsa_private.pem", |claims| {
+        claims["aud"] = "another-client".into();
+    })
+    .await;
+
+    let result = client()
+        .exchange_code(
+            &provider(&idp.issuer),
+            CODE,
+            REDIRECT_URI,
+            CODE_VERIFIER,
+            NONCE,
+        )
+        .await;
+
+    assert!(matches!(result, Err(AppError::AuthError(_))));
+}
+
+#[tokio::test]
+async fn test_exchange_code_rejects_unknown_key() {
+    let idp = start_idp("idp-2", "rsa_old_private.pem", |_| {}).await;
+
+    let result = client()
+        .exchange_code(
+            &provider(&idp.issuer),
+            CODE,
+            REDIRECT_URI,
+            CODE_VERIFIER,
+            NONCE,
+        )
+        .await;
+
+    assert!(matches!(result, Err(AppError::AuthError(_))));
+    // The key set is fetched again in case the provider rotated its keys
+    assert_eq!(idp.jwks_requests.load(Ordering::SeqCst), 2);
+}
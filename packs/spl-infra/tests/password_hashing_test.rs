@@ -0,0 +1,53 @@
+use spl_domain::ports::auth::{BreachedPasswordList, PasswordEncoder};
+use spl_infra::adapters::auth::breached_passwords::FileBreachedPasswordList;
+use spl_infra::adapters::auth::password::Argon2PasswordEncoder;
+
+// Low costs keep the tests fast
+fn encoder(memory_kib: u32, iterations: u32) -> Argon2PasswordEncoder {
+    Argon2PasswordEncoder::with_params(memory_kib, iterations, 1).unwrap()
+}
+
+#[test]
+fn test_hash_with_current_parameters_needs_no_rehash() {
+    let encoder = encoder(1024, 1);
+    let hash = encoder.hash("secret-password").unwrap();
+
+    assert!(hash.contains("m=1024,t=1,p=1"));
+    assert!(!encoder.needs_rehash(&hash));
+}
+
+#[test]
+fn test_hash_with_weaker_parameters_needs_rehash() {
+    let hash = encoder(1024, 1).hash("secret-password").unwrap();
+    let stronger = encoder(2048, 2);
+
+    assert!(stronger.verify("secret-password", &hash).unwrap());
+    assert!(stronger.needs_rehash(&hash));
+}
+
+#[test]
+fn test_hash_with_stronger_parameters_is_kept() {
+    let hash = encoder(2048, 2).hash("secret-password").unwrap();
+
+    assert!(!encoder(1024, 1).needs_rehash(&hash));
+}
+
+#[test]
+fn test_invalid_parameters_are_rejected() {
+    assert!(Argon2PasswordEncoder::with_params(1024, 0, 1).is_err());
+}
+
+#[test]
+fn test_breached_passwords_ignore_case_and_blank_lines() {
+    let list = FileBreachedPasswordList::from_lines("password123\n\n  Qwerty  \r\nletmein\n");
+
+    assert_eq!(list.len(), 3);
+    assert!(list.contains("PASSWORD123"));
+    assert!(list.contains("qwerty"));
+    assert!(!list.contains("correct horse battery staple"));
+}
+
+#[test]
+fn test_missing_breached_passwords_file_fails() {
+    assert!(FileBreachedPasswordList::load("/nonexistent/breached.txt").is_err());
+}
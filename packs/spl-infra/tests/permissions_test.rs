@@ -3,7 +3,7 @@ use axum::{
     http::{Method, Request, StatusCode},
 };
 use mockall::predicate::*;
-use spl_domain::entities::user::{Role, User};
+use spl_domain::entities::user::{permissions, PermissionScope, Role, RolePermission, User};
 use tower::ServiceExt;
 
 mod common;
@@ -39,11 +39,13 @@ async fn test_rbac_admin_access_user_route() {
         id: user_id,
         username: "test_admin".to_string(),
         email: Some("admin@example.com".to_string()),
+        email_verified_at: None,
         password_hash: "hashed_secret".to_string(),
         name: None,
         surname: None,
         role: admin_role.clone(), // Has Admin Role object
         company: None,
+        deactivated_at: None,
         created_at: chrono::Utc::now(),
         updated_at: chrono::Utc::now(),
     };
@@ -102,3 +104,126 @@ async fn test_rbac_admin_access_user_route() {
     // Admin (100) >= User (10) -> Should be OK (200)
     assert_eq!(response.status(), StatusCode::OK);
 }
+
+fn user_with_role(role_name: &str) -> User {
+    User {
+        id: uuid::Uuid::new_v4(),
+        username: format!("test_{}", role_name),
+        email: Some(format!("{}@example.com", role_name)),
+        email_verified_at: None,
+        password_hash: "hashed_secret".to_string(),
+        name: None,
+        surname: None,
+        role: Role {
+            id: 4,
+            name: role_name.to_string(),
+            level: 30,
+            created_at: chrono::Utc::now(),
+            updated_at: chrono::Utc::now(),
+        },
+        company: None,
+        deactivated_at: None,
+        created_at: chrono::Utc::now(),
+        updated_at: chrono::Utc::now(),
+    }
+}
+
+async fn get_lockout_as(user: User, grants: Vec<RolePermission>) -> StatusCode {
+    let path = format!("/api/v1/users/{}/lockout", user.id);
+    get_as(user, grants, &path).await
+}
+
+async fn get_as(user: User, grants: Vec<RolePermission>, path: &str) -> StatusCode {
+    let user_id = user.id;
+
+    let mut mock_repo = MockUserRepository::new();
+    mock_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(user.clone())));
+
+    let mut mock_token = MockTokenGenerator::new();
+    mock_token
+        .expect_validate()
+        .returning(move |_| Ok(serde_json::json!({ "sub": user_id.to_string() })));
+
+    let mut auth_mocks = AuthMocks::default();
+    let mut permission_repo = MockPermissionRepository::new();
+    permission_repo
+        .expect_get_grants()
+        .returning(move || Ok(grants.clone()));
+    auth_mocks.permission_repo = permission_repo;
+
+    let app = build_auth_app(
+        mock_repo,
+        MockPasswordEncoder::new(),
+        mock_token,
+        auth_mocks,
+    );
+
+    app.oneshot(
+        Request::builder()
+            .method(Method::GET)
+            .uri(path)
+            .header("Authorization", "Bearer valid_token")
+            .body(Body::empty())
+            .unwrap(),
+    )
+    .await
+    .unwrap()
+    .status()
+}
+
+#[tokio::test]
+async fn test_custom_role_is_authorized_by_its_grants() {
+    let grants = vec![RolePermission {
+        role_id: 4,
+        role: "agronomist".to_string(),
+        permission: permissions::USERS_MANAGE.to_string(),
+        scope: PermissionScope::Any,
+    }];
+
+    let status = get_lockout_as(user_with_role("agronomist"), grants).await;
+
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_route_rejects_role_with_narrower_scope() {
+    let grants = vec![RolePermission {
+        role_id: 4,
+        role: "agronomist".to_string(),
+        permission: permissions::USERS_MANAGE.to_string(),
+        scope: PermissionScope::Company,
+    }];
+
+    let status = get_lockout_as(user_with_role("agronomist"), grants).await;
+
+    assert_eq!(status, StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_route_rejects_role_without_grant() {
+    let status = get_lockout_as(user_with_role("agronomist"), default_grants()).await;
+
+    assert_eq!(status, StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_roles_require_roles_manage_grant() {
+    let status = get_as(
+        user_with_role("supervisor"),
+        default_grants(),
+        "/api/v1/roles",
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_password_policy_requires_users_manage_grant() {
+    let path = format!("/api/v1/companies/{}/password-policy", uuid::Uuid::new_v4());
+    let status = get_as(user_with_role("user"), default_grants(), &path).await;
+
+    assert_eq!(status, StatusCode::FORBIDDEN);
+}
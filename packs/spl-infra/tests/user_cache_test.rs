@@ -0,0 +1,81 @@
+use chrono::Utc;
+use spl_domain::entities::user::{Role, User};
+use spl_domain::ports::cache::UserCache;
+use spl_infra::adapters::cache::memory::InMemoryUserCache;
+use uuid::Uuid;
+
+fn user() -> User {
+    User {
+        id: Uuid::new_v4(),
+        username: "cached".to_string(),
+        email: None,
+        email_verified_at: None,
+        password_hash: "hash".to_string(),
+        name: None,
+        surname: None,
+        role: Role {
+            id: 1,
+            name: "user".to_string(),
+            level: 10,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        },
+        company: None,
+        deactivated_at: None,
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+    }
+}
+
+#[tokio::test]
+async fn test_cached_user_is_returned_until_invalidated() {
+    let cache = InMemoryUserCache::new(10, 60);
+    let user = user();
+
+    cache.set(&user).await;
+    assert_eq!(cache.get(user.id).await.map(|u| u.id), Some(user.id));
+
+    cache.invalidate(user.id).await;
+    assert!(cache.get(user.id).await.is_none());
+}
+
+#[tokio::test]
+async fn test_expired_user_is_a_miss() {
+    let cache = InMemoryUserCache::new(10, 0);
+    let user = user();
+
+    cache.set(&user).await;
+
+    assert!(cache.get(user.id).await.is_none());
+}
+
+#[tokio::test]
+async fn test_clear_drops_every_user() {
+    let cache = InMemoryUserCache::new(10, 60);
+    let first = user();
+    let second = user();
+
+    cache.set(&first).await;
+    cache.set(&second).await;
+    cache.clear().await;
+
+    assert!(cache.get(first.id).await.is_none());
+    assert!(cache.get(second.id).await.is_none());
+}
+
+#[tokio::test]
+async fn test_least_recently_used_user_is_evicted() {
+    let cache = InMemoryUserCache::new(2, 60);
+    let first = user();
+    let second = user();
+    let third = user();
+
+    cache.set(&first).await;
+    cache.set(&second).await;
+    cache.get(first.id).await;
+    cache.set(&third).await;
+
+    assert!(cache.get(first.id).await.is_some());
+    assert!(cache.get(second.id).await.is_none());
+    assert!(cache.get(third.id).await.is_some());
+}
@@ -25,6 +25,8 @@ async fn test_get_company_success() {
         id: company_id,
         name: "Test Company".to_string(),
         description: Some("Description".to_string()),
+        parent_id: None,
+        two_factor_required_level: None,
         created_at: chrono::Utc::now(),
         updated_at: chrono::Utc::now(),
     };
@@ -33,6 +35,7 @@ async fn test_get_company_success() {
         id: user_id,
         username: "testuser".to_string(),
         email: Some("test@example.com".to_string()),
+        email_verified_at: None,
         password_hash: "hashed".to_string(),
         name: None,
         surname: None,
@@ -44,6 +47,7 @@ async fn test_get_company_success() {
             updated_at: chrono::Utc::now(),
         },
         company: Some(company.clone()),
+        deactivated_at: None,
         created_at: chrono::Utc::now(),
         updated_at: chrono::Utc::now(),
     };
@@ -103,6 +107,7 @@ async fn test_create_company_admin_only_success() {
         id: user_id,
         username: "adminuser".to_string(),
         email: Some("admin@example.com".to_string()),
+        email_verified_at: None,
         password_hash: "hashed".to_string(),
         name: None,
         surname: None,
@@ -114,6 +119,7 @@ async fn test_create_company_admin_only_success() {
             updated_at: chrono::Utc::now(),
         },
         company: None,
+        deactivated_at: None,
         created_at: chrono::Utc::now(),
         updated_at: chrono::Utc::now(),
     };
@@ -179,6 +185,7 @@ async fn test_update_company_success() {
         id: user_id,
         username: "adminuser".to_string(),
         email: Some("admin@example.com".to_string()),
+        email_verified_at: None,
         password_hash: "hashed".to_string(),
         name: None,
         surname: None,
@@ -190,6 +197,7 @@ async fn test_update_company_success() {
             updated_at: chrono::Utc::now(),
         },
         company: None,
+        deactivated_at: None,
         created_at: chrono::Utc::now(),
         updated_at: chrono::Utc::now(),
     };
@@ -198,6 +206,8 @@ async fn test_update_company_success() {
         id: company_id,
         name: "Test Company".to_string(),
         description: Some("Description".to_string()),
+        parent_id: None,
+        two_factor_required_level: None,
         created_at: chrono::Utc::now(),
         updated_at: chrono::Utc::now(),
     };
@@ -273,6 +283,7 @@ async fn test_delete_company_success() {
         id: user_id,
         username: "adminuser".to_string(),
         email: Some("admin@example.com".to_string()),
+        email_verified_at: None,
         password_hash: "hashed".to_string(),
         name: None,
         surname: None,
@@ -284,6 +295,7 @@ async fn test_delete_company_success() {
             updated_at: chrono::Utc::now(),
         },
         company: None,
+        deactivated_at: None,
         created_at: chrono::Utc::now(),
         updated_at: chrono::Utc::now(),
     };
@@ -292,6 +304,8 @@ async fn test_delete_company_success() {
         id: company_id,
         name: "Test Company".to_string(),
         description: Some("Description".to_string()),
+        parent_id: None,
+        two_factor_required_level: None,
         created_at: chrono::Utc::now(),
         updated_at: chrono::Utc::now(),
     };
@@ -0,0 +1,111 @@
+use crate::common::build_company_app;
+use crate::common::factories::{create_company, create_user};
+use axum::body::{to_bytes, Body};
+use axum::http::{Request, StatusCode};
+use tower::ServiceExt;
+use uuid::Uuid;
+
+fn put_settings(company_id: Uuid, body: serde_json::Value) -> Request<Body> {
+    Request::builder()
+        .uri(format!("/api/v1/companies/{company_id}/settings"))
+        .method("PUT")
+        .header("Authorization", "Bearer valid_token")
+        .header("Content-Type", "application/json")
+        .body(Body::from(body.to_string()))
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_member_gets_default_settings() {
+    let company = create_company();
+    let company_id = company.id;
+    let app = build_company_app(create_user("user", 10, company.clone()), company);
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri(format!("/api/v1/companies/{company_id}/settings"))
+                .method("GET")
+                .header("Authorization", "Bearer valid_token")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["timezone"], "UTC");
+    assert_eq!(json["default_page_size"], 16);
+    assert!(json.get("company_id").is_none());
+}
+
+#[tokio::test]
+async fn test_supervisor_updates_settings() {
+    let company = create_company();
+    let company_id = company.id;
+    let app = build_company_app(create_user("supervisor", 50, company.clone()), company);
+
+    let response = app
+        .oneshot(put_settings(
+            company_id,
+            serde_json::json!({
+                "timezone": "America/Lima",
+                "default_page_size": 25,
+                "allowed_upload_formats": ["jpeg", "png"]
+            }),
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["timezone"], "America/Lima");
+    assert_eq!(json["default_page_size"], 25);
+    assert_eq!(
+        json["allowed_upload_formats"],
+        serde_json::json!(["jpeg", "png"])
+    );
+    assert_eq!(json["default_language"], "es");
+}
+
+#[tokio::test]
+async fn test_user_cannot_update_settings() {
+    let company = create_company();
+    let company_id = company.id;
+    let app = build_company_app(create_user("user", 10, company.clone()), company);
+
+    let response = app
+        .oneshot(put_settings(
+            company_id,
+            serde_json::json!({ "timezone": "America/Lima" }),
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_update_settings_rejects_invalid_timezone() {
+    // Names shaped like a time zone are not enough, they must exist
+    for timezone in ["not a timezone", "Foo/Bar"] {
+        let company = create_company();
+        let company_id = company.id;
+        let app = build_company_app(create_user("supervisor", 50, company.clone()), company);
+
+        let response = app
+            .oneshot(put_settings(
+                company_id,
+                serde_json::json!({ "timezone": timezone }),
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+}
@@ -21,6 +21,7 @@ async fn test_get_all_labels_success() {
         id: user_id,
         username: "testuser".to_string(),
         email: Some("test@example.com".to_string()),
+        email_verified_at: None,
         password_hash: "hashed".to_string(),
         name: None,
         surname: None,
@@ -32,6 +33,7 @@ async fn test_get_all_labels_success() {
             updated_at: chrono::Utc::now(),
         },
         company: None,
+        deactivated_at: None,
         created_at: chrono::Utc::now(),
         updated_at: chrono::Utc::now(),
     };
@@ -108,6 +110,7 @@ async fn test_get_all_mark_types_success() {
         id: user_id,
         username: "adminuser".to_string(),
         email: Some("admin@example.com".to_string()),
+        email_verified_at: None,
         password_hash: "hashed".to_string(),
         name: None,
         surname: None,
@@ -119,6 +122,7 @@ async fn test_get_all_mark_types_success() {
             updated_at: chrono::Utc::now(),
         },
         company: None,
+        deactivated_at: None,
         created_at: chrono::Utc::now(),
         updated_at: chrono::Utc::now(),
     };
@@ -193,6 +197,7 @@ async fn test_update_label_success() {
         id: user_id,
         username: "adminuser".to_string(),
         email: Some("admin@example.com".to_string()),
+        email_verified_at: None,
         password_hash: "hashed".to_string(),
         name: None,
         surname: None,
@@ -204,6 +209,7 @@ async fn test_update_label_success() {
             updated_at: chrono::Utc::now(),
         },
         company: None,
+        deactivated_at: None,
         created_at: chrono::Utc::now(),
         updated_at: chrono::Utc::now(),
     };
@@ -299,6 +305,7 @@ async fn test_delete_label_success() {
         id: user_id,
         username: "adminuser".to_string(),
         email: Some("admin@example.com".to_string()),
+        email_verified_at: None,
         password_hash: "hashed".to_string(),
         name: None,
         surname: None,
@@ -310,6 +317,7 @@ async fn test_delete_label_success() {
             updated_at: chrono::Utc::now(),
         },
         company: None,
+        deactivated_at: None,
         created_at: chrono::Utc::now(),
         updated_at: chrono::Utc::now(),
     };
@@ -395,6 +403,7 @@ async fn test_delete_prediction_success() {
         id: user_id,
         username: "testuser".to_string(),
         email: Some("test@example.com".to_string()),
+        email_verified_at: None,
         password_hash: "hashed".to_string(),
         name: None,
         surname: None,
@@ -406,6 +415,7 @@ async fn test_delete_prediction_success() {
             updated_at: chrono::Utc::now(),
         },
         company: None,
+        deactivated_at: None,
         created_at: chrono::Utc::now(),
         updated_at: chrono::Utc::now(),
     };
@@ -436,6 +446,7 @@ async fn test_delete_prediction_success() {
     let prediction = Prediction {
         id: prediction_id,
         user: user.clone(),
+        company_id: user.company.as_ref().map(|c| c.id),
         image: image.clone(),
         label: label.clone(),
         plot_id: None,
@@ -444,6 +455,7 @@ async fn test_delete_prediction_success() {
         severity: 50.0,
         created_at: chrono::Utc::now(),
         marks: vec![],
+        model: None,
         feedback: None
     };
 
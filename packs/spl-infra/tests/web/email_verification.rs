@@ -0,0 +1,156 @@
+use crate::common::build_auth_app;
+use crate::common::factories::create_user_with_id;
+use crate::common::mocks::{
+    AuthMocks, MockEmailVerificationTokenRepository, MockPasswordEncoder, MockTokenGenerator,
+    MockUserRepository,
+};
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use chrono::{Duration, Utc};
+use spl_domain::entities::auth::EmailVerificationToken;
+use tower::ServiceExt;
+use uuid::Uuid;
+
+fn create_token(user_id: Uuid, email: &str) -> EmailVerificationToken {
+    EmailVerificationToken {
+        id: Uuid::new_v4(),
+        user_id,
+        email: email.to_string(),
+        token_hash: "hash".to_string(),
+        expires_at: Utc::now() + Duration::hours(1),
+        used_at: None,
+        created_at: Utc::now(),
+    }
+}
+
+fn post_json(uri: &str, payload: serde_json::Value) -> Request<Body> {
+    Request::builder()
+        .uri(uri)
+        .method("POST")
+        .header("Content-Type", "application/json")
+        .body(Body::from(payload.to_string()))
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_verify_email_success() {
+    let user_id = Uuid::new_v4();
+    let user = create_user_with_id(user_id);
+    let token = create_token(user_id, "web@example.com");
+
+    let mut mock_user_repo = MockUserRepository::new();
+    mock_user_repo
+        .expect_get_by_id()
+        .times(1)
+        .returning(move |_| Ok(Some(user.clone())));
+    mock_user_repo
+        .expect_update()
+        .withf(|user| user.is_email_verified())
+        .times(1)
+        .returning(Ok);
+
+    let mut token_repo = MockEmailVerificationTokenRepository::new();
+    token_repo
+        .expect_get_by_token_hash()
+        .times(1)
+        .returning(move |_| Ok(Some(token.clone())));
+    token_repo
+        .expect_mark_used()
+        .times(1)
+        .returning(|_| Ok(true));
+
+    let app = build_auth_app(
+        mock_user_repo,
+        MockPasswordEncoder::new(),
+        MockTokenGenerator::new(),
+        AuthMocks {
+            email_verification_token_repo: token_repo,
+            ..Default::default()
+        },
+    );
+
+    let response = app
+        .oneshot(post_json(
+            "/api/v1/auth/email/verify",
+            serde_json::json!({ "token": "verification_token" }),
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_verify_email_rejects_token_of_previous_address() {
+    let user_id = Uuid::new_v4();
+    let user = create_user_with_id(user_id);
+    let token = create_token(user_id, "old@example.com");
+
+    let mut mock_user_repo = MockUserRepository::new();
+    mock_user_repo
+        .expect_get_by_id()
+        .times(1)
+        .returning(move |_| Ok(Some(user.clone())));
+    mock_user_repo.expect_update().never();
+
+    let mut token_repo = MockEmailVerificationTokenRepository::new();
+    token_repo
+        .expect_get_by_token_hash()
+        .times(1)
+        .returning(move |_| Ok(Some(token.clone())));
+    token_repo.expect_mark_used().never();
+
+    let app = build_auth_app(
+        mock_user_repo,
+        MockPasswordEncoder::new(),
+        MockTokenGenerator::new(),
+        AuthMocks {
+            email_verification_token_repo: token_repo,
+            ..Default::default()
+        },
+    );
+
+    let response = app
+        .oneshot(post_json(
+            "/api/v1/auth/email/verify",
+            serde_json::json!({ "token": "verification_token" }),
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_login_with_unverified_email_fails() {
+    let user = create_user_with_id(Uuid::new_v4());
+
+    let mut mock_user_repo = MockUserRepository::new();
+    mock_user_repo
+        .expect_get_by_username_or_email_and_company()
+        .times(1)
+        .returning(move |_, _, _| Ok(Some(user.clone())));
+
+    let mut mock_encoder = MockPasswordEncoder::new();
+    mock_encoder.expect_verify().returning(|_, _| Ok(true));
+
+    let mut mock_token = MockTokenGenerator::new();
+    mock_token.expect_generate().never();
+
+    let app = build_auth_app(
+        mock_user_repo,
+        mock_encoder,
+        mock_token,
+        AuthMocks::default(),
+    );
+
+    let response = app
+        .oneshot(post_json(
+            "/api/v1/auth/login",
+            serde_json::json!({ "email": "web@example.com", "password": "password123" }),
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
@@ -43,3 +43,40 @@ async fn test_health_check() {
     assert_eq!(body_json["status"], "ok");
     assert_eq!(body_json["message"], "Server is clean and running");
 }
+
+#[tokio::test]
+async fn test_jwks_endpoint() {
+    let mut mock_token = MockTokenGenerator::new();
+    mock_token.expect_jwks().times(1).returning(|| {
+        serde_json::json!({
+            "keys": [{ "kty": "OKP", "crv": "Ed25519", "kid": "ed-1", "x": "abc" }]
+        })
+    });
+
+    let app = build_app(
+        MockUserRepository::new(),
+        MockRoleRepository::new(),
+        MockCompanyRepository::new(),
+        MockPasswordEncoder::new(),
+        mock_token,
+    );
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/.well-known/jwks.json")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body_bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let body_json: serde_json::Value = serde_json::from_slice(&body_bytes).unwrap();
+
+    assert_eq!(body_json["keys"][0]["kid"], "ed-1");
+}
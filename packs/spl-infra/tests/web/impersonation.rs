@@ -0,0 +1,462 @@
+use crate::common::build_auth_app;
+use crate::common::mocks::{
+    AuthMocks, MockImpersonationActionRepository, MockImpersonationRepository, MockPasswordEncoder,
+    MockTokenGenerator, MockUserRepository,
+};
+use axum::body::{to_bytes, Body};
+use axum::http::{Request, StatusCode};
+use chrono::{Duration, Utc};
+use spl_domain::entities::auth::Impersonation;
+use spl_domain::entities::user::{Role, User};
+use tower::ServiceExt;
+use uuid::Uuid;
+
+fn create_user(id: Uuid, role: &str, level: i16) -> User {
+    User {
+        id,
+        username: format!("{role}_user"),
+        email: None,
+        email_verified_at: None,
+        password_hash: "hashed".to_string(),
+        name: None,
+        surname: None,
+        role: Role {
+            id: level as i32,
+            name: role.to_string(),
+            level,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        },
+        company: None,
+        deactivated_at: None,
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+    }
+}
+
+fn create_impersonation(actor_id: Uuid, subject_id: Uuid) -> Impersonation {
+    Impersonation {
+        id: Uuid::new_v4(),
+        actor_id: Some(actor_id),
+        subject_id: Some(subject_id),
+        reason: "Ticket #42".to_string(),
+        ip_address: None,
+        started_at: Utc::now(),
+        expires_at: Utc::now() + Duration::minutes(15),
+        ended_at: None,
+    }
+}
+
+/// Users by id, and a token generator whose tokens carry the given claims
+fn mocks_for(
+    users: Vec<User>,
+    claims: serde_json::Value,
+) -> (MockUserRepository, MockTokenGenerator) {
+    let mut user_repo = MockUserRepository::new();
+    user_repo
+        .expect_get_by_id()
+        .returning(move |id| Ok(users.iter().find(|user| user.id == id).cloned()));
+
+    let mut token_gen = MockTokenGenerator::new();
+    token_gen
+        .expect_validate()
+        .returning(move |_| Ok(claims.clone()));
+
+    (user_repo, token_gen)
+}
+
+fn impersonation_claims(impersonation: &Impersonation) -> serde_json::Value {
+    serde_json::json!({
+        "sub": impersonation.subject_id.unwrap().to_string(),
+        "role": "user",
+        "imp": impersonation.id.to_string(),
+        "act": { "sub": impersonation.actor_id.unwrap().to_string() },
+    })
+}
+
+#[tokio::test]
+async fn test_admin_starts_impersonation() {
+    let admin = create_user(Uuid::new_v4(), "admin", 100);
+    let subject = create_user(Uuid::new_v4(), "user", 10);
+    let subject_id = subject.id;
+    let admin_id = admin.id;
+
+    let (user_repo, mut token_gen) = mocks_for(
+        vec![admin, subject],
+        serde_json::json!({ "sub": admin_id.to_string(), "role": "admin" }),
+    );
+    token_gen
+        .expect_generate()
+        .withf(move |sub, claims| {
+            sub == subject_id.to_string() && claims["act"]["sub"] == admin_id.to_string()
+        })
+        .times(1)
+        .returning(|_, _| Ok("impersonation_token".to_string()));
+
+    let mut impersonation_repo = MockImpersonationRepository::new();
+    impersonation_repo
+        .expect_create()
+        .withf(move |impersonation| {
+            impersonation.actor_id == Some(admin_id) && impersonation.subject_id == Some(subject_id)
+        })
+        .times(1)
+        .returning(Ok);
+
+    let app = build_auth_app(
+        user_repo,
+        MockPasswordEncoder::new(),
+        token_gen,
+        AuthMocks {
+            impersonation_repo,
+            ..Default::default()
+        },
+    );
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri(format!("/api/v1/auth/impersonate/{subject_id}"))
+                .method("POST")
+                .header("Authorization", "Bearer admin_token")
+                .header("Content-Type", "application/json")
+                .body(Body::from(
+                    serde_json::json!({ "reason": "Ticket #42" }).to_string(),
+                ))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    // Only requests made with the impersonation token are flagged
+    assert!(response.headers().get("x-impersonated-by").is_none());
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(body["token"], "impersonation_token");
+    assert_eq!(body["impersonated"], true);
+    assert_eq!(body["impersonation"]["subject_id"], subject_id.to_string());
+}
+
+#[tokio::test]
+async fn test_non_admin_cannot_impersonate() {
+    let supervisor = create_user(Uuid::new_v4(), "supervisor", 50);
+    let supervisor_id = supervisor.id;
+
+    let (user_repo, mut token_gen) = mocks_for(
+        vec![supervisor],
+        serde_json::json!({ "sub": supervisor_id.to_string(), "role": "supervisor" }),
+    );
+    token_gen.expect_generate().never();
+
+    let app = build_auth_app(
+        user_repo,
+        MockPasswordEncoder::new(),
+        token_gen,
+        AuthMocks::default(),
+    );
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri(format!("/api/v1/auth/impersonate/{}", Uuid::new_v4()))
+                .method("POST")
+                .header("Authorization", "Bearer supervisor_token")
+                .header("Content-Type", "application/json")
+                .body(Body::from(
+                    serde_json::json!({ "reason": "Ticket #42" }).to_string(),
+                ))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_requests_under_impersonation_are_flagged_and_recorded() {
+    let admin = create_user(Uuid::new_v4(), "admin", 100);
+    let subject = create_user(Uuid::new_v4(), "user", 10);
+    let impersonation = create_impersonation(admin.id, subject.id);
+    let impersonation_id = impersonation.id;
+    let admin_id = admin.id;
+
+    let (user_repo, token_gen) =
+        mocks_for(vec![admin, subject], impersonation_claims(&impersonation));
+
+    let mut impersonation_repo = MockImpersonationRepository::new();
+    impersonation_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(impersonation.clone())));
+
+    let mut action_repo = MockImpersonationActionRepository::new();
+    action_repo
+        .expect_create()
+        .withf(move |action| {
+            action.impersonation_id == impersonation_id
+                && action.method == "GET"
+                && action.path == "/api/v1/users/me"
+                && action.status_code == 200
+        })
+        .times(1)
+        .returning(Ok);
+
+    let app = build_auth_app(
+        user_repo,
+        MockPasswordEncoder::new(),
+        token_gen,
+        AuthMocks {
+            impersonation_repo,
+            impersonation_action_repo: action_repo,
+            ..Default::default()
+        },
+    );
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/api/v1/users/me")
+                .header("Authorization", "Bearer impersonation_token")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers().get("x-impersonated-by").unwrap(),
+        admin_id.to_string().as_str()
+    );
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(body["username"], "user_user");
+    assert_eq!(body["impersonated_by"], admin_id.to_string());
+}
+
+#[tokio::test]
+async fn test_impersonation_cannot_change_password() {
+    let admin = create_user(Uuid::new_v4(), "admin", 100);
+    let subject = create_user(Uuid::new_v4(), "user", 10);
+    let impersonation = create_impersonation(admin.id, subject.id);
+
+    let (mut user_repo, token_gen) =
+        mocks_for(vec![admin, subject], impersonation_claims(&impersonation));
+    user_repo.expect_update().never();
+
+    let mut impersonation_repo = MockImpersonationRepository::new();
+    impersonation_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(impersonation.clone())));
+
+    // Refused attempts are recorded too
+    let mut action_repo = MockImpersonationActionRepository::new();
+    action_repo
+        .expect_create()
+        .withf(|action| action.status_code == 403)
+        .times(1)
+        .returning(Ok);
+
+    let app = build_auth_app(
+        user_repo,
+        MockPasswordEncoder::new(),
+        token_gen,
+        AuthMocks {
+            impersonation_repo,
+            impersonation_action_repo: action_repo,
+            ..Default::default()
+        },
+    );
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/api/v1/users/me/password")
+                .method("PUT")
+                .header("Authorization", "Bearer impersonation_token")
+                .header("Content-Type", "application/json")
+                .body(Body::from(
+                    serde_json::json!({
+                        "current_password": "password123",
+                        "new_password": "NewPassword123!"
+                    })
+                    .to_string(),
+                ))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_ended_impersonation_token_is_rejected() {
+    let admin = create_user(Uuid::new_v4(), "admin", 100);
+    let subject = create_user(Uuid::new_v4(), "user", 10);
+    let mut impersonation = create_impersonation(admin.id, subject.id);
+    impersonation.ended_at = Some(Utc::now());
+
+    let (user_repo, token_gen) =
+        mocks_for(vec![admin, subject], impersonation_claims(&impersonation));
+
+    let mut impersonation_repo = MockImpersonationRepository::new();
+    impersonation_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(impersonation.clone())));
+
+    let mut action_repo = MockImpersonationActionRepository::new();
+    action_repo.expect_create().never();
+
+    let app = build_auth_app(
+        user_repo,
+        MockPasswordEncoder::new(),
+        token_gen,
+        AuthMocks {
+            impersonation_repo,
+            impersonation_action_repo: action_repo,
+            ..Default::default()
+        },
+    );
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/api/v1/users/me")
+                .header("Authorization", "Bearer impersonation_token")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+/// Sends a request with the token of an impersonated supervisor, whose role would
+/// otherwise allow it, and returns the status it was answered with
+async fn status_under_impersonation(
+    method: &str,
+    uri: &str,
+    body: serde_json::Value,
+) -> StatusCode {
+    let admin = create_user(Uuid::new_v4(), "admin", 100);
+    let subject = create_user(Uuid::new_v4(), "supervisor", 50);
+    let impersonation = create_impersonation(admin.id, subject.id);
+
+    let (mut user_repo, token_gen) =
+        mocks_for(vec![admin, subject], impersonation_claims(&impersonation));
+    user_repo.expect_update().never();
+
+    let mut impersonation_repo = MockImpersonationRepository::new();
+    impersonation_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(impersonation.clone())));
+
+    let mut action_repo = MockImpersonationActionRepository::new();
+    action_repo.expect_create().returning(Ok);
+
+    let app = build_auth_app(
+        user_repo,
+        MockPasswordEncoder::new(),
+        token_gen,
+        AuthMocks {
+            impersonation_repo,
+            impersonation_action_repo: action_repo,
+            ..Default::default()
+        },
+    );
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri(uri)
+                .method(method)
+                .header("Authorization", "Bearer impersonation_token")
+                .header("Content-Type", "application/json")
+                .body(Body::from(body.to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    response.status()
+}
+
+#[tokio::test]
+async fn test_impersonation_cannot_update_profile() {
+    let status = status_under_impersonation(
+        "PUT",
+        "/api/v1/users/me",
+        serde_json::json!({ "email": "attacker@example.com" }),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_impersonation_cannot_create_service_account() {
+    let status = status_under_impersonation(
+        "POST",
+        "/api/v1/service-accounts",
+        serde_json::json!({ "name": "device" }),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_impersonation_cannot_create_api_key() {
+    let status = status_under_impersonation(
+        "POST",
+        &format!("/api/v1/service-accounts/{}/keys", Uuid::new_v4()),
+        serde_json::json!({ "name": "key", "scopes": ["predictions:read"] }),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_impersonation_cannot_revoke_api_key() {
+    let status = status_under_impersonation(
+        "DELETE",
+        &format!(
+            "/api/v1/service-accounts/{}/keys/{}",
+            Uuid::new_v4(),
+            Uuid::new_v4()
+        ),
+        serde_json::Value::Null,
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_impersonation_cannot_revoke_sessions() {
+    let status = status_under_impersonation(
+        "DELETE",
+        "/api/v1/users/me/sessions",
+        serde_json::Value::Null,
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_impersonation_cannot_revoke_session() {
+    let status = status_under_impersonation(
+        "DELETE",
+        &format!("/api/v1/users/me/sessions/{}", Uuid::new_v4()),
+        serde_json::Value::Null,
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::FORBIDDEN);
+}
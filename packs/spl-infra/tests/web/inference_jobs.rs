@@ -0,0 +1,215 @@
+use crate::common::build_auth_app;
+use crate::common::factories::{create_company, create_user};
+use crate::common::mocks::{
+    AuthMocks, MockInferenceJobRepository, MockPasswordEncoder, MockTokenGenerator,
+    MockUserRepository,
+};
+use axum::body::{to_bytes, Body};
+use axum::http::{Request, StatusCode};
+use spl_domain::entities::diagnostics::{InferenceJob, JobStatus};
+use spl_domain::entities::user::User;
+use tower::ServiceExt;
+use uuid::Uuid;
+
+const BOUNDARY: &str = "job-boundary";
+
+fn create_job(user: &User, status: JobStatus) -> InferenceJob {
+    let mut job = InferenceJob::queued(
+        user.id,
+        user.company.as_ref().map(|c| c.id),
+        None,
+        "leaf.jpg".to_string(),
+        format!("{}/jobs/{}", user.id, Uuid::new_v4()),
+    );
+    job.status = status;
+    job
+}
+
+/// App where the user is authenticated
+fn app_for(user: User, inference_job_repo: MockInferenceJobRepository) -> axum::Router {
+    let user_id = user.id;
+
+    let mut user_repo = MockUserRepository::new();
+    user_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(user.clone())));
+
+    let mut token_gen = MockTokenGenerator::new();
+    token_gen
+        .expect_validate()
+        .returning(move |_| Ok(serde_json::json!({ "sub": user_id.to_string() })));
+
+    build_auth_app(
+        user_repo,
+        MockPasswordEncoder::new(),
+        token_gen,
+        AuthMocks {
+            inference_job_repo,
+            ..Default::default()
+        },
+    )
+}
+
+fn request(method: &str, uri: &str) -> Request<Body> {
+    Request::builder()
+        .uri(uri)
+        .method(method)
+        .header("Authorization", "Bearer valid_token")
+        .body(Body::empty())
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_create_job_queues_the_upload() {
+    let user = create_user("user", 10, create_company());
+    let user_id = user.id;
+
+    let mut job_repo = MockInferenceJobRepository::new();
+    job_repo
+        .expect_create()
+        .withf(move |job| {
+            job.user_id == user_id
+                && job.status == JobStatus::Queued
+                && job.filename == "leaf.jpg"
+                && job.upload_path.is_some()
+        })
+        .times(1)
+        .returning(Ok);
+
+    let app = app_for(user, job_repo);
+
+    let mut body = format!(
+        "--{BOUNDARY}\r\nContent-Disposition: form-data; name=\"file\"; filename=\"leaf.jpg\"\r\nContent-Type: image/jpeg\r\n\r\n"
+    )
+    .into_bytes();
+    body.extend_from_slice(b"image");
+    body.extend_from_slice(format!("\r\n--{BOUNDARY}--\r\n").as_bytes());
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/api/v1/diagnostics/predictions/jobs")
+                .method("POST")
+                .header("Authorization", "Bearer valid_token")
+                .header(
+                    "Content-Type",
+                    format!("multipart/form-data; boundary={BOUNDARY}"),
+                )
+                .body(Body::from(body))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::ACCEPTED);
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["status"], "queued");
+    assert_eq!(json["attempts"], 0);
+}
+
+#[tokio::test]
+async fn test_get_jobs_rejects_unknown_status() {
+    let app = app_for(
+        create_user("user", 10, create_company()),
+        MockInferenceJobRepository::new(),
+    );
+
+    let response = app
+        .oneshot(request(
+            "GET",
+            "/api/v1/diagnostics/predictions/jobs?status=lost",
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_job_of_another_user_is_not_found() {
+    let job = create_job(
+        &create_user("user", 10, create_company()),
+        JobStatus::Queued,
+    );
+    let job_id = job.id;
+
+    let mut job_repo = MockInferenceJobRepository::new();
+    job_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(job.clone())));
+
+    let app = app_for(create_user("user", 10, create_company()), job_repo);
+
+    let response = app
+        .oneshot(request(
+            "GET",
+            &format!("/api/v1/diagnostics/predictions/jobs/{job_id}"),
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_retry_requires_a_dead_job() {
+    let user = create_user("user", 10, create_company());
+    let job = create_job(&user, JobStatus::Completed);
+    let job_id = job.id;
+
+    let mut job_repo = MockInferenceJobRepository::new();
+    job_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(job.clone())));
+    job_repo.expect_update().never();
+
+    let app = app_for(user, job_repo);
+
+    let response = app
+        .oneshot(request(
+            "POST",
+            &format!("/api/v1/diagnostics/predictions/jobs/{job_id}/retry"),
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn test_events_of_a_finished_job_end_with_its_state() {
+    let user = create_user("user", 10, create_company());
+    let mut job = create_job(&user, JobStatus::Completed);
+    job.prediction_id = Some(Uuid::new_v4());
+    let job_id = job.id;
+
+    let mut job_repo = MockInferenceJobRepository::new();
+    job_repo
+        .expect_get_by_id()
+        .times(1)
+        .returning(move |_| Ok(Some(job.clone())));
+
+    let app = app_for(user, job_repo);
+
+    let response = app
+        .oneshot(request(
+            "GET",
+            &format!("/api/v1/diagnostics/predictions/jobs/{job_id}/events"),
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers()["content-type"].to_str().unwrap(),
+        "text/event-stream"
+    );
+
+    // The stream ends by itself once the finished job was sent
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let body = String::from_utf8(body.to_vec()).unwrap();
+    assert_eq!(body.matches("event: job").count(), 1);
+    assert!(body.contains("\"status\":\"completed\""));
+}
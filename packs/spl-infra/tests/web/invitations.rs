@@ -0,0 +1,157 @@
+use crate::common::build_auth_app;
+use crate::common::factories::{create_company, create_user};
+use crate::common::mocks::{
+    AuthMocks, MockInvitationRepository, MockPasswordEncoder, MockTokenGenerator,
+    MockUserRepository,
+};
+use axum::body::{to_bytes, Body};
+use axum::http::{Request, StatusCode};
+use chrono::{Duration, Utc};
+use spl_domain::entities::user::{Invitation, User};
+use tower::ServiceExt;
+use uuid::Uuid;
+
+fn create_invitation(company_id: Uuid, expires_in_hours: i64) -> Invitation {
+    Invitation {
+        id: Uuid::new_v4(),
+        company_id,
+        email: "invitee@example.com".to_string(),
+        role_id: 10,
+        token_hash: "hash".to_string(),
+        invited_by: None,
+        expires_at: Utc::now() + Duration::hours(expires_in_hours),
+        accepted_at: None,
+        cancelled_at: None,
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+    }
+}
+
+/// Mocks authenticating every request as `user`
+fn authenticated(user: User) -> (MockUserRepository, MockTokenGenerator) {
+    let user_id = user.id;
+
+    let mut mock_token = MockTokenGenerator::new();
+    mock_token
+        .expect_validate()
+        .returning(move |_| Ok(serde_json::json!({ "sub": user_id.to_string() })));
+
+    let mut mock_user_repo = MockUserRepository::new();
+    mock_user_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(user.clone())));
+
+    (mock_user_repo, mock_token)
+}
+
+#[tokio::test]
+async fn test_supervisor_lists_company_invitations() {
+    let company = create_company();
+    let company_id = company.id;
+    let (mock_user_repo, mock_token) = authenticated(create_user("supervisor", 50, company));
+
+    let mut invitation_repo = MockInvitationRepository::new();
+    invitation_repo
+        .expect_get_by_company_id()
+        .with(mockall::predicate::eq(company_id))
+        .times(1)
+        .returning(|company_id| {
+            Ok(vec![
+                create_invitation(company_id, 72),
+                create_invitation(company_id, -1),
+            ])
+        });
+
+    let app = build_auth_app(
+        mock_user_repo,
+        MockPasswordEncoder::new(),
+        mock_token,
+        AuthMocks {
+            invitation_repo,
+            ..Default::default()
+        },
+    );
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/api/v1/invitations")
+                .header("Authorization", "Bearer valid_token")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(body[0]["status"], "pending");
+    assert_eq!(body[1]["status"], "expired");
+    assert!(body[0].get("token_hash").is_none());
+}
+
+#[tokio::test]
+async fn test_user_cannot_list_invitations() {
+    let (mock_user_repo, mock_token) = authenticated(create_user("user", 10, create_company()));
+
+    let app = build_auth_app(
+        mock_user_repo,
+        MockPasswordEncoder::new(),
+        mock_token,
+        AuthMocks::default(),
+    );
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/api/v1/invitations")
+                .header("Authorization", "Bearer valid_token")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_accept_with_unknown_token_is_rejected() {
+    let mut invitation_repo = MockInvitationRepository::new();
+    invitation_repo
+        .expect_get_by_token_hash()
+        .returning(|_| Ok(None));
+
+    let app = build_auth_app(
+        MockUserRepository::new(),
+        MockPasswordEncoder::new(),
+        MockTokenGenerator::new(),
+        AuthMocks {
+            invitation_repo,
+            ..Default::default()
+        },
+    );
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/api/v1/auth/invitations/accept")
+                .method("POST")
+                .header("Content-Type", "application/json")
+                .body(Body::from(
+                    serde_json::json!({
+                        "token": "forged",
+                        "username": "invitee",
+                        "password": "password123"
+                    })
+                    .to_string(),
+                ))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
@@ -0,0 +1,228 @@
+use crate::common::build_auth_app;
+use crate::common::mocks::{
+    AuthMocks, MockLoginAttemptStore, MockPasswordEncoder, MockTokenGenerator,
+    MockUserRepository,
+};
+use axum::body::Body;
+use axum::http::{header, Request, StatusCode};
+use chrono::{Duration, Utc};
+use mockall::predicate::*;
+use spl_domain::entities::auth::LoginAttempts;
+use spl_domain::entities::user::{Role, User};
+use tower::ServiceExt;
+use uuid::Uuid;
+
+fn create_user(id: Uuid, role: &str, level: i16) -> User {
+    User {
+        id,
+        username: "webuser".to_string(),
+        email: Some("web@example.com".to_string()),
+        email_verified_at: None,
+        password_hash: "hashed".to_string(),
+        name: None,
+        surname: None,
+        role: Role {
+            id: 2,
+            name: role.to_string(),
+            level,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        },
+        company: None,
+        deactivated_at: None,
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+    }
+}
+
+fn authenticated(requester: User, target: User) -> (MockUserRepository, MockTokenGenerator) {
+    let requester_id = requester.id;
+
+    let mut mock_token = MockTokenGenerator::new();
+    mock_token
+        .expect_validate()
+        .returning(move |_| Ok(serde_json::json!({ "sub": requester_id.to_string() })));
+
+    let mut mock_user_repo = MockUserRepository::new();
+    mock_user_repo.expect_get_by_id().returning(move |id| {
+        if id == requester_id {
+            Ok(Some(requester.clone()))
+        } else {
+            Ok(Some(target.clone()))
+        }
+    });
+
+    (mock_user_repo, mock_token)
+}
+
+fn lockout_request(method: &str, user_id: Uuid) -> Request<Body> {
+    Request::builder()
+        .uri(format!("/api/v1/users/{}/lockout", user_id))
+        .method(method)
+        .header("Authorization", "Bearer valid_token")
+        .body(Body::empty())
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_login_locked_account_returns_423() {
+    let user = create_user(Uuid::new_v4(), "user", 10);
+
+    let mut mock_user_repo = MockUserRepository::new();
+    mock_user_repo
+        .expect_get_by_username_or_email_and_company()
+        .returning(move |_, _, _| Ok(Some(user.clone())));
+
+    let mut mock_encoder = MockPasswordEncoder::new();
+    mock_encoder.expect_verify().never();
+
+    let mut login_attempt_store = MockLoginAttemptStore::new();
+    login_attempt_store.expect_get().returning(|user_id| {
+        Ok(Some(LoginAttempts {
+            user_id,
+            failed_attempts: 0,
+            lockout_count: 1,
+            locked_until: Some(Utc::now() + Duration::minutes(2)),
+        }))
+    });
+
+    let app = build_auth_app(
+        mock_user_repo,
+        mock_encoder,
+        MockTokenGenerator::new(),
+        AuthMocks {
+            login_attempt_store,
+            ..Default::default()
+        },
+    );
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/api/v1/auth/login")
+                .method("POST")
+                .header("Content-Type", "application/json")
+                .body(Body::from(
+                    serde_json::json!({ "username": "webuser", "password": "password123" })
+                        .to_string(),
+                ))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::LOCKED);
+
+    let retry_after: i64 = response.headers()[header::RETRY_AFTER]
+        .to_str()
+        .unwrap()
+        .parse()
+        .unwrap();
+    assert!((1..=120).contains(&retry_after));
+}
+
+#[tokio::test]
+async fn test_admin_can_unlock_user() {
+    let target_id = Uuid::new_v4();
+    let (mock_user_repo, mock_token) = authenticated(
+        create_user(Uuid::new_v4(), "admin", 100),
+        create_user(target_id, "user", 10),
+    );
+
+    let mut login_attempt_store = MockLoginAttemptStore::new();
+    login_attempt_store
+        .expect_clear()
+        .with(eq(target_id))
+        .times(1)
+        .returning(|_| Ok(()));
+
+    let app = build_auth_app(
+        mock_user_repo,
+        MockPasswordEncoder::new(),
+        mock_token,
+        AuthMocks {
+            login_attempt_store,
+            ..Default::default()
+        },
+    );
+
+    let response = app
+        .oneshot(lockout_request("DELETE", target_id))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_admin_can_read_lockout_status() {
+    let target_id = Uuid::new_v4();
+    let (mock_user_repo, mock_token) = authenticated(
+        create_user(Uuid::new_v4(), "admin", 100),
+        create_user(target_id, "user", 10),
+    );
+
+    let mut login_attempt_store = MockLoginAttemptStore::new();
+    login_attempt_store.expect_get().returning(|user_id| {
+        Ok(Some(LoginAttempts {
+            user_id,
+            failed_attempts: 2,
+            lockout_count: 1,
+            locked_until: Some(Utc::now() + Duration::minutes(1)),
+        }))
+    });
+
+    let app = build_auth_app(
+        mock_user_repo,
+        MockPasswordEncoder::new(),
+        mock_token,
+        AuthMocks {
+            login_attempt_store,
+            ..Default::default()
+        },
+    );
+
+    let response = app
+        .oneshot(lockout_request("GET", target_id))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["locked"], true);
+    assert_eq!(json["failed_attempts"], 2);
+    assert_eq!(json["lockout_count"], 1);
+}
+
+#[tokio::test]
+async fn test_non_admin_cannot_unlock_user() {
+    let target_id = Uuid::new_v4();
+    let (mock_user_repo, mock_token) = authenticated(
+        create_user(Uuid::new_v4(), "supervisor", 50),
+        create_user(target_id, "user", 10),
+    );
+
+    let mut login_attempt_store = MockLoginAttemptStore::new();
+    login_attempt_store.expect_clear().never();
+
+    let app = build_auth_app(
+        mock_user_repo,
+        MockPasswordEncoder::new(),
+        mock_token,
+        AuthMocks {
+            login_attempt_store,
+            ..Default::default()
+        },
+    );
+
+    let response = app
+        .oneshot(lockout_request("DELETE", target_id))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
@@ -20,6 +20,7 @@ async fn test_login_endpoint_success() {
         id: Uuid::new_v4(),
         username: "webuser".to_string(),
         email: Some("web@example.com".to_string()),
+        email_verified_at: None,
         password_hash: "hashed".to_string(),
         name: None,
         surname: None,
@@ -31,6 +32,7 @@ async fn test_login_endpoint_success() {
             updated_at: chrono::Utc::now(),
         },
         company: None,
+        deactivated_at: None,
         created_at: chrono::Utc::now(),
         updated_at: chrono::Utc::now(),
     };
@@ -90,6 +92,7 @@ async fn test_login_endpoint_success() {
     let body_json: serde_json::Value = serde_json::from_slice(&body_bytes).unwrap();
 
     assert_eq!(body_json["token"], "mocked_jwt_token");
+    assert!(body_json["refresh_token"].as_str().is_some());
 }
 
 #[tokio::test]
@@ -102,6 +105,7 @@ async fn test_login_endpoint_with_email_success() {
         id: Uuid::new_v4(),
         username: "webuser".to_string(),
         email: Some("web@example.com".to_string()),
+        email_verified_at: Some(chrono::Utc::now()),
         password_hash: "hashed".to_string(),
         name: None,
         surname: None,
@@ -113,6 +117,7 @@ async fn test_login_endpoint_with_email_success() {
             updated_at: chrono::Utc::now(),
         },
         company: None,
+        deactivated_at: None,
         created_at: chrono::Utc::now(),
         updated_at: chrono::Utc::now(),
     };
@@ -0,0 +1,253 @@
+use crate::common::build_app_with_auth;
+use crate::common::mocks::{
+    AuthMocks, MockCompanyRepository, MockImageRepository, MockLabelRepository,
+    MockMarkTypeRepository, MockMembershipRepository, MockPasswordEncoder, MockPlotRepository,
+    MockPredictionMarkRepository, MockPredictionRepository, MockRecommendationCategoryRepository,
+    MockRecommendationRepository, MockRoleRepository, MockTokenGenerator, MockUserRepository,
+};
+use axum::body::{to_bytes, Body};
+use axum::http::{Request, StatusCode};
+use chrono::Utc;
+use spl_domain::entities::company::Company;
+use spl_domain::entities::diagnostics::{Label, Prediction};
+use spl_domain::entities::image::Image;
+use spl_domain::entities::user::{CompanyMembership, Role, User};
+use tower::ServiceExt;
+use uuid::Uuid;
+
+fn create_company(name: &str) -> Company {
+    Company {
+        id: Uuid::new_v4(),
+        name: name.to_string(),
+        description: None,
+        parent_id: None,
+        two_factor_required_level: None,
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+    }
+}
+
+fn create_role(name: &str, level: i16) -> Role {
+    Role {
+        id: level as i32,
+        name: name.to_string(),
+        level,
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+    }
+}
+
+fn create_user(company: Company) -> User {
+    User {
+        id: Uuid::new_v4(),
+        username: "consultant".to_string(),
+        email: None,
+        email_verified_at: None,
+        password_hash: "hashed".to_string(),
+        name: None,
+        surname: None,
+        role: create_role("user", 10),
+        company: Some(company),
+        deactivated_at: None,
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+    }
+}
+
+/// Prediction of the user made while acting in the company
+fn create_prediction(user: &User, company_id: Uuid) -> Prediction {
+    let id = Uuid::new_v4();
+
+    Prediction {
+        id,
+        user: user.clone(),
+        company_id: Some(company_id),
+        image: Image {
+            id: Uuid::new_v4(),
+            user_id: user.id,
+            filename: "leaf.jpg".to_string(),
+            filepath: format!("{}/leaf.jpg", user.id),
+            prediction_id: Some(id),
+            created_at: Utc::now(),
+        },
+        label: Label {
+            id: 1,
+            name: "rust".to_string(),
+            description: None,
+            min: 0.0,
+            max: 0.5,
+            weight: 1,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        },
+        plot_id: None,
+        presence_confidence: 0.8,
+        absence_confidence: 0.2,
+        severity: 50.0,
+        created_at: Utc::now(),
+        marks: vec![],
+        model: None,
+        feedback: None,
+    }
+}
+
+/// App where the user is authenticated with a token acting in the company
+fn app_for(user: User, company_id: Uuid, memberships: Vec<CompanyMembership>) -> axum::Router {
+    app_with_predictions(
+        user,
+        company_id,
+        memberships,
+        MockPredictionRepository::new(),
+    )
+}
+
+fn app_with_predictions(
+    user: User,
+    company_id: Uuid,
+    memberships: Vec<CompanyMembership>,
+    prediction_repo: MockPredictionRepository,
+) -> axum::Router {
+    let user_id = user.id;
+
+    let mut user_repo = MockUserRepository::new();
+    user_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(user.clone())));
+
+    let mut token_gen = MockTokenGenerator::new();
+    token_gen.expect_validate().returning(move |_| {
+        Ok(serde_json::json!({
+            "sub": user_id.to_string(),
+            "cid": company_id.to_string(),
+        }))
+    });
+
+    let mut membership_repo = MockMembershipRepository::new();
+    let found = memberships.clone();
+    membership_repo
+        .expect_get()
+        .returning(move |_, company_id| {
+            Ok(found.iter().find(|m| m.company.id == company_id).cloned())
+        });
+    membership_repo
+        .expect_get_by_user_id()
+        .returning(move |_| Ok(memberships.clone()));
+
+    build_app_with_auth(
+        user_repo,
+        MockRoleRepository::new(),
+        MockCompanyRepository::new(),
+        MockRecommendationRepository::new(),
+        MockRecommendationCategoryRepository::new(),
+        MockLabelRepository::new(),
+        MockMarkTypeRepository::new(),
+        MockPlotRepository::new(),
+        prediction_repo,
+        MockPredictionMarkRepository::new(),
+        MockImageRepository::new(),
+        MockPasswordEncoder::new(),
+        token_gen,
+        AuthMocks {
+            membership_repo,
+            ..Default::default()
+        },
+    )
+}
+
+fn get(uri: &str) -> Request<Body> {
+    Request::builder()
+        .uri(uri)
+        .method("GET")
+        .header("Authorization", "Bearer valid_token")
+        .body(Body::empty())
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_my_companies_mark_company_of_token_as_active() {
+    let home = create_company("Home");
+    let client = create_company("Client");
+    let user = create_user(home.clone());
+    let membership = CompanyMembership {
+        user_id: user.id,
+        company: client.clone(),
+        role: create_role("supervisor", 50),
+        created_at: Utc::now(),
+    };
+    let app = app_for(user, client.id, vec![membership]);
+
+    let response = app
+        .oneshot(get("/api/v1/users/me/companies"))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json[0]["company"]["id"], home.id.to_string());
+    assert_eq!(json[0]["home"], true);
+    assert_eq!(json[0]["active"], false);
+    assert_eq!(json[1]["company"]["id"], client.id.to_string());
+    assert_eq!(json[1]["role"]["name"], "supervisor");
+    assert_eq!(json[1]["active"], true);
+}
+
+#[tokio::test]
+async fn test_token_of_ended_membership_is_rejected() {
+    let user = create_user(create_company("Home"));
+    let app = app_for(user, Uuid::new_v4(), vec![]);
+
+    let response = app
+        .oneshot(get("/api/v1/users/me/companies"))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_predictions_are_listed_in_the_company_they_were_made_in() {
+    let home = create_company("Home");
+    let client = create_company("Client");
+    let user = create_user(home.clone());
+    let membership = CompanyMembership {
+        user_id: user.id,
+        company: client.clone(),
+        role: create_role("user", 10),
+        created_at: Utc::now(),
+    };
+    let at_home = create_prediction(&user, home.id);
+    let at_client = create_prediction(&user, client.id);
+
+    for (company_id, expected) in [(home.id, at_home.id), (client.id, at_client.id)] {
+        let mut prediction_repo = MockPredictionRepository::new();
+        let predictions = vec![at_home.clone(), at_client.clone()];
+        prediction_repo
+            .expect_get_by_user_id()
+            .returning(move |_| Ok(predictions.clone()));
+        let app = app_with_predictions(
+            user.clone(),
+            company_id,
+            vec![membership.clone()],
+            prediction_repo,
+        );
+
+        let response = app
+            .oneshot(get("/api/v1/diagnostics/predictions?simplified=true"))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        let ids: Vec<&str> = json
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|p| p["id"].as_str().unwrap())
+            .collect();
+        assert_eq!(ids, vec![expected.to_string()]);
+    }
+}
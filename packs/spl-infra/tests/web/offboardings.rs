@@ -0,0 +1,61 @@
+use crate::common::build_company_app;
+use crate::common::factories::{create_company, create_user};
+use axum::body::{to_bytes, Body};
+use axum::http::{Request, StatusCode};
+use tower::ServiceExt;
+use uuid::Uuid;
+
+fn offboard(company_id: Uuid) -> Request<Body> {
+    Request::builder()
+        .uri(format!("/api/v1/companies/{company_id}/offboardings"))
+        .method("POST")
+        .header("Authorization", "Bearer valid_token")
+        .body(Body::empty())
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_supervisor_cannot_offboard_company() {
+    let company = create_company();
+    let company_id = company.id;
+    let app = build_company_app(create_user("supervisor", 50, company.clone()), company);
+
+    let response = app.oneshot(offboard(company_id)).await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_supervisor_cannot_download_export() {
+    let company = create_company();
+    let app = build_company_app(create_user("supervisor", 50, company.clone()), company);
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri(format!("/api/v1/offboardings/{}/export", Uuid::new_v4()))
+                .method("GET")
+                .header("Authorization", "Bearer valid_token")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_admin_cannot_offboard_own_company() {
+    let company = create_company();
+    let company_id = company.id;
+    let app = build_company_app(create_user("admin", 100, company.clone()), company);
+
+    let response = app.oneshot(offboard(company_id)).await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert!(json.to_string().contains("Cannot offboard your own company"));
+}
@@ -0,0 +1,224 @@
+use crate::common::build_auth_app;
+use crate::common::mocks::{
+    AuthMocks, MockMailer, MockPasswordEncoder, MockPasswordResetTokenRepository,
+    MockSessionRepository, MockTokenGenerator, MockUserRepository,
+};
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+use chrono::{Duration, Utc};
+use spl_domain::entities::auth::PasswordResetToken;
+use spl_domain::entities::user::{Role, User};
+use tower::ServiceExt;
+use uuid::Uuid;
+
+fn create_user(id: Uuid) -> User {
+    User {
+        id,
+        username: "webuser".to_string(),
+        email: Some("web@example.com".to_string()),
+        email_verified_at: Some(Utc::now()),
+        password_hash: "hashed".to_string(),
+        name: None,
+        surname: None,
+        role: Role {
+            id: 2,
+            name: "user".to_string(),
+            level: 10,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        },
+        company: None,
+        deactivated_at: None,
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+    }
+}
+
+fn post_json(uri: &str, payload: serde_json::Value) -> Request<Body> {
+    Request::builder()
+        .uri(uri)
+        .method("POST")
+        .header("Content-Type", "application/json")
+        .body(Body::from(payload.to_string()))
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_forgot_password_sends_email() {
+    let user_id = Uuid::new_v4();
+    let user = create_user(user_id);
+
+    let mut mock_user_repo = MockUserRepository::new();
+    mock_user_repo
+        .expect_get_by_username_or_email_and_company()
+        .times(1)
+        .returning(move |_, _, _| Ok(Some(user.clone())));
+
+    let mut reset_token_repo = MockPasswordResetTokenRepository::new();
+    reset_token_repo
+        .expect_invalidate_by_user_id()
+        .times(1)
+        .returning(|_| Ok(0));
+    reset_token_repo
+        .expect_create()
+        .withf(move |token| token.user_id == user_id)
+        .times(1)
+        .returning(Ok);
+
+    let mut mailer = MockMailer::new();
+    mailer
+        .expect_send()
+        .withf(|message| message.to == "web@example.com")
+        .times(1)
+        .returning(|_| Ok(()));
+
+    let app = build_auth_app(
+        mock_user_repo,
+        MockPasswordEncoder::new(),
+        MockTokenGenerator::new(),
+        AuthMocks {
+            password_reset_token_repo: reset_token_repo,
+            mailer,
+            ..Default::default()
+        },
+    );
+
+    let response = app
+        .oneshot(post_json(
+            "/api/v1/auth/password/forgot",
+            serde_json::json!({ "email": "web@example.com" }),
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_forgot_password_unknown_user_returns_ok() {
+    let mut mock_user_repo = MockUserRepository::new();
+    mock_user_repo
+        .expect_get_by_username_or_email_and_company()
+        .times(1)
+        .returning(|_, _, _| Ok(None));
+
+    let mut mailer = MockMailer::new();
+    mailer.expect_send().never();
+
+    let app = build_auth_app(
+        mock_user_repo,
+        MockPasswordEncoder::new(),
+        MockTokenGenerator::new(),
+        AuthMocks {
+            mailer,
+            ..Default::default()
+        },
+    );
+
+    let response = app
+        .oneshot(post_json(
+            "/api/v1/auth/password/forgot",
+            serde_json::json!({ "username": "ghost" }),
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_reset_password_success() {
+    let user_id = Uuid::new_v4();
+    let user = create_user(user_id);
+    let token = PasswordResetToken {
+        id: Uuid::new_v4(),
+        user_id,
+        token_hash: "hash".to_string(),
+        expires_at: Utc::now() + Duration::minutes(10),
+        used_at: None,
+        created_at: Utc::now(),
+    };
+
+    let mut mock_user_repo = MockUserRepository::new();
+    mock_user_repo
+        .expect_get_by_id()
+        .times(1)
+        .returning(move |_| Ok(Some(user.clone())));
+    mock_user_repo
+        .expect_update()
+        .withf(|user| user.password_hash == "new_hash")
+        .times(1)
+        .returning(Ok);
+
+    let mut mock_encoder = MockPasswordEncoder::new();
+    mock_encoder
+        .expect_hash()
+        .times(1)
+        .returning(|_| Ok("new_hash".to_string()));
+
+    let mut reset_token_repo = MockPasswordResetTokenRepository::new();
+    reset_token_repo
+        .expect_get_by_token_hash()
+        .times(1)
+        .returning(move |_| Ok(Some(token.clone())));
+    reset_token_repo
+        .expect_mark_used()
+        .times(1)
+        .returning(|_| Ok(true));
+
+    let mut session_repo = MockSessionRepository::new();
+    session_repo
+        .expect_revoke_by_user_id()
+        .times(1)
+        .returning(|_| Ok(1));
+
+    let app = build_auth_app(
+        mock_user_repo,
+        mock_encoder,
+        MockTokenGenerator::new(),
+        AuthMocks {
+            session_repo,
+            password_reset_token_repo: reset_token_repo,
+            ..Default::default()
+        },
+    );
+
+    let response = app
+        .oneshot(post_json(
+            "/api/v1/auth/password/reset",
+            serde_json::json!({ "token": "reset_token", "new_password": "new_password123" }),
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_reset_password_invalid_token() {
+    let mut reset_token_repo = MockPasswordResetTokenRepository::new();
+    reset_token_repo
+        .expect_get_by_token_hash()
+        .times(1)
+        .returning(|_| Ok(None));
+
+    let app = build_auth_app(
+        MockUserRepository::new(),
+        MockPasswordEncoder::new(),
+        MockTokenGenerator::new(),
+        AuthMocks {
+            password_reset_token_repo: reset_token_repo,
+            ..Default::default()
+        },
+    );
+
+    let response = app
+        .oneshot(post_json(
+            "/api/v1/auth/password/reset",
+            serde_json::json!({ "token": "unknown", "new_password": "new_password123" }),
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
@@ -24,6 +24,8 @@ async fn test_get_plots_success() {
         id: company_id,
         name: "Test Company".to_string(),
         description: None,
+        parent_id: None,
+        two_factor_required_level: None,
         created_at: chrono::Utc::now(),
         updated_at: chrono::Utc::now(),
     };
@@ -32,6 +34,7 @@ async fn test_get_plots_success() {
         id: user_id,
         username: "testuser".to_string(),
         email: Some("test@example.com".to_string()),
+        email_verified_at: None,
         password_hash: "hashed".to_string(),
         name: None,
         surname: None,
@@ -43,6 +46,7 @@ async fn test_get_plots_success() {
             updated_at: chrono::Utc::now(),
         },
         company: Some(company),
+        deactivated_at: None,
         created_at: chrono::Utc::now(),
         updated_at: chrono::Utc::now(),
     };
@@ -77,10 +81,17 @@ async fn test_get_plots_success() {
         .times(1)
         .returning(move |_| Ok(plots.clone()));
 
+    // The company has no member companies
+    let mut mock_company_repo = MockCompanyRepository::new();
+    mock_company_repo
+        .expect_get_by_parent_ids()
+        .with(eq(vec![company_id]))
+        .returning(|_| Ok(vec![]));
+
     let app = build_app_full(
         mock_user_repo,
         MockRoleRepository::new(),
-        MockCompanyRepository::new(),
+        mock_company_repo,
         MockRecommendationRepository::new(),
         MockRecommendationCategoryRepository::new(),
         MockLabelRepository::new(),
@@ -122,6 +133,8 @@ async fn test_get_plot_by_id_success() {
         id: company_id,
         name: "Test Company".to_string(),
         description: None,
+        parent_id: None,
+        two_factor_required_level: None,
         created_at: chrono::Utc::now(),
         updated_at: chrono::Utc::now(),
     };
@@ -130,6 +143,7 @@ async fn test_get_plot_by_id_success() {
         id: user_id,
         username: "testuser".to_string(),
         email: Some("test@example.com".to_string()),
+        email_verified_at: None,
         password_hash: "hashed".to_string(),
         name: None,
         surname: None,
@@ -141,6 +155,7 @@ async fn test_get_plot_by_id_success() {
             updated_at: chrono::Utc::now(),
         },
         company: Some(company),
+        deactivated_at: None,
         created_at: chrono::Utc::now(),
         updated_at: chrono::Utc::now(),
     };
@@ -219,6 +234,8 @@ async fn test_create_plot_success() {
         id: company_id,
         name: "Test Company".to_string(),
         description: None,
+        parent_id: None,
+        two_factor_required_level: None,
         created_at: chrono::Utc::now(),
         updated_at: chrono::Utc::now(),
     };
@@ -227,6 +244,7 @@ async fn test_create_plot_success() {
         id: user_id,
         username: "supervisor".to_string(),
         email: Some("supervisor@example.com".to_string()),
+        email_verified_at: None,
         password_hash: "hashed".to_string(),
         name: None,
         surname: None,
@@ -238,6 +256,7 @@ async fn test_create_plot_success() {
             updated_at: chrono::Utc::now(),
         },
         company: Some(company),
+        deactivated_at: None,
         created_at: chrono::Utc::now(),
         updated_at: chrono::Utc::now(),
     };
@@ -324,6 +343,8 @@ async fn test_create_plot_forbidden() {
         id: company_id,
         name: "Test Company".to_string(),
         description: None,
+        parent_id: None,
+        two_factor_required_level: None,
         created_at: chrono::Utc::now(),
         updated_at: chrono::Utc::now(),
     };
@@ -332,6 +353,7 @@ async fn test_create_plot_forbidden() {
         id: user_id,
         username: "user".to_string(),
         email: Some("user@example.com".to_string()),
+        email_verified_at: None,
         password_hash: "hashed".to_string(),
         name: None,
         surname: None,
@@ -343,6 +365,7 @@ async fn test_create_plot_forbidden() {
             updated_at: chrono::Utc::now(),
         },
         company: Some(company),
+        deactivated_at: None,
         created_at: chrono::Utc::now(),
         updated_at: chrono::Utc::now(),
     };
@@ -413,6 +436,8 @@ async fn test_update_plot_success() {
         id: company_id,
         name: "Test Company".to_string(),
         description: None,
+        parent_id: None,
+        two_factor_required_level: None,
         created_at: chrono::Utc::now(),
         updated_at: chrono::Utc::now(),
     };
@@ -421,6 +446,7 @@ async fn test_update_plot_success() {
         id: user_id,
         username: "testuser".to_string(),
         email: Some("test@example.com".to_string()),
+        email_verified_at: None,
         password_hash: "hashed".to_string(),
         name: None,
         surname: None,
@@ -432,6 +458,7 @@ async fn test_update_plot_success() {
             updated_at: chrono::Utc::now(),
         },
         company: Some(company),
+        deactivated_at: None,
         created_at: chrono::Utc::now(),
         updated_at: chrono::Utc::now(),
     };
@@ -525,6 +552,8 @@ async fn test_delete_plot_success() {
         id: company_id,
         name: "Test Company".to_string(),
         description: None,
+        parent_id: None,
+        two_factor_required_level: None,
         created_at: chrono::Utc::now(),
         updated_at: chrono::Utc::now(),
     };
@@ -533,6 +562,7 @@ async fn test_delete_plot_success() {
         id: user_id,
         username: "testuser".to_string(),
         email: Some("test@example.com".to_string()),
+        email_verified_at: None,
         password_hash: "hashed".to_string(),
         name: None,
         surname: None,
@@ -544,6 +574,7 @@ async fn test_delete_plot_success() {
             updated_at: chrono::Utc::now(),
         },
         company: Some(company),
+        deactivated_at: None,
         created_at: chrono::Utc::now(),
         updated_at: chrono::Utc::now(),
     };
@@ -633,6 +664,8 @@ async fn test_detailed_plots_with_label_filtering() {
         id: company_id,
         name: "Test Company".to_string(),
         description: None,
+        parent_id: None,
+        two_factor_required_level: None,
         created_at: chrono::Utc::now(),
         updated_at: chrono::Utc::now(),
     };
@@ -641,6 +674,7 @@ async fn test_detailed_plots_with_label_filtering() {
         id: user_id,
         username: "testuser".to_string(),
         email: Some("test@example.com".to_string()),
+        email_verified_at: None,
         password_hash: "hashed".to_string(),
         name: None,
         surname: None,
@@ -652,6 +686,7 @@ async fn test_detailed_plots_with_label_filtering() {
             updated_at: chrono::Utc::now(),
         },
         company: Some(company),
+        deactivated_at: None,
         created_at: chrono::Utc::now(),
         updated_at: chrono::Utc::now(),
     };
@@ -690,14 +725,19 @@ async fn test_detailed_plots_with_label_filtering() {
 
     mock_plot_repo
         .expect_get_detailed()
-        .with(eq(company_id), eq(0), eq(10), eq(labels_filter.clone())) // Use labels_clone here
+        .with(eq(vec![company_id]), eq(None), eq(0), eq(10), eq(labels_filter.clone())) // Use labels_clone here
         .times(1)
-        .returning(move |_, _, _, _| Ok((1, vec![detailed_plot.clone()])));
+        .returning(move |_, _, _, _, _| Ok((1, vec![detailed_plot.clone()])));
+
+    let mut mock_company_repo = MockCompanyRepository::new();
+    mock_company_repo
+        .expect_get_by_parent_ids()
+        .returning(|_| Ok(vec![]));
 
     let app = build_app_full(
         mock_user_repo,
         MockRoleRepository::new(),
-        MockCompanyRepository::new(),
+        mock_company_repo,
         MockRecommendationRepository::new(),
         MockRecommendationCategoryRepository::new(),
         MockLabelRepository::new(),
@@ -0,0 +1,193 @@
+use crate::common::build_auth_app;
+use crate::common::factories::{create_company, create_user};
+use crate::common::mocks::{
+    AuthMocks, MockInferenceJobRepository, MockPasswordEncoder, MockPredictionBatchItemRepository,
+    MockPredictionBatchRepository, MockTokenGenerator, MockUserRepository,
+};
+use axum::body::{to_bytes, Body};
+use axum::http::{Request, StatusCode};
+use chrono::Utc;
+use spl_domain::entities::diagnostics::{BatchStatus, PredictionBatch};
+use spl_domain::entities::user::User;
+use spl_domain::ports::archive::ArchiveWriter;
+use spl_infra::adapters::archive::zip::ZipArchiveWriter;
+use tower::ServiceExt;
+use uuid::Uuid;
+
+const BOUNDARY: &str = "batch-boundary";
+
+fn create_batch(user: &User) -> PredictionBatch {
+    PredictionBatch {
+        id: Uuid::new_v4(),
+        user_id: user.id,
+        company_id: user.company.as_ref().map(|c| c.id),
+        status: BatchStatus::Processing,
+        items_total: 1,
+        items_completed: 0,
+        items_failed: 0,
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+        completed_at: None,
+    }
+}
+
+/// App where the user is authenticated
+fn app_for(
+    user: User,
+    prediction_batch_repo: MockPredictionBatchRepository,
+    prediction_batch_item_repo: MockPredictionBatchItemRepository,
+    inference_job_repo: MockInferenceJobRepository,
+) -> axum::Router {
+    let user_id = user.id;
+
+    let mut user_repo = MockUserRepository::new();
+    user_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(user.clone())));
+
+    let mut token_gen = MockTokenGenerator::new();
+    token_gen
+        .expect_validate()
+        .returning(move |_| Ok(serde_json::json!({ "sub": user_id.to_string() })));
+
+    build_auth_app(
+        user_repo,
+        MockPasswordEncoder::new(),
+        token_gen,
+        AuthMocks {
+            prediction_batch_repo,
+            prediction_batch_item_repo,
+            inference_job_repo,
+            ..Default::default()
+        },
+    )
+}
+
+fn upload(files: &[(&str, &str, &[u8])]) -> Request<Body> {
+    let mut body = Vec::new();
+    for (field, filename, content) in files {
+        body.extend_from_slice(
+            format!(
+                "--{BOUNDARY}\r\nContent-Disposition: form-data; name=\"{field}\"; filename=\"{filename}\"\r\nContent-Type: application/octet-stream\r\n\r\n"
+            )
+            .as_bytes(),
+        );
+        body.extend_from_slice(content);
+        body.extend_from_slice(b"\r\n");
+    }
+    body.extend_from_slice(format!("--{BOUNDARY}--\r\n").as_bytes());
+
+    Request::builder()
+        .uri("/api/v1/diagnostics/predictions/batches")
+        .method("POST")
+        .header("Authorization", "Bearer valid_token")
+        .header(
+            "Content-Type",
+            format!("multipart/form-data; boundary={BOUNDARY}"),
+        )
+        .body(Body::from(body))
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_create_batch_extracts_archives() {
+    let user = create_user("user", 10, create_company());
+
+    let mut archive = ZipArchiveWriter::new().create();
+    archive.add("field/a.jpg", b"first").unwrap();
+    archive.add("field/b.jpg", b"second").unwrap();
+    let zip = archive.finish().unwrap();
+
+    let mut batch_repo = MockPredictionBatchRepository::new();
+    batch_repo.expect_create().times(1).returning(Ok);
+
+    let mut item_repo = MockPredictionBatchItemRepository::new();
+    item_repo
+        .expect_create_many()
+        .withf(|items| {
+            items.iter().map(|item| item.filename.as_str()).eq([
+                "field/a.jpg",
+                "field/b.jpg",
+                "c.jpg",
+            ])
+        })
+        .times(1)
+        .returning(|_| Ok(()));
+
+    // Every image is queued for the workers
+    let mut job_repo = MockInferenceJobRepository::new();
+    job_repo
+        .expect_create_many()
+        .withf(|jobs| jobs.len() == 3 && jobs.iter().all(|job| job.batch_item_id.is_some()))
+        .times(1)
+        .returning(|_| Ok(()));
+
+    let app = app_for(user, batch_repo, item_repo, job_repo);
+
+    let response = app
+        .oneshot(upload(&[
+            ("file", "photos.zip", &zip),
+            ("file", "c.jpg", b"third"),
+        ]))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::ACCEPTED);
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["status"], "processing");
+    assert_eq!(json["items_total"], 3);
+    assert_eq!(json["items_pending"], 3);
+}
+
+#[tokio::test]
+async fn test_create_batch_without_files_is_rejected() {
+    let app = app_for(
+        create_user("user", 10, create_company()),
+        MockPredictionBatchRepository::new(),
+        MockPredictionBatchItemRepository::new(),
+        MockInferenceJobRepository::new(),
+    );
+
+    let response = app
+        .oneshot(upload(&[("image", "a.jpg", b"content")]))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_batch_of_another_user_is_not_found() {
+    let batch = create_batch(&create_user("user", 10, create_company()));
+    let batch_id = batch.id;
+
+    let mut batch_repo = MockPredictionBatchRepository::new();
+    batch_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(batch.clone())));
+
+    let app = app_for(
+        create_user("user", 10, create_company()),
+        batch_repo,
+        MockPredictionBatchItemRepository::new(),
+        MockInferenceJobRepository::new(),
+    );
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri(format!(
+                    "/api/v1/diagnostics/predictions/batches/{batch_id}"
+                ))
+                .method("GET")
+                .header("Authorization", "Bearer valid_token")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
@@ -21,6 +21,7 @@ async fn test_get_all_categories_success() {
         id: user_id,
         username: "testuser".to_string(),
         email: Some("test@example.com".to_string()),
+        email_verified_at: None,
         password_hash: "hashed".to_string(),
         name: None,
         surname: None,
@@ -32,6 +33,7 @@ async fn test_get_all_categories_success() {
             updated_at: chrono::Utc::now(),
         },
         company: None,
+        deactivated_at: None,
         created_at: chrono::Utc::now(),
         updated_at: chrono::Utc::now(),
     };
@@ -105,6 +107,7 @@ async fn test_get_all_recommendations_success() {
         id: user_id,
         username: "testuser".to_string(),
         email: Some("test@example.com".to_string()),
+        email_verified_at: None,
         password_hash: "hashed".to_string(),
         name: None,
         surname: None,
@@ -116,6 +119,7 @@ async fn test_get_all_recommendations_success() {
             updated_at: chrono::Utc::now(),
         },
         company: None,
+        deactivated_at: None,
         created_at: chrono::Utc::now(),
         updated_at: chrono::Utc::now(),
     };
@@ -201,6 +205,7 @@ async fn test_update_category_success() {
         id: user_id,
         username: "adminuser".to_string(),
         email: Some("admin@example.com".to_string()),
+        email_verified_at: None,
         password_hash: "hashed".to_string(),
         name: None,
         surname: None,
@@ -212,6 +217,7 @@ async fn test_update_category_success() {
             updated_at: chrono::Utc::now(),
         },
         company: None,
+        deactivated_at: None,
         created_at: chrono::Utc::now(),
         updated_at: chrono::Utc::now(),
     };
@@ -304,6 +310,7 @@ async fn test_delete_category_success() {
         id: user_id,
         username: "adminuser".to_string(),
         email: Some("admin@example.com".to_string()),
+        email_verified_at: None,
         password_hash: "hashed".to_string(),
         name: None,
         surname: None,
@@ -315,6 +322,7 @@ async fn test_delete_category_success() {
             updated_at: chrono::Utc::now(),
         },
         company: None,
+        deactivated_at: None,
         created_at: chrono::Utc::now(),
         updated_at: chrono::Utc::now(),
     };
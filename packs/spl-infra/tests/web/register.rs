@@ -29,6 +29,7 @@ async fn test_register_endpoint_success() {
         id: creator_id,
         username: "admin".to_string(),
         email: Some("admin@example.com".to_string()),
+        email_verified_at: None,
         password_hash: "hash".to_string(),
         name: None,
         surname: None,
@@ -40,6 +41,7 @@ async fn test_register_endpoint_success() {
             updated_at: Utc::now(),
         },
         company: None,
+        deactivated_at: None,
         created_at: Utc::now(),
         updated_at: Utc::now(),
     };
@@ -48,6 +50,7 @@ async fn test_register_endpoint_success() {
         id: new_user_id,
         username: "newuser".to_string(),
         email: Some("new@example.com".to_string()),
+        email_verified_at: None,
         password_hash: "hashed_new".to_string(),
         name: None,
         surname: None,
@@ -59,6 +62,7 @@ async fn test_register_endpoint_success() {
             updated_at: Utc::now(),
         },
         company: None, // Simplified for this test as we mocking response
+        deactivated_at: None,
         created_at: Utc::now(),
         updated_at: Utc::now(),
     };
@@ -135,6 +139,8 @@ async fn test_register_endpoint_success() {
                 id: company_id,
                 name: "Test Company".to_string(),
                 description: None,
+                parent_id: None,
+                two_factor_required_level: None,
                 created_at: Utc::now(),
                 updated_at: Utc::now(),
             }))
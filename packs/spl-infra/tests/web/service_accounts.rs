@@ -0,0 +1,228 @@
+use crate::common::build_auth_app;
+use crate::common::factories::{create_company, create_user};
+use crate::common::mocks::{
+    AuthMocks, MockApiKeyRepository, MockPasswordEncoder, MockServiceAccountRepository,
+    MockTokenGenerator, MockUserRepository,
+};
+use axum::body::{to_bytes, Body};
+use axum::http::{Request, StatusCode};
+use chrono::Utc;
+use spl_domain::entities::auth::{ApiKey, ServiceAccount};
+use spl_domain::entities::user::User;
+use tower::ServiceExt;
+use uuid::Uuid;
+
+fn create_api_key(service_account_id: Uuid, scopes: &[&str]) -> ApiKey {
+    ApiKey {
+        id: Uuid::new_v4(),
+        service_account_id,
+        name: "Field device".to_string(),
+        key_prefix: "spl_abcdefgh".to_string(),
+        key_hash: "hash".to_string(),
+        scopes: scopes.iter().map(|s| s.to_string()).collect(),
+        expires_at: None,
+        last_used_at: None,
+        revoked_at: None,
+        created_at: Utc::now(),
+    }
+}
+
+/// Mocks resolving any API key to `key`, owned by the service account user `user`
+fn api_key_mocks(user: User, key: ApiKey) -> (MockUserRepository, AuthMocks) {
+    let mut mock_user_repo = MockUserRepository::new();
+    mock_user_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(user.clone())));
+
+    let mut api_key_repo = MockApiKeyRepository::new();
+    api_key_repo
+        .expect_get_by_key_hash()
+        .returning(move |_| Ok(Some(key.clone())));
+    api_key_repo.expect_touch().returning(|_, _| Ok(()));
+
+    (
+        mock_user_repo,
+        AuthMocks {
+            api_key_repo,
+            ..Default::default()
+        },
+    )
+}
+
+fn api_key_request(method: &str, uri: &str) -> Request<Body> {
+    Request::builder()
+        .uri(uri)
+        .method(method)
+        .header("X-API-Key", "spl_device_key")
+        .body(Body::empty())
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_api_key_with_scope_is_accepted() {
+    let device = create_user("user", 10, create_company());
+    let key = create_api_key(device.id, &["predictions:write"]);
+    let (mock_user_repo, auth_mocks) = api_key_mocks(device, key);
+
+    let app = build_auth_app(
+        mock_user_repo,
+        MockPasswordEncoder::new(),
+        MockTokenGenerator::new(),
+        auth_mocks,
+    );
+
+    let response = app
+        .oneshot(api_key_request("POST", "/api/v1/diagnostics/predictions"))
+        .await
+        .unwrap();
+
+    // Authentication passed, the request fails on the missing multipart body
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_api_key_without_scope_is_forbidden() {
+    let device = create_user("user", 10, create_company());
+    let key = create_api_key(device.id, &["dashboard:read"]);
+    let (mock_user_repo, auth_mocks) = api_key_mocks(device, key);
+
+    let app = build_auth_app(
+        mock_user_repo,
+        MockPasswordEncoder::new(),
+        MockTokenGenerator::new(),
+        auth_mocks,
+    );
+
+    let response = app
+        .oneshot(api_key_request("POST", "/api/v1/diagnostics/predictions"))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_api_key_is_rejected_on_unscoped_routes() {
+    let device = create_user("user", 10, create_company());
+    let key = create_api_key(device.id, &["predictions:read", "predictions:write"]);
+    let (mock_user_repo, auth_mocks) = api_key_mocks(device, key);
+
+    let app = build_auth_app(
+        mock_user_repo,
+        MockPasswordEncoder::new(),
+        MockTokenGenerator::new(),
+        auth_mocks,
+    );
+
+    let response = app
+        .oneshot(api_key_request("GET", "/api/v1/users/me"))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_revoked_api_key_is_unauthorized() {
+    let device = create_user("user", 10, create_company());
+    let mut key = create_api_key(device.id, &["predictions:write"]);
+    key.revoked_at = Some(Utc::now());
+    let (mock_user_repo, auth_mocks) = api_key_mocks(device, key);
+
+    let app = build_auth_app(
+        mock_user_repo,
+        MockPasswordEncoder::new(),
+        MockTokenGenerator::new(),
+        auth_mocks,
+    );
+
+    let response = app
+        .oneshot(api_key_request("POST", "/api/v1/diagnostics/predictions"))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_supervisor_creates_api_key() {
+    let company = create_company();
+    let company_id = company.id;
+    let supervisor = create_user("supervisor", 50, company);
+    let supervisor_id = supervisor.id;
+    let account_id = Uuid::new_v4();
+
+    let mut mock_token = MockTokenGenerator::new();
+    mock_token
+        .expect_validate()
+        .returning(move |_| Ok(serde_json::json!({ "sub": supervisor_id.to_string() })));
+
+    let mut mock_user_repo = MockUserRepository::new();
+    mock_user_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(supervisor.clone())));
+
+    let mut service_account_repo = MockServiceAccountRepository::new();
+    service_account_repo
+        .expect_get_by_id()
+        .returning(move |id| {
+            Ok(Some(ServiceAccount {
+                id,
+                company_id,
+                name: "Field devices".to_string(),
+                description: None,
+                created_by: None,
+                created_at: Utc::now(),
+            }))
+        });
+
+    let mut api_key_repo = MockApiKeyRepository::new();
+    api_key_repo
+        .expect_create()
+        .withf(move |key| {
+            key.service_account_id == account_id && key.key_prefix.starts_with("spl_")
+        })
+        .times(1)
+        .returning(Ok);
+
+    let app = build_auth_app(
+        mock_user_repo,
+        MockPasswordEncoder::new(),
+        mock_token,
+        AuthMocks {
+            service_account_repo,
+            api_key_repo,
+            ..Default::default()
+        },
+    );
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri(format!("/api/v1/service-accounts/{}/keys", account_id))
+                .method("POST")
+                .header("Content-Type", "application/json")
+                .header("Authorization", "Bearer valid_token")
+                .body(Body::from(
+                    serde_json::json!({
+                        "name": "Field device",
+                        "scopes": ["predictions:write", "predictions:write"]
+                    })
+                    .to_string(),
+                ))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert!(body["key"].as_str().unwrap().starts_with("spl_"));
+    assert_eq!(
+        body["api_key"]["scopes"],
+        serde_json::json!(["predictions:write"])
+    );
+    assert!(body["api_key"].get("key_hash").is_none());
+}
@@ -0,0 +1,451 @@
+use crate::common::build_auth_app;
+use crate::common::factories::{create_company, create_user_with_id};
+use crate::common::mocks::{
+    AuthMocks, MockPasswordEncoder, MockRefreshTokenRepository, MockSessionRepository,
+    MockTokenGenerator, MockUserRepository,
+};
+use axum::body::{to_bytes, Body};
+use axum::http::{Request, StatusCode};
+use chrono::{Duration, Utc};
+use mockall::predicate::*;
+use spl_domain::entities::auth::{RefreshToken, Session};
+use tower::ServiceExt;
+use uuid::Uuid;
+
+fn create_session(user_id: Uuid, revoked: bool) -> Session {
+    Session {
+        id: Uuid::new_v4(),
+        user_id,
+        expires_at: Utc::now() + Duration::days(30),
+        revoked_at: if revoked { Some(Utc::now()) } else { None },
+        user_agent: None,
+        ip_address: None,
+        active_company_id: None,
+        last_seen_at: Utc::now(),
+        created_at: Utc::now(),
+    }
+}
+
+fn create_refresh_token(session_id: Uuid, used: bool) -> RefreshToken {
+    RefreshToken {
+        id: Uuid::new_v4(),
+        session_id,
+        token_hash: "hash".to_string(),
+        expires_at: Utc::now() + Duration::days(30),
+        used_at: if used { Some(Utc::now()) } else { None },
+        created_at: Utc::now(),
+    }
+}
+
+fn post_json(uri: &str, payload: serde_json::Value) -> Request<Body> {
+    Request::builder()
+        .uri(uri)
+        .method("POST")
+        .header("Content-Type", "application/json")
+        .body(Body::from(payload.to_string()))
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_refresh_endpoint_rotates_tokens() {
+    let user_id = Uuid::new_v4();
+    let user = create_user_with_id(user_id);
+    let session = create_session(user_id, false);
+    let token = create_refresh_token(session.id, false);
+    let token_id = token.id;
+
+    let mut mock_user_repo = MockUserRepository::new();
+    mock_user_repo
+        .expect_get_by_id()
+        .with(eq(user_id))
+        .returning(move |_| Ok(Some(user.clone())));
+
+    let mut session_repo = MockSessionRepository::new();
+    session_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(session.clone())));
+    // The address of the client is recorded on the session
+    session_repo
+        .expect_touch()
+        .withf(|_, _, ip| ip.as_deref() == Some("127.0.0.1"))
+        .times(1)
+        .returning(|_, _, _| Ok(()));
+
+    let mut refresh_token_repo = MockRefreshTokenRepository::new();
+    refresh_token_repo
+        .expect_get_by_token_hash()
+        .times(1)
+        .returning(move |_| Ok(Some(token.clone())));
+    refresh_token_repo
+        .expect_mark_used()
+        .with(eq(token_id))
+        .times(1)
+        .returning(|_| Ok(true));
+    refresh_token_repo
+        .expect_create()
+        .times(1)
+        .returning(Ok);
+
+    let mut mock_token = MockTokenGenerator::new();
+    mock_token
+        .expect_generate()
+        .times(1)
+        .returning(|_, _| Ok("new_jwt_token".to_string()));
+
+    let app = build_auth_app(
+        mock_user_repo,
+        MockPasswordEncoder::new(),
+        mock_token,
+        AuthMocks {
+            session_repo,
+            refresh_token_repo,
+            ..Default::default()
+        },
+    );
+
+    let response = app
+        .oneshot(post_json(
+            "/api/v1/auth/refresh",
+            serde_json::json!({ "refresh_token": "old_refresh_token" }),
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body_bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let body_json: serde_json::Value = serde_json::from_slice(&body_bytes).unwrap();
+
+    assert_eq!(body_json["token"], "new_jwt_token");
+    assert_ne!(body_json["refresh_token"], "old_refresh_token");
+}
+
+#[tokio::test]
+async fn test_refresh_endpoint_reuse_revokes_session() {
+    let user_id = Uuid::new_v4();
+    let session = create_session(user_id, false);
+    let session_id = session.id;
+    let token = create_refresh_token(session.id, true);
+
+    let mut session_repo = MockSessionRepository::new();
+    session_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(session.clone())));
+    session_repo
+        .expect_revoke()
+        .with(eq(session_id))
+        .times(1)
+        .returning(|_| Ok(true));
+
+    let mut refresh_token_repo = MockRefreshTokenRepository::new();
+    refresh_token_repo
+        .expect_get_by_token_hash()
+        .returning(move |_| Ok(Some(token.clone())));
+    refresh_token_repo.expect_mark_used().never();
+
+    let app = build_auth_app(
+        MockUserRepository::new(),
+        MockPasswordEncoder::new(),
+        MockTokenGenerator::new(),
+        AuthMocks {
+            session_repo,
+            refresh_token_repo,
+            ..Default::default()
+        },
+    );
+
+    let response = app
+        .oneshot(post_json(
+            "/api/v1/auth/refresh",
+            serde_json::json!({ "refresh_token": "reused_refresh_token" }),
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_refresh_endpoint_unknown_token() {
+    let mut refresh_token_repo = MockRefreshTokenRepository::new();
+    refresh_token_repo
+        .expect_get_by_token_hash()
+        .returning(|_| Ok(None));
+
+    let app = build_auth_app(
+        MockUserRepository::new(),
+        MockPasswordEncoder::new(),
+        MockTokenGenerator::new(),
+        AuthMocks {
+            session_repo: MockSessionRepository::new(),
+            refresh_token_repo,
+            ..Default::default()
+        },
+    );
+
+    let response = app
+        .oneshot(post_json(
+            "/api/v1/auth/refresh",
+            serde_json::json!({ "refresh_token": "unknown" }),
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_logout_endpoint_revokes_session() {
+    let session_id = Uuid::new_v4();
+    let token = create_refresh_token(session_id, false);
+
+    let mut session_repo = MockSessionRepository::new();
+    session_repo
+        .expect_revoke()
+        .with(eq(session_id))
+        .times(1)
+        .returning(|_| Ok(true));
+
+    let mut refresh_token_repo = MockRefreshTokenRepository::new();
+    refresh_token_repo
+        .expect_get_by_token_hash()
+        .returning(move |_| Ok(Some(token.clone())));
+
+    let app = build_auth_app(
+        MockUserRepository::new(),
+        MockPasswordEncoder::new(),
+        MockTokenGenerator::new(),
+        AuthMocks {
+            session_repo,
+            refresh_token_repo,
+            ..Default::default()
+        },
+    );
+
+    let response = app
+        .oneshot(post_json(
+            "/api/v1/auth/logout",
+            serde_json::json!({ "refresh_token": "refresh_token" }),
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_access_token_of_revoked_session_is_rejected() {
+    let user_id = Uuid::new_v4();
+    let session = create_session(user_id, true);
+    let session_id = session.id;
+
+    let mut session_repo = MockSessionRepository::new();
+    session_repo
+        .expect_get_by_id()
+        .with(eq(session_id))
+        .returning(move |_| Ok(Some(session.clone())));
+
+    let mut mock_token = MockTokenGenerator::new();
+    mock_token.expect_validate().returning(move |_| {
+        Ok(serde_json::json!({
+            "sub": user_id.to_string(),
+            "role": "user",
+            "sid": session_id.to_string(),
+        }))
+    });
+
+    let app = build_auth_app(
+        MockUserRepository::new(),
+        MockPasswordEncoder::new(),
+        mock_token,
+        AuthMocks {
+            session_repo,
+            refresh_token_repo: MockRefreshTokenRepository::new(),
+            ..Default::default()
+        },
+    );
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/api/v1/auth/validate")
+                .method("POST")
+                .header("Authorization", "Bearer revoked_token")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+fn get_with_token(uri: &str, method: &str) -> Request<Body> {
+    Request::builder()
+        .uri(uri)
+        .method(method)
+        .header("Authorization", "Bearer valid_token")
+        .body(Body::empty())
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_list_own_sessions_marks_current() {
+    let user_id = Uuid::new_v4();
+    let user = create_user_with_id(user_id);
+    let current = create_session(user_id, false);
+    let current_id = current.id;
+    let other = create_session(user_id, false);
+
+    let mut session_repo = MockSessionRepository::new();
+    let active = current.clone();
+    session_repo
+        .expect_get_by_id()
+        .with(eq(current_id))
+        .returning(move |_| Ok(Some(active.clone())));
+    session_repo
+        .expect_get_active_by_user_id()
+        .with(eq(user_id))
+        .times(1)
+        .returning(move |_| Ok(vec![current.clone(), other.clone()]));
+
+    let mut mock_token = MockTokenGenerator::new();
+    mock_token.expect_validate().returning(move |_| {
+        Ok(serde_json::json!({
+            "sub": user_id.to_string(),
+            "role": "user",
+            "sid": current_id.to_string(),
+        }))
+    });
+
+    let mut mock_user_repo = MockUserRepository::new();
+    mock_user_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(user.clone())));
+
+    let app = build_auth_app(
+        mock_user_repo,
+        MockPasswordEncoder::new(),
+        mock_token,
+        AuthMocks {
+            session_repo,
+            ..Default::default()
+        },
+    );
+
+    let response = app
+        .oneshot(get_with_token("/api/v1/users/me/sessions", "GET"))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(body.as_array().unwrap().len(), 2);
+    assert_eq!(body[0]["id"], current_id.to_string());
+    assert_eq!(body[0]["current"], true);
+    assert_eq!(body[1]["current"], false);
+}
+
+#[tokio::test]
+async fn test_supervisor_revokes_sessions_of_company_user() {
+    let company = create_company();
+
+    let mut supervisor = create_user_with_id(Uuid::new_v4());
+    supervisor.role.name = "supervisor".to_string();
+    supervisor.role.level = 50;
+    supervisor.company = Some(company.clone());
+    let supervisor_id = supervisor.id;
+
+    let mut target = create_user_with_id(Uuid::new_v4());
+    target.company = Some(company);
+    let target_id = target.id;
+
+    let mut mock_user_repo = MockUserRepository::new();
+    mock_user_repo
+        .expect_get_by_id()
+        .with(eq(supervisor_id))
+        .returning(move |_| Ok(Some(supervisor.clone())));
+    mock_user_repo
+        .expect_get_by_id()
+        .with(eq(target_id))
+        .returning(move |_| Ok(Some(target.clone())));
+
+    let mut mock_token = MockTokenGenerator::new();
+    mock_token
+        .expect_validate()
+        .returning(move |_| Ok(serde_json::json!({ "sub": supervisor_id.to_string() })));
+
+    let mut session_repo = MockSessionRepository::new();
+    session_repo
+        .expect_revoke_by_user_id()
+        .with(eq(target_id))
+        .times(1)
+        .returning(|_| Ok(2));
+
+    let app = build_auth_app(
+        mock_user_repo,
+        MockPasswordEncoder::new(),
+        mock_token,
+        AuthMocks {
+            session_repo,
+            ..Default::default()
+        },
+    );
+
+    let response = app
+        .oneshot(get_with_token(
+            &format!("/api/v1/users/{}/sessions", target_id),
+            "DELETE",
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(body["revoked"], 2);
+}
+
+#[tokio::test]
+async fn test_user_cannot_list_sessions_of_other_users() {
+    let user_id = Uuid::new_v4();
+    let user = create_user_with_id(user_id);
+
+    let mut mock_user_repo = MockUserRepository::new();
+    mock_user_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(user.clone())));
+
+    let mut mock_token = MockTokenGenerator::new();
+    mock_token
+        .expect_validate()
+        .returning(move |_| Ok(serde_json::json!({ "sub": user_id.to_string() })));
+
+    let mut session_repo = MockSessionRepository::new();
+    session_repo.expect_get_active_by_user_id().never();
+
+    let app = build_auth_app(
+        mock_user_repo,
+        MockPasswordEncoder::new(),
+        mock_token,
+        AuthMocks {
+            session_repo,
+            ..Default::default()
+        },
+    );
+
+    let response = app
+        .oneshot(get_with_token(
+            &format!("/api/v1/users/{}/sessions", Uuid::new_v4()),
+            "GET",
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
@@ -0,0 +1,194 @@
+use crate::common::build_auth_app;
+use crate::common::mocks::{
+    AuthMocks, MockIdentityProviderRepository, MockOidcClient, MockOidcLoginStateRepository,
+    MockPasswordEncoder, MockTokenGenerator, MockUserRepository,
+};
+use axum::body::{to_bytes, Body};
+use axum::http::{header, Request, StatusCode};
+use chrono::Utc;
+use spl_domain::entities::auth::IdentityProvider;
+use spl_domain::entities::company::Company;
+use spl_domain::entities::user::{Role, User};
+use std::collections::BTreeMap;
+use tower::ServiceExt;
+use uuid::Uuid;
+
+fn create_provider(company_id: Uuid) -> IdentityProvider {
+    IdentityProvider {
+        company_id,
+        issuer: "https://idp.example.com".to_string(),
+        client_id: "spl-backend".to_string(),
+        client_secret: Some("very-secret".to_string()),
+        scopes: "openid email profile".to_string(),
+        role_claim: "roles".to_string(),
+        role_mapping: BTreeMap::from([("agronomists".to_string(), "user".to_string())]),
+        default_role: None,
+        auto_provision: false,
+        enabled: true,
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+    }
+}
+
+#[tokio::test]
+async fn test_authorize_redirects_to_identity_provider() {
+    let company_id = Uuid::new_v4();
+
+    let mut identity_provider_repo = MockIdentityProviderRepository::new();
+    identity_provider_repo
+        .expect_get_by_id()
+        .returning(move |id| Ok(Some(create_provider(id))));
+
+    let mut oidc_login_state_repo = MockOidcLoginStateRepository::new();
+    oidc_login_state_repo
+        .expect_create()
+        .withf(move |state| state.company_id == company_id)
+        .times(1)
+        .returning(Ok);
+
+    let mut oidc_client = MockOidcClient::new();
+    oidc_client
+        .expect_authorization_url()
+        .returning(|_, _, state, _, _| {
+            Ok(format!("https://idp.example.com/authorize?state={}", state))
+        });
+
+    let app = build_auth_app(
+        MockUserRepository::new(),
+        MockPasswordEncoder::new(),
+        MockTokenGenerator::new(),
+        AuthMocks {
+            identity_provider_repo,
+            oidc_login_state_repo,
+            oidc_client,
+            ..Default::default()
+        },
+    );
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri(format!("/api/v1/auth/oidc/{}/authorize", company_id))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::SEE_OTHER);
+    let location = response.headers()[header::LOCATION].to_str().unwrap();
+    assert!(location.starts_with("https://idp.example.com/authorize?state="));
+}
+
+#[tokio::test]
+async fn test_callback_with_unknown_state_is_unauthorized() {
+    let mut oidc_login_state_repo = MockOidcLoginStateRepository::new();
+    oidc_login_state_repo
+        .expect_get_by_state_hash()
+        .returning(|_| Ok(None));
+
+    let app = build_auth_app(
+        MockUserRepository::new(),
+        MockPasswordEncoder::new(),
+        MockTokenGenerator::new(),
+        AuthMocks {
+            oidc_login_state_repo,
+            ..Default::default()
+        },
+    );
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/api/v1/auth/oidc/callback")
+                .method("POST")
+                .header("Content-Type", "application/json")
+                .body(Body::from(
+                    serde_json::json!({ "code": "code", "state": "forged" }).to_string(),
+                ))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_get_identity_provider_hides_client_secret() {
+    let company = Company {
+        id: Uuid::new_v4(),
+        name: "Company".to_string(),
+        description: None,
+        parent_id: None,
+        two_factor_required_level: None,
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+    };
+    let company_id = company.id;
+    let supervisor = User {
+        id: Uuid::new_v4(),
+        username: "supervisor".to_string(),
+        email: None,
+        email_verified_at: None,
+        password_hash: "hashed".to_string(),
+        name: None,
+        surname: None,
+        role: Role {
+            id: 2,
+            name: "supervisor".to_string(),
+            level: 50,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        },
+        company: Some(company),
+        deactivated_at: None,
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+    };
+    let supervisor_id = supervisor.id;
+
+    let mut mock_token = MockTokenGenerator::new();
+    mock_token
+        .expect_validate()
+        .returning(move |_| Ok(serde_json::json!({ "sub": supervisor_id.to_string() })));
+
+    let mut mock_user_repo = MockUserRepository::new();
+    mock_user_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(supervisor.clone())));
+
+    let mut identity_provider_repo = MockIdentityProviderRepository::new();
+    identity_provider_repo
+        .expect_get_by_id()
+        .returning(move |id| Ok(Some(create_provider(id))));
+
+    let app = build_auth_app(
+        mock_user_repo,
+        MockPasswordEncoder::new(),
+        mock_token,
+        AuthMocks {
+            identity_provider_repo,
+            ..Default::default()
+        },
+    );
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri(format!("/api/v1/companies/{}/sso", company_id))
+                .header("Authorization", "Bearer valid_token")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(body["client_id"], "spl-backend");
+    assert_eq!(body["has_client_secret"], true);
+    assert!(body.get("client_secret").is_none());
+}
@@ -0,0 +1,65 @@
+use crate::common::build_company_app;
+use crate::common::factories::{create_company, create_user};
+use axum::body::{to_bytes, Body};
+use axum::http::{Request, StatusCode};
+use tower::ServiceExt;
+use uuid::Uuid;
+
+fn teams_request(method: &str, company_id: Uuid, body: Body) -> Request<Body> {
+    Request::builder()
+        .uri(format!("/api/v1/companies/{company_id}/teams"))
+        .method(method)
+        .header("Authorization", "Bearer valid_token")
+        .header("Content-Type", "application/json")
+        .body(body)
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_supervisor_cannot_create_team() {
+    let company = create_company();
+    let company_id = company.id;
+    let app = build_company_app(create_user("supervisor", 50, company.clone()), company);
+
+    let response = app
+        .oneshot(teams_request(
+            "POST",
+            company_id,
+            Body::from(r#"{"name":"North"}"#),
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_supervisor_lists_teams_of_own_company() {
+    let company = create_company();
+    let company_id = company.id;
+    let app = build_company_app(create_user("supervisor", 50, company.clone()), company);
+
+    let response = app
+        .oneshot(teams_request("GET", company_id, Body::empty()))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json, serde_json::json!([]));
+}
+
+#[tokio::test]
+async fn test_supervisor_cannot_list_teams_of_other_company() {
+    let company = create_company();
+    let app = build_company_app(create_user("supervisor", 50, company.clone()), company);
+
+    let response = app
+        .oneshot(teams_request("GET", Uuid::new_v4(), Body::empty()))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
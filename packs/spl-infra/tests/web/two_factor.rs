@@ -0,0 +1,292 @@
+use crate::common::build_auth_app;
+use crate::common::mocks::{
+    AuthMocks, MockPasswordEncoder, MockRecoveryCodeRepository, MockTokenGenerator,
+    MockTwoFactorChallengeRepository, MockTwoFactorProvider, MockTwoFactorRepository,
+    MockUserRepository,
+};
+use axum::body::{to_bytes, Body};
+use axum::http::{Request, StatusCode};
+use chrono::{Duration, Utc};
+use spl_domain::entities::auth::{TwoFactor, TwoFactorChallenge};
+use spl_domain::entities::company::Company;
+use spl_domain::entities::user::{Role, User};
+use tower::ServiceExt;
+use uuid::Uuid;
+
+fn create_user(role: &str, level: i16, two_factor_required_level: Option<i16>) -> User {
+    User {
+        id: Uuid::new_v4(),
+        username: "webuser".to_string(),
+        email: None,
+        email_verified_at: None,
+        password_hash: "hashed".to_string(),
+        name: None,
+        surname: None,
+        role: Role {
+            id: 2,
+            name: role.to_string(),
+            level,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        },
+        company: Some(Company {
+            id: Uuid::new_v4(),
+            name: "Company".to_string(),
+            description: None,
+            parent_id: None,
+            two_factor_required_level,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }),
+        deactivated_at: None,
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+    }
+}
+
+fn enabled_two_factor_repo() -> MockTwoFactorRepository {
+    let mut two_factor_repo = MockTwoFactorRepository::new();
+    two_factor_repo.expect_get_by_id().returning(|user_id| {
+        Ok(Some(TwoFactor {
+            user_id,
+            secret: "SECRET".to_string(),
+            enabled_at: Some(Utc::now()),
+            created_at: Utc::now(),
+        }))
+    });
+    two_factor_repo
+}
+
+fn authenticated(user: User) -> (MockUserRepository, MockTokenGenerator) {
+    let user_id = user.id;
+
+    let mut mock_token = MockTokenGenerator::new();
+    mock_token
+        .expect_validate()
+        .returning(move |_| Ok(serde_json::json!({ "sub": user_id.to_string() })));
+
+    let mut mock_user_repo = MockUserRepository::new();
+    mock_user_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(user.clone())));
+
+    (mock_user_repo, mock_token)
+}
+
+fn json_request(method: &str, uri: &str, payload: serde_json::Value) -> Request<Body> {
+    Request::builder()
+        .uri(uri)
+        .method(method)
+        .header("Content-Type", "application/json")
+        .header("Authorization", "Bearer valid_token")
+        .body(Body::from(payload.to_string()))
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_login_with_two_factor_returns_challenge() {
+    let user = create_user("supervisor", 50, None);
+
+    let mut mock_user_repo = MockUserRepository::new();
+    mock_user_repo
+        .expect_get_by_username_or_email_and_company()
+        .returning(move |_, _, _| Ok(Some(user.clone())));
+
+    let mut mock_encoder = MockPasswordEncoder::new();
+    mock_encoder.expect_verify().returning(|_, _| Ok(true));
+
+    let mut mock_token = MockTokenGenerator::new();
+    mock_token.expect_generate().never();
+
+    let mut two_factor_challenge_repo = MockTwoFactorChallengeRepository::new();
+    two_factor_challenge_repo
+        .expect_create()
+        .times(1)
+        .returning(Ok);
+
+    let app = build_auth_app(
+        mock_user_repo,
+        mock_encoder,
+        mock_token,
+        AuthMocks {
+            two_factor_repo: enabled_two_factor_repo(),
+            two_factor_challenge_repo,
+            ..Default::default()
+        },
+    );
+
+    let response = app
+        .oneshot(json_request(
+            "POST",
+            "/api/v1/auth/login",
+            serde_json::json!({ "username": "webuser", "password": "password123" }),
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::ACCEPTED);
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert!(body["challenge_token"].is_string());
+    assert_eq!(body["setup_required"], false);
+    assert!(body.get("token").is_none());
+}
+
+#[tokio::test]
+async fn test_verify_two_factor_returns_tokens() {
+    let user = create_user("supervisor", 50, None);
+    let user_id = user.id;
+
+    let mut mock_user_repo = MockUserRepository::new();
+    mock_user_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(user.clone())));
+
+    let mut mock_token = MockTokenGenerator::new();
+    mock_token
+        .expect_generate()
+        .returning(|_, _| Ok("jwt_token".to_string()));
+
+    let mut two_factor_challenge_repo = MockTwoFactorChallengeRepository::new();
+    two_factor_challenge_repo
+        .expect_get_by_token_hash()
+        .returning(move |token_hash| {
+            Ok(Some(TwoFactorChallenge {
+                id: Uuid::new_v4(),
+                user_id,
+                token_hash: token_hash.to_string(),
+                expires_at: Utc::now() + Duration::minutes(5),
+                used_at: None,
+                created_at: Utc::now(),
+            }))
+        });
+    two_factor_challenge_repo
+        .expect_mark_used()
+        .times(1)
+        .returning(|_| Ok(true));
+
+    let mut two_factor_provider = MockTwoFactorProvider::new();
+    two_factor_provider
+        .expect_verify()
+        .returning(|_, code| Ok(code == "123456"));
+
+    let app = build_auth_app(
+        mock_user_repo,
+        MockPasswordEncoder::new(),
+        mock_token,
+        AuthMocks {
+            two_factor_repo: enabled_two_factor_repo(),
+            two_factor_challenge_repo,
+            two_factor_provider,
+            ..Default::default()
+        },
+    );
+
+    let response = app
+        .oneshot(json_request(
+            "POST",
+            "/api/v1/auth/2fa/verify",
+            serde_json::json!({ "challenge_token": "challenge", "code": "123456" }),
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(body["token"], "jwt_token");
+}
+
+#[tokio::test]
+async fn test_get_two_factor_status() {
+    let (mock_user_repo, mock_token) = authenticated(create_user("supervisor", 50, Some(50)));
+
+    let mut recovery_code_repo = MockRecoveryCodeRepository::new();
+    recovery_code_repo
+        .expect_count_unused()
+        .returning(|_| Ok(7));
+
+    let app = build_auth_app(
+        mock_user_repo,
+        MockPasswordEncoder::new(),
+        mock_token,
+        AuthMocks {
+            two_factor_repo: enabled_two_factor_repo(),
+            recovery_code_repo,
+            ..Default::default()
+        },
+    );
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/api/v1/users/me/2fa")
+                .header("Authorization", "Bearer valid_token")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(body["enabled"], true);
+    assert_eq!(body["required"], true);
+    assert_eq!(body["recovery_codes_remaining"], 7);
+}
+
+#[tokio::test]
+async fn test_enroll_two_factor_forbidden_for_regular_user() {
+    let (mock_user_repo, mock_token) = authenticated(create_user("user", 10, None));
+
+    let app = build_auth_app(
+        mock_user_repo,
+        MockPasswordEncoder::new(),
+        mock_token,
+        AuthMocks::default(),
+    );
+
+    let response = app
+        .oneshot(json_request(
+            "POST",
+            "/api/v1/users/me/2fa",
+            serde_json::json!({}),
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_disable_mandatory_two_factor_is_rejected() {
+    let (mock_user_repo, mock_token) = authenticated(create_user("supervisor", 50, Some(50)));
+
+    let mut two_factor_repo = enabled_two_factor_repo();
+    two_factor_repo.expect_delete().never();
+
+    let app = build_auth_app(
+        mock_user_repo,
+        MockPasswordEncoder::new(),
+        mock_token,
+        AuthMocks {
+            two_factor_repo,
+            ..Default::default()
+        },
+    );
+
+    let response = app
+        .oneshot(json_request(
+            "DELETE",
+            "/api/v1/users/me/2fa",
+            serde_json::json!({ "code": "123456" }),
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
@@ -0,0 +1,88 @@
+use crate::common::build_company_app;
+use crate::common::factories::{create_company, create_user};
+use axum::body::{to_bytes, Body};
+use axum::http::{Request, StatusCode};
+use tower::ServiceExt;
+use uuid::Uuid;
+
+fn get_usage(company_id: Uuid, query: &str) -> Request<Body> {
+    Request::builder()
+        .uri(format!("/api/v1/companies/{company_id}/usage{query}"))
+        .method("GET")
+        .header("Authorization", "Bearer valid_token")
+        .body(Body::empty())
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_supervisor_reads_usage_of_month() {
+    let company = create_company();
+    let company_id = company.id;
+    let app = build_company_app(create_user("supervisor", 50, company.clone()), company);
+
+    let response = app
+        .oneshot(get_usage(company_id, "?month=2026-02"))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["month"], "2026-02");
+    assert_eq!(json["predictions"], 0);
+    assert_eq!(json["status"], "ok");
+    assert!(json["resets_at"]
+        .as_str()
+        .unwrap()
+        .starts_with("2026-03-01"));
+}
+
+#[tokio::test]
+async fn test_user_cannot_read_usage() {
+    let company = create_company();
+    let company_id = company.id;
+    let app = build_company_app(create_user("user", 10, company.clone()), company);
+
+    let response = app.oneshot(get_usage(company_id, "")).await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_usage_rejects_invalid_month() {
+    let company = create_company();
+    let company_id = company.id;
+    let app = build_company_app(create_user("supervisor", 50, company.clone()), company);
+
+    let response = app
+        .oneshot(get_usage(company_id, "?month=february"))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn test_supervisor_cannot_set_quota() {
+    let company = create_company();
+    let company_id = company.id;
+    let app = build_company_app(create_user("supervisor", 50, company.clone()), company);
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri(format!("/api/v1/companies/{company_id}/quota"))
+                .method("PUT")
+                .header("Authorization", "Bearer valid_token")
+                .header("Content-Type", "application/json")
+                .body(Body::from(
+                    serde_json::json!({ "predictions_per_month": 100 }).to_string(),
+                ))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
@@ -1,5 +1,5 @@
-use crate::common::build_app;
 use crate::common::mocks::*;
+use crate::common::{build_app, build_auth_app};
 use axum::http::{Request, StatusCode};
 use axum::body::Body;
 use mockall::predicate::*;
@@ -21,6 +21,7 @@ async fn test_me_endpoint_success() {
         id: user_id,
         username: "meuser".to_string(),
         email: Some("me@example.com".to_string()),
+        email_verified_at: None,
         password_hash: "hashed".to_string(),
         name: None,
         surname: None,
@@ -32,6 +33,7 @@ async fn test_me_endpoint_success() {
             updated_at: chrono::Utc::now(),
         },
         company: None,
+        deactivated_at: None,
         created_at: chrono::Utc::now(),
         updated_at: chrono::Utc::now(),
     };
@@ -81,3 +83,124 @@ async fn test_me_endpoint_success() {
     assert_eq!(body_json["id"], user_id.to_string());
 }
 
+
+fn create_user(id: Uuid, role: &str, level: i16) -> User {
+    User {
+        id,
+        username: role.to_string(),
+        email: None,
+        email_verified_at: None,
+        password_hash: "hashed".to_string(),
+        name: None,
+        surname: None,
+        role: Role {
+            id: level as i32,
+            name: role.to_string(),
+            level,
+            created_at: chrono::Utc::now(),
+            updated_at: chrono::Utc::now(),
+        },
+        company: None,
+        deactivated_at: None,
+        created_at: chrono::Utc::now(),
+        updated_at: chrono::Utc::now(),
+    }
+}
+
+#[tokio::test]
+async fn test_delete_user_deactivates_and_revokes_sessions() {
+    let admin = create_user(Uuid::new_v4(), "admin", 100);
+    let admin_id = admin.id;
+    let target = create_user(Uuid::new_v4(), "user", 10);
+    let target_id = target.id;
+
+    let mut mock_token = MockTokenGenerator::new();
+    mock_token
+        .expect_validate()
+        .returning(move |_| Ok(serde_json::json!({ "sub": admin_id.to_string() })));
+
+    let mut mock_user_repo = MockUserRepository::new();
+    mock_user_repo.expect_get_by_id().returning(move |id| {
+        if id == admin_id {
+            Ok(Some(admin.clone()))
+        } else {
+            Ok(Some(target.clone()))
+        }
+    });
+    mock_user_repo
+        .expect_update()
+        .withf(|user| user.deactivated_at.is_some())
+        .times(1)
+        .returning(Ok);
+    mock_user_repo.expect_delete().never();
+
+    let mut session_repo = MockSessionRepository::new();
+    session_repo
+        .expect_revoke_by_user_id()
+        .with(eq(target_id))
+        .times(1)
+        .returning(|_| Ok(1));
+
+    let app = build_auth_app(
+        mock_user_repo,
+        MockPasswordEncoder::new(),
+        mock_token,
+        AuthMocks {
+            session_repo,
+            ..Default::default()
+        },
+    );
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri(format!("/api/v1/users/{}", target_id))
+                .method("DELETE")
+                .header("Authorization", "Bearer valid_token")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_deactivated_user_token_is_rejected() {
+    let mut user = create_user(Uuid::new_v4(), "user", 10);
+    user.deactivated_at = Some(chrono::Utc::now());
+    let user_id = user.id;
+
+    let mut mock_token = MockTokenGenerator::new();
+    mock_token
+        .expect_validate()
+        .returning(move |_| Ok(serde_json::json!({ "sub": user_id.to_string() })));
+
+    let mut mock_user_repo = MockUserRepository::new();
+    mock_user_repo
+        .expect_get_by_id()
+        .returning(move |_| Ok(Some(user.clone())));
+
+    let app = build_app(
+        mock_user_repo,
+        MockRoleRepository::new(),
+        MockCompanyRepository::new(),
+        MockPasswordEncoder::new(),
+        mock_token,
+    );
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/api/v1/users/me")
+                .method("GET")
+                .header("Authorization", "Bearer valid_token")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
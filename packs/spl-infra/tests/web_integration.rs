@@ -2,10 +2,26 @@ mod common;
 mod web {
     mod health;
     mod login;
+    mod session;
+    mod password_reset;
+    mod email_verification;
+    mod impersonation;
+    mod lockout;
+    mod two_factor;
+    mod service_accounts;
+    mod sso;
+    mod invitations;
     mod register;
     mod companies;
+    mod company_settings;
+    mod usage;
+    mod offboardings;
+    mod teams;
+    mod memberships;
     mod plots;
     mod diagnostics;
+    mod prediction_batches;
+    mod inference_jobs;
     mod recommendation;
     mod user;
 }
@@ -0,0 +1,55 @@
+use spl_domain::ports::archive::{ArchiveReader, ArchiveWriter};
+use spl_infra::adapters::archive::zip::{ZipArchiveReader, ZipArchiveWriter};
+use spl_shared::error::AppError;
+
+fn zip(files: &[(&str, &[u8])]) -> Vec<u8> {
+    let mut archive = ZipArchiveWriter::new().create();
+    for (path, content) in files {
+        archive.add(path, content).unwrap();
+    }
+    archive.finish().unwrap().to_vec()
+}
+
+#[test]
+fn test_written_archives_are_read_back_in_order() {
+    let content = zip(&[("photos/a.jpg", b"first"), ("photos/b.jpg", b"second")]);
+    let reader = ZipArchiveReader::new();
+
+    assert!(reader.is_archive(&content));
+
+    let entries = reader.extract(&content, 1024).unwrap();
+    let paths: Vec<&str> = entries.iter().map(|e| e.path.as_str()).collect();
+    assert_eq!(paths, ["photos/a.jpg", "photos/b.jpg"]);
+    assert_eq!(entries[1].content, b"second");
+}
+
+#[test]
+fn test_files_added_by_archivers_are_skipped() {
+    let content = zip(&[
+        ("a.jpg", b"image"),
+        ("__MACOSX/._a.jpg", b"resource fork"),
+        ("photos/.DS_Store", b"finder"),
+    ]);
+
+    let entries = ZipArchiveReader::new().extract(&content, 1024).unwrap();
+
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].path, "a.jpg");
+}
+
+#[test]
+fn test_archives_larger_than_allowed_once_extracted_are_rejected() {
+    let content = zip(&[("a.jpg", b"0123456789"), ("b.jpg", b"0123456789")]);
+
+    let result = ZipArchiveReader::new().extract(&content, 15);
+
+    assert!(matches!(result, Err(AppError::ValidationError(_))));
+}
+
+#[test]
+fn test_images_are_not_archives() {
+    let reader = ZipArchiveReader::new();
+
+    assert!(!reader.is_archive(&[0xFF, 0xD8, 0xFF, 0xE0]));
+    assert!(!reader.is_archive(b""));
+}
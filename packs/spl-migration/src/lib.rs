@@ -9,6 +9,31 @@ mod m20260206_000006_create_feedback_tables;
 mod m20260207_000007_create_plots_table;
 mod m20260207_000008_create_images_table;
 mod m20260209_000009_seed_recommendations;
+mod m20260215_000010_create_sessions_tables;
+mod m20260216_000011_create_password_reset_tokens_table;
+mod m20260217_000012_create_login_attempts_table;
+mod m20260218_000013_create_two_factor_tables;
+mod m20260219_000014_create_service_accounts_tables;
+mod m20260220_000015_create_sso_tables;
+mod m20260221_000016_add_session_client_info;
+mod m20260222_000017_create_invitations_table;
+mod m20260223_000018_add_user_deactivated_at;
+mod m20260224_000019_create_permissions_tables;
+mod m20260225_000020_add_roles_manage_permission;
+mod m20260226_000021_create_password_policy_tables;
+mod m20260227_000022_add_email_verification;
+mod m20260228_000023_create_impersonation_tables;
+mod m20260301_000024_create_company_settings_table;
+mod m20260302_000025_create_usage_tables;
+mod m20260303_000026_create_company_offboardings_tables;
+mod m20260304_000027_create_teams_tables;
+mod m20260305_000028_create_company_memberships_table;
+mod m20260306_000029_add_company_parent;
+mod m20260307_000030_create_prediction_batches_tables;
+mod m20260308_000031_create_inference_jobs_table;
+mod m20260309_000032_add_prediction_model;
+mod m20260310_000033_add_prediction_company;
+mod m20260311_000034_add_prediction_batch_company;
 
 pub struct Migrator;
 
@@ -25,6 +50,31 @@ impl MigratorTrait for Migrator {
             Box::new(m20260207_000007_create_plots_table::Migration),
             Box::new(m20260207_000008_create_images_table::Migration),
             Box::new(m20260209_000009_seed_recommendations::Migration),
+            Box::new(m20260215_000010_create_sessions_tables::Migration),
+            Box::new(m20260216_000011_create_password_reset_tokens_table::Migration),
+            Box::new(m20260217_000012_create_login_attempts_table::Migration),
+            Box::new(m20260218_000013_create_two_factor_tables::Migration),
+            Box::new(m20260219_000014_create_service_accounts_tables::Migration),
+            Box::new(m20260220_000015_create_sso_tables::Migration),
+            Box::new(m20260221_000016_add_session_client_info::Migration),
+            Box::new(m20260222_000017_create_invitations_table::Migration),
+            Box::new(m20260223_000018_add_user_deactivated_at::Migration),
+            Box::new(m20260224_000019_create_permissions_tables::Migration),
+            Box::new(m20260225_000020_add_roles_manage_permission::Migration),
+            Box::new(m20260226_000021_create_password_policy_tables::Migration),
+            Box::new(m20260227_000022_add_email_verification::Migration),
+            Box::new(m20260228_000023_create_impersonation_tables::Migration),
+            Box::new(m20260301_000024_create_company_settings_table::Migration),
+            Box::new(m20260302_000025_create_usage_tables::Migration),
+            Box::new(m20260303_000026_create_company_offboardings_tables::Migration),
+            Box::new(m20260304_000027_create_teams_tables::Migration),
+            Box::new(m20260305_000028_create_company_memberships_table::Migration),
+            Box::new(m20260306_000029_add_company_parent::Migration),
+            Box::new(m20260307_000030_create_prediction_batches_tables::Migration),
+            Box::new(m20260308_000031_create_inference_jobs_table::Migration),
+            Box::new(m20260309_000032_add_prediction_model::Migration),
+            Box::new(m20260310_000033_add_prediction_company::Migration),
+            Box::new(m20260311_000034_add_prediction_batch_company::Migration),
         ]
     }
 }
@@ -0,0 +1,188 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+const PERMISSIONS: &[(&str, &str)] = &[
+    ("users:read", "List users"),
+    (
+        "users:manage",
+        "Create, update and deactivate users, their sessions and invitations",
+    ),
+    ("companies:manage", "Create, update and delete companies"),
+    ("plots:read", "View plots and assign predictions to them"),
+    ("plots:manage", "Create, update and delete plots"),
+    ("predictions:read", "View predictions and dashboards"),
+    (
+        "catalog:manage",
+        "Manage labels, mark types, recommendations and feedback statuses",
+    ),
+    (
+        "service_accounts:manage",
+        "Manage service accounts and their API keys",
+    ),
+    ("sso:manage", "Configure single sign-on"),
+];
+
+/// Grants reproducing the former role levels: (role, permission, scope)
+const GRANTS: &[(&str, &str, &str)] = &[
+    ("user", "plots:read", "company"),
+    ("user", "predictions:read", "own"),
+    ("supervisor", "users:read", "company"),
+    ("supervisor", "users:manage", "company"),
+    ("supervisor", "plots:read", "company"),
+    ("supervisor", "plots:manage", "company"),
+    ("supervisor", "predictions:read", "company"),
+    ("supervisor", "service_accounts:manage", "company"),
+    ("supervisor", "sso:manage", "company"),
+    ("admin", "users:read", "any"),
+    ("admin", "users:manage", "any"),
+    ("admin", "companies:manage", "any"),
+    ("admin", "plots:manage", "any"),
+    ("admin", "predictions:read", "any"),
+    ("admin", "catalog:manage", "any"),
+    ("admin", "service_accounts:manage", "any"),
+    ("admin", "sso:manage", "any"),
+];
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Permissions::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Permissions::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(Permissions::Name)
+                            .string()
+                            .not_null()
+                            .unique_key(),
+                    )
+                    .col(ColumnDef::new(Permissions::Description).text().null())
+                    .col(
+                        ColumnDef::new(Permissions::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(RolePermissions::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(RolePermissions::RoleId).integer().not_null())
+                    .col(
+                        ColumnDef::new(RolePermissions::PermissionId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(RolePermissions::Scope).string().not_null())
+                    .primary_key(
+                        Index::create()
+                            .col(RolePermissions::RoleId)
+                            .col(RolePermissions::PermissionId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-role_permissions-role_id")
+                            .from(RolePermissions::Table, RolePermissions::RoleId)
+                            .to(Roles::Table, Roles::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-role_permissions-permission_id")
+                            .from(RolePermissions::Table, RolePermissions::PermissionId)
+                            .to(Permissions::Table, Permissions::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        let mut insert = Query::insert();
+        insert
+            .into_table(Permissions::Table)
+            .columns([Permissions::Name, Permissions::Description]);
+        for (name, description) in PERMISSIONS {
+            insert.values_panic([(*name).into(), (*description).into()]);
+        }
+        manager.exec_stmt(insert).await?;
+
+        // Roles are matched by name, their ids depend on the database
+        for (role, permission, scope) in GRANTS {
+            let select = Query::select()
+                .column((Roles::Table, Roles::Id))
+                .column((Permissions::Table, Permissions::Id))
+                .expr(Expr::val(*scope))
+                .from(Roles::Table)
+                .from(Permissions::Table)
+                .and_where(Expr::col((Roles::Table, Roles::Name)).eq(*role))
+                .and_where(Expr::col((Permissions::Table, Permissions::Name)).eq(*permission))
+                .to_owned();
+
+            let insert = Query::insert()
+                .into_table(RolePermissions::Table)
+                .columns([
+                    RolePermissions::RoleId,
+                    RolePermissions::PermissionId,
+                    RolePermissions::Scope,
+                ])
+                .select_from(select)
+                .map_err(|e| DbErr::Custom(e.to_string()))?
+                .to_owned();
+
+            manager.exec_stmt(insert).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(RolePermissions::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(Permissions::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum Permissions {
+    Table,
+    Id,
+    Name,
+    Description,
+    CreatedAt,
+}
+
+#[derive(Iden)]
+enum RolePermissions {
+    Table,
+    RoleId,
+    PermissionId,
+    Scope,
+}
+
+#[derive(Iden)]
+enum Roles {
+    Table,
+    Id,
+    Name,
+}
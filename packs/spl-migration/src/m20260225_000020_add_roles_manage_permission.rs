@@ -0,0 +1,77 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+const PERMISSION: &str = "roles:manage";
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let insert = Query::insert()
+            .into_table(Permissions::Table)
+            .columns([Permissions::Name, Permissions::Description])
+            .values_panic([
+                PERMISSION.into(),
+                "Create, update and delete roles and their permissions".into(),
+            ])
+            .to_owned();
+        manager.exec_stmt(insert).await?;
+
+        let select = Query::select()
+            .column((Roles::Table, Roles::Id))
+            .column((Permissions::Table, Permissions::Id))
+            .expr(Expr::val("any"))
+            .from(Roles::Table)
+            .from(Permissions::Table)
+            .and_where(Expr::col((Roles::Table, Roles::Name)).eq("admin"))
+            .and_where(Expr::col((Permissions::Table, Permissions::Name)).eq(PERMISSION))
+            .to_owned();
+
+        let insert = Query::insert()
+            .into_table(RolePermissions::Table)
+            .columns([
+                RolePermissions::RoleId,
+                RolePermissions::PermissionId,
+                RolePermissions::Scope,
+            ])
+            .select_from(select)
+            .map_err(|e| DbErr::Custom(e.to_string()))?
+            .to_owned();
+
+        manager.exec_stmt(insert).await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Grants of the permission are removed by the foreign key cascade
+        let delete = Query::delete()
+            .from_table(Permissions::Table)
+            .and_where(Expr::col(Permissions::Name).eq(PERMISSION))
+            .to_owned();
+
+        manager.exec_stmt(delete).await
+    }
+}
+
+#[derive(Iden)]
+enum Permissions {
+    Table,
+    Id,
+    Name,
+    Description,
+}
+
+#[derive(Iden)]
+enum RolePermissions {
+    Table,
+    RoleId,
+    PermissionId,
+    Scope,
+}
+
+#[derive(Iden)]
+enum Roles {
+    Table,
+    Id,
+    Name,
+}
@@ -0,0 +1,244 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+const PERMISSION: &str = "users:impersonate";
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Impersonations::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Impersonations::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    // Kept as an audit trail once either user is deleted
+                    .col(ColumnDef::new(Impersonations::ActorId).uuid().null())
+                    .col(ColumnDef::new(Impersonations::SubjectId).uuid().null())
+                    .col(ColumnDef::new(Impersonations::Reason).text().not_null())
+                    .col(ColumnDef::new(Impersonations::IpAddress).string().null())
+                    .col(
+                        ColumnDef::new(Impersonations::StartedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(Impersonations::ExpiresAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(Impersonations::EndedAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-impersonations-actor_id")
+                            .from(Impersonations::Table, Impersonations::ActorId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-impersonations-subject_id")
+                            .from(Impersonations::Table, Impersonations::SubjectId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(Impersonations::Table)
+                    .name("idx_impersonations_actor_id")
+                    .col(Impersonations::ActorId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(Impersonations::Table)
+                    .name("idx_impersonations_subject_id")
+                    .col(Impersonations::SubjectId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(ImpersonationActions::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(ImpersonationActions::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(ImpersonationActions::ImpersonationId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ImpersonationActions::Method)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(ImpersonationActions::Path).text().not_null())
+                    .col(
+                        ColumnDef::new(ImpersonationActions::StatusCode)
+                            .small_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(ImpersonationActions::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-impersonation_actions-impersonation_id")
+                            .from(
+                                ImpersonationActions::Table,
+                                ImpersonationActions::ImpersonationId,
+                            )
+                            .to(Impersonations::Table, Impersonations::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(ImpersonationActions::Table)
+                    .name("idx_impersonation_actions_impersonation_id")
+                    .col(ImpersonationActions::ImpersonationId)
+                    .to_owned(),
+            )
+            .await?;
+
+        let insert = Query::insert()
+            .into_table(Permissions::Table)
+            .columns([Permissions::Name, Permissions::Description])
+            .values_panic([
+                PERMISSION.into(),
+                "Act as another user with lower privileges, every action is recorded".into(),
+            ])
+            .to_owned();
+        manager.exec_stmt(insert).await?;
+
+        let select = Query::select()
+            .column((Roles::Table, Roles::Id))
+            .column((Permissions::Table, Permissions::Id))
+            .expr(Expr::val("any"))
+            .from(Roles::Table)
+            .from(Permissions::Table)
+            .and_where(Expr::col((Roles::Table, Roles::Name)).eq("admin"))
+            .and_where(Expr::col((Permissions::Table, Permissions::Name)).eq(PERMISSION))
+            .to_owned();
+
+        let insert = Query::insert()
+            .into_table(RolePermissions::Table)
+            .columns([
+                RolePermissions::RoleId,
+                RolePermissions::PermissionId,
+                RolePermissions::Scope,
+            ])
+            .select_from(select)
+            .map_err(|e| DbErr::Custom(e.to_string()))?
+            .to_owned();
+
+        manager.exec_stmt(insert).await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Grants of the permission are removed by the foreign key cascade
+        let delete = Query::delete()
+            .from_table(Permissions::Table)
+            .and_where(Expr::col(Permissions::Name).eq(PERMISSION))
+            .to_owned();
+        manager.exec_stmt(delete).await?;
+
+        manager
+            .drop_table(Table::drop().table(ImpersonationActions::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(Impersonations::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum Impersonations {
+    Table,
+    Id,
+    ActorId,
+    SubjectId,
+    Reason,
+    IpAddress,
+    StartedAt,
+    ExpiresAt,
+    EndedAt,
+}
+
+#[derive(Iden)]
+enum ImpersonationActions {
+    Table,
+    Id,
+    ImpersonationId,
+    Method,
+    Path,
+    StatusCode,
+    CreatedAt,
+}
+
+#[derive(Iden)]
+enum Users {
+    Table,
+    Id,
+}
+
+#[derive(Iden)]
+enum Permissions {
+    Table,
+    Id,
+    Name,
+    Description,
+}
+
+#[derive(Iden)]
+enum RolePermissions {
+    Table,
+    RoleId,
+    PermissionId,
+    Scope,
+}
+
+#[derive(Iden)]
+enum Roles {
+    Table,
+    Id,
+    Name,
+}
@@ -0,0 +1,169 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+const PERMISSION: &str = "settings:manage";
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(CompanySettings::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(CompanySettings::CompanyId)
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(CompanySettings::Timezone).string().null())
+                    .col(
+                        ColumnDef::new(CompanySettings::DefaultLanguage)
+                            .string()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(CompanySettings::DataRetentionDays)
+                            .integer()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(CompanySettings::DefaultPageSize)
+                            .integer()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(CompanySettings::AllowedUploadFormats)
+                            .string()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(CompanySettings::AlertSeverityThreshold)
+                            .float()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(CompanySettings::AlertConfidenceThreshold)
+                            .float()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(CompanySettings::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(CompanySettings::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-company_settings-company_id")
+                            .from(CompanySettings::Table, CompanySettings::CompanyId)
+                            .to(Companies::Table, Companies::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        let insert = Query::insert()
+            .into_table(Permissions::Table)
+            .columns([Permissions::Name, Permissions::Description])
+            .values_panic([PERMISSION.into(), "Change the settings of a company".into()])
+            .to_owned();
+        manager.exec_stmt(insert).await?;
+
+        // Supervisors manage their own company, admins every company
+        for (role, scope) in [("supervisor", "company"), ("admin", "any")] {
+            let select = Query::select()
+                .column((Roles::Table, Roles::Id))
+                .column((Permissions::Table, Permissions::Id))
+                .expr(Expr::val(scope))
+                .from(Roles::Table)
+                .from(Permissions::Table)
+                .and_where(Expr::col((Roles::Table, Roles::Name)).eq(role))
+                .and_where(Expr::col((Permissions::Table, Permissions::Name)).eq(PERMISSION))
+                .to_owned();
+
+            let insert = Query::insert()
+                .into_table(RolePermissions::Table)
+                .columns([
+                    RolePermissions::RoleId,
+                    RolePermissions::PermissionId,
+                    RolePermissions::Scope,
+                ])
+                .select_from(select)
+                .map_err(|e| DbErr::Custom(e.to_string()))?
+                .to_owned();
+
+            manager.exec_stmt(insert).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Grants of the permission are removed by the foreign key cascade
+        let delete = Query::delete()
+            .from_table(Permissions::Table)
+            .and_where(Expr::col(Permissions::Name).eq(PERMISSION))
+            .to_owned();
+        manager.exec_stmt(delete).await?;
+
+        manager
+            .drop_table(Table::drop().table(CompanySettings::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum CompanySettings {
+    Table,
+    CompanyId,
+    Timezone,
+    DefaultLanguage,
+    DataRetentionDays,
+    DefaultPageSize,
+    AllowedUploadFormats,
+    AlertSeverityThreshold,
+    AlertConfidenceThreshold,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(Iden)]
+enum Companies {
+    Table,
+    Id,
+}
+
+#[derive(Iden)]
+enum Permissions {
+    Table,
+    Id,
+    Name,
+    Description,
+}
+
+#[derive(Iden)]
+enum RolePermissions {
+    Table,
+    RoleId,
+    PermissionId,
+    Scope,
+}
+
+#[derive(Iden)]
+enum Roles {
+    Table,
+    Id,
+    Name,
+}
@@ -0,0 +1,244 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+const PERMISSION: &str = "usage:read";
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(UsageCounters::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(UsageCounters::UserId).uuid().not_null())
+                    .col(ColumnDef::new(UsageCounters::Period).date().not_null())
+                    .col(ColumnDef::new(UsageCounters::CompanyId).uuid().null())
+                    .col(
+                        ColumnDef::new(UsageCounters::Predictions)
+                            .big_integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(UsageCounters::StoredBytes)
+                            .big_integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(UsageCounters::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .primary_key(
+                        Index::create()
+                            .col(UsageCounters::UserId)
+                            .col(UsageCounters::Period),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-usage_counters-user_id")
+                            .from(UsageCounters::Table, UsageCounters::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-usage_counters-company_id")
+                            .from(UsageCounters::Table, UsageCounters::CompanyId)
+                            .to(Companies::Table, Companies::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-usage_counters-company_id-period")
+                    .table(UsageCounters::Table)
+                    .col(UsageCounters::CompanyId)
+                    .col(UsageCounters::Period)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(CompanyQuotas::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(CompanyQuotas::CompanyId)
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(CompanyQuotas::PredictionsPerMonth)
+                            .big_integer()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(CompanyQuotas::PredictionsPerUserPerMonth)
+                            .big_integer()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(CompanyQuotas::StoredBytesPerMonth)
+                            .big_integer()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(CompanyQuotas::WarningRatio)
+                            .float()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(CompanyQuotas::Enforcement)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(CompanyQuotas::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(CompanyQuotas::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-company_quotas-company_id")
+                            .from(CompanyQuotas::Table, CompanyQuotas::CompanyId)
+                            .to(Companies::Table, Companies::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        let insert = Query::insert()
+            .into_table(Permissions::Table)
+            .columns([Permissions::Name, Permissions::Description])
+            .values_panic([PERMISSION.into(), "Read the usage of a company".into()])
+            .to_owned();
+        manager.exec_stmt(insert).await?;
+
+        // Supervisors read their own company, admins every company
+        for (role, scope) in [("supervisor", "company"), ("admin", "any")] {
+            let select = Query::select()
+                .column((Roles::Table, Roles::Id))
+                .column((Permissions::Table, Permissions::Id))
+                .expr(Expr::val(scope))
+                .from(Roles::Table)
+                .from(Permissions::Table)
+                .and_where(Expr::col((Roles::Table, Roles::Name)).eq(role))
+                .and_where(Expr::col((Permissions::Table, Permissions::Name)).eq(PERMISSION))
+                .to_owned();
+
+            let insert = Query::insert()
+                .into_table(RolePermissions::Table)
+                .columns([
+                    RolePermissions::RoleId,
+                    RolePermissions::PermissionId,
+                    RolePermissions::Scope,
+                ])
+                .select_from(select)
+                .map_err(|e| DbErr::Custom(e.to_string()))?
+                .to_owned();
+
+            manager.exec_stmt(insert).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Grants of the permission are removed by the foreign key cascade
+        let delete = Query::delete()
+            .from_table(Permissions::Table)
+            .and_where(Expr::col(Permissions::Name).eq(PERMISSION))
+            .to_owned();
+        manager.exec_stmt(delete).await?;
+
+        manager
+            .drop_table(Table::drop().table(CompanyQuotas::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(UsageCounters::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum UsageCounters {
+    Table,
+    UserId,
+    Period,
+    CompanyId,
+    Predictions,
+    StoredBytes,
+    UpdatedAt,
+}
+
+#[derive(Iden)]
+enum CompanyQuotas {
+    Table,
+    CompanyId,
+    PredictionsPerMonth,
+    PredictionsPerUserPerMonth,
+    StoredBytesPerMonth,
+    WarningRatio,
+    Enforcement,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(Iden)]
+enum Users {
+    Table,
+    Id,
+}
+
+#[derive(Iden)]
+enum Companies {
+    Table,
+    Id,
+}
+
+#[derive(Iden)]
+enum Permissions {
+    Table,
+    Id,
+    Name,
+    Description,
+}
+
+#[derive(Iden)]
+enum RolePermissions {
+    Table,
+    RoleId,
+    PermissionId,
+    Scope,
+}
+
+#[derive(Iden)]
+enum Roles {
+    Table,
+    Id,
+    Name,
+}
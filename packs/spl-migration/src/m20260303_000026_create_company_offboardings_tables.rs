@@ -0,0 +1,186 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // No foreign keys to the company nor the requester: the audit trail has to
+        // outlive both
+        manager
+            .create_table(
+                Table::create()
+                    .table(CompanyOffboardings::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(CompanyOffboardings::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(CompanyOffboardings::CompanyId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(CompanyOffboardings::CompanyName)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(CompanyOffboardings::RequestedBy)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(CompanyOffboardings::Status)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(CompanyOffboardings::ExportPath)
+                            .string()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(CompanyOffboardings::ExportSize)
+                            .big_integer()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(CompanyOffboardings::UsersTotal)
+                            .big_integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(CompanyOffboardings::UsersDeleted)
+                            .big_integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(ColumnDef::new(CompanyOffboardings::Error).text().null())
+                    .col(
+                        ColumnDef::new(CompanyOffboardings::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(CompanyOffboardings::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(CompanyOffboardings::CompletedAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-company_offboardings-company_id")
+                    .table(CompanyOffboardings::Table)
+                    .col(CompanyOffboardings::CompanyId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(OffboardingEvents::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(OffboardingEvents::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(OffboardingEvents::OffboardingId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(OffboardingEvents::ActorId).uuid().null())
+                    .col(
+                        ColumnDef::new(OffboardingEvents::Action)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(OffboardingEvents::Detail).text().null())
+                    .col(
+                        ColumnDef::new(OffboardingEvents::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-offboarding_events-offboarding_id")
+                            .from(OffboardingEvents::Table, OffboardingEvents::OffboardingId)
+                            .to(CompanyOffboardings::Table, CompanyOffboardings::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-offboarding_events-offboarding_id")
+                    .table(OffboardingEvents::Table)
+                    .col(OffboardingEvents::OffboardingId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(OffboardingEvents::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(CompanyOffboardings::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum CompanyOffboardings {
+    Table,
+    Id,
+    CompanyId,
+    CompanyName,
+    RequestedBy,
+    Status,
+    ExportPath,
+    ExportSize,
+    UsersTotal,
+    UsersDeleted,
+    Error,
+    CreatedAt,
+    UpdatedAt,
+    CompletedAt,
+}
+
+#[derive(Iden)]
+enum OffboardingEvents {
+    Table,
+    Id,
+    OffboardingId,
+    ActorId,
+    Action,
+    Detail,
+    CreatedAt,
+}
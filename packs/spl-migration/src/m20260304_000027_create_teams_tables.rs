@@ -0,0 +1,299 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+const PERMISSION: &str = "teams:manage";
+
+/// Permissions supervisors now read through their teams
+const TEAM_SCOPED: &[&str] = &["users:read", "plots:read", "predictions:read"];
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Teams::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(Teams::Id).uuid().not_null().primary_key())
+                    .col(ColumnDef::new(Teams::CompanyId).uuid().not_null())
+                    .col(ColumnDef::new(Teams::Name).string().not_null())
+                    .col(ColumnDef::new(Teams::Description).text().null())
+                    .col(
+                        ColumnDef::new(Teams::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(Teams::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-teams-company_id")
+                            .from(Teams::Table, Teams::CompanyId)
+                            .to(Companies::Table, Companies::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-teams-company_id-name")
+                    .table(Teams::Table)
+                    .col(Teams::CompanyId)
+                    .col(Teams::Name)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(TeamMembers::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(TeamMembers::TeamId).uuid().not_null())
+                    .col(ColumnDef::new(TeamMembers::UserId).uuid().not_null())
+                    .col(ColumnDef::new(TeamMembers::Role).string().not_null())
+                    .col(
+                        ColumnDef::new(TeamMembers::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .primary_key(
+                        Index::create()
+                            .col(TeamMembers::TeamId)
+                            .col(TeamMembers::UserId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-team_members-team_id")
+                            .from(TeamMembers::Table, TeamMembers::TeamId)
+                            .to(Teams::Table, Teams::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-team_members-user_id")
+                            .from(TeamMembers::Table, TeamMembers::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-team_members-user_id")
+                    .table(TeamMembers::Table)
+                    .col(TeamMembers::UserId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(TeamPlots::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(TeamPlots::TeamId).uuid().not_null())
+                    .col(ColumnDef::new(TeamPlots::PlotId).uuid().not_null())
+                    .primary_key(
+                        Index::create()
+                            .col(TeamPlots::TeamId)
+                            .col(TeamPlots::PlotId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-team_plots-team_id")
+                            .from(TeamPlots::Table, TeamPlots::TeamId)
+                            .to(Teams::Table, Teams::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-team_plots-plot_id")
+                            .from(TeamPlots::Table, TeamPlots::PlotId)
+                            .to(Plots::Table, Plots::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        let insert = Query::insert()
+            .into_table(Permissions::Table)
+            .columns([Permissions::Name, Permissions::Description])
+            .values_panic([
+                PERMISSION.into(),
+                "Create teams and choose their members and plots".into(),
+            ])
+            .to_owned();
+        manager.exec_stmt(insert).await?;
+
+        let select = Query::select()
+            .column((Roles::Table, Roles::Id))
+            .column((Permissions::Table, Permissions::Id))
+            .expr(Expr::val("any"))
+            .from(Roles::Table)
+            .from(Permissions::Table)
+            .and_where(Expr::col((Roles::Table, Roles::Name)).eq("admin"))
+            .and_where(Expr::col((Permissions::Table, Permissions::Name)).eq(PERMISSION))
+            .to_owned();
+
+        let insert = Query::insert()
+            .into_table(RolePermissions::Table)
+            .columns([
+                RolePermissions::RoleId,
+                RolePermissions::PermissionId,
+                RolePermissions::Scope,
+            ])
+            .select_from(select)
+            .map_err(|e| DbErr::Custom(e.to_string()))?
+            .to_owned();
+        manager.exec_stmt(insert).await?;
+
+        // Supervisors keep seeing the whole company until it creates teams
+        set_supervisor_scope(manager, "company", "team").await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        set_supervisor_scope(manager, "team", "company").await?;
+
+        // Grants of the permission are removed by the foreign key cascade
+        let delete = Query::delete()
+            .from_table(Permissions::Table)
+            .and_where(Expr::col(Permissions::Name).eq(PERMISSION))
+            .to_owned();
+        manager.exec_stmt(delete).await?;
+
+        manager
+            .drop_table(Table::drop().table(TeamPlots::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(TeamMembers::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(Teams::Table).to_owned())
+            .await
+    }
+}
+
+/// Changes the scope of the supervisor grants of the permissions read through teams
+async fn set_supervisor_scope(
+    manager: &SchemaManager<'_>,
+    from: &str,
+    to: &str,
+) -> Result<(), DbErr> {
+    let update = Query::update()
+        .table(RolePermissions::Table)
+        .value(RolePermissions::Scope, to)
+        .and_where(Expr::col(RolePermissions::Scope).eq(from))
+        .and_where(
+            Expr::col(RolePermissions::RoleId).in_subquery(
+                Query::select()
+                    .column(Roles::Id)
+                    .from(Roles::Table)
+                    .and_where(Expr::col(Roles::Name).eq("supervisor"))
+                    .to_owned(),
+            ),
+        )
+        .and_where(
+            Expr::col(RolePermissions::PermissionId).in_subquery(
+                Query::select()
+                    .column(Permissions::Id)
+                    .from(Permissions::Table)
+                    .and_where(Expr::col(Permissions::Name).is_in(TEAM_SCOPED.iter().copied()))
+                    .to_owned(),
+            ),
+        )
+        .to_owned();
+
+    manager.exec_stmt(update).await
+}
+
+#[derive(Iden)]
+enum Teams {
+    Table,
+    Id,
+    CompanyId,
+    Name,
+    Description,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(Iden)]
+enum TeamMembers {
+    Table,
+    TeamId,
+    UserId,
+    Role,
+    CreatedAt,
+}
+
+#[derive(Iden)]
+enum TeamPlots {
+    Table,
+    TeamId,
+    PlotId,
+}
+
+#[derive(Iden)]
+enum Companies {
+    Table,
+    Id,
+}
+
+#[derive(Iden)]
+enum Users {
+    Table,
+    Id,
+}
+
+#[derive(Iden)]
+enum Plots {
+    Table,
+    Id,
+}
+
+#[derive(Iden)]
+enum Permissions {
+    Table,
+    Id,
+    Name,
+    Description,
+}
+
+#[derive(Iden)]
+enum RolePermissions {
+    Table,
+    RoleId,
+    PermissionId,
+    Scope,
+}
+
+#[derive(Iden)]
+enum Roles {
+    Table,
+    Id,
+    Name,
+}
@@ -0,0 +1,133 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(CompanyMemberships::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(CompanyMemberships::UserId).uuid().not_null())
+                    .col(
+                        ColumnDef::new(CompanyMemberships::CompanyId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(CompanyMemberships::RoleId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(CompanyMemberships::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .primary_key(
+                        Index::create()
+                            .col(CompanyMemberships::UserId)
+                            .col(CompanyMemberships::CompanyId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-company_memberships-user_id")
+                            .from(CompanyMemberships::Table, CompanyMemberships::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-company_memberships-company_id")
+                            .from(CompanyMemberships::Table, CompanyMemberships::CompanyId)
+                            .to(Companies::Table, Companies::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    // Roles in use cannot be deleted
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-company_memberships-role_id")
+                            .from(CompanyMemberships::Table, CompanyMemberships::RoleId)
+                            .to(Roles::Table, Roles::Id)
+                            .on_delete(ForeignKeyAction::Restrict)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-company_memberships-company_id")
+                    .table(CompanyMemberships::Table)
+                    .col(CompanyMemberships::CompanyId)
+                    .to_owned(),
+            )
+            .await?;
+
+        // Company the session acts in, the one of the user when null
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Sessions::Table)
+                    .add_column(ColumnDef::new(Sessions::ActiveCompanyId).uuid().null())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Sessions::Table)
+                    .drop_column(Sessions::ActiveCompanyId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(CompanyMemberships::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum CompanyMemberships {
+    Table,
+    UserId,
+    CompanyId,
+    RoleId,
+    CreatedAt,
+}
+
+#[derive(Iden)]
+enum Sessions {
+    Table,
+    ActiveCompanyId,
+}
+
+#[derive(Iden)]
+enum Users {
+    Table,
+    Id,
+}
+
+#[derive(Iden)]
+enum Companies {
+    Table,
+    Id,
+}
+
+#[derive(Iden)]
+enum Roles {
+    Table,
+    Id,
+}
@@ -0,0 +1,67 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Member farms of a cooperative point to it, deleting the cooperative detaches them
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Companies::Table)
+                    .add_column(ColumnDef::new(Companies::ParentId).uuid().null())
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk-companies-parent_id")
+                            .from_tbl(Companies::Table)
+                            .from_col(Companies::ParentId)
+                            .to_tbl(Companies::Table)
+                            .to_col(Companies::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-companies-parent_id")
+                    .table(Companies::Table)
+                    .col(Companies::ParentId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx-companies-parent_id")
+                    .table(Companies::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Companies::Table)
+                    .drop_foreign_key(Alias::new("fk-companies-parent_id"))
+                    .drop_column(Companies::ParentId)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum Companies {
+    Table,
+    Id,
+    ParentId,
+}
@@ -0,0 +1,222 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(PredictionBatches::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(PredictionBatches::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(PredictionBatches::UserId).uuid().not_null())
+                    .col(
+                        ColumnDef::new(PredictionBatches::Status)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(PredictionBatches::ItemsTotal)
+                            .big_integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(PredictionBatches::ItemsCompleted)
+                            .big_integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(PredictionBatches::ItemsFailed)
+                            .big_integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(PredictionBatches::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(PredictionBatches::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(PredictionBatches::CompletedAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-prediction_batches-user_id")
+                            .from(PredictionBatches::Table, PredictionBatches::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-prediction_batches-user_id")
+                    .table(PredictionBatches::Table)
+                    .col(PredictionBatches::UserId)
+                    .to_owned(),
+            )
+            .await?;
+
+        // Predictions outlive the batch they came from, and the other way around
+        manager
+            .create_table(
+                Table::create()
+                    .table(PredictionBatchItems::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(PredictionBatchItems::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(PredictionBatchItems::BatchId)
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(PredictionBatchItems::Position)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(PredictionBatchItems::Filename)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(PredictionBatchItems::UploadPath)
+                            .string()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(PredictionBatchItems::Status)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(PredictionBatchItems::PredictionId)
+                            .uuid()
+                            .null(),
+                    )
+                    .col(ColumnDef::new(PredictionBatchItems::Error).text().null())
+                    .col(
+                        ColumnDef::new(PredictionBatchItems::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(PredictionBatchItems::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-prediction_batch_items-batch_id")
+                            .from(PredictionBatchItems::Table, PredictionBatchItems::BatchId)
+                            .to(PredictionBatches::Table, PredictionBatches::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-prediction_batch_items-prediction_id")
+                            .from(
+                                PredictionBatchItems::Table,
+                                PredictionBatchItems::PredictionId,
+                            )
+                            .to(Predictions::Table, Predictions::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-prediction_batch_items-batch_id")
+                    .table(PredictionBatchItems::Table)
+                    .col(PredictionBatchItems::BatchId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(PredictionBatchItems::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(PredictionBatches::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum PredictionBatches {
+    Table,
+    Id,
+    UserId,
+    Status,
+    ItemsTotal,
+    ItemsCompleted,
+    ItemsFailed,
+    CreatedAt,
+    UpdatedAt,
+    CompletedAt,
+}
+
+#[derive(Iden)]
+enum PredictionBatchItems {
+    Table,
+    Id,
+    BatchId,
+    Position,
+    Filename,
+    UploadPath,
+    Status,
+    PredictionId,
+    Error,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(Iden)]
+enum Users {
+    Table,
+    Id,
+}
+
+#[derive(Iden)]
+enum Predictions {
+    Table,
+    Id,
+}
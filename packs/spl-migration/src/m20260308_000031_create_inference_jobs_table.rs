@@ -0,0 +1,154 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(InferenceJobs::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(InferenceJobs::Id)
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(InferenceJobs::UserId).uuid().not_null())
+                    .col(ColumnDef::new(InferenceJobs::BatchItemId).uuid().null())
+                    .col(ColumnDef::new(InferenceJobs::Filename).string().not_null())
+                    .col(ColumnDef::new(InferenceJobs::UploadPath).string().null())
+                    .col(ColumnDef::new(InferenceJobs::Status).string().not_null())
+                    .col(
+                        ColumnDef::new(InferenceJobs::Attempts)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(ColumnDef::new(InferenceJobs::PredictionId).uuid().null())
+                    .col(ColumnDef::new(InferenceJobs::Error).text().null())
+                    .col(
+                        ColumnDef::new(InferenceJobs::RunAfter)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(InferenceJobs::LockedUntil)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(InferenceJobs::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(InferenceJobs::UpdatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(InferenceJobs::CompletedAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-inference_jobs-user_id")
+                            .from(InferenceJobs::Table, InferenceJobs::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-inference_jobs-batch_item_id")
+                            .from(InferenceJobs::Table, InferenceJobs::BatchItemId)
+                            .to(PredictionBatchItems::Table, PredictionBatchItems::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-inference_jobs-prediction_id")
+                            .from(InferenceJobs::Table, InferenceJobs::PredictionId)
+                            .to(Predictions::Table, Predictions::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Workers look for the oldest job due of a status
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-inference_jobs-status-run_after")
+                    .table(InferenceJobs::Table)
+                    .col(InferenceJobs::Status)
+                    .col(InferenceJobs::RunAfter)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-inference_jobs-user_id")
+                    .table(InferenceJobs::Table)
+                    .col(InferenceJobs::UserId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(InferenceJobs::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum InferenceJobs {
+    Table,
+    Id,
+    UserId,
+    BatchItemId,
+    Filename,
+    UploadPath,
+    Status,
+    Attempts,
+    PredictionId,
+    Error,
+    RunAfter,
+    LockedUntil,
+    CreatedAt,
+    UpdatedAt,
+    CompletedAt,
+}
+
+#[derive(Iden)]
+enum Users {
+    Table,
+    Id,
+}
+
+#[derive(Iden)]
+enum PredictionBatchItems {
+    Table,
+    Id,
+}
+
+#[derive(Iden)]
+enum Predictions {
+    Table,
+    Id,
+}
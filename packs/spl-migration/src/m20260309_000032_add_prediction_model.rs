@@ -0,0 +1,78 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Predictions made before the model was recorded keep them empty
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Predictions::Table)
+                    .add_column(ColumnDef::new(Predictions::ModelName).string().null())
+                    .add_column(ColumnDef::new(Predictions::ModelVersion).string().null())
+                    .add_column(ColumnDef::new(Predictions::ModelProvider).string().null())
+                    .add_column(ColumnDef::new(Predictions::ModelImageSize).integer().null())
+                    .add_column(
+                        ColumnDef::new(Predictions::ModelLeafThreshold)
+                            .float()
+                            .null(),
+                    )
+                    .add_column(
+                        ColumnDef::new(Predictions::ModelLesionThreshold)
+                            .float()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-predictions-model_version")
+                    .table(Predictions::Table)
+                    .col(Predictions::ModelVersion)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx-predictions-model_version")
+                    .table(Predictions::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Predictions::Table)
+                    .drop_column(Predictions::ModelName)
+                    .drop_column(Predictions::ModelVersion)
+                    .drop_column(Predictions::ModelProvider)
+                    .drop_column(Predictions::ModelImageSize)
+                    .drop_column(Predictions::ModelLeafThreshold)
+                    .drop_column(Predictions::ModelLesionThreshold)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum Predictions {
+    Table,
+    ModelName,
+    ModelVersion,
+    ModelProvider,
+    ModelImageSize,
+    ModelLeafThreshold,
+    ModelLesionThreshold,
+}
@@ -0,0 +1,157 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Predictions and jobs belong to the company the user was acting in, which is
+        // not their own under a membership. They go with that company when it is deleted.
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Predictions::Table)
+                    .add_column(ColumnDef::new(Predictions::CompanyId).uuid().null())
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk-predictions-company_id")
+                            .from_tbl(Predictions::Table)
+                            .from_col(Predictions::CompanyId)
+                            .to_tbl(Companies::Table)
+                            .to_col(Companies::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(InferenceJobs::Table)
+                    .add_column(ColumnDef::new(InferenceJobs::CompanyId).uuid().null())
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk-inference_jobs-company_id")
+                            .from_tbl(InferenceJobs::Table)
+                            .from_col(InferenceJobs::CompanyId)
+                            .to_tbl(Companies::Table)
+                            .to_col(Companies::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Rows made before memberships were scoped belong to the company of their user
+        manager
+            .exec_stmt(
+                Query::update()
+                    .table(Predictions::Table)
+                    .value(
+                        Predictions::CompanyId,
+                        company_of_user((Predictions::Table, Predictions::UserId)),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .exec_stmt(
+                Query::update()
+                    .table(InferenceJobs::Table)
+                    .value(
+                        InferenceJobs::CompanyId,
+                        company_of_user((InferenceJobs::Table, InferenceJobs::UserId)),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-predictions-company_id")
+                    .table(Predictions::Table)
+                    .col(Predictions::CompanyId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx-predictions-company_id")
+                    .table(Predictions::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(InferenceJobs::Table)
+                    .drop_foreign_key(Alias::new("fk-inference_jobs-company_id"))
+                    .drop_column(InferenceJobs::CompanyId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Predictions::Table)
+                    .drop_foreign_key(Alias::new("fk-predictions-company_id"))
+                    .drop_column(Predictions::CompanyId)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+/// Company of the user the column points to
+fn company_of_user<C: IntoColumnRef>(user_id: C) -> SimpleExpr {
+    SimpleExpr::SubQuery(
+        None,
+        Box::new(
+            Query::select()
+                .column((Users::Table, Users::CompanyId))
+                .from(Users::Table)
+                .and_where(Expr::col((Users::Table, Users::Id)).equals(user_id))
+                .to_owned()
+                .into_sub_query_statement(),
+        ),
+    )
+}
+
+#[derive(Iden)]
+enum Predictions {
+    Table,
+    UserId,
+    CompanyId,
+}
+
+#[derive(Iden)]
+enum InferenceJobs {
+    Table,
+    UserId,
+    CompanyId,
+}
+
+#[derive(Iden)]
+enum Users {
+    Table,
+    Id,
+    CompanyId,
+}
+
+#[derive(Iden)]
+enum Companies {
+    Table,
+    Id,
+}
@@ -0,0 +1,89 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Batches belong to the company the user was acting in, like their jobs
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(PredictionBatches::Table)
+                    .add_column(ColumnDef::new(PredictionBatches::CompanyId).uuid().null())
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk-prediction_batches-company_id")
+                            .from_tbl(PredictionBatches::Table)
+                            .from_col(PredictionBatches::CompanyId)
+                            .to_tbl(Companies::Table)
+                            .to_col(Companies::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::NoAction),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Batches made before memberships were scoped belong to the company of their user
+        manager
+            .exec_stmt(
+                Query::update()
+                    .table(PredictionBatches::Table)
+                    .value(
+                        PredictionBatches::CompanyId,
+                        SimpleExpr::SubQuery(
+                            None,
+                            Box::new(
+                                Query::select()
+                                    .column((Users::Table, Users::CompanyId))
+                                    .from(Users::Table)
+                                    .and_where(Expr::col((Users::Table, Users::Id)).equals((
+                                        PredictionBatches::Table,
+                                        PredictionBatches::UserId,
+                                    )))
+                                    .to_owned()
+                                    .into_sub_query_statement(),
+                            ),
+                        ),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(PredictionBatches::Table)
+                    .drop_foreign_key(Alias::new("fk-prediction_batches-company_id"))
+                    .drop_column(PredictionBatches::CompanyId)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum PredictionBatches {
+    Table,
+    UserId,
+    CompanyId,
+}
+
+#[derive(Iden)]
+enum Users {
+    Table,
+    Id,
+    CompanyId,
+}
+
+#[derive(Iden)]
+enum Companies {
+    Table,
+    Id,
+}
@@ -1,17 +1,22 @@
 use crate::setup::database::initialize_database;
 use crate::setup::integrations;
-use crate::setup::integrations::{initialize_model_client, initialize_storage_client};
+use crate::setup::integrations::{
+    initialize_mailer, initialize_model_client, initialize_storage_client,
+};
+use crate::setup::login_lockout::initialize_login_attempt_store;
 use crate::setup::rate_limiting::initialize_rate_limiting;
 use crate::setup::redis::initialize_redis;
 use crate::setup::repositories::{initialize_adapters, initialize_repositories};
-use crate::setup::seed::{load_role_cache, seed_admin_user};
+use crate::setup::seed::seed_admin_user;
 use crate::setup::services::initialize_services;
+use crate::setup::user_cache::initialize_user_cache;
 use anyhow::Result;
 use spl_infra::adapters::web::{router, state::AppState};
 use spl_shared::config::AppConfig;
 use spl_shared::telemetry::init_telemetry;
 use std::net::SocketAddr;
 use std::sync::Arc;
+use std::time::Duration;
 use tracing::info;
 
 mod setup;
@@ -36,8 +41,8 @@ async fn main() -> Result<()> {
     let db = initialize_database(&config.database).await?;
 
     // 5. Initialize Repositories & Adapters
-    let repos = initialize_repositories(db);
-    let adapters = initialize_adapters(config.clone());
+    let repos = initialize_repositories(db.clone());
+    let adapters = initialize_adapters(config.clone())?;
 
     // 5.1 Seed Admin User
     if let Some(admin_config) = &config.admin {
@@ -54,6 +59,7 @@ async fn main() -> Result<()> {
     info!("Initializing integration clients...");
     let model_client = initialize_model_client(&config.integrations).await?;
     let storage_client = initialize_storage_client(&config.integrations).await?;
+    let mailer = initialize_mailer(&config.integrations).await?;
 
     // 6.1 Health Checks
     integrations::health_checks(&model_client, &storage_client).await?;
@@ -61,38 +67,72 @@ async fn main() -> Result<()> {
     // 6.2 Initialize Redis (shared infrastructure for rate limiting, caching, etc.)
     let redis_pool = initialize_redis(&config.redis).await;
 
-    // 6.3 Initialize Rate Limiting
+    // 6.3 Initialize Login Attempt Tracking (Redis, falling back to the database)
+    let login_attempt_store = initialize_login_attempt_store(&config, db, redis_pool.clone());
+
+    // 6.4 Initialize User Cache (Redis, falling back to memory)
+    let user_cache = initialize_user_cache(&config, redis_pool.clone());
+
+    // 6.5 Initialize Rate Limiting
     let rate_limit_state = initialize_rate_limiting(&config, redis_pool);
 
     // 7. Initialize Services
     let services = initialize_services(
+        &config,
         &repos,
         &adapters,
         model_client.clone(),
         storage_client.clone(),
+        mailer,
+        login_attempt_store,
+        user_cache,
     );
 
-    // 8. Load Role Cache
-    let role_cache = load_role_cache(&repos.role_repo).await?;
+    // 7.1 Resume offboardings interrupted by a restart
+    tokio::spawn(services.offboarding_service.clone().resume_running());
+
+    // 7.2 Start the inference workers, they also pick up the jobs left by a restart
+    let jobs_config = config.inference_jobs.clone().unwrap_or_default();
+    services.inference_job_service.start_workers(
+        jobs_config.workers(),
+        Duration::from_secs(jobs_config.poll_interval_seconds()),
+    );
 
-    // 9. Initialize Web Router & State
+    // 8. Initialize Web Router & State
     let app_state = Arc::new(AppState::new(
         config.clone(),
         services.auth_service,
+        services.password_reset_service,
+        services.password_policy_service,
+        services.email_verification_service,
+        services.impersonation_service,
+        services.login_lockout_service,
+        services.two_factor_service,
+        services.service_account_service,
+        services.sso_service,
+        services.session_service,
+        services.invitation_service,
         services.role_service,
         services.user_service,
         services.company_service,
+        services.company_settings_service,
+        services.usage_service,
+        services.offboarding_service,
+        services.team_service,
+        services.membership_service,
         services.image_service,
         services.recommendation_category_service,
         services.recommendation_service,
         services.label_service,
         services.mark_type_service,
         services.prediction_service,
+        services.prediction_batch_service,
+        services.inference_job_service,
         services.plot_service,
         services.dashboard_service,
         services.feedback_service,
         services.feedback_status_service,
-        role_cache,
+        services.policy_service,
         model_client,
         storage_client,
     ));
@@ -100,7 +140,7 @@ async fn main() -> Result<()> {
     let app =
         router(app_state, rate_limit_state).into_make_service_with_connect_info::<SocketAddr>();
 
-    // 10. Start Server
+    // 9. Start Server
     let addr: SocketAddr = format!("{}:{}", config.server.host, config.server.port)
         .parse()
         .expect("Invalid address");
@@ -1,6 +1,12 @@
 use anyhow::Result;
 use spl_domain::ports::integrations::{BlobStorageClient, ModelPredictionClient};
+use spl_domain::ports::mailer::Mailer;
 use spl_infra::adapters::integrations::{
+    mail::{
+        file::FileMailer,
+        log::LogMailer,
+        smtp::{SmtpMailer, SmtpSecurity},
+    },
     model_serving::{
         mock::MockModelClient,
         tensorflow::{TensorFlowServingClient, TensorFlowServingGrpcClient},
@@ -97,6 +103,65 @@ pub async fn initialize_storage_client(
     Ok(storage_client)
 }
 
+pub async fn initialize_mailer(config: &IntegrationsConfig) -> Result<Arc<dyn Mailer>> {
+    let Some(mail_config) = &config.mail else {
+        info!("No mail provider configured, emails will only be logged");
+        return Ok(Arc::new(LogMailer::new()));
+    };
+
+    let mailer: Arc<dyn Mailer> = match mail_config.provider.as_str() {
+        "smtp" => {
+            info!("Using SMTP mailer");
+            let host = mail_config
+                .smtp_host
+                .clone()
+                .ok_or_else(|| anyhow::anyhow!("SMTP host is required"))?;
+            let security = match &mail_config.smtp_security {
+                Some(value) => SmtpSecurity::parse(value).ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "Invalid SMTP security: {}. Use 'starttls', 'tls', or 'none'",
+                        value
+                    )
+                })?,
+                None => SmtpSecurity::StartTls,
+            };
+            let credentials = match (&mail_config.smtp_username, &mail_config.smtp_password) {
+                (Some(username), Some(password)) => Some((username.clone(), password.clone())),
+                _ => None,
+            };
+            Arc::new(SmtpMailer::new(
+                host,
+                mail_config.smtp_port.unwrap_or(587),
+                credentials,
+                security,
+                mail_config.from.clone(),
+                mail_config.timeout_seconds.unwrap_or(10),
+            ))
+        }
+        "file" => {
+            info!("Using file mailer");
+            let base_path = mail_config
+                .file_path
+                .clone()
+                .unwrap_or_else(|| "/tmp/spl-mail".to_string());
+            Arc::new(FileMailer::new(mail_config.from.clone(), base_path))
+        }
+        "log" => {
+            info!("Using log mailer (development mode)");
+            Arc::new(LogMailer::new())
+        }
+        provider => {
+            error!("Invalid mail provider: {}", provider);
+            anyhow::bail!(
+                "Invalid mail provider: {}. Use 'smtp', 'file', or 'log'",
+                provider
+            );
+        }
+    };
+
+    Ok(mailer)
+}
+
 pub async fn health_checks(
     model_client: &Arc<dyn ModelPredictionClient>,
     storage_client: &Arc<dyn BlobStorageClient>,
@@ -0,0 +1,31 @@
+use sea_orm::DatabaseConnection;
+use spl_domain::ports::auth::LoginAttemptStore;
+use spl_infra::adapters::auth::login_attempts::RedisLoginAttemptStore;
+use spl_infra::adapters::persistence::repositories::auth::DbLoginAttemptStore;
+use spl_shared::adapters::redis::RedisPool;
+use spl_shared::config::AppConfig;
+use std::sync::Arc;
+use tracing::info;
+
+pub fn initialize_login_attempt_store(
+    config: &AppConfig,
+    db: DatabaseConnection,
+    redis_pool: Option<Arc<RedisPool>>,
+) -> Arc<dyn LoginAttemptStore> {
+    let window_seconds = config
+        .login_lockout
+        .clone()
+        .unwrap_or_default()
+        .failure_window_seconds();
+
+    match redis_pool {
+        Some(pool) => {
+            info!("Tracking failed logins in Redis");
+            Arc::new(RedisLoginAttemptStore::new((*pool).clone(), window_seconds))
+        }
+        None => {
+            info!("Redis not available, tracking failed logins in the database");
+            Arc::new(DbLoginAttemptStore::new(db, window_seconds))
+        }
+    }
+}
@@ -1,7 +1,9 @@
 pub mod database;
 pub mod integrations;
+pub mod login_lockout;
 pub mod rate_limiting;
 pub mod redis;
 pub mod repositories;
 pub mod seed;
 pub mod services;
+pub mod user_cache;
@@ -1,48 +1,119 @@
 use sea_orm::DatabaseConnection;
-use spl_domain::ports::auth::{PasswordEncoder, TokenGenerator};
+use spl_domain::ports::archive::{ArchiveReader, ArchiveWriter};
+use spl_domain::ports::auth::{
+    BreachedPasswordList, OpaqueTokenGenerator, PasswordEncoder, TokenGenerator, TwoFactorProvider,
+};
+use spl_domain::ports::oidc::OidcClient;
 use spl_domain::ports::repositories::{
-    company::CompanyRepository,
+    auth::{
+        ApiKeyRepository, CompanyPasswordPolicyRepository, EmailVerificationTokenRepository,
+        IdentityProviderRepository, ImpersonationActionRepository, ImpersonationRepository,
+        OidcLoginStateRepository, PasswordHistoryRepository, PasswordResetTokenRepository,
+        RecoveryCodeRepository, RefreshTokenRepository, ServiceAccountRepository,
+        SessionRepository, TwoFactorChallengeRepository, TwoFactorRepository,
+        UserIdentityRepository,
+    },
+    company::{CompanyRepository, CompanySettingsRepository},
     dashboard::DashboardSummaryRepository,
     diagnostics::{
-        LabelRepository, MarkTypeRepository, PredictionMarkRepository, PredictionRepository,
+        InferenceJobRepository, LabelRepository, MarkTypeRepository, PredictionBatchItemRepository,
+        PredictionBatchRepository, PredictionMarkRepository, PredictionRepository,
     },
     feedback::{FeedbackRepository, FeedbackStatusRepository},
     image::ImageRepository,
+    offboarding::{CompanyOffboardingRepository, OffboardingEventRepository},
     plot::PlotRepository,
     recommendation::{CategoryRepository, RecommendationRepository},
-    user::{RoleRepository, UserRepository},
+    team::TeamRepository,
+    usage::{CompanyQuotaRepository, UsageRepository},
+    user::{
+        InvitationRepository, MembershipRepository, PermissionRepository, RoleRepository,
+        UserRepository,
+    },
 };
 use spl_infra::adapters::persistence::repositories::{
     dashboard::DbDashboardSummaryRepository, recommendation::DbCategoryRepository,
     DbFeedbackStatusRepository,
 };
 use spl_infra::adapters::{
-    auth::{jwt::JwtTokenGenerator, password::Argon2PasswordEncoder},
+    archive::zip::{ZipArchiveReader, ZipArchiveWriter},
+    auth::{
+        breached_passwords::FileBreachedPasswordList, jwt::JwtTokenGenerator, oidc::HttpOidcClient,
+        opaque::RandomOpaqueTokenGenerator, password::Argon2PasswordEncoder,
+        totp::TotpTwoFactorProvider,
+    },
     persistence::repositories::{
+        auth::{
+            DbApiKeyRepository, DbCompanyPasswordPolicyRepository,
+            DbEmailVerificationTokenRepository, DbIdentityProviderRepository,
+            DbImpersonationActionRepository, DbImpersonationRepository, DbOidcLoginStateRepository,
+            DbPasswordHistoryRepository, DbPasswordResetTokenRepository, DbRecoveryCodeRepository,
+            DbRefreshTokenRepository, DbServiceAccountRepository, DbSessionRepository,
+            DbTwoFactorChallengeRepository, DbTwoFactorRepository, DbUserIdentityRepository,
+        },
         company::DbCompanyRepository,
+        company_settings::DbCompanySettingsRepository,
         diagnostics::{
-            DbLabelRepository, DbMarkTypeRepository, DbPredictionMarkRepository,
-            DbPredictionRepository,
+            DbInferenceJobRepository, DbLabelRepository, DbMarkTypeRepository,
+            DbPredictionBatchItemRepository, DbPredictionBatchRepository,
+            DbPredictionMarkRepository, DbPredictionRepository,
         },
         feedback::DbFeedbackRepository,
         image::DbImageRepository,
+        offboarding::{DbCompanyOffboardingRepository, DbOffboardingEventRepository},
         plot::DbPlotRepository,
         recommendation::DbRecommendationRepository,
-        user::{role::DbRoleRepository, DbUserRepository},
+        team::DbTeamRepository,
+        usage::{DbCompanyQuotaRepository, DbUsageRepository},
+        user::{
+            role::DbRoleRepository, DbInvitationRepository, DbMembershipRepository,
+            DbPermissionRepository, DbUserRepository,
+        },
     },
 };
 use spl_shared::config::AppConfig;
+use spl_shared::error::Result;
 use std::sync::Arc;
+use std::time::Duration;
+use tracing::info;
 
 pub struct Repositories {
     pub role_repo: Arc<dyn RoleRepository>,
+    pub permission_repo: Arc<dyn PermissionRepository>,
     pub company_repo: Arc<dyn CompanyRepository>,
+    pub company_settings_repo: Arc<dyn CompanySettingsRepository>,
+    pub company_quota_repo: Arc<dyn CompanyQuotaRepository>,
+    pub usage_repo: Arc<dyn UsageRepository>,
+    pub offboarding_repo: Arc<dyn CompanyOffboardingRepository>,
+    pub offboarding_event_repo: Arc<dyn OffboardingEventRepository>,
+    pub team_repo: Arc<dyn TeamRepository>,
     pub user_repo: Arc<dyn UserRepository>,
+    pub invitation_repo: Arc<dyn InvitationRepository>,
+    pub membership_repo: Arc<dyn MembershipRepository>,
+    pub session_repo: Arc<dyn SessionRepository>,
+    pub refresh_token_repo: Arc<dyn RefreshTokenRepository>,
+    pub password_reset_token_repo: Arc<dyn PasswordResetTokenRepository>,
+    pub email_verification_token_repo: Arc<dyn EmailVerificationTokenRepository>,
+    pub impersonation_repo: Arc<dyn ImpersonationRepository>,
+    pub impersonation_action_repo: Arc<dyn ImpersonationActionRepository>,
+    pub two_factor_repo: Arc<dyn TwoFactorRepository>,
+    pub recovery_code_repo: Arc<dyn RecoveryCodeRepository>,
+    pub two_factor_challenge_repo: Arc<dyn TwoFactorChallengeRepository>,
+    pub service_account_repo: Arc<dyn ServiceAccountRepository>,
+    pub api_key_repo: Arc<dyn ApiKeyRepository>,
+    pub identity_provider_repo: Arc<dyn IdentityProviderRepository>,
+    pub user_identity_repo: Arc<dyn UserIdentityRepository>,
+    pub oidc_login_state_repo: Arc<dyn OidcLoginStateRepository>,
+    pub company_password_policy_repo: Arc<dyn CompanyPasswordPolicyRepository>,
+    pub password_history_repo: Arc<dyn PasswordHistoryRepository>,
     pub image_repo: Arc<dyn ImageRepository>,
     pub label_repo: Arc<dyn LabelRepository>,
     pub mark_type_repo: Arc<dyn MarkTypeRepository>,
     pub prediction_mark_repo: Arc<dyn PredictionMarkRepository>,
     pub prediction_repo: Arc<dyn PredictionRepository>,
+    pub prediction_batch_repo: Arc<dyn PredictionBatchRepository>,
+    pub prediction_batch_item_repo: Arc<dyn PredictionBatchItemRepository>,
+    pub inference_job_repo: Arc<dyn InferenceJobRepository>,
     pub plot_repo: Arc<dyn PlotRepository>,
     pub recommendation_category_repo: Arc<dyn CategoryRepository>,
     pub recommendation_repo: Arc<dyn RecommendationRepository>,
@@ -54,16 +125,68 @@ pub struct Repositories {
 pub struct Adapters {
     pub password_encoder: Arc<dyn PasswordEncoder>,
     pub token_generator: Arc<dyn TokenGenerator>,
+    pub opaque_token_generator: Arc<dyn OpaqueTokenGenerator>,
+    pub two_factor_provider: Arc<dyn TwoFactorProvider>,
+    pub oidc_client: Arc<dyn OidcClient>,
+    pub breached_passwords: Arc<dyn BreachedPasswordList>,
+    pub archive_writer: Arc<dyn ArchiveWriter>,
+    pub archive_reader: Arc<dyn ArchiveReader>,
 }
 
 pub fn initialize_repositories(db: DatabaseConnection) -> Repositories {
     let role_repo: Arc<dyn RoleRepository> = Arc::new(DbRoleRepository::new(db.clone()));
+    let permission_repo: Arc<dyn PermissionRepository> =
+        Arc::new(DbPermissionRepository::new(db.clone()));
     let company_repo: Arc<dyn CompanyRepository> = Arc::new(DbCompanyRepository::new(db.clone()));
+    let company_settings_repo: Arc<dyn CompanySettingsRepository> =
+        Arc::new(DbCompanySettingsRepository::new(db.clone()));
+    let company_quota_repo: Arc<dyn CompanyQuotaRepository> =
+        Arc::new(DbCompanyQuotaRepository::new(db.clone()));
+    let usage_repo: Arc<dyn UsageRepository> = Arc::new(DbUsageRepository::new(db.clone()));
+    let offboarding_repo: Arc<dyn CompanyOffboardingRepository> =
+        Arc::new(DbCompanyOffboardingRepository::new(db.clone()));
+    let offboarding_event_repo: Arc<dyn OffboardingEventRepository> =
+        Arc::new(DbOffboardingEventRepository::new(db.clone()));
+    let team_repo: Arc<dyn TeamRepository> = Arc::new(DbTeamRepository::new(db.clone()));
     let user_repo: Arc<dyn UserRepository> = Arc::new(DbUserRepository::new(
         db.clone(),
         role_repo.clone(),
         company_repo.clone(),
     ));
+    let invitation_repo: Arc<dyn InvitationRepository> =
+        Arc::new(DbInvitationRepository::new(db.clone()));
+    let membership_repo: Arc<dyn MembershipRepository> =
+        Arc::new(DbMembershipRepository::new(db.clone()));
+    let session_repo: Arc<dyn SessionRepository> = Arc::new(DbSessionRepository::new(db.clone()));
+    let refresh_token_repo: Arc<dyn RefreshTokenRepository> =
+        Arc::new(DbRefreshTokenRepository::new(db.clone()));
+    let password_reset_token_repo: Arc<dyn PasswordResetTokenRepository> =
+        Arc::new(DbPasswordResetTokenRepository::new(db.clone()));
+    let email_verification_token_repo: Arc<dyn EmailVerificationTokenRepository> =
+        Arc::new(DbEmailVerificationTokenRepository::new(db.clone()));
+    let impersonation_repo: Arc<dyn ImpersonationRepository> =
+        Arc::new(DbImpersonationRepository::new(db.clone()));
+    let impersonation_action_repo: Arc<dyn ImpersonationActionRepository> =
+        Arc::new(DbImpersonationActionRepository::new(db.clone()));
+    let two_factor_repo: Arc<dyn TwoFactorRepository> =
+        Arc::new(DbTwoFactorRepository::new(db.clone()));
+    let recovery_code_repo: Arc<dyn RecoveryCodeRepository> =
+        Arc::new(DbRecoveryCodeRepository::new(db.clone()));
+    let two_factor_challenge_repo: Arc<dyn TwoFactorChallengeRepository> =
+        Arc::new(DbTwoFactorChallengeRepository::new(db.clone()));
+    let service_account_repo: Arc<dyn ServiceAccountRepository> =
+        Arc::new(DbServiceAccountRepository::new(db.clone()));
+    let api_key_repo: Arc<dyn ApiKeyRepository> = Arc::new(DbApiKeyRepository::new(db.clone()));
+    let identity_provider_repo: Arc<dyn IdentityProviderRepository> =
+        Arc::new(DbIdentityProviderRepository::new(db.clone()));
+    let user_identity_repo: Arc<dyn UserIdentityRepository> =
+        Arc::new(DbUserIdentityRepository::new(db.clone()));
+    let oidc_login_state_repo: Arc<dyn OidcLoginStateRepository> =
+        Arc::new(DbOidcLoginStateRepository::new(db.clone()));
+    let company_password_policy_repo: Arc<dyn CompanyPasswordPolicyRepository> =
+        Arc::new(DbCompanyPasswordPolicyRepository::new(db.clone()));
+    let password_history_repo: Arc<dyn PasswordHistoryRepository> =
+        Arc::new(DbPasswordHistoryRepository::new(db.clone()));
 
     let image_repo: Arc<dyn ImageRepository> = Arc::new(DbImageRepository::new(db.clone()));
     let label_repo: Arc<dyn LabelRepository> = Arc::new(DbLabelRepository::new(db.clone()));
@@ -97,6 +220,13 @@ pub fn initialize_repositories(db: DatabaseConnection) -> Repositories {
         recommendation_repo.clone(),
     ));
 
+    let prediction_batch_repo: Arc<dyn PredictionBatchRepository> =
+        Arc::new(DbPredictionBatchRepository::new(db.clone()));
+    let prediction_batch_item_repo: Arc<dyn PredictionBatchItemRepository> =
+        Arc::new(DbPredictionBatchItemRepository::new(db.clone()));
+    let inference_job_repo: Arc<dyn InferenceJobRepository> =
+        Arc::new(DbInferenceJobRepository::new(db.clone()));
+
     let plot_repo: Arc<dyn PlotRepository> = Arc::new(DbPlotRepository::new(db.clone()));
 
     let dashboard_repo: Arc<dyn DashboardSummaryRepository> = Arc::new(
@@ -105,13 +235,41 @@ pub fn initialize_repositories(db: DatabaseConnection) -> Repositories {
 
     Repositories {
         role_repo,
+        permission_repo,
         company_repo,
+        company_settings_repo,
+        company_quota_repo,
+        usage_repo,
+        offboarding_repo,
+        offboarding_event_repo,
+        team_repo,
         user_repo,
+        invitation_repo,
+        membership_repo,
+        session_repo,
+        refresh_token_repo,
+        password_reset_token_repo,
+        email_verification_token_repo,
+        impersonation_repo,
+        impersonation_action_repo,
+        two_factor_repo,
+        recovery_code_repo,
+        two_factor_challenge_repo,
+        service_account_repo,
+        api_key_repo,
+        identity_provider_repo,
+        user_identity_repo,
+        oidc_login_state_repo,
+        company_password_policy_repo,
+        password_history_repo,
         image_repo,
         label_repo,
         mark_type_repo,
         prediction_mark_repo,
         prediction_repo,
+        prediction_batch_repo,
+        prediction_batch_item_repo,
+        inference_job_repo,
         plot_repo,
         recommendation_category_repo,
         recommendation_repo,
@@ -121,12 +279,49 @@ pub fn initialize_repositories(db: DatabaseConnection) -> Repositories {
     }
 }
 
-pub fn initialize_adapters(config: Arc<AppConfig>) -> Adapters {
-    let password_encoder: Arc<dyn PasswordEncoder> = Arc::new(Argon2PasswordEncoder::new());
-    let token_generator: Arc<dyn TokenGenerator> = Arc::new(JwtTokenGenerator::new(config));
+pub fn initialize_adapters(config: Arc<AppConfig>) -> Result<Adapters> {
+    let hashing_config = config.password_hashing.clone().unwrap_or_default();
+    let password_encoder: Arc<dyn PasswordEncoder> = Arc::new(Argon2PasswordEncoder::with_params(
+        hashing_config.memory_kib(),
+        hashing_config.iterations(),
+        hashing_config.parallelism(),
+    )?);
+    let token_generator: Arc<dyn TokenGenerator> =
+        Arc::new(JwtTokenGenerator::new(config.clone())?);
+    let opaque_token_generator: Arc<dyn OpaqueTokenGenerator> =
+        Arc::new(RandomOpaqueTokenGenerator::new());
+    let two_factor_provider: Arc<dyn TwoFactorProvider> = Arc::new(TotpTwoFactorProvider::new(
+        config.server.two_factor_issuer(),
+    ));
+    let oidc_config = config.oidc.clone().unwrap_or_default();
+    let oidc_client: Arc<dyn OidcClient> = Arc::new(HttpOidcClient::new(Duration::from_secs(
+        oidc_config.timeout_seconds(),
+    )));
+
+    let breached_passwords_path = config
+        .password_policy
+        .as_ref()
+        .and_then(|policy| policy.breached_passwords_path.as_deref());
+    let breached_passwords: Arc<dyn BreachedPasswordList> = match breached_passwords_path {
+        Some(path) => {
+            let list = FileBreachedPasswordList::load(path)?;
+            info!(count = list.len(), "Breached passwords loaded");
+            Arc::new(list)
+        }
+        None => Arc::new(FileBreachedPasswordList::empty()),
+    };
 
-    Adapters {
+    let archive_writer: Arc<dyn ArchiveWriter> = Arc::new(ZipArchiveWriter::new());
+    let archive_reader: Arc<dyn ArchiveReader> = Arc::new(ZipArchiveReader::new());
+
+    Ok(Adapters {
         password_encoder,
         token_generator,
-    }
+        opaque_token_generator,
+        two_factor_provider,
+        oidc_client,
+        breached_passwords,
+        archive_writer,
+        archive_reader,
+    })
 }
@@ -3,7 +3,6 @@ use spl_domain::entities::user::User;
 use spl_domain::ports::auth::PasswordEncoder;
 use spl_domain::ports::repositories::user::{RoleRepository, UserRepository};
 use spl_shared::config::AdminConfig;
-use std::collections::HashMap;
 use std::sync::Arc;
 use tracing::{error, info};
 use uuid::Uuid;
@@ -61,11 +60,14 @@ pub async fn seed_admin_user(
         id: Uuid::new_v4(),
         username: admin_config.username.clone(),
         email: Some(admin_config.email.clone()),
+        // Set by whoever configures the server
+        email_verified_at: Some(chrono::Utc::now()),
         password_hash,
         name: None,
         surname: None,
         role: admin_role,
         company: None,
+        deactivated_at: None,
         created_at: chrono::Utc::now(),
         updated_at: chrono::Utc::now(),
     };
@@ -77,16 +79,3 @@ pub async fn seed_admin_user(
 
     Ok(())
 }
-
-pub async fn load_role_cache(role_repo: &Arc<dyn RoleRepository>) -> Result<HashMap<String, i16>> {
-    info!("Loading role cache...");
-
-    let cache = role_repo
-        .get_all()
-        .await?
-        .into_iter()
-        .map(|r| (r.name, r.level))
-        .collect::<HashMap<String, i16>>();
-
-    Ok(cache)
-}
@@ -5,24 +5,66 @@ use spl_application::services::feedback::FeedbackService;
 use spl_application::services::{
     auth::AuthService,
     company::CompanyService,
-    diagnostics::{LabelService, MarkTypeService},
+    company_settings::CompanySettingsService,
+    diagnostics::{InferenceJobService, LabelService, MarkTypeService, PredictionBatchService},
+    email_verification::EmailVerificationService,
     image::ImageService,
+    impersonation::ImpersonationService,
+    login_lockout::{LockoutPolicy, LoginLockoutService},
+    offboarding::OffboardingService,
+    password_policy::PasswordPolicyService,
+    password_reset::PasswordResetService,
     plot::PlotService,
+    policy::PolicyService,
     recommendation::RecommendationService,
-    user::{role::RoleService, UserService},
+    service_account::ServiceAccountService,
+    session::SessionService,
+    sso::SsoService,
+    team::TeamService,
+    two_factor::TwoFactorService,
+    usage::UsageService,
+    user::{role::RoleService, InvitationService, MembershipService, UserService},
 };
+use spl_domain::entities::auth::PasswordPolicy;
+use spl_domain::entities::company::CompanySettings;
+use spl_domain::entities::diagnostics::{BatchLimits, JobPolicy};
+use spl_domain::entities::image::ImageFormat;
+use spl_domain::entities::usage::{QuotaEnforcement, UsageQuota};
+use spl_domain::ports::auth::LoginAttemptStore;
+use spl_domain::ports::cache::UserCache;
 use spl_domain::ports::integrations::{BlobStorageClient, ModelPredictionClient};
+use spl_domain::ports::mailer::Mailer;
+use spl_shared::config::AppConfig;
 use std::sync::Arc;
+use tracing::warn;
 
 pub struct Services {
+    pub policy_service: Arc<PolicyService>,
     pub auth_service: Arc<AuthService>,
+    pub password_reset_service: Arc<PasswordResetService>,
+    pub password_policy_service: Arc<PasswordPolicyService>,
+    pub email_verification_service: Arc<EmailVerificationService>,
+    pub impersonation_service: Arc<ImpersonationService>,
+    pub login_lockout_service: Arc<LoginLockoutService>,
+    pub two_factor_service: Arc<TwoFactorService>,
+    pub service_account_service: Arc<ServiceAccountService>,
+    pub sso_service: Arc<SsoService>,
+    pub session_service: Arc<SessionService>,
+    pub invitation_service: Arc<InvitationService>,
     pub role_service: Arc<RoleService>,
     pub user_service: Arc<UserService>,
     pub company_service: Arc<CompanyService>,
+    pub company_settings_service: Arc<CompanySettingsService>,
+    pub usage_service: Arc<UsageService>,
+    pub offboarding_service: Arc<OffboardingService>,
+    pub team_service: Arc<TeamService>,
+    pub membership_service: Arc<MembershipService>,
     pub image_service: Arc<ImageService>,
     pub label_service: Arc<LabelService>,
     pub mark_type_service: Arc<MarkTypeService>,
     pub prediction_service: Arc<services::diagnostics::PredictionService>,
+    pub prediction_batch_service: Arc<PredictionBatchService>,
+    pub inference_job_service: Arc<InferenceJobService>,
     pub plot_service: Arc<PlotService>,
     pub recommendation_category_service: Arc<services::recommendation::CategoryService>,
     pub recommendation_service: Arc<RecommendationService>,
@@ -31,31 +73,185 @@ pub struct Services {
     pub feedback_service: Arc<FeedbackService>,
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn initialize_services(
+    config: &AppConfig,
     repos: &Repositories,
     adapters: &Adapters,
     model_client: Arc<dyn ModelPredictionClient>,
     storage_client: Arc<dyn BlobStorageClient>,
+    mailer: Arc<dyn Mailer>,
+    login_attempt_store: Arc<dyn LoginAttemptStore>,
+    user_cache: Arc<dyn UserCache>,
 ) -> Services {
+    let lockout_config = config.login_lockout.clone().unwrap_or_default();
+    let login_lockout_service = Arc::new(LoginLockoutService::new(
+        repos.user_repo.clone(),
+        login_attempt_store,
+        LockoutPolicy {
+            enabled: lockout_config.enabled,
+            max_attempts: lockout_config.max_attempts(),
+            lockout_seconds: lockout_config.lockout_seconds(),
+            max_lockout_seconds: lockout_config.max_lockout_seconds(),
+        },
+    ));
+
+    let two_factor_service = Arc::new(TwoFactorService::new(
+        repos.two_factor_repo.clone(),
+        repos.recovery_code_repo.clone(),
+        repos.two_factor_challenge_repo.clone(),
+        adapters.two_factor_provider.clone(),
+        adapters.opaque_token_generator.clone(),
+        config.server.two_factor_challenge_ttl_seconds(),
+    ));
+
     let auth_service = Arc::new(AuthService::new(
         repos.user_repo.clone(),
+        repos.membership_repo.clone(),
+        repos.session_repo.clone(),
+        repos.refresh_token_repo.clone(),
         adapters.password_encoder.clone(),
         adapters.token_generator.clone(),
+        adapters.opaque_token_generator.clone(),
+        login_lockout_service.clone(),
+        two_factor_service.clone(),
+        user_cache.clone(),
+        config.server.access_token_ttl_seconds(),
+        config.server.refresh_token_ttl_days(),
+    ));
+
+    // Grants are trusted as long as the users holding them
+    let user_cache_config = config.user_cache.clone().unwrap_or_default();
+    let policy_service = Arc::new(PolicyService::new(
+        repos.permission_repo.clone(),
+        user_cache_config.ttl_seconds(),
+    ));
+
+    let impersonation_service = Arc::new(ImpersonationService::new(
+        repos.impersonation_repo.clone(),
+        repos.impersonation_action_repo.clone(),
+        repos.user_repo.clone(),
+        adapters.token_generator.clone(),
+        policy_service.clone(),
+        config.server.access_token_ttl_seconds(),
     ));
 
-    let role_service = Arc::new(RoleService::new(repos.role_repo.clone()));
+    let role_service = Arc::new(RoleService::new(
+        repos.role_repo.clone(),
+        repos.permission_repo.clone(),
+        repos.user_repo.clone(),
+        repos.invitation_repo.clone(),
+        repos.membership_repo.clone(),
+        policy_service.clone(),
+        user_cache.clone(),
+    ));
 
     let access_control_service = Arc::new(services::access_control::AccessControlService::new(
         repos.company_repo.clone(),
+        repos.team_repo.clone(),
+        policy_service.clone(),
+    ));
+
+    let password_policy_config = config.password_policy.clone().unwrap_or_default();
+    let password_policy_service = Arc::new(PasswordPolicyService::new(
+        repos.company_password_policy_repo.clone(),
+        repos.password_history_repo.clone(),
+        repos.company_repo.clone(),
+        adapters.breached_passwords.clone(),
+        adapters.password_encoder.clone(),
+        access_control_service.clone(),
+        PasswordPolicy {
+            min_length: password_policy_config.min_length(),
+            require_uppercase: password_policy_config.require_uppercase(),
+            require_lowercase: password_policy_config.require_lowercase(),
+            require_digit: password_policy_config.require_digit(),
+            require_symbol: password_policy_config.require_symbol(),
+            history_size: password_policy_config.history_size(),
+        },
+    ));
+
+    let password_reset_service = Arc::new(PasswordResetService::new(
+        repos.user_repo.clone(),
+        repos.password_reset_token_repo.clone(),
+        repos.session_repo.clone(),
+        adapters.password_encoder.clone(),
+        adapters.opaque_token_generator.clone(),
+        mailer.clone(),
+        password_policy_service.clone(),
+        user_cache.clone(),
+        config.server.frontend_url.clone(),
+        config.server.password_reset_ttl_minutes(),
+    ));
+
+    let email_verification_service = Arc::new(EmailVerificationService::new(
         repos.user_repo.clone(),
+        repos.email_verification_token_repo.clone(),
+        adapters.opaque_token_generator.clone(),
+        mailer.clone(),
+        user_cache.clone(),
+        config.server.frontend_url.clone(),
+        config.server.email_verification_ttl_hours(),
     ));
 
     let user_service = Arc::new(UserService::new(
+        repos.user_repo.clone(),
+        repos.role_repo.clone(),
+        repos.company_repo.clone(),
+        repos.session_repo.clone(),
+        adapters.password_encoder.clone(),
+        access_control_service.clone(),
+        password_policy_service.clone(),
+        email_verification_service.clone(),
+        user_cache.clone(),
+    ));
+
+    let service_account_service = Arc::new(ServiceAccountService::new(
+        repos.service_account_repo.clone(),
+        repos.api_key_repo.clone(),
+        repos.user_repo.clone(),
+        repos.role_repo.clone(),
+        repos.company_repo.clone(),
+        adapters.password_encoder.clone(),
+        adapters.opaque_token_generator.clone(),
+        access_control_service.clone(),
+    ));
+
+    let oidc_config = config.oidc.clone().unwrap_or_default();
+    let sso_service = Arc::new(SsoService::new(
+        repos.identity_provider_repo.clone(),
+        repos.user_identity_repo.clone(),
+        repos.oidc_login_state_repo.clone(),
+        repos.user_repo.clone(),
+        repos.role_repo.clone(),
+        repos.company_repo.clone(),
+        adapters.oidc_client.clone(),
+        adapters.password_encoder.clone(),
+        adapters.opaque_token_generator.clone(),
+        auth_service.clone(),
+        access_control_service.clone(),
+        user_cache.clone(),
+        oidc_config.redirect_url(config.server.frontend_url.as_deref()),
+        oidc_config.state_ttl_seconds(),
+    ));
+
+    let session_service = Arc::new(SessionService::new(
+        repos.session_repo.clone(),
+        repos.user_repo.clone(),
+        access_control_service.clone(),
+    ));
+
+    let invitation_service = Arc::new(InvitationService::new(
+        repos.invitation_repo.clone(),
         repos.user_repo.clone(),
         repos.role_repo.clone(),
         repos.company_repo.clone(),
         adapters.password_encoder.clone(),
+        adapters.opaque_token_generator.clone(),
+        mailer,
         access_control_service.clone(),
+        password_policy_service.clone(),
+        config.server.frontend_url.clone(),
+        config.server.invitation_ttl_hours(),
     ));
 
     let company_service = Arc::new(CompanyService::new(
@@ -63,6 +259,77 @@ pub fn initialize_services(
         access_control_service.clone(),
     ));
 
+    let settings_config = config.company_settings.clone().unwrap_or_default();
+    let allowed_upload_formats = match &settings_config.allowed_upload_formats {
+        Some(names) => names
+            .iter()
+            .filter_map(|name| {
+                let format = ImageFormat::parse(&name.to_lowercase());
+                if format.is_none() {
+                    warn!(
+                        "Ignoring unknown upload format in company_settings: {}",
+                        name
+                    );
+                }
+                format
+            })
+            .collect(),
+        None => ImageFormat::ALL.to_vec(),
+    };
+    let company_settings_service = Arc::new(CompanySettingsService::new(
+        repos.company_settings_repo.clone(),
+        repos.company_repo.clone(),
+        access_control_service.clone(),
+        user_cache.clone(),
+        CompanySettings {
+            timezone: settings_config.timezone(),
+            default_language: settings_config.default_language(),
+            data_retention_days: settings_config.data_retention_days(),
+            two_factor_required_level: None,
+            default_page_size: settings_config.default_page_size(),
+            allowed_upload_formats,
+            alert_severity_threshold: settings_config.alert_severity_threshold(),
+            alert_confidence_threshold: settings_config.alert_confidence_threshold(),
+        },
+    ));
+
+    let quotas_config = config.quotas.clone().unwrap_or_default();
+    let enforcement = QuotaEnforcement::parse(&quotas_config.enforcement().to_lowercase())
+        .unwrap_or_else(|| {
+            warn!(
+                "Unknown quota enforcement {}, using hard",
+                quotas_config.enforcement()
+            );
+            QuotaEnforcement::Hard
+        });
+    let usage_service = Arc::new(UsageService::new(
+        repos.usage_repo.clone(),
+        repos.company_quota_repo.clone(),
+        repos.company_repo.clone(),
+        access_control_service.clone(),
+        UsageQuota {
+            predictions_per_month: quotas_config.predictions_per_month,
+            predictions_per_user_per_month: quotas_config.predictions_per_user_per_month,
+            stored_bytes_per_month: quotas_config.stored_bytes_per_month,
+            warning_ratio: quotas_config.warning_ratio(),
+            enforcement,
+        },
+    ));
+
+    let offboarding_service = Arc::new(OffboardingService::new(
+        repos.offboarding_repo.clone(),
+        repos.offboarding_event_repo.clone(),
+        repos.company_repo.clone(),
+        repos.user_repo.clone(),
+        repos.plot_repo.clone(),
+        repos.prediction_repo.clone(),
+        repos.inference_job_repo.clone(),
+        storage_client.clone(),
+        adapters.archive_writer.clone(),
+        user_cache,
+        policy_service.clone(),
+    ));
+
     let recommendation_category_service = Arc::new(services::recommendation::CategoryService::new(
         repos.recommendation_category_repo.clone(),
     ));
@@ -72,10 +339,23 @@ pub fn initialize_services(
         recommendation_category_service.clone(),
     ));
 
-    let label_service = Arc::new(LabelService::new(repos.label_repo.clone()));
-    let mark_type_service = Arc::new(MarkTypeService::new(repos.mark_type_repo.clone()));
+    let label_service = Arc::new(LabelService::new(
+        repos.label_repo.clone(),
+        policy_service.clone(),
+    ));
+    let mark_type_service = Arc::new(MarkTypeService::new(
+        repos.mark_type_repo.clone(),
+        policy_service.clone(),
+    ));
     let image_service = Arc::new(ImageService::new(repos.image_repo.clone()));
 
+    let membership_service = Arc::new(MembershipService::new(
+        repos.membership_repo.clone(),
+        repos.user_repo.clone(),
+        repos.role_repo.clone(),
+        access_control_service.clone(),
+    ));
+
     let prediction_service = Arc::new(services::diagnostics::PredictionService::new(
         repos.prediction_repo.clone(),
         repos.user_repo.clone(),
@@ -84,15 +364,54 @@ pub fn initialize_services(
         repos.prediction_mark_repo.clone(),
         repos.mark_type_repo.clone(),
         repos.recommendation_repo.clone(),
-        storage_client,
+        storage_client.clone(),
         model_client,
         access_control_service.clone(),
+        company_settings_service.clone(),
+        usage_service.clone(),
+    ));
+
+    let jobs_config = config.inference_jobs.clone().unwrap_or_default();
+    let inference_job_service = Arc::new(InferenceJobService::new(
+        repos.inference_job_repo.clone(),
+        repos.prediction_batch_repo.clone(),
+        repos.prediction_batch_item_repo.clone(),
+        repos.user_repo.clone(),
+        prediction_service.clone(),
+        membership_service.clone(),
+        storage_client.clone(),
+        JobPolicy {
+            max_attempts: jobs_config.max_attempts(),
+            retry_delay_seconds: jobs_config.retry_delay_seconds(),
+            lease_seconds: jobs_config.lease_seconds(),
+        },
+    ));
+
+    let batch_config = config.batch_uploads.clone().unwrap_or_default();
+    let prediction_batch_service = Arc::new(PredictionBatchService::new(
+        repos.prediction_batch_repo.clone(),
+        repos.prediction_batch_item_repo.clone(),
+        inference_job_service.clone(),
+        storage_client,
+        adapters.archive_reader.clone(),
+        BatchLimits {
+            max_files: batch_config.max_files(),
+            max_extracted_bytes: batch_config.max_upload_bytes() as u64,
+        },
     ));
 
     let plot_service = Arc::new(PlotService::new(
         repos.plot_repo.clone(),
         repos.prediction_repo.clone(),
-        access_control_service,
+        access_control_service.clone(),
+        company_settings_service.clone(),
+    ));
+
+    let team_service = Arc::new(TeamService::new(
+        repos.team_repo.clone(),
+        repos.user_repo.clone(),
+        repos.plot_repo.clone(),
+        access_control_service.clone(),
     ));
 
     let dashboard_service = Arc::new(services::dashboard::DashboardService::new(
@@ -100,6 +419,8 @@ pub fn initialize_services(
         repos.label_repo.clone(),
         repos.plot_repo.clone(),
         repos.user_repo.clone(),
+        repos.membership_repo.clone(),
+        access_control_service,
     ));
 
     let feedback_status_service = Arc::new(FeedbackStatusService::new(
@@ -113,14 +434,32 @@ pub fn initialize_services(
     ));
 
     Services {
+        policy_service,
         auth_service,
+        password_reset_service,
+        password_policy_service,
+        email_verification_service,
+        impersonation_service,
+        login_lockout_service,
+        two_factor_service,
+        service_account_service,
+        sso_service,
+        session_service,
+        invitation_service,
         role_service,
         user_service,
         company_service,
+        company_settings_service,
+        usage_service,
+        offboarding_service,
+        team_service,
+        membership_service,
         image_service,
         label_service,
         mark_type_service,
         prediction_service,
+        prediction_batch_service,
+        inference_job_service,
         plot_service,
         recommendation_category_service,
         recommendation_service,
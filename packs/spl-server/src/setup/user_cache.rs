@@ -0,0 +1,36 @@
+use spl_domain::ports::cache::UserCache;
+use spl_infra::adapters::cache::memory::{InMemoryUserCache, NoUserCache};
+use spl_infra::adapters::cache::redis::RedisUserCache;
+use spl_shared::adapters::redis::RedisPool;
+use spl_shared::config::AppConfig;
+use std::sync::Arc;
+use tracing::info;
+
+pub fn initialize_user_cache(
+    config: &AppConfig,
+    redis_pool: Option<Arc<RedisPool>>,
+) -> Arc<dyn UserCache> {
+    let cache_config = config.user_cache.clone().unwrap_or_default();
+
+    if !cache_config.enabled {
+        info!("User cache disabled");
+        return Arc::new(NoUserCache);
+    }
+
+    match redis_pool {
+        Some(pool) => {
+            info!("Caching users in Redis");
+            Arc::new(RedisUserCache::new(
+                (*pool).clone(),
+                cache_config.ttl_seconds(),
+            ))
+        }
+        None => {
+            info!("Redis not available, caching users in memory");
+            Arc::new(InMemoryUserCache::new(
+                cache_config.capacity(),
+                cache_config.ttl_seconds(),
+            ))
+        }
+    }
+}
@@ -10,6 +10,23 @@ pub struct AppConfig {
     pub integrations: IntegrationsConfig,
     pub redis: Option<RedisConfig>,
     pub rate_limiting: Option<RateLimitingConfig>,
+    /// Per-account login lockout. Enabled with the defaults when missing.
+    pub login_lockout: Option<LoginLockoutConfig>,
+    /// OpenID Connect single sign-on. Identity providers are configured per company.
+    pub oidc: Option<OidcConfig>,
+    /// Cache of authenticated users. Enabled with the defaults when missing.
+    pub user_cache: Option<UserCacheConfig>,
+    /// Server-wide password rules. Companies can only make them stricter.
+    pub password_policy: Option<PasswordPolicyConfig>,
+    /// Argon2 cost of new password hashes. The Argon2 defaults apply when missing.
+    pub password_hashing: Option<PasswordHashingConfig>,
+    /// Defaults of the settings companies have not changed
+    pub company_settings: Option<CompanySettingsConfig>,
+    pub quotas: Option<QuotasConfig>,
+    /// Limits of batch prediction uploads. Enabled with the defaults when missing.
+    pub batch_uploads: Option<BatchUploadsConfig>,
+    /// Workers that predict queued uploads. Enabled with the defaults when missing.
+    pub inference_jobs: Option<InferenceJobsConfig>,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -18,8 +35,41 @@ pub struct ServerConfig {
     pub port: u16,
     pub jwt_secret: String,
     pub jwt_expiration_hours: u64,
+    /// Access token lifetime in minutes. When set, takes precedence over `jwt_expiration_hours`.
+    pub access_token_expiration_minutes: Option<u64>,
+    /// Refresh token (and session) lifetime in days. Defaults to 30.
+    pub refresh_token_expiration_days: Option<u64>,
     /// Allowed CORS origins. When empty or missing, CORS headers are not added.
     pub cors_allowed_origins: Option<String>,
+    /// Asymmetric JWT keys. When empty or missing, tokens are signed with HS256 and `jwt_secret`.
+    pub jwt_keys: Option<Vec<JwtKeyConfig>>,
+    /// `kid` of the key used to sign new tokens. Defaults to the first key with a private key.
+    /// The remaining keys are only used to verify tokens, which allows rotating keys.
+    pub jwt_signing_kid: Option<String>,
+    /// Base URL of the web client, used to build links sent by email
+    pub frontend_url: Option<String>,
+    /// Password reset token lifetime in minutes. Defaults to 30.
+    pub password_reset_expiration_minutes: Option<u64>,
+    /// Invitation link lifetime in hours. Defaults to 72.
+    pub invitation_expiration_hours: Option<u64>,
+    /// Email verification link lifetime in hours. Defaults to 48.
+    pub email_verification_expiration_hours: Option<u64>,
+    /// Issuer shown by authenticator apps. Defaults to "SmartPotatoLeaf".
+    pub two_factor_issuer: Option<String>,
+    /// Time to complete the second login step, in seconds. Defaults to 300.
+    pub two_factor_challenge_expiration_seconds: Option<u64>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct JwtKeyConfig {
+    /// Key identifier, sent in the `kid` header and published in the JWKS
+    pub kid: String,
+    /// Algorithm: "RS256" or "EdDSA"
+    pub algorithm: String,
+    /// Path to the PEM encoded private key. Only required for the signing key.
+    pub private_key_path: Option<String>,
+    /// Path to the PEM encoded public key
+    pub public_key_path: String,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -61,6 +111,8 @@ pub struct AdminConfig {
 pub struct IntegrationsConfig {
     pub model_serving: ModelServingConfig,
     pub storage: StorageConfig,
+    /// Outgoing email. When missing, emails are only logged.
+    pub mail: Option<MailConfig>,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -92,6 +144,29 @@ pub struct StorageConfig {
     pub local_base_path: Option<String>,
 }
 
+#[derive(Debug, Deserialize, Clone)]
+pub struct MailConfig {
+    /// Provider type: "smtp", "file" or "log"
+    pub provider: String,
+    /// Sender address (e.g., "SmartPotatoLeaf <no-reply@domain.com>")
+    pub from: String,
+    /// SMTP server host (required for smtp provider)
+    pub smtp_host: Option<String>,
+    /// SMTP server port. Defaults to 587.
+    pub smtp_port: Option<u16>,
+    /// SMTP username
+    pub smtp_username: Option<String>,
+    /// SMTP password
+    #[serde(skip_serializing)]
+    pub smtp_password: Option<String>,
+    /// Connection security: "starttls", "tls" or "none". Defaults to "starttls".
+    pub smtp_security: Option<String>,
+    /// Request timeout in seconds. Defaults to 10.
+    pub timeout_seconds: Option<u64>,
+    /// Directory where the file provider writes `.eml` files
+    pub file_path: Option<String>,
+}
+
 #[derive(Debug, Deserialize, Clone)]
 pub struct RateLimitingConfig {
     /// Enable rate limiting globally
@@ -110,6 +185,340 @@ pub struct RateLimitingConfig {
     pub endpoint_behavior: Option<String>,
 }
 
+#[derive(Debug, Deserialize, Clone)]
+pub struct LoginLockoutConfig {
+    /// Enable per-account lockout after repeated failed logins
+    pub enabled: bool,
+    /// Failed attempts allowed before the account is locked. Defaults to 5.
+    pub max_attempts: Option<u32>,
+    /// Duration of the first lockout in seconds, doubled on every new lockout. Defaults to 60.
+    pub lockout_seconds: Option<u64>,
+    /// Upper bound for the lockout duration in seconds. Defaults to 3600.
+    pub max_lockout_seconds: Option<u64>,
+    /// Seconds without failed attempts after which the counters are forgotten. Defaults to 900.
+    pub failure_window_seconds: Option<u64>,
+}
+
+impl Default for LoginLockoutConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            max_attempts: None,
+            lockout_seconds: None,
+            max_lockout_seconds: None,
+            failure_window_seconds: None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct UserCacheConfig {
+    /// Cache users between requests. Redis is used when available, an in-process LRU otherwise.
+    pub enabled: bool,
+    /// Seconds a cached user, and the permissions granted to roles, are trusted for.
+    /// Defaults to 30.
+    pub ttl_seconds: Option<u64>,
+    /// Users kept by the in-process cache. Defaults to 10000.
+    pub capacity: Option<usize>,
+}
+
+impl Default for UserCacheConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            ttl_seconds: None,
+            capacity: None,
+        }
+    }
+}
+
+impl UserCacheConfig {
+    pub fn ttl_seconds(&self) -> u64 {
+        self.ttl_seconds.unwrap_or(30)
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity.unwrap_or(10_000)
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct PasswordPolicyConfig {
+    /// Minimum password length. Defaults to 8.
+    pub min_length: Option<u16>,
+    /// Require an uppercase letter. Defaults to false.
+    pub require_uppercase: Option<bool>,
+    /// Require a lowercase letter. Defaults to false.
+    pub require_lowercase: Option<bool>,
+    /// Require a digit. Defaults to false.
+    pub require_digit: Option<bool>,
+    /// Require a character that is neither a letter nor a digit. Defaults to false.
+    pub require_symbol: Option<bool>,
+    /// Previous passwords that cannot be reused. Defaults to 0.
+    pub history_size: Option<u16>,
+    /// File with one breached password per line, rejected regardless of case
+    pub breached_passwords_path: Option<String>,
+}
+
+impl PasswordPolicyConfig {
+    pub fn min_length(&self) -> u16 {
+        self.min_length.unwrap_or(8)
+    }
+
+    pub fn require_uppercase(&self) -> bool {
+        self.require_uppercase.unwrap_or(false)
+    }
+
+    pub fn require_lowercase(&self) -> bool {
+        self.require_lowercase.unwrap_or(false)
+    }
+
+    pub fn require_digit(&self) -> bool {
+        self.require_digit.unwrap_or(false)
+    }
+
+    pub fn require_symbol(&self) -> bool {
+        self.require_symbol.unwrap_or(false)
+    }
+
+    pub fn history_size(&self) -> u16 {
+        self.history_size.unwrap_or(0)
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct PasswordHashingConfig {
+    /// Argon2 memory cost in KiB. Defaults to 19456.
+    pub memory_kib: Option<u32>,
+    /// Argon2 iterations. Defaults to 2.
+    pub iterations: Option<u32>,
+    /// Argon2 lanes. Defaults to 1.
+    pub parallelism: Option<u32>,
+}
+
+impl PasswordHashingConfig {
+    pub fn memory_kib(&self) -> u32 {
+        self.memory_kib.unwrap_or(19_456)
+    }
+
+    pub fn iterations(&self) -> u32 {
+        self.iterations.unwrap_or(2)
+    }
+
+    pub fn parallelism(&self) -> u32 {
+        self.parallelism.unwrap_or(1)
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct CompanySettingsConfig {
+    /// IANA time zone. Defaults to "UTC".
+    pub timezone: Option<String>,
+    /// Language code. Defaults to "es".
+    pub default_language: Option<String>,
+    /// Days predictions are kept, 0 to keep them forever. Defaults to 0.
+    pub data_retention_days: Option<u32>,
+    /// Items per page of listings that do not ask for a size. Defaults to 16.
+    pub default_page_size: Option<u64>,
+    /// Image formats that can be uploaded. Defaults to every supported format.
+    pub allowed_upload_formats: Option<Vec<String>>,
+    /// Severity (0-100) from which a prediction needs attention. Defaults to 50.
+    pub alert_severity_threshold: Option<f32>,
+    /// Lesion confidence (0-1) from which a prediction needs attention. Defaults to 0.8.
+    pub alert_confidence_threshold: Option<f32>,
+}
+
+impl CompanySettingsConfig {
+    pub fn timezone(&self) -> String {
+        self.timezone.clone().unwrap_or_else(|| "UTC".to_string())
+    }
+
+    pub fn default_language(&self) -> String {
+        self.default_language
+            .clone()
+            .unwrap_or_else(|| "es".to_string())
+    }
+
+    pub fn data_retention_days(&self) -> u32 {
+        self.data_retention_days.unwrap_or(0)
+    }
+
+    pub fn default_page_size(&self) -> u64 {
+        self.default_page_size.unwrap_or(16)
+    }
+
+    pub fn alert_severity_threshold(&self) -> f32 {
+        self.alert_severity_threshold.unwrap_or(50.0)
+    }
+
+    pub fn alert_confidence_threshold(&self) -> f32 {
+        self.alert_confidence_threshold.unwrap_or(0.8)
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct QuotasConfig {
+    /// Predictions of a company per month. Unlimited when missing.
+    pub predictions_per_month: Option<u64>,
+    /// Predictions of each user per month. Unlimited when missing.
+    pub predictions_per_user_per_month: Option<u64>,
+    /// Bytes of images a company stores per month. Unlimited when missing.
+    pub stored_bytes_per_month: Option<u64>,
+    /// Fraction of a limit (0-1) from which usage is reported as a warning. Defaults to 0.8.
+    pub warning_ratio: Option<f32>,
+    /// "hard" rejects predictions once a limit is reached, "soft" only warns. Defaults to "hard".
+    pub enforcement: Option<String>,
+}
+
+impl QuotasConfig {
+    pub fn warning_ratio(&self) -> f32 {
+        self.warning_ratio.unwrap_or(0.8).clamp(0.0, 1.0)
+    }
+
+    pub fn enforcement(&self) -> String {
+        self.enforcement.clone().unwrap_or_else(|| "hard".to_string())
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct BatchUploadsConfig {
+    /// Images of a single batch, counting those inside archives. Defaults to 500.
+    pub max_files: Option<usize>,
+    /// Size of a batch upload request in bytes. Defaults to 256 MiB.
+    pub max_upload_bytes: Option<usize>,
+}
+
+impl BatchUploadsConfig {
+    pub fn max_files(&self) -> usize {
+        self.max_files.unwrap_or(500)
+    }
+
+    pub fn max_upload_bytes(&self) -> usize {
+        self.max_upload_bytes.unwrap_or(256 * 1024 * 1024)
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct InferenceJobsConfig {
+    /// Jobs predicted at the same time by this server. Defaults to 2, 0 disables the workers.
+    pub workers: Option<usize>,
+    /// Attempts before a job is dead-lettered. Defaults to 3.
+    pub max_attempts: Option<u32>,
+    /// Wait before the first retry in seconds, doubled with every attempt. Defaults to 30.
+    pub retry_delay_seconds: Option<u64>,
+    /// Time a worker has to finish a job in seconds. Defaults to 300.
+    pub lease_seconds: Option<u64>,
+    /// How often idle workers look for jobs queued by other servers, in seconds. Defaults to 5.
+    pub poll_interval_seconds: Option<u64>,
+}
+
+impl InferenceJobsConfig {
+    pub fn workers(&self) -> usize {
+        self.workers.unwrap_or(2)
+    }
+
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts.unwrap_or(3).max(1)
+    }
+
+    pub fn retry_delay_seconds(&self) -> u64 {
+        self.retry_delay_seconds.unwrap_or(30)
+    }
+
+    pub fn lease_seconds(&self) -> u64 {
+        self.lease_seconds.unwrap_or(300)
+    }
+
+    pub fn poll_interval_seconds(&self) -> u64 {
+        self.poll_interval_seconds.unwrap_or(5).max(1)
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct OidcConfig {
+    /// Callback URL registered at the identity providers. Defaults to `{frontend_url}/auth/callback`.
+    pub redirect_url: Option<String>,
+    /// Time to complete the login at the identity provider, in seconds. Defaults to 600.
+    pub state_expiration_seconds: Option<u64>,
+    /// Timeout of the requests to the identity providers in seconds. Defaults to 10.
+    pub timeout_seconds: Option<u64>,
+}
+
+impl OidcConfig {
+    pub fn redirect_url(&self, frontend_url: Option<&str>) -> Option<String> {
+        self.redirect_url.clone().or_else(|| {
+            frontend_url.map(|url| format!("{}/auth/callback", url.trim_end_matches('/')))
+        })
+    }
+
+    pub fn state_ttl_seconds(&self) -> i64 {
+        self.state_expiration_seconds.unwrap_or(600) as i64
+    }
+
+    pub fn timeout_seconds(&self) -> u64 {
+        self.timeout_seconds.unwrap_or(10)
+    }
+}
+
+impl LoginLockoutConfig {
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts.unwrap_or(5)
+    }
+
+    pub fn lockout_seconds(&self) -> u64 {
+        self.lockout_seconds.unwrap_or(60)
+    }
+
+    pub fn max_lockout_seconds(&self) -> u64 {
+        self.max_lockout_seconds.unwrap_or(3600)
+    }
+
+    pub fn failure_window_seconds(&self) -> u64 {
+        self.failure_window_seconds.unwrap_or(900)
+    }
+}
+
+impl ServerConfig {
+    /// Access token lifetime in seconds
+    pub fn access_token_ttl_seconds(&self) -> i64 {
+        match self.access_token_expiration_minutes {
+            Some(minutes) => minutes as i64 * 60,
+            None => self.jwt_expiration_hours as i64 * 3600,
+        }
+    }
+
+    /// Refresh token lifetime in days
+    pub fn refresh_token_ttl_days(&self) -> i64 {
+        self.refresh_token_expiration_days.unwrap_or(30) as i64
+    }
+
+    /// Password reset token lifetime in minutes
+    pub fn password_reset_ttl_minutes(&self) -> i64 {
+        self.password_reset_expiration_minutes.unwrap_or(30) as i64
+    }
+
+    /// Invitation link lifetime in hours
+    pub fn invitation_ttl_hours(&self) -> i64 {
+        self.invitation_expiration_hours.unwrap_or(72) as i64
+    }
+
+    /// Email verification link lifetime in hours
+    pub fn email_verification_ttl_hours(&self) -> i64 {
+        self.email_verification_expiration_hours.unwrap_or(48) as i64
+    }
+
+    pub fn two_factor_issuer(&self) -> String {
+        self.two_factor_issuer
+            .clone()
+            .unwrap_or_else(|| "SmartPotatoLeaf".to_string())
+    }
+
+    /// Two-factor login challenge lifetime in seconds
+    pub fn two_factor_challenge_ttl_seconds(&self) -> i64 {
+        self.two_factor_challenge_expiration_seconds.unwrap_or(300) as i64
+    }
+}
+
 impl AppConfig {
     pub fn load() -> Result<Self> {
         let builder = Config::builder()
@@ -1,3 +1,4 @@
+use chrono::{DateTime, Utc};
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -46,6 +47,15 @@ pub enum AppError {
 
     #[error("Invalid credentials")]
     InvalidCredentials,
+
+    #[error("Account locked until {until}")]
+    AccountLocked { until: DateTime<Utc> },
+
+    #[error("Quota exceeded until {resets_at}: {message}")]
+    QuotaExceeded {
+        message: String,
+        resets_at: DateTime<Utc>,
+    },
 }
 
 impl From<sea_orm::DbErr> for AppError {
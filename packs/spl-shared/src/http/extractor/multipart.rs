@@ -30,3 +30,35 @@ pub async fn extract_file(
 
     Ok((bytes, filename))
 }
+
+/// Every file sent in `field_name`, in the order they were sent
+pub async fn extract_files(
+    field_name: &str,
+    multipart: &mut Multipart,
+) -> Result<Vec<(Vec<u8>, Option<String>)>> {
+    let mut files = Vec::new();
+
+    while let Some(field) = multipart
+        .next_field()
+        .await
+        .map_err(|e| AppError::ValidationError(format!("Failed to process multipart: {}", e)))?
+    {
+        if field.name() != Some(field_name) {
+            continue;
+        }
+
+        let filename = field.file_name().map(str::to_string);
+        let data = field
+            .bytes()
+            .await
+            .map_err(|e| AppError::ValidationError(format!("Failed to read file bytes: {}", e)))?;
+
+        files.push((data.to_vec(), filename));
+    }
+
+    if files.is_empty() {
+        return Err(AppError::ValidationError("No file provided".into()));
+    }
+
+    Ok(files)
+}
@@ -150,11 +150,20 @@ fn extract_client_ip(
     headers: &HeaderMap,
     connect_info: Option<&ConnectInfo<SocketAddr>>,
 ) -> String {
+    // Last resort
+    client_ip(headers, connect_info).unwrap_or_else(|| "unknown".to_string())
+}
+
+/// Client IP from the proxy headers, falling back to the connection address
+pub fn client_ip(
+    headers: &HeaderMap,
+    connect_info: Option<&ConnectInfo<SocketAddr>>,
+) -> Option<String> {
     // Try X-Forwarded-For header first (for proxies/load balancers)
     if let Some(forwarded) = headers.get("x-forwarded-for") {
         if let Ok(forwarded_str) = forwarded.to_str() {
             if let Some(first_ip) = forwarded_str.split(',').next() {
-                return first_ip.trim().to_string();
+                return Some(first_ip.trim().to_string());
             }
         }
     }
@@ -162,17 +171,12 @@ fn extract_client_ip(
     // Try X-Real-IP header
     if let Some(real_ip) = headers.get("x-real-ip") {
         if let Ok(ip_str) = real_ip.to_str() {
-            return ip_str.to_string();
+            return Some(ip_str.to_string());
         }
     }
 
     // Fall back to connection info
-    if let Some(ConnectInfo(addr)) = connect_info {
-        return addr.ip().to_string();
-    }
-
-    // Last resort
-    "unknown".to_string()
+    connect_info.map(|ConnectInfo(addr)| addr.ip().to_string())
 }
 
 /// Create rate limit response headers
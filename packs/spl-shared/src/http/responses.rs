@@ -1,5 +1,5 @@
 use crate::error::AppError;
-use axum::http::StatusCode;
+use axum::http::{header, HeaderValue, StatusCode};
 use axum::response::{IntoResponse, Response};
 use axum::Json;
 use serde::{Deserialize, Serialize};
@@ -16,6 +16,16 @@ pub struct StatusResponse {
 
 impl IntoResponse for AppError {
     fn into_response(self) -> Response {
+        let retry_after = match &self {
+            AppError::AccountLocked { until } => {
+                Some((*until - chrono::Utc::now()).num_seconds().max(1))
+            }
+            AppError::QuotaExceeded { resets_at, .. } => {
+                Some((*resets_at - chrono::Utc::now()).num_seconds().max(1))
+            }
+            _ => None,
+        };
+
         let (status, code, message) = match self {
             AppError::Forbidden => (
                 StatusCode::FORBIDDEN,
@@ -87,9 +97,20 @@ impl IntoResponse for AppError {
                 error!("{:?}", message);
                 (StatusCode::NOT_FOUND, "NO_CONTENT", message)
             }
+            AppError::AccountLocked { until } => (
+                StatusCode::LOCKED,
+                "ACCOUNT_LOCKED",
+                format!(
+                    "Too many failed login attempts, account locked until {}",
+                    until.to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
+                ),
+            ),
+            AppError::QuotaExceeded { message, .. } => {
+                (StatusCode::PAYMENT_REQUIRED, "QUOTA_EXCEEDED", message)
+            }
         };
 
-        (
+        let mut response = (
             status,
             Json(StatusResponse {
                 success: false,
@@ -97,7 +118,15 @@ impl IntoResponse for AppError {
                 message: format!("{}: {}", code, message),
             }),
         )
-            .into_response()
+            .into_response();
+
+        if let Some(seconds) = retry_after {
+            response
+                .headers_mut()
+                .insert(header::RETRY_AFTER, HeaderValue::from(seconds));
+        }
+
+        response
     }
 }
 
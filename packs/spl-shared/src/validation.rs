@@ -1,3 +1,4 @@
+use std::str::FromStr;
 use std::sync::OnceLock;
 use regex::Regex;
 use validator::ValidationError;
@@ -20,3 +21,23 @@ pub fn validate_range_min_max<T: PartialOrd>(min: T, max: T) -> Result<(), Valid
         Err(ValidationError::new("range_min_max"))
     }
 }
+
+/// IANA time zone names such as `UTC` or `America/Lima`
+pub fn validate_timezone(timezone: &str) -> Result<(), ValidationError> {
+    match chrono_tz::Tz::from_str(timezone) {
+        Ok(_) => Ok(()),
+        Err(_) => Err(ValidationError::new("timezone")),
+    }
+}
+
+static RE_LANGUAGE: OnceLock<Regex> = OnceLock::new();
+
+/// Language codes such as `es` or `en-US`
+pub fn validate_language(language: &str) -> Result<(), ValidationError> {
+    let re = RE_LANGUAGE.get_or_init(|| Regex::new(r"^[a-z]{2,3}(-[A-Z]{2})?$").unwrap());
+    if re.is_match(language) {
+        Ok(())
+    } else {
+        Err(ValidationError::new("language"))
+    }
+}